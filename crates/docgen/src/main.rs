@@ -36,6 +36,15 @@ const FILES: &[DocFile] = &[
             ),
         ],
     },
+    DocFile {
+        path: "docs/reference/functions/window.md",
+        sections: &[(
+            "window_functions",
+            &FunctionSectionWriter::<3> {
+                category: Category::Window,
+            },
+        )],
+    },
     DocFile {
         path: "docs/reference/functions/numeric.md",
         sections: &[(
@@ -95,6 +104,15 @@ const FILES: &[DocFile] = &[
             ),
         ],
     },
+    DocFile {
+        path: "docs/reference/functions/json.md",
+        sections: &[(
+            "json_functions",
+            &FunctionSectionWriter::<2> {
+                category: Category::Json,
+            },
+        )],
+    },
     DocFile {
         path: "docs/reference/functions/list.md",
         sections: &[(
@@ -104,6 +122,15 @@ const FILES: &[DocFile] = &[
             },
         )],
     },
+    DocFile {
+        path: "docs/reference/functions/map.md",
+        sections: &[(
+            "map_functions",
+            &FunctionSectionWriter::<2> {
+                category: Category::Map,
+            },
+        )],
+    },
     DocFile {
         path: "docs/reference/functions/system.md",
         sections: &[(
@@ -122,6 +149,15 @@ const FILES: &[DocFile] = &[
             },
         )],
     },
+    DocFile {
+        path: "docs/reference/functions/uuid.md",
+        sections: &[(
+            "uuid_functions",
+            &FunctionSectionWriter::<2> {
+                category: Category::Uuid,
+            },
+        )],
+    },
 ];
 
 fn main() -> Result<()> {
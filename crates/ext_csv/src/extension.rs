@@ -1,6 +1,7 @@
-use glaredb_core::catalog::create::FileInferScan;
-use glaredb_core::extension::{Extension, ExtensionTableFunction};
+use glaredb_core::catalog::create::{FileInferCopyTo, FileInferScan};
+use glaredb_core::extension::{Extension, ExtensionCopyToFunction, ExtensionTableFunction};
 
+use crate::functions::copy_to_csv::FUNCTION_SET_COPY_TO_CSV;
 use crate::functions::read_csv::FUNCTION_SET_READ_CSV;
 
 #[derive(Debug, Clone, Copy)]
@@ -23,4 +24,15 @@ impl Extension for CsvExtension {
 
         FUNCTIONS
     }
+
+    fn copy_to_functions(&self) -> &[ExtensionCopyToFunction] {
+        const FUNCTIONS: &[ExtensionCopyToFunction] = &[ExtensionCopyToFunction {
+            infer_copy_to: Some(FileInferCopyTo {
+                can_handle: |path| path.ends_with(".csv"),
+            }),
+            function: &FUNCTION_SET_COPY_TO_CSV,
+        }];
+
+        FUNCTIONS
+    }
 }
@@ -0,0 +1,53 @@
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_core::functions::copy_to::{
+    CopyToFunction,
+    CopyToFunctionSet,
+    CopyToOptions,
+    RawCopyToFunction,
+};
+use glaredb_error::{DbError, Result};
+
+use crate::dialect::DialectOptions;
+use crate::writer::CsvEncoder;
+
+pub const FUNCTION_SET_COPY_TO_CSV: CopyToFunctionSet = CopyToFunctionSet {
+    name: "csv",
+    function: RawCopyToFunction::new(&CopyToCsv),
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct CopyToCsv;
+
+impl CopyToFunction for CopyToCsv {
+    type BindState = DialectOptions;
+    type Sink = CsvEncoder;
+
+    fn bind(&self, _schema: &ColumnSchema, options: &CopyToOptions) -> Result<Self::BindState> {
+        let mut dialect = DialectOptions::default();
+
+        if let Some(delimiter) = options.get_str("delimiter")? {
+            let &[delimiter] = delimiter.as_bytes() else {
+                return Err(DbError::new(format!(
+                    "Delimiter must be a single byte character, got '{delimiter}'"
+                )));
+            };
+            dialect.delimiter = delimiter;
+        }
+
+        Ok(dialect)
+    }
+
+    fn create_sink(state: &Self::BindState, schema: &ColumnSchema) -> Result<Self::Sink> {
+        Ok(CsvEncoder::new(schema.clone(), *state))
+    }
+
+    fn encode(sink: &mut Self::Sink, batch: &Batch, buf: &mut Vec<u8>) -> Result<()> {
+        sink.encode(batch, buf)
+    }
+
+    fn finish(_sink: &mut Self::Sink, _buf: &mut Vec<u8>) -> Result<()> {
+        // Everything already written during encode.
+        Ok(())
+    }
+}
@@ -1 +1,2 @@
+pub mod copy_to_csv;
 pub mod read_csv;
@@ -150,6 +150,7 @@ impl TableScanFunction for ReadCsv {
             data_schema: col_schema,
             meta_schema: Some(provider.meta_schema()),
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -0,0 +1,369 @@
+//! Reading actions from parquet checkpoints.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#checkpoints>
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use ext_parquet::metadata::loader::MetaDataLoader;
+use ext_parquet::reader::{Reader, ScanRowGroup, ScanUnit};
+use futures::future;
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::datatype::DataType;
+use glaredb_core::arrays::field::{ColumnSchema, Field};
+use glaredb_core::arrays::scalar::BorrowedScalarValue;
+use glaredb_core::buffer::buffer_manager::DefaultBufferManager;
+use glaredb_core::execution::operators::PollPull;
+use glaredb_core::runtime::filesystem::{FileSystemWithState, OpenFlags};
+use glaredb_core::storage::projections::Projections;
+use glaredb_error::{DbError, Result};
+
+use crate::protocol::action::{
+    Action,
+    ActionAddFile,
+    ActionChangeMetadata,
+    ActionProtocol,
+    ActionRemoveFile,
+    FormatSpec,
+};
+use crate::table::percent_decode;
+
+const CHECKPOINT_BATCH_SIZE: usize = 4096;
+
+/// Leaf columns we read from the checkpoint.
+///
+/// Only non-repeated leaves can be read since the parquet reader only supports
+/// flat schemas. This means map and list columns (e.g. 'add.partitionValues',
+/// 'metaData.partitionColumns') are skipped, see `partition_values_from_path`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CheckpointColumn {
+    AddPath,
+    AddSize,
+    AddModificationTime,
+    AddDataChange,
+    AddStats,
+    RemovePath,
+    RemoveDeletionTimestamp,
+    RemoveDataChange,
+    MetadataId,
+    MetadataName,
+    MetadataDescription,
+    MetadataFormatProvider,
+    MetadataSchemaString,
+    ProtocolMinReaderVersion,
+    ProtocolMinWriterVersion,
+}
+
+impl CheckpointColumn {
+    const ALL: &[CheckpointColumn] = &[
+        Self::AddPath,
+        Self::AddSize,
+        Self::AddModificationTime,
+        Self::AddDataChange,
+        Self::AddStats,
+        Self::RemovePath,
+        Self::RemoveDeletionTimestamp,
+        Self::RemoveDataChange,
+        Self::MetadataId,
+        Self::MetadataName,
+        Self::MetadataDescription,
+        Self::MetadataFormatProvider,
+        Self::MetadataSchemaString,
+        Self::ProtocolMinReaderVersion,
+        Self::ProtocolMinWriterVersion,
+    ];
+
+    const fn path(&self) -> &'static [&'static str] {
+        match self {
+            Self::AddPath => &["add", "path"],
+            Self::AddSize => &["add", "size"],
+            Self::AddModificationTime => &["add", "modificationTime"],
+            Self::AddDataChange => &["add", "dataChange"],
+            Self::AddStats => &["add", "stats"],
+            Self::RemovePath => &["remove", "path"],
+            Self::RemoveDeletionTimestamp => &["remove", "deletionTimestamp"],
+            Self::RemoveDataChange => &["remove", "dataChange"],
+            Self::MetadataId => &["metaData", "id"],
+            Self::MetadataName => &["metaData", "name"],
+            Self::MetadataDescription => &["metaData", "description"],
+            Self::MetadataFormatProvider => &["metaData", "format", "provider"],
+            Self::MetadataSchemaString => &["metaData", "schemaString"],
+            Self::ProtocolMinReaderVersion => &["protocol", "minReaderVersion"],
+            Self::ProtocolMinWriterVersion => &["protocol", "minWriterVersion"],
+        }
+    }
+
+    const fn datatype(&self) -> DataType {
+        match self {
+            Self::AddSize | Self::AddModificationTime | Self::RemoveDeletionTimestamp => {
+                DataType::int64()
+            }
+            Self::AddDataChange | Self::RemoveDataChange => DataType::boolean(),
+            Self::ProtocolMinReaderVersion | Self::ProtocolMinWriterVersion => DataType::int32(),
+            _ => DataType::utf8(),
+        }
+    }
+}
+
+/// Read all actions from a single checkpoint file.
+///
+/// For multi-part checkpoints, this should be called for each part.
+pub async fn read_checkpoint_actions(fs: &FileSystemWithState, path: &str) -> Result<Vec<Action>> {
+    let mut file = fs.open(OpenFlags::READ, path).await?;
+    let mut metadata = MetaDataLoader::new().load_from_file(&mut file).await?;
+    // Actions are nested structs, read each field we care about as its own
+    // column.
+    metadata.file_metadata.schema_descr =
+        Arc::new(metadata.file_metadata.schema_descr.with_leaves_as_roots());
+    let schema_descr = &metadata.file_metadata.schema_descr;
+
+    // Find the leaves for the columns we care about. Columns may be missing if
+    // the writer didn't include them.
+    let mut leaf_fields: Vec<_> = schema_descr
+        .leaves
+        .iter()
+        .map(|leaf| Field::new(leaf.name(), DataType::null(), true))
+        .collect();
+    let mut projected_leaves = Vec::new();
+    // Position of each checkpoint column in the output batch.
+    let mut positions = HashMap::new();
+
+    for &col in CheckpointColumn::ALL {
+        let leaf_idx = schema_descr.leaves.iter().position(|leaf| {
+            leaf.path
+                .parts
+                .iter()
+                .map(|s| s.as_str())
+                .eq(col.path().iter().copied())
+        });
+        if let Some(leaf_idx) = leaf_idx {
+            leaf_fields[leaf_idx].datatype = col.datatype();
+            positions.insert(col, projected_leaves.len());
+            projected_leaves.push(leaf_idx);
+        }
+    }
+
+    let types: Vec<_> = projected_leaves
+        .iter()
+        .map(|&idx| leaf_fields[idx].datatype.clone())
+        .collect();
+
+    let mut reader = Reader::try_new(
+        &DefaultBufferManager,
+        ColumnSchema::new(leaf_fields),
+        schema_descr,
+        Projections::new(projected_leaves),
+        &[],
+    )?;
+
+    let row_groups: VecDeque<_> = ScanRowGroup::from_metadata(&metadata).collect();
+    reader.prepare(ScanUnit {
+        metadata: Arc::new(metadata),
+        file,
+        row_groups,
+    })?;
+
+    let mut batch = Batch::new(types, CHECKPOINT_BATCH_SIZE)?;
+    let mut actions = Vec::new();
+
+    loop {
+        batch.reset_for_write()?;
+        let poll = future::poll_fn(|cx| match reader.poll_pull(cx, &mut batch) {
+            Ok(PollPull::Pending) => std::task::Poll::Pending,
+            other => std::task::Poll::Ready(other),
+        })
+        .await?;
+
+        if poll == PollPull::Exhausted {
+            break;
+        }
+
+        let rows = CheckpointRows {
+            batch: &batch,
+            positions: &positions,
+        };
+        for row in 0..batch.num_rows() {
+            if let Some(action) = rows.action(row)? {
+                actions.push(action);
+            }
+        }
+    }
+
+    Ok(actions)
+}
+
+/// Wrapper around a batch read from a checkpoint for converting rows into
+/// actions.
+#[derive(Debug)]
+struct CheckpointRows<'a> {
+    batch: &'a Batch,
+    positions: &'a HashMap<CheckpointColumn, usize>,
+}
+
+impl CheckpointRows<'_> {
+    /// Convert a row into an action.
+    ///
+    /// Each row in a checkpoint contains exactly one action, with all other
+    /// columns being NULL. Rows for actions that we don't read (e.g. 'txn')
+    /// return None.
+    fn action(&self, row: usize) -> Result<Option<Action>> {
+        if let Some(path) = self.string(CheckpointColumn::AddPath, row)? {
+            let partition_values = partition_values_from_path(&path);
+            return Ok(Some(Action::AddFile(ActionAddFile {
+                path,
+                partition_values,
+                size: self.require_i64(CheckpointColumn::AddSize, row)? as u64,
+                modification_time: self.require_i64(CheckpointColumn::AddModificationTime, row)?
+                    as u64,
+                data_change: self.bool(CheckpointColumn::AddDataChange, row)?,
+                stats: self.string(CheckpointColumn::AddStats, row)?,
+            })));
+        }
+
+        if let Some(path) = self.string(CheckpointColumn::RemovePath, row)? {
+            return Ok(Some(Action::RemoveFile(ActionRemoveFile {
+                path,
+                deletion_timestamp: self
+                    .i64(CheckpointColumn::RemoveDeletionTimestamp, row)?
+                    .map(|v| v as u64),
+                data_change: self.bool(CheckpointColumn::RemoveDataChange, row)?,
+            })));
+        }
+
+        if let Some(id) = self.string(CheckpointColumn::MetadataId, row)? {
+            let schema_string = self
+                .string(CheckpointColumn::MetadataSchemaString, row)?
+                .ok_or_else(|| DbError::new("Missing schema string in checkpoint metadata"))?;
+
+            return Ok(Some(Action::ChangeMetadata(ActionChangeMetadata {
+                id,
+                name: self.string(CheckpointColumn::MetadataName, row)?,
+                description: self.string(CheckpointColumn::MetadataDescription, row)?,
+                format: FormatSpec {
+                    provider: self
+                        .string(CheckpointColumn::MetadataFormatProvider, row)?
+                        .unwrap_or_else(|| "parquet".to_string()),
+                },
+                schema_string,
+                // Repeated column, not read. Partition values are derived from
+                // the file paths instead.
+                partition_columns: Vec::new(),
+            })));
+        }
+
+        if let Some(min_reader_version) =
+            self.i64(CheckpointColumn::ProtocolMinReaderVersion, row)?
+        {
+            return Ok(Some(Action::Protocol(ActionProtocol {
+                min_reader_version: min_reader_version as u32,
+                min_writer_version: self
+                    .i64(CheckpointColumn::ProtocolMinWriterVersion, row)?
+                    .unwrap_or_default() as u32,
+                reader_features: None,
+                writer_features: None,
+            })));
+        }
+
+        Ok(None)
+    }
+
+    fn value(&self, col: CheckpointColumn, row: usize) -> Result<BorrowedScalarValue<'_>> {
+        match self.positions.get(&col) {
+            Some(&pos) => self.batch.arrays()[pos].get_value(row),
+            None => Ok(BorrowedScalarValue::Null),
+        }
+    }
+
+    fn string(&self, col: CheckpointColumn, row: usize) -> Result<Option<String>> {
+        match self.value(col, row)? {
+            BorrowedScalarValue::Null => Ok(None),
+            BorrowedScalarValue::Utf8(s) => Ok(Some(s.into_owned())),
+            other => Err(DbError::new(format!(
+                "Unexpected value for checkpoint column {col:?}: {other}"
+            ))),
+        }
+    }
+
+    fn i64(&self, col: CheckpointColumn, row: usize) -> Result<Option<i64>> {
+        match self.value(col, row)? {
+            BorrowedScalarValue::Null => Ok(None),
+            BorrowedScalarValue::Int32(v) => Ok(Some(v as i64)),
+            BorrowedScalarValue::Int64(v) => Ok(Some(v)),
+            other => Err(DbError::new(format!(
+                "Unexpected value for checkpoint column {col:?}: {other}"
+            ))),
+        }
+    }
+
+    fn require_i64(&self, col: CheckpointColumn, row: usize) -> Result<i64> {
+        self.i64(col, row)?
+            .ok_or_else(|| DbError::new(format!("Missing value for checkpoint column {col:?}")))
+    }
+
+    fn bool(&self, col: CheckpointColumn, row: usize) -> Result<bool> {
+        match self.value(col, row)? {
+            BorrowedScalarValue::Null => Ok(false),
+            BorrowedScalarValue::Boolean(v) => Ok(v),
+            other => Err(DbError::new(format!(
+                "Unexpected value for checkpoint column {col:?}: {other}"
+            ))),
+        }
+    }
+}
+
+/// Value hive uses for NULL partition values.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Derive partition values from hive-style directories in a relative file
+/// path.
+///
+/// Checkpoints store partition values in a map column which we can't read yet.
+/// Every writer we know of lays out partitioned data as 'col=value/', so we
+/// fall back to parsing that. Segments for columns that aren't part of the
+/// table are ignored when scanning.
+pub(crate) fn partition_values_from_path(path: &str) -> HashMap<String, Option<String>> {
+    let decoded = percent_decode(path);
+    let mut segments: Vec<_> = decoded.split('/').collect();
+    // Last segment is the file name.
+    segments.pop();
+
+    segments
+        .into_iter()
+        .filter_map(|segment| {
+            let (col, val) = segment.split_once('=')?;
+            let val = if val == HIVE_DEFAULT_PARTITION {
+                None
+            } else {
+                // Hive escapes special characters in the directory name.
+                Some(percent_decode(val))
+            };
+            Some((percent_decode(col), val))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_values_from_paths() {
+        assert!(partition_values_from_path("part-0000.parquet").is_empty());
+
+        let values = partition_values_from_path("date=2017-12-10/part-0000.parquet");
+        let expected: HashMap<_, _> = [("date".to_string(), Some("2017-12-10".to_string()))]
+            .into_iter()
+            .collect();
+        assert_eq!(expected, values);
+
+        // Double encoded by hive, then the delta path.
+        let values =
+            partition_values_from_path("a=__HIVE_DEFAULT_PARTITION__/b=x%253Ay/part-0000.parquet");
+        let expected: HashMap<_, _> = [
+            ("a".to_string(), None),
+            ("b".to_string(), Some("x:y".to_string())),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(expected, values);
+    }
+}
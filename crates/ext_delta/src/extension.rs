@@ -0,0 +1,18 @@
+use glaredb_core::extension::{Extension, ExtensionTableFunction};
+
+use crate::functions::read_delta::FUNCTION_SET_READ_DELTA;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DeltaExtension;
+
+impl Extension for DeltaExtension {
+    const NAME: &str = "delta";
+    const FUNCTION_NAMESPACE: Option<&str> = None; // Place functions in default schema.
+
+    fn table_functions(&self) -> &[ExtensionTableFunction] {
+        const FUNCTIONS: &[ExtensionTableFunction] =
+            &[ExtensionTableFunction::new(&FUNCTION_SET_READ_DELTA)];
+
+        FUNCTIONS
+    }
+}
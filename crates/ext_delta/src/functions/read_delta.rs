@@ -0,0 +1,412 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use ext_parquet::metadata::loader::MetaDataLoader;
+use ext_parquet::reader::{Reader, ScanRowGroup, ScanUnit};
+use ext_parquet::schema::types::SchemaDescriptor;
+use futures::FutureExt;
+use glaredb_core::arrays::array::Array;
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::cache::NopCache;
+use glaredb_core::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use glaredb_core::arrays::field::{ColumnSchema, Field};
+use glaredb_core::arrays::scalar::{BorrowedScalarValue, ScalarValue};
+use glaredb_core::buffer::buffer_manager::DefaultBufferManager;
+use glaredb_core::execution::operators::{ExecutionProperties, PollPull};
+use glaredb_core::functions::Signature;
+use glaredb_core::functions::documentation::{Category, Documentation};
+use glaredb_core::functions::function_set::TableFunctionSet;
+use glaredb_core::functions::table::scan::{ScanContext, TableScanFunction};
+use glaredb_core::functions::table::{
+    RawTableFunction,
+    TableFunctionBindState,
+    TableFunctionInput,
+};
+use glaredb_core::optimizer::expr_rewrite::ExpressionRewriteRule;
+use glaredb_core::optimizer::expr_rewrite::const_fold::ConstFold;
+use glaredb_core::runtime::filesystem::{
+    FileOpenContext,
+    FileSystemFuture,
+    FileSystemWithState,
+    OpenFlags,
+};
+use glaredb_core::statistics::value::StatisticsValue;
+use glaredb_core::storage::projections::Projections;
+use glaredb_core::storage::scan_filter::PhysicalScanFilter;
+use glaredb_error::{DbError, Result, not_implemented};
+use tracing::debug;
+
+use crate::pruning::prune_files;
+use crate::table::{ScanFile, Table};
+
+pub const FUNCTION_SET_READ_DELTA: TableFunctionSet = TableFunctionSet {
+    name: "read_delta",
+    aliases: &["delta_scan"],
+    doc: &[&Documentation {
+        category: Category::Table,
+        description: "Read a Delta Lake table. Use the 'version' or 'timestamp' named arguments to read an older version of the table.",
+        arguments: &["path"],
+        example: None,
+    }],
+    functions: &[RawTableFunction::new_scan(
+        &Signature::new(&[DataTypeId::Utf8], DataTypeId::Table),
+        &ReadDelta,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReadDelta;
+
+pub struct ReadDeltaBindState {
+    fs: FileSystemWithState,
+    schema: ColumnSchema,
+    files: Arc<[ScanFile]>,
+}
+
+pub struct ReadDeltaOperatorState {
+    fs: FileSystemWithState,
+    schema: ColumnSchema,
+    projections: Projections,
+    files: Arc<[ScanFile]>,
+    batch_size: usize,
+}
+
+pub struct ReadDeltaPartitionState {
+    state: ScanState,
+    /// Indices of the files this partition is responsible for.
+    file_queue: VecDeque<usize>,
+}
+
+enum ScanState {
+    /// Init the next file to read.
+    Init,
+    /// Currently opening a file.
+    Opening {
+        open_fut: FileSystemFuture<'static, Result<FileScan>>,
+    },
+    /// Currently scanning a file.
+    Scanning(Box<FileScan>),
+}
+
+/// Where the data for a projected column comes from.
+#[derive(Debug)]
+enum ColumnSource {
+    /// Column is read from the file, value indicates the index of the array in
+    /// the file batch.
+    File(usize),
+    /// Column is a constant, either a partition value, or NULL if the column
+    /// is missing from the file.
+    ///
+    /// Holds a single value.
+    Constant(Array),
+}
+
+/// State for scanning a single data file.
+#[derive(Debug)]
+struct FileScan {
+    reader: Reader,
+    /// Batch that the parquet reader writes to.
+    batch: Batch,
+    /// Source for each projected column.
+    columns: Vec<ColumnSource>,
+}
+
+impl TableScanFunction for ReadDelta {
+    type BindState = ReadDeltaBindState;
+    type OperatorState = ReadDeltaOperatorState;
+    type PartitionState = ReadDeltaPartitionState;
+
+    async fn bind(
+        &'static self,
+        scan_context: ScanContext<'_>,
+        input: TableFunctionInput,
+    ) -> Result<TableFunctionBindState<Self::BindState>> {
+        let path = ConstFold::rewrite(input.positional[0].clone())?
+            .try_into_scalar()?
+            .try_into_string()?;
+
+        let fs = scan_context.dispatch.filesystem_for_path(&path)?;
+        let context = FileOpenContext::new(scan_context.database_context, &input.named);
+        let version = context.get_value("version")?;
+        let timestamp = context.get_value("timestamp")?;
+        let fs = fs.load_state(context).await?;
+
+        let table = match (version, timestamp) {
+            (Some(_), Some(_)) => {
+                return Err(DbError::new(
+                    "Cannot specify both 'version' and 'timestamp' for read_delta",
+                ));
+            }
+            (Some(version), None) => {
+                let version = u64::try_from(version.try_as_i64()?)
+                    .map_err(|_| DbError::new(format!("Invalid delta version: {version}")))?;
+                Table::load_version(&fs, &path, Some(version)).await?
+            }
+            (None, Some(timestamp)) => {
+                Table::load_at_timestamp(&fs, &path, timestamp_millis(&timestamp)?).await?
+            }
+            (None, None) => Table::load(&fs, &path).await?,
+        };
+        let schema = table.column_schema()?;
+        let files: Arc<[ScanFile]> = table.scan_files(&schema)?.into();
+
+        Ok(TableFunctionBindState {
+            state: ReadDeltaBindState {
+                fs,
+                schema: schema.clone(),
+                files,
+            },
+            input,
+            data_schema: schema,
+            meta_schema: None,
+            cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
+        })
+    }
+
+    fn create_pull_operator_state(
+        bind_state: &Self::BindState,
+        projections: Projections,
+        filters: &[PhysicalScanFilter],
+        props: ExecutionProperties,
+    ) -> Result<Self::OperatorState> {
+        let files = if filters.is_empty() {
+            bind_state.files.clone()
+        } else {
+            let files = prune_files(&bind_state.schema, &bind_state.files, filters);
+            debug!(
+                total = bind_state.files.len(),
+                remaining = files.len(),
+                "pruned delta files"
+            );
+            files.into()
+        };
+
+        Ok(ReadDeltaOperatorState {
+            fs: bind_state.fs.clone(),
+            schema: bind_state.schema.clone(),
+            projections,
+            files,
+            batch_size: props.batch_size,
+        })
+    }
+
+    fn create_pull_partition_states(
+        op_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionState>> {
+        let states = (0..partitions)
+            .map(|partition_idx| ReadDeltaPartitionState {
+                state: ScanState::Init,
+                file_queue: (0..op_state.files.len())
+                    .skip(partition_idx)
+                    .step_by(partitions)
+                    .collect(),
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_pull(
+        cx: &mut Context,
+        op_state: &Self::OperatorState,
+        state: &mut Self::PartitionState,
+        output: &mut Batch,
+    ) -> Result<PollPull> {
+        loop {
+            match &mut state.state {
+                ScanState::Init => {
+                    let file_idx = match state.file_queue.pop_front() {
+                        Some(idx) => idx,
+                        None => {
+                            output.set_num_rows(0)?;
+                            return Ok(PollPull::Exhausted);
+                        }
+                    };
+
+                    let file = op_state.files[file_idx].clone();
+                    let open_fut = op_state.fs.open_static(OpenFlags::READ, file.path.clone());
+                    let schema = op_state.schema.clone();
+                    let projections = op_state.projections.clone();
+                    let batch_size = op_state.batch_size;
+
+                    let fut = Box::pin(async move {
+                        let mut handle = open_fut.await?;
+                        let metadata = MetaDataLoader::new().load_from_file(&mut handle).await?;
+                        let row_groups = ScanRowGroup::from_metadata(&metadata).collect();
+
+                        let mut scan = FileScan::try_new(
+                            &schema,
+                            &projections,
+                            &file,
+                            &metadata.file_metadata.schema_descr,
+                            batch_size,
+                        )?;
+                        scan.reader.prepare(ScanUnit {
+                            metadata: Arc::new(metadata),
+                            file: handle,
+                            row_groups,
+                        })?;
+
+                        Ok(scan)
+                    });
+
+                    state.state = ScanState::Opening { open_fut: fut };
+                    // Continue...
+                }
+                ScanState::Opening { open_fut } => {
+                    let scan = match open_fut.poll_unpin(cx)? {
+                        Poll::Ready(scan) => scan,
+                        Poll::Pending => return Ok(PollPull::Pending),
+                    };
+                    state.state = ScanState::Scanning(Box::new(scan));
+                    // Continue...
+                }
+                ScanState::Scanning(scan) => {
+                    scan.batch.reset_for_write()?;
+                    let poll = scan.reader.poll_pull(cx, &mut scan.batch)?;
+                    match poll {
+                        PollPull::Pending => return Ok(PollPull::Pending),
+                        PollPull::Exhausted => {
+                            // Move to the next file.
+                            state.state = ScanState::Init;
+                            continue;
+                        }
+                        PollPull::HasMore => {
+                            scan.write_output(output)?;
+                            return Ok(PollPull::HasMore);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Convert the value for the 'timestamp' argument to milliseconds since epoch.
+///
+/// Dates are treated as midnight UTC.
+fn timestamp_millis(value: &ScalarValue) -> Result<i64> {
+    match value {
+        BorrowedScalarValue::Timestamp(ts) => Ok(match ts.unit {
+            TimeUnit::Second => ts.value * 1000,
+            TimeUnit::Millisecond => ts.value,
+            TimeUnit::Microsecond => ts.value.div_euclid(1000),
+            TimeUnit::Nanosecond => ts.value.div_euclid(1_000_000),
+        }),
+        BorrowedScalarValue::Date32(days) => Ok(*days as i64 * 86_400_000),
+        other => Err(DbError::new(format!(
+            "Expected a timestamp or date for 'timestamp', got {other}"
+        ))),
+    }
+}
+
+impl FileScan {
+    fn try_new(
+        schema: &ColumnSchema,
+        projections: &Projections,
+        file: &ScanFile,
+        parquet_schema: &SchemaDescriptor,
+        batch_size: usize,
+    ) -> Result<Self> {
+        // Schema for the parquet reader. Fields are per leaf, only projected
+        // leaves need the real type.
+        let mut leaf_fields: Vec<_> = parquet_schema
+            .leaves
+            .iter()
+            .map(|leaf| Field::new(leaf.name(), DataType::null(), true))
+            .collect();
+        let mut leaf_projections = Vec::new();
+        let mut columns = Vec::with_capacity(projections.data_indices().len());
+
+        for &col_idx in projections.data_indices() {
+            let field = &schema.fields[col_idx];
+
+            let partition_value = file
+                .partition_values
+                .iter()
+                .find_map(|(idx, value)| (*idx == col_idx).then_some(value));
+            if let Some(value) = partition_value {
+                let constant = if matches!(value, BorrowedScalarValue::Null) {
+                    Array::new_null(&DefaultBufferManager, field.datatype.clone(), 1)?
+                } else {
+                    Array::new_constant(&DefaultBufferManager, value, 1)?
+                };
+                columns.push(ColumnSource::Constant(constant));
+                continue;
+            }
+
+            let leaf_idx = parquet_schema
+                .leaves
+                .iter()
+                .position(|leaf| leaf.path.parts.first() == Some(&field.name));
+            match leaf_idx {
+                Some(leaf_idx) => {
+                    if parquet_schema.leaves[leaf_idx].path.parts.len() != 1 {
+                        not_implemented!("Reading nested delta column '{}'", field.name);
+                    }
+                    leaf_fields[leaf_idx].datatype = field.datatype.clone();
+                    columns.push(ColumnSource::File(leaf_projections.len()));
+                    leaf_projections.push(leaf_idx);
+                }
+                None => {
+                    // Column was added to the table after this file was
+                    // written.
+                    let constant =
+                        Array::new_null(&DefaultBufferManager, field.datatype.clone(), 1)?;
+                    columns.push(ColumnSource::Constant(constant));
+                }
+            }
+        }
+
+        if !projections.meta_indices().is_empty() {
+            return Err(DbError::new("Delta scan does not support metadata columns"));
+        }
+
+        let types: Vec<_> = leaf_projections
+            .iter()
+            .map(|&idx| leaf_fields[idx].datatype.clone())
+            .collect();
+
+        let reader = Reader::try_new(
+            &DefaultBufferManager,
+            ColumnSchema::new(leaf_fields),
+            parquet_schema,
+            Projections::new(leaf_projections),
+            &[],
+        )?;
+
+        Ok(FileScan {
+            reader,
+            batch: Batch::new(types, batch_size)?,
+            columns,
+        })
+    }
+
+    /// Write the current file batch to the output, filling in constant
+    /// columns.
+    fn write_output(&mut self, output: &mut Batch) -> Result<()> {
+        let num_rows = self.batch.num_rows();
+
+        for (out_idx, source) in self.columns.iter_mut().enumerate() {
+            match source {
+                ColumnSource::File(idx) => {
+                    output.clone_array_from(out_idx, (&mut self.batch, *idx))?;
+                }
+                ColumnSource::Constant(constant) => {
+                    output.arrays_mut()[out_idx].clone_constant_from(
+                        constant,
+                        0,
+                        num_rows,
+                        &mut NopCache,
+                    )?;
+                }
+            }
+        }
+
+        output.set_num_rows(num_rows)
+    }
+}
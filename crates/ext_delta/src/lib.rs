@@ -1,4 +1,10 @@
+pub mod extension;
+pub mod functions;
 pub mod protocol;
+pub mod table;
+
+mod checkpoint;
+mod pruning;
 
 // TODO: How do we want to handle catalogs that provide data sources? Do we want
 // to have "Glue" and "Unity" data sources that are separate from the base
@@ -4,6 +4,7 @@ use glaredb_error::{Result, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use super::schema::StructType;
+use super::stats::FileStatistics;
 
 /// Action for modifying a table.
 ///
@@ -14,7 +15,7 @@ pub enum Action {
     #[serde(rename = "metaData")]
     ChangeMetadata(ActionChangeMetadata),
 
-    // TODO: stats, tags, deletionVector, baseRowId, ...
+    // TODO: tags, deletionVector, baseRowId, ...
     #[serde(rename = "add")]
     AddFile(ActionAddFile),
 
@@ -46,6 +47,8 @@ pub struct ActionChangeMetadata {
     pub description: Option<String>,
     pub format: FormatSpec,
     pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
 }
 
 impl ActionChangeMetadata {
@@ -62,10 +65,28 @@ impl ActionChangeMetadata {
 #[serde(rename_all = "camelCase")]
 pub struct ActionAddFile {
     pub path: String,
-    pub partition_values: HashMap<String, String>,
+    /// Partition values for the file, keyed by column name.
+    ///
+    /// A missing value indicates the value for the partition is NULL.
+    pub partition_values: HashMap<String, Option<String>>,
     pub size: u64,
     pub modification_time: u64,
     pub data_change: bool,
+    /// Json encoded statistics for the file.
+    ///
+    /// See [`FileStatistics`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<String>,
+}
+
+impl ActionAddFile {
+    /// Deserializes the statistics for this file if present.
+    pub fn deserialize_stats(&self) -> Result<Option<FileStatistics>> {
+        self.stats
+            .as_deref()
+            .map(|stats| serde_json::from_str(stats).context("failed to deserialize file stats"))
+            .transpose()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -80,7 +101,7 @@ pub struct ActionRemoveFile {
 #[serde(rename_all = "camelCase")]
 pub struct ActionAddCdcFile {
     pub path: String,
-    pub partition_values: HashMap<String, String>,
+    pub partition_values: HashMap<String, Option<String>>,
     pub size: u64,
     pub data_change: bool,
     pub tags: Option<HashMap<String, String>>,
@@ -110,6 +131,40 @@ pub struct FormatSpec {
     pub provider: String,
 }
 
+/// Actions that we know how to deserialize.
+const KNOWN_ACTIONS: &[&str] = &[
+    "metaData",
+    "add",
+    "remove",
+    "cdc",
+    "txn",
+    "protocol",
+    "commitInfo",
+];
+
+/// Parse actions from a newline-delimited json commit file.
+///
+/// Actions we don't know about (e.g. 'domainMetadata') are skipped.
+pub fn parse_commit_actions(buf: &[u8]) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+
+    for value in serde_json::Deserializer::from_slice(buf).into_iter::<serde_json::Value>() {
+        let value = value.context("failed to parse delta commit")?;
+        let is_known = value
+            .as_object()
+            .and_then(|obj| obj.keys().next())
+            .is_some_and(|key| KNOWN_ACTIONS.contains(&key.as_str()));
+        if !is_known {
+            continue;
+        }
+
+        let action = serde_json::from_value(value).context("failed to deserialize action")?;
+        actions.push(action);
+    }
+
+    Ok(actions)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +194,7 @@ mod tests {
                 provider: "parquet".to_string(),
             },
             schema_string: "...".to_string(),
+            partition_columns: Vec::new(),
         });
 
         assert_eq!(expected, action);
@@ -162,7 +218,7 @@ mod tests {
         "#;
 
         let action: Action = serde_json::from_str(input).unwrap();
-        let partition_values = [("date".to_string(), "2017-12-10".to_string())]
+        let partition_values = [("date".to_string(), Some("2017-12-10".to_string()))]
             .into_iter()
             .collect();
         let expected = Action::AddFile(ActionAddFile {
@@ -171,6 +227,7 @@ mod tests {
             size: 841454,
             modification_time: 1512909768000,
             data_change: true,
+            stats: Some("{\"numRecords\":1,\"minValues\":{\"val...".to_string()),
         });
 
         assert_eq!(expected, action);
@@ -201,6 +258,7 @@ mod tests {
             size: 841454,
             modification_time: 1512909768000,
             data_change: true,
+            stats: Some("{\"numRecords\":1,\"minValues\":{\"val...".to_string()),
         });
 
         assert_eq!(expected, action);
@@ -317,4 +375,44 @@ mod tests {
         let action: Action = serde_json::from_str(input).unwrap();
         assert!(matches!(action, Action::CommitInfo(_)))
     }
+
+    #[test]
+    fn action_add_null_partition_value() {
+        let input = r#"
+        {
+          "add": {
+            "path": "date=__HIVE_DEFAULT_PARTITION__/part-000...c000.gz.parquet",
+            "partitionValues": {"date": null},
+            "size": 841454,
+            "modificationTime": 1512909768000,
+            "dataChange": true
+          }
+        }
+        "#;
+
+        let action: Action = serde_json::from_str(input).unwrap();
+        let expected = Action::AddFile(ActionAddFile {
+            path: "date=__HIVE_DEFAULT_PARTITION__/part-000...c000.gz.parquet".to_string(),
+            partition_values: [("date".to_string(), None)].into_iter().collect(),
+            size: 841454,
+            modification_time: 1512909768000,
+            data_change: true,
+            stats: None,
+        });
+
+        assert_eq!(expected, action);
+    }
+
+    #[test]
+    fn parse_commit_skips_unknown_actions() {
+        let input = br#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"domainMetadata":{"domain":"delta.rowTracking","configuration":"{}","removed":false}}
+{"remove":{"path":"a.parquet","deletionTimestamp":1515488792485,"dataChange":true}}
+"#;
+
+        let actions = parse_commit_actions(input).unwrap();
+        assert_eq!(2, actions.len());
+        assert!(matches!(actions[0], Action::Protocol(_)));
+        assert!(matches!(actions[1], Action::RemoveFile(_)));
+    }
 }
@@ -1,3 +1,4 @@
 pub mod action;
 pub mod schema;
 pub mod snapshot;
+pub mod stats;
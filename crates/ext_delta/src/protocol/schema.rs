@@ -1,3 +1,7 @@
+use glaredb_core::arrays::datatype::{DataType, DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
+use glaredb_core::arrays::field::{ColumnSchema, Field};
+use glaredb_core::arrays::scalar::decimal::{Decimal64Type, DecimalType};
+use glaredb_error::{DbError, Result};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -31,9 +35,8 @@ pub struct MapType {
     pub value_type: SchemaType,
 }
 
-// TODO: Timestamp timezone
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(try_from = "String", into = "String")]
 pub enum PrimitiveType {
     String,
     Long,
@@ -42,11 +45,135 @@ pub enum PrimitiveType {
     Byte,
     Float,
     Double,
-    Decimal,
+    /// Serialized as 'decimal(precision,scale)'.
+    Decimal {
+        precision: u8,
+        scale: i8,
+    },
     Boolean,
     Binary,
     Date,
+    /// Microsecond precision timestamp, adjusted to UTC.
     Timestamp,
+    /// Microsecond precision timestamp without a timezone.
+    TimestampNtz,
+}
+
+impl TryFrom<String> for PrimitiveType {
+    type Error = DbError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Ok(match value.as_str() {
+            "string" => PrimitiveType::String,
+            "long" => PrimitiveType::Long,
+            "integer" => PrimitiveType::Integer,
+            "short" => PrimitiveType::Short,
+            "byte" => PrimitiveType::Byte,
+            "float" => PrimitiveType::Float,
+            "double" => PrimitiveType::Double,
+            "boolean" => PrimitiveType::Boolean,
+            "binary" => PrimitiveType::Binary,
+            "date" => PrimitiveType::Date,
+            "timestamp" => PrimitiveType::Timestamp,
+            "timestamp_ntz" => PrimitiveType::TimestampNtz,
+            other => {
+                let (precision, scale) = other
+                    .strip_prefix("decimal(")
+                    .and_then(|s| s.strip_suffix(')'))
+                    .and_then(|s| s.split_once(','))
+                    .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)))
+                    .ok_or_else(|| {
+                        DbError::new(format!("Unknown delta primitive type: '{other}'"))
+                    })?;
+                PrimitiveType::Decimal { precision, scale }
+            }
+        })
+    }
+}
+
+impl From<PrimitiveType> for String {
+    fn from(value: PrimitiveType) -> Self {
+        match value {
+            PrimitiveType::String => "string".to_string(),
+            PrimitiveType::Long => "long".to_string(),
+            PrimitiveType::Integer => "integer".to_string(),
+            PrimitiveType::Short => "short".to_string(),
+            PrimitiveType::Byte => "byte".to_string(),
+            PrimitiveType::Float => "float".to_string(),
+            PrimitiveType::Double => "double".to_string(),
+            PrimitiveType::Decimal { precision, scale } => format!("decimal({precision},{scale})"),
+            PrimitiveType::Boolean => "boolean".to_string(),
+            PrimitiveType::Binary => "binary".to_string(),
+            PrimitiveType::Date => "date".to_string(),
+            PrimitiveType::Timestamp => "timestamp".to_string(),
+            PrimitiveType::TimestampNtz => "timestamp_ntz".to_string(),
+        }
+    }
+}
+
+impl PrimitiveType {
+    pub fn to_datatype(&self) -> DataType {
+        match self {
+            PrimitiveType::String => DataType::utf8(),
+            PrimitiveType::Long => DataType::int64(),
+            PrimitiveType::Integer => DataType::int32(),
+            PrimitiveType::Short => DataType::int16(),
+            PrimitiveType::Byte => DataType::int8(),
+            PrimitiveType::Float => DataType::float32(),
+            PrimitiveType::Double => DataType::float64(),
+            PrimitiveType::Decimal { precision, scale } => {
+                let meta = DecimalTypeMeta::new(*precision, *scale);
+                if *precision <= Decimal64Type::MAX_PRECISION {
+                    DataType::decimal64(meta)
+                } else {
+                    DataType::decimal128(meta)
+                }
+            }
+            PrimitiveType::Boolean => DataType::boolean(),
+            PrimitiveType::Binary => DataType::binary(),
+            PrimitiveType::Date => DataType::date32(),
+            // TODO: Timezone
+            PrimitiveType::Timestamp | PrimitiveType::TimestampNtz => {
+                DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
+            }
+        }
+    }
+}
+
+impl SchemaType {
+    /// Convert this type to a datatype.
+    pub fn to_datatype(&self) -> Result<DataType> {
+        match self {
+            SchemaType::Primitive(prim) => Ok(prim.to_datatype()),
+            SchemaType::Struct(s) => {
+                let fields = s
+                    .fields
+                    .iter()
+                    .map(|f| f.to_field())
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::struct_type(fields))
+            }
+            SchemaType::Array(a) => Ok(DataType::list(a.element_type.to_datatype()?)),
+            SchemaType::Map(m) => Ok(DataType::map(
+                m.key_type.to_datatype()?,
+                m.value_type.to_datatype()?,
+            )),
+        }
+    }
+}
+
+impl StructType {
+    /// Convert the struct to a column schema.
+    ///
+    /// Used for converting the table schema.
+    pub fn to_column_schema(&self) -> Result<ColumnSchema> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.to_field())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ColumnSchema::new(fields))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -57,6 +184,16 @@ pub struct StructField {
     pub nullable: bool,
 }
 
+impl StructField {
+    pub fn to_field(&self) -> Result<Field> {
+        Ok(Field::new(
+            self.name.clone(),
+            self.typ.to_datatype()?,
+            self.nullable,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,5 +274,62 @@ mod tests {
         };
 
         assert_eq!(expected, field);
+        assert_eq!(
+            DataType::map(DataType::utf8(), DataType::utf8()),
+            field.to_field().unwrap().datatype
+        );
+    }
+
+    #[test]
+    fn primitive_decimal() {
+        let typ: PrimitiveType = serde_json::from_str(r#""decimal(10, 2)""#).unwrap();
+        assert_eq!(
+            PrimitiveType::Decimal {
+                precision: 10,
+                scale: 2
+            },
+            typ
+        );
+        assert_eq!(
+            DataType::decimal64(DecimalTypeMeta::new(10, 2)),
+            typ.to_datatype()
+        );
+
+        let typ: PrimitiveType = serde_json::from_str(r#""decimal(38,4)""#).unwrap();
+        assert_eq!(
+            DataType::decimal128(DecimalTypeMeta::new(38, 4)),
+            typ.to_datatype()
+        );
+
+        let out = serde_json::to_string(&typ).unwrap();
+        assert_eq!(r#""decimal(38,4)""#, out);
+    }
+
+    #[test]
+    fn primitive_unknown() {
+        serde_json::from_str::<PrimitiveType>(r#""interval""#).unwrap_err();
+        serde_json::from_str::<PrimitiveType>(r#""decimal(10)""#).unwrap_err();
+    }
+
+    #[test]
+    fn struct_to_column_schema() {
+        let input = r#"
+        {
+          "type" : "struct",
+          "fields" : [
+            { "name" : "a", "type" : "long", "nullable" : false, "metadata" : { } },
+            { "name" : "b", "type" : "string", "nullable" : true, "metadata" : { } }
+          ]
+        }
+        "#;
+
+        let s: StructType = serde_json::from_str(input).unwrap();
+        let schema = s.to_column_schema().unwrap();
+        let expected = ColumnSchema::new([
+            Field::new("a", DataType::int64(), false),
+            Field::new("b", DataType::utf8(), true),
+        ]);
+
+        assert_eq!(expected, schema);
     }
 }
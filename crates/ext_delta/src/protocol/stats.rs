@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Per-file statistics stored as a json string in add actions.
+///
+/// Values are keyed by physical column name. Nested columns are stored as
+/// nested objects.
+///
+/// See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#per-file-statistics>
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStatistics {
+    /// Number of records in the file.
+    pub num_records: Option<u64>,
+    /// Minimum value for each column.
+    #[serde(default)]
+    pub min_values: HashMap<String, serde_json::Value>,
+    /// Maximum value for each column.
+    #[serde(default)]
+    pub max_values: HashMap<String, serde_json::Value>,
+    /// Number of NULL values for each column.
+    #[serde(default)]
+    pub null_count: HashMap<String, serde_json::Value>,
+}
+
+impl FileStatistics {
+    /// Returns if we know every value for a top-level column is NULL.
+    pub fn is_all_null(&self, column: &str) -> bool {
+        let null_count = self.null_count.get(column).and_then(|v| v.as_u64());
+        match (self.num_records, null_count) {
+            (Some(num_records), Some(null_count)) => num_records == null_count,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_stats() {
+        let input = r#"
+        {
+          "numRecords": 3,
+          "minValues": {"a": 1, "b": "x", "c": {"d": 2.5}},
+          "maxValues": {"a": 8, "b": "z", "c": {"d": 4.0}},
+          "nullCount": {"a": 0, "b": 3, "c": {"d": 0}}
+        }
+        "#;
+
+        let stats: FileStatistics = serde_json::from_str(input).unwrap();
+        assert_eq!(Some(3), stats.num_records);
+        assert_eq!(Some(&serde_json::json!(1)), stats.min_values.get("a"));
+        assert_eq!(Some(&serde_json::json!("z")), stats.max_values.get("b"));
+
+        assert!(!stats.is_all_null("a"));
+        assert!(stats.is_all_null("b"));
+        assert!(!stats.is_all_null("c"));
+        assert!(!stats.is_all_null("missing"));
+    }
+
+    #[test]
+    fn deserialize_stats_only_num_records() {
+        let stats: FileStatistics = serde_json::from_str(r#"{"numRecords": 10}"#).unwrap();
+        let expected = FileStatistics {
+            num_records: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(expected, stats);
+    }
+}
@@ -0,0 +1,257 @@
+use std::cmp::Ordering;
+
+use glaredb_core::arrays::datatype::{DataType, DataTypeId};
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_core::arrays::scalar::{BorrowedScalarValue, ScalarValue};
+use glaredb_core::storage::projections::ProjectedColumn;
+use glaredb_core::storage::scan_filter::{PhysicalScanFilter, PhysicalScanFilterType};
+
+use crate::table::{ScanFile, parse_partition_value};
+
+/// Returns the files that may contain rows matching the filters.
+///
+/// Files are skipped if a filter can't be satisfied by the file's partition
+/// values or by the min/max values in its statistics.
+pub fn prune_files(
+    schema: &ColumnSchema,
+    files: &[ScanFile],
+    filters: &[PhysicalScanFilter],
+) -> Vec<ScanFile> {
+    files
+        .iter()
+        .filter(|file| !filters.iter().any(|filter| can_skip(schema, file, filter)))
+        .cloned()
+        .collect()
+}
+
+/// Check if we know that no row in the file can satisfy the filter.
+fn can_skip(schema: &ColumnSchema, file: &ScanFile, filter: &PhysicalScanFilter) -> bool {
+    let constant = match &filter.filter_type {
+        PhysicalScanFilterType::ConstantEq(constant) => constant,
+        PhysicalScanFilterType::Unknown => return false,
+    };
+    let col_idx = match filter.columns.as_slice() {
+        [ProjectedColumn::Data(idx)] => *idx,
+        _ => return false,
+    };
+    if matches!(constant, BorrowedScalarValue::Null) {
+        // Comparing to NULL, let the filter handle it.
+        return false;
+    }
+
+    let partition_value = file
+        .partition_values
+        .iter()
+        .find_map(|(idx, value)| (*idx == col_idx).then_some(value));
+    if let Some(value) = partition_value {
+        // NULL never equals anything.
+        if matches!(value, BorrowedScalarValue::Null) {
+            return true;
+        }
+        return matches!(
+            compare_scalars(value, constant),
+            Some(Ordering::Less | Ordering::Greater)
+        );
+    }
+
+    let stats = match &file.stats {
+        Some(stats) => stats,
+        None => return false,
+    };
+    let field = &schema.fields[col_idx];
+
+    if stats.is_all_null(&field.name) {
+        return true;
+    }
+
+    let all_above = stats
+        .min_values
+        .get(&field.name)
+        .and_then(|v| stats_value(v, &field.datatype))
+        .is_some_and(|min| compare_scalars(&min, constant) == Some(Ordering::Greater));
+    if all_above {
+        return true;
+    }
+
+    // Writers may truncate string max values, so they can't be used as an
+    // upper bound.
+    if field.datatype.id() != DataTypeId::Utf8 {
+        let all_below = stats
+            .max_values
+            .get(&field.name)
+            .and_then(|v| stats_value(v, &field.datatype))
+            .is_some_and(|max| compare_scalars(&max, constant) == Some(Ordering::Less));
+        if all_below {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Convert a json min/max value to a scalar of the given type.
+///
+/// Returns None if the value can't be converted (e.g. nested columns or
+/// timestamps, whose stats are truncated to milliseconds).
+fn stats_value(value: &serde_json::Value, datatype: &DataType) -> Option<ScalarValue> {
+    let value = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        _ => return None,
+    };
+    parse_partition_value(Some(&value), datatype).ok()
+}
+
+/// Compare two scalars of compatible types.
+///
+/// Returns None if the values can't be compared.
+fn compare_scalars(a: &ScalarValue, b: &ScalarValue) -> Option<Ordering> {
+    use BorrowedScalarValue as S;
+
+    match (a, b) {
+        (S::Boolean(a), S::Boolean(b)) => Some(a.cmp(b)),
+        (S::Utf8(a), S::Utf8(b)) => Some(a.cmp(b)),
+        (S::Binary(a), S::Binary(b)) => Some(a.cmp(b)),
+        (S::Date32(a), S::Date32(b)) => Some(a.cmp(b)),
+        (S::Float32(a), S::Float32(b)) => a.partial_cmp(b),
+        (S::Float64(a), S::Float64(b)) => a.partial_cmp(b),
+        (S::Decimal64(a), S::Decimal64(b)) if a.scale == b.scale => Some(a.value.cmp(&b.value)),
+        (S::Decimal128(a), S::Decimal128(b)) if a.scale == b.scale => Some(a.value.cmp(&b.value)),
+        (a, b) => Some(as_i64(a)?.cmp(&as_i64(b)?)),
+    }
+}
+
+fn as_i64(v: &ScalarValue) -> Option<i64> {
+    use BorrowedScalarValue as S;
+
+    match v {
+        S::Int8(v) => Some(*v as i64),
+        S::Int16(v) => Some(*v as i64),
+        S::Int32(v) => Some(*v as i64),
+        S::Int64(v) => Some(*v),
+        S::UInt8(v) => Some(*v as i64),
+        S::UInt16(v) => Some(*v as i64),
+        S::UInt32(v) => Some(*v as i64),
+        S::UInt64(v) => (*v).try_into().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glaredb_core::arrays::field::Field;
+    use glaredb_core::expr::physical::PhysicalScalarExpression;
+    use glaredb_core::expr::physical::literal_expr::PhysicalLiteralExpr;
+
+    use super::*;
+    use crate::protocol::stats::FileStatistics;
+
+    fn schema() -> ColumnSchema {
+        ColumnSchema::new([
+            Field::new("id", DataType::int64(), true),
+            Field::new("name", DataType::utf8(), true),
+            Field::new("part", DataType::utf8(), true),
+        ])
+    }
+
+    fn eq_filter(col: usize, constant: impl Into<ScalarValue>) -> PhysicalScanFilter {
+        PhysicalScanFilter {
+            columns: vec![ProjectedColumn::Data(col)],
+            // Not used for pruning.
+            filter: PhysicalScalarExpression::Literal(PhysicalLiteralExpr::new(true)),
+            filter_type: PhysicalScanFilterType::ConstantEq(constant.into()),
+        }
+    }
+
+    fn file(path: &str, part: Option<&str>, stats: &str) -> ScanFile {
+        let part = match part {
+            Some(part) => BorrowedScalarValue::Utf8(part.to_string().into()),
+            None => BorrowedScalarValue::Null,
+        };
+        ScanFile {
+            path: path.to_string(),
+            partition_values: vec![(2, part)],
+            stats: Some(serde_json::from_str::<FileStatistics>(stats).unwrap()),
+        }
+    }
+
+    fn pruned_paths(files: &[ScanFile], filters: &[PhysicalScanFilter]) -> Vec<String> {
+        prune_files(&schema(), files, filters)
+            .into_iter()
+            .map(|f| f.path)
+            .collect()
+    }
+
+    fn files() -> Vec<ScanFile> {
+        vec![
+            file(
+                "a1",
+                Some("a"),
+                r#"{"numRecords":2,"minValues":{"id":1,"name":"bob"},"maxValues":{"id":10,"name":"sam"},"nullCount":{"id":0,"name":0}}"#,
+            ),
+            file(
+                "a2",
+                Some("a"),
+                r#"{"numRecords":2,"minValues":{"id":11,"name":"alice"},"maxValues":{"id":20,"name":"bob"},"nullCount":{"id":0,"name":0}}"#,
+            ),
+            file(
+                "b1",
+                Some("b"),
+                r#"{"numRecords":2,"minValues":{"id":5},"maxValues":{"id":15},"nullCount":{"id":0,"name":2}}"#,
+            ),
+            file("n1", None, r#"{"numRecords":2}"#),
+        ]
+    }
+
+    #[test]
+    fn no_filters() {
+        assert_eq!(vec!["a1", "a2", "b1", "n1"], pruned_paths(&files(), &[]));
+    }
+
+    #[test]
+    fn prune_partition_value() {
+        let filters = [eq_filter(2, "a")];
+        assert_eq!(vec!["a1", "a2"], pruned_paths(&files(), &filters));
+
+        let filters = [eq_filter(2, "c")];
+        assert!(pruned_paths(&files(), &filters).is_empty());
+    }
+
+    #[test]
+    fn prune_min_max() {
+        let filters = [eq_filter(0, 12_i64)];
+        assert_eq!(vec!["a2", "b1", "n1"], pruned_paths(&files(), &filters));
+
+        // Constant with a different integer width.
+        let filters = [eq_filter(0, 3_i32)];
+        assert_eq!(vec!["a1", "n1"], pruned_paths(&files(), &filters));
+    }
+
+    #[test]
+    fn prune_string_min_only() {
+        // 'zed' is above the max for a1 and a2, but string max values aren't
+        // used.
+        let filters = [eq_filter(1, "zed")];
+        assert_eq!(vec!["a1", "a2", "n1"], pruned_paths(&files(), &filters));
+
+        // 'bo' is below the min for a1.
+        let filters = [eq_filter(1, "bo")];
+        assert_eq!(vec!["a2", "n1"], pruned_paths(&files(), &filters));
+    }
+
+    #[test]
+    fn prune_multiple_filters() {
+        let filters = [eq_filter(2, "a"), eq_filter(0, 5_i64)];
+        assert_eq!(vec!["a1"], pruned_paths(&files(), &filters));
+    }
+
+    #[test]
+    fn no_prune_null_constant() {
+        let filters = [eq_filter(0, BorrowedScalarValue::Null)];
+        assert_eq!(
+            vec!["a1", "a2", "b1", "n1"],
+            pruned_paths(&files(), &filters)
+        );
+    }
+}
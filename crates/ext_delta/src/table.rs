@@ -0,0 +1,551 @@
+use std::collections::BTreeMap;
+
+use futures::future;
+use glaredb_core::arrays::datatype::{DataType, DataTypeId};
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_core::arrays::scalar::decimal::{Decimal64Scalar, Decimal128Scalar};
+use glaredb_core::arrays::scalar::timestamp::TimestampScalar;
+use glaredb_core::arrays::scalar::timezone::{naive_to_timestamp, parse_timestamp};
+use glaredb_core::arrays::scalar::{BorrowedScalarValue, ScalarValue};
+use glaredb_core::functions::cast::parse::{
+    BoolParser,
+    Date32Parser,
+    Decimal64Parser,
+    Decimal128Parser,
+    Float32Parser,
+    Float64Parser,
+    Int8Parser,
+    Int16Parser,
+    Int32Parser,
+    Int64Parser,
+    Parser,
+};
+use glaredb_core::runtime::filesystem::{FileSystemWithState, OpenFlags};
+use glaredb_error::{DbError, Result, ResultExt, not_implemented};
+use tracing::debug;
+
+use crate::checkpoint::read_checkpoint_actions;
+use crate::protocol::action::{Action, ActionAddFile, parse_commit_actions};
+use crate::protocol::snapshot::Snapshot;
+use crate::protocol::stats::FileStatistics;
+
+/// Directory containing the delta log, relative to the table root.
+const DELTA_LOG_DIR: &str = "_delta_log";
+
+/// A delta table at some version.
+#[derive(Debug)]
+pub struct Table {
+    /// Root path of the table, without a trailing slash.
+    root: String,
+    /// Version of the table this snapshot is for.
+    version: u64,
+    snapshot: Snapshot,
+}
+
+/// A data file to scan.
+#[derive(Debug, Clone)]
+pub struct ScanFile {
+    /// Full path to the file.
+    pub path: String,
+    /// Partition values for this file with the index of the column in the
+    /// table schema.
+    ///
+    /// Columns not in this list are read from the file.
+    pub partition_values: Vec<(usize, ScalarValue)>,
+    /// Statistics for the file if the writer provided them.
+    pub stats: Option<FileStatistics>,
+}
+
+impl Table {
+    /// Load the latest version of a table by replaying the log.
+    pub async fn load(fs: &FileSystemWithState, root: &str) -> Result<Self> {
+        Self::load_version(fs, root, None).await
+    }
+
+    /// Load a table at a specific version, or the latest version if `None`.
+    ///
+    /// The most recent complete checkpoint at or before the version is used as
+    /// the starting point if there is one, with all commits after it up to the
+    /// version applied on top.
+    pub async fn load_version(
+        fs: &FileSystemWithState,
+        root: &str,
+        version: Option<u64>,
+    ) -> Result<Self> {
+        let root = root.trim_end_matches('/').to_string();
+        let log = list_log(fs, &root)
+            .await
+            .context_fn(|| format!("Failed to read delta log for table at '{root}'"))?;
+
+        Self::load_from_listing(fs, root, &log, version).await
+    }
+
+    /// Load the version of the table that was current as of the given
+    /// timestamp (milliseconds since epoch).
+    ///
+    /// This is the latest version whose commit timestamp is at or before the
+    /// provided timestamp.
+    pub async fn load_at_timestamp(
+        fs: &FileSystemWithState,
+        root: &str,
+        timestamp_ms: i64,
+    ) -> Result<Self> {
+        let root = root.trim_end_matches('/').to_string();
+        let log = list_log(fs, &root)
+            .await
+            .context_fn(|| format!("Failed to read delta log for table at '{root}'"))?;
+
+        // Commit timestamps are monotonically increasing with the version, so
+        // binary search to avoid reading every commit.
+        let versions: Vec<_> = log.commits.keys().copied().collect();
+        let (mut lo, mut hi) = (0, versions.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let commit_ts = commit_timestamp(fs, &log, versions[mid]).await?;
+            if commit_ts <= timestamp_ms {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        if lo == 0 {
+            return Err(DbError::new(format!(
+                "No version of delta table at '{root}' exists at or before timestamp {timestamp_ms}"
+            )));
+        }
+
+        Self::load_from_listing(fs, root, &log, Some(versions[lo - 1])).await
+    }
+
+    async fn load_from_listing(
+        fs: &FileSystemWithState,
+        root: String,
+        log: &LogListing,
+        version: Option<u64>,
+    ) -> Result<Self> {
+        let target = match version {
+            Some(version) => {
+                if !log.commits.contains_key(&version) && !log.checkpoints.contains_key(&version) {
+                    return Err(DbError::new(format!(
+                        "Version {version} does not exist for delta table at '{root}'"
+                    )));
+                }
+                version
+            }
+            None => u64::MAX,
+        };
+
+        let checkpoint = log.latest_checkpoint(target);
+        let start_version = match checkpoint {
+            Some((version, _)) => version + 1,
+            None => 0,
+        };
+
+        let mut actions = Vec::new();
+        if let Some((version, parts)) = checkpoint {
+            debug!(%version, "reading delta checkpoint");
+            for part in parts {
+                actions.extend(read_checkpoint_actions(fs, part).await?);
+            }
+        }
+
+        // Nothing to replay if the target is the checkpoint version.
+        let commits = if start_version <= target {
+            log.commits.range(start_version..=target)
+        } else {
+            Default::default()
+        };
+
+        let mut version = start_version.checked_sub(1);
+        for (&commit_version, path) in commits {
+            let expected = version.map(|v| v + 1).unwrap_or(0);
+            if commit_version != expected {
+                return Err(DbError::new(format!(
+                    "Delta log is missing commit for version {expected}"
+                )));
+            }
+
+            let buf = read_file(fs, path).await?;
+            actions.extend(parse_commit_actions(&buf)?);
+            version = Some(commit_version);
+        }
+
+        let version = version
+            .ok_or_else(|| DbError::new(format!("No delta log found for table at '{root}'")))?;
+
+        let snapshot = Snapshot::try_new_from_actions(actions)?;
+
+        Ok(Table {
+            root,
+            version,
+            snapshot,
+        })
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// Get the table schema.
+    pub fn column_schema(&self) -> Result<ColumnSchema> {
+        self.snapshot.schema()?.to_column_schema()
+    }
+
+    /// Get all files in the current snapshot to scan.
+    pub fn scan_files(&self, schema: &ColumnSchema) -> Result<Vec<ScanFile>> {
+        let mut files = self
+            .snapshot
+            .add
+            .values()
+            .map(|add| self.scan_file(schema, add))
+            .collect::<Result<Vec<_>>>()?;
+        // Deterministic ordering for scans.
+        files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(files)
+    }
+
+    fn scan_file(&self, schema: &ColumnSchema, add: &ActionAddFile) -> Result<ScanFile> {
+        let partition_values = add
+            .partition_values
+            .iter()
+            .filter_map(|(name, value)| {
+                let idx = schema.fields.iter().position(|f| &f.name == name)?;
+                let datatype = &schema.fields[idx].datatype;
+                Some(parse_partition_value(value.as_deref(), datatype).map(|v| (idx, v)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Stats are only used for skipping files, a file with stats we can't
+        // read just can't be skipped.
+        let stats = add.deserialize_stats().unwrap_or_else(|e| {
+            debug!(path = %add.path, %e, "failed to deserialize delta file stats");
+            None
+        });
+
+        Ok(ScanFile {
+            path: resolve_path(&self.root, &add.path),
+            partition_values,
+            stats,
+        })
+    }
+}
+
+/// Files in the delta log keyed by version.
+#[derive(Debug, Default)]
+struct LogListing {
+    /// Json commit files.
+    commits: BTreeMap<u64, String>,
+    /// Checkpoint files, with the number of parts expected for the checkpoint.
+    checkpoints: BTreeMap<u64, (usize, Vec<String>)>,
+}
+
+impl LogListing {
+    /// Returns the latest checkpoint at or before `version` that has all of
+    /// its parts.
+    fn latest_checkpoint(&self, version: u64) -> Option<(u64, &[String])> {
+        self.checkpoints
+            .range(..=version)
+            .rev()
+            .find(|(_, (num_parts, parts))| *num_parts == parts.len())
+            .map(|(&version, (_, parts))| (version, parts.as_slice()))
+    }
+}
+
+/// List all commits and checkpoints in the log directory.
+async fn list_log(fs: &FileSystemWithState, root: &str) -> Result<LogListing> {
+    let mut provider = fs.read_glob(&format!("{root}/{DELTA_LOG_DIR}/*"))?;
+    let mut paths = Vec::new();
+    loop {
+        let n = future::poll_fn(|cx| provider.poll_next(cx, &mut paths)).await?;
+        if n == 0 {
+            break;
+        }
+    }
+
+    let mut listing = LogListing::default();
+    for path in paths {
+        let name = path.rsplit('/').next().unwrap_or(&path);
+        let (version, rest) = match name.split_once('.') {
+            Some((version, rest)) => match version.parse::<u64>() {
+                Ok(version) => (version, rest),
+                Err(_) => continue,
+            },
+            None => continue,
+        };
+
+        match rest.split('.').collect::<Vec<_>>().as_slice() {
+            ["json"] => {
+                listing.commits.insert(version, path);
+            }
+            ["checkpoint", "parquet"] => {
+                listing.checkpoints.insert(version, (1, vec![path]));
+            }
+            ["checkpoint", _part, num_parts, "parquet"] => {
+                let num_parts = num_parts.parse().map_err(|_| {
+                    DbError::new(format!("Invalid multi-part checkpoint file name: {name}"))
+                })?;
+                let entry = listing
+                    .checkpoints
+                    .entry(version)
+                    .or_insert_with(|| (num_parts, Vec::new()));
+                entry.1.push(path);
+                entry.1.sort();
+            }
+            _ => (), // CRCs, temp files, etc.
+        }
+    }
+
+    Ok(listing)
+}
+
+/// Get the timestamp (milliseconds since epoch) for a commit.
+///
+/// Uses the in-commit timestamp if the writer provided one, otherwise the
+/// 'timestamp' field from the commit info.
+async fn commit_timestamp(fs: &FileSystemWithState, log: &LogListing, version: u64) -> Result<i64> {
+    let path = log.commits.get(&version).ok_or_else(|| {
+        DbError::new(format!("Delta log is missing commit for version {version}"))
+    })?;
+    let buf = read_file(fs, path).await?;
+
+    parse_commit_actions(&buf)?
+        .iter()
+        .find_map(|action| match action {
+            Action::CommitInfo(info) => info
+                .get("inCommitTimestamp")
+                .or_else(|| info.get("timestamp"))
+                .and_then(|ts| ts.as_i64()),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            DbError::new(format!(
+                "Delta commit for version {version} is missing a commit timestamp"
+            ))
+        })
+}
+
+/// Read an entire file into memory.
+async fn read_file(fs: &FileSystemWithState, path: &str) -> Result<Vec<u8>> {
+    let mut file = fs.open(OpenFlags::READ, path).await?;
+    let mut buf = vec![0; file.call_size()];
+    file.call_read_exact(&mut buf).await?;
+    Ok(buf)
+}
+
+/// Resolve the path for a data file.
+///
+/// Paths in add actions are URIs that are either relative to the table root,
+/// or absolute.
+fn resolve_path(root: &str, path: &str) -> String {
+    let decoded = percent_decode(path);
+    if let Some(local) = decoded.strip_prefix("file://") {
+        return local.to_string();
+    }
+    if decoded.contains("://") {
+        return decoded;
+    }
+    format!("{root}/{decoded}")
+}
+
+/// Decode '%XX' sequences in a string.
+///
+/// Invalid sequences are left as-is.
+pub(crate) fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx] == b'%' && idx + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[idx + 1..idx + 3]).ok();
+            if let Some(b) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                out.push(b);
+                idx += 3;
+                continue;
+            }
+        }
+        out.push(bytes[idx]);
+        idx += 1;
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Parse a serialized partition value.
+///
+/// See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#partition-value-serialization>
+pub(crate) fn parse_partition_value(
+    value: Option<&str>,
+    datatype: &DataType,
+) -> Result<ScalarValue> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(BorrowedScalarValue::Null),
+    };
+    // Empty strings are NULL for every type other than strings.
+    if value.is_empty() && datatype.id() != DataTypeId::Utf8 {
+        return Ok(BorrowedScalarValue::Null);
+    }
+
+    fn parse_err(value: &str, datatype: &DataType) -> DbError {
+        DbError::new(format!(
+            "Failed to parse partition value '{value}' as {datatype}"
+        ))
+    }
+
+    fn parse<P: Parser>(mut parser: P, value: &str, datatype: &DataType) -> Result<P::Type> {
+        parser
+            .parse(value)
+            .ok_or_else(|| parse_err(value, datatype))
+    }
+
+    Ok(match datatype.id() {
+        DataTypeId::Utf8 => BorrowedScalarValue::Utf8(value.to_string().into()),
+        DataTypeId::Binary => BorrowedScalarValue::Binary(value.as_bytes().to_vec().into()),
+        DataTypeId::Boolean => BorrowedScalarValue::Boolean(parse(BoolParser, value, datatype)?),
+        DataTypeId::Int8 => BorrowedScalarValue::Int8(parse(Int8Parser::new(), value, datatype)?),
+        DataTypeId::Int16 => {
+            BorrowedScalarValue::Int16(parse(Int16Parser::new(), value, datatype)?)
+        }
+        DataTypeId::Int32 => {
+            BorrowedScalarValue::Int32(parse(Int32Parser::new(), value, datatype)?)
+        }
+        DataTypeId::Int64 => {
+            BorrowedScalarValue::Int64(parse(Int64Parser::new(), value, datatype)?)
+        }
+        DataTypeId::Float32 => {
+            BorrowedScalarValue::Float32(parse(Float32Parser::new(), value, datatype)?)
+        }
+        DataTypeId::Float64 => {
+            BorrowedScalarValue::Float64(parse(Float64Parser::new(), value, datatype)?)
+        }
+        DataTypeId::Date32 => BorrowedScalarValue::Date32(parse(Date32Parser, value, datatype)?),
+        DataTypeId::Decimal64 => {
+            let m = datatype.try_get_decimal_type_meta()?;
+            let parser = Decimal64Parser::new(m.precision, m.scale);
+            BorrowedScalarValue::Decimal64(Decimal64Scalar {
+                precision: m.precision,
+                scale: m.scale,
+                value: parse(parser, value, datatype)?,
+            })
+        }
+        DataTypeId::Decimal128 => {
+            let m = datatype.try_get_decimal_type_meta()?;
+            let parser = Decimal128Parser::new(m.precision, m.scale);
+            BorrowedScalarValue::Decimal128(Decimal128Scalar {
+                precision: m.precision,
+                scale: m.scale,
+                value: parse(parser, value, datatype)?,
+            })
+        }
+        DataTypeId::Timestamp => {
+            // Written as '2024-03-10 02:30:00.123456', or ISO 8601 (possibly
+            // with an offset) by newer writers. Values for UTC adjusted
+            // timestamps are in UTC.
+            let m = datatype.try_get_timestamp_type_meta()?;
+            let (datetime, tz) =
+                parse_timestamp(value).ok_or_else(|| parse_err(value, datatype))?;
+            let datetime = match tz {
+                Some(tz) => tz.local_to_utc(datetime),
+                None => datetime,
+            };
+            BorrowedScalarValue::Timestamp(TimestampScalar {
+                unit: m.unit,
+                value: naive_to_timestamp(m.unit, datetime)
+                    .ok_or_else(|| parse_err(value, datatype))?,
+                tz: m.tz.clone(),
+            })
+        }
+        other => not_implemented!("Delta partition values for type {other}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use glaredb_core::arrays::datatype::{DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
+
+    use super::*;
+
+    #[test]
+    fn percent_decode_paths() {
+        assert_eq!("a/b.parquet", percent_decode("a/b.parquet"));
+        assert_eq!("a b/c.parquet", percent_decode("a%20b/c.parquet"));
+        assert_eq!("x%3Ay", percent_decode("x%253Ay"));
+        // Invalid/truncated sequences.
+        assert_eq!("100%", percent_decode("100%"));
+        assert_eq!("%zz", percent_decode("%zz"));
+    }
+
+    #[test]
+    fn resolve_data_file_paths() {
+        assert_eq!(
+            "s3://bucket/table/date=2024-01-01/part-0.parquet",
+            resolve_path("s3://bucket/table", "date=2024-01-01/part-0.parquet")
+        );
+        assert_eq!(
+            "/data/other/part-0.parquet",
+            resolve_path("s3://bucket/table", "file:///data/other/part-0.parquet")
+        );
+    }
+
+    #[test]
+    fn parse_partition_values() {
+        assert_eq!(
+            BorrowedScalarValue::Null,
+            parse_partition_value(None, &DataType::int32()).unwrap()
+        );
+        assert_eq!(
+            BorrowedScalarValue::Int32(4),
+            parse_partition_value(Some("4"), &DataType::int32()).unwrap()
+        );
+        assert_eq!(
+            BorrowedScalarValue::Decimal64(Decimal64Scalar {
+                precision: 6,
+                scale: 2,
+                value: 1234,
+            }),
+            parse_partition_value(
+                Some("12.34"),
+                &DataType::decimal64(DecimalTypeMeta::new(6, 2))
+            )
+            .unwrap()
+        );
+        parse_partition_value(Some("abc"), &DataType::int32()).unwrap_err();
+
+        // Empty strings are only NULL for non-string types.
+        assert_eq!(
+            BorrowedScalarValue::Null,
+            parse_partition_value(Some(""), &DataType::int32()).unwrap()
+        );
+        assert_eq!(
+            BorrowedScalarValue::Utf8("".into()),
+            parse_partition_value(Some(""), &DataType::utf8()).unwrap()
+        );
+
+        let ts = DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+        let expected = BorrowedScalarValue::Timestamp(TimestampScalar {
+            unit: TimeUnit::Microsecond,
+            value: 1_700_000_000_123_456,
+            tz: None,
+        });
+        assert_eq!(
+            expected,
+            parse_partition_value(Some("2023-11-14 22:13:20.123456"), &ts).unwrap()
+        );
+        assert_eq!(
+            expected,
+            parse_partition_value(Some("2023-11-14T22:13:20.123456Z"), &ts).unwrap()
+        );
+        assert_eq!(
+            expected,
+            parse_partition_value(Some("2023-11-15T00:13:20.123456+02:00"), &ts).unwrap()
+        );
+        parse_partition_value(Some("2023-13-14 22:13:20"), &ts).unwrap_err();
+    }
+}
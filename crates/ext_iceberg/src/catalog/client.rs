@@ -59,7 +59,7 @@ where
     /// The catalog's 'config' endpoint will be queried to configure this
     /// client.
     pub async fn configure(client: C, base: Url, prefix: impl Into<String>) -> Result<Self> {
-        let client = CatalogClient {
+        let mut client = CatalogClient {
             endpoints: Endpoints::new(base, prefix),
             client,
             properties: HashMap::new(),
@@ -69,18 +69,58 @@ where
         struct CatalogConfig {
             overrides: HashMap<String, String>,
             defaults: HashMap<String, String>,
+            #[serde(default)]
             endpoints: Vec<String>,
         }
 
-        // TODO
-        let _conf: CatalogConfig = client
+        let conf: CatalogConfig = client
             .do_request::<(), _>(Method::GET, client.endpoints.v1_config()?, None)
             .await?;
 
+        // Server may provide the prefix to use for all other endpoints.
+        if let Some(prefix) = conf.overrides.get("prefix") {
+            client.endpoints.prefix = prefix.clone();
+        }
+
+        // Overrides take precedence over defaults.
+        client.properties = conf.defaults;
+        client.properties.extend(conf.overrides);
+
         Ok(client)
     }
 
     async fn do_request<B, R>(&self, method: Method, url: Url, body: Option<B>) -> Result<R>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        self.do_request_inner(method, url, body, false)
+            .await?
+            .ok_or_else(|| DbError::new("Iceberg request unexpectedly returned nothing"))
+    }
+
+    /// Like `do_request`, but returns None if the catalog responds with not
+    /// found.
+    async fn try_do_request<B, R>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<B>,
+    ) -> Result<Option<R>>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        self.do_request_inner(method, url, body, true).await
+    }
+
+    async fn do_request_inner<B, R>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<B>,
+        allow_not_found: bool,
+    ) -> Result<Option<R>>
     where
         B: Serialize,
         R: DeserializeOwned,
@@ -93,6 +133,10 @@ where
         // Do the request!
         let resp = self.client.do_request(request).await?;
 
+        if allow_not_found && resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
         if resp.status() != StatusCode::OK {
             // Error!
             let error_resp: ErrorModel = read_json_response(resp.into_bytes_stream())
@@ -109,7 +153,7 @@ where
         // Read the response!
         let resp = read_json_response(resp.into_bytes_stream()).await?;
 
-        Ok(resp)
+        Ok(Some(resp))
     }
 
     /// Create a namespace in the catalog.
@@ -252,6 +296,24 @@ where
         Ok(resp)
     }
 
+    /// Like `load_table`, but returns None if the namespace or table doesn't
+    /// exist.
+    pub async fn try_load_table(
+        &self,
+        namespace: impl Into<String>,
+        table: impl Into<String>,
+    ) -> Result<Option<LoadTableResponse>> {
+        let namespace = namespace.into();
+        let table = table.into();
+        self.try_do_request::<(), _>(
+            Method::GET,
+            self.endpoints
+                .v1_namespaces_namespace_tables_table(&namespace, &table)?,
+            None,
+        )
+        .await
+    }
+
     ///
     /// Reference: <https://iceberg.apache.org/spec/#update-table>
     pub async fn update_table(
@@ -352,9 +414,11 @@ impl Endpoints {
 
     fn with_path<'a>(&self, segments: impl IntoIterator<Item = &'a str>) -> Result<Url> {
         let mut url = self.base.clone();
+        // Empty segments are skipped to allow for an empty prefix.
         url.path_segments_mut()
             .map_err(|_| DbError::new("Cannot get path segments for url"))?
-            .extend(segments);
+            .pop_if_empty()
+            .extend(segments.into_iter().filter(|s| !s.is_empty()));
 
         Ok(url)
     }
@@ -466,4 +530,23 @@ mod tests {
             endpoints.v1_views_rename().unwrap().as_str()
         );
     }
+
+    #[test]
+    fn endpoints_empty_prefix() {
+        let base = Url::parse("http://localhost:8181/").unwrap();
+        let endpoints = Endpoints::new(base, "");
+
+        assert_eq!(
+            "http://localhost:8181/v1/config",
+            endpoints.v1_config().unwrap().as_str()
+        );
+
+        assert_eq!(
+            "http://localhost:8181/v1/namespaces/my_ns/tables",
+            endpoints
+                .v1_namespaces_namespace_tables("my_ns")
+                .unwrap()
+                .as_str()
+        );
+    }
 }
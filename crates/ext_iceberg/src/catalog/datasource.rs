@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use glaredb_core::arrays::scalar::ScalarValue;
+use glaredb_core::catalog::database::AccessMode;
+use glaredb_core::catalog::datasource::{
+    AttachedDatabase,
+    ConnectContext,
+    DataSource,
+    DataSourceFuture,
+};
+use glaredb_core::storage::storage_manager::StorageManager;
+use glaredb_error::{DbError, Result, ResultExt};
+use glaredb_http::client::HttpClient;
+use url::Url;
+
+use super::client::CatalogClient;
+use super::rest::IcebergRestCatalog;
+
+/// Name to register the Iceberg REST datasource under.
+pub const ICEBERG_REST_DATASOURCE_NAME: &str = "iceberg_rest";
+
+/// Datasource for attaching an Iceberg REST catalog.
+///
+/// Namespaces in the catalog are exposed as schemas, and tables as external
+/// tables read with `read_iceberg`. Tables are loaded from the catalog when
+/// they're referenced, so queries always see the table's current metadata.
+///
+/// ```sql
+/// ATTACH 'http://localhost:8181' AS lake (TYPE iceberg_rest);
+/// SELECT * FROM lake.sales.orders;
+/// ```
+#[derive(Debug)]
+pub struct IcebergRestDataSource<C: HttpClient> {
+    client: C,
+}
+
+impl<C> IcebergRestDataSource<C>
+where
+    C: HttpClient,
+{
+    pub fn new(client: C) -> Self {
+        IcebergRestDataSource { client }
+    }
+
+    async fn connect_inner(
+        &self,
+        options: &HashMap<String, ScalarValue>,
+    ) -> Result<AttachedDatabase> {
+        let path = options
+            .get("path")
+            .ok_or_else(|| DbError::new("Missing path for Iceberg REST catalog"))?
+            .try_as_str()?;
+        let base = Url::parse(path)
+            .context_fn(|| format!("Failed to parse Iceberg REST catalog url '{path}'"))?;
+        let prefix = match options.get("prefix") {
+            Some(prefix) => prefix.try_as_str()?,
+            None => "",
+        };
+
+        let client = CatalogClient::configure(self.client.clone(), base, prefix).await?;
+
+        // Tables are read directly from object storage, nothing is stored
+        // locally.
+        Ok(AttachedDatabase {
+            mode: AccessMode::ReadOnly,
+            catalog: Arc::new(IcebergRestCatalog::new(client)),
+            storage: Arc::new(StorageManager::empty()),
+        })
+    }
+}
+
+impl<C> DataSource for IcebergRestDataSource<C>
+where
+    C: HttpClient,
+{
+    fn connect<'a>(
+        &'a self,
+        _context: ConnectContext<'a>,
+        options: &'a HashMap<String, ScalarValue>,
+    ) -> DataSourceFuture<'a, Result<AttachedDatabase>> {
+        Box::pin(self.connect_inner(options))
+    }
+}
@@ -1,3 +1,4 @@
 pub mod client;
+pub mod datasource;
 pub mod rest;
 pub mod spec;
@@ -1,266 +1,186 @@
 use std::sync::Arc;
 
-use futures::{Stream, stream};
-use glaredb_core::catalog::create::{
-    CreateAggregateFunctionInfo,
-    CreateScalarFunctionInfo,
-    CreateSchemaInfo,
-    CreateTableFunctionInfo,
-    CreateTableInfo,
-    CreateViewInfo,
-};
+use futures::{TryStreamExt, stream};
+use glaredb_core::catalog::create::{CreateExternalTableInfo, CreateSchemaInfo, OnConflict};
 use glaredb_core::catalog::drop::DropInfo;
-use glaredb_core::catalog::entry::{CatalogEntry, CatalogEntryType};
-use glaredb_core::catalog::{Catalog, Schema};
-use glaredb_core::execution::operators::PlannedOperator;
-use glaredb_core::execution::planner::OperatorIdGen;
-use glaredb_core::storage::storage_manager::{StorageManager, StorageTableId};
+use glaredb_core::catalog::entry::{CatalogEntry, CatalogEntryInner, TableStorage};
+use glaredb_core::catalog::memory::MemoryCatalog;
+use glaredb_core::catalog::{Catalog, CatalogFuture, CatalogStream, Schema};
+use glaredb_core::transaction::Transaction;
 use glaredb_error::{DbError, Result};
+use glaredb_http::client::HttpClient;
+use parking_lot::RwLock;
 
-/// A catalog implementation for the Iceberg REST API.
-///
-/// This is a stub implementation that will be expanded to support the full
-/// Iceberg REST API spec.
-///
-/// Reference: <https://editor-next.swagger.io/?url=https://raw.githubusercontent.com/apache/iceberg/main/open-api/rest-catalog-open-api.yaml>
-#[derive(Debug)]
-pub struct RestCatalog {
-    #[allow(dead_code)]
-    base_url: String,
-}
+use super::client::CatalogClient;
+use super::spec::LoadTableResponse;
+use crate::functions::read_iceberg::FUNCTION_SET_READ_ICEBERG;
 
-impl RestCatalog {
-    pub fn new(base_url: String) -> Self {
-        RestCatalog { base_url }
-    }
-}
+/// Separator to use for multi-level namespaces in requests.
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
 
-/// Schema implementation for Iceberg REST catalog
+/// Catalog for an attached Iceberg REST catalog.
+///
+/// Tables are loaded from the remote catalog when referenced in a query and
+/// cached in an in-memory catalog. Listing schemas (e.g. for
+/// `list_databases`-style functions) reloads every namespace and table,
+/// replacing the cached catalog.
 ///
-/// Reference: https://iceberg.apache.org/spec/#rest-catalog-api-namespace-and-table-operations
+/// Reference: <https://editor-next.swagger.io/?url=https://raw.githubusercontent.com/apache/iceberg/main/open-api/rest-catalog-open-api.yaml>
 #[derive(Debug)]
-pub struct RestSchema {
-    schema: Arc<CatalogEntry>,
-    #[allow(dead_code)]
-    base_url: String,
-    #[allow(dead_code)]
-    namespace: String,
+pub struct IcebergRestCatalog<C: HttpClient> {
+    client: Arc<CatalogClient<C>>,
+    /// Namespaces and tables loaded from the remote catalog so far.
+    loaded: Arc<RwLock<Arc<MemoryCatalog>>>,
 }
 
-impl Catalog for RestCatalog {
-    type Schema = RestSchema;
-
-    fn create_schema(&self, _create: &CreateSchemaInfo) -> Result<Arc<Self::Schema>> {
-        //
-
-        Err(DbError::new(
-            "Iceberg REST catalog create_schema not yet implemented",
-        ))
-    }
-
-    fn get_schema(&self, _name: &str) -> Result<Option<Arc<Self::Schema>>> {
-        //
-
-        Err(DbError::new(
-            "Iceberg REST catalog get_schema not yet implemented",
-        ))
-    }
-
-    fn drop_entry(&self, _drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
-        //
-
-        Err(DbError::new(
-            "Iceberg REST catalog drop_entry not yet implemented",
-        ))
-    }
-
-    fn plan_create_view(
-        self: &Arc<Self>,
-        _id_gen: &mut OperatorIdGen,
-        _schema: &str,
-        _create: CreateViewInfo,
-    ) -> Result<PlannedOperator> {
-        Err(DbError::new(
-            "Iceberg REST catalog plan_create_view not yet implemented",
-        ))
+impl<C> IcebergRestCatalog<C>
+where
+    C: HttpClient,
+{
+    pub fn new(client: CatalogClient<C>) -> Self {
+        IcebergRestCatalog {
+            client: Arc::new(client),
+            loaded: Arc::new(RwLock::new(Arc::new(MemoryCatalog::empty()))),
+        }
     }
 
-    fn plan_create_table(
-        self: &Arc<Self>,
-        _storage: &Arc<StorageManager>,
-        _id_gen: &mut OperatorIdGen,
-        _schema: &str,
-        _create: CreateTableInfo,
-    ) -> Result<PlannedOperator> {
-        Err(DbError::new(
-            "Iceberg REST catalog plan_create_table not yet implemented",
-        ))
+    fn loaded(&self) -> Arc<MemoryCatalog> {
+        self.loaded.read().clone()
     }
 
-    fn plan_insert(
-        self: &Arc<Self>,
-        _storage: &Arc<StorageManager>,
-        _id_gen: &mut OperatorIdGen,
-        _table: Arc<CatalogEntry>,
-    ) -> Result<PlannedOperator> {
-        Err(DbError::new(
-            "Iceberg REST catalog plan_insert not yet implemented",
-        ))
-    }
+    async fn resolve_inner(&self, schema: &str, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        let namespace = schema
+            .split('.')
+            .collect::<Vec<_>>()
+            .join(NAMESPACE_SEPARATOR);
+        let table = match self.client.try_load_table(&namespace, name).await? {
+            Some(table) => table,
+            None => return Ok(None),
+        };
 
-    fn plan_create_table_as(
-        self: &Arc<Self>,
-        _storage: &Arc<StorageManager>,
-        _id_gen: &mut OperatorIdGen,
-        _schema: &str,
-        _create: CreateTableInfo,
-    ) -> Result<PlannedOperator> {
-        Err(DbError::new(
-            "Iceberg REST catalog plan_create_table_as not yet implemented",
-        ))
+        let entry = insert_table(&self.loaded(), schema, name, table)?;
+        Ok(Some(entry))
     }
 
-    fn plan_create_schema(
-        self: &Arc<Self>,
-        _id_gen: &mut OperatorIdGen,
-        _create: CreateSchemaInfo,
-    ) -> Result<PlannedOperator> {
-        Err(DbError::new(
-            "Iceberg REST catalog plan_create_schema not yet implemented",
-        ))
-    }
+    /// Load every namespace and table from the remote catalog into a new
+    /// in-memory catalog.
+    async fn load_all(client: &CatalogClient<C>) -> Result<MemoryCatalog> {
+        let catalog = MemoryCatalog::empty();
 
-    fn plan_drop(
-        self: &Arc<Self>,
-        _storage: &Arc<StorageManager>,
-        _id_gen: &mut OperatorIdGen,
-        _drop: DropInfo,
-    ) -> Result<PlannedOperator> {
-        Err(DbError::new(
-            "Iceberg REST catalog plan_drop not yet implemented",
-        ))
-    }
+        for namespace in client.list_namespaces().await? {
+            let schema = namespace.join(".");
+            create_schema(&catalog, &schema)?;
 
-    fn list_schemas(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<Self::Schema>>>> + Sync + Send + 'static {
-        //
+            let namespace = namespace.join(NAMESPACE_SEPARATOR);
+            for ident in client.list_tables(&namespace).await? {
+                let table = client.load_table(&namespace, &ident.name).await?;
+                insert_table(&catalog, &schema, &ident.name, table)?;
+            }
+        }
 
-        stream::once(async move { Ok(vec![]) })
+        Ok(catalog)
     }
 }
 
-impl Schema for RestSchema {
-    fn as_entry(&self) -> Arc<CatalogEntry> {
-        self.schema.clone()
-    }
-
-    fn create_table(
+impl<C> Catalog for IcebergRestCatalog<C>
+where
+    C: HttpClient,
+{
+    fn create_schema(
         &self,
-        _create: &CreateTableInfo,
-        _storage_id: StorageTableId,
-    ) -> Result<Arc<CatalogEntry>> {
-        //
-
-        Err(DbError::new(
-            "Iceberg REST schema create_table not yet implemented",
-        ))
-    }
-
-    fn create_view(&self, _create: &CreateViewInfo) -> Result<Arc<CatalogEntry>> {
-        Err(DbError::new(
-            "Iceberg REST schema create_view not yet implemented",
-        ))
-    }
-
-    fn create_scalar_function(
-        &self,
-        _create: &CreateScalarFunctionInfo,
-    ) -> Result<Arc<CatalogEntry>> {
-        Err(DbError::new(
-            "Iceberg REST schema create_scalar_function not yet implemented",
-        ))
-    }
-
-    fn create_aggregate_function(
-        &self,
-        _create: &CreateAggregateFunctionInfo,
-    ) -> Result<Arc<CatalogEntry>> {
-        Err(DbError::new(
-            "Iceberg REST schema create_aggregate_function not yet implemented",
-        ))
-    }
-
-    fn create_table_function(
-        &self,
-        _create: &CreateTableFunctionInfo,
-    ) -> Result<Arc<CatalogEntry>> {
-        Err(DbError::new(
-            "Iceberg REST schema create_table_function not yet implemented",
-        ))
+        _tx: &Transaction,
+        _create: &CreateSchemaInfo,
+    ) -> Result<Arc<dyn Schema>> {
+        Err(DbError::new("Iceberg REST catalogs are read only"))
     }
 
-    fn get_table_or_view(&self, _name: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        //
-
-        Err(DbError::new(
-            "Iceberg REST schema get_table_or_view not yet implemented",
-        ))
-    }
-
-    fn get_table_function(&self, _name: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        Err(DbError::new(
-            "Iceberg REST schema get_table_function not yet implemented",
-        ))
+    fn get_schema(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<dyn Schema>>> {
+        self.loaded().get_schema(tx, name)
     }
 
-    fn get_inferred_table_function(&self, _path: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        Err(DbError::new(
-            "Iceberg REST schema get_inferred_table_function not yet implemented",
-        ))
+    fn drop_entry(&self, _tx: &Transaction, _drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
+        Err(DbError::new("Iceberg REST catalogs are read only"))
     }
 
-    fn get_function(&self, _name: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        Err(DbError::new(
-            "Iceberg REST schema get_function not yet implemented",
-        ))
-    }
+    fn list_schemas(&self, _tx: &Transaction) -> CatalogStream<Arc<dyn Schema>> {
+        let client = self.client.clone();
+        let loaded = self.loaded.clone();
 
-    fn get_scalar_function(&self, _name: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        Err(DbError::new(
-            "Iceberg REST schema get_scalar_function not yet implemented",
-        ))
-    }
+        Box::pin(stream::once(async move {
+            let catalog = Arc::new(Self::load_all(&client).await?);
+            *loaded.write() = catalog.clone();
 
-    fn get_aggregate_function(&self, _name: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        Err(DbError::new(
-            "Iceberg REST schema get_aggregate_function not yet implemented",
-        ))
-    }
+            let mut schemas = Vec::new();
+            let mut stream = catalog.list_schemas(&Transaction::system());
+            while let Some(batch) = stream.try_next().await? {
+                schemas.extend(batch);
+            }
 
-    fn find_similar_entry(
-        &self,
-        _entry_types: &[CatalogEntryType],
-        _name: &str,
-    ) -> Result<Option<Arc<CatalogEntry>>> {
-        Err(DbError::new(
-            "Iceberg REST schema find_similar_entry not yet implemented",
-        ))
+            Ok(schemas)
+        }))
     }
 
-    fn list_entries(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<CatalogEntry>>>> + Sync + Send + 'static {
-        //
-
-        stream::once(async move { Ok(vec![]) })
+    fn resolve_table_or_view<'a>(
+        &'a self,
+        _tx: &'a Transaction,
+        schema: &'a str,
+        name: &'a str,
+    ) -> CatalogFuture<'a, Result<Option<Arc<CatalogEntry>>>> {
+        Box::pin(self.resolve_inner(schema, name))
     }
+}
 
-    fn list_tables(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<CatalogEntry>>>> + Sync + Send + 'static {
-        //
+// The cached catalog is only a view of the remote catalog and isn't
+// transactional, so changes are made with the system transaction to be
+// visible immediately.
+
+fn create_schema(catalog: &MemoryCatalog, schema: &str) -> Result<Arc<dyn Schema>> {
+    catalog.create_schema(
+        &Transaction::system(),
+        &CreateSchemaInfo {
+            name: schema.to_string(),
+            on_conflict: OnConflict::Ignore,
+        },
+    )
+}
 
-        stream::once(async move { Ok(vec![]) })
-    }
+/// Insert a loaded table into the catalog.
+///
+/// An existing entry is reused if the table's metadata location hasn't
+/// changed.
+fn insert_table(
+    catalog: &MemoryCatalog,
+    schema: &str,
+    name: &str,
+    table: LoadTableResponse,
+) -> Result<Arc<CatalogEntry>> {
+    let tx = Transaction::system();
+    let schema = create_schema(catalog, schema)?;
+
+    if let Some(existing) = schema.get_table_or_view(&tx, name)? {
+        let unchanged = match &existing.entry {
+            CatalogEntryInner::Table(ent) => {
+                ent.storage
+                    == TableStorage::External {
+                        location: table.metadata_location.clone(),
+                    }
+            }
+            _ => false,
+        };
+        if unchanged {
+            return Ok(existing);
+        }
+    }
+
+    let columns = table.metadata.current_schema()?.to_schema()?;
+    schema.create_external_table(
+        &tx,
+        &CreateExternalTableInfo {
+            name: name.to_string(),
+            columns: columns.fields,
+            function: &FUNCTION_SET_READ_ICEBERG,
+            location: table.metadata_location,
+            on_conflict: OnConflict::Replace,
+        },
+    )
 }
@@ -190,8 +190,9 @@ pub struct TableIdentifier {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct LoadTableResponse {
-    pub metadata: TableMetadata,
+    pub metadata: crate::spec::TableMetadata,
     pub metadata_location: String,
     pub config: Option<HashMap<String, String>>,
 }
@@ -0,0 +1,78 @@
+use std::collections::{HashMap, HashSet};
+
+use glaredb_core::arrays::datatype::DataType;
+use glaredb_core::arrays::scalar::{BorrowedScalarValue, ScalarValue};
+use glaredb_core::runtime::filesystem::FileSystemWithState;
+use glaredb_error::{DbError, Result};
+
+use crate::scan::{ReadColumn, read_all_rows};
+
+/// Reserved field id for the 'file_path' column in position delete files.
+const POSITION_DELETE_FILE_PATH_ID: i32 = 2147483546;
+/// Reserved field id for the 'pos' column in position delete files.
+const POSITION_DELETE_POS_ID: i32 = 2147483545;
+
+/// Rows deleted by an equality delete file.
+#[derive(Debug)]
+pub struct EqualityDeletes {
+    /// Indices of the columns in the table schema that make up the key.
+    pub columns: Vec<usize>,
+    /// Deleted keys.
+    ///
+    /// NULLs are considered equal to each other for equality deletes.
+    pub rows: HashSet<Vec<ScalarValue>>,
+}
+
+/// Read a position delete file, returning deleted row positions keyed by data
+/// file path.
+pub async fn read_position_deletes(
+    fs: &FileSystemWithState,
+    path: &str,
+) -> Result<HashMap<String, Vec<i64>>> {
+    let columns = [
+        ReadColumn {
+            field_id: POSITION_DELETE_FILE_PATH_ID,
+            name: "file_path".to_string(),
+            datatype: DataType::utf8(),
+        },
+        ReadColumn {
+            field_id: POSITION_DELETE_POS_ID,
+            name: "pos".to_string(),
+            datatype: DataType::int64(),
+        },
+    ];
+
+    let mut deletes: HashMap<String, Vec<i64>> = HashMap::new();
+    for row in read_all_rows(fs, path, &columns).await? {
+        match (&row[0], &row[1]) {
+            (BorrowedScalarValue::Utf8(file_path), BorrowedScalarValue::Int64(pos)) => {
+                deletes.entry(file_path.to_string()).or_default().push(*pos);
+            }
+            _ => {
+                return Err(DbError::new(format!(
+                    "Invalid row in position delete file '{path}'"
+                )));
+            }
+        }
+    }
+
+    Ok(deletes)
+}
+
+/// Read an equality delete file.
+///
+/// `columns` are the table columns for the file's equality ids, with
+/// `indices` being the position of each column in the table schema.
+pub async fn read_equality_deletes(
+    fs: &FileSystemWithState,
+    path: &str,
+    columns: &[ReadColumn],
+    indices: Vec<usize>,
+) -> Result<EqualityDeletes> {
+    let rows = read_all_rows(fs, path, columns).await?;
+
+    Ok(EqualityDeletes {
+        columns: indices,
+        rows: rows.into_iter().collect(),
+    })
+}
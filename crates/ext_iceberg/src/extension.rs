@@ -1,13 +1,18 @@
 use glaredb_core::extension::{Extension, ExtensionTableFunction};
 
+use crate::functions::read_iceberg::FUNCTION_SET_READ_ICEBERG;
+
 #[derive(Debug, Clone, Copy)]
 pub struct IcebergExtension;
 
 impl Extension for IcebergExtension {
     const NAME: &str = "iceberg";
-    const FUNCTION_NAMESPACE: Option<&str> = Some("iceberg");
+    const FUNCTION_NAMESPACE: Option<&str> = None; // Place functions in default schema.
 
     fn table_functions(&self) -> &[ExtensionTableFunction] {
-        &[]
+        const FUNCTIONS: &[ExtensionTableFunction] =
+            &[ExtensionTableFunction::new(&FUNCTION_SET_READ_ICEBERG)];
+
+        FUNCTIONS
     }
 }
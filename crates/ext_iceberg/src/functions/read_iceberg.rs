@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::FutureExt;
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::datatype::DataTypeId;
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_core::execution::operators::{ExecutionProperties, PollPull};
+use glaredb_core::functions::Signature;
+use glaredb_core::functions::documentation::{Category, Documentation};
+use glaredb_core::functions::function_set::TableFunctionSet;
+use glaredb_core::functions::table::scan::{ScanContext, TableScanFunction};
+use glaredb_core::functions::table::{
+    RawTableFunction,
+    TableFunctionBindState,
+    TableFunctionInput,
+};
+use glaredb_core::optimizer::expr_rewrite::ExpressionRewriteRule;
+use glaredb_core::optimizer::expr_rewrite::const_fold::ConstFold;
+use glaredb_core::runtime::filesystem::{FileOpenContext, FileSystemFuture, FileSystemWithState};
+use glaredb_core::statistics::value::StatisticsValue;
+use glaredb_core::storage::projections::Projections;
+use glaredb_core::storage::scan_filter::PhysicalScanFilter;
+use glaredb_error::{DbError, Result};
+
+use crate::scan::{FileScan, ReadColumn};
+use crate::table::{ScanFile, Table};
+
+pub const FUNCTION_SET_READ_ICEBERG: TableFunctionSet = TableFunctionSet {
+    name: "read_iceberg",
+    aliases: &["iceberg_scan"],
+    doc: &[&Documentation {
+        category: Category::Table,
+        description: "Read the current snapshot of an Iceberg table.",
+        arguments: &["path"],
+        example: None,
+    }],
+    functions: &[RawTableFunction::new_scan(
+        &Signature::new(&[DataTypeId::Utf8], DataTypeId::Table),
+        &ReadIceberg,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReadIceberg;
+
+pub struct ReadIcebergBindState {
+    fs: FileSystemWithState,
+    columns: Arc<[ReadColumn]>,
+    files: Arc<[Arc<ScanFile>]>,
+}
+
+pub struct ReadIcebergOperatorState {
+    fs: FileSystemWithState,
+    columns: Arc<[ReadColumn]>,
+    projections: Projections,
+    files: Arc<[Arc<ScanFile>]>,
+    batch_size: usize,
+}
+
+pub struct ReadIcebergPartitionState {
+    state: ScanState,
+    /// Indices of the files this partition is responsible for.
+    file_queue: VecDeque<usize>,
+}
+
+enum ScanState {
+    /// Init the next file to read.
+    Init,
+    /// Currently opening a file.
+    Opening {
+        open_fut: FileSystemFuture<'static, Result<FileScan>>,
+    },
+    /// Currently scanning a file.
+    Scanning(Box<FileScan>),
+}
+
+impl TableScanFunction for ReadIceberg {
+    type BindState = ReadIcebergBindState;
+    type OperatorState = ReadIcebergOperatorState;
+    type PartitionState = ReadIcebergPartitionState;
+
+    async fn bind(
+        &'static self,
+        scan_context: ScanContext<'_>,
+        input: TableFunctionInput,
+    ) -> Result<TableFunctionBindState<Self::BindState>> {
+        let path = ConstFold::rewrite(input.positional[0].clone())?
+            .try_into_scalar()?
+            .try_into_string()?;
+
+        let fs = scan_context.dispatch.filesystem_for_path(&path)?;
+        let context = FileOpenContext::new(scan_context.database_context, &input.named);
+        let fs = fs.load_state(context).await?;
+
+        let table = Table::load(&fs, &path).await?;
+        let schema: ColumnSchema = table.column_schema()?;
+        let columns: Arc<[ReadColumn]> = table.read_columns()?.into();
+        let files: Arc<[Arc<ScanFile>]> = table
+            .scan_files(&fs)
+            .await?
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+
+        Ok(TableFunctionBindState {
+            state: ReadIcebergBindState { fs, columns, files },
+            input,
+            data_schema: schema,
+            meta_schema: None,
+            cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
+        })
+    }
+
+    fn create_pull_operator_state(
+        bind_state: &Self::BindState,
+        projections: Projections,
+        _filters: &[PhysicalScanFilter],
+        props: ExecutionProperties,
+    ) -> Result<Self::OperatorState> {
+        if !projections.meta_indices().is_empty() {
+            return Err(DbError::new(
+                "Iceberg scan does not support metadata columns",
+            ));
+        }
+
+        Ok(ReadIcebergOperatorState {
+            fs: bind_state.fs.clone(),
+            columns: bind_state.columns.clone(),
+            projections,
+            files: bind_state.files.clone(),
+            batch_size: props.batch_size,
+        })
+    }
+
+    fn create_pull_partition_states(
+        op_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionState>> {
+        let states = (0..partitions)
+            .map(|partition_idx| ReadIcebergPartitionState {
+                state: ScanState::Init,
+                file_queue: (0..op_state.files.len())
+                    .skip(partition_idx)
+                    .step_by(partitions)
+                    .collect(),
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_pull(
+        cx: &mut Context,
+        op_state: &Self::OperatorState,
+        state: &mut Self::PartitionState,
+        output: &mut Batch,
+    ) -> Result<PollPull> {
+        loop {
+            match &mut state.state {
+                ScanState::Init => {
+                    let file_idx = match state.file_queue.pop_front() {
+                        Some(idx) => idx,
+                        None => {
+                            output.set_num_rows(0)?;
+                            return Ok(PollPull::Exhausted);
+                        }
+                    };
+
+                    let fs = op_state.fs.clone();
+                    let file = op_state.files[file_idx].clone();
+                    let columns = op_state.columns.clone();
+                    let projections = op_state.projections.clone();
+                    let batch_size = op_state.batch_size;
+
+                    let fut = Box::pin(async move {
+                        FileScan::open(&fs, file, &columns, &projections, batch_size).await
+                    });
+
+                    state.state = ScanState::Opening { open_fut: fut };
+                    // Continue...
+                }
+                ScanState::Opening { open_fut } => {
+                    let scan = match open_fut.poll_unpin(cx)? {
+                        Poll::Ready(scan) => scan,
+                        Poll::Pending => return Ok(PollPull::Pending),
+                    };
+                    state.state = ScanState::Scanning(Box::new(scan));
+                    // Continue...
+                }
+                ScanState::Scanning(scan) => match scan.poll_pull(cx, output)? {
+                    PollPull::Pending => return Ok(PollPull::Pending),
+                    PollPull::Exhausted => {
+                        // Move to the next file.
+                        state.state = ScanState::Init;
+                        continue;
+                    }
+                    PollPull::HasMore => return Ok(PollPull::HasMore),
+                },
+            }
+        }
+    }
+}
@@ -1,3 +1,8 @@
 pub mod catalog;
 pub mod extension;
+pub mod functions;
 pub mod spec;
+pub mod table;
+
+mod deletes;
+mod scan;
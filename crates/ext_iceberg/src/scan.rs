@@ -0,0 +1,359 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use ext_parquet::metadata::loader::MetaDataLoader;
+use ext_parquet::reader::{Reader, ScanRowGroup, ScanUnit};
+use ext_parquet::schema::types::SchemaDescriptor;
+use futures::future;
+use glaredb_core::arrays::array::Array;
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::cache::NopCache;
+use glaredb_core::arrays::datatype::DataType;
+use glaredb_core::arrays::field::{ColumnSchema, Field};
+use glaredb_core::arrays::scalar::ScalarValue;
+use glaredb_core::buffer::buffer_manager::DefaultBufferManager;
+use glaredb_core::execution::operators::PollPull;
+use glaredb_core::runtime::filesystem::{FileSystemWithState, OpenFlags};
+use glaredb_core::storage::projections::Projections;
+use glaredb_error::{Result, not_implemented};
+
+use crate::table::ScanFile;
+
+/// Batch size to use when reading delete files.
+const DELETE_FILE_BATCH_SIZE: usize = 4096;
+
+/// A column to read from a parquet file.
+#[derive(Debug, Clone)]
+pub struct ReadColumn {
+    /// Iceberg field id for the column.
+    pub field_id: i32,
+    pub name: String,
+    pub datatype: DataType,
+}
+
+/// Find the leaf in the parquet schema for a top-level column.
+///
+/// Columns are matched by field id if the file was written with field ids,
+/// otherwise by name.
+pub fn find_leaf(parquet_schema: &SchemaDescriptor, field_id: i32, name: &str) -> Option<usize> {
+    let has_ids = parquet_schema
+        .leaves
+        .iter()
+        .any(|leaf| leaf.primitive_type.basic_info.has_id());
+
+    parquet_schema.leaves.iter().position(|leaf| {
+        if has_ids {
+            let info = &leaf.primitive_type.basic_info;
+            info.has_id() && info.id() == field_id
+        } else {
+            leaf.path.parts.first().map(|s| s.as_str()) == Some(name)
+        }
+    })
+}
+
+/// Where the data for a column comes from.
+#[derive(Debug)]
+enum ColumnSource {
+    /// Column is read from the file, value indicates the index of the array in
+    /// the file batch.
+    File(usize),
+    /// Column is missing from the file, all values are NULL.
+    Null(Array),
+}
+
+/// State for scanning a single data file with its deletes applied.
+#[derive(Debug)]
+pub struct FileScan {
+    reader: Reader,
+    /// Batch that the parquet reader writes to.
+    batch: Batch,
+    /// Source for each projected column.
+    columns: Vec<ColumnSource>,
+    /// Index of the first row of the current batch in the file.
+    row_offset: i64,
+    file: Arc<ScanFile>,
+    /// Indices of the arrays in the file batch for each equality delete in
+    /// the file.
+    equality_arrays: Vec<Vec<usize>>,
+    /// Rows from the current batch that weren't deleted.
+    selection: Vec<usize>,
+}
+
+impl FileScan {
+    /// Open a data file for scanning.
+    ///
+    /// `columns` is the full table schema, `projections` indicate which
+    /// columns should be written to the output.
+    pub async fn open(
+        fs: &FileSystemWithState,
+        file: Arc<ScanFile>,
+        columns: &[ReadColumn],
+        projections: &Projections,
+        batch_size: usize,
+    ) -> Result<Self> {
+        let mut handle = fs.open(OpenFlags::READ, &file.path).await?;
+        let metadata = MetaDataLoader::new().load_from_file(&mut handle).await?;
+        let parquet_schema = &metadata.file_metadata.schema_descr;
+
+        // Columns (table column indices) that need to be read from the file.
+        // Starts with the projected columns, with any columns needed for
+        // equality deletes appended.
+        let mut read_columns: Vec<usize> = projections.data_indices().to_vec();
+        let mut equality_arrays = Vec::with_capacity(file.equality_deletes.len());
+        for deletes in &file.equality_deletes {
+            let arrays = deletes
+                .columns
+                .iter()
+                .map(|&col| match read_columns.iter().position(|&c| c == col) {
+                    Some(pos) => pos,
+                    None => {
+                        read_columns.push(col);
+                        read_columns.len() - 1
+                    }
+                })
+                .collect();
+            equality_arrays.push(arrays);
+        }
+
+        let mut leaf_fields: Vec<_> = parquet_schema
+            .leaves
+            .iter()
+            .map(|leaf| Field::new(leaf.name(), DataType::null(), true))
+            .collect();
+        let mut leaf_projections = Vec::new();
+        // Maps each read column to its source.
+        let mut sources = Vec::with_capacity(read_columns.len());
+
+        for &col_idx in &read_columns {
+            let column = &columns[col_idx];
+            match find_leaf(parquet_schema, column.field_id, &column.name) {
+                Some(leaf_idx) => {
+                    if parquet_schema.leaves[leaf_idx].path.parts.len() != 1 {
+                        not_implemented!("Reading nested iceberg column '{}'", column.name);
+                    }
+                    leaf_fields[leaf_idx].datatype = column.datatype.clone();
+                    sources.push(ColumnSource::File(leaf_projections.len()));
+                    leaf_projections.push(leaf_idx);
+                }
+                None => {
+                    // Column was added after this file was written.
+                    let arr = Array::new_null(&DefaultBufferManager, column.datatype.clone(), 1)?;
+                    sources.push(ColumnSource::Null(arr));
+                }
+            }
+        }
+
+        // Equality delete columns need to be read from the file, resolve their
+        // positions in the file batch.
+        let equality_arrays = equality_arrays
+            .into_iter()
+            .map(|arrays: Vec<usize>| {
+                arrays
+                    .into_iter()
+                    .map(|pos| match &sources[pos] {
+                        ColumnSource::File(idx) => Some(*idx),
+                        ColumnSource::Null(_) => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>();
+        let equality_arrays = match equality_arrays {
+            Some(arrays) => arrays,
+            None => not_implemented!("Equality deletes on columns missing from the data file"),
+        };
+
+        // Only the projected columns are written to the output.
+        sources.truncate(projections.data_indices().len());
+
+        let types: Vec<_> = leaf_projections
+            .iter()
+            .map(|&idx| leaf_fields[idx].datatype.clone())
+            .collect();
+
+        let mut reader = Reader::try_new(
+            &DefaultBufferManager,
+            ColumnSchema::new(leaf_fields),
+            parquet_schema,
+            Projections::new(leaf_projections),
+            &[],
+        )?;
+        let row_groups = ScanRowGroup::from_metadata(&metadata).collect();
+        reader.prepare(ScanUnit {
+            metadata: Arc::new(metadata),
+            file: handle,
+            row_groups,
+        })?;
+
+        Ok(FileScan {
+            reader,
+            batch: Batch::new(types, batch_size)?,
+            columns: sources,
+            row_offset: 0,
+            file,
+            equality_arrays,
+            selection: Vec::with_capacity(batch_size),
+        })
+    }
+
+    /// Pull the next batch from the file into `output`, applying deletes.
+    pub fn poll_pull(
+        &mut self,
+        cx: &mut std::task::Context,
+        output: &mut Batch,
+    ) -> Result<PollPull> {
+        loop {
+            self.batch.reset_for_write()?;
+            let poll = self.reader.poll_pull(cx, &mut self.batch)?;
+            if poll != PollPull::HasMore {
+                return Ok(poll);
+            }
+
+            let num_rows = self.batch.num_rows();
+            self.compute_selection()?;
+            self.row_offset += num_rows as i64;
+
+            if self.selection.is_empty() && num_rows > 0 {
+                // Everything in this batch was deleted, try the next one.
+                continue;
+            }
+
+            self.write_output(output)?;
+            return Ok(PollPull::HasMore);
+        }
+    }
+
+    /// Compute the rows in the current batch that haven't been deleted.
+    fn compute_selection(&mut self) -> Result<()> {
+        self.selection.clear();
+
+        'rows: for row in 0..self.batch.num_rows() {
+            let pos = self.row_offset + row as i64;
+            if self.file.position_deletes.binary_search(&pos).is_ok() {
+                continue;
+            }
+
+            for (deletes, arrays) in self.file.equality_deletes.iter().zip(&self.equality_arrays) {
+                let key = arrays
+                    .iter()
+                    .map(|&idx| Ok(self.batch.arrays()[idx].get_value(row)?.into_owned()))
+                    .collect::<Result<Vec<_>>>()?;
+                if deletes.rows.contains(&key) {
+                    continue 'rows;
+                }
+            }
+
+            self.selection.push(row);
+        }
+
+        Ok(())
+    }
+
+    fn write_output(&mut self, output: &mut Batch) -> Result<()> {
+        let num_rows = self.batch.num_rows();
+
+        for (out_idx, source) in self.columns.iter_mut().enumerate() {
+            match source {
+                ColumnSource::File(idx) => {
+                    output.clone_array_from(out_idx, (&mut self.batch, *idx))?;
+                }
+                ColumnSource::Null(arr) => {
+                    output.arrays_mut()[out_idx].clone_constant_from(
+                        arr,
+                        0,
+                        num_rows,
+                        &mut NopCache,
+                    )?;
+                }
+            }
+        }
+        output.set_num_rows(num_rows)?;
+
+        if self.selection.len() != num_rows {
+            output.select(self.selection.iter().copied())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Read all rows for the given columns from a parquet file.
+///
+/// Used for reading delete files which are expected to be small. Columns
+/// missing from the file are read as NULL.
+pub async fn read_all_rows(
+    fs: &FileSystemWithState,
+    path: &str,
+    columns: &[ReadColumn],
+) -> Result<Vec<Vec<ScalarValue>>> {
+    let mut handle = fs.open(OpenFlags::READ, path).await?;
+    let metadata = MetaDataLoader::new().load_from_file(&mut handle).await?;
+    let parquet_schema = &metadata.file_metadata.schema_descr;
+
+    let mut leaf_fields: Vec<_> = parquet_schema
+        .leaves
+        .iter()
+        .map(|leaf| Field::new(leaf.name(), DataType::null(), true))
+        .collect();
+    let mut leaf_projections = Vec::new();
+    // Position of each column in the batch.
+    let mut positions = Vec::with_capacity(columns.len());
+
+    for column in columns {
+        match find_leaf(parquet_schema, column.field_id, &column.name) {
+            Some(leaf_idx) => {
+                leaf_fields[leaf_idx].datatype = column.datatype.clone();
+                positions.push(Some(leaf_projections.len()));
+                leaf_projections.push(leaf_idx);
+            }
+            None => positions.push(None),
+        }
+    }
+
+    let types: Vec<_> = leaf_projections
+        .iter()
+        .map(|&idx| leaf_fields[idx].datatype.clone())
+        .collect();
+
+    let mut reader = Reader::try_new(
+        &DefaultBufferManager,
+        ColumnSchema::new(leaf_fields),
+        parquet_schema,
+        Projections::new(leaf_projections),
+        &[],
+    )?;
+    let row_groups: VecDeque<_> = ScanRowGroup::from_metadata(&metadata).collect();
+    reader.prepare(ScanUnit {
+        metadata: Arc::new(metadata),
+        file: handle,
+        row_groups,
+    })?;
+
+    let mut batch = Batch::new(types, DELETE_FILE_BATCH_SIZE)?;
+    let mut rows = Vec::new();
+
+    loop {
+        batch.reset_for_write()?;
+        let poll = future::poll_fn(|cx| match reader.poll_pull(cx, &mut batch) {
+            Ok(PollPull::Pending) => std::task::Poll::Pending,
+            other => std::task::Poll::Ready(other),
+        })
+        .await?;
+
+        if poll == PollPull::Exhausted {
+            break;
+        }
+
+        for row in 0..batch.num_rows() {
+            let values = positions
+                .iter()
+                .map(|pos| match pos {
+                    Some(pos) => Ok(batch.arrays()[*pos].get_value(row)?.into_owned()),
+                    None => Ok(ScalarValue::Null),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            rows.push(values);
+        }
+    }
+
+    Ok(rows)
+}
@@ -124,7 +124,7 @@ impl FromStr for ManifestContent {
     fn from_str(s: &str) -> Result<Self> {
         Ok(match s {
             "data" => ManifestContent::Data,
-            "delete" => ManifestContent::Delete,
+            "deletes" => ManifestContent::Delete,
             other => {
                 return Err(DbError::new(format!(
                     "'{other}' is not valid content for manifest"
@@ -138,7 +138,7 @@ impl fmt::Display for ManifestContent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ManifestContent::Data => write!(f, "data"),
-            ManifestContent::Delete => write!(f, "delete"),
+            ManifestContent::Delete => write!(f, "deletes"),
         }
     }
 }
@@ -272,6 +272,9 @@ pub struct DataFile {
     pub content: i32,
     pub file_path: String,
     pub file_format: String,
+    /// Partition values for the file, keyed by partition field name.
+    #[serde(default)]
+    pub partition: HashMap<String, serde_json::Value>,
     pub record_count: i64,
     pub file_size_in_bytes: i64,
     pub column_sizes: Option<Vec<I64Entry>>,
@@ -35,6 +35,21 @@ pub struct TableMetadata {
     // refs: Option<HashMap<String, SnapshotReference>>,
 }
 
+impl TableMetadata {
+    /// Get the current schema for the table.
+    pub fn current_schema(&self) -> Result<&Schema> {
+        self.schemas
+            .iter()
+            .find(|s| s.schema_id == self.current_schema_id)
+            .ok_or_else(|| {
+                DbError::new(format!(
+                    "Missing current schema with id {}",
+                    self.current_schema_id
+                ))
+            })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct Snapshot {
@@ -3,6 +3,7 @@ use std::sync::LazyLock;
 
 use glaredb_core::arrays::datatype::{DataType, DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
 use glaredb_core::arrays::field::{ColumnSchema as BulletSchema, Field};
+use glaredb_core::arrays::scalar::decimal::{Decimal64Type, DecimalType};
 use glaredb_error::{DbError, Result, not_implemented};
 use regex::Regex;
 use serde::{Deserialize, Deserializer, de};
@@ -43,10 +44,14 @@ impl TryFrom<PrimitiveType> for DataType {
             PrimitiveType::Long => DataType::int64(),
             PrimitiveType::Float => DataType::float32(),
             PrimitiveType::Double => DataType::float64(),
-            PrimitiveType::Decimal { p, s } => DataType::decimal128(DecimalTypeMeta {
-                precision: p,
-                scale: s as i8,
-            }),
+            PrimitiveType::Decimal { p, s } => {
+                let meta = DecimalTypeMeta::new(p, s as i8);
+                if p <= Decimal64Type::MAX_PRECISION {
+                    DataType::decimal64(meta)
+                } else {
+                    DataType::decimal128(meta)
+                }
+            }
             PrimitiveType::Date => DataType::date32(),
             PrimitiveType::Time => {
                 DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
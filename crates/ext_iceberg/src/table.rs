@@ -0,0 +1,423 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::future;
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_core::runtime::filesystem::{FileSystemWithState, OpenFlags};
+use glaredb_error::{DbError, Result, ResultExt, not_implemented};
+use tracing::debug;
+
+use crate::deletes::{EqualityDeletes, read_equality_deletes, read_position_deletes};
+use crate::scan::ReadColumn;
+use crate::spec::{
+    DataFile,
+    Manifest,
+    ManifestEntryStatus,
+    ManifestList,
+    Schema,
+    Snapshot,
+    TableMetadata,
+};
+
+/// Directory containing table metadata, relative to the table root.
+const METADATA_DIR: &str = "metadata";
+/// File in the metadata directory containing the latest metadata version.
+const VERSION_HINT_FILE: &str = "version-hint.text";
+
+/// An iceberg table loaded from a metadata file.
+#[derive(Debug)]
+pub struct Table {
+    /// Root path of the table, without a trailing slash.
+    root: String,
+    metadata: TableMetadata,
+}
+
+/// A data file to scan along with the deletes that apply to it.
+#[derive(Debug)]
+pub struct ScanFile {
+    /// Full path to the data file.
+    pub path: String,
+    /// Sorted positions of deleted rows in the file.
+    pub position_deletes: Vec<i64>,
+    /// Equality deletes that apply to this file.
+    pub equality_deletes: Vec<Arc<EqualityDeletes>>,
+}
+
+/// A file from a manifest with the sequence number it was added with.
+#[derive(Debug)]
+struct ManifestFile {
+    data_file: DataFile,
+    sequence_number: i64,
+    partition_spec_id: i32,
+}
+
+impl Table {
+    /// Load a table.
+    ///
+    /// `path` may either be the root of the table, or a path to a specific
+    /// metadata json file. If the root is provided, the version hint in the
+    /// metadata directory is used to find the latest metadata file.
+    pub async fn load(fs: &FileSystemWithState, path: &str) -> Result<Self> {
+        let path = path.trim_end_matches('/');
+
+        let (root, metadata_path) = if path.ends_with(".metadata.json") {
+            let dir = path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or(".");
+            let root = dir.strip_suffix(&format!("/{METADATA_DIR}")).unwrap_or(dir);
+            (root.to_string(), path.to_string())
+        } else {
+            let metadata_path = find_metadata_path(fs, path).await?;
+            (path.to_string(), metadata_path)
+        };
+
+        debug!(%root, %metadata_path, "loading iceberg table");
+
+        let buf = read_file(fs, &metadata_path).await?;
+        let metadata: TableMetadata = serde_json::from_slice(&buf)
+            .context_fn(|| format!("failed to deserialize metadata file '{metadata_path}'"))?;
+
+        Ok(Table { root, metadata })
+    }
+
+    pub fn metadata(&self) -> &TableMetadata {
+        &self.metadata
+    }
+
+    /// Get the current schema for the table.
+    pub fn schema(&self) -> Result<&Schema> {
+        self.metadata.current_schema()
+    }
+
+    pub fn column_schema(&self) -> Result<ColumnSchema> {
+        self.schema()?.to_schema()
+    }
+
+    /// Get the columns to read for each field in the current schema.
+    pub fn read_columns(&self) -> Result<Vec<ReadColumn>> {
+        self.schema()?
+            .fields
+            .iter()
+            .map(|f| {
+                Ok(ReadColumn {
+                    field_id: f.id,
+                    name: f.name.clone(),
+                    datatype: f.to_field()?.datatype,
+                })
+            })
+            .collect()
+    }
+
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        let id = self.metadata.current_snapshot_id?;
+        self.metadata.snapshots.iter().find(|s| s.snapshot_id == id)
+    }
+
+    /// Get the data files for the current snapshot.
+    ///
+    /// This reads all manifests for the snapshot, and all delete files
+    /// referenced by those manifests.
+    pub async fn scan_files(&self, fs: &FileSystemWithState) -> Result<Vec<ScanFile>> {
+        // Tables without a snapshot are empty.
+        let snapshot = match self.current_snapshot() {
+            Some(snapshot) => snapshot,
+            None => return Ok(Vec::new()),
+        };
+
+        let list_path = self.resolve_path(&snapshot.manifest_list);
+        let list = ManifestList::from_raw_avro(read_file(fs, &list_path).await?.as_slice())?;
+
+        let mut data_files = Vec::new();
+        let mut position_delete_files = Vec::new();
+        let mut equality_delete_files = Vec::new();
+
+        for list_entry in &list.entries {
+            let manifest_path = self.resolve_path(&list_entry.manifest_path);
+            let manifest =
+                Manifest::from_raw_avro(read_file(fs, &manifest_path).await?.as_slice())?;
+
+            for entry in manifest.entries {
+                if ManifestEntryStatus::try_from(entry.status)?.is_deleted() {
+                    continue;
+                }
+                if !entry.data_file.file_format.eq_ignore_ascii_case("parquet") {
+                    not_implemented!(
+                        "Reading iceberg files with format '{}'",
+                        entry.data_file.file_format
+                    );
+                }
+
+                // > When reading v1 manifests with no sequence number column,
+                // > sequence numbers for all files must default to 0.
+                //
+                // Sequence numbers are also inherited from the manifest when
+                // null.
+                let file = ManifestFile {
+                    sequence_number: entry.sequence_number.unwrap_or(list_entry.sequence_number),
+                    partition_spec_id: list_entry.partition_spec_id,
+                    data_file: entry.data_file,
+                };
+
+                match file.data_file.content {
+                    0 => data_files.push(file),
+                    1 => position_delete_files.push(file),
+                    2 => equality_delete_files.push(file),
+                    other => {
+                        return Err(DbError::new(format!(
+                            "Unknown content type for iceberg data file: {other}"
+                        )));
+                    }
+                }
+            }
+        }
+
+        let mut scan_files: Vec<_> = data_files
+            .iter()
+            .map(|file| ScanFile {
+                path: self.resolve_path(&file.data_file.file_path),
+                position_deletes: Vec::new(),
+                equality_deletes: Vec::new(),
+            })
+            .collect();
+
+        // Position deletes reference data files by their path as written in
+        // the manifest.
+        let file_indices: HashMap<_, _> = data_files
+            .iter()
+            .enumerate()
+            .map(|(idx, file)| (file.data_file.file_path.as_str(), idx))
+            .collect();
+
+        for delete_file in &position_delete_files {
+            let path = self.resolve_path(&delete_file.data_file.file_path);
+            for (data_path, positions) in read_position_deletes(fs, &path).await? {
+                let idx = match file_indices.get(data_path.as_str()) {
+                    Some(&idx) => idx,
+                    None => continue,
+                };
+                // > A position delete file must be applied to a data file when
+                // > ... the data file's data sequence number is less than or
+                // > equal to the delete file's data sequence number
+                if data_files[idx].sequence_number <= delete_file.sequence_number {
+                    scan_files[idx].position_deletes.extend(positions);
+                }
+            }
+        }
+
+        if !equality_delete_files.is_empty() {
+            let columns = self.read_columns()?;
+            let schema = self.schema()?;
+
+            for delete_file in &equality_delete_files {
+                let equality_ids = delete_file.data_file.equality_ids.as_deref().unwrap_or(&[]);
+                let indices = equality_ids
+                    .iter()
+                    .map(|id| {
+                        schema
+                            .fields
+                            .iter()
+                            .position(|f| f.id == *id)
+                            .ok_or_else(|| {
+                                DbError::new(format!(
+                                    "Equality delete references unknown field id {id}"
+                                ))
+                            })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let delete_columns: Vec<_> =
+                    indices.iter().map(|&idx| columns[idx].clone()).collect();
+
+                let path = self.resolve_path(&delete_file.data_file.file_path);
+                let deletes =
+                    Arc::new(read_equality_deletes(fs, &path, &delete_columns, indices).await?);
+
+                for (idx, data_file) in data_files.iter().enumerate() {
+                    // > An equality delete file must be applied to a data file
+                    // > when ... the data file's data sequence number is
+                    // > strictly less than the delete's data sequence number
+                    if data_file.sequence_number >= delete_file.sequence_number {
+                        continue;
+                    }
+                    if !self.same_partition(data_file, delete_file) {
+                        continue;
+                    }
+                    scan_files[idx].equality_deletes.push(deletes.clone());
+                }
+            }
+        }
+
+        for file in &mut scan_files {
+            file.position_deletes.sort_unstable();
+            file.position_deletes.dedup();
+        }
+
+        Ok(scan_files)
+    }
+
+    /// Check if an equality delete file applies to a data file's partition.
+    ///
+    /// Deletes in an unpartitioned spec apply to all files.
+    fn same_partition(&self, data_file: &ManifestFile, delete_file: &ManifestFile) -> bool {
+        let unpartitioned = self
+            .metadata
+            .partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == delete_file.partition_spec_id)
+            .is_some_and(|spec| spec.fields.is_empty());
+        if unpartitioned {
+            return true;
+        }
+
+        data_file.partition_spec_id == delete_file.partition_spec_id
+            && data_file.data_file.partition == delete_file.data_file.partition
+    }
+
+    fn resolve_path(&self, path: &str) -> String {
+        resolve_path(&self.root, &self.metadata.location, path)
+    }
+}
+
+/// Resolve a path found in table metadata or manifests.
+///
+/// Paths are written relative to the table location at the time of writing,
+/// which may not match where we're reading the table from (e.g. the table was
+/// copied). Paths under the table location are re-rooted to the path we
+/// loaded the table from.
+fn resolve_path(root: &str, location: &str, path: &str) -> String {
+    fn normalize(path: &str) -> &str {
+        let path = path
+            .strip_prefix("file://")
+            .or_else(|| path.strip_prefix("file:"))
+            .unwrap_or(path);
+        path.trim_start_matches("./").trim_end_matches('/')
+    }
+
+    let location = normalize(location);
+    let normalized = normalize(path);
+
+    if let Some(rest) = normalized
+        .strip_prefix(location)
+        .and_then(|rest| rest.strip_prefix('/'))
+    {
+        return format!("{root}/{rest}");
+    }
+
+    if normalized.contains("://") || normalized.starts_with('/') {
+        return normalized.to_string();
+    }
+
+    format!("{root}/{normalized}")
+}
+
+/// Find the path to the latest metadata file for a table.
+async fn find_metadata_path(fs: &FileSystemWithState, root: &str) -> Result<String> {
+    let hint_path = format!("{root}/{METADATA_DIR}/{VERSION_HINT_FILE}");
+    if fs.stat(&hint_path).await?.is_some() {
+        let hint = read_file(fs, &hint_path).await?;
+        let hint = String::from_utf8_lossy(&hint);
+        let hint = hint.trim();
+        if hint.ends_with(".metadata.json") {
+            return Ok(format!("{root}/{METADATA_DIR}/{hint}"));
+        }
+        return Ok(format!("{root}/{METADATA_DIR}/v{hint}.metadata.json"));
+    }
+
+    // No version hint, find the metadata file with the highest version.
+    let err_msg = || format!("Failed to read iceberg metadata for table at '{root}'");
+    let mut provider = fs
+        .read_glob(&format!("{root}/{METADATA_DIR}/*.metadata.json"))
+        .context_fn(err_msg)?;
+    let mut paths = Vec::new();
+    loop {
+        let n = future::poll_fn(|cx| provider.poll_next(cx, &mut paths))
+            .await
+            .context_fn(err_msg)?;
+        if n == 0 {
+            break;
+        }
+    }
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.rsplit('/').next()?;
+            let version = metadata_file_version(name)?;
+            Some((version, path))
+        })
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, path)| path)
+        .ok_or_else(|| DbError::new(format!("No iceberg metadata found for table at '{root}'")))
+}
+
+/// Parse the version from a metadata file name.
+///
+/// Names are either 'v<version>.metadata.json' or
+/// '<version>-<uuid>.metadata.json'.
+fn metadata_file_version(name: &str) -> Option<u64> {
+    let name = name.strip_suffix(".metadata.json")?;
+    let version = match name.strip_prefix('v') {
+        Some(version) => version,
+        None => name.split('-').next()?,
+    };
+    version.parse().ok()
+}
+
+/// Read an entire file into memory.
+async fn read_file(fs: &FileSystemWithState, path: &str) -> Result<Vec<u8>> {
+    let mut file = fs.open(OpenFlags::READ, path).await?;
+    let mut buf = vec![0; file.call_size()];
+    file.call_read_exact(&mut buf).await?;
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_metadata_file_versions() {
+        assert_eq!(Some(3), metadata_file_version("v3.metadata.json"));
+        assert_eq!(
+            Some(100000000000),
+            metadata_file_version("v100000000000.metadata.json")
+        );
+        assert_eq!(
+            Some(12),
+            metadata_file_version("00012-6e2c3a3b-e2a1-4d4f-a6c3-8b9d2c6a1f55.metadata.json")
+        );
+        assert_eq!(None, metadata_file_version("version-hint.text"));
+        assert_eq!(None, metadata_file_version("vabc.metadata.json"));
+    }
+
+    #[test]
+    fn resolve_paths() {
+        let root = "../testdata/iceberg/tables-v2/t";
+
+        // Relative location, written with and without a leading './'.
+        let location = "./iceberg/tables-v2/t";
+        assert_eq!(
+            "../testdata/iceberg/tables-v2/t/metadata/snap-1.avro",
+            resolve_path(root, location, "iceberg/tables-v2/t/metadata/snap-1.avro")
+        );
+        assert_eq!(
+            "../testdata/iceberg/tables-v2/t/data/a.parquet",
+            resolve_path(root, location, "./iceberg/tables-v2/t/data/a.parquet")
+        );
+
+        // Absolute location.
+        let location = "file:/warehouse/db/t";
+        assert_eq!(
+            "../testdata/iceberg/tables-v2/t/data/a.parquet",
+            resolve_path(root, location, "file:/warehouse/db/t/data/a.parquet")
+        );
+
+        // Paths outside of the table location are left alone.
+        let location = "s3://bucket/t";
+        assert_eq!(
+            "s3://other/data/a.parquet",
+            resolve_path(root, location, "s3://other/data/a.parquet")
+        );
+        assert_eq!(
+            "s3://bucket/a/data/a.parquet",
+            resolve_path("s3://bucket/a", location, "s3://bucket/t/data/a.parquet")
+        );
+    }
+}
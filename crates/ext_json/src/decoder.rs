@@ -0,0 +1,256 @@
+use std::collections::VecDeque;
+
+use glaredb_error::{DbError, Result};
+use serde_json::{Map, Value};
+
+/// A single decoded record.
+pub type JsonRecord = Map<String, Value>;
+
+/// How records are laid out in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormat {
+    /// Records are elements of a single top-level array.
+    Array,
+    /// Records are top-level objects separated by whitespace, typically
+    /// newlines.
+    NewlineDelimited,
+}
+
+impl JsonFormat {
+    /// Infer the format from the beginning of a file.
+    pub fn infer_from_sample(sample: &[u8]) -> Self {
+        match sample.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'[') => JsonFormat::Array,
+            _ => JsonFormat::NewlineDelimited,
+        }
+    }
+}
+
+/// Position within a top-level array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArrayState {
+    /// Expecting the opening '['.
+    Start,
+    /// Expecting either the first value or ']'.
+    FirstValue,
+    /// Expecting a value after a ','.
+    Value,
+    /// Expecting either ',' or ']'.
+    AfterValue,
+    /// Array has been closed.
+    End,
+}
+
+/// Incrementally decodes JSON records from a byte stream.
+#[derive(Debug)]
+pub struct JsonDecoder {
+    format: JsonFormat,
+    /// Bytes that haven't been decoded yet.
+    ///
+    /// This will contain the start of a record that's split across reads.
+    buf: Vec<u8>,
+    array_state: ArrayState,
+}
+
+impl JsonDecoder {
+    pub fn new(format: JsonFormat) -> Self {
+        JsonDecoder {
+            format,
+            buf: Vec::new(),
+            array_state: ArrayState::Start,
+        }
+    }
+
+    /// Reset the decoder to begin decoding a new stream.
+    pub fn reset(&mut self) {
+        self.buf.clear();
+        self.array_state = ArrayState::Start;
+    }
+
+    /// Decode as many complete records from the input as possible, pushing
+    /// them to `records`.
+    ///
+    /// A partial record at the end of the input is buffered and decoded on
+    /// the next call.
+    pub fn decode(&mut self, input: &[u8], records: &mut VecDeque<JsonRecord>) -> Result<()> {
+        self.buf.extend_from_slice(input);
+
+        let mut pos = 0;
+        loop {
+            pos += self.buf[pos..]
+                .iter()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count();
+            let b = match self.buf.get(pos) {
+                Some(b) => *b,
+                None => break,
+            };
+
+            match (self.format, self.array_state) {
+                (JsonFormat::NewlineDelimited, _) => match self.decode_record(pos, records)? {
+                    Some(n) => pos += n,
+                    None => break,
+                },
+                (JsonFormat::Array, ArrayState::Start) => {
+                    if b != b'[' {
+                        return Err(DbError::new("Expected '[' at start of JSON array"));
+                    }
+                    pos += 1;
+                    self.array_state = ArrayState::FirstValue;
+                }
+                (JsonFormat::Array, ArrayState::FirstValue) if b == b']' => {
+                    pos += 1;
+                    self.array_state = ArrayState::End;
+                }
+                (JsonFormat::Array, ArrayState::FirstValue | ArrayState::Value) => {
+                    match self.decode_record(pos, records)? {
+                        Some(n) => {
+                            pos += n;
+                            self.array_state = ArrayState::AfterValue;
+                        }
+                        None => break,
+                    }
+                }
+                (JsonFormat::Array, ArrayState::AfterValue) => {
+                    match b {
+                        b',' => self.array_state = ArrayState::Value,
+                        b']' => self.array_state = ArrayState::End,
+                        _ => {
+                            return Err(DbError::new(format!(
+                                "Expected ',' or ']' in JSON array, got '{}'",
+                                b as char
+                            )));
+                        }
+                    }
+                    pos += 1;
+                }
+                (JsonFormat::Array, ArrayState::End) => {
+                    return Err(DbError::new("Unexpected data after end of JSON array"));
+                }
+            }
+        }
+
+        self.buf.drain(..pos);
+
+        Ok(())
+    }
+
+    /// Decode a single record starting at `pos`, returning the number of bytes
+    /// consumed.
+    ///
+    /// Returns None if more input is needed.
+    fn decode_record(
+        &self,
+        pos: usize,
+        records: &mut VecDeque<JsonRecord>,
+    ) -> Result<Option<usize>> {
+        let mut stream = serde_json::Deserializer::from_slice(&self.buf[pos..]).into_iter();
+        match stream.next() {
+            Some(Ok(Value::Object(record))) => {
+                records.push_back(record);
+                Ok(Some(stream.byte_offset()))
+            }
+            Some(Ok(other)) => Err(DbError::new("Expected JSON object for record")
+                .with_field("value", other.to_string())),
+            Some(Err(e)) if e.is_eof() => Ok(None),
+            Some(Err(e)) => Err(DbError::with_source(
+                "Failed to decode JSON record",
+                Box::new(e),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Finish decoding the stream, erroring if there's any incomplete input.
+    pub fn finish(&self) -> Result<()> {
+        if self.buf.iter().any(|b| !b.is_ascii_whitespace()) {
+            return Err(DbError::new("Unexpected end of JSON input"));
+        }
+        if self.format == JsonFormat::Array && self.array_state != ArrayState::End {
+            return Err(DbError::new("Unexpected end of JSON array"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn decode_chunked(format: JsonFormat, input: &str, chunk_size: usize) -> Vec<Value> {
+        let mut decoder = JsonDecoder::new(format);
+        let mut records = VecDeque::new();
+        for chunk in input.as_bytes().chunks(chunk_size) {
+            decoder.decode(chunk, &mut records).unwrap();
+        }
+        decoder.finish().unwrap();
+        records.into_iter().map(Value::Object).collect()
+    }
+
+    #[test]
+    fn infer_format() {
+        assert_eq!(
+            JsonFormat::Array,
+            JsonFormat::infer_from_sample(b"  \n[{}]")
+        );
+        assert_eq!(
+            JsonFormat::NewlineDelimited,
+            JsonFormat::infer_from_sample(b"{\"a\": 1}\n")
+        );
+    }
+
+    #[test]
+    fn decode_newline_delimited() {
+        let input = "{\"a\": 1, \"b\": \"x\"}\n{\"a\": 2}\n\n{\"a\": {\"c\": [3]}}\n";
+        let expected = vec![
+            json!({"a": 1, "b": "x"}),
+            json!({"a": 2}),
+            json!({"a": {"c": [3]}}),
+        ];
+
+        for chunk_size in [1, 3, 7, 1024] {
+            assert_eq!(
+                expected,
+                decode_chunked(JsonFormat::NewlineDelimited, input, chunk_size)
+            );
+        }
+    }
+
+    #[test]
+    fn decode_array() {
+        let input = "[\n  {\"a\": 1},\n  {\"a\": \"]\"}\n]\n";
+        let expected = vec![json!({"a": 1}), json!({"a": "]"})];
+
+        for chunk_size in [1, 4, 1024] {
+            assert_eq!(
+                expected,
+                decode_chunked(JsonFormat::Array, input, chunk_size)
+            );
+        }
+
+        assert!(decode_chunked(JsonFormat::Array, "[]", 1).is_empty());
+    }
+
+    #[test]
+    fn decode_errors() {
+        let mut records = VecDeque::new();
+
+        let mut decoder = JsonDecoder::new(JsonFormat::NewlineDelimited);
+        decoder.decode(b"[1, 2]\n", &mut records).unwrap_err();
+
+        let mut decoder = JsonDecoder::new(JsonFormat::NewlineDelimited);
+        decoder.decode(b"{\"a\": 1}\n{\"a\"", &mut records).unwrap();
+        decoder.finish().unwrap_err();
+
+        let mut decoder = JsonDecoder::new(JsonFormat::Array);
+        decoder
+            .decode(b"[{\"a\": 1} {\"a\": 2}]", &mut records)
+            .unwrap_err();
+
+        let mut decoder = JsonDecoder::new(JsonFormat::Array);
+        decoder.decode(b"[{\"a\": 1},", &mut records).unwrap();
+        decoder.finish().unwrap_err();
+    }
+}
@@ -0,0 +1,32 @@
+use glaredb_core::catalog::create::FileInferScan;
+use glaredb_core::extension::{Extension, ExtensionTableFunction};
+
+use crate::functions::read_json::{FUNCTION_SET_READ_JSON, FUNCTION_SET_READ_NDJSON};
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsonExtension;
+
+impl Extension for JsonExtension {
+    const NAME: &str = "json";
+    const FUNCTION_NAMESPACE: Option<&str> = None; // Place functions in default schema.
+
+    fn table_functions(&self) -> &[ExtensionTableFunction] {
+        const FUNCTIONS: &[ExtensionTableFunction] = &[
+            // Scan functions
+            ExtensionTableFunction {
+                infer_scan: Some(FileInferScan {
+                    can_handle: |path| path.ends_with(".json"),
+                }),
+                function: &FUNCTION_SET_READ_JSON,
+            },
+            ExtensionTableFunction {
+                infer_scan: Some(FileInferScan {
+                    can_handle: |path| path.ends_with(".jsonl") || path.ends_with(".ndjson"),
+                }),
+                function: &FUNCTION_SET_READ_NDJSON,
+            },
+        ];
+
+        FUNCTIONS
+    }
+}
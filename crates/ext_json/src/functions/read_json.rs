@@ -0,0 +1,289 @@
+use std::collections::VecDeque;
+use std::task::{Context, Poll};
+
+use futures::FutureExt;
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::datatype::DataTypeId;
+use glaredb_core::execution::operators::{ExecutionProperties, PollPull};
+use glaredb_core::functions::Signature;
+use glaredb_core::functions::documentation::{Category, Documentation};
+use glaredb_core::functions::function_set::TableFunctionSet;
+use glaredb_core::functions::table::scan::{ScanContext, TableScanFunction};
+use glaredb_core::functions::table::{
+    RawTableFunction,
+    TableFunctionBindState,
+    TableFunctionInput,
+};
+use glaredb_core::runtime::filesystem::file_provider::{MultiFileData, MultiFileProvider};
+use glaredb_core::runtime::filesystem::{
+    AnyFile,
+    FileSystemFuture,
+    FileSystemWithState,
+    OpenFlags,
+};
+use glaredb_core::statistics::value::StatisticsValue;
+use glaredb_core::storage::projections::Projections;
+use glaredb_core::storage::scan_filter::PhysicalScanFilter;
+use glaredb_error::{DbError, Result};
+
+use crate::decoder::{JsonDecoder, JsonFormat};
+use crate::reader::JsonReader;
+use crate::schema::JsonSchema;
+
+pub const FUNCTION_SET_READ_JSON: TableFunctionSet = TableFunctionSet {
+    name: "read_json",
+    aliases: &["scan_json"],
+    doc: &[&Documentation {
+        category: Category::Table,
+        description: "Read a JSON file containing either a top-level array of objects, or newline-delimited objects.",
+        arguments: &["path"],
+        example: None,
+    }],
+    functions: &[
+        RawTableFunction::new_scan(
+            &Signature::new(&[DataTypeId::Utf8], DataTypeId::Table),
+            &ReadJson { format: None },
+        ),
+        RawTableFunction::new_scan(
+            &Signature::new(&[DataTypeId::List], DataTypeId::Table),
+            &ReadJson { format: None },
+        ),
+    ],
+};
+
+pub const FUNCTION_SET_READ_NDJSON: TableFunctionSet = TableFunctionSet {
+    name: "read_ndjson",
+    aliases: &["scan_ndjson"],
+    doc: &[&Documentation {
+        category: Category::Table,
+        description: "Read a newline-delimited JSON file.",
+        arguments: &["path"],
+        example: None,
+    }],
+    functions: &[
+        RawTableFunction::new_scan(
+            &Signature::new(&[DataTypeId::Utf8], DataTypeId::Table),
+            &ReadJson {
+                format: Some(JsonFormat::NewlineDelimited),
+            },
+        ),
+        RawTableFunction::new_scan(
+            &Signature::new(&[DataTypeId::List], DataTypeId::Table),
+            &ReadJson {
+                format: Some(JsonFormat::NewlineDelimited),
+            },
+        ),
+    ],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReadJson {
+    /// Format of the files. Inferred from the first file if None.
+    format: Option<JsonFormat>,
+}
+
+pub struct ReadJsonBindState {
+    fs: FileSystemWithState,
+    mf_data: MultiFileData,
+    format: JsonFormat,
+    column_names: Vec<String>,
+}
+
+pub struct ReadJsonOperatorState {
+    fs: FileSystemWithState,
+    mf_data: MultiFileData,
+    format: JsonFormat,
+    column_names: Vec<String>,
+    projections: Projections,
+}
+
+pub struct ReadJsonPartitionState {
+    /// Current read state.
+    state: ReadState,
+    /// Queue of files this partition will be handling.
+    queue: VecDeque<String>,
+    /// Reader that's reused across all files.
+    reader: Box<JsonReader>,
+}
+
+enum ReadState {
+    /// Initialize the next file to read.
+    Init,
+    /// Currently opening a file.
+    Opening {
+        open_fut: FileSystemFuture<'static, Result<AnyFile>>,
+    },
+    /// Currently scanning a file.
+    Scanning,
+}
+
+impl TableScanFunction for ReadJson {
+    type BindState = ReadJsonBindState;
+    type OperatorState = ReadJsonOperatorState;
+    type PartitionState = ReadJsonPartitionState;
+
+    async fn bind(
+        &'static self,
+        scan_context: ScanContext<'_>,
+        input: TableFunctionInput,
+    ) -> Result<TableFunctionBindState<Self::BindState>> {
+        let (mut provider, fs) =
+            MultiFileProvider::try_new_from_inputs(scan_context, &input).await?;
+
+        let mut mf_data = MultiFileData::empty();
+        provider.expand_all(&mut mf_data).await?;
+
+        // Use first file for schema inference.
+        let first = mf_data
+            .get(0)
+            .ok_or_else(|| DbError::new("No files for path, expected at least one file"))?;
+
+        // Read from the start of the file until we have at least one complete
+        // record, and use everything decoded from those reads for inference.
+        const INFER_BUF_SIZE: usize = 64 * 1024;
+        let mut infer_buf = vec![0; INFER_BUF_SIZE];
+
+        let mut file = fs.open(OpenFlags::READ, first).await?;
+        let mut n = file.call_read_fill(&mut infer_buf).await?;
+
+        let format = self
+            .format
+            .unwrap_or_else(|| JsonFormat::infer_from_sample(&infer_buf[0..n]));
+        let mut decoder = JsonDecoder::new(format);
+        let mut records = VecDeque::new();
+
+        loop {
+            decoder.decode(&infer_buf[0..n], &mut records)?;
+            if n < INFER_BUF_SIZE {
+                // Read the entire file, make sure it's complete.
+                decoder.finish()?;
+                break;
+            }
+            if !records.is_empty() {
+                break;
+            }
+            n = file.call_read_fill(&mut infer_buf).await?;
+        }
+
+        let schema = JsonSchema::infer_from_records(&records)?;
+        let column_names = schema
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.name.clone())
+            .collect();
+
+        Ok(TableFunctionBindState {
+            state: ReadJsonBindState {
+                fs: fs.clone(),
+                mf_data,
+                format,
+                column_names,
+            },
+            input,
+            data_schema: schema.schema,
+            meta_schema: Some(provider.meta_schema()),
+            cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
+        })
+    }
+
+    fn create_pull_operator_state(
+        bind_state: &Self::BindState,
+        projections: Projections,
+        _filters: &[PhysicalScanFilter],
+        _props: ExecutionProperties,
+    ) -> Result<Self::OperatorState> {
+        Ok(ReadJsonOperatorState {
+            fs: bind_state.fs.clone(),
+            mf_data: bind_state.mf_data.clone(),
+            format: bind_state.format,
+            column_names: bind_state.column_names.clone(),
+            projections,
+        })
+    }
+
+    fn create_pull_partition_states(
+        op_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionState>> {
+        let expanded = op_state.mf_data.expanded();
+
+        // Split files to read across all partitions.
+        let states = (0..partitions)
+            .map(|partition_idx| {
+                let queue: VecDeque<_> = expanded
+                    .iter()
+                    .skip(partition_idx)
+                    .step_by(partitions)
+                    .map(|path| path.to_string())
+                    .collect();
+
+                const READ_BUF_SIZE: usize = 1024 * 1024 * 4; // 4MB
+                let read_buf = vec![0; READ_BUF_SIZE];
+
+                let reader = JsonReader::new(
+                    op_state.column_names.clone(),
+                    op_state.projections.clone(),
+                    read_buf,
+                    JsonDecoder::new(op_state.format),
+                );
+
+                ReadJsonPartitionState {
+                    state: ReadState::Init,
+                    queue,
+                    reader: Box::new(reader),
+                }
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_pull(
+        cx: &mut Context,
+        op_state: &Self::OperatorState,
+        state: &mut Self::PartitionState,
+        output: &mut Batch,
+    ) -> Result<PollPull> {
+        loop {
+            match &mut state.state {
+                ReadState::Init => {
+                    let path = match state.queue.pop_front() {
+                        Some(path) => path,
+                        None => {
+                            // We're done.
+                            output.set_num_rows(0)?;
+                            return Ok(PollPull::Exhausted);
+                        }
+                    };
+
+                    let open_fut = op_state.fs.open_static(OpenFlags::READ, path);
+                    state.state = ReadState::Opening { open_fut };
+                    // Continue...
+                }
+                ReadState::Opening { open_fut } => {
+                    let file = match open_fut.poll_unpin(cx) {
+                        Poll::Ready(Ok(file)) => file,
+                        Poll::Ready(Err(e)) => return Err(e),
+                        Poll::Pending => return Ok(PollPull::Pending),
+                    };
+
+                    state.reader.prepare(file);
+                    state.state = ReadState::Scanning;
+                    // Continue...
+                }
+                ReadState::Scanning => {
+                    let poll = state.reader.poll_pull(cx, output)?;
+                    if poll == PollPull::Exhausted {
+                        // Flip back to init to read the next path from the queue.
+                        state.state = ReadState::Init;
+                        continue;
+                    }
+                    return Ok(poll);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod decoder;
+pub mod extension;
+pub mod functions;
+pub mod reader;
+pub mod schema;
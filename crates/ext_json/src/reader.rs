@@ -0,0 +1,312 @@
+use std::collections::VecDeque;
+use std::task::{Context, Poll};
+
+use glaredb_core::arrays::array::Array;
+use glaredb_core::arrays::array::physical_type::{
+    AddressableMut,
+    MutableScalarStorage,
+    PhysicalBool,
+    PhysicalF64,
+    PhysicalI64,
+    PhysicalUtf8,
+};
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::datatype::DataTypeId;
+use glaredb_core::execution::operators::PollPull;
+use glaredb_core::runtime::filesystem::AnyFile;
+use glaredb_core::runtime::filesystem::file_provider::MultiFileProvider;
+use glaredb_core::storage::projections::{ProjectedColumn, Projections};
+use glaredb_error::{DbError, Result};
+use serde_json::Value;
+
+use crate::decoder::{JsonDecoder, JsonRecord};
+
+#[derive(Debug)]
+pub struct JsonReader {
+    /// Source file.
+    ///
+    /// This may be None if we need to reset the reader to read from a new file.
+    file: Option<AnyFile>,
+    /// Reusable read buffer.
+    read_buf: Vec<u8>,
+    decoder: JsonDecoder,
+    /// Decoded records that haven't been written to a batch yet.
+    records: VecDeque<JsonRecord>,
+    /// Names of all data columns, used to look up values in records.
+    column_names: Vec<String>,
+    projections: Projections,
+    /// If we've reached the end of the file.
+    stream_exhausted: bool,
+    /// Total number of rows we've emitted so far for this file.
+    ///
+    /// Used for row id.
+    current_count: i64,
+}
+
+impl JsonReader {
+    pub fn new(
+        column_names: Vec<String>,
+        projections: Projections,
+        read_buf: Vec<u8>,
+        decoder: JsonDecoder,
+    ) -> Self {
+        JsonReader {
+            file: None,
+            read_buf,
+            decoder,
+            records: VecDeque::new(),
+            column_names,
+            projections,
+            stream_exhausted: false,
+            current_count: 0,
+        }
+    }
+
+    /// Prepares the reader to begin reading from a new file.
+    ///
+    /// Resets internal state as needed. `poll_pull` can immediately be called.
+    pub fn prepare(&mut self, file: AnyFile) {
+        self.file = Some(file);
+        self.decoder.reset();
+        self.records.clear();
+        self.stream_exhausted = false;
+        self.current_count = 0;
+    }
+
+    /// Pulls the next batch by decoding the stream.
+    pub fn poll_pull(&mut self, cx: &mut Context, output: &mut Batch) -> Result<PollPull> {
+        let out_cap = output.write_capacity()?;
+        debug_assert_ne!(0, out_cap);
+
+        let file = match self.file.as_mut() {
+            Some(file) => file,
+            None => {
+                return Err(DbError::new(
+                    "Attempted to pull from JSON reader without preparing a file",
+                ));
+            }
+        };
+
+        loop {
+            if self.records.len() >= out_cap || (self.stream_exhausted && !self.records.is_empty())
+            {
+                let count = usize::min(self.records.len(), out_cap);
+                self.write_batch(output, count)?;
+                self.records.drain(..count);
+                self.current_count += count as i64;
+
+                output.set_num_rows(count)?;
+                return Ok(PollPull::HasMore);
+            }
+
+            if self.stream_exhausted {
+                output.set_num_rows(0)?;
+                return Ok(PollPull::Exhausted);
+            }
+
+            match file.call_poll_read(cx, &mut self.read_buf)? {
+                Poll::Ready(0) => {
+                    self.decoder.finish()?;
+                    self.stream_exhausted = true;
+                }
+                Poll::Ready(n) => {
+                    self.decoder
+                        .decode(&self.read_buf[0..n], &mut self.records)?;
+                }
+                Poll::Pending => return Ok(PollPull::Pending),
+            }
+        }
+    }
+
+    /// Write the first `count` buffered records to the output batch.
+    fn write_batch(&self, batch: &mut Batch, count: usize) -> Result<()> {
+        self.projections
+            .for_each_column(batch, &mut |col_idx, array| match col_idx {
+                ProjectedColumn::Data(col_idx) => {
+                    let name = &self.column_names[col_idx];
+                    match array.datatype().id() {
+                        DataTypeId::Boolean => {
+                            self.write_primitive::<PhysicalBool>(name, array, count, Value::as_bool)
+                        }
+                        DataTypeId::Int64 => {
+                            self.write_primitive::<PhysicalI64>(name, array, count, Value::as_i64)
+                        }
+                        DataTypeId::Float64 => {
+                            self.write_primitive::<PhysicalF64>(name, array, count, Value::as_f64)
+                        }
+                        DataTypeId::Utf8 => self.write_string(name, array, count),
+                        other => Err(DbError::new("Unhandled datatype for JSON scanning")
+                            .with_field("datatype", other)),
+                    }
+                }
+                ProjectedColumn::Metadata(MultiFileProvider::META_PROJECTION_FILENAME) => {
+                    let file = self
+                        .file
+                        .as_ref()
+                        .expect("file to be Some when writing projections");
+
+                    let data = PhysicalUtf8::buffer_downcast_mut(array.data_mut())?;
+                    data.put_duplicated(file.call_path().as_bytes(), 0..count)?;
+
+                    Ok(())
+                }
+                ProjectedColumn::Metadata(MultiFileProvider::META_PROJECTION_ROWID) => {
+                    let data = PhysicalI64::buffer_downcast_mut(array.data_mut())?;
+                    let row_ids = &mut data.as_slice_mut()[0..count];
+                    for (idx, row_id) in row_ids.iter_mut().enumerate() {
+                        *row_id = self.current_count + idx as i64;
+                    }
+
+                    Ok(())
+                }
+                other => panic!("invalid projection: {other:?}"),
+            })
+    }
+
+    fn write_primitive<S>(
+        &self,
+        name: &str,
+        array: &mut Array,
+        count: usize,
+        get: impl Fn(&Value) -> Option<S::StorageType>,
+    ) -> Result<()>
+    where
+        S: MutableScalarStorage,
+        S::StorageType: Sized,
+    {
+        let (data, validity) = array.data_and_validity_mut();
+        let mut output = S::get_addressable_mut(data)?;
+
+        for (idx, record) in self.records.iter().take(count).enumerate() {
+            match record.get(name) {
+                None | Some(Value::Null) => validity.set_invalid(idx),
+                Some(value) => {
+                    let v = get(value).ok_or_else(|| {
+                        DbError::new(format!(
+                            "Failed to read JSON value for '{name}' as {}",
+                            S::PHYSICAL_TYPE
+                        ))
+                        .with_field("value", value.to_string())
+                    })?;
+                    output.put(idx, &v);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write string values.
+    ///
+    /// Non-string values are written as JSON.
+    fn write_string(&self, name: &str, array: &mut Array, count: usize) -> Result<()> {
+        let (data, validity) = array.data_and_validity_mut();
+        let mut output = PhysicalUtf8::get_addressable_mut(data)?;
+
+        for (idx, record) in self.records.iter().take(count).enumerate() {
+            match record.get(name) {
+                None | Some(Value::Null) => validity.set_invalid(idx),
+                Some(Value::String(s)) => output.put(idx, s.as_str()),
+                Some(other) => output.put(idx, other.to_string().as_str()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glaredb_core::arrays::datatype::DataType;
+    use glaredb_core::buffer::buffer_manager::DefaultBufferManager;
+    use glaredb_core::generate_batch;
+    use glaredb_core::runtime::filesystem::memory::MemoryFileHandle;
+    use glaredb_core::testutil::arrays::assert_batches_eq;
+    use glaredb_core::util::task::noop_context;
+
+    use super::*;
+    use crate::decoder::JsonFormat;
+
+    fn make_file(bytes: impl AsRef<[u8]>) -> AnyFile {
+        let file = MemoryFileHandle::from_bytes(&DefaultBufferManager, bytes).unwrap();
+        AnyFile::from_file(file)
+    }
+
+    fn make_reader(format: JsonFormat, read_buf_size: usize) -> JsonReader {
+        JsonReader::new(
+            vec!["name".to_string(), "score".to_string(), "tags".to_string()],
+            Projections::new([0, 1, 2]),
+            vec![0; read_buf_size],
+            JsonDecoder::new(format),
+        )
+    }
+
+    #[test]
+    fn newline_delimited_complete_read() {
+        let input = r#"{"name": "mario", "score": 8000, "tags": ["red"]}
+{"name": "wario", "tags": null}
+{"score": 10000, "name": "yoshi", "extra": 1}
+"#;
+        for read_buf_size in [8, 256] {
+            let mut reader = make_reader(JsonFormat::NewlineDelimited, read_buf_size);
+            reader.prepare(make_file(input));
+
+            let mut batch =
+                Batch::new([DataType::utf8(), DataType::int64(), DataType::utf8()], 16).unwrap();
+            let poll = reader.poll_pull(&mut noop_context(), &mut batch).unwrap();
+            assert_eq!(PollPull::HasMore, poll);
+
+            let expected = generate_batch!(
+                ["mario", "wario", "yoshi"],
+                [Some(8000_i64), None, Some(10000)],
+                [Some(r#"["red"]"#), None, None]
+            );
+            assert_batches_eq(&expected, &batch);
+
+            let poll = reader.poll_pull(&mut noop_context(), &mut batch).unwrap();
+            assert_eq!(PollPull::Exhausted, poll);
+            assert_eq!(0, batch.num_rows());
+        }
+    }
+
+    #[test]
+    fn array_small_output_batch() {
+        let input = r#"[
+  {"name": "mario", "score": 8000, "tags": "a"},
+  {"name": "wario", "score": 950, "tags": "b"},
+  {"name": "yoshi", "score": 10000, "tags": "c"}
+]"#;
+        let mut reader = make_reader(JsonFormat::Array, 256);
+        reader.prepare(make_file(input));
+
+        let mut batch =
+            Batch::new([DataType::utf8(), DataType::int64(), DataType::utf8()], 2).unwrap();
+        let poll = reader.poll_pull(&mut noop_context(), &mut batch).unwrap();
+        assert_eq!(PollPull::HasMore, poll);
+
+        let expected = generate_batch!(["mario", "wario"], [8000_i64, 950], ["a", "b"]);
+        assert_batches_eq(&expected, &batch);
+
+        let poll = reader.poll_pull(&mut noop_context(), &mut batch).unwrap();
+        assert_eq!(PollPull::HasMore, poll);
+
+        let expected = generate_batch!(["yoshi"], [10000_i64], ["c"]);
+        assert_batches_eq(&expected, &batch);
+
+        let poll = reader.poll_pull(&mut noop_context(), &mut batch).unwrap();
+        assert_eq!(PollPull::Exhausted, poll);
+    }
+
+    #[test]
+    fn type_mismatch_errors() {
+        let input = r#"{"name": "mario", "score": "lots", "tags": null}"#;
+        let mut reader = make_reader(JsonFormat::NewlineDelimited, 256);
+        reader.prepare(make_file(input));
+
+        let mut batch =
+            Batch::new([DataType::utf8(), DataType::int64(), DataType::utf8()], 16).unwrap();
+        reader
+            .poll_pull(&mut noop_context(), &mut batch)
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,156 @@
+use glaredb_core::arrays::datatype::DataType;
+use glaredb_core::arrays::field::{ColumnSchema, Field};
+use glaredb_error::{DbError, Result};
+use serde_json::Value;
+
+use crate::decoder::JsonRecord;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonSchema {
+    /// All fields found in the sampled records.
+    pub schema: ColumnSchema,
+}
+
+impl JsonSchema {
+    /// Try to infer the schema from some number of records.
+    ///
+    /// Columns are ordered by when their key was first seen. Nested objects
+    /// and arrays are read as JSON strings.
+    pub fn infer_from_records<'a>(
+        records: impl IntoIterator<Item = &'a JsonRecord>,
+    ) -> Result<Self> {
+        let mut columns: Vec<(&str, CandidateType)> = Vec::new();
+        let mut num_records = 0;
+
+        for record in records {
+            num_records += 1;
+            for (key, value) in record {
+                let candidate = CandidateType::from_value(value);
+                match columns.iter_mut().find(|(name, _)| *name == key) {
+                    Some((_, existing)) => *existing = existing.merge(candidate),
+                    None => columns.push((key, candidate)),
+                }
+            }
+        }
+
+        if num_records == 0 {
+            return Err(DbError::new("Unable to infer JSON schema with no records"));
+        }
+        if columns.is_empty() {
+            return Err(DbError::new(
+                "Unable to infer JSON schema, records contain no fields",
+            ));
+        }
+
+        let fields = columns.into_iter().map(|(name, candidate)| Field {
+            name: name.to_string(),
+            datatype: candidate.as_datatype(),
+            nullable: true,
+        });
+
+        Ok(JsonSchema {
+            schema: ColumnSchema::new(fields),
+        })
+    }
+}
+
+/// Candidate types used when trying to infer the types for a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CandidateType {
+    /// Only nulls seen so far, no type information.
+    Null,
+    Boolean,
+    Int64,
+    Float64,
+    /// Strings, or values with mixed types. Able to encompass any value.
+    Utf8,
+}
+
+impl CandidateType {
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(_) => Self::Boolean,
+            Value::Number(n) if n.is_i64() => Self::Int64,
+            Value::Number(_) => Self::Float64,
+            Value::String(_) | Value::Array(_) | Value::Object(_) => Self::Utf8,
+        }
+    }
+
+    /// Get a type that can hold values for both candidates.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Self::Null, other) | (other, Self::Null) => other,
+            (Self::Int64, Self::Float64) | (Self::Float64, Self::Int64) => Self::Float64,
+            _ => Self::Utf8,
+        }
+    }
+
+    const fn as_datatype(&self) -> DataType {
+        match self {
+            Self::Boolean => DataType::boolean(),
+            Self::Int64 => DataType::int64(),
+            Self::Float64 => DataType::float64(),
+            Self::Null | Self::Utf8 => DataType::utf8(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn make_records(values: impl IntoIterator<Item = Value>) -> Vec<JsonRecord> {
+        values
+            .into_iter()
+            .map(|v| match v {
+                Value::Object(obj) => obj,
+                other => panic!("not an object: {other}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn infer_primitives() {
+        let records = make_records([
+            json!({"a": 1, "b": "x", "c": true, "d": 1.5}),
+            json!({"a": 2, "b": "y", "c": false, "d": 2}),
+        ]);
+
+        let schema = JsonSchema::infer_from_records(&records).unwrap();
+        let expected = ColumnSchema::new([
+            Field::new("a", DataType::int64(), true),
+            Field::new("b", DataType::utf8(), true),
+            Field::new("c", DataType::boolean(), true),
+            Field::new("d", DataType::float64(), true),
+        ]);
+        assert_eq!(expected, schema.schema);
+    }
+
+    #[test]
+    fn infer_missing_and_null_fields() {
+        let records = make_records([
+            json!({"a": null, "b": 1}),
+            json!({"c": {"nested": true}}),
+            json!({"a": 3, "b": "mixed", "d": null}),
+        ]);
+
+        let schema = JsonSchema::infer_from_records(&records).unwrap();
+        let expected = ColumnSchema::new([
+            Field::new("a", DataType::int64(), true),
+            Field::new("b", DataType::utf8(), true),
+            Field::new("c", DataType::utf8(), true),
+            Field::new("d", DataType::utf8(), true),
+        ]);
+        assert_eq!(expected, schema.schema);
+    }
+
+    #[test]
+    fn infer_no_records() {
+        JsonSchema::infer_from_records(&[]).unwrap_err();
+        JsonSchema::infer_from_records(&make_records([json!({})])).unwrap_err();
+    }
+}
@@ -21,29 +21,63 @@ pub trait ColumnReader: Debug + Sync + Send {
     /// Reads `count` values into the output array.
     fn read(&mut self, output: &mut Array, count: usize) -> Result<()>;
 
+    /// Reads up to `count` level entries from the current page into `output`
+    /// starting at index 0, returning the levels for the entries read.
+    ///
+    /// Unlike `read`, this doesn't assume one entry per row, and is used for
+    /// reading leaves of nested types where repetition levels determine row
+    /// boundaries.
+    ///
+    /// Returns empty levels once the column chunk has been exhausted.
+    fn read_entries(&mut self, output: &mut Array, count: usize) -> Result<Levels<'_>>;
+
     /// Prepares this reader for reading from a new "scan unit" by updating the
-    /// column descriptor to use.
+    /// column descriptors to use, one per leaf.
     ///
     /// Errors if the physical type of the new descriptor does not match the
     /// physical type of the existing descriptor.
-    fn prepare_scan_unit(&mut self, descr: ColumnDescriptor) -> Result<()>;
+    fn prepare_scan_unit(&mut self, descrs: &[ColumnDescriptor]) -> Result<()>;
 
-    /// Prepares this reader for the next chunk.
+    /// Prepares this reader for the next chunk for a leaf.
     ///
     /// The underlying chunk buffers should be resized to accomadate the new
     /// chunk.
-    fn prepare_for_chunk(&mut self, chunk_size: usize, compression: Compression) -> Result<()>;
+    fn prepare_for_chunk(
+        &mut self,
+        leaf: usize,
+        chunk_size: usize,
+        compression: Compression,
+    ) -> Result<()>;
 
-    /// Return a mutable reference to the chunk buffer.
+    /// Return a mutable reference to the chunk buffer for a leaf.
     ///
     /// This buffer will be used for reading the column chunk from the file
     /// directly.
-    fn chunk_buf_mut(&mut self) -> &mut [u8];
+    fn chunk_buf_mut(&mut self, leaf: usize) -> &mut [u8];
 
     /// See if we can prune a row group based on these column statistics.
     fn should_prune(&self, stats: &Statistics) -> Result<bool>;
 }
 
+/// Definition and repetition levels for entries read from a leaf column.
+#[derive(Debug)]
+pub struct Levels<'a> {
+    pub definitions: &'a [i16],
+    pub repetitions: &'a [i16],
+    /// Max definition level for the leaf. Entries at this level have a value.
+    pub max_def: i16,
+}
+
+impl Levels<'_> {
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+}
+
 #[derive(Debug)]
 pub struct ValueColumnReader<V: ValueReader, F: RowGroupPruner<V::PlainType>> {
     /// Filter used for pruning row groups.
@@ -78,12 +112,63 @@ where
     }
 }
 
+impl<V, F> ValueColumnReader<V, F>
+where
+    V: ValueReader,
+    F: RowGroupPruner<V::PlainType>,
+{
+    /// Decode `count` values from the current page into `output` starting at
+    /// `offset`.
+    fn read_page_values(
+        page_reader: &mut PageReader<V>,
+        definitions: Definitions,
+        output: &mut Array,
+        offset: usize,
+        count: usize,
+    ) -> Result<()> {
+        let decoder = match page_reader.state.page_decoder.as_mut() {
+            Some(decoder) => decoder,
+            None => return Err(DbError::new("Missing page decoder")),
+        };
+        match decoder {
+            PageDecoder::Plain(dec) => dec.read_plain(definitions, output, offset, count)?,
+            PageDecoder::Dictionary(dec) => dec.read(
+                &page_reader.state.dictionary,
+                definitions,
+                output,
+                offset,
+                count,
+            )?,
+            PageDecoder::DeltaBinaryPackedI32(dec) => {
+                dec.read(definitions, output, offset, count)?
+            }
+            PageDecoder::DeltaBinaryPackedI64(dec) => {
+                dec.read(definitions, output, offset, count)?
+            }
+            PageDecoder::DeltaLengthByteArray(dec) => {
+                dec.read(definitions, output, offset, count)?
+            }
+            PageDecoder::DeltaByteArray(dec) => dec.read(definitions, output, offset, count)?,
+            PageDecoder::RleBool(dec) => dec.read(definitions, output, offset, count)?,
+            PageDecoder::ByteStreamSplit4(dec) => dec.read(definitions, output, offset, count)?,
+            PageDecoder::ByteStreamSplit8(dec) => dec.read(definitions, output, offset, count)?,
+        }
+
+        Ok(())
+    }
+}
+
 impl<V, F> ColumnReader for ValueColumnReader<V, F>
 where
     V: ValueReader,
     F: RowGroupPruner<V::PlainType>,
 {
-    fn prepare_for_chunk(&mut self, chunk_size: usize, compression: Compression) -> Result<()> {
+    fn prepare_for_chunk(
+        &mut self,
+        _leaf: usize,
+        chunk_size: usize,
+        compression: Compression,
+    ) -> Result<()> {
         self.page_reader.chunk_offset = 0;
         // SAFETY: We'll be writing `chunk_size` bytes to this chunks before
         // reading it.
@@ -92,11 +177,17 @@ where
         Ok(())
     }
 
-    fn prepare_scan_unit(&mut self, descr: ColumnDescriptor) -> Result<()> {
-        self.page_reader.prepare_scan_unit(descr)
+    fn prepare_scan_unit(&mut self, descrs: &[ColumnDescriptor]) -> Result<()> {
+        match descrs {
+            [descr] => self.page_reader.prepare_scan_unit(descr.clone()),
+            _ => Err(
+                DbError::new("Expected a single column descriptor for value reader")
+                    .with_field("len", descrs.len()),
+            ),
+        }
     }
 
-    fn chunk_buf_mut(&mut self) -> &mut [u8] {
+    fn chunk_buf_mut(&mut self, _leaf: usize) -> &mut [u8] {
         self.page_reader.chunk.as_slice_mut()
     }
 
@@ -139,37 +230,7 @@ where
             };
 
             // Read the actual data.
-            let decoder = match self.page_reader.state.page_decoder.as_mut() {
-                Some(decoder) => decoder,
-                None => return Err(DbError::new("Missing page decoder")),
-            };
-            match decoder {
-                PageDecoder::Plain(dec) => dec.read_plain(definitions, output, offset, count)?,
-                PageDecoder::Dictionary(dec) => dec.read(
-                    &self.page_reader.state.dictionary,
-                    definitions,
-                    output,
-                    offset,
-                    count,
-                )?,
-                PageDecoder::DeltaBinaryPackedI32(dec) => {
-                    dec.read(definitions, output, offset, count)?
-                }
-                PageDecoder::DeltaBinaryPackedI64(dec) => {
-                    dec.read(definitions, output, offset, count)?
-                }
-                PageDecoder::DeltaLengthByteArray(dec) => {
-                    dec.read(definitions, output, offset, count)?
-                }
-                PageDecoder::DeltaByteArray(dec) => dec.read(definitions, output, offset, count)?,
-                PageDecoder::RleBool(dec) => dec.read(definitions, output, offset, count)?,
-                PageDecoder::ByteStreamSplit4(dec) => {
-                    dec.read(definitions, output, offset, count)?
-                }
-                PageDecoder::ByteStreamSplit8(dec) => {
-                    dec.read(definitions, output, offset, count)?
-                }
-            }
+            Self::read_page_values(&mut self.page_reader, definitions, output, offset, count)?;
 
             // Update page reader state.
             self.page_reader.state.remaining_page_values -= count;
@@ -181,6 +242,50 @@ where
         Ok(())
     }
 
+    fn read_entries(&mut self, output: &mut Array, count: usize) -> Result<Levels<'_>> {
+        // Skip over pages without values (e.g. dictionary pages) until we
+        // either have values to read or have exhausted the chunk.
+        while self.page_reader.state.remaining_page_values == 0 {
+            if self.page_reader.chunk_offset >= self.page_reader.chunk.len() {
+                return Ok(Levels {
+                    definitions: &[],
+                    repetitions: &[],
+                    max_def: self.page_reader.descr.max_def_level,
+                });
+            }
+            self.page_reader.prepare_next()?;
+        }
+
+        let count = usize::min(count, self.page_reader.state.remaining_page_values);
+        let max_def = self.page_reader.descr.max_def_level;
+
+        self.definitions.clear();
+        self.definitions.resize(count, max_def);
+        self.repetitions.clear();
+        self.repetitions.resize(count, 0);
+
+        self.page_reader
+            .read_levels(&mut self.definitions, &mut self.repetitions)?;
+
+        let definitions = if self.page_reader.state.definitions.is_some() {
+            Definitions::HasDefinitions {
+                levels: &self.definitions,
+                max: max_def,
+            }
+        } else {
+            Definitions::NoDefinitions
+        };
+
+        Self::read_page_values(&mut self.page_reader, definitions, output, 0, count)?;
+        self.page_reader.state.remaining_page_values -= count;
+
+        Ok(Levels {
+            definitions: &self.definitions,
+            repetitions: &self.repetitions,
+            max_def,
+        })
+    }
+
     fn should_prune(&self, stats: &Statistics) -> Result<bool> {
         let stats = <V::PlainType as PlainType>::statistics(stats)
             .ok_or_else(|| DbError::new("Unexpected column stats"))?;
@@ -0,0 +1,295 @@
+use glaredb_core::arrays::array::Array;
+use glaredb_core::arrays::compute::copy::copy_rows_array;
+use glaredb_core::arrays::compute::make_map::append_map_entries;
+use glaredb_core::arrays::datatype::DataType;
+use glaredb_core::buffer::buffer_manager::{AsRawBufferManager, RawBufferManager};
+use glaredb_error::{DbError, Result};
+
+use super::column_reader::{ColumnReader, Levels};
+use super::struct_reader::new_column_reader;
+use crate::basic::Compression;
+use crate::metadata::statistics::Statistics;
+use crate::schema::types::ColumnDescriptor;
+
+/// Number of level entries to decode from a leaf at a time.
+const LEAF_READ_SIZE: usize = 2048;
+
+/// Reads a Parquet MAP column.
+///
+/// Maps are stored as two leaf columns, one for keys and one for values. Each
+/// leaf contains one level entry per map entry (or one entry for a NULL or
+/// empty map), with a repetition level of zero indicating the start of a new
+/// row.
+///
+/// Only top-level maps are supported.
+#[derive(Debug)]
+pub struct MapColumnReader {
+    keys: LeafEntries,
+    values: LeafEntries,
+}
+
+impl MapColumnReader {
+    pub fn try_new(
+        manager: &impl AsRawBufferManager,
+        datatype: DataType,
+        key_descr: ColumnDescriptor,
+        value_descr: ColumnDescriptor,
+    ) -> Result<Self> {
+        let meta = datatype.try_get_map_type_meta()?;
+        let manager = manager.as_raw_buffer_manager();
+
+        let keys = LeafEntries::try_new(manager, meta.key.as_ref().clone(), key_descr)?;
+        let values = LeafEntries::try_new(manager, meta.value.as_ref().clone(), value_descr)?;
+
+        Ok(MapColumnReader { keys, values })
+    }
+
+    fn leaf_mut(&mut self, leaf: usize) -> &mut LeafEntries {
+        if leaf == 0 {
+            &mut self.keys
+        } else {
+            &mut self.values
+        }
+    }
+}
+
+impl ColumnReader for MapColumnReader {
+    fn read(&mut self, output: &mut Array, count: usize) -> Result<()> {
+        self.keys.reset_builder()?;
+        self.values.reset_builder()?;
+
+        let mut rows = Vec::with_capacity(count);
+        let mut num_entries = 0;
+
+        for row_idx in 0..count {
+            let (def, rep) = match self.keys.peek()? {
+                Some(levels) => levels,
+                None => {
+                    return Err(
+                        DbError::new("Reached end of map column chunk, expected more")
+                            .with_field("row_idx", row_idx),
+                    );
+                }
+            };
+            if rep != 0 {
+                return Err(DbError::new("Expected map entry to start a new row")
+                    .with_field("repetition", rep));
+            }
+
+            // Key leaf is required within the key_value group, so its max
+            // definition level indicates the entry exists. One less than that
+            // is an empty map, anything lower is a NULL map.
+            let entry_def = self.keys.max_def;
+            if def < entry_def {
+                self.keys.consume(None);
+                self.values.consume_after_peek(None)?;
+
+                let entries = (def == entry_def - 1).then_some(num_entries..num_entries);
+                rows.push((row_idx, entries));
+                continue;
+            }
+
+            let start = num_entries;
+            loop {
+                self.keys.consume(Some(num_entries));
+                self.values.consume_after_peek(Some(num_entries))?;
+                num_entries += 1;
+
+                match self.keys.peek()? {
+                    Some((def, rep)) if rep != 0 && def >= entry_def => continue,
+                    Some((def, rep)) if rep != 0 => {
+                        return Err(DbError::new("Unexpected definition level for map entry")
+                            .with_field("definition", def));
+                    }
+                    _ => break,
+                }
+            }
+
+            rows.push((row_idx, Some(start..num_entries)));
+        }
+
+        self.keys.flush()?;
+        self.values.flush()?;
+
+        append_map_entries(&self.keys.builder, &self.values.builder, rows, output)
+    }
+
+    fn read_entries(&mut self, _output: &mut Array, _count: usize) -> Result<Levels<'_>> {
+        Err(DbError::new("Reading nested map entries not supported"))
+    }
+
+    fn prepare_scan_unit(&mut self, descrs: &[ColumnDescriptor]) -> Result<()> {
+        match descrs {
+            [key_descr, value_descr] => {
+                self.keys
+                    .reader
+                    .prepare_scan_unit(std::slice::from_ref(key_descr))?;
+                self.values
+                    .reader
+                    .prepare_scan_unit(std::slice::from_ref(value_descr))?;
+                Ok(())
+            }
+            _ => Err(
+                DbError::new("Expected two column descriptors for map reader")
+                    .with_field("len", descrs.len()),
+            ),
+        }
+    }
+
+    fn prepare_for_chunk(
+        &mut self,
+        leaf: usize,
+        chunk_size: usize,
+        compression: Compression,
+    ) -> Result<()> {
+        let leaf = self.leaf_mut(leaf);
+        leaf.clear_levels();
+        leaf.reader.prepare_for_chunk(0, chunk_size, compression)
+    }
+
+    fn chunk_buf_mut(&mut self, leaf: usize) -> &mut [u8] {
+        self.leaf_mut(leaf).reader.chunk_buf_mut(0)
+    }
+
+    fn should_prune(&self, _stats: &Statistics) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+/// Decoded entries for a single leaf of a map.
+#[derive(Debug)]
+struct LeafEntries {
+    manager: RawBufferManager,
+    datatype: DataType,
+    reader: Box<dyn ColumnReader>,
+    /// Entries decoded from the current page.
+    entries: Array,
+    definitions: Vec<i16>,
+    repetitions: Vec<i16>,
+    max_def: i16,
+    /// Position of the next entry to consume.
+    pos: usize,
+    /// Pending (entry, builder) copies for entries that have been consumed.
+    pending: Vec<(usize, usize)>,
+    /// Entries for the maps being produced by the current read.
+    builder: Array,
+    builder_capacity: usize,
+}
+
+impl LeafEntries {
+    fn try_new(
+        manager: RawBufferManager,
+        datatype: DataType,
+        descr: ColumnDescriptor,
+    ) -> Result<Self> {
+        let max_def = descr.max_def_level;
+        let reader = new_column_reader(&manager, datatype.clone(), descr, std::iter::empty())?;
+        let entries = Array::new(&manager, datatype.clone(), 0)?;
+        let builder = Array::new(&manager, datatype.clone(), 0)?;
+
+        Ok(LeafEntries {
+            manager,
+            datatype,
+            reader,
+            entries,
+            definitions: Vec::new(),
+            repetitions: Vec::new(),
+            max_def,
+            pos: 0,
+            pending: Vec::new(),
+            builder,
+            builder_capacity: 0,
+        })
+    }
+
+    /// Clear out buffered levels when starting a new chunk.
+    fn clear_levels(&mut self) {
+        self.definitions.clear();
+        self.repetitions.clear();
+        self.pending.clear();
+        self.pos = 0;
+    }
+
+    fn reset_builder(&mut self) -> Result<()> {
+        self.builder = Array::new(&self.manager, self.datatype.clone(), self.builder_capacity)?;
+        Ok(())
+    }
+
+    /// Returns the (definition, repetition) levels for the next entry,
+    /// decoding more entries if needed.
+    ///
+    /// Returns `None` if the column chunk has been exhausted.
+    fn peek(&mut self) -> Result<Option<(i16, i16)>> {
+        if self.pos == self.definitions.len() {
+            self.flush()?;
+
+            self.entries = Array::new(&self.manager, self.datatype.clone(), LEAF_READ_SIZE)?;
+            let levels = self
+                .reader
+                .read_entries(&mut self.entries, LEAF_READ_SIZE)?;
+            self.max_def = levels.max_def;
+            self.definitions.clear();
+            self.definitions.extend_from_slice(levels.definitions);
+            self.repetitions.clear();
+            self.repetitions.extend_from_slice(levels.repetitions);
+            self.pos = 0;
+
+            if self.definitions.is_empty() {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some((
+            self.definitions[self.pos],
+            self.repetitions[self.pos],
+        )))
+    }
+
+    /// Consume the current entry, optionally copying it into the builder at
+    /// the given index.
+    ///
+    /// `peek` must have been called prior to this.
+    fn consume(&mut self, builder_idx: Option<usize>) {
+        if let Some(builder_idx) = builder_idx {
+            self.pending.push((self.pos, builder_idx));
+        }
+        self.pos += 1;
+    }
+
+    /// Peek then consume the current entry.
+    ///
+    /// Errors if there's no entry to consume.
+    fn consume_after_peek(&mut self, builder_idx: Option<usize>) -> Result<()> {
+        if self.peek()?.is_none() {
+            return Err(DbError::new(
+                "Map values column has fewer entries than keys",
+            ));
+        }
+        self.consume(builder_idx);
+        Ok(())
+    }
+
+    /// Copy pending entries into the builder, growing it as needed.
+    fn flush(&mut self) -> Result<()> {
+        let required = match self.pending.last() {
+            Some(&(_, builder_idx)) => builder_idx + 1,
+            None => return Ok(()),
+        };
+
+        if required > self.builder_capacity {
+            let new_capacity = usize::max(required, self.builder_capacity * 2);
+            let mut builder = Array::new(&self.manager, self.datatype.clone(), new_capacity)?;
+            // Only entries up to the first pending entry have been written.
+            let written = self.pending[0].1;
+            copy_rows_array(
+                &self.builder,
+                (0..written).map(|idx| (idx, idx)),
+                &mut builder,
+            )?;
+            self.builder = builder;
+            self.builder_capacity = new_capacity;
+        }
+
+        copy_rows_array(&self.entries, self.pending.drain(..), &mut self.builder)
+    }
+}
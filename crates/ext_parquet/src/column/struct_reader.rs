@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use glaredb_core::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use glaredb_core::arrays::field::ColumnSchema;
 use glaredb_core::arrays::scalar::unwrap::{
@@ -13,11 +15,13 @@ use glaredb_core::arrays::scalar::unwrap::{
 use glaredb_core::buffer::buffer_manager::AsRawBufferManager;
 use glaredb_core::storage::projections::{ProjectedColumn, Projections};
 use glaredb_core::storage::scan_filter::PhysicalScanFilter;
-use glaredb_error::{Result, not_implemented};
+use glaredb_error::{DbError, Result, not_implemented};
 
 use super::column_reader::{ColumnReader, ValueColumnReader};
+use super::map_reader::MapColumnReader;
 use super::row_group_pruner::{NopRowGroupPruner, PrimitiveRowGroupPruner};
 use super::value_reader::bool::BoolValueReader;
+use super::value_reader::decimal::Decimal128ValueReader;
 use super::value_reader::int96::Int96TsReader;
 use super::value_reader::primitive::{
     CastingInt32ToInt8Reader,
@@ -35,6 +39,7 @@ use super::value_reader::primitive::{
     PlainTsMicrosValueReader,
     PlainTsNsValueReader,
 };
+use super::value_reader::uuid::UuidValueReader;
 use super::value_reader::varlen::{BinaryValueReader, Utf8ValueReader};
 use crate::basic;
 use crate::metadata::RowGroupMetaData;
@@ -43,6 +48,11 @@ use crate::schema::types::{ColumnDescriptor, SchemaDescriptor};
 #[derive(Debug)]
 pub struct StructReader {
     pub(crate) readers: Vec<Box<dyn ColumnReader>>,
+    /// Indices of the leaf columns for each reader.
+    ///
+    /// Most readers read from a single leaf, nested types (e.g. maps) read
+    /// from multiple.
+    pub(crate) leaves: Vec<Range<usize>>,
 }
 
 impl StructReader {
@@ -57,25 +67,47 @@ impl StructReader {
         parquet_schema: &SchemaDescriptor,
         filters: &[PhysicalScanFilter],
     ) -> Result<Self> {
+        let leaves: Vec<_> = projections
+            .data_indices()
+            .iter()
+            .map(|&col_idx| parquet_schema.leaves_for_root(col_idx))
+            .collect();
+
         let readers = projections
             .data_indices()
             .iter()
-            .map(|&col_idx| {
+            .zip(&leaves)
+            .map(|(&col_idx, leaves)| {
+                let datatype = column_schema.fields[col_idx].datatype.clone();
+
+                if datatype.id() == DataTypeId::Map {
+                    return match &parquet_schema.leaves[leaves.clone()] {
+                        [key_descr, value_descr] => {
+                            let reader = MapColumnReader::try_new(
+                                manager,
+                                datatype,
+                                key_descr.clone(),
+                                value_descr.clone(),
+                            )?;
+                            Ok(Box::new(reader) as _)
+                        }
+                        other => Err(DbError::new("Expected two leaf columns for map")
+                            .with_field("num_leaves", other.len())),
+                    };
+                }
+
                 // Get the filters to apply to just this column.
                 let filters = filters.iter().filter(|filter| {
                     filter.columns.len() == 1
                         && filter.columns.contains(&ProjectedColumn::Data(col_idx))
                 });
 
-                // TODO: I'll fix this later, we're just assuming a flat schema
-                // right now.
-                let col_descr = parquet_schema.leaves[col_idx].clone();
-                let datatype = column_schema.fields[col_idx].datatype.clone();
+                let col_descr = single_leaf(parquet_schema, leaves)?.clone();
                 new_column_reader(manager, datatype, col_descr, filters)
             })
             .collect::<Result<Vec<_>>>()?;
 
-        Ok(StructReader { readers })
+        Ok(StructReader { readers, leaves })
     }
 
     pub fn prepare_scan_unit(
@@ -87,8 +119,9 @@ impl StructReader {
 
         for (idx, reader) in self.readers.iter_mut().enumerate() {
             let col_idx = projections.data_indices()[idx];
-            let col_descr = parquet_schema.leaves[col_idx].clone();
-            reader.prepare_scan_unit(col_descr)?;
+            let leaves = parquet_schema.leaves_for_root(col_idx);
+            reader.prepare_scan_unit(&parquet_schema.leaves[leaves.clone()])?;
+            self.leaves[idx] = leaves;
         }
 
         Ok(())
@@ -107,8 +140,12 @@ impl StructReader {
     ) -> Result<bool> {
         debug_assert_eq!(projections.data_indices().len(), self.readers.len());
 
-        for (&proj, col_reader) in projections.data_indices().iter().zip(&self.readers) {
-            let col_meta = &row_group.columns[proj];
+        for (leaves, col_reader) in self.leaves.iter().zip(&self.readers) {
+            // Only prune using stats for single leaf columns.
+            if leaves.len() != 1 {
+                continue;
+            }
+            let col_meta = &row_group.columns[leaves.start];
             if let Some(stats) = &col_meta.statistics {
                 if col_reader.should_prune(stats)? {
                     return Ok(true);
@@ -120,6 +157,20 @@ impl StructReader {
     }
 }
 
+/// Get the descriptor for a column that should only be made up of a single
+/// leaf.
+fn single_leaf<'a>(
+    parquet_schema: &'a SchemaDescriptor,
+    leaves: &Range<usize>,
+) -> Result<&'a ColumnDescriptor> {
+    match &parquet_schema.leaves[leaves.clone()] {
+        [descr] => Ok(descr),
+        other => {
+            Err(DbError::new("Expected a single leaf column").with_field("num_leaves", other.len()))
+        }
+    }
+}
+
 /// Create a new boxed column reader.
 pub(crate) fn new_column_reader<'a>(
     manager: &impl AsRawBufferManager,
@@ -229,6 +280,23 @@ pub(crate) fn new_column_reader<'a>(
             )?),
             other => not_implemented!("decimal64 reader for physical type: {other:?}"),
         },
+        DataTypeId::Decimal128 => match (descr.physical_type(), descr.type_length()) {
+            (basic::Type::FIXED_LEN_BYTE_ARRAY, 16) => {
+                Box::new(ValueColumnReader::<Decimal128ValueReader, _>::try_new(
+                    manager,
+                    datatype,
+                    descr,
+                    NopRowGroupPruner::default(),
+                )?)
+            }
+            other => not_implemented!("decimal128 reader for physical type: {other:?}"),
+        },
+        DataTypeId::Time => Box::new(ValueColumnReader::<PlainInt64ValueReader, _>::try_new(
+            manager,
+            datatype,
+            descr,
+            NopRowGroupPruner::default(),
+        )?),
         DataTypeId::Timestamp => {
             let m = datatype.try_get_timestamp_type_meta()?;
             match (m.unit, descr.physical_type()) {
@@ -271,6 +339,12 @@ pub(crate) fn new_column_reader<'a>(
             descr,
             NopRowGroupPruner::default(),
         )?),
+        DataTypeId::Uuid => Box::new(ValueColumnReader::<UuidValueReader, _>::try_new(
+            manager,
+            datatype,
+            descr,
+            NopRowGroupPruner::default(),
+        )?),
         other => not_implemented!("create parquet column reader for data type: {other}"),
     })
 }
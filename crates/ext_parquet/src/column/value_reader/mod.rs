@@ -1,6 +1,8 @@
 pub mod bool;
+pub mod decimal;
 pub mod int96;
 pub mod primitive;
+pub mod uuid;
 pub mod varlen;
 
 use std::fmt::Debug;
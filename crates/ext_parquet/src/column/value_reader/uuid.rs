@@ -0,0 +1,42 @@
+use glaredb_core::arrays::array::physical_type::{
+    AddressableMut,
+    MutableScalarStorage,
+    PhysicalU128,
+};
+
+use super::{ReaderErrorState, ValueReader};
+use crate::column::read_buffer::ReadCursor;
+use crate::column::row_group_pruner::PlainTypeFixedLenByteArray;
+
+/// Interprets a FIXED_LEN_BYTE_ARRAY(16) as a UUID.
+///
+/// UUID bytes are stored in big-endian order, which is also how we store the
+/// u128.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidValueReader;
+
+impl ValueReader for UuidValueReader {
+    type Storage = PhysicalU128;
+    type PlainType = PlainTypeFixedLenByteArray;
+
+    unsafe fn read_next_unchecked(
+        &mut self,
+        data: &mut ReadCursor,
+        out_idx: usize,
+        out: &mut <Self::Storage as MutableScalarStorage>::AddressableMut<'_>,
+        _error_state: &mut ReaderErrorState,
+    ) {
+        let bytes = unsafe { data.read_next_unchecked::<[u8; 16]>() };
+        out.put(out_idx, &u128::from_be_bytes(bytes));
+    }
+
+    unsafe fn skip_unchecked(
+        &mut self,
+        data: &mut ReadCursor,
+        _error_state: &mut ReaderErrorState,
+    ) {
+        unsafe {
+            data.skip_bytes_unchecked(16);
+        }
+    }
+}
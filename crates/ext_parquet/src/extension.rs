@@ -1,6 +1,7 @@
-use glaredb_core::catalog::create::FileInferScan;
-use glaredb_core::extension::{Extension, ExtensionTableFunction};
+use glaredb_core::catalog::create::{FileInferCopyTo, FileInferScan};
+use glaredb_core::extension::{Extension, ExtensionCopyToFunction, ExtensionTableFunction};
 
+use crate::functions::copy_to::FUNCTION_SET_COPY_TO_PARQUET;
 use crate::functions::metadata::{
     FUNCTION_SET_PARQUET_COLUMN_METADATA,
     FUNCTION_SET_PARQUET_FILE_METADATA,
@@ -32,4 +33,15 @@ impl Extension for ParquetExtension {
 
         FUNCTIONS
     }
+
+    fn copy_to_functions(&self) -> &[ExtensionCopyToFunction] {
+        const FUNCTIONS: &[ExtensionCopyToFunction] = &[ExtensionCopyToFunction {
+            infer_copy_to: Some(FileInferCopyTo {
+                can_handle: |path| path.ends_with(".parquet"),
+            }),
+            function: &FUNCTION_SET_COPY_TO_PARQUET,
+        }];
+
+        FUNCTIONS
+    }
 }
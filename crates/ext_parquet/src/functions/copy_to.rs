@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_core::functions::copy_to::{
+    CopyToFunction,
+    CopyToFunctionSet,
+    CopyToOptions,
+    RawCopyToFunction,
+};
+use glaredb_error::{DbError, Result};
+
+use crate::basic::{BrotliLevel, Compression, GzipLevel, ZstdLevel};
+use crate::metadata::properties::WriterProperties;
+use crate::writer::ParquetWriter;
+
+pub const FUNCTION_SET_COPY_TO_PARQUET: CopyToFunctionSet = CopyToFunctionSet {
+    name: "parquet",
+    function: RawCopyToFunction::new(&CopyToParquet),
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct CopyToParquet;
+
+impl CopyToFunction for CopyToParquet {
+    type BindState = Arc<WriterProperties>;
+    type Sink = ParquetWriter;
+
+    fn bind(&self, schema: &ColumnSchema, options: &CopyToOptions) -> Result<Self::BindState> {
+        let mut builder = WriterProperties::builder();
+
+        if let Some(compression) = options.get_str("compression")? {
+            builder = builder.set_compression(parse_compression(compression)?);
+        }
+        if let Some(row_group_size) = options.get_usize("row_group_size")? {
+            if row_group_size == 0 {
+                return Err(DbError::new("Row group size must be greater than zero"));
+            }
+            builder = builder.set_max_row_group_size(row_group_size);
+        }
+        if let Some(dictionary) = options.get_bool("dictionary")? {
+            builder = builder.set_dictionary_enabled(dictionary);
+        }
+
+        let props = Arc::new(builder.build());
+        // Create a writer up front so unsupported types error during bind.
+        let _ = ParquetWriter::try_new(schema, props.clone())?;
+
+        Ok(props)
+    }
+
+    fn create_sink(state: &Self::BindState, schema: &ColumnSchema) -> Result<Self::Sink> {
+        ParquetWriter::try_new(schema, state.clone())
+    }
+
+    fn encode(sink: &mut Self::Sink, batch: &Batch, buf: &mut Vec<u8>) -> Result<()> {
+        sink.write(batch, buf)
+    }
+
+    fn finish(sink: &mut Self::Sink, buf: &mut Vec<u8>) -> Result<()> {
+        sink.finish(buf)
+    }
+}
+
+/// Parse a compression option.
+///
+/// Codecs that take a level may omit it, e.g. 'zstd' instead of 'zstd(3)', in
+/// which case the default level is used.
+fn parse_compression(s: &str) -> Result<Compression> {
+    Ok(match s.to_lowercase().as_str() {
+        "gzip" => Compression::GZIP(GzipLevel::default()),
+        "brotli" => Compression::BROTLI(BrotliLevel::default()),
+        "zstd" => Compression::ZSTD(ZstdLevel::default()),
+        other => other.parse()?,
+    })
+}
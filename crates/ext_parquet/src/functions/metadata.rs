@@ -554,6 +554,7 @@ where
             data_schema: T::column_schema(),
             meta_schema: None, // TODO: I think None is fine, but may be inconsistent with some other function.
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -1,4 +1,5 @@
 #![allow(clippy::new_without_default)]
 
+pub mod copy_to;
 pub mod metadata;
 pub mod scan;
@@ -145,6 +145,7 @@ impl TableScanFunction for ReadParquet {
             data_schema: schema,
             meta_schema: Some(provider.meta_schema()),
             cardinality: StatisticsValue::Exact(cardinality),
+            column_statistics: Vec::new(),
         })
     }
 
@@ -37,6 +37,7 @@ pub mod extension;
 pub mod functions;
 pub mod metadata;
 pub mod reader;
+pub mod writer;
 
 mod compression;
 mod encodings;
@@ -78,6 +78,8 @@ enum FetchState {
         ///
         /// Relative to the output projection.
         column_idx: usize,
+        /// Leaf within the column we need to fetch data for.
+        leaf_idx: usize,
     },
     /// We're currently seeking for a column.
     Seeking {
@@ -85,6 +87,8 @@ enum FetchState {
         seek: io::SeekFrom,
         /// The column we're seeking for.
         column_idx: usize,
+        /// The leaf within the column we're seeking for.
+        leaf_idx: usize,
     },
     /// We're currently fetching a column.
     Fetching {
@@ -92,6 +96,8 @@ enum FetchState {
         ///
         /// This index is relative to the output projection.
         column_idx: usize,
+        /// Leaf within the column we're reading for.
+        leaf_idx: usize,
         /// Amount written to the columns buffer.
         amount_written: usize,
     },
@@ -147,7 +153,10 @@ impl Reader {
                 remaining_group_rows: 0, // Will trigger getting the real first row group.
                 relative_scan_offset: 0,
             },
-            fetch_state: FetchState::NeedsFetch { column_idx: 0 }, // This doesn't matter here.
+            fetch_state: FetchState::NeedsFetch {
+                column_idx: 0,
+                leaf_idx: 0,
+            }, // This doesn't matter here.
             projections,
             root,
         })
@@ -168,7 +177,10 @@ impl Reader {
             remaining_group_rows: 0,
             relative_scan_offset: 0,
         };
-        self.fetch_state = FetchState::NeedsFetch { column_idx: 0 };
+        self.fetch_state = FetchState::NeedsFetch {
+            column_idx: 0,
+            leaf_idx: 0,
+        };
 
         Ok(())
     }
@@ -212,7 +224,10 @@ impl Reader {
                 };
 
                 // Init fetch.
-                self.fetch_state = FetchState::NeedsFetch { column_idx: 0 };
+                self.fetch_state = FetchState::NeedsFetch {
+                    column_idx: 0,
+                    leaf_idx: 0,
+                };
                 // Continue on... The fetch state will trigger fetching of the
                 // columns.
                 break;
@@ -286,7 +301,10 @@ impl Reader {
         let row_group = &unit.metadata.row_groups[self.state.current_group.idx];
         loop {
             match &mut self.fetch_state {
-                FetchState::NeedsFetch { column_idx } => {
+                FetchState::NeedsFetch {
+                    column_idx,
+                    leaf_idx,
+                } => {
                     if self.root.readers.is_empty() {
                         // We're not actually trying to read any columns.
                         // Indicate the we've successfully "fetched" no columns.
@@ -294,29 +312,35 @@ impl Reader {
                         continue;
                     }
 
-                    let real_idx = self.projections.data_indices()[*column_idx];
+                    let real_idx = self.root.leaves[*column_idx].start + *leaf_idx;
                     let col = &row_group.columns[real_idx];
 
                     let (start, len) = col.byte_range();
 
                     // Reset and resize this column's buffer.
                     let reader = &mut self.root.readers[*column_idx];
-                    reader.prepare_for_chunk(len as usize, col.compression)?;
+                    reader.prepare_for_chunk(*leaf_idx, len as usize, col.compression)?;
 
                     // Begin seeking to the start of this column chunk.
                     self.fetch_state = FetchState::Seeking {
                         seek: io::SeekFrom::Start(start),
                         column_idx: *column_idx,
+                        leaf_idx: *leaf_idx,
                     };
                     // Continue...
                 }
-                FetchState::Seeking { seek, column_idx } => {
+                FetchState::Seeking {
+                    seek,
+                    column_idx,
+                    leaf_idx,
+                } => {
                     // Seek in the file.
                     match unit.file.call_poll_seek(cx, *seek) {
                         Poll::Ready(Ok(())) => {
                             // Begin fetching.
                             self.fetch_state = FetchState::Fetching {
                                 column_idx: *column_idx,
+                                leaf_idx: *leaf_idx,
                                 amount_written: 0,
                             }
                             // Continue...
@@ -327,11 +351,12 @@ impl Reader {
                 }
                 FetchState::Fetching {
                     column_idx,
+                    leaf_idx,
                     amount_written,
                 } => {
                     // Read into this column's buffer. Buffer should be the
                     // exact size.
-                    let buf = self.root.readers[*column_idx].chunk_buf_mut();
+                    let buf = self.root.readers[*column_idx].chunk_buf_mut(*leaf_idx);
 
                     let read_buf = &mut buf[*amount_written..];
                     match unit.file.call_poll_read(cx, read_buf) {
@@ -339,9 +364,19 @@ impl Reader {
                             *amount_written += n;
 
                             if *amount_written == buf.len() {
-                                // We've read everyting for this column, move to
-                                // the next column, or indicate everything's
-                                // been fetched if this is the last column.
+                                // We've read everyting for this leaf, move to
+                                // the next leaf in the column if there is one.
+                                if *leaf_idx + 1 < self.root.leaves[*column_idx].len() {
+                                    self.fetch_state = FetchState::NeedsFetch {
+                                        column_idx: *column_idx,
+                                        leaf_idx: *leaf_idx + 1,
+                                    };
+                                    continue;
+                                }
+
+                                // Otherwise move to the next column, or
+                                // indicate everything's been fetched if this
+                                // is the last column.
                                 if *column_idx == self.root.readers.len() - 1 {
                                     // We're done fetching.
                                     self.fetch_state = FetchState::Fetched;
@@ -351,6 +386,7 @@ impl Reader {
                                 // Move to next column.
                                 self.fetch_state = FetchState::NeedsFetch {
                                     column_idx: *column_idx + 1,
+                                    leaf_idx: 0,
                                 };
                                 // Continue...
                             }
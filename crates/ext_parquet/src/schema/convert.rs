@@ -51,8 +51,31 @@ impl TypeVisitor<DataType, ()> for ColumnSchemaTypeVisitor {
         Ok(datatype)
     }
 
-    fn visit_map(&mut self, _map_type: &GroupType, _context: ()) -> Result<DataType> {
-        not_implemented!("Visit MAP")
+    fn visit_map(&mut self, map_type: &GroupType, _context: ()) -> Result<DataType> {
+        // Maps contain a single repeated group holding the key and value:
+        //
+        // ```text
+        // <map-repetition> group <name> (MAP) {
+        //   repeated group key_value {
+        //     required <key-type> key;
+        //     <value-repetition> <value-type> value;
+        //   }
+        // }
+        // ```
+        let key_value = match map_type.fields.as_slice() {
+            [SchemaType::GroupType(key_value)] if key_value.fields.len() == 2 => key_value,
+            _ => {
+                return Err(DbError::new(
+                    "MAP group must contain a single repeated group with a key and value field",
+                )
+                .with_field("name", map_type.basic_info.name().to_string()));
+            }
+        };
+
+        let key = self.dispatch(&key_value.fields[0], ())?;
+        let value = self.dispatch(&key_value.fields[1], ())?;
+
+        Ok(DataType::map(key, value))
     }
 
     fn visit_list_with_item(
@@ -159,17 +182,34 @@ fn convert_primitive(prim: &PrimitiveType) -> Result<DataType> {
                     let meta = decimal_type_meta(precision, scale)?;
                     Ok(DataType::decimal64(meta))
                 }
-                (Some(basic::LogicalType::Timestamp { unit, .. }), _) => match unit {
-                    format::TimeUnit::MILLIS(_) => Ok(DataType::timestamp(TimestampTypeMeta::new(
-                        TimeUnit::Millisecond,
-                    ))),
-                    format::TimeUnit::MICROS(_) => Ok(DataType::timestamp(TimestampTypeMeta::new(
-                        TimeUnit::Microsecond,
-                    ))),
-                    format::TimeUnit::NANOS(_) => Ok(DataType::timestamp(TimestampTypeMeta::new(
-                        TimeUnit::Nanosecond,
-                    ))),
-                },
+                (None, basic::ConvertedType::TIMESTAMP_MILLIS) => {
+                    Ok(timestamp_type(TimeUnit::Millisecond, true))
+                }
+                (None, basic::ConvertedType::TIMESTAMP_MICROS) => {
+                    Ok(timestamp_type(TimeUnit::Microsecond, true))
+                }
+                (
+                    Some(basic::LogicalType::Timestamp {
+                        unit,
+                        is_adjusted_to_u_t_c,
+                    }),
+                    _,
+                ) => {
+                    let unit = match unit {
+                        format::TimeUnit::MILLIS(_) => TimeUnit::Millisecond,
+                        format::TimeUnit::MICROS(_) => TimeUnit::Microsecond,
+                        format::TimeUnit::NANOS(_) => TimeUnit::Nanosecond,
+                    };
+                    Ok(timestamp_type(unit, is_adjusted_to_u_t_c))
+                }
+                (None, basic::ConvertedType::TIME_MICROS) => Ok(DataType::time()),
+                (
+                    Some(basic::LogicalType::Time {
+                        unit: format::TimeUnit::MICROS(_),
+                        ..
+                    }),
+                    _,
+                ) => Ok(DataType::time()),
                 (Some(basic::LogicalType::Date), _) => Ok(DataType::date64()),
                 (logical, converted) => Err(DbError::new(format!(
                     "Cannot handle INT64 with logical type {logical:?} or converted type {converted:?}",
@@ -187,6 +227,15 @@ fn convert_primitive(prim: &PrimitiveType) -> Result<DataType> {
                 prim.basic_info.converted_type(),
             ) {
                 (Some(LogicalType::Float16), _) => Ok(DataType::float16()),
+                (Some(LogicalType::Uuid), _) => Ok(DataType::uuid()),
+                (None, basic::ConvertedType::DECIMAL) => {
+                    let meta = decimal_type_meta(prim.precision, prim.scale)?;
+                    Ok(DataType::decimal128(meta))
+                }
+                (Some(LogicalType::Decimal { scale, precision }), _) => {
+                    let meta = decimal_type_meta(precision, scale)?;
+                    Ok(DataType::decimal128(meta))
+                }
                 (logical, converted) => Err(DbError::new(format!(
                     "Cannot handle FIXED_LEN_BYTE_ARRAY with logical type {logical:?} or converted type {converted:?}",
                 ))),
@@ -208,6 +257,18 @@ fn convert_primitive(prim: &PrimitiveType) -> Result<DataType> {
     }
 }
 
+/// Create a timestamp type for a parquet timestamp column.
+///
+/// Timestamps adjusted to UTC are instants, and so are read as timestamps with
+/// a UTC time zone. Otherwise the timestamp is naive.
+fn timestamp_type(unit: TimeUnit, is_adjusted_to_utc: bool) -> DataType {
+    if is_adjusted_to_utc {
+        DataType::timestamp(TimestampTypeMeta::new_with_tz(unit, "UTC"))
+    } else {
+        DataType::timestamp(TimestampTypeMeta::new(unit))
+    }
+}
+
 fn decimal_type_meta(prec: i32, scale: i32) -> Result<DecimalTypeMeta> {
     if prec < 0 {
         return Err(DbError::new("Decimal precision cannot be negative"));
@@ -217,3 +278,108 @@ fn decimal_type_meta(prec: i32, scale: i32) -> Result<DecimalTypeMeta> {
 
     Ok(DecimalTypeMeta::new(prec, scale))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::schema::parser::parse_message_type;
+
+    #[test]
+    fn convert_map() {
+        let message = "
+            message schema {
+                REQUIRED INT32 id;
+                OPTIONAL group m (MAP) {
+                    REPEATED group key_value {
+                        REQUIRED BYTE_ARRAY key (UTF8);
+                        OPTIONAL INT32 value;
+                    }
+                }
+            }
+        ";
+        let desc = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        let schema = ColumnSchemaTypeVisitor.convert_schema(&desc).unwrap();
+
+        assert_eq!(DataType::int32(), schema.fields[0].datatype);
+        assert_eq!(
+            DataType::map(DataType::utf8(), DataType::int32()),
+            schema.fields[1].datatype
+        );
+    }
+
+    #[test]
+    fn convert_map_missing_value() {
+        let message = "
+            message schema {
+                OPTIONAL group m (MAP) {
+                    REPEATED group key_value {
+                        REQUIRED BYTE_ARRAY key (UTF8);
+                    }
+                }
+            }
+        ";
+        let desc = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        ColumnSchemaTypeVisitor.convert_schema(&desc).unwrap_err();
+    }
+
+    #[test]
+    fn convert_timestamps() {
+        let message = "
+            message schema {
+                REQUIRED INT64 naive (TIMESTAMP(MICROS,false));
+                REQUIRED INT64 utc (TIMESTAMP(MILLIS,true));
+                REQUIRED INT64 legacy (TIMESTAMP_MICROS);
+            }
+        ";
+        let desc = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        let schema = ColumnSchemaTypeVisitor.convert_schema(&desc).unwrap();
+
+        assert_eq!(
+            DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            schema.fields[0].datatype
+        );
+        assert_eq!(
+            DataType::timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Millisecond, "UTC")),
+            schema.fields[1].datatype
+        );
+        assert_eq!(
+            DataType::timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Microsecond, "UTC")),
+            schema.fields[2].datatype
+        );
+    }
+
+    #[test]
+    fn convert_uuid() {
+        let message = "
+            message schema {
+                REQUIRED FIXED_LEN_BYTE_ARRAY (16) id (UUID);
+            }
+        ";
+        let desc = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        let schema = ColumnSchemaTypeVisitor.convert_schema(&desc).unwrap();
+
+        assert_eq!(DataType::uuid(), schema.fields[0].datatype);
+    }
+
+    #[test]
+    fn convert_time_and_decimal128() {
+        let message = "
+            message schema {
+                REQUIRED INT64 t (TIME(MICROS,false));
+                REQUIRED INT64 legacy (TIME_MICROS);
+                REQUIRED FIXED_LEN_BYTE_ARRAY (16) d (DECIMAL(30,4));
+            }
+        ";
+        let desc = SchemaDescriptor::new(Arc::new(parse_message_type(message).unwrap()));
+        let schema = ColumnSchemaTypeVisitor.convert_schema(&desc).unwrap();
+
+        assert_eq!(DataType::time(), schema.fields[0].datatype);
+        assert_eq!(DataType::time(), schema.fields[1].datatype);
+        assert_eq!(
+            DataType::decimal128(DecimalTypeMeta::new(30, 4)),
+            schema.fields[2].datatype
+        );
+    }
+}
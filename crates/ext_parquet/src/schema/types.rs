@@ -18,6 +18,7 @@
 //! Contains structs and methods to build Parquet schema and schema descriptors.
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::Arc;
 
 use glaredb_error::{DbError, Result};
@@ -796,6 +797,33 @@ impl SchemaDescriptor {
         self.column_root_of(i)
     }
 
+    /// Returns a descriptor that treats every leaf column as its own root
+    /// column.
+    ///
+    /// Used for reading individual fields out of nested structs, with each
+    /// projected column being a single leaf. Leaves keep their definition
+    /// levels, so a field reads as NULL if any of its parents are NULL.
+    ///
+    /// Only the leaf to root mapping changes, `get_column_root` should not be
+    /// used on the returned descriptor.
+    pub fn with_leaves_as_roots(&self) -> Self {
+        SchemaDescriptor {
+            schema: self.schema.clone(),
+            leaves: self.leaves.clone(),
+            leaf_to_base: (0..self.leaves.len()).collect(),
+        }
+    }
+
+    /// Returns the indices of the leaf columns that belong to a root column.
+    ///
+    /// Leaves are stored in DFS order, so all leaves for a root column are
+    /// contiguous.
+    pub fn leaves_for_root(&self, root: usize) -> Range<usize> {
+        let start = self.leaf_to_base.partition_point(|&base| base < root);
+        let end = self.leaf_to_base.partition_point(|&base| base <= root);
+        start..end
+    }
+
     /// Returns the index of the root column for a field position
     pub fn get_column_root_idx(&self, leaf: usize) -> usize {
         assert!(
@@ -1531,6 +1559,16 @@ mod tests {
         assert_eq!(descr.get_column_root(4).name(), "bag");
         assert_eq!(descr.get_column_root(5).name(), "bag");
 
+        assert_eq!(descr.leaves_for_root(0), 0..1);
+        assert_eq!(descr.leaves_for_root(2), 2..3);
+        assert_eq!(descr.leaves_for_root(3), 3..6);
+
+        let flat = descr.with_leaves_as_roots();
+        assert_eq!(flat.num_columns(), nleaves);
+        assert_eq!(flat.leaves_for_root(3), 3..4);
+        assert_eq!(flat.leaves_for_root(5), 5..6);
+        assert_eq!(flat.column(4).max_def_level, 3);
+
         Ok(())
     }
 
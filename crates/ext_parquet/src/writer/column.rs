@@ -0,0 +1,775 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use glaredb_core::arrays::array::Array;
+use glaredb_core::arrays::array::physical_type::{
+    PhysicalBinary,
+    PhysicalBool,
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI8,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI128,
+    PhysicalU8,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU128,
+    PhysicalUtf8,
+    ScalarStorage,
+};
+use glaredb_core::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use glaredb_core::arrays::executor::scalar::UnaryExecutor;
+use glaredb_core::arrays::field::Field;
+use glaredb_error::{DbError, Result, ResultExt};
+use thrift::protocol::{TCompactOutputProtocol, TOutputProtocol};
+
+use crate::basic::{self, Compression};
+use crate::compression::{Codec, CodecOptions, create_codec};
+use crate::encodings::levels::LevelEncoder;
+use crate::format::{
+    ColumnChunk,
+    ColumnMetaData,
+    CompressionCodec,
+    DataPageHeader,
+    DateType,
+    DecimalType,
+    DictionaryPageHeader,
+    Encoding,
+    FieldRepetitionType,
+    Float16Type,
+    IntType,
+    LogicalType,
+    MicroSeconds,
+    MilliSeconds,
+    NanoSeconds,
+    PageEncodingStats,
+    PageHeader,
+    PageType,
+    SchemaElement,
+    StringType,
+    TimeType,
+    TimestampType,
+    Type,
+    UUIDType,
+};
+use crate::metadata::properties::{EnabledStatistics, WriterProperties};
+use crate::schema::types::ColumnPath;
+use crate::thrift::TSerializable;
+use crate::writer::encoder::{PlainDictionary, encode_dictionary_indices, encode_rle_bools};
+use crate::writer::stats::{ColumnStats, StatsOrder};
+
+/// How values from an array get converted into their physical parquet
+/// representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Bool,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float16,
+    Float32,
+    Float64,
+    /// Timestamp with second precision, written as milliseconds since parquet
+    /// doesn't have a seconds unit.
+    TimestampSeconds,
+    Utf8,
+    Binary,
+    /// Written as 16 big-endian bytes.
+    Uuid,
+    /// Written as 16 big-endian two's complement bytes.
+    Decimal128,
+}
+
+impl ValueKind {
+    /// Sort order to use for statistics.
+    const fn stats_order(&self) -> StatsOrder {
+        match self {
+            Self::Bool => StatsOrder::Bool,
+            Self::Int8 | Self::Int16 | Self::Int32 => StatsOrder::Signed32,
+            Self::Int64 | Self::TimestampSeconds => StatsOrder::Signed64,
+            Self::UInt8 | Self::UInt16 | Self::UInt32 => StatsOrder::Unsigned32,
+            Self::UInt64 => StatsOrder::Unsigned64,
+            Self::Float16 => StatsOrder::Float16,
+            Self::Float32 => StatsOrder::Float32,
+            Self::Float64 => StatsOrder::Float64,
+            Self::Utf8 | Self::Binary | Self::Uuid => StatsOrder::Bytes,
+            Self::Decimal128 => StatsOrder::Signed128,
+        }
+    }
+}
+
+/// How non-null values are encoded in data pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueEncoding {
+    Plain,
+    /// RLE/bit-packed hybrid, only used for booleans.
+    Rle,
+    /// Indices into the column chunk's dictionary.
+    Dictionary,
+}
+
+/// Values buffered for the page currently being built.
+#[derive(Debug, Default)]
+struct PageBuffer {
+    /// Definition levels for all values in the page.
+    def_levels: Vec<i16>,
+    /// PLAIN encoded non-null values.
+    ///
+    /// Booleans are stored as a byte per value, and RLE encoded when flushing.
+    values: Vec<u8>,
+    /// Dictionary indices for non-null values when dictionary encoding.
+    indices: Vec<u32>,
+    stats: ColumnStats,
+}
+
+/// Encoded pages for the column chunk currently being built.
+#[derive(Debug, Default)]
+struct ChunkBuffer {
+    /// Encoded (and possibly compressed) data pages, including headers.
+    pages: Vec<u8>,
+    /// Uncompressed size of all data pages, including headers.
+    uncompressed_size: usize,
+    num_values: usize,
+    stats: ColumnStats,
+    encodings: Vec<Encoding>,
+    encoding_stats: Vec<PageEncodingStats>,
+}
+
+impl ChunkBuffer {
+    fn add_encoding(&mut self, encoding: Encoding) {
+        if !self.encodings.contains(&encoding) {
+            self.encodings.push(encoding);
+        }
+    }
+
+    fn add_page(&mut self, page_type: PageType, encoding: Encoding) {
+        match self
+            .encoding_stats
+            .iter_mut()
+            .find(|s| s.page_type == page_type && s.encoding == encoding)
+        {
+            Some(stats) => stats.count += 1,
+            None => self
+                .encoding_stats
+                .push(PageEncodingStats::new(page_type, encoding, 1)),
+        }
+    }
+}
+
+/// Buffers and encodes values for a single column in a row group.
+///
+/// Values are split into data pages according to the page limits in the
+/// writer properties. Pages are encoded and compressed as they fill up, and the
+/// entire column chunk is written out when the row group is flushed.
+///
+/// Dictionary encoding is used by default for non-boolean columns. If the
+/// dictionary grows past the dictionary page size limit, the writer falls back
+/// to PLAIN encoding for the remainder of the column chunk.
+#[derive(Debug)]
+pub struct ColumnWriter {
+    name: String,
+    kind: ValueKind,
+    physical_type: Type,
+    order: StatsOrder,
+    props: Arc<WriterProperties>,
+    statistics: EnabledStatistics,
+    max_statistics_size: usize,
+    compression: Compression,
+    codec: Option<Box<dyn Codec>>,
+    /// Encoding to use for new values.
+    encoding: ValueEncoding,
+    /// Dictionary for the current column chunk.
+    dictionary: PlainDictionary,
+    page: PageBuffer,
+    chunk: ChunkBuffer,
+    /// Scratch buffer for encoding a single value.
+    scratch: Vec<u8>,
+}
+
+impl ColumnWriter {
+    /// Create a new column writer for the given field, returning the writer and
+    /// the schema element describing the column.
+    pub fn try_new(field: &Field, props: Arc<WriterProperties>) -> Result<(Self, SchemaElement)> {
+        let (kind, physical_type, type_length, logical_type) = column_type(&field.datatype)?;
+
+        let element = SchemaElement::new(
+            physical_type,
+            type_length,
+            FieldRepetitionType::OPTIONAL,
+            field.name.clone(),
+            None,
+            None,
+            match &logical_type {
+                Some(LogicalType::DECIMAL(d)) => Some(d.scale),
+                _ => None,
+            },
+            match &logical_type {
+                Some(LogicalType::DECIMAL(d)) => Some(d.precision),
+                _ => None,
+            },
+            None,
+            logical_type,
+        );
+
+        let path = ColumnPath::new(vec![field.name.clone()]);
+        let compression = props.compression(&path);
+        let codec = create_codec(compression, &CodecOptions::default())?;
+
+        let writer = ColumnWriter {
+            name: field.name.clone(),
+            kind,
+            physical_type,
+            order: kind.stats_order(),
+            statistics: props.statistics_enabled(&path),
+            max_statistics_size: props.max_statistics_size(&path),
+            compression,
+            codec,
+            encoding: Self::initial_encoding(kind, &props, &path),
+            dictionary: PlainDictionary::default(),
+            page: PageBuffer::default(),
+            chunk: ChunkBuffer::default(),
+            scratch: Vec::new(),
+            props,
+        };
+
+        Ok((writer, element))
+    }
+
+    fn initial_encoding(
+        kind: ValueKind,
+        props: &WriterProperties,
+        path: &ColumnPath,
+    ) -> ValueEncoding {
+        if kind == ValueKind::Bool {
+            ValueEncoding::Rle
+        } else if props.dictionary_enabled(path) {
+            ValueEncoding::Dictionary
+        } else {
+            ValueEncoding::Plain
+        }
+    }
+
+    /// Append values for the given rows from the array.
+    ///
+    /// Rows are appended in chunks of the write batch size, with page limits
+    /// checked after each chunk.
+    pub fn append(&mut self, array: &Array, rows: Range<usize>) -> Result<()> {
+        let batch_size = self.props.write_batch_size().max(1);
+
+        let mut start = rows.start;
+        while start < rows.end {
+            let end = usize::min(start + batch_size, rows.end);
+            self.append_chunk(array, start..end)?;
+            self.flush_page_if_needed()?;
+            start = end;
+        }
+
+        Ok(())
+    }
+
+    fn append_chunk(&mut self, array: &Array, rows: Range<usize>) -> Result<()> {
+        match self.kind {
+            ValueKind::Bool => self.append_values::<PhysicalBool>(array, rows, |&v, buf| {
+                buf.push(v as u8);
+            }),
+            ValueKind::Int8 => self.append_values::<PhysicalI8>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&(v as i32).to_le_bytes())
+            }),
+            ValueKind::Int16 => self.append_values::<PhysicalI16>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&(v as i32).to_le_bytes())
+            }),
+            ValueKind::Int32 => self.append_values::<PhysicalI32>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::Int64 => self.append_values::<PhysicalI64>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::UInt8 => self.append_values::<PhysicalU8>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&(v as i32).to_le_bytes())
+            }),
+            ValueKind::UInt16 => self.append_values::<PhysicalU16>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&(v as i32).to_le_bytes())
+            }),
+            ValueKind::UInt32 => self.append_values::<PhysicalU32>(array, rows, |&v, buf| {
+                // Unsigned values are stored with the same bits as signed.
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::UInt64 => self.append_values::<PhysicalU64>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::Float16 => self.append_values::<PhysicalF16>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::Float32 => self.append_values::<PhysicalF32>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::Float64 => self.append_values::<PhysicalF64>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            }),
+            ValueKind::TimestampSeconds => {
+                self.append_values::<PhysicalI64>(array, rows, |&v, buf| {
+                    buf.extend_from_slice(&v.saturating_mul(1000).to_le_bytes())
+                })
+            }
+            ValueKind::Utf8 => self.append_values::<PhysicalUtf8>(array, rows, |v, buf| {
+                buf.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                buf.extend_from_slice(v.as_bytes());
+            }),
+            ValueKind::Binary => self.append_values::<PhysicalBinary>(array, rows, |v, buf| {
+                buf.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                buf.extend_from_slice(v);
+            }),
+            ValueKind::Uuid => self.append_values::<PhysicalU128>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_be_bytes())
+            }),
+            ValueKind::Decimal128 => self.append_values::<PhysicalI128>(array, rows, |&v, buf| {
+                buf.extend_from_slice(&v.to_be_bytes())
+            }),
+        }
+    }
+
+    fn append_values<S>(
+        &mut self,
+        array: &Array,
+        rows: Range<usize>,
+        mut encode: impl FnMut(&S::StorageType, &mut Vec<u8>),
+    ) -> Result<()>
+    where
+        S: ScalarStorage,
+    {
+        // Stats are computed on the value without the length prefix.
+        let prefix_len = if self.physical_type == Type::BYTE_ARRAY {
+            4
+        } else {
+            0
+        };
+        let compute_stats = self.statistics != EnabledStatistics::None;
+        let order = self.order;
+        let encoding = self.encoding;
+
+        let page = &mut self.page;
+        let dictionary = &mut self.dictionary;
+        let scratch = &mut self.scratch;
+
+        UnaryExecutor::for_each_flat::<S, _>(array, rows, |_, v| match v {
+            Some(v) => {
+                page.def_levels.push(1);
+
+                scratch.clear();
+                encode(v, scratch);
+                if compute_stats {
+                    page.stats.update(order, &scratch[prefix_len..]);
+                }
+
+                match encoding {
+                    ValueEncoding::Dictionary => page.indices.push(dictionary.insert(scratch)),
+                    ValueEncoding::Plain | ValueEncoding::Rle => {
+                        page.values.extend_from_slice(scratch)
+                    }
+                }
+            }
+            None => {
+                page.def_levels.push(0);
+                page.stats.null_count += 1;
+            }
+        })
+    }
+
+    /// Flushes the current page if it's hit any of the configured limits.
+    ///
+    /// Also handles falling back to PLAIN encoding if the dictionary is too
+    /// large.
+    fn flush_page_if_needed(&mut self) -> Result<()> {
+        if self.encoding == ValueEncoding::Dictionary
+            && self.dictionary.size_in_bytes() > self.props.dictionary_page_size_limit()
+        {
+            // Pages written so far still reference the dictionary, the
+            // dictionary itself gets written when we flush the chunk.
+            self.flush_page()?;
+            self.encoding = ValueEncoding::Plain;
+            return Ok(());
+        }
+
+        if self.page.def_levels.len() >= self.props.data_page_row_count_limit()
+            || self.estimated_page_size() >= self.props.data_page_size_limit()
+        {
+            self.flush_page()?;
+        }
+
+        Ok(())
+    }
+
+    /// Estimate the encoded size of the current page.
+    fn estimated_page_size(&self) -> usize {
+        let levels = self.page.def_levels.len().div_ceil(8);
+        let values = match self.encoding {
+            ValueEncoding::Plain => self.page.values.len(),
+            ValueEncoding::Rle => self.page.values.len().div_ceil(8),
+            ValueEncoding::Dictionary => {
+                1 + (self.page.indices.len() * self.dictionary.bit_width() as usize).div_ceil(8)
+            }
+        };
+        levels + values
+    }
+
+    /// Encodes and compresses the current page, appending it to the chunk
+    /// buffer.
+    fn flush_page(&mut self) -> Result<()> {
+        let num_values = self.page.def_levels.len();
+        if num_values == 0 {
+            return Ok(());
+        }
+
+        let mut levels = LevelEncoder::v1(basic::Encoding::RLE, 1, num_values);
+        levels.put(&self.page.def_levels);
+        let mut body = levels.consume();
+
+        let encoding = match self.encoding {
+            ValueEncoding::Dictionary if self.dictionary.num_entries() > 0 => {
+                encode_dictionary_indices(
+                    &self.page.indices,
+                    self.dictionary.bit_width(),
+                    &mut body,
+                );
+                Encoding::RLE_DICTIONARY
+            }
+            ValueEncoding::Dictionary => {
+                // All nulls, no values to write.
+                Encoding::PLAIN
+            }
+            ValueEncoding::Rle => {
+                encode_rle_bools(&self.page.values, &mut body);
+                Encoding::RLE
+            }
+            ValueEncoding::Plain => {
+                body.extend_from_slice(&self.page.values);
+                Encoding::PLAIN
+            }
+        };
+
+        let statistics = match self.statistics {
+            EnabledStatistics::Page => Some(
+                self.page
+                    .stats
+                    .to_thrift(self.order, self.max_statistics_size),
+            ),
+            _ => None,
+        };
+
+        let header = DataPageHeader::new(
+            num_values as i32,
+            encoding,
+            Encoding::RLE,
+            Encoding::RLE,
+            statistics,
+        );
+        let size = write_page(
+            &mut self.codec,
+            PageType::DATA_PAGE,
+            Some(header),
+            None,
+            &body,
+            &mut self.chunk.pages,
+        )?;
+
+        self.chunk.uncompressed_size += size;
+        self.chunk.num_values += num_values;
+        self.chunk.stats.merge(self.order, &self.page.stats);
+        self.chunk.add_encoding(encoding);
+        self.chunk.add_encoding(Encoding::RLE);
+        self.chunk.add_page(PageType::DATA_PAGE, encoding);
+
+        self.page.def_levels.clear();
+        self.page.values.clear();
+        self.page.indices.clear();
+        self.page.stats = ColumnStats::default();
+
+        Ok(())
+    }
+
+    /// Write all buffered values as a column chunk to `out`.
+    ///
+    /// `base` is the position in the file of the first byte in `out`, and is
+    /// used to compute page offsets for the column metadata.
+    pub fn flush(&mut self, base: usize, out: &mut Vec<u8>) -> Result<ColumnChunk> {
+        self.flush_page()?;
+
+        let start = out.len();
+        let mut uncompressed_size = self.chunk.uncompressed_size;
+        let mut encodings = Vec::with_capacity(self.chunk.encodings.len() + 1);
+        let mut encoding_stats = Vec::with_capacity(self.chunk.encoding_stats.len() + 1);
+
+        // Dictionary page must come before all data pages.
+        let dictionary_page_offset = if self.dictionary.num_entries() > 0 {
+            let offset = (base + out.len()) as i64;
+            let header = DictionaryPageHeader::new(
+                self.dictionary.num_entries() as i32,
+                Encoding::PLAIN,
+                false,
+            );
+            uncompressed_size += write_page(
+                &mut self.codec,
+                PageType::DICTIONARY_PAGE,
+                None,
+                Some(header),
+                self.dictionary.data(),
+                out,
+            )?;
+            encodings.push(Encoding::PLAIN);
+            encoding_stats.push(PageEncodingStats::new(
+                PageType::DICTIONARY_PAGE,
+                Encoding::PLAIN,
+                1,
+            ));
+            Some(offset)
+        } else {
+            None
+        };
+
+        let data_page_offset = (base + out.len()) as i64;
+        out.extend_from_slice(&self.chunk.pages);
+        let compressed_size = (out.len() - start) as i64;
+
+        for encoding in self.chunk.encodings.drain(..) {
+            if !encodings.contains(&encoding) {
+                encodings.push(encoding);
+            }
+        }
+        encoding_stats.append(&mut self.chunk.encoding_stats);
+
+        let statistics = match self.statistics {
+            EnabledStatistics::None => None,
+            _ => Some(
+                self.chunk
+                    .stats
+                    .to_thrift(self.order, self.max_statistics_size),
+            ),
+        };
+
+        let metadata = ColumnMetaData::new(
+            self.physical_type,
+            encodings,
+            vec![self.name.clone()],
+            CompressionCodec::from(self.compression),
+            self.chunk.num_values as i64,
+            uncompressed_size as i64,
+            compressed_size,
+            None,
+            data_page_offset,
+            None,
+            dictionary_page_offset,
+            statistics,
+            encoding_stats,
+            None,
+            None,
+        );
+
+        // Reset for the next row group. Each column chunk gets its own
+        // dictionary.
+        self.chunk = ChunkBuffer::default();
+        self.dictionary = PlainDictionary::default();
+        let path = ColumnPath::new(vec![self.name.clone()]);
+        self.encoding = Self::initial_encoding(self.kind, &self.props, &path);
+
+        Ok(ColumnChunk::new(
+            None,
+            data_page_offset,
+            metadata,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ))
+    }
+}
+
+/// Compress and write a page along with its header to `out`.
+///
+/// Returns the uncompressed size of the page including the header.
+fn write_page(
+    codec: &mut Option<Box<dyn Codec>>,
+    page_type: PageType,
+    data_page_header: Option<DataPageHeader>,
+    dictionary_page_header: Option<DictionaryPageHeader>,
+    body: &[u8],
+    out: &mut Vec<u8>,
+) -> Result<usize> {
+    let mut compressed = Vec::new();
+    let data = match codec {
+        Some(codec) => {
+            codec.compress(body, &mut compressed)?;
+            compressed.as_slice()
+        }
+        None => body,
+    };
+
+    let uncompressed_size: i32 = body
+        .len()
+        .try_into()
+        .context("Page too large for parquet")?;
+    let compressed_size: i32 = data
+        .len()
+        .try_into()
+        .context("Page too large for parquet")?;
+
+    let header = PageHeader::new(
+        page_type,
+        uncompressed_size,
+        compressed_size,
+        None,
+        data_page_header,
+        None,
+        dictionary_page_header,
+        None,
+    );
+
+    let start = out.len();
+    write_thrift(&header, out)?;
+    let header_size = out.len() - start;
+    out.extend_from_slice(data);
+
+    Ok(header_size + body.len())
+}
+
+/// Write a thrift struct using the compact protocol.
+pub(crate) fn write_thrift<T: TSerializable>(val: &T, out: &mut Vec<u8>) -> Result<()> {
+    let mut protocol = TCompactOutputProtocol::new(out);
+    val.write_to_out_protocol(&mut protocol)
+        .context("Failed to write thrift struct")?;
+    protocol
+        .flush()
+        .context("Failed to flush thrift protocol")?;
+    Ok(())
+}
+
+/// Get the physical and logical parquet types for a datatype.
+///
+/// Nested types (lists, structs, maps) aren't supported yet since the writer
+/// only produces flat columns without repetition levels.
+#[allow(clippy::type_complexity)]
+fn column_type(datatype: &DataType) -> Result<(ValueKind, Type, Option<i32>, Option<LogicalType>)> {
+    let int = |bit_width, is_signed| Some(LogicalType::INTEGER(IntType::new(bit_width, is_signed)));
+
+    Ok(match datatype.id() {
+        DataTypeId::Boolean => (ValueKind::Bool, Type::BOOLEAN, None, None),
+        DataTypeId::Int8 => (ValueKind::Int8, Type::INT32, None, int(8, true)),
+        DataTypeId::Int16 => (ValueKind::Int16, Type::INT32, None, int(16, true)),
+        DataTypeId::Int32 => (ValueKind::Int32, Type::INT32, None, None),
+        DataTypeId::Int64 => (ValueKind::Int64, Type::INT64, None, None),
+        DataTypeId::UInt8 => (ValueKind::UInt8, Type::INT32, None, int(8, false)),
+        DataTypeId::UInt16 => (ValueKind::UInt16, Type::INT32, None, int(16, false)),
+        DataTypeId::UInt32 => (ValueKind::UInt32, Type::INT32, None, int(32, false)),
+        DataTypeId::UInt64 => (ValueKind::UInt64, Type::INT64, None, int(64, false)),
+        DataTypeId::Float16 => (
+            ValueKind::Float16,
+            Type::FIXED_LEN_BYTE_ARRAY,
+            Some(2),
+            Some(LogicalType::FLOAT16(Float16Type::new())),
+        ),
+        DataTypeId::Float32 => (ValueKind::Float32, Type::FLOAT, None, None),
+        DataTypeId::Float64 => (ValueKind::Float64, Type::DOUBLE, None, None),
+        DataTypeId::Date32 => (
+            ValueKind::Int32,
+            Type::INT32,
+            None,
+            Some(LogicalType::DATE(DateType::new())),
+        ),
+        DataTypeId::Decimal64 => {
+            let meta = datatype.try_get_decimal_type_meta()?;
+            (
+                ValueKind::Int64,
+                Type::INT64,
+                None,
+                Some(LogicalType::DECIMAL(DecimalType::new(
+                    meta.scale as i32,
+                    meta.precision as i32,
+                ))),
+            )
+        }
+        DataTypeId::Decimal128 => {
+            let meta = datatype.try_get_decimal_type_meta()?;
+            (
+                ValueKind::Decimal128,
+                Type::FIXED_LEN_BYTE_ARRAY,
+                Some(16),
+                Some(LogicalType::DECIMAL(DecimalType::new(
+                    meta.scale as i32,
+                    meta.precision as i32,
+                ))),
+            )
+        }
+        DataTypeId::Time => (
+            ValueKind::Int64,
+            Type::INT64,
+            None,
+            Some(LogicalType::TIME(TimeType::new(
+                false,
+                crate::format::TimeUnit::MICROS(MicroSeconds::new()),
+            ))),
+        ),
+        DataTypeId::Timestamp => {
+            let meta = datatype.try_get_timestamp_type_meta()?;
+            let (kind, unit) = match meta.unit {
+                TimeUnit::Second => (
+                    ValueKind::TimestampSeconds,
+                    crate::format::TimeUnit::MILLIS(MilliSeconds::new()),
+                ),
+                TimeUnit::Millisecond => (
+                    ValueKind::Int64,
+                    crate::format::TimeUnit::MILLIS(MilliSeconds::new()),
+                ),
+                TimeUnit::Microsecond => (
+                    ValueKind::Int64,
+                    crate::format::TimeUnit::MICROS(MicroSeconds::new()),
+                ),
+                TimeUnit::Nanosecond => (
+                    ValueKind::Int64,
+                    crate::format::TimeUnit::NANOS(NanoSeconds::new()),
+                ),
+            };
+            (
+                kind,
+                Type::INT64,
+                None,
+                Some(LogicalType::TIMESTAMP(TimestampType::new(
+                    meta.tz.is_some(),
+                    unit,
+                ))),
+            )
+        }
+        DataTypeId::Utf8 => (
+            ValueKind::Utf8,
+            Type::BYTE_ARRAY,
+            None,
+            Some(LogicalType::STRING(StringType::new())),
+        ),
+        DataTypeId::Binary => (ValueKind::Binary, Type::BYTE_ARRAY, None, None),
+        DataTypeId::Uuid => (
+            ValueKind::Uuid,
+            Type::FIXED_LEN_BYTE_ARRAY,
+            Some(16),
+            Some(LogicalType::UUID(UUIDType::new())),
+        ),
+        other @ (DataTypeId::List | DataTypeId::Struct | DataTypeId::Map) => {
+            return Err(DbError::new(format!(
+                "Writing nested type {other} to parquet not yet supported"
+            )));
+        }
+        other => {
+            return Err(DbError::new(format!(
+                "Writing {other} to parquet not yet supported"
+            )));
+        }
+    })
+}
@@ -0,0 +1,144 @@
+use crate::encodings::rle::RleEncoder;
+use crate::util::bit_util::num_required_bits;
+use crate::util::interner::{Interner, Storage};
+
+/// Storage for unique PLAIN encoded values.
+///
+/// Values are stored contiguously, so the data buffer can be used directly as
+/// the body of a dictionary page.
+#[derive(Debug)]
+struct DictionaryStorage {
+    data: Vec<u8>,
+    /// Offsets into `data`. Always has one more entry than the number of
+    /// values.
+    offsets: Vec<usize>,
+}
+
+impl Storage for DictionaryStorage {
+    type Key = u32;
+    type Value = [u8];
+
+    fn get(&self, idx: Self::Key) -> &Self::Value {
+        let idx = idx as usize;
+        &self.data[self.offsets[idx]..self.offsets[idx + 1]]
+    }
+
+    fn push(&mut self, value: &Self::Value) -> Self::Key {
+        let key = (self.offsets.len() - 1) as u32;
+        self.data.extend_from_slice(value);
+        self.offsets.push(self.data.len());
+        key
+    }
+}
+
+/// Dictionary of PLAIN encoded values for a column chunk.
+#[derive(Debug)]
+pub struct PlainDictionary {
+    interner: Interner<DictionaryStorage>,
+}
+
+impl Default for PlainDictionary {
+    fn default() -> Self {
+        PlainDictionary {
+            interner: Interner::new(DictionaryStorage {
+                data: Vec::new(),
+                offsets: vec![0],
+            }),
+        }
+    }
+}
+
+impl PlainDictionary {
+    /// Get the index for a PLAIN encoded value, inserting it into the
+    /// dictionary if needed.
+    pub fn insert(&mut self, value: &[u8]) -> u32 {
+        self.interner.intern(value)
+    }
+
+    /// Number of unique values in the dictionary.
+    pub fn num_entries(&self) -> usize {
+        self.interner.storage().offsets.len() - 1
+    }
+
+    /// Size in bytes of the encoded dictionary page.
+    pub fn size_in_bytes(&self) -> usize {
+        self.interner.storage().data.len()
+    }
+
+    /// The PLAIN encoded dictionary values.
+    pub fn data(&self) -> &[u8] {
+        &self.interner.storage().data
+    }
+
+    /// Bit width needed to encode indices into this dictionary.
+    pub fn bit_width(&self) -> u8 {
+        // Always use at least one bit. A zero bit width is technically valid,
+        // but not all readers handle it.
+        num_required_bits(self.num_entries().saturating_sub(1) as u64).max(1)
+    }
+}
+
+/// Encode dictionary indices for a data page.
+///
+/// Format: bit width as a single byte, followed by the RLE/bit-packed hybrid
+/// encoded indices.
+pub fn encode_dictionary_indices(indices: &[u32], bit_width: u8, out: &mut Vec<u8>) {
+    out.push(bit_width);
+
+    let buf = Vec::with_capacity(RleEncoder::max_buffer_size(bit_width, indices.len()));
+    let mut encoder = RleEncoder::new_from_buf(bit_width, buf);
+    for &idx in indices {
+        encoder.put(idx as u64);
+    }
+    out.extend_from_slice(&encoder.consume());
+}
+
+/// RLE encode booleans (stored as a byte per value).
+///
+/// Format: 4 byte little-endian length, followed by the RLE/bit-packed hybrid
+/// encoded values.
+pub fn encode_rle_bools(values: &[u8], out: &mut Vec<u8>) {
+    let buf = Vec::with_capacity(RleEncoder::max_buffer_size(1, values.len()));
+    let mut encoder = RleEncoder::new_from_buf(1, buf);
+    for &v in values {
+        encoder.put(v as u64);
+    }
+    let encoded = encoder.consume();
+
+    out.extend_from_slice(&(encoded.len() as u32).to_le_bytes());
+    out.extend_from_slice(&encoded);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encodings::rle::RleDecoder;
+
+    #[test]
+    fn dictionary_dedups() {
+        let mut dict = PlainDictionary::default();
+        assert_eq!(0, dict.insert(b"aa"));
+        assert_eq!(1, dict.insert(b"b"));
+        assert_eq!(0, dict.insert(b"aa"));
+        assert_eq!(2, dict.insert(b"ccc"));
+
+        assert_eq!(3, dict.num_entries());
+        assert_eq!(b"aabccc", dict.data());
+        assert_eq!(2, dict.bit_width());
+    }
+
+    #[test]
+    fn dictionary_indices_roundtrip() {
+        let indices = [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 0];
+        let mut out = Vec::new();
+        encode_dictionary_indices(&indices, 2, &mut out);
+        assert_eq!(2, out[0]);
+
+        let mut decoder = RleDecoder::new(2);
+        decoder.set_data(out[1..].to_vec().into());
+        let mut decoded = vec![0_u32; indices.len()];
+        let n = decoder.get_batch(&mut decoded).unwrap();
+        assert_eq!(indices.len(), n);
+        assert_eq!(&indices[..], &decoded[..]);
+    }
+}
@@ -0,0 +1,411 @@
+pub mod column;
+pub mod encoder;
+pub mod stats;
+
+use std::sync::Arc;
+
+use column::{ColumnWriter, write_thrift};
+use glaredb_core::arrays::batch::Batch;
+use glaredb_core::arrays::field::ColumnSchema;
+use glaredb_error::{DbError, Result};
+
+use crate::format::{ColumnOrder, FileMetaData, RowGroup, SchemaElement, TypeDefinedOrder};
+use crate::metadata::PARQUET_MAGIC;
+use crate::metadata::properties::WriterProperties;
+
+/// Writes batches as a single parquet file.
+///
+/// The writer doesn't do any IO itself, bytes for the file are appended to the
+/// output buffers provided to `write` and `finish`.
+#[derive(Debug)]
+pub struct ParquetWriter {
+    props: Arc<WriterProperties>,
+    /// Flattened schema, starting with the root element.
+    schema: Vec<SchemaElement>,
+    columns: Vec<ColumnWriter>,
+    /// Metadata for all row groups written so far.
+    row_groups: Vec<RowGroup>,
+    /// Total bytes emitted so far. This is our current position in the file.
+    bytes_written: usize,
+    /// Number of rows buffered in the current row group.
+    buffered_rows: usize,
+}
+
+impl ParquetWriter {
+    pub fn try_new(schema: &ColumnSchema, props: Arc<WriterProperties>) -> Result<Self> {
+        let mut elements = Vec::with_capacity(schema.fields.len() + 1);
+        elements.push(SchemaElement::new(
+            None,
+            None,
+            None,
+            "schema".to_string(),
+            schema.fields.len() as i32,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        let mut columns = Vec::with_capacity(schema.fields.len());
+        for field in &schema.fields {
+            let (column, element) = ColumnWriter::try_new(field, props.clone())?;
+            columns.push(column);
+            elements.push(element);
+        }
+
+        Ok(ParquetWriter {
+            props,
+            schema: elements,
+            columns,
+            row_groups: Vec::new(),
+            bytes_written: 0,
+            buffered_rows: 0,
+        })
+    }
+
+    /// Write a batch, appending any bytes for the file to `out`.
+    ///
+    /// Rows are buffered until we have enough rows for a row group.
+    pub fn write(&mut self, batch: &Batch, out: &mut Vec<u8>) -> Result<()> {
+        if batch.arrays().len() != self.columns.len() {
+            return Err(DbError::new("Batch does not match parquet schema")
+                .with_field("expected", self.columns.len())
+                .with_field("got", batch.arrays().len()));
+        }
+
+        self.write_magic_if_needed(out);
+
+        let max_rows = self.props.max_row_group_size();
+        let mut offset = 0;
+
+        while offset < batch.num_rows() {
+            let count = usize::min(batch.num_rows() - offset, max_rows - self.buffered_rows);
+
+            for (column, array) in self.columns.iter_mut().zip(batch.arrays()) {
+                column.append(array, offset..(offset + count))?;
+            }
+
+            offset += count;
+            self.buffered_rows += count;
+
+            if self.buffered_rows >= max_rows {
+                self.flush_row_group(out)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finish the file, writing out any remaining rows and the footer.
+    pub fn finish(&mut self, out: &mut Vec<u8>) -> Result<()> {
+        self.write_magic_if_needed(out);
+
+        if self.buffered_rows > 0 {
+            self.flush_row_group(out)?;
+        }
+
+        let num_rows = self.row_groups.iter().map(|rg| rg.num_rows).sum();
+        let metadata = FileMetaData {
+            version: 1,
+            schema: self.schema.clone(),
+            num_rows,
+            row_groups: std::mem::take(&mut self.row_groups),
+            key_value_metadata: self.props.key_value_metadata().cloned(),
+            created_by: Some(self.props.created_by().to_string()),
+            // All columns use the ordering defined by their type, let readers
+            // know they can trust min/max_value.
+            column_orders: Some(
+                (0..self.columns.len())
+                    .map(|_| ColumnOrder::TYPEORDER(TypeDefinedOrder::new()))
+                    .collect(),
+            ),
+            encryption_algorithm: None,
+            footer_signing_key_metadata: None,
+        };
+
+        let start = out.len();
+        write_thrift(&metadata, out)?;
+        let metadata_len = (out.len() - start) as i32;
+
+        out.extend_from_slice(&metadata_len.to_le_bytes());
+        out.extend_from_slice(PARQUET_MAGIC);
+
+        self.bytes_written += out.len() - start;
+
+        Ok(())
+    }
+
+    fn write_magic_if_needed(&mut self, out: &mut Vec<u8>) {
+        if self.bytes_written == 0 {
+            out.extend_from_slice(PARQUET_MAGIC);
+            self.bytes_written += PARQUET_MAGIC.len();
+        }
+    }
+
+    fn flush_row_group(&mut self, out: &mut Vec<u8>) -> Result<()> {
+        let start = out.len();
+        let file_offset = self.bytes_written;
+        // Position in the file of the first byte in `out`. Everything in `out`
+        // has already been counted in `bytes_written`.
+        let base = self.bytes_written - out.len();
+
+        let columns = self
+            .columns
+            .iter_mut()
+            .map(|column| column.flush(base, out))
+            .collect::<Result<Vec<_>>>()?;
+
+        let size = (out.len() - start) as i64;
+        self.bytes_written += out.len() - start;
+
+        let row_group = RowGroup::new(
+            columns,
+            size,
+            self.buffered_rows as i64,
+            None,
+            file_offset as i64,
+            size,
+            self.row_groups.len() as i16,
+        );
+        self.row_groups.push(row_group);
+        self.buffered_rows = 0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glaredb_core::arrays::datatype::DataType;
+    use glaredb_core::arrays::field::Field;
+    use glaredb_core::generate_batch;
+
+    use super::*;
+    use crate::basic::{BrotliLevel, Compression, Encoding, GzipLevel, ZstdLevel};
+    use crate::metadata::FOOTER_SIZE;
+    use crate::metadata::loader::decode_metadata;
+
+    fn decode_footer(buf: &[u8]) -> crate::metadata::ParquetMetaData {
+        let footer = &buf[buf.len() - FOOTER_SIZE..];
+        assert_eq!(PARQUET_MAGIC, &footer[4..]);
+        let len = i32::from_le_bytes(footer[0..4].try_into().unwrap()) as usize;
+        let start = buf.len() - FOOTER_SIZE - len;
+        decode_metadata(&buf[start..(buf.len() - FOOTER_SIZE)]).unwrap()
+    }
+
+    #[test]
+    fn write_multiple_row_groups() {
+        let schema = ColumnSchema::new([
+            Field::new("a", DataType::int32(), true),
+            Field::new("b", DataType::utf8(), true),
+        ]);
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_max_row_group_size(2)
+                .build(),
+        );
+        let mut writer = ParquetWriter::try_new(&schema, props).unwrap();
+
+        let mut buf = Vec::new();
+        writer
+            .write(
+                &generate_batch!([Some(1), None, Some(3)], ["a", "b", "c"]),
+                &mut buf,
+            )
+            .unwrap();
+        // Splitting across writes shouldn't affect offsets.
+        let first_len = buf.len();
+        let mut rest = Vec::new();
+        writer
+            .write(&generate_batch!([Some(4)], ["d"]), &mut rest)
+            .unwrap();
+        writer.finish(&mut rest).unwrap();
+        buf.extend(rest);
+
+        assert_eq!(PARQUET_MAGIC, &buf[0..4]);
+        assert!(first_len > 4);
+
+        let metadata = decode_footer(&buf);
+        assert_eq!(4, metadata.file_metadata.num_rows);
+        assert_eq!(2, metadata.row_groups.len());
+        assert_eq!(2, metadata.row_groups[0].num_rows);
+        assert_eq!(2, metadata.row_groups[1].num_rows);
+
+        assert_eq!(4, metadata.row_groups[0].columns[0].byte_range().0);
+        assert_eq!(
+            first_len as u64,
+            metadata.row_groups[1].columns[0].byte_range().0
+        );
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let schema = ColumnSchema::new([Field::new("a", DataType::list(DataType::int32()), true)]);
+        ParquetWriter::try_new(&schema, Arc::new(WriterProperties::default())).unwrap_err();
+    }
+
+    #[test]
+    fn dictionary_encoding_with_stats() {
+        let schema = ColumnSchema::new([
+            Field::new("a", DataType::int32(), true),
+            Field::new("b", DataType::utf8(), true),
+        ]);
+        let mut writer =
+            ParquetWriter::try_new(&schema, Arc::new(WriterProperties::default())).unwrap();
+
+        let mut buf = Vec::new();
+        writer
+            .write(
+                &generate_batch!(
+                    [Some(3), None, Some(-1), Some(3)],
+                    [Some("dog"), Some("cat"), None, Some("dog")]
+                ),
+                &mut buf,
+            )
+            .unwrap();
+        writer.finish(&mut buf).unwrap();
+
+        let metadata = decode_footer(&buf);
+        assert_eq!(
+            Some(vec![
+                crate::basic::ColumnOrder::TYPE_DEFINED_ORDER(crate::basic::SortOrder::SIGNED),
+                crate::basic::ColumnOrder::TYPE_DEFINED_ORDER(crate::basic::SortOrder::UNSIGNED),
+            ]),
+            metadata.file_metadata.column_orders
+        );
+
+        let col = &metadata.row_groups[0].columns[0];
+        assert_eq!(Some(4), col.dictionary_page_offset);
+        assert!(col.data_page_offset > 4);
+        assert!(col.encodings.contains(&Encoding::RLE_DICTIONARY));
+        let stats = col.statistics.as_ref().unwrap();
+        assert_eq!(Some(&(-1_i32).to_le_bytes()[..]), stats.min_as_bytes());
+        assert_eq!(Some(&3_i32.to_le_bytes()[..]), stats.max_as_bytes());
+        assert_eq!(1, stats.null_count());
+
+        let col = &metadata.row_groups[0].columns[1];
+        assert!(col.dictionary_page_offset.is_some());
+        let stats = col.statistics.as_ref().unwrap();
+        assert_eq!(Some(&b"cat"[..]), stats.min_as_bytes());
+        assert_eq!(Some(&b"dog"[..]), stats.max_as_bytes());
+        assert_eq!(1, stats.null_count());
+    }
+
+    #[test]
+    fn dictionary_fallback_to_plain() {
+        let schema = ColumnSchema::new([Field::new("a", DataType::int64(), true)]);
+        let props = WriterProperties::builder()
+            .set_dictionary_page_size_limit(16)
+            .set_write_batch_size(2)
+            .build();
+        let mut writer = ParquetWriter::try_new(&schema, Arc::new(props)).unwrap();
+
+        let mut buf = Vec::new();
+        writer
+            .write(
+                &generate_batch!((0..10).map(Some).collect::<Vec<Option<i64>>>()),
+                &mut buf,
+            )
+            .unwrap();
+        writer.finish(&mut buf).unwrap();
+
+        let metadata = decode_footer(&buf);
+        let col = &metadata.row_groups[0].columns[0];
+        assert_eq!(10, col.num_values);
+        assert!(col.dictionary_page_offset.is_some());
+        assert!(col.encodings.contains(&Encoding::RLE_DICTIONARY));
+        assert!(col.encodings.contains(&Encoding::PLAIN));
+
+        let stats = col.statistics.as_ref().unwrap();
+        assert_eq!(Some(&0_i64.to_le_bytes()[..]), stats.min_as_bytes());
+        assert_eq!(Some(&9_i64.to_le_bytes()[..]), stats.max_as_bytes());
+    }
+
+    #[test]
+    fn dictionary_disabled() {
+        let schema = ColumnSchema::new([
+            Field::new("a", DataType::int32(), true),
+            Field::new("b", DataType::boolean(), true),
+        ]);
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .build();
+        let mut writer = ParquetWriter::try_new(&schema, Arc::new(props)).unwrap();
+
+        let mut buf = Vec::new();
+        writer
+            .write(
+                &generate_batch!([Some(1), Some(1)], [Some(true), None]),
+                &mut buf,
+            )
+            .unwrap();
+        writer.finish(&mut buf).unwrap();
+
+        let metadata = decode_footer(&buf);
+        let col = &metadata.row_groups[0].columns[0];
+        assert_eq!(None, col.dictionary_page_offset);
+        assert_eq!(vec![Encoding::PLAIN, Encoding::RLE], col.encodings);
+
+        // Booleans are always RLE encoded.
+        let col = &metadata.row_groups[0].columns[1];
+        assert_eq!(None, col.dictionary_page_offset);
+        assert_eq!(vec![Encoding::RLE], col.encodings);
+    }
+
+    #[test]
+    fn compression_codecs() {
+        let codecs = [
+            Compression::UNCOMPRESSED,
+            Compression::SNAPPY,
+            Compression::GZIP(GzipLevel::default()),
+            Compression::BROTLI(BrotliLevel::default()),
+            Compression::LZ4,
+            Compression::ZSTD(ZstdLevel::default()),
+            Compression::LZ4_RAW,
+        ];
+
+        let schema = ColumnSchema::new([Field::new("a", DataType::utf8(), true)]);
+        let values: Vec<_> = (0..1000).map(|i| format!("value_{}", i % 10)).collect();
+
+        for codec in codecs {
+            let props = WriterProperties::builder().set_compression(codec).build();
+            let mut writer = ParquetWriter::try_new(&schema, Arc::new(props)).unwrap();
+
+            let mut buf = Vec::new();
+            writer
+                .write(
+                    &generate_batch!(values.iter().map(|s| s.as_str())),
+                    &mut buf,
+                )
+                .unwrap();
+            writer.finish(&mut buf).unwrap();
+
+            let metadata = decode_footer(&buf);
+            let col = &metadata.row_groups[0].columns[0];
+            assert_eq!(codec, col.compression);
+            assert_eq!(1000, col.num_values);
+            // Chunk should span exactly up to the footer.
+            let (start, len) = col.byte_range();
+            assert_eq!(4, start);
+            assert_eq!(
+                buf.len() - FOOTER_SIZE - metadata_len(&buf),
+                (start + len) as usize,
+            );
+        }
+    }
+
+    #[test]
+    fn unsupported_codec() {
+        let schema = ColumnSchema::new([Field::new("a", DataType::int32(), true)]);
+        let props = WriterProperties::builder()
+            .set_compression(Compression::LZO)
+            .build();
+        ParquetWriter::try_new(&schema, Arc::new(props)).unwrap_err();
+    }
+
+    fn metadata_len(buf: &[u8]) -> usize {
+        let footer = &buf[buf.len() - FOOTER_SIZE..];
+        i32::from_le_bytes(footer[0..4].try_into().unwrap()) as usize
+    }
+}
@@ -0,0 +1,258 @@
+use std::cmp::Ordering;
+
+use half::f16;
+
+use crate::format::Statistics;
+
+/// Ordering to use when comparing values for min/max statistics.
+///
+/// Values are compared using their PLAIN encoded bytes (without the length
+/// prefix for byte arrays).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsOrder {
+    Bool,
+    Signed32,
+    Signed64,
+    Unsigned32,
+    Unsigned64,
+    Float16,
+    Float32,
+    Float64,
+    /// Lexicographic unsigned byte comparison.
+    Bytes,
+    /// 16 byte big-endian two's complement, used for decimals stored as
+    /// FIXED_LEN_BYTE_ARRAY.
+    Signed128,
+}
+
+impl StatsOrder {
+    /// Compare two encoded values.
+    ///
+    /// Returns None if either value is NaN.
+    fn compare(&self, a: &[u8], b: &[u8]) -> Option<Ordering> {
+        fn arr<const N: usize>(v: &[u8]) -> [u8; N] {
+            v[..N].try_into().unwrap()
+        }
+
+        match self {
+            Self::Bool => Some(a[0].cmp(&b[0])),
+            Self::Signed32 => Some(i32::from_le_bytes(arr(a)).cmp(&i32::from_le_bytes(arr(b)))),
+            Self::Signed64 => Some(i64::from_le_bytes(arr(a)).cmp(&i64::from_le_bytes(arr(b)))),
+            Self::Unsigned32 => Some(u32::from_le_bytes(arr(a)).cmp(&u32::from_le_bytes(arr(b)))),
+            Self::Unsigned64 => Some(u64::from_le_bytes(arr(a)).cmp(&u64::from_le_bytes(arr(b)))),
+            Self::Float16 => f16::from_le_bytes(arr(a)).partial_cmp(&f16::from_le_bytes(arr(b))),
+            Self::Float32 => f32::from_le_bytes(arr(a)).partial_cmp(&f32::from_le_bytes(arr(b))),
+            Self::Float64 => f64::from_le_bytes(arr(a)).partial_cmp(&f64::from_le_bytes(arr(b))),
+            Self::Bytes => Some(a.cmp(b)),
+            Self::Signed128 => Some(i128::from_be_bytes(arr(a)).cmp(&i128::from_be_bytes(arr(b)))),
+        }
+    }
+
+    fn is_nan(&self, v: &[u8]) -> bool {
+        match self {
+            Self::Float16 => f16::from_le_bytes([v[0], v[1]]).is_nan(),
+            Self::Float32 => f32::from_le_bytes(v[..4].try_into().unwrap()).is_nan(),
+            Self::Float64 => f64::from_le_bytes(v[..8].try_into().unwrap()).is_nan(),
+            _ => false,
+        }
+    }
+}
+
+/// Min/max and null count statistics for a page or column chunk.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnStats {
+    pub min: Option<Vec<u8>>,
+    pub max: Option<Vec<u8>>,
+    pub null_count: i64,
+}
+
+impl ColumnStats {
+    pub fn update(&mut self, order: StatsOrder, value: &[u8]) {
+        // NaNs are excluded from min/max per the spec.
+        if order.is_nan(value) {
+            return;
+        }
+
+        match &mut self.min {
+            Some(min) => {
+                if order.compare(value, min) == Some(Ordering::Less) {
+                    min.clear();
+                    min.extend_from_slice(value);
+                }
+            }
+            None => self.min = Some(value.to_vec()),
+        }
+
+        match &mut self.max {
+            Some(max) => {
+                if order.compare(value, max) == Some(Ordering::Greater) {
+                    max.clear();
+                    max.extend_from_slice(value);
+                }
+            }
+            None => self.max = Some(value.to_vec()),
+        }
+    }
+
+    /// Merge other stats into this one.
+    pub fn merge(&mut self, order: StatsOrder, other: &ColumnStats) {
+        if let Some(min) = &other.min {
+            self.update(order, min);
+        }
+        if let Some(max) = &other.max {
+            self.update(order, max);
+        }
+        self.null_count += other.null_count;
+    }
+
+    /// Convert to thrift statistics.
+    ///
+    /// Min/max values larger than `max_size` are omitted.
+    pub fn to_thrift(&self, order: StatsOrder, max_size: usize) -> Statistics {
+        let fits = |v: &Vec<u8>| v.len() <= max_size;
+        let (mut min, mut max) = match (&self.min, &self.max) {
+            (Some(min), Some(max)) if fits(min) && fits(max) => {
+                (Some(min.clone()), Some(max.clone()))
+            }
+            _ => (None, None),
+        };
+
+        // Readers should treat a zero min as -0.0 and a zero max as +0.0. Write
+        // them out that way to be safe.
+        if let (Some(min), Some(max)) = (&mut min, &mut max) {
+            match order {
+                StatsOrder::Float32 => {
+                    if f32::from_le_bytes(min[..4].try_into().unwrap()) == 0.0 {
+                        min.copy_from_slice(&(-0.0_f32).to_le_bytes());
+                    }
+                    if f32::from_le_bytes(max[..4].try_into().unwrap()) == 0.0 {
+                        max.copy_from_slice(&0.0_f32.to_le_bytes());
+                    }
+                }
+                StatsOrder::Float64 => {
+                    if f64::from_le_bytes(min[..8].try_into().unwrap()) == 0.0 {
+                        min.copy_from_slice(&(-0.0_f64).to_le_bytes());
+                    }
+                    if f64::from_le_bytes(max[..8].try_into().unwrap()) == 0.0 {
+                        max.copy_from_slice(&0.0_f64.to_le_bytes());
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // Only write the deprecated min/max fields when the sort order matches
+        // the (signed) order older readers expect.
+        let legacy = matches!(
+            order,
+            StatsOrder::Bool
+                | StatsOrder::Signed32
+                | StatsOrder::Signed64
+                | StatsOrder::Float32
+                | StatsOrder::Float64
+        );
+
+        let is_exact = min.as_ref().map(|_| true);
+
+        Statistics::new(
+            if legacy { max.clone() } else { None },
+            if legacy { min.clone() } else { None },
+            self.null_count,
+            None,
+            max,
+            min,
+            is_exact,
+            is_exact,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_stats() {
+        let mut stats = ColumnStats::default();
+        for v in [4_i32, -2, 8, 1] {
+            stats.update(StatsOrder::Signed32, &v.to_le_bytes());
+        }
+        stats.null_count = 3;
+
+        let thrift = stats.to_thrift(StatsOrder::Signed32, 4096);
+        assert_eq!(Some((-2_i32).to_le_bytes().to_vec()), thrift.min_value);
+        assert_eq!(Some(8_i32.to_le_bytes().to_vec()), thrift.max_value);
+        assert_eq!(thrift.min_value, thrift.min);
+        assert_eq!(Some(3), thrift.null_count);
+    }
+
+    #[test]
+    fn unsigned_stats() {
+        let mut stats = ColumnStats::default();
+        for v in [1_u32, u32::MAX, 5] {
+            stats.update(StatsOrder::Unsigned32, &v.to_le_bytes());
+        }
+
+        let thrift = stats.to_thrift(StatsOrder::Unsigned32, 4096);
+        assert_eq!(Some(1_u32.to_le_bytes().to_vec()), thrift.min_value);
+        assert_eq!(Some(u32::MAX.to_le_bytes().to_vec()), thrift.max_value);
+        // Signed readers would misinterpret these.
+        assert_eq!(None, thrift.min);
+    }
+
+    #[test]
+    fn signed128_stats() {
+        let mut stats = ColumnStats::default();
+        for v in [12_i128, -350, 7] {
+            stats.update(StatsOrder::Signed128, &v.to_be_bytes());
+        }
+
+        let thrift = stats.to_thrift(StatsOrder::Signed128, 4096);
+        assert_eq!(Some((-350_i128).to_be_bytes().to_vec()), thrift.min_value);
+        assert_eq!(Some(12_i128.to_be_bytes().to_vec()), thrift.max_value);
+    }
+
+    #[test]
+    fn float_stats_skip_nan() {
+        let mut stats = ColumnStats::default();
+        for v in [f64::NAN, 1.5, -3.0, f64::NAN] {
+            stats.update(StatsOrder::Float64, &v.to_le_bytes());
+        }
+
+        let thrift = stats.to_thrift(StatsOrder::Float64, 4096);
+        assert_eq!(Some((-3.0_f64).to_le_bytes().to_vec()), thrift.min_value);
+        assert_eq!(Some(1.5_f64.to_le_bytes().to_vec()), thrift.max_value);
+    }
+
+    #[test]
+    fn bytes_stats_too_large() {
+        let mut stats = ColumnStats::default();
+        stats.update(StatsOrder::Bytes, b"a");
+        stats.update(StatsOrder::Bytes, b"zzzzzzzz");
+
+        let thrift = stats.to_thrift(StatsOrder::Bytes, 4);
+        assert_eq!(None, thrift.min_value);
+        assert_eq!(None, thrift.max_value);
+
+        let thrift = stats.to_thrift(StatsOrder::Bytes, 4096);
+        assert_eq!(Some(b"a".to_vec()), thrift.min_value);
+        assert_eq!(Some(b"zzzzzzzz".to_vec()), thrift.max_value);
+    }
+
+    #[test]
+    fn merge_stats() {
+        let mut a = ColumnStats::default();
+        a.update(StatsOrder::Signed64, &4_i64.to_le_bytes());
+        a.null_count = 1;
+
+        let mut b = ColumnStats::default();
+        b.update(StatsOrder::Signed64, &(-4_i64).to_le_bytes());
+        b.update(StatsOrder::Signed64, &10_i64.to_le_bytes());
+        b.null_count = 2;
+
+        a.merge(StatsOrder::Signed64, &b);
+        assert_eq!(Some((-4_i64).to_le_bytes().to_vec()), a.min);
+        assert_eq!(Some(10_i64.to_le_bytes().to_vec()), a.max);
+        assert_eq!(3, a.null_count);
+    }
+}
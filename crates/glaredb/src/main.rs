@@ -4,13 +4,15 @@ use std::path::PathBuf;
 use clap::Parser;
 use crossterm::event::{self, Event, KeyModifiers};
 use ext_csv::extension::CsvExtension;
+use ext_delta::extension::DeltaExtension;
+use ext_iceberg::catalog::datasource::{ICEBERG_REST_DATASOURCE_NAME, IcebergRestDataSource};
 use ext_iceberg::extension::IcebergExtension;
+use ext_json::extension::JsonExtension;
 use ext_parquet::extension::ParquetExtension;
 use ext_spark::SparkExtension;
 use ext_tpch_gen::TpchGenExtension;
 use glaredb_core::engine::single_user::SingleUserEngine;
 use glaredb_core::runtime::pipeline::PipelineRuntime;
-use glaredb_core::runtime::system::SystemRuntime;
 use glaredb_core::shell::lineedit::{KeyEvent, TermSize, UserInput};
 use glaredb_core::shell::{InteractiveShell, RawModeTerm, Shell, ShellSignal};
 use glaredb_error::Result;
@@ -24,6 +26,11 @@ use glaredb_rt_native::runtime::{
 #[clap(name = "glaredb")]
 #[clap(version)]
 struct Arguments {
+    /// Database file to attach, created if it doesn't exist.
+    ///
+    /// The database is attached using the file stem as its name, e.g.
+    /// 'mydb.glare' can be queried with 'mydb.main.<table>'.
+    database: Option<PathBuf>,
     /// Execute a file containing SQL statements and continue.
     #[clap(long)]
     init: Option<PathBuf>,
@@ -104,14 +111,26 @@ impl RawModeTerm for CrosstermRawModeTerm {
 async fn inner(
     args: Arguments,
     executor: impl PipelineRuntime,
-    runtime: impl SystemRuntime,
+    runtime: NativeSystemRuntime,
 ) -> Result<()> {
     let engine = SingleUserEngine::try_new(executor, runtime.clone())?;
     engine.register_extension(SparkExtension)?;
     engine.register_extension(TpchGenExtension)?;
     engine.register_extension(CsvExtension)?;
+    engine.register_extension(JsonExtension)?;
     engine.register_extension(ParquetExtension)?;
+    engine.register_extension(DeltaExtension)?;
     engine.register_extension(IcebergExtension)?;
+    engine.register_datasource(
+        ICEBERG_REST_DATASOURCE_NAME,
+        IcebergRestDataSource::new(runtime.http_client().clone()),
+    )?;
+
+    if let Some(database) = args.database {
+        let path = database.to_string_lossy().replace('\'', "''");
+        let mut q_res = engine.session().query(&format!("ATTACH '{path}'")).await?;
+        q_res.output.collect().await?;
+    }
 
     let mut stdout = BufWriter::new(std::io::stdout());
 
@@ -28,6 +28,7 @@ pub enum ArrayBufferType {
     Dictionary,
     List,
     Struct,
+    Map,
 }
 
 pub trait ArrayBuffer: Sized + Sync + Send + 'static {
@@ -243,6 +244,9 @@ impl AnyArrayBuffer {
                 manager, datatype, capacity,
             )?)),
             PhysicalType::Struct => Ok(Self::new_unique(StructBuffer::try_new(manager, capacity)?)),
+            PhysicalType::Map => Ok(Self::new_unique(MapBuffer::try_new(
+                manager, datatype, capacity,
+            )?)),
         }
     }
 
@@ -531,6 +535,56 @@ impl ArrayBuffer for ListBuffer {
     }
 }
 
+/// Buffer for map arrays.
+///
+/// Keys and values are stored in separate child buffers, with each map entry
+/// being at the same position in both children. Both children share the same
+/// offsets and lengths from the metadata.
+#[derive(Debug)]
+pub struct MapBuffer {
+    pub(crate) metadata: DbVec<ListItemMetadata>,
+    pub(crate) keys: ListChildBuffer,
+    pub(crate) values: ListChildBuffer,
+}
+
+impl MapBuffer {
+    pub fn try_new(
+        manager: &impl AsRawBufferManager,
+        datatype: &DataType,
+        capacity: usize,
+    ) -> Result<Self> {
+        let map_meta = datatype.try_get_map_type_meta()?;
+        let keys = ListChildBuffer {
+            validity: Validity::new_all_valid(0),
+            buffer: AnyArrayBuffer::new_for_datatype(manager, &map_meta.key, 0)?,
+        };
+        let values = ListChildBuffer {
+            validity: Validity::new_all_valid(0),
+            buffer: AnyArrayBuffer::new_for_datatype(manager, &map_meta.value, 0)?,
+        };
+
+        let metadata = unsafe { DbVec::new_uninit(manager, capacity)? };
+
+        Ok(MapBuffer {
+            metadata,
+            keys,
+            values,
+        })
+    }
+}
+
+impl ArrayBuffer for MapBuffer {
+    const BUFFER_TYPE: ArrayBufferType = ArrayBufferType::Map;
+
+    fn logical_len(&self) -> usize {
+        self.metadata.len()
+    }
+
+    fn resize(&mut self, len: usize) -> Result<()> {
+        unsafe { self.metadata.resize_uninit(len) }
+    }
+}
+
 #[derive(Debug)]
 pub struct StructBuffer {}
 
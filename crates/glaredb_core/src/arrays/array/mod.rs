@@ -16,6 +16,7 @@ use array_buffer::{
     ListBuffer,
     ListChildBuffer,
     ListItemMetadata,
+    MapBuffer,
 };
 use execution_format::{ExecutionFormat, ExecutionFormatMut};
 use glaredb_error::{DbError, Result, not_implemented};
@@ -607,6 +608,7 @@ fn get_physical_value<'a>(
             Ok(BorrowedScalarValue::Timestamp(TimestampScalar {
                 unit: m.unit,
                 value: *v,
+                tz: m.tz.clone(),
             }))
         }
         DataTypeId::Date32 => {
@@ -617,6 +619,10 @@ fn get_physical_value<'a>(
             let v = get_value_inner::<PhysicalI64>(buffer, row_idx)?;
             Ok(BorrowedScalarValue::Date64(*v))
         }
+        DataTypeId::Time => {
+            let v = get_value_inner::<PhysicalI64>(buffer, row_idx)?;
+            Ok(BorrowedScalarValue::Time(*v))
+        }
         DataTypeId::Utf8 => {
             let v = get_value_inner::<PhysicalUtf8>(buffer, row_idx)?;
             Ok(BorrowedScalarValue::Utf8(v.into()))
@@ -625,6 +631,10 @@ fn get_physical_value<'a>(
             let v = get_value_inner::<PhysicalBinary>(buffer, row_idx)?;
             Ok(BorrowedScalarValue::Binary(v.into()))
         }
+        DataTypeId::Uuid => {
+            let v = get_value_inner::<PhysicalU128>(buffer, row_idx)?;
+            Ok(BorrowedScalarValue::Uuid(*v))
+        }
         DataTypeId::List => {
             let m = datatype.try_get_list_type_meta()?;
             let list = ListBuffer::downcast_execution_format(buffer)?.into_selection_format()?;
@@ -645,6 +655,32 @@ fn get_physical_value<'a>(
 
             Ok(BorrowedScalarValue::List(vals))
         }
+        DataTypeId::Map => {
+            let m = datatype.try_get_map_type_meta()?;
+            let map = MapBuffer::downcast_execution_format(buffer)?.into_selection_format()?;
+            let sel_idx = map.selection.get(row_idx).unwrap();
+            let meta = map.buffer.metadata.as_slice()[sel_idx];
+
+            let mut entries = Vec::with_capacity(meta.len as usize);
+
+            for child_idx in meta.offset..(meta.offset + meta.len) {
+                let key = get_physical_value(
+                    &m.key,
+                    &map.buffer.keys.validity,
+                    &map.buffer.keys.buffer,
+                    child_idx as usize,
+                )?;
+                let value = get_physical_value(
+                    &m.value,
+                    &map.buffer.values.validity,
+                    &map.buffer.values.buffer,
+                    child_idx as usize,
+                )?;
+                entries.push((key, value));
+            }
+
+            Ok(BorrowedScalarValue::Map(entries))
+        }
 
         other => not_implemented!("get value for scalar type: {other:?}"),
     }
@@ -754,6 +790,9 @@ fn set_physical_value(
         BorrowedScalarValue::Date64(val) => {
             set_value_inner::<PhysicalI64>(buffer, val, row_idx)?;
         }
+        BorrowedScalarValue::Time(val) => {
+            set_value_inner::<PhysicalI64>(buffer, val, row_idx)?;
+        }
         BorrowedScalarValue::Timestamp(val) => {
             set_value_inner::<PhysicalI64>(buffer, &val.value, row_idx)?;
         }
@@ -766,15 +805,75 @@ fn set_physical_value(
         BorrowedScalarValue::Binary(val) => {
             set_value_inner::<PhysicalBinary>(buffer, val, row_idx)?;
         }
+        BorrowedScalarValue::Uuid(val) => {
+            set_value_inner::<PhysicalU128>(buffer, val, row_idx)?;
+        }
         BorrowedScalarValue::List(val) => {
             set_list_value_raw(datatype, validity, buffer, val, row_idx)?
         }
+        BorrowedScalarValue::Map(entries) => set_map_value(datatype, buffer, entries, row_idx)?,
         BorrowedScalarValue::Struct(_) => not_implemented!("set value for struct"),
     }
 
     Ok(())
 }
 
+/// Set a map value for a row by appending the entries to the end of the key
+/// and value child buffers.
+fn set_map_value(
+    datatype: &DataType,
+    buffer: &mut AnyArrayBuffer,
+    entries: &[(BorrowedScalarValue, BorrowedScalarValue)],
+    row_idx: usize,
+) -> Result<()> {
+    let m = datatype.try_get_map_type_meta()?;
+    let map = match MapBuffer::downcast_execution_format_mut(buffer)? {
+        ExecutionFormatMut::Flat(buf) => buf,
+        ExecutionFormatMut::Selection(_) => {
+            return Err(DbError::new(
+                "Cannot set map value with selection on map array",
+            ));
+        }
+    };
+
+    let offset = map.keys.validity.len();
+    let new_len = offset + entries.len();
+
+    for child in [&mut map.keys, &mut map.values] {
+        child.validity.resize(new_len);
+        if child.buffer.logical_len() < new_len {
+            child.buffer.resize(new_len)?;
+        }
+    }
+
+    for (idx, (key, value)) in entries.iter().enumerate() {
+        if key.is_null() {
+            return Err(DbError::new("Map keys cannot be NULL"));
+        }
+        set_physical_value(
+            key,
+            &m.key,
+            &mut map.keys.validity,
+            &mut map.keys.buffer,
+            offset + idx,
+        )?;
+        set_physical_value(
+            value,
+            &m.value,
+            &mut map.values.validity,
+            &mut map.values.buffer,
+            offset + idx,
+        )?;
+    }
+
+    map.metadata.as_slice_mut()[row_idx] = ListItemMetadata {
+        offset: offset as i32,
+        len: entries.len() as i32,
+    };
+
+    Ok(())
+}
+
 /// Implements `try_from_iter` for primitive types.
 ///
 /// Note these create arrays using Nop buffer manager and so really only
@@ -10,6 +10,7 @@ use super::array_buffer::{
     ArrayBufferDowncast,
     ListBuffer,
     ListItemMetadata,
+    MapBuffer,
     ScalarBuffer,
     StringBuffer,
     StringViewBuffer,
@@ -40,6 +41,7 @@ pub enum PhysicalType {
     Utf8,
     List,
     Struct,
+    Map,
 }
 
 impl PhysicalType {
@@ -65,6 +67,7 @@ impl PhysicalType {
             Self::Utf8 => "Utf8",
             Self::List => "List",
             Self::Struct => "Struct",
+            Self::Map => "Map",
         }
     }
 }
@@ -100,6 +103,7 @@ impl ProtoConv for PhysicalType {
             Self::Binary => Self::ProtoType::Binary,
             Self::List => Self::ProtoType::List,
             Self::Struct => Self::ProtoType::Struct,
+            Self::Map => Self::ProtoType::Map,
         })
     }
 
@@ -126,6 +130,7 @@ impl ProtoConv for PhysicalType {
             Self::ProtoType::Binary => Self::Binary,
             Self::ProtoType::List => Self::List,
             Self::ProtoType::Struct => Self::Struct,
+            Self::ProtoType::Map => Self::Map,
         })
     }
 }
@@ -533,3 +538,31 @@ impl MutableScalarStorage for PhysicalList {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhysicalMap;
+
+impl ScalarStorage for PhysicalMap {
+    const PHYSICAL_TYPE: PhysicalType = PhysicalType::Map;
+
+    type StorageType = ListItemMetadata;
+    type Addressable<'a> = PrimitiveSlice<'a, Self::StorageType>;
+
+    type ArrayBuffer = MapBuffer;
+
+    fn addressable(buffer: &Self::ArrayBuffer) -> Self::Addressable<'_> {
+        PrimitiveSlice {
+            slice: buffer.metadata.as_slice(),
+        }
+    }
+}
+
+impl MutableScalarStorage for PhysicalMap {
+    type AddressableMut<'a> = PrimitiveSliceMut<'a, Self::StorageType>;
+
+    fn addressable_mut(buffer: &mut Self::ArrayBuffer) -> Self::AddressableMut<'_> {
+        PrimitiveSliceMut {
+            slice: buffer.metadata.as_slice_mut(),
+        }
+    }
+}
@@ -89,6 +89,39 @@ impl Validity {
         }
     }
 
+    /// Resize the validity mask to `new_len`.
+    ///
+    /// Newly added entries are marked valid. Existing entries past `new_len`
+    /// are discarded.
+    pub fn resize(&mut self, new_len: usize) {
+        match &mut self.inner {
+            ValidityInner::AllValid { len } => *len = new_len,
+            ValidityInner::AllInvalid { len } => {
+                let old_len = *len;
+                let mut data = vec![u8::MAX; num_bytes_for_bitmap(new_len)];
+                let mut view = BitmapViewMut::new(&mut data, new_len);
+                for idx in 0..usize::min(old_len, new_len) {
+                    view.unset(idx);
+                }
+                self.inner = ValidityInner::Mask { len: new_len, data }
+            }
+            ValidityInner::Mask { len, data } => {
+                let old_view = BitmapView::new(data, *len);
+                let mut new_data = vec![u8::MAX; num_bytes_for_bitmap(new_len)];
+                let mut new_view = BitmapViewMut::new(&mut new_data, new_len);
+                for idx in 0..usize::min(*len, new_len) {
+                    if !old_view.value(idx) {
+                        new_view.unset(idx);
+                    }
+                }
+                self.inner = ValidityInner::Mask {
+                    len: new_len,
+                    data: new_data,
+                }
+            }
+        }
+    }
+
     /// Produce a new validity bitmap by applying a selection on an existing
     /// mask.
     pub fn select(&self, selection: impl IntoExactSizeIterator<Item = usize>) -> Self {
@@ -162,4 +195,28 @@ mod tests {
         assert!(new_v.is_valid(1));
         assert!(new_v.is_valid(2));
     }
+
+    #[test]
+    fn resize_keeps_existing_entries() {
+        let mut v = Validity::new_all_valid(3);
+        v.set_invalid(1);
+        v.resize(10);
+
+        assert_eq!(10, v.len());
+        assert!(v.is_valid(0));
+        assert!(!v.is_valid(1));
+        assert!(v.is_valid(2));
+        assert!(v.is_valid(9));
+    }
+
+    #[test]
+    fn resize_all_invalid() {
+        let mut v = Validity::new_all_invalid(2);
+        v.resize(4);
+
+        assert!(!v.is_valid(0));
+        assert!(!v.is_valid(1));
+        assert!(v.is_valid(2));
+        assert!(v.is_valid(3));
+    }
 }
@@ -1,13 +1,21 @@
-use glaredb_error::{Result, not_implemented};
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
 
 use crate::arrays::array::Array;
 use crate::arrays::array::array_buffer::AnyArrayBuffer;
+use crate::arrays::array::physical_type::{AddressableMut, MutableScalarStorage, PhysicalI64};
 use crate::arrays::array::validity::Validity;
 use crate::arrays::batch::Batch;
 use crate::arrays::compute::copy::copy_rows_raw;
 use crate::arrays::datatype::DataType;
-use crate::buffer::buffer_manager::AsRawBufferManager;
+use crate::buffer::buffer_manager::{AsRawBufferManager, DefaultBufferManager};
 use crate::storage::projections::{ProjectedColumn, Projections};
+use crate::transaction::{NOT_DELETED, SYSTEM_VERSION, Snapshot};
+
+/// Metadata projection index for the row id column.
+///
+/// Row ids are the relative offsets of rows in the collection.
+pub const META_PROJECTION_ROWID: usize = 0;
 
 #[derive(Debug)]
 pub struct ColumnChunk {
@@ -21,6 +29,33 @@ pub struct ColumnChunk {
     pub capacity: usize,
     /// Number of rows filled up in this chunk.
     pub filled: usize,
+    /// Versions determining which rows are visible to a scan.
+    pub versions: Mutex<ChunkVersions>,
+}
+
+/// Insert and delete versions for rows in a chunk.
+#[derive(Debug)]
+pub struct ChunkVersions {
+    /// Version all rows in the chunk were inserted with.
+    ///
+    /// Chunks are only ever written to by a single append state, so all rows
+    /// share the same version.
+    pub inserted: u64,
+    /// Delete version for each row, None if no rows have been deleted.
+    pub deleted: Option<Vec<u64>>,
+}
+
+impl ChunkVersions {
+    /// Returns if the row at `idx` is visible to the snapshot.
+    pub fn is_row_visible(&self, snapshot: &Snapshot, idx: usize) -> bool {
+        if !snapshot.is_visible(self.inserted) {
+            return false;
+        }
+        match &self.deleted {
+            Some(deleted) => !snapshot.is_visible(deleted[idx]),
+            None => true,
+        }
+    }
 }
 
 impl ColumnChunk {
@@ -43,6 +78,10 @@ impl ColumnChunk {
             buffers,
             capacity,
             filled: 0,
+            versions: Mutex::new(ChunkVersions {
+                inserted: SYSTEM_VERSION,
+                deleted: None,
+            }),
         })
     }
 
@@ -71,10 +110,72 @@ impl ColumnChunk {
         Ok(())
     }
 
+    /// Marks a row in this chunk as deleted by the transaction with the given
+    /// version.
+    ///
+    /// Returns false if the row was already deleted. Errors if the row was
+    /// deleted by a transaction the snapshot can't see.
+    pub fn delete_row(&self, snapshot: &Snapshot, version: u64, idx: usize) -> Result<bool> {
+        if idx >= self.filled {
+            return Err(DbError::new("Row index out of range for chunk")
+                .with_field("idx", idx)
+                .with_field("filled", self.filled));
+        }
+
+        let mut versions = self.versions.lock();
+        if !snapshot.is_visible(versions.inserted) {
+            return Err(
+                DbError::new("Cannot delete row not visible to transaction").with_field("idx", idx)
+            );
+        }
+
+        let capacity = self.capacity;
+        let deleted = versions
+            .deleted
+            .get_or_insert_with(|| vec![NOT_DELETED; capacity]);
+
+        let current = deleted[idx];
+        if current == version || snapshot.is_visible(current) {
+            return Ok(false);
+        }
+        if current != NOT_DELETED {
+            return Err(DbError::new(
+                "Write conflict, row was modified by a concurrent transaction",
+            ));
+        }
+        deleted[idx] = version;
+
+        Ok(true)
+    }
+
+    /// Set the insert version for all rows in this chunk.
+    pub fn set_inserted_version(&self, version: u64) {
+        self.versions.lock().inserted = version;
+    }
+
+    /// Replace the delete version for all rows deleted with `from` with `to`.
+    pub fn replace_deleted_version(&self, from: u64, to: u64) {
+        let mut versions = self.versions.lock();
+        if let Some(deleted) = &mut versions.deleted {
+            for version in deleted.iter_mut().filter(|v| **v == from) {
+                *version = to;
+            }
+        }
+    }
+
     /// Scan the chunk into the output batch.
     ///
-    /// Projections indicates which columns to scan.
-    pub fn scan(&self, projections: &Projections, output: &mut Batch) -> Result<usize> {
+    /// Projections indicates which columns to scan. Rows not visible to the
+    /// snapshot are omitted from the output.
+    ///
+    /// Returns the number of rows scanned. Zero is returned if no rows in the
+    /// chunk are visible.
+    pub fn scan(
+        &self,
+        snapshot: &Snapshot,
+        projections: &Projections,
+        output: &mut Batch,
+    ) -> Result<usize> {
         debug_assert!(
             projections
                 .data_indices()
@@ -82,14 +183,57 @@ impl ColumnChunk {
                 .all(|&idx| idx < self.buffers.len())
         );
 
+        let versions = self.versions.lock();
+        if !snapshot.is_visible(versions.inserted) {
+            output.set_num_rows(0)?;
+            return Ok(0);
+        }
+
+        let selection: Option<Vec<usize>> = match &versions.deleted {
+            Some(deleted) => {
+                let selection: Vec<_> = deleted
+                    .iter()
+                    .take(self.filled)
+                    .enumerate()
+                    .filter_map(|(idx, &version)| (!snapshot.is_visible(version)).then_some(idx))
+                    .collect();
+                if selection.is_empty() {
+                    output.set_num_rows(0)?;
+                    return Ok(0);
+                }
+                (selection.len() != self.filled).then_some(selection)
+            }
+            None => None,
+        };
+        std::mem::drop(versions);
+
         projections.for_each_column(output, &mut |col_idx, output| match col_idx {
             ProjectedColumn::Data(idx) => self.buffers[idx].clone_to_array(output),
-            ProjectedColumn::Metadata(0) => not_implemented!("materialized row id"),
+            ProjectedColumn::Metadata(META_PROJECTION_ROWID) => self.write_row_ids(output),
             other => panic!("invalid projection: {other:?}"),
         })?;
         output.set_num_rows(self.filled)?;
 
-        Ok(self.filled)
+        match selection {
+            Some(selection) => {
+                let num_rows = selection.len();
+                output.select(selection.iter().copied())?;
+                Ok(num_rows)
+            }
+            None => Ok(self.filled),
+        }
+    }
+
+    /// Write row ids for all rows in this chunk to the output array.
+    fn write_row_ids(&self, output: &mut Array) -> Result<()> {
+        let mut row_ids = Array::new(&DefaultBufferManager, DataType::int64(), self.filled)?;
+        let mut data = PhysicalI64::get_addressable_mut(&mut row_ids.data)?;
+        for idx in 0..self.filled {
+            data.put(idx, &((self.relative_offset + idx) as i64));
+        }
+        *output = row_ids;
+
+        Ok(())
     }
 }
 
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use std::sync::atomic::{self, AtomicUsize};
 
-use glaredb_error::Result;
+use glaredb_error::{DbError, Result};
 use parking_lot::Mutex;
 
 use super::segment::ColumnCollectionSegment;
@@ -9,14 +9,105 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::buffer::buffer_manager::DefaultBufferManager;
 use crate::storage::projections::Projections;
+use crate::transaction::{
+    ABORTED_VERSION,
+    NOT_DELETED,
+    SYSTEM_VERSION,
+    Snapshot,
+    TransactionChange,
+};
 
 #[derive(Debug)]
 pub struct ColumnCollectionAppendState {
     segment: ColumnCollectionSegment,
+    /// Version to stamp appended rows with.
+    version: u64,
+    /// Segments flushed by this state, only tracked for versioned appends.
+    flushed: Vec<Arc<ColumnCollectionSegment>>,
+}
+
+impl ColumnCollectionAppendState {
+    /// Take the segments flushed so far for finalizing once the transaction
+    /// that appended them completes.
+    pub fn take_appended(&mut self) -> AppendedRows {
+        AppendedRows {
+            segments: std::mem::take(&mut self.flushed),
+        }
+    }
+}
+
+/// Rows appended to the collection by a transaction.
+#[derive(Debug, Default)]
+pub struct AppendedRows {
+    segments: Vec<Arc<ColumnCollectionSegment>>,
+}
+
+impl AppendedRows {
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+impl TransactionChange for AppendedRows {
+    fn commit(&self, commit_ts: u64) {
+        for segment in &self.segments {
+            segment.set_inserted_version(commit_ts);
+        }
+    }
+
+    fn rollback(&self) {
+        for segment in &self.segments {
+            segment.set_inserted_version(ABORTED_VERSION);
+        }
+    }
+}
+
+/// Rows deleted from the collection by a transaction.
+#[derive(Debug)]
+pub struct DeletedRows {
+    /// Version rows were deleted with.
+    version: u64,
+    /// Segments containing the deleted rows.
+    segments: Vec<Arc<ColumnCollectionSegment>>,
+}
+
+impl DeletedRows {
+    pub fn new(version: u64) -> Self {
+        DeletedRows {
+            version,
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    fn push_segment(&mut self, segment: &Arc<ColumnCollectionSegment>) {
+        if !self.segments.iter().any(|s| Arc::ptr_eq(s, segment)) {
+            self.segments.push(segment.clone());
+        }
+    }
+}
+
+impl TransactionChange for DeletedRows {
+    fn commit(&self, commit_ts: u64) {
+        for segment in &self.segments {
+            segment.replace_deleted_version(self.version, commit_ts);
+        }
+    }
+
+    fn rollback(&self) {
+        for segment in &self.segments {
+            segment.replace_deleted_version(self.version, NOT_DELETED);
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct ColumnCollectionScanState {
+    /// Snapshot determining which rows are visible.
+    snapshot: Snapshot,
     /// Row offset for the batch we just scanned relative to the entire
     /// collection.
     relative_scan_offset: usize,
@@ -87,9 +178,22 @@ impl ConcurrentColumnCollection {
         }
     }
 
+    /// Initializes an append state for rows that are visible as soon as
+    /// they're flushed.
     pub fn init_append_state(&self) -> ColumnCollectionAppendState {
+        self.init_versioned_append_state(SYSTEM_VERSION)
+    }
+
+    /// Initializes an append state for rows that are only visible to the
+    /// transaction with the given version until it commits.
+    ///
+    /// Flushed rows should be taken from the state with `take_appended` and
+    /// finalized when the transaction completes.
+    pub fn init_versioned_append_state(&self, version: u64) -> ColumnCollectionAppendState {
         ColumnCollectionAppendState {
             segment: ColumnCollectionSegment::new(self.chunk_capacity),
+            version,
+            flushed: Vec::new(),
         }
     }
 
@@ -99,12 +203,29 @@ impl ConcurrentColumnCollection {
         &self,
         num_parallel: usize,
     ) -> impl Iterator<Item = ParallelColumnCollectionScanState> + '_ {
-        CreateParallelStateIter::new(num_parallel)
+        self.init_snapshot_parallel_scan_states(Snapshot::LATEST, num_parallel)
+    }
+
+    /// Like `init_parallel_scan_states`, but only scanning rows visible to the
+    /// snapshot.
+    pub fn init_snapshot_parallel_scan_states(
+        &self,
+        snapshot: Snapshot,
+        num_parallel: usize,
+    ) -> impl Iterator<Item = ParallelColumnCollectionScanState> + '_ {
+        CreateParallelStateIter::new(snapshot, num_parallel)
     }
 
-    /// Initializes a scan state for reading all batches in the collection.
+    /// Initializes a scan state for reading all committed batches in the
+    /// collection.
     pub fn init_scan_state(&self) -> ColumnCollectionScanState {
+        self.init_snapshot_scan_state(Snapshot::LATEST)
+    }
+
+    /// Initializes a scan state for reading all rows visible to the snapshot.
+    pub fn init_snapshot_scan_state(&self, snapshot: Snapshot) -> ColumnCollectionScanState {
         ColumnCollectionScanState {
+            snapshot,
             relative_scan_offset: 0,
             next_segment_idx: 0,
             segment: None,
@@ -130,12 +251,28 @@ impl ConcurrentColumnCollection {
         state: &mut ColumnCollectionAppendState,
         batch: &Batch,
     ) -> Result<()> {
+        self.append_batch_with(state, batch, |_| Ok(()))
+    }
+
+    /// Appends a batch to the collection, calling `on_flush` if the append
+    /// triggers a flush.
+    ///
+    /// See `flush_with`.
+    pub fn append_batch_with<F>(
+        &self,
+        state: &mut ColumnCollectionAppendState,
+        batch: &Batch,
+        on_flush: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&Batch) -> Result<()>,
+    {
         state
             .segment
             .append_batch(&DefaultBufferManager, batch, &self.datatypes)?;
 
         if state.segment.num_chunks() >= self.segment_size {
-            self.flush(state)?;
+            self.flush_with(state, on_flush)?;
         }
 
         Ok(())
@@ -145,6 +282,24 @@ impl ConcurrentColumnCollection {
     ///
     /// The state may continue to be used.
     pub fn flush(&self, state: &mut ColumnCollectionAppendState) -> Result<()> {
+        self.flush_with(state, |_| Ok(()))
+    }
+
+    /// Flushes any pending chunks from the append state to the collection,
+    /// calling `on_flush` with the rows of each chunk before they're visible
+    /// to scans.
+    ///
+    /// `on_flush` is called while holding the lock for the flushed segments,
+    /// so rows are passed to it in the same order they're assigned offsets in
+    /// the collection, even across append states.
+    pub fn flush_with<F>(
+        &self,
+        state: &mut ColumnCollectionAppendState,
+        mut on_flush: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&Batch) -> Result<()>,
+    {
         let mut segment = std::mem::replace(
             &mut state.segment,
             ColumnCollectionSegment::new(self.chunk_capacity),
@@ -163,12 +318,97 @@ impl ConcurrentColumnCollection {
         let relative_offset = flushed.flushed_row_count;
         segment.set_relative_offsets(relative_offset);
 
-        flushed.segments.push(Arc::new(segment));
+        let projections = Projections::new(0..self.datatypes.len());
+        let mut batch = Batch::new(self.datatypes.iter().cloned(), 0)?;
+        for chunk_idx in 0..segment.num_chunks() {
+            let chunk = segment.get_chunk(chunk_idx).expect("chunk to exist");
+            if chunk.scan(&Snapshot::LATEST, &projections, &mut batch)? > 0 {
+                on_flush(&batch)?;
+            }
+        }
+
+        segment.set_inserted_version(state.version);
+        let segment = Arc::new(segment);
+        if state.version != SYSTEM_VERSION {
+            state.flushed.push(segment.clone());
+        }
+
+        flushed.segments.push(segment);
         flushed.flushed_row_count += num_rows;
 
         Ok(())
     }
 
+    /// Marks rows as deleted, returning the number of rows that weren't
+    /// already deleted.
+    ///
+    /// Row ids are the relative offsets of rows in the collection, and must
+    /// be for rows that have been flushed and are visible to the snapshot.
+    /// Rows are deleted with the version of `deleted`, and are skipped by all
+    /// scans that can see that version.
+    ///
+    /// Errors if a row has been deleted by a transaction that the snapshot
+    /// can't see. Rows deleted before the error are still tracked in
+    /// `deleted`.
+    pub fn delete_rows(
+        &self,
+        snapshot: &Snapshot,
+        row_ids: impl IntoIterator<Item = usize>,
+        deleted: &mut DeletedRows,
+    ) -> Result<usize> {
+        let flushed = self.flushed.lock();
+
+        let mut count = 0;
+        for row_id in row_ids {
+            if row_id >= flushed.flushed_row_count {
+                return Err(DbError::new("Row id out of range for collection")
+                    .with_field("row_id", row_id)
+                    .with_field("flushed_row_count", flushed.flushed_row_count));
+            }
+
+            // Segments are ordered by offset, and the first segment starts at
+            // zero.
+            let idx = flushed
+                .segments
+                .partition_point(|segment| segment.relative_offset() <= row_id);
+            let segment = &flushed.segments[idx - 1];
+            if segment.delete_row(snapshot, deleted.version, row_id)? {
+                deleted.push_segment(segment);
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Returns the row ids of all rows not visible to the latest snapshot in
+    /// sorted order.
+    ///
+    /// This includes deleted rows, rows from rolled back transactions, and
+    /// rows from transactions that haven't yet committed.
+    pub fn deleted_row_ids(&self) -> Vec<usize> {
+        let flushed = self.flushed.lock();
+        let snapshot = Snapshot::LATEST;
+
+        let mut row_ids = Vec::new();
+        for segment in &flushed.segments {
+            for chunk_idx in 0..segment.num_chunks() {
+                let chunk = segment.get_chunk(chunk_idx).expect("chunk to exist");
+                let versions = chunk.versions.lock();
+                if snapshot.is_visible(versions.inserted) && versions.deleted.is_none() {
+                    continue;
+                }
+                row_ids.extend(
+                    (0..chunk.filled)
+                        .filter(|&idx| !versions.is_row_visible(&snapshot, idx))
+                        .map(|idx| chunk.relative_offset + idx),
+                );
+            }
+        }
+
+        row_ids
+    }
+
     /// Scans the next batch from the collection.
     ///
     /// Returns the number of rows scanned into output. Zero may be returned if
@@ -231,10 +471,15 @@ impl ConcurrentColumnCollection {
 
             match segment.get_chunk(state.chunk_idx) {
                 Some(chunk) => {
-                    let num_rows = chunk.scan(projections, output)?;
+                    let num_rows = chunk.scan(&state.snapshot, projections, output)?;
                     // TODO: Do we need to check that that chunk is exhausted
                     // before incrementing?
                     state.chunk_idx += 1;
+                    if num_rows == 0 {
+                        // No visible rows in the chunk, move to the next one
+                        // since zero indicates the scan is complete.
+                        continue;
+                    }
                     state.relative_scan_offset = chunk.relative_offset;
 
                     return Ok(num_rows);
@@ -251,14 +496,16 @@ impl ConcurrentColumnCollection {
 /// Helper for creating parallel scan states.
 #[derive(Debug)]
 struct CreateParallelStateIter {
+    snapshot: Snapshot,
     next: Arc<AtomicUsize>,
     idx: usize,
     count: usize,
 }
 
 impl CreateParallelStateIter {
-    fn new(num_parallel: usize) -> Self {
+    fn new(snapshot: Snapshot, num_parallel: usize) -> Self {
         CreateParallelStateIter {
+            snapshot,
             next: Arc::new(AtomicUsize::new(num_parallel)),
             idx: 0,
             count: num_parallel,
@@ -277,6 +524,7 @@ impl Iterator for CreateParallelStateIter {
         let state = ParallelColumnCollectionScanState {
             next: self.next.clone(),
             state: ColumnCollectionScanState {
+                snapshot: self.snapshot,
                 relative_scan_offset: 0,
                 next_segment_idx: self.idx,
                 segment: None,
@@ -300,8 +548,44 @@ impl ExactSizeIterator for CreateParallelStateIter {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arrays::collection::chunk::META_PROJECTION_ROWID;
     use crate::generate_batch;
     use crate::testutil::arrays::assert_batches_eq;
+    use crate::transaction::TRANSACTION_ID_START;
+
+    /// Delete rows outside of a transaction.
+    fn delete_rows(
+        collection: &ConcurrentColumnCollection,
+        row_ids: impl IntoIterator<Item = usize>,
+    ) -> Result<usize> {
+        collection.delete_rows(
+            &Snapshot::LATEST,
+            row_ids,
+            &mut DeletedRows::new(SYSTEM_VERSION),
+        )
+    }
+
+    /// Scan all rows visible to the snapshot into a single batch.
+    fn scan_all(collection: &ConcurrentColumnCollection, snapshot: Snapshot) -> Vec<i32> {
+        let projections = Projections::new([0]);
+        let mut scan_state = collection.init_snapshot_scan_state(snapshot);
+        let mut output = Batch::new([DataType::int32()], 16).unwrap();
+
+        let mut values = Vec::new();
+        loop {
+            let count = collection
+                .scan(&projections, &mut scan_state, &mut output)
+                .unwrap();
+            if count == 0 {
+                break;
+            }
+            for idx in 0..count {
+                let value = output.arrays[0].get_value(idx).unwrap();
+                values.push(value.try_as_i32().unwrap());
+            }
+        }
+        values
+    }
 
     #[test]
     fn append_scan_simple() {
@@ -501,4 +785,204 @@ mod tests {
         assert_batches_eq(&expected2, &out2);
         assert_eq!(2, scan_states[1].state.relative_scan_offset());
     }
+
+    #[test]
+    fn delete_rows_skipped_by_scan() {
+        let collection =
+            ConcurrentColumnCollection::new([DataType::int32(), DataType::utf8()], 16, 16);
+        let projections = Projections::new([0, 1]);
+
+        let mut append_state = collection.init_append_state();
+        let input = generate_batch!([4, 5, 6, 7], ["a", "b", "c", "d"]);
+        collection.append_batch(&mut append_state, &input).unwrap();
+        collection.flush(&mut append_state).unwrap();
+
+        let count = delete_rows(&collection, [1, 3]).unwrap();
+        assert_eq!(2, count);
+
+        // Deleting again doesn't count.
+        let count = delete_rows(&collection, [1]).unwrap();
+        assert_eq!(0, count);
+
+        let mut output = Batch::new([DataType::int32(), DataType::utf8()], 16).unwrap();
+        let mut scan_state = collection.init_scan_state();
+        collection
+            .scan(&projections, &mut scan_state, &mut output)
+            .unwrap();
+
+        let expected = generate_batch!([4, 6], ["a", "c"]);
+        assert_batches_eq(&expected, &output);
+        assert_eq!(vec![1, 3], collection.deleted_row_ids());
+    }
+
+    #[test]
+    fn delete_all_rows_in_chunk() {
+        // SEGMENT SIZE: 1 chunk
+        // CHUNK CAPACITY: 2 rows
+        let collection = ConcurrentColumnCollection::new([DataType::int32()], 1, 2);
+        let projections = Projections::new([0]);
+
+        let mut append_state = collection.init_append_state();
+        let input1 = generate_batch!([4, 5]);
+        collection.append_batch(&mut append_state, &input1).unwrap();
+        collection.flush(&mut append_state).unwrap();
+        let input2 = generate_batch!([6, 7]);
+        collection.append_batch(&mut append_state, &input2).unwrap();
+        collection.flush(&mut append_state).unwrap();
+
+        delete_rows(&collection, [0, 1]).unwrap();
+
+        // Scan should skip the first chunk entirely.
+        let mut output = Batch::new([DataType::int32()], 2).unwrap();
+        let mut scan_state = collection.init_scan_state();
+        collection
+            .scan(&projections, &mut scan_state, &mut output)
+            .unwrap();
+        assert_batches_eq(&input2, &output);
+
+        collection
+            .scan(&projections, &mut scan_state, &mut output)
+            .unwrap();
+        assert_eq!(0, output.num_rows());
+    }
+
+    #[test]
+    fn delete_rows_out_of_range() {
+        let collection = ConcurrentColumnCollection::new([DataType::int32()], 16, 16);
+
+        let mut append_state = collection.init_append_state();
+        let input = generate_batch!([4, 5]);
+        collection.append_batch(&mut append_state, &input).unwrap();
+        collection.flush(&mut append_state).unwrap();
+
+        delete_rows(&collection, [2]).unwrap_err();
+    }
+
+    #[test]
+    fn scan_row_ids() {
+        let collection = ConcurrentColumnCollection::new([DataType::utf8()], 1, 2);
+        let projections = Projections::new_with_meta([0], [META_PROJECTION_ROWID]);
+
+        let mut append_state = collection.init_append_state();
+        let input1 = generate_batch!(["a", "b"]);
+        collection.append_batch(&mut append_state, &input1).unwrap();
+        collection.flush(&mut append_state).unwrap();
+        let input2 = generate_batch!(["c", "d"]);
+        collection.append_batch(&mut append_state, &input2).unwrap();
+        collection.flush(&mut append_state).unwrap();
+
+        delete_rows(&collection, [2]).unwrap();
+
+        let mut output = Batch::new([DataType::utf8(), DataType::int64()], 2).unwrap();
+        let mut scan_state = collection.init_scan_state();
+
+        collection
+            .scan(&projections, &mut scan_state, &mut output)
+            .unwrap();
+        let expected = generate_batch!(["a", "b"], [0_i64, 1]);
+        assert_batches_eq(&expected, &output);
+
+        collection
+            .scan(&projections, &mut scan_state, &mut output)
+            .unwrap();
+        let expected = generate_batch!(["d"], [3_i64]);
+        assert_batches_eq(&expected, &output);
+    }
+
+    #[test]
+    fn versioned_append_visibility() {
+        let collection = ConcurrentColumnCollection::new([DataType::int32()], 1, 2);
+
+        let mut state = collection.init_append_state();
+        collection
+            .append_batch(&mut state, &generate_batch!([1, 2]))
+            .unwrap();
+        collection.flush(&mut state).unwrap();
+
+        let tx_id = TRANSACTION_ID_START;
+        let tx_snapshot = Snapshot {
+            start: 1,
+            transaction_id: tx_id,
+        };
+
+        let mut state = collection.init_versioned_append_state(tx_id);
+        collection
+            .append_batch(&mut state, &generate_batch!([3, 4]))
+            .unwrap();
+        collection.flush(&mut state).unwrap();
+        let appended = state.take_appended();
+        assert!(!appended.is_empty());
+
+        // Only visible to the appending transaction.
+        assert_eq!(vec![1, 2, 3, 4], scan_all(&collection, tx_snapshot));
+        assert_eq!(vec![1, 2], scan_all(&collection, Snapshot::LATEST));
+        assert_eq!(vec![2, 3], collection.deleted_row_ids());
+
+        appended.commit(1);
+        assert_eq!(vec![1, 2, 3, 4], scan_all(&collection, Snapshot::LATEST));
+        assert!(collection.deleted_row_ids().is_empty());
+    }
+
+    #[test]
+    fn versioned_append_rollback() {
+        let collection = ConcurrentColumnCollection::new([DataType::int32()], 1, 2);
+
+        let tx_id = TRANSACTION_ID_START;
+        let mut state = collection.init_versioned_append_state(tx_id);
+        collection
+            .append_batch(&mut state, &generate_batch!([1, 2]))
+            .unwrap();
+        collection.flush(&mut state).unwrap();
+        state.take_appended().rollback();
+
+        let snapshot = Snapshot {
+            start: 1,
+            transaction_id: tx_id,
+        };
+        assert!(scan_all(&collection, snapshot).is_empty());
+        assert_eq!(vec![0, 1], collection.deleted_row_ids());
+    }
+
+    #[test]
+    fn versioned_delete() {
+        let collection = ConcurrentColumnCollection::new([DataType::int32()], 16, 16);
+
+        let mut state = collection.init_append_state();
+        collection
+            .append_batch(&mut state, &generate_batch!([1, 2, 3]))
+            .unwrap();
+        collection.flush(&mut state).unwrap();
+
+        let tx1 = Snapshot {
+            start: 1,
+            transaction_id: TRANSACTION_ID_START,
+        };
+        let tx2 = Snapshot {
+            start: 1,
+            transaction_id: TRANSACTION_ID_START + 1,
+        };
+
+        let mut deleted1 = DeletedRows::new(tx1.transaction_id);
+        let count = collection.delete_rows(&tx1, [1], &mut deleted1).unwrap();
+        assert_eq!(1, count);
+
+        assert_eq!(vec![1, 3], scan_all(&collection, tx1));
+        assert_eq!(vec![1, 2, 3], scan_all(&collection, tx2));
+
+        // Concurrent delete of the same row conflicts.
+        let mut deleted2 = DeletedRows::new(tx2.transaction_id);
+        collection
+            .delete_rows(&tx2, [1], &mut deleted2)
+            .unwrap_err();
+
+        // Rolling back makes the row deletable again.
+        deleted1.rollback();
+        assert_eq!(vec![1, 2, 3], scan_all(&collection, tx1));
+        let count = collection.delete_rows(&tx2, [1], &mut deleted2).unwrap();
+        assert_eq!(1, count);
+
+        deleted2.commit(1);
+        assert_eq!(vec![1, 3], scan_all(&collection, Snapshot::LATEST));
+        assert_eq!(vec![1], collection.deleted_row_ids());
+    }
 }
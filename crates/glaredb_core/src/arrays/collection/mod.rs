@@ -4,3 +4,5 @@ pub(crate) mod equal;
 
 mod chunk;
 mod segment;
+
+pub use chunk::META_PROJECTION_ROWID;
@@ -1,9 +1,10 @@
-use glaredb_error::Result;
+use glaredb_error::{DbError, Result};
 
 use super::chunk::ColumnChunk;
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::buffer::buffer_manager::AsRawBufferManager;
+use crate::transaction::Snapshot;
 
 #[derive(Debug)]
 pub struct ColumnCollectionSegment {
@@ -92,6 +93,42 @@ impl ColumnCollectionSegment {
     pub fn get_chunk(&self, chunk_idx: usize) -> Option<&ColumnChunk> {
         self.chunks.get(chunk_idx)
     }
+
+    /// Relative row offset of the first row in this segment.
+    pub fn relative_offset(&self) -> usize {
+        self.chunks.first().map(|c| c.relative_offset).unwrap_or(0)
+    }
+
+    /// Marks a row in this segment as deleted.
+    ///
+    /// `row_id` is relative to the entire collection. Returns false if the row
+    /// was already deleted.
+    pub fn delete_row(&self, snapshot: &Snapshot, version: u64, row_id: usize) -> Result<bool> {
+        let idx = self
+            .chunks
+            .partition_point(|chunk| chunk.relative_offset <= row_id);
+        let chunk = idx
+            .checked_sub(1)
+            .and_then(|idx| self.chunks.get(idx))
+            .ok_or_else(|| DbError::new("Row id not in segment").with_field("row_id", row_id))?;
+
+        chunk.delete_row(snapshot, version, row_id - chunk.relative_offset)
+    }
+
+    /// Set the insert version for all rows in this segment.
+    pub fn set_inserted_version(&self, version: u64) {
+        for chunk in &self.chunks {
+            chunk.set_inserted_version(version);
+        }
+    }
+
+    /// Replace the delete version for all rows in this segment deleted with
+    /// `from` with `to`.
+    pub fn replace_deleted_version(&self, from: u64, to: u64) {
+        for chunk in &self.chunks {
+            chunk.replace_deleted_version(from, to);
+        }
+    }
 }
 
 #[cfg(test)]
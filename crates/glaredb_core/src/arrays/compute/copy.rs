@@ -1,7 +1,12 @@
 use glaredb_error::{Result, not_implemented};
 
 use crate::arrays::array::Array;
-use crate::arrays::array::array_buffer::AnyArrayBuffer;
+use crate::arrays::array::array_buffer::{
+    AnyArrayBuffer,
+    ArrayBufferDowncast,
+    ListItemMetadata,
+    MapBuffer,
+};
 use crate::arrays::array::execution_format::ExecutionFormat;
 use crate::arrays::array::physical_type::{
     Addressable,
@@ -149,6 +154,9 @@ pub(crate) fn copy_rows_raw(
             dest_buf,
             dest_validity,
         ),
+        PhysicalType::Map => {
+            copy_rows_map(datatype, src_buf, src_validity, mapping, dest_buf, dest_validity)
+        }
         other => not_implemented!("copy rows raw: {other}"),
     }
 }
@@ -212,6 +220,68 @@ where
     }
 }
 
+/// Copy map rows from the source to the destination.
+///
+/// Map entries are appended to the end of the destination's child buffers.
+fn copy_rows_map(
+    datatype: &DataType,
+    src_buf: &AnyArrayBuffer,
+    src_validity: &Validity,
+    mapping: impl IntoIterator<Item = (usize, usize)>,
+    dest_buf: &mut AnyArrayBuffer,
+    dest_validity: &mut Validity,
+) -> Result<()> {
+    let map_meta = datatype.try_get_map_type_meta()?;
+
+    let src = MapBuffer::downcast_execution_format(src_buf)?.into_selection_format()?;
+    let src_metadata = src.buffer.metadata.as_slice();
+    let dest = MapBuffer::downcast_mut(dest_buf)?;
+
+    let mut child_mapping = Vec::new();
+    let mut offset = dest.keys.validity.len();
+
+    for (src_idx, dest_idx) in mapping {
+        if !src_validity.is_valid(src_idx) {
+            dest_validity.set_invalid(dest_idx);
+            continue;
+        }
+
+        let meta = src_metadata[src.selection.get(src_idx).unwrap()];
+        for idx in 0..(meta.len as usize) {
+            child_mapping.push((meta.offset as usize + idx, offset + idx));
+        }
+
+        dest.metadata.as_slice_mut()[dest_idx] = ListItemMetadata {
+            offset: offset as i32,
+            len: meta.len,
+        };
+        offset += meta.len as usize;
+    }
+
+    let children = [
+        (&map_meta.key, &src.buffer.keys, &mut dest.keys),
+        (&map_meta.value, &src.buffer.values, &mut dest.values),
+    ];
+
+    for (child_type, src_child, dest_child) in children {
+        dest_child.validity.resize(offset);
+        if dest_child.buffer.logical_len() < offset {
+            dest_child.buffer.resize(offset)?;
+        }
+
+        copy_rows_raw(
+            child_type,
+            &src_child.buffer,
+            &src_child.validity,
+            child_mapping.iter().copied(),
+            &mut dest_child.buffer,
+            &mut dest_child.validity,
+        )?;
+    }
+
+    Ok(())
+}
+
 #[allow(unused)]
 fn copy_rows_list(
     _datatype: &DataType,
@@ -233,6 +303,7 @@ fn copy_rows_list(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arrays::scalar::BorrowedScalarValue;
     use crate::buffer::buffer_manager::DefaultBufferManager;
     use crate::testutil::arrays::assert_arrays_eq;
     use crate::util::iter::TryFromExactSizeIterator;
@@ -275,4 +346,35 @@ mod tests {
         let expected = Array::try_from_iter([Some("d"), Some("a"), None]).unwrap();
         assert_arrays_eq(&expected, &to);
     }
+
+    #[test]
+    fn copy_rows_map() {
+        let datatype = DataType::map(DataType::utf8(), DataType::int32());
+
+        let mut from = Array::new(&DefaultBufferManager, datatype.clone(), 3).unwrap();
+        from.set_value(0, &BorrowedScalarValue::Map(vec![("a".into(), 1.into())]))
+            .unwrap();
+        from.set_value(1, &BorrowedScalarValue::Null).unwrap();
+        from.set_value(
+            2,
+            &BorrowedScalarValue::Map(vec![("b".into(), 2.into()), ("c".into(), 3.into())]),
+        )
+        .unwrap();
+
+        let mut to = Array::new(&DefaultBufferManager, datatype, 3).unwrap();
+        to.set_value(0, &BorrowedScalarValue::Map(vec![("z".into(), 9.into())]))
+            .unwrap();
+
+        copy_rows_array(&from, [(2, 1), (1, 2)], &mut to).unwrap();
+
+        assert_eq!(
+            BorrowedScalarValue::Map(vec![("z".into(), 9.into())]),
+            to.get_value(0).unwrap()
+        );
+        assert_eq!(
+            BorrowedScalarValue::Map(vec![("b".into(), 2.into()), ("c".into(), 3.into())]),
+            to.get_value(1).unwrap()
+        );
+        assert_eq!(BorrowedScalarValue::Null, to.get_value(2).unwrap());
+    }
 }
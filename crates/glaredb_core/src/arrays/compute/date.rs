@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveTime, Timelike, Utc};
 use glaredb_error::{DbError, Result, not_implemented};
 
 use crate::arrays::array::Array;
@@ -7,6 +7,8 @@ use crate::arrays::datatype::{DataTypeId, TimeUnit};
 use crate::arrays::executor::OutBuffer;
 use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::scalar::decimal::{Decimal64Type, DecimalType};
+use crate::arrays::scalar::interval::Interval;
+use crate::arrays::scalar::timezone::{TimeZone, naive_to_timestamp, timestamp_to_naive};
 use crate::util::iter::IntoExactSizeIterator;
 
 pub const EPOCH_NAIVE_DATE: NaiveDate = match NaiveDate::from_ymd_opt(1970, 1, 1) {
@@ -18,6 +20,69 @@ pub const EPOCH_DAYS_FROM_CE: i32 = 719_163;
 
 pub const SECONDS_IN_DAY: i64 = 86_400;
 
+pub const MICROSECONDS_IN_DAY: i64 = SECONDS_IN_DAY * 1_000_000;
+
+/// Convert days since epoch to a date.
+pub fn date32_to_naive(days: i32) -> Option<NaiveDate> {
+    EPOCH_NAIVE_DATE.checked_add_signed(chrono::Duration::days(days as i64))
+}
+
+/// Convert a date to days since epoch.
+pub fn naive_to_date32(date: NaiveDate) -> i32 {
+    date.num_days_from_ce() - EPOCH_DAYS_FROM_CE
+}
+
+/// Convert microseconds since midnight to a time.
+///
+/// Returns None if the value is not within a single day.
+pub fn time_to_naive(micros: i64) -> Option<NaiveTime> {
+    if !(0..MICROSECONDS_IN_DAY).contains(&micros) {
+        return None;
+    }
+    let secs = (micros / 1_000_000) as u32;
+    let nanos = (micros % 1_000_000) as u32 * 1_000;
+    NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+}
+
+/// Convert a time to microseconds since midnight.
+pub fn naive_to_time(time: NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * 1_000_000 + (time.nanosecond() / 1_000) as i64
+}
+
+/// Add an interval to a timestamp.
+///
+/// The month and day components are applied to the local date in the given
+/// time zone, clamping to the end of the month where needed (Jan 31 + 1 month
+/// is Feb 28/29). The remaining time component is applied to the instant.
+pub fn timestamp_add_interval(
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+    val: i64,
+    interval: Interval,
+) -> Option<i64> {
+    let mut datetime = timestamp_to_naive(unit, val)?;
+
+    if interval.months != 0 || interval.days != 0 {
+        let mut local = match tz {
+            Some(tz) => tz.utc_to_local(datetime),
+            None => datetime,
+        };
+        local = if interval.months >= 0 {
+            local.checked_add_months(Months::new(interval.months as u32))?
+        } else {
+            local.checked_sub_months(Months::new(interval.months.unsigned_abs()))?
+        };
+        local = local.checked_add_signed(chrono::Duration::days(interval.days as i64))?;
+        datetime = match tz {
+            Some(tz) => tz.local_to_utc(local),
+            None => local,
+        };
+    }
+
+    let datetime = datetime.checked_add_signed(chrono::Duration::nanoseconds(interval.nanos))?;
+    naive_to_timestamp(unit, datetime)
+}
+
 /// Date parts that can be extracted for date and time values.
 ///
 /// Follows Postgres conventions: <https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-EXTRACT>
@@ -83,6 +148,7 @@ pub fn extract_date_part(
             DatePart::Milliseconds => date32_extract_with_fn(arr, sel, extract_milliseconds, out),
             DatePart::Second => date32_extract_with_fn(arr, sel, extract_seconds, out),
             DatePart::Minute => date32_extract_with_fn(arr, sel, extract_minute, out),
+            DatePart::Hour => date32_extract_with_fn(arr, sel, extract_hour, out),
             DatePart::DayOfWeek => date32_extract_with_fn(arr, sel, extract_day_of_week, out),
             DatePart::IsoDayOfWeek => {
                 date32_extract_with_fn(arr, sel, extract_iso_day_of_week, out)
@@ -98,6 +164,7 @@ pub fn extract_date_part(
             DatePart::Milliseconds => date64_extract_with_fn(arr, sel, extract_milliseconds, out),
             DatePart::Second => date64_extract_with_fn(arr, sel, extract_seconds, out),
             DatePart::Minute => date64_extract_with_fn(arr, sel, extract_minute, out),
+            DatePart::Hour => date64_extract_with_fn(arr, sel, extract_hour, out),
             DatePart::DayOfWeek => date64_extract_with_fn(arr, sel, extract_day_of_week, out),
             DatePart::IsoDayOfWeek => {
                 date64_extract_with_fn(arr, sel, extract_iso_day_of_week, out)
@@ -110,33 +177,35 @@ pub fn extract_date_part(
         },
         DataTypeId::Timestamp => {
             let m = datatype.try_get_timestamp_type_meta()?;
-            match part {
-                DatePart::Microseconds => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_microseconds, out)
-                }
-                DatePart::Milliseconds => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_milliseconds, out)
-                }
-                DatePart::Second => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_seconds, out)
-                }
-                DatePart::Minute => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_minute, out)
-                }
-                DatePart::DayOfWeek => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_day_of_week, out)
-                }
-                DatePart::IsoDayOfWeek => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_iso_day_of_week, out)
-                }
-                DatePart::Day => timestamp_extract_with_fn(m.unit, arr, sel, extract_day, out),
-                DatePart::Month => timestamp_extract_with_fn(m.unit, arr, sel, extract_month, out),
-                DatePart::Quarter => {
-                    timestamp_extract_with_fn(m.unit, arr, sel, extract_quarter, out)
-                }
-                DatePart::Year => timestamp_extract_with_fn(m.unit, arr, sel, extract_year, out),
+            let tz = m.tz.as_deref().map(str::parse).transpose()?;
+            let f: fn(DateTime<Utc>) -> i64 = match part {
+                DatePart::Microseconds => extract_microseconds,
+                DatePart::Milliseconds => extract_milliseconds,
+                DatePart::Second => extract_seconds,
+                DatePart::Minute => extract_minute,
+                DatePart::Hour => extract_hour,
+                DatePart::DayOfWeek => extract_day_of_week,
+                DatePart::IsoDayOfWeek => extract_iso_day_of_week,
+                DatePart::Day => extract_day,
+                DatePart::Month => extract_month,
+                DatePart::Quarter => extract_quarter,
+                DatePart::Year => extract_year,
                 other => not_implemented!("Extract {other:?} from {datatype}"),
-            }
+            };
+            timestamp_extract_with_fn(m.unit, tz, arr, sel, f, out)
+        }
+        DataTypeId::Time => {
+            let f: fn(DateTime<Utc>) -> i64 = match part {
+                DatePart::Microseconds => extract_microseconds,
+                DatePart::Milliseconds => extract_milliseconds,
+                DatePart::Second => extract_seconds,
+                DatePart::Minute => extract_minute,
+                DatePart::Hour => extract_hour,
+                other => not_implemented!("Extract {other:?} from {datatype}"),
+            };
+            // Times are microseconds since midnight, extract from the time
+            // on the epoch date.
+            timestamp_extract_with_fn(TimeUnit::Microsecond, None, arr, sel, f, out)
         }
         other => Err(DbError::new(format!(
             "Unable to extract date part for array with data type {other}"
@@ -144,8 +213,13 @@ pub fn extract_date_part(
     }
 }
 
+/// Extract a part from a timestamp.
+///
+/// If a time zone is provided, the part is extracted from the local time in
+/// that zone.
 fn timestamp_extract_with_fn<F>(
     unit: TimeUnit,
+    tz: Option<TimeZone>,
     arr: &Array,
     sel: impl IntoExactSizeIterator<Item = usize>,
     f: F,
@@ -154,6 +228,19 @@ fn timestamp_extract_with_fn<F>(
 where
     F: Fn(DateTime<Utc>) -> i64,
 {
+    if let Some(tz) = tz {
+        return timestamp_extract_with_fn_and_datetime_builder(
+            arr,
+            sel,
+            f,
+            |val| {
+                let utc = timestamp_to_naive(unit, val).unwrap_or_default();
+                tz.utc_to_local(utc).and_utc()
+            },
+            out,
+        );
+    }
+
     match unit {
         TimeUnit::Second => timestamp_extract_with_fn_and_datetime_builder(
             arr,
@@ -281,6 +368,10 @@ fn extract_iso_day_of_week<T: Datelike + Timelike>(val: T) -> i64 {
     (val.weekday().number_from_monday() as i64) * WHOLE_INT_SCALE
 }
 
+fn extract_hour<T: Datelike + Timelike>(val: T) -> i64 {
+    (val.hour() as i64) * WHOLE_INT_SCALE
+}
+
 fn extract_minute<T: Datelike + Timelike>(val: T) -> i64 {
     (val.minute() as i64) * WHOLE_INT_SCALE
 }
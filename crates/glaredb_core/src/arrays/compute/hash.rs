@@ -5,7 +5,12 @@ use glaredb_error::{Result, not_implemented};
 use half::f16;
 
 use crate::arrays::array::Array;
-use crate::arrays::array::array_buffer::{AnyArrayBuffer, ArrayBufferDowncast, ListBuffer};
+use crate::arrays::array::array_buffer::{
+    AnyArrayBuffer,
+    ArrayBufferDowncast,
+    ListBuffer,
+    MapBuffer,
+};
 use crate::arrays::array::execution_format::ExecutionFormat;
 use crate::arrays::array::physical_type::{
     Addressable,
@@ -178,6 +183,10 @@ where
             let m = datatype.try_get_list_type_meta()?;
             hash_list_array::<H>(validity, buffer, &m.datatype, sel, hashes)
         }
+        PhysicalType::Map => {
+            let m = datatype.try_get_map_type_meta()?;
+            hash_map_array::<H>(validity, buffer, &m.key, &m.value, sel, hashes)
+        }
 
         other => not_implemented!("hash physical type: {other:?}"),
     }
@@ -298,6 +307,66 @@ where
     Ok(())
 }
 
+fn hash_map_array<H>(
+    validity: &Validity,
+    buffer: &AnyArrayBuffer,
+    key_type: &DataType,
+    value_type: &DataType,
+    sel: impl IntoExactSizeIterator<Item = usize>,
+    hashes: &mut [u64],
+) -> Result<()>
+where
+    H: SetHashOp,
+{
+    let map = MapBuffer::downcast_execution_format(buffer)?.into_selection_format()?;
+    let metadata = map.buffer.metadata.as_slice();
+
+    let mut key_hashes = Vec::new();
+    let mut value_hashes = Vec::new();
+
+    for (idx, hash) in sel.into_exact_size_iter().zip(hashes) {
+        if !validity.is_valid(idx) {
+            H::set_hash(hash, DefaultHasher::NULL_HASH);
+            continue;
+        }
+
+        let sel_idx = map.selection.get(idx).unwrap();
+        let meta = metadata[sel_idx];
+
+        key_hashes.clear();
+        key_hashes.resize(meta.len as usize, 0);
+        value_hashes.clear();
+        value_hashes.resize(meta.len as usize, 0);
+
+        let selection = Selection::linear(meta.offset as usize, meta.len as usize);
+        hash_inner::<H>(
+            key_type,
+            &map.buffer.keys.validity,
+            &map.buffer.keys.buffer,
+            selection,
+            &mut key_hashes,
+        )?;
+        hash_inner::<H>(
+            value_type,
+            &map.buffer.values.validity,
+            &map.buffer.values.buffer,
+            selection,
+            &mut value_hashes,
+        )?;
+
+        // Combine each entry's key and value hash, then combine all entries.
+        let mut entry_hash = DefaultHasher::NULL_HASH;
+        for (&key_hash, &value_hash) in key_hashes.iter().zip(&value_hashes) {
+            let kv = DefaultHasher::combine_hashes(key_hash, value_hash);
+            entry_hash = DefaultHasher::combine_hashes(entry_hash, kv);
+        }
+
+        H::set_hash(hash, entry_hash);
+    }
+
+    Ok(())
+}
+
 /// Helper trait for hashing values.
 ///
 /// This is mostly for floats since they don't automatically implement `Hash`.
@@ -0,0 +1,388 @@
+use std::ops::Range;
+
+use glaredb_error::{DbError, Result, not_implemented};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::array_buffer::{
+    AnyArrayBuffer,
+    ArrayBufferDowncast,
+    ListBuffer,
+    ListItemMetadata,
+    MapBuffer,
+};
+use crate::arrays::array::physical_type::{
+    Addressable,
+    PhysicalBinary,
+    PhysicalBool,
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI8,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI128,
+    PhysicalInterval,
+    PhysicalType,
+    PhysicalU8,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU128,
+    PhysicalUtf8,
+    ScalarStorage,
+};
+use crate::arrays::array::validity::Validity;
+use crate::arrays::compute::copy::copy_rows_raw;
+use crate::util::iter::IntoExactSizeIterator;
+
+/// Create map values from a list of keys and a list of values, writing the
+/// maps to `output`.
+///
+/// `output` will be overwritten.
+///
+/// The key and value lists for a row must be the same length, and keys must
+/// be unique and not NULL. If either list for a row is NULL, the output map is
+/// NULL.
+pub fn make_map(
+    keys: &Array,
+    values: &Array,
+    sel: impl IntoExactSizeIterator<Item = usize>,
+    output: &mut Array,
+) -> Result<()> {
+    let map_meta = output.datatype.try_get_map_type_meta()?;
+    let key_type = map_meta.key.as_ref().clone();
+    let value_type = map_meta.value.as_ref().clone();
+
+    let has_duplicates = match key_type.physical_type()? {
+        PhysicalType::Boolean => has_duplicate_keys::<PhysicalBool>,
+        PhysicalType::Int8 => has_duplicate_keys::<PhysicalI8>,
+        PhysicalType::Int16 => has_duplicate_keys::<PhysicalI16>,
+        PhysicalType::Int32 => has_duplicate_keys::<PhysicalI32>,
+        PhysicalType::Int64 => has_duplicate_keys::<PhysicalI64>,
+        PhysicalType::Int128 => has_duplicate_keys::<PhysicalI128>,
+        PhysicalType::UInt8 => has_duplicate_keys::<PhysicalU8>,
+        PhysicalType::UInt16 => has_duplicate_keys::<PhysicalU16>,
+        PhysicalType::UInt32 => has_duplicate_keys::<PhysicalU32>,
+        PhysicalType::UInt64 => has_duplicate_keys::<PhysicalU64>,
+        PhysicalType::UInt128 => has_duplicate_keys::<PhysicalU128>,
+        PhysicalType::Float16 => has_duplicate_keys::<PhysicalF16>,
+        PhysicalType::Float32 => has_duplicate_keys::<PhysicalF32>,
+        PhysicalType::Float64 => has_duplicate_keys::<PhysicalF64>,
+        PhysicalType::Interval => has_duplicate_keys::<PhysicalInterval>,
+        PhysicalType::Utf8 => has_duplicate_keys::<PhysicalUtf8>,
+        PhysicalType::Binary => has_duplicate_keys::<PhysicalBinary>,
+        other => not_implemented!("Map with key type {other}"),
+    };
+
+    let key_lists = ListBuffer::downcast_execution_format(&keys.data)?.into_selection_format()?;
+    let value_lists =
+        ListBuffer::downcast_execution_format(&values.data)?.into_selection_format()?;
+    let key_metas = key_lists.buffer.metadata.as_slice();
+    let value_metas = value_lists.buffer.metadata.as_slice();
+
+    let sel = sel.into_exact_size_iter();
+    let output_len = sel.len();
+    output.validity = Validity::new_all_valid(output_len);
+
+    let mut out_metas = Vec::with_capacity(output_len);
+    let mut key_mapping = Vec::new();
+    let mut value_mapping = Vec::new();
+
+    for (output_idx, input_idx) in sel.enumerate() {
+        let offset = key_mapping.len();
+
+        if !keys.validity.is_valid(input_idx) || !values.validity.is_valid(input_idx) {
+            output.validity.set_invalid(output_idx);
+            out_metas.push(ListItemMetadata {
+                offset: offset as i32,
+                len: 0,
+            });
+            continue;
+        }
+
+        let key_meta = key_metas[key_lists.selection.get(input_idx).unwrap()];
+        let value_meta = value_metas[value_lists.selection.get(input_idx).unwrap()];
+
+        if key_meta.len != value_meta.len {
+            return Err(
+                DbError::new("Map keys and values lists must be the same length")
+                    .with_field("keys", key_meta.len)
+                    .with_field("values", value_meta.len),
+            );
+        }
+
+        for idx in 0..(key_meta.len as usize) {
+            let key_idx = key_meta.offset as usize + idx;
+            if !key_lists.buffer.child.validity.is_valid(key_idx) {
+                return Err(DbError::new("Map keys cannot be NULL"));
+            }
+
+            key_mapping.push((key_idx, offset + idx));
+            value_mapping.push((value_meta.offset as usize + idx, offset + idx));
+        }
+
+        let start = key_meta.offset as usize;
+        let end = start + key_meta.len as usize;
+        if has_duplicates(&key_lists.buffer.child.buffer, start..end)? {
+            return Err(DbError::new("Map keys must be unique"));
+        }
+
+        out_metas.push(ListItemMetadata {
+            offset: offset as i32,
+            len: key_meta.len,
+        });
+    }
+
+    let map = MapBuffer::downcast_mut(&mut output.data)?;
+    let num_entries = key_mapping.len();
+
+    map.keys.validity = Validity::new_all_valid(num_entries);
+    map.keys.buffer.resize(num_entries)?;
+    copy_rows_raw(
+        &key_type,
+        &key_lists.buffer.child.buffer,
+        &key_lists.buffer.child.validity,
+        key_mapping,
+        &mut map.keys.buffer,
+        &mut map.keys.validity,
+    )?;
+
+    map.values.validity = Validity::new_all_valid(num_entries);
+    map.values.buffer.resize(num_entries)?;
+    copy_rows_raw(
+        &value_type,
+        &value_lists.buffer.child.buffer,
+        &value_lists.buffer.child.validity,
+        value_mapping,
+        &mut map.values.buffer,
+        &mut map.values.validity,
+    )?;
+
+    // SAFETY: We're overwriting all values, never reading.
+    unsafe { map.metadata.resize_uninit(output_len)? };
+    map.metadata.as_slice_mut().copy_from_slice(&out_metas);
+
+    Ok(())
+}
+
+/// Check if any keys in `range` of the key buffer are equal to each other.
+fn has_duplicate_keys<S>(keys: &AnyArrayBuffer, range: Range<usize>) -> Result<bool>
+where
+    S: ScalarStorage,
+    S::StorageType: PartialEq,
+{
+    let keys = S::addressable(S::buffer_downcast_ref(keys)?);
+    let has_duplicates = range.clone().any(|a| {
+        let key = keys.get(a).unwrap();
+        (a + 1..range.end).any(|b| keys.get(b).unwrap() == key)
+    });
+
+    Ok(has_duplicates)
+}
+
+/// Append map entries to the child buffers of `output`, writing map rows
+/// that reference them.
+///
+/// `keys` and `values` hold flat key/value entries. `rows` provides
+/// (output_row, entries) pairs, where `entries` is the range of entries that
+/// make up the map for that row, or `None` if the map is NULL.
+///
+/// This is used when maps are decoded from a flat representation, e.g. when
+/// reading Parquet MAP columns.
+pub fn append_map_entries(
+    keys: &Array,
+    values: &Array,
+    rows: impl IntoIterator<Item = (usize, Option<Range<usize>>)>,
+    output: &mut Array,
+) -> Result<()> {
+    let map_meta = output.datatype.try_get_map_type_meta()?;
+    let key_type = map_meta.key.as_ref().clone();
+    let value_type = map_meta.value.as_ref().clone();
+
+    let map = MapBuffer::downcast_mut(&mut output.data)?;
+
+    let mut mapping = Vec::new();
+    let mut offset = map.keys.validity.len();
+
+    for (output_idx, entries) in rows {
+        let entries = match entries {
+            Some(entries) => entries,
+            None => {
+                output.validity.set_invalid(output_idx);
+                continue;
+            }
+        };
+
+        let len = entries.len();
+        for (idx, entry_idx) in entries.enumerate() {
+            if !keys.validity.is_valid(entry_idx) {
+                return Err(DbError::new("Map keys cannot be NULL"));
+            }
+            mapping.push((entry_idx, offset + idx));
+        }
+
+        output.validity.set_valid(output_idx);
+        map.metadata.as_slice_mut()[output_idx] = ListItemMetadata {
+            offset: offset as i32,
+            len: len as i32,
+        };
+        offset += len;
+    }
+
+    let children = [
+        (&key_type, keys, &mut map.keys),
+        (&value_type, values, &mut map.values),
+    ];
+
+    for (child_type, src, dest) in children {
+        dest.validity.resize(offset);
+        if dest.buffer.logical_len() < offset {
+            dest.buffer.resize(offset)?;
+        }
+
+        copy_rows_raw(
+            child_type,
+            &src.data,
+            &src.validity,
+            mapping.iter().copied(),
+            &mut dest.buffer,
+            &mut dest.validity,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::compute::make_list::make_list;
+    use crate::arrays::datatype::DataType;
+    use crate::arrays::scalar::BorrowedScalarValue;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn make_lists(elems: [Array; 2], datatype: DataType) -> Array {
+        let mut lists = Array::new(&DefaultBufferManager, DataType::list(datatype), 2).unwrap();
+        make_list(&elems, 0..2, &mut lists).unwrap();
+        lists
+    }
+
+    #[test]
+    fn make_map_simple() {
+        let keys = make_lists(
+            [
+                Array::try_from_iter(["a", "c"]).unwrap(),
+                Array::try_from_iter(["b", "d"]).unwrap(),
+            ],
+            DataType::utf8(),
+        );
+        let values = make_lists(
+            [
+                Array::try_from_iter([1, 3]).unwrap(),
+                Array::try_from_iter([Some(2), None]).unwrap(),
+            ],
+            DataType::int32(),
+        );
+
+        let mut out = Array::new(
+            &DefaultBufferManager,
+            DataType::map(DataType::utf8(), DataType::int32()),
+            2,
+        )
+        .unwrap();
+        make_map(&keys, &values, 0..2, &mut out).unwrap();
+
+        let expected =
+            BorrowedScalarValue::Map(vec![("a".into(), 1.into()), ("b".into(), 2.into())]);
+        assert_eq!(expected, out.get_value(0).unwrap());
+
+        let expected = BorrowedScalarValue::Map(vec![
+            ("c".into(), 3.into()),
+            ("d".into(), BorrowedScalarValue::Null),
+        ]);
+        assert_eq!(expected, out.get_value(1).unwrap());
+    }
+
+    #[test]
+    fn make_map_null_key() {
+        let keys = make_lists(
+            [
+                Array::try_from_iter([Some("a"), Some("c")]).unwrap(),
+                Array::try_from_iter([Some("b"), None]).unwrap(),
+            ],
+            DataType::utf8(),
+        );
+        let values = make_lists(
+            [
+                Array::try_from_iter([1, 3]).unwrap(),
+                Array::try_from_iter([2, 4]).unwrap(),
+            ],
+            DataType::int32(),
+        );
+
+        let mut out = Array::new(
+            &DefaultBufferManager,
+            DataType::map(DataType::utf8(), DataType::int32()),
+            2,
+        )
+        .unwrap();
+        make_map(&keys, &values, 0..2, &mut out).unwrap_err();
+    }
+
+    #[test]
+    fn make_map_duplicate_key() {
+        let keys = make_lists(
+            [
+                Array::try_from_iter(["a", "c"]).unwrap(),
+                Array::try_from_iter(["b", "c"]).unwrap(),
+            ],
+            DataType::utf8(),
+        );
+        let values = make_lists(
+            [
+                Array::try_from_iter([1, 3]).unwrap(),
+                Array::try_from_iter([2, 4]).unwrap(),
+            ],
+            DataType::int32(),
+        );
+
+        let mut out = Array::new(
+            &DefaultBufferManager,
+            DataType::map(DataType::utf8(), DataType::int32()),
+            2,
+        )
+        .unwrap();
+        make_map(&keys, &values, 0..2, &mut out).unwrap_err();
+    }
+
+    #[test]
+    fn append_map_entries_with_nulls() {
+        let keys = Array::try_from_iter(["a", "b", "c"]).unwrap();
+        let values = Array::try_from_iter([Some(1), None, Some(3)]).unwrap();
+
+        let mut out = Array::new(
+            &DefaultBufferManager,
+            DataType::map(DataType::utf8(), DataType::int32()),
+            3,
+        )
+        .unwrap();
+        append_map_entries(
+            &keys,
+            &values,
+            [(0, Some(0..2)), (1, None), (2, Some(2..3))],
+            &mut out,
+        )
+        .unwrap();
+
+        let expected = BorrowedScalarValue::Map(vec![
+            ("a".into(), 1.into()),
+            ("b".into(), BorrowedScalarValue::Null),
+        ]);
+        assert_eq!(expected, out.get_value(0).unwrap());
+        assert_eq!(BorrowedScalarValue::Null, out.get_value(1).unwrap());
+        let expected = BorrowedScalarValue::Map(vec![("c".into(), 3.into())]);
+        assert_eq!(expected, out.get_value(2).unwrap());
+    }
+}
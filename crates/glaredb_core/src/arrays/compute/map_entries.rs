@@ -0,0 +1,137 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::array_buffer::{
+    ArrayBufferDowncast,
+    ListBuffer,
+    ListItemMetadata,
+    MapBuffer,
+};
+use crate::arrays::array::validity::Validity;
+use crate::arrays::compute::copy::copy_rows_raw;
+use crate::util::iter::IntoExactSizeIterator;
+
+/// Which part of the map entries to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapEntryPart {
+    Keys,
+    Values,
+}
+
+/// Writes either the keys or the values for each map in `map` to a list array.
+///
+/// `output` will be overwritten. NULL maps produce NULL lists.
+pub fn map_entries_to_list(
+    map: &Array,
+    part: MapEntryPart,
+    sel: impl IntoExactSizeIterator<Item = usize>,
+    output: &mut Array,
+) -> Result<()> {
+    let map_meta = map.datatype().try_get_map_type_meta()?;
+    let map_buffer = MapBuffer::downcast_execution_format(&map.data)?.into_selection_format()?;
+    let metadata = map_buffer.buffer.metadata.as_slice();
+
+    let (child_type, child) = match part {
+        MapEntryPart::Keys => (map_meta.key.as_ref(), &map_buffer.buffer.keys),
+        MapEntryPart::Values => (map_meta.value.as_ref(), &map_buffer.buffer.values),
+    };
+
+    let sel = sel.into_exact_size_iter();
+    let output_len = sel.len();
+    output.validity = Validity::new_all_valid(output_len);
+
+    let mut out_metas = Vec::with_capacity(output_len);
+    let mut mapping = Vec::new();
+
+    for (output_idx, input_idx) in sel.enumerate() {
+        let offset = mapping.len();
+
+        if !map.validity.is_valid(input_idx) {
+            output.validity.set_invalid(output_idx);
+            out_metas.push(ListItemMetadata {
+                offset: offset as i32,
+                len: 0,
+            });
+            continue;
+        }
+
+        let meta = metadata[map_buffer.selection.get(input_idx).unwrap()];
+        for idx in 0..(meta.len as usize) {
+            mapping.push((meta.offset as usize + idx, offset + idx));
+        }
+
+        out_metas.push(ListItemMetadata {
+            offset: offset as i32,
+            len: meta.len,
+        });
+    }
+
+    let list = ListBuffer::downcast_mut(&mut output.data)?;
+    let num_entries = mapping.len();
+
+    list.child.validity = Validity::new_all_valid(num_entries);
+    list.child.buffer.resize(num_entries)?;
+    copy_rows_raw(
+        child_type,
+        &child.buffer,
+        &child.validity,
+        mapping,
+        &mut list.child.buffer,
+        &mut list.child.validity,
+    )?;
+
+    // SAFETY: We're overwriting all values, never reading.
+    unsafe { list.metadata.resize_uninit(output_len)? };
+    list.metadata.as_slice_mut().copy_from_slice(&out_metas);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::arrays::scalar::BorrowedScalarValue;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+
+    #[test]
+    fn map_keys_and_values() {
+        let mut maps = Array::new(
+            &DefaultBufferManager,
+            DataType::map(DataType::utf8(), DataType::int32()),
+            3,
+        )
+        .unwrap();
+        maps.set_value(
+            0,
+            &BorrowedScalarValue::Map(vec![("a".into(), 1.into()), ("b".into(), 2.into())]),
+        )
+        .unwrap();
+        maps.set_value(1, &BorrowedScalarValue::Null).unwrap();
+        maps.set_value(2, &BorrowedScalarValue::Map(Vec::new()))
+            .unwrap();
+
+        let mut keys =
+            Array::new(&DefaultBufferManager, DataType::list(DataType::utf8()), 3).unwrap();
+        map_entries_to_list(&maps, MapEntryPart::Keys, 0..3, &mut keys).unwrap();
+
+        assert_eq!(
+            BorrowedScalarValue::List(vec!["a".into(), "b".into()]),
+            keys.get_value(0).unwrap()
+        );
+        assert_eq!(BorrowedScalarValue::Null, keys.get_value(1).unwrap());
+        assert_eq!(
+            BorrowedScalarValue::List(Vec::new()),
+            keys.get_value(2).unwrap()
+        );
+
+        let mut values =
+            Array::new(&DefaultBufferManager, DataType::list(DataType::int32()), 3).unwrap();
+        map_entries_to_list(&maps, MapEntryPart::Values, 0..3, &mut values).unwrap();
+
+        assert_eq!(
+            BorrowedScalarValue::List(vec![1.into(), 2.into()]),
+            values.get_value(0).unwrap()
+        );
+    }
+}
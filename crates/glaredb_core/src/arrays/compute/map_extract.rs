@@ -0,0 +1,181 @@
+use glaredb_error::{Result, not_implemented};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::array_buffer::{ArrayBufferDowncast, MapBuffer};
+use crate::arrays::array::physical_type::{
+    Addressable,
+    PhysicalBinary,
+    PhysicalBool,
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI8,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI128,
+    PhysicalInterval,
+    PhysicalType,
+    PhysicalU8,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU128,
+    PhysicalUtf8,
+    ScalarStorage,
+};
+use crate::arrays::array::validity::Validity;
+use crate::arrays::compute::copy::copy_rows_raw;
+use crate::util::iter::IntoExactSizeIterator;
+
+/// Extract the value for a key from each map within a map array.
+///
+/// `keys` provides the key to look up for each row, and must be the same type
+/// as the map's keys.
+///
+/// If the map is NULL, the key is NULL, or the key doesn't exist in the map,
+/// the output for that row will be NULL.
+pub fn map_extract(
+    map: &Array,
+    keys: &Array,
+    sel: impl IntoExactSizeIterator<Item = usize>,
+    output: &mut Array,
+) -> Result<()> {
+    let map_meta = map.datatype().try_get_map_type_meta()?;
+
+    let indices = match map_meta.key.physical_type()? {
+        PhysicalType::Boolean => find_key_indices::<PhysicalBool>(map, keys, sel)?,
+        PhysicalType::Int8 => find_key_indices::<PhysicalI8>(map, keys, sel)?,
+        PhysicalType::Int16 => find_key_indices::<PhysicalI16>(map, keys, sel)?,
+        PhysicalType::Int32 => find_key_indices::<PhysicalI32>(map, keys, sel)?,
+        PhysicalType::Int64 => find_key_indices::<PhysicalI64>(map, keys, sel)?,
+        PhysicalType::Int128 => find_key_indices::<PhysicalI128>(map, keys, sel)?,
+        PhysicalType::UInt8 => find_key_indices::<PhysicalU8>(map, keys, sel)?,
+        PhysicalType::UInt16 => find_key_indices::<PhysicalU16>(map, keys, sel)?,
+        PhysicalType::UInt32 => find_key_indices::<PhysicalU32>(map, keys, sel)?,
+        PhysicalType::UInt64 => find_key_indices::<PhysicalU64>(map, keys, sel)?,
+        PhysicalType::UInt128 => find_key_indices::<PhysicalU128>(map, keys, sel)?,
+        PhysicalType::Float16 => find_key_indices::<PhysicalF16>(map, keys, sel)?,
+        PhysicalType::Float32 => find_key_indices::<PhysicalF32>(map, keys, sel)?,
+        PhysicalType::Float64 => find_key_indices::<PhysicalF64>(map, keys, sel)?,
+        PhysicalType::Interval => find_key_indices::<PhysicalInterval>(map, keys, sel)?,
+        PhysicalType::Utf8 => find_key_indices::<PhysicalUtf8>(map, keys, sel)?,
+        PhysicalType::Binary => find_key_indices::<PhysicalBinary>(map, keys, sel)?,
+        other => not_implemented!("Map extract for key type {other}"),
+    };
+
+    output.validity = Validity::new_all_valid(indices.len());
+
+    let mut mapping = Vec::with_capacity(indices.len());
+    for (output_idx, child_idx) in indices.into_iter().enumerate() {
+        match child_idx {
+            Some(child_idx) => mapping.push((child_idx, output_idx)),
+            None => output.validity.set_invalid(output_idx),
+        }
+    }
+
+    let map_buffer = MapBuffer::downcast_execution_format(&map.data)?.into_selection_format()?;
+    copy_rows_raw(
+        &map_meta.value,
+        &map_buffer.buffer.values.buffer,
+        &map_buffer.buffer.values.validity,
+        mapping,
+        &mut output.data,
+        &mut output.validity,
+    )
+}
+
+/// Find the index in the map's child buffers for the key in each row.
+///
+/// Returns `None` for a row if there's no matching key.
+fn find_key_indices<S>(
+    map: &Array,
+    keys: &Array,
+    sel: impl IntoExactSizeIterator<Item = usize>,
+) -> Result<Vec<Option<usize>>>
+where
+    S: ScalarStorage,
+    S::StorageType: PartialEq,
+{
+    let map_buffer = MapBuffer::downcast_execution_format(&map.data)?.into_selection_format()?;
+    let metadata = map_buffer.buffer.metadata.as_slice();
+    let map_keys = S::addressable(S::buffer_downcast_ref(&map_buffer.buffer.keys.buffer)?);
+
+    let search = S::downcast_execution_format(&keys.data)?.into_selection_format()?;
+    let search_keys = S::addressable(search.buffer);
+
+    let indices = sel
+        .into_exact_size_iter()
+        .map(|input_idx| {
+            if !map.validity.is_valid(input_idx) || !keys.validity.is_valid(input_idx) {
+                return None;
+            }
+
+            let meta = metadata[map_buffer.selection.get(input_idx).unwrap()];
+            let key = search_keys
+                .get(search.selection.get(input_idx).unwrap())
+                .unwrap();
+
+            let start = meta.offset as usize;
+            let end = start + meta.len as usize;
+
+            (start..end).find(|&child_idx| map_keys.get(child_idx).unwrap() == key)
+        })
+        .collect();
+
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::arrays::scalar::BorrowedScalarValue;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::testutil::arrays::assert_arrays_eq;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn make_maps() -> Array {
+        let mut maps = Array::new(
+            &DefaultBufferManager,
+            DataType::map(DataType::utf8(), DataType::int32()),
+            3,
+        )
+        .unwrap();
+
+        maps.set_value(
+            0,
+            &BorrowedScalarValue::Map(vec![("a".into(), 1.into()), ("b".into(), 2.into())]),
+        )
+        .unwrap();
+        maps.set_value(1, &BorrowedScalarValue::Map(vec![("b".into(), 3.into())]))
+            .unwrap();
+        maps.set_value(2, &BorrowedScalarValue::Null).unwrap();
+
+        maps
+    }
+
+    #[test]
+    fn map_extract_found_and_missing() {
+        let maps = make_maps();
+        let keys = Array::try_from_iter(["b", "a", "a"]).unwrap();
+
+        let mut out = Array::new(&DefaultBufferManager, DataType::int32(), 3).unwrap();
+        map_extract(&maps, &keys, 0..3, &mut out).unwrap();
+
+        let expected = Array::try_from_iter([Some(2), None, None]).unwrap();
+        assert_arrays_eq(&expected, &out);
+    }
+
+    #[test]
+    fn map_extract_constant_key() {
+        let maps = make_maps();
+        let keys = Array::new_constant(&DefaultBufferManager, &"b".into(), 3).unwrap();
+
+        let mut out = Array::new(&DefaultBufferManager, DataType::int32(), 3).unwrap();
+        map_extract(&maps, &keys, 0..3, &mut out).unwrap();
+
+        let expected = Array::try_from_iter([Some(2), Some(3), None]).unwrap();
+        assert_arrays_eq(&expected, &out);
+    }
+}
@@ -4,4 +4,7 @@ pub mod date;
 pub mod hash;
 pub mod list_extract;
 pub mod make_list;
+pub mod make_map;
+pub mod map_entries;
+pub mod map_extract;
 pub mod set_list_value;
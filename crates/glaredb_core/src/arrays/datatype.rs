@@ -1,4 +1,5 @@
 use std::fmt;
+use std::sync::Arc;
 
 use glaredb_error::{DbError, Result, ResultExt};
 use serde::{Deserialize, Serialize};
@@ -52,14 +53,21 @@ pub enum DataTypeId {
     Date32,
     /// Milliseconds since unix epoch.
     Date64,
+    /// Time of day, represented as microseconds since midnight.
+    Time,
     /// Some time interval with nanosecond resolution.
     Interval,
     Utf8,
     Binary,
+    /// 128-bit UUID, stored as a big-endian u128 so that ordering matches the
+    /// byte ordering.
+    Uuid,
     /// A struct of different types.
     Struct,
     /// A list of values all of the same type.
     List,
+    /// A map of keys to values.
+    Map,
 }
 
 impl fmt::Display for DataTypeId {
@@ -87,11 +95,14 @@ impl fmt::Display for DataTypeId {
             Self::Timestamp => write!(f, "Timestamp"),
             Self::Date32 => write!(f, "Date32"),
             Self::Date64 => write!(f, "Date64"),
+            Self::Time => write!(f, "Time"),
             Self::Interval => write!(f, "Interval"),
             Self::Utf8 => write!(f, "Utf8"),
             Self::Binary => write!(f, "Binary"),
+            Self::Uuid => write!(f, "Uuid"),
             Self::Struct => write!(f, "Struct"),
             Self::List => write!(f, "List"),
+            Self::Map => write!(f, "Map"),
         }
     }
 }
@@ -206,12 +217,25 @@ impl DecimalTypeMeta {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TimestampTypeMeta {
     pub unit: TimeUnit,
-    // TODO: Optional timezone (hence no copy)
+    /// Optional time zone for the timestamp.
+    ///
+    /// Values are always stored as an offset from the unix epoch in UTC. A
+    /// timestamp with a time zone is displayed in, and has its local fields
+    /// (day, hour, etc) computed in, that time zone. Timestamps without a
+    /// time zone are naive.
+    pub tz: Option<Arc<str>>,
 }
 
 impl TimestampTypeMeta {
     pub const fn new(unit: TimeUnit) -> Self {
-        TimestampTypeMeta { unit }
+        TimestampTypeMeta { unit, tz: None }
+    }
+
+    pub fn new_with_tz(unit: TimeUnit, tz: impl Into<Arc<str>>) -> Self {
+        TimestampTypeMeta {
+            unit,
+            tz: Some(tz.into()),
+        }
     }
 }
 
@@ -266,6 +290,22 @@ impl ListTypeMeta {
     }
 }
 
+/// Metadata associated with maps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MapTypeMeta {
+    pub key: Box<DataType>,
+    pub value: Box<DataType>,
+}
+
+impl MapTypeMeta {
+    pub fn new(key: DataType, value: DataType) -> Self {
+        MapTypeMeta {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+    }
+}
+
 /// Additional metadata that may be associated with a type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DataTypeMeta {
@@ -273,6 +313,7 @@ pub enum DataTypeMeta {
     Timestamp(TimestampTypeMeta),
     Struct(StructTypeMeta),
     List(ListTypeMeta),
+    Map(MapTypeMeta),
     None,
 }
 
@@ -322,11 +363,15 @@ impl DataType {
     pub const DATE32: &'static Self = &Self::new_primitive(DataTypeId::Date32);
     pub const DATE64: &'static Self = &Self::new_primitive(DataTypeId::Date64);
 
+    pub const TIME: &'static Self = &Self::new_primitive(DataTypeId::Time);
+
     pub const INTERVAL: &'static Self = &Self::new_primitive(DataTypeId::Interval);
 
     pub const UTF8: &'static Self = &Self::new_primitive(DataTypeId::Utf8);
     pub const BINARY: &'static Self = &Self::new_primitive(DataTypeId::Binary);
 
+    pub const UUID: &'static Self = &Self::new_primitive(DataTypeId::Uuid);
+
     datatype_new_primitive!(null, NULL);
 
     datatype_new_primitive!(boolean, BOOLEAN);
@@ -350,11 +395,15 @@ impl DataType {
     datatype_new_primitive!(date32, DATE32);
     datatype_new_primitive!(date64, DATE64);
 
+    datatype_new_primitive!(time, TIME);
+
     datatype_new_primitive!(interval, INTERVAL);
 
     datatype_new_primitive!(utf8, UTF8);
     datatype_new_primitive!(binary, BINARY);
 
+    datatype_new_primitive!(uuid, UUID);
+
     const fn new_primitive(id: DataTypeId) -> Self {
         DataType {
             id,
@@ -369,6 +418,13 @@ impl DataType {
         }
     }
 
+    pub fn map(key_type: DataType, value_type: DataType) -> Self {
+        DataType {
+            id: DataTypeId::Map,
+            metadata: DataTypeMeta::Map(MapTypeMeta::new(key_type, value_type)),
+        }
+    }
+
     pub fn struct_type(fields: impl IntoIterator<Item = Field>) -> Self {
         DataType {
             id: DataTypeId::Struct,
@@ -460,21 +516,24 @@ impl DataType {
             },
             DataTypeId::Timestamp => (
                 DataTypeId::Timestamp,
-                DataTypeMeta::Timestamp(TimestampTypeMeta {
-                    unit: TimeUnit::Microsecond,
-                }),
+                DataTypeMeta::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
             ),
             DataTypeId::Date32 => (DataTypeId::Date32, DataTypeMeta::None),
             DataTypeId::Date64 => (DataTypeId::Date64, DataTypeMeta::None),
+            DataTypeId::Time => (DataTypeId::Time, DataTypeMeta::None),
             DataTypeId::Interval => (DataTypeId::Interval, DataTypeMeta::None),
             DataTypeId::Utf8 => (DataTypeId::Utf8, DataTypeMeta::None),
             DataTypeId::Binary => (DataTypeId::Binary, DataTypeMeta::None),
+            DataTypeId::Uuid => (DataTypeId::Uuid, DataTypeMeta::None),
             DataTypeId::Struct => {
                 return Err(DbError::new("Cannot create a default Struct datatype"));
             }
             DataTypeId::List => {
                 return Err(DbError::new("Cannot create a default List datatype"));
             }
+            DataTypeId::Map => {
+                return Err(DbError::new("Cannot create a default Map datatype"));
+            }
         };
 
         Ok(DataType { id, metadata: meta })
@@ -507,11 +566,14 @@ impl DataType {
             DataTypeId::Timestamp => PhysicalType::Int64,
             DataTypeId::Date32 => PhysicalType::Int32,
             DataTypeId::Date64 => PhysicalType::Int64,
+            DataTypeId::Time => PhysicalType::Int64,
             DataTypeId::Interval => PhysicalType::Interval,
             DataTypeId::Utf8 => PhysicalType::Utf8,
             DataTypeId::Binary => PhysicalType::Binary,
+            DataTypeId::Uuid => PhysicalType::UInt128,
             DataTypeId::Struct => PhysicalType::Struct,
             DataTypeId::List => PhysicalType::List,
+            DataTypeId::Map => PhysicalType::Map,
             other => {
                 return Err(DbError::new(format!(
                     "Cannot get physical type for {other}"
@@ -567,6 +629,15 @@ impl DataType {
         }
     }
 
+    pub fn try_get_map_type_meta(&self) -> Result<&MapTypeMeta> {
+        match &self.metadata {
+            DataTypeMeta::Map(m) => Ok(m),
+            other => Err(DbError::new(format!(
+                "Cannot get map type meta from metadata {other:?}"
+            ))),
+        }
+    }
+
     pub fn try_get_timestamp_type_meta(&self) -> Result<&TimestampTypeMeta> {
         match &self.metadata {
             DataTypeMeta::Timestamp(m) => Ok(m),
@@ -619,14 +690,19 @@ impl fmt::Display for DataType {
                 _ => write!(f, "Decimal128(Unknown,Unknown)"),
             },
             DataTypeId::Timestamp => match &self.metadata {
-                DataTypeMeta::Timestamp(m) => write!(f, "Timestamp({})", m.unit),
+                DataTypeMeta::Timestamp(m) => match &m.tz {
+                    Some(tz) => write!(f, "Timestamp({}, {})", m.unit, tz),
+                    None => write!(f, "Timestamp({})", m.unit),
+                },
                 _ => write!(f, "Timestamp(Unknown)"),
             },
             DataTypeId::Date32 => write!(f, "Date32"),
             DataTypeId::Date64 => write!(f, "Date64"),
+            DataTypeId::Time => write!(f, "Time"),
             DataTypeId::Interval => write!(f, "Interval"),
             DataTypeId::Utf8 => write!(f, "Utf8"),
             DataTypeId::Binary => write!(f, "Binary"),
+            DataTypeId::Uuid => write!(f, "Uuid"),
             DataTypeId::Struct => match &self.metadata {
                 DataTypeMeta::Struct(m) => {
                     write!(
@@ -645,6 +721,10 @@ impl fmt::Display for DataType {
                 DataTypeMeta::List(m) => write!(f, "List[{}]", m.datatype),
                 _ => write!(f, "List[Unknown]"),
             },
+            DataTypeId::Map => match &self.metadata {
+                DataTypeMeta::Map(m) => write!(f, "Map[{}, {}]", m.key, m.value),
+                _ => write!(f, "Map[Unknown, Unknown]"),
+            },
         }
     }
 }
@@ -160,7 +160,7 @@ mod tests {
     use crate::util::iter::TryFromExactSizeIterator;
 
     // SUM(col) + PRODUCT(col)
-    #[derive(Debug)]
+    #[derive(Debug, Clone)]
     struct TestAddSumAndProductState {
         sum: i32,
         product: i32,
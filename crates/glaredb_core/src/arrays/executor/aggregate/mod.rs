@@ -16,7 +16,10 @@ use crate::arrays::array::physical_type::AddressableMut;
 ///
 /// An example state for SUM would be a struct that takes a running sum from
 /// values provided in `update`.
-pub trait AggregateState<Input, Output: ?Sized>: Debug + Sync + Send {
+///
+/// States must be cloneable to allow for window functions to finalize an
+/// intermediate state while continuing to update it.
+pub trait AggregateState<Input, Output: ?Sized>: Clone + Debug + Sync + Send {
     type BindState: Sync + Send;
 
     /// Merge other state into this state.
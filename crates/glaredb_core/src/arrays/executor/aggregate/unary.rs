@@ -91,7 +91,7 @@ mod tests {
     use crate::buffer::buffer_manager::DefaultBufferManager;
     use crate::util::iter::TryFromExactSizeIterator;
 
-    #[derive(Debug, Default)]
+    #[derive(Debug, Clone, Default)]
     struct TestSumState {
         val: i32,
     }
@@ -239,7 +239,7 @@ mod tests {
         sep: String,
     }
 
-    #[derive(Debug, Default)]
+    #[derive(Debug, Clone, Default)]
     struct TestStringAgg {
         val: String,
     }
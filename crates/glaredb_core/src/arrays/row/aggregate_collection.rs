@@ -66,6 +66,11 @@ impl AggregateCollection {
         self.blocks.total_rows()
     }
 
+    /// Returns the number of bytes allocated for this collection.
+    pub fn size_bytes(&self) -> usize {
+        self.blocks.size_bytes()
+    }
+
     pub fn num_row_blocks(&self) -> usize {
         self.blocks.num_row_blocks()
     }
@@ -115,6 +115,16 @@ where
         self.heap_blocks.len()
     }
 
+    /// Returns the total number of bytes allocated for both row and heap
+    /// blocks.
+    pub fn size_bytes(&self) -> usize {
+        self.row_blocks
+            .iter()
+            .chain(&self.heap_blocks)
+            .map(|block| block.data.capacity())
+            .sum()
+    }
+
     /// Moves the blocks from other to self.
     ///
     /// This does not verify or update any data inside the blocks.
@@ -185,6 +185,7 @@ impl RowLayout {
                 }
                 PhysicalType::Struct => not_implemented!("compute heap sizes for struct"),
                 PhysicalType::List => not_implemented!("compute heap sizes for list"),
+                PhysicalType::Map => not_implemented!("compute heap sizes for map"),
                 _ => (),
             }
         }
@@ -255,7 +256,11 @@ impl RowLayout {
 pub(crate) const fn row_encoding_requires_heap(phys_type: PhysicalType) -> bool {
     matches!(
         phys_type,
-        PhysicalType::Utf8 | PhysicalType::Binary | PhysicalType::List | PhysicalType::Struct
+        PhysicalType::Utf8
+            | PhysicalType::Binary
+            | PhysicalType::List
+            | PhysicalType::Struct
+            | PhysicalType::Map
     )
 }
 
@@ -281,6 +286,7 @@ pub(crate) const fn row_width_for_physical_type(phys_type: PhysicalType) -> usiz
         PhysicalType::Utf8 => std::mem::size_of::<StringPtr>(),
         PhysicalType::List => 0,   // TODO: Probably metadata
         PhysicalType::Struct => 0, // TODO: Probaby metadata
+        PhysicalType::Map => 0,
     }
 }
 
@@ -173,6 +173,9 @@ where
         PhysicalType::Struct => Box::new(UnsupportedMatcher::new(
             "Matching struct rows not yet supported",
         )),
+        PhysicalType::Map => Box::new(UnsupportedMatcher::new(
+            "Matching map rows not yet supported",
+        )),
     }
 }
 
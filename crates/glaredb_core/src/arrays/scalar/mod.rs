@@ -1,6 +1,7 @@
 pub mod decimal;
 pub mod interval;
 pub mod timestamp;
+pub mod timezone;
 pub mod unwrap;
 
 use std::borrow::Cow;
@@ -33,15 +34,18 @@ use crate::functions::cast::format::{
     Int64Formatter,
     Int128Formatter,
     IntervalFormatter,
+    TimeFormatter,
     TimestampMicrosecondsFormatter,
     TimestampMillisecondsFormatter,
     TimestampNanosecondsFormatter,
     TimestampSecondsFormatter,
+    TimestampTzFormatter,
     UInt8Formatter,
     UInt16Formatter,
     UInt32Formatter,
     UInt64Formatter,
     UInt128Formatter,
+    UuidFormatter,
 };
 
 /// A single scalar value.
@@ -66,12 +70,18 @@ pub enum BorrowedScalarValue<'a> {
     Decimal128(Decimal128Scalar),
     Date32(i32),
     Date64(i64),
+    /// Microseconds since midnight.
+    Time(i64),
     Timestamp(TimestampScalar),
     Interval(Interval),
     Utf8(Cow<'a, str>),
     Binary(Cow<'a, [u8]>),
+    /// UUID as a big-endian u128.
+    Uuid(u128),
     Struct(Vec<BorrowedScalarValue<'a>>),
     List(Vec<BorrowedScalarValue<'a>>),
+    /// Map entries as (key, value) pairs.
+    Map(Vec<(BorrowedScalarValue<'a>, BorrowedScalarValue<'a>)>),
 }
 
 // TODO: TBD if we want this. We may need to implement PartialEq to exact
@@ -100,12 +110,15 @@ impl Hash for BorrowedScalarValue<'_> {
             Self::Decimal128(v) => v.hash(state),
             Self::Date32(v) => v.hash(state),
             Self::Date64(v) => v.hash(state),
+            Self::Time(v) => v.hash(state),
             Self::Timestamp(v) => v.hash(state),
             Self::Interval(v) => v.hash(state),
             Self::Utf8(v) => v.hash(state),
             Self::Binary(v) => v.hash(state),
+            Self::Uuid(v) => v.hash(state),
             Self::Struct(v) => v.hash(state),
             Self::List(v) => v.hash(state),
+            Self::Map(v) => v.hash(state),
         }
     }
 }
@@ -138,17 +151,24 @@ impl BorrowedScalarValue<'_> {
             }
             BorrowedScalarValue::Date32(_) => DataType::date32(),
             BorrowedScalarValue::Date64(_) => DataType::date64(),
-            BorrowedScalarValue::Timestamp(v) => {
-                DataType::timestamp(TimestampTypeMeta::new(v.unit))
-            }
+            BorrowedScalarValue::Time(_) => DataType::time(),
+            BorrowedScalarValue::Timestamp(v) => DataType::timestamp(TimestampTypeMeta {
+                unit: v.unit,
+                tz: v.tz.clone(),
+            }),
             BorrowedScalarValue::Interval(_) => DataType::interval(),
             BorrowedScalarValue::Utf8(_) => DataType::utf8(),
             BorrowedScalarValue::Binary(_) => DataType::binary(),
+            BorrowedScalarValue::Uuid(_) => DataType::uuid(),
             BorrowedScalarValue::Struct(_fields) => unimplemented!(), // TODO: Fill out the meta
             BorrowedScalarValue::List(list) => match list.first() {
                 Some(first) => DataType::list(first.datatype()),
                 None => DataType::list(DataType::null()),
             },
+            BorrowedScalarValue::Map(entries) => match entries.first() {
+                Some((key, value)) => DataType::map(key.datatype(), value.datatype()),
+                None => DataType::map(DataType::null(), DataType::null()),
+            },
         }
     }
 
@@ -173,12 +193,19 @@ impl BorrowedScalarValue<'_> {
             Self::Decimal128(v) => ScalarValue::Decimal128(v),
             Self::Date32(v) => ScalarValue::Date32(v),
             Self::Date64(v) => ScalarValue::Date64(v),
+            Self::Time(v) => ScalarValue::Time(v),
             Self::Timestamp(v) => ScalarValue::Timestamp(v),
             Self::Interval(v) => ScalarValue::Interval(v),
             Self::Utf8(v) => ScalarValue::Utf8(v.into_owned().into()),
             Self::Binary(v) => ScalarValue::Binary(v.into_owned().into()),
+            Self::Uuid(v) => ScalarValue::Uuid(v),
             Self::Struct(v) => ScalarValue::Struct(v.into_iter().map(|v| v.into_owned()).collect()),
             Self::List(v) => ScalarValue::List(v.into_iter().map(|v| v.into_owned()).collect()),
+            Self::Map(v) => ScalarValue::Map(
+                v.into_iter()
+                    .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                    .collect(),
+            ),
         }
     }
 
@@ -315,19 +342,27 @@ impl fmt::Display for BorrowedScalarValue<'_> {
             }
             Self::Date32(v) => Date32Formatter.write(v, f),
             Self::Date64(v) => Date64Formatter.write(v, f),
-            Self::Timestamp(v) => match v.unit {
-                TimeUnit::Second => TimestampSecondsFormatter::default().write(&v.value, f),
-                TimeUnit::Millisecond => {
+            Self::Time(v) => TimeFormatter.write(v, f),
+            Self::Timestamp(v) => match (v.unit, &v.tz) {
+                (unit, Some(tz)) => {
+                    let tz = tz.parse().map_err(|_| fmt::Error)?;
+                    TimestampTzFormatter { unit, tz }.write(&v.value, f)
+                }
+                (TimeUnit::Second, _) => TimestampSecondsFormatter::default().write(&v.value, f),
+                (TimeUnit::Millisecond, _) => {
                     TimestampMillisecondsFormatter::default().write(&v.value, f)
                 }
-                TimeUnit::Microsecond => {
+                (TimeUnit::Microsecond, _) => {
                     TimestampMicrosecondsFormatter::default().write(&v.value, f)
                 }
-                TimeUnit::Nanosecond => TimestampNanosecondsFormatter::default().write(&v.value, f),
+                (TimeUnit::Nanosecond, _) => {
+                    TimestampNanosecondsFormatter::default().write(&v.value, f)
+                }
             },
             Self::Interval(v) => IntervalFormatter.write(v, f),
             Self::Utf8(v) => write!(f, "{}", v),
             Self::Binary(v) => write!(f, "{:X?}", v),
+            Self::Uuid(v) => UuidFormatter.write(v, f),
             Self::Struct(fields) => write!(
                 f,
                 "{{{}}}",
@@ -345,6 +380,15 @@ impl fmt::Display for BorrowedScalarValue<'_> {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Self::Map(entries) => write!(
+                f,
+                "{{{}}}",
+                entries
+                    .iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 }
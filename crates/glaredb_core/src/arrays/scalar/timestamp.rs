@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
 use crate::arrays::datatype::TimeUnit;
@@ -6,4 +8,6 @@ use crate::arrays::datatype::TimeUnit;
 pub struct TimestampScalar {
     pub unit: TimeUnit,
     pub value: i64,
+    /// Time zone of the timestamp, if any.
+    pub tz: Option<Arc<str>>,
 }
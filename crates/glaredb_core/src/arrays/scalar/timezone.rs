@@ -0,0 +1,312 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::offset::LocalResult;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone as _};
+use chrono_tz::Tz;
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::datatype::TimeUnit;
+
+/// A time zone used for timestamps.
+///
+/// Time zones can either be a named zone from the IANA database (e.g.
+/// 'America/New_York'), or a fixed offset from UTC (e.g. '+05:30').
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeZone {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl TimeZone {
+    pub const UTC: TimeZone = TimeZone::Named(Tz::UTC);
+
+    /// Get the offset from UTC for this zone at the given UTC time.
+    pub fn offset_from_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Named(tz) => tz.offset_from_utc_datetime(utc).fix(),
+            Self::Fixed(offset) => *offset,
+        }
+    }
+
+    /// Convert a UTC time to the local wall clock time in this zone.
+    pub fn utc_to_local(&self, utc: NaiveDateTime) -> NaiveDateTime {
+        utc + self.offset_from_utc(&utc)
+    }
+
+    /// Convert a local wall clock time in this zone to UTC.
+    ///
+    /// Ambiguous local times (during a backward transition) resolve to the
+    /// earliest instant. Local times that don't exist (during a forward
+    /// transition) are shifted forward by the size of the gap, matching
+    /// Postgres.
+    pub fn local_to_utc(&self, local: NaiveDateTime) -> NaiveDateTime {
+        let offset = match self {
+            Self::Named(tz) => match tz.offset_from_local_datetime(&local) {
+                LocalResult::Single(offset) => offset.fix(),
+                LocalResult::Ambiguous(earliest, _) => earliest.fix(),
+                // Use the offset in effect prior to the transition.
+                LocalResult::None => tz.offset_from_utc_datetime(&local).fix(),
+            },
+            Self::Fixed(offset) => *offset,
+        };
+        local - offset
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = DbError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("z") {
+            return Ok(Self::UTC);
+        }
+
+        if let Some(rest) = s.strip_prefix('+') {
+            return parse_offset(rest, 1).map(Self::Fixed);
+        }
+        if let Some(rest) = s.strip_prefix('-') {
+            return parse_offset(rest, -1).map(Self::Fixed);
+        }
+
+        Tz::from_str_insensitive(s)
+            .map(Self::Named)
+            .map_err(|_| DbError::new(format!("Unknown time zone: '{s}'")))
+    }
+}
+
+/// Parse the 'HH', 'HH:MM', or 'HHMM' portion of a UTC offset.
+fn parse_offset(s: &str, sign: i32) -> Result<FixedOffset> {
+    let invalid = || DbError::new(format!("Invalid UTC offset: '{s}'"));
+
+    let (hours, minutes) = match s.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if s.len() > 2 => s.split_at(2),
+        None => (s, "0"),
+    };
+    if hours.is_empty()
+        || !hours.bytes().all(|b| b.is_ascii_digit())
+        || !minutes.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 15 || minutes > 59 {
+        return Err(invalid());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(tz) => write!(f, "{}", tz.name()),
+            Self::Fixed(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+/// Write a UTC offset in the short form used for displaying timestamps,
+/// e.g. '+05', '-03:30'.
+pub fn write_utc_offset<W: fmt::Write>(offset: FixedOffset, buf: &mut W) -> fmt::Result {
+    let secs = offset.local_minus_utc();
+    let sign = if secs < 0 { '-' } else { '+' };
+    let secs = secs.abs();
+    let (hours, minutes) = (secs / 3600, (secs / 60) % 60);
+    if minutes == 0 {
+        write!(buf, "{sign}{hours:02}")
+    } else {
+        write!(buf, "{sign}{hours:02}:{minutes:02}")
+    }
+}
+
+/// Convert a timestamp value in the given unit to a naive date time.
+///
+/// Returns None if the value is out of range.
+pub fn timestamp_to_naive(unit: TimeUnit, val: i64) -> Option<NaiveDateTime> {
+    let datetime = match unit {
+        TimeUnit::Second => DateTime::from_timestamp(val, 0)?,
+        TimeUnit::Millisecond => DateTime::from_timestamp_millis(val)?,
+        TimeUnit::Microsecond => DateTime::from_timestamp_micros(val)?,
+        TimeUnit::Nanosecond => DateTime::from_timestamp_nanos(val),
+    };
+    Some(datetime.naive_utc())
+}
+
+/// Convert a naive date time to a timestamp value in the given unit.
+///
+/// Returns None if the value is out of range.
+pub fn naive_to_timestamp(unit: TimeUnit, datetime: NaiveDateTime) -> Option<i64> {
+    let datetime = datetime.and_utc();
+    match unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    }
+}
+
+/// Parse a timestamp string into its wall clock time and an optional time
+/// zone.
+///
+/// Accepts a date, optionally followed by a time separated by a space or 'T',
+/// optionally followed by a UTC offset or zone name.
+///
+/// Example formats:
+///
+/// '2024-03-10'
+/// '2024-03-10 02:30:00'
+/// '2024-03-10T02:30:00.123Z'
+/// '2024-03-10 02:30:00+05:30'
+/// '2024-03-10 02:30:00 America/New_York'
+pub fn parse_timestamp(s: &str) -> Option<(NaiveDateTime, Option<TimeZone>)> {
+    let s = s.trim();
+
+    let date_end = s.find([' ', 'T']).unwrap_or(s.len());
+    let date = NaiveDate::from_str(&s[..date_end]).ok()?;
+
+    let mut rest = &s[date_end..];
+    if let Some(stripped) = rest.strip_prefix('T') {
+        rest = stripped;
+    }
+    let rest = rest.trim_start();
+
+    let time_end = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == ':' || c == '.'))
+        .unwrap_or(rest.len());
+    let time = match &rest[..time_end] {
+        "" => NaiveTime::MIN,
+        time => NaiveTime::parse_from_str(time, "%H:%M:%S%.f")
+            .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+            .ok()?,
+    };
+
+    let tz = match rest[time_end..].trim() {
+        "" => None,
+        tz => Some(tz.parse().ok()?),
+    };
+
+    Some((date.and_time(time), tz))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn parse_time_zones() {
+        assert_eq!(TimeZone::UTC, "UTC".parse().unwrap());
+        assert_eq!(TimeZone::UTC, "utc".parse().unwrap());
+        assert_eq!(TimeZone::UTC, "Z".parse().unwrap());
+        assert_eq!(
+            TimeZone::Named(Tz::America__New_York),
+            "america/new_york".parse().unwrap()
+        );
+
+        let offset = |secs| TimeZone::Fixed(FixedOffset::east_opt(secs).unwrap());
+        assert_eq!(offset(5 * 3600), "+05".parse().unwrap());
+        assert_eq!(offset(5 * 3600 + 1800), "+05:30".parse().unwrap());
+        assert_eq!(offset(5 * 3600 + 1800), "+0530".parse().unwrap());
+        assert_eq!(offset(-8 * 3600), "-8".parse().unwrap());
+
+        "Mars/Olympus_Mons".parse::<TimeZone>().unwrap_err();
+        "+25".parse::<TimeZone>().unwrap_err();
+        "+05:xx".parse::<TimeZone>().unwrap_err();
+        "+".parse::<TimeZone>().unwrap_err();
+    }
+
+    #[test]
+    fn convert_named_zone() {
+        let tz: TimeZone = "America/New_York".parse().unwrap();
+
+        // Standard time.
+        let utc = datetime("2024-01-15 15:00:00");
+        assert_eq!(datetime("2024-01-15 10:00:00"), tz.utc_to_local(utc));
+        assert_eq!(utc, tz.local_to_utc(datetime("2024-01-15 10:00:00")));
+
+        // Daylight time.
+        let utc = datetime("2024-07-15 14:00:00");
+        assert_eq!(datetime("2024-07-15 10:00:00"), tz.utc_to_local(utc));
+        assert_eq!(utc, tz.local_to_utc(datetime("2024-07-15 10:00:00")));
+    }
+
+    #[test]
+    fn convert_across_transitions() {
+        let tz: TimeZone = "America/New_York".parse().unwrap();
+
+        // 02:30 doesn't exist on 2024-03-10, shifts forward to 03:30 EDT.
+        let utc = tz.local_to_utc(datetime("2024-03-10 02:30:00"));
+        assert_eq!(datetime("2024-03-10 07:30:00"), utc);
+        assert_eq!(datetime("2024-03-10 03:30:00"), tz.utc_to_local(utc));
+
+        // 01:30 happens twice on 2024-11-03, picks the first (EDT).
+        let utc = tz.local_to_utc(datetime("2024-11-03 01:30:00"));
+        assert_eq!(datetime("2024-11-03 05:30:00"), utc);
+    }
+
+    #[test]
+    fn timestamp_units_roundtrip() {
+        let dt = datetime("2024-03-10 02:30:00");
+        for unit in [
+            TimeUnit::Second,
+            TimeUnit::Millisecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Nanosecond,
+        ] {
+            let val = naive_to_timestamp(unit, dt).unwrap();
+            assert_eq!(dt, timestamp_to_naive(unit, val).unwrap());
+        }
+    }
+
+    #[test]
+    fn parse_timestamp_strings() {
+        let dt = datetime("2024-03-10 02:30:00");
+        assert_eq!(Some((dt, None)), parse_timestamp("2024-03-10 02:30:00"));
+        assert_eq!(Some((dt, None)), parse_timestamp("2024-03-10T02:30:00"));
+        assert_eq!(Some((dt, None)), parse_timestamp("2024-03-10 02:30"));
+        assert_eq!(
+            Some((datetime("2024-03-10 00:00:00"), None)),
+            parse_timestamp("2024-03-10")
+        );
+        assert_eq!(
+            Some((dt, Some(TimeZone::UTC))),
+            parse_timestamp("2024-03-10T02:30:00Z")
+        );
+        assert_eq!(
+            Some((dt, Some("+05:30".parse().unwrap()))),
+            parse_timestamp("2024-03-10 02:30:00+05:30")
+        );
+        assert_eq!(
+            Some((dt, Some("America/New_York".parse().unwrap()))),
+            parse_timestamp("2024-03-10 02:30:00 America/New_York")
+        );
+
+        let (frac, _) = parse_timestamp("2024-03-10 02:30:00.123456").unwrap();
+        assert_eq!(123_456_000, frac.and_utc().timestamp_subsec_nanos());
+
+        assert_eq!(None, parse_timestamp("2024-03-10 02:30:00 Nowhere"));
+        assert_eq!(None, parse_timestamp("2024-13-10"));
+        assert_eq!(None, parse_timestamp("hello"));
+    }
+
+    #[test]
+    fn write_offsets() {
+        let write = |secs| {
+            let mut s = String::new();
+            write_utc_offset(FixedOffset::east_opt(secs).unwrap(), &mut s).unwrap();
+            s
+        };
+        assert_eq!("+00", write(0));
+        assert_eq!("-05", write(-5 * 3600));
+        assert_eq!("+05:30", write(5 * 3600 + 1800));
+        assert_eq!("-03:30", write(-(3 * 3600 + 1800)));
+    }
+}
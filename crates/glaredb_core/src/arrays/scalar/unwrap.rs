@@ -76,7 +76,6 @@ impl_single_variant!(u8, UnwrapU8, UInt8);
 impl_single_variant!(u16, UnwrapU16, UInt16);
 impl_single_variant!(u32, UnwrapU32, UInt32);
 impl_single_variant!(u64, UnwrapU64, UInt64);
-impl_single_variant!(u128, UnwrapU128, UInt128);
 
 impl_single_variant!(f16, UnwrapF16, Float16);
 impl_single_variant!(f32, UnwrapF32, Float32);
@@ -108,6 +107,27 @@ impl ScalarValueUnwrap for UnwrapI32 {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnwrapU128;
+
+impl ScalarValueUnwrap for UnwrapU128 {
+    type StorageType = u128;
+
+    fn try_unwrap<'a>(
+        scalar: &'a BorrowedScalarValue<'a>,
+    ) -> Result<NullableValue<'a, Self::StorageType>> {
+        match scalar {
+            BorrowedScalarValue::Null => Ok(NullableValue::Null),
+            BorrowedScalarValue::UInt128(v) => Ok(NullableValue::Value(v)),
+            BorrowedScalarValue::Uuid(v) => Ok(NullableValue::Value(v)),
+            other => Err(DbError::new(format!(
+                "Cannot unwrap '{other}' using {:?}",
+                Self,
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnwrapI64;
 
@@ -121,6 +141,7 @@ impl ScalarValueUnwrap for UnwrapI64 {
             BorrowedScalarValue::Null => Ok(NullableValue::Null),
             BorrowedScalarValue::Int64(v) => Ok(NullableValue::Value(v)),
             BorrowedScalarValue::Date64(v) => Ok(NullableValue::Value(v)),
+            BorrowedScalarValue::Time(v) => Ok(NullableValue::Value(v)),
             BorrowedScalarValue::Decimal64(v) => Ok(NullableValue::Value(&v.value)),
             BorrowedScalarValue::Timestamp(v) => Ok(NullableValue::Value(&v.value)),
             other => Err(DbError::new(format!(
@@ -97,6 +97,16 @@ impl PartialSortedRowCollection {
             .sum()
     }
 
+    /// Returns the number of bytes allocated for both the unsorted and sorted
+    /// rows in this collection.
+    pub fn size_bytes(&self) -> usize {
+        let sorted: usize = self.sorted.iter().map(|block| block.size_bytes()).sum();
+        self.key_blocks.size_bytes()
+            + self.key_heap_blocks.size_bytes()
+            + self.data_blocks.size_bytes()
+            + sorted
+    }
+
     pub fn init_append_state(&self) -> SortedRowAppendState {
         // TODO: We should probably be able to reuse the same append state for
         // each step.
@@ -31,6 +31,17 @@ pub struct SortedBlock {
 }
 
 impl SortedBlock {
+    /// Returns the number of bytes allocated for this block, including heap
+    /// blocks.
+    pub fn size_bytes(&self) -> usize {
+        [&self.keys, &self.heap_keys, &self.data]
+            .into_iter()
+            .chain(&self.heap_keys_heap)
+            .chain(&self.data_heap)
+            .map(|block| block.data.capacity())
+            .sum()
+    }
+
     /// Create a sorted block from the given key/data blocks.
     ///
     /// Returns None if the number of rows in the block is zero.
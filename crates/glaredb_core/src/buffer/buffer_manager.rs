@@ -1,6 +1,8 @@
 use std::alloc::{self, Layout};
 use std::fmt::Debug;
 use std::ptr::NonNull;
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicUsize};
 
 use glaredb_error::{DbError, Result, ResultExt};
 
@@ -140,6 +142,118 @@ impl Reservation {
     }
 }
 
+/// Memory limit for a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLimit {
+    bytes: usize,
+}
+
+impl MemoryLimit {
+    pub const fn new(bytes: usize) -> Self {
+        MemoryLimit { bytes }
+    }
+
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+/// Tracks bytes reserved against a memory limit.
+///
+/// This doesn't allocate anything, and allocations made through buffer
+/// managers aren't counted. Operators that are able to spill to disk account
+/// for the data they hold with a `MemoryReservation`, and spill once growing
+/// that reservation would go over the limit. Clones share the same accounting.
+#[derive(Debug, Clone)]
+pub struct MemoryTracker {
+    limit: MemoryLimit,
+    reserved: Arc<AtomicUsize>,
+}
+
+impl MemoryTracker {
+    pub fn new(limit: MemoryLimit) -> Self {
+        MemoryTracker {
+            limit,
+            reserved: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn limit(&self) -> MemoryLimit {
+        self.limit
+    }
+
+    /// Returns the total number of bytes currently reserved.
+    pub fn reserved_bytes(&self) -> usize {
+        self.reserved.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Creates a new empty reservation.
+    pub fn new_reservation(&self) -> MemoryReservation {
+        MemoryReservation {
+            tracker: self.clone(),
+            size: 0,
+        }
+    }
+
+    /// Try to add `bytes` to the total reserved, returning false if that would
+    /// go over the limit.
+    fn try_track(&self, bytes: usize) -> bool {
+        self.reserved
+            .fetch_update(
+                atomic::Ordering::Relaxed,
+                atomic::Ordering::Relaxed,
+                |curr| {
+                    curr.checked_add(bytes)
+                        .filter(|&total| total <= self.limit.bytes())
+                },
+            )
+            .is_ok()
+    }
+
+    fn untrack(&self, bytes: usize) {
+        self.reserved.fetch_sub(bytes, atomic::Ordering::Relaxed);
+    }
+}
+
+/// Bytes accounted for against a `MemoryTracker`.
+///
+/// Used by operators to track the size of data they're able to spill. The
+/// bytes are released when the reservation is dropped.
+#[derive(Debug)]
+pub struct MemoryReservation {
+    tracker: MemoryTracker,
+    size: usize,
+}
+
+impl MemoryReservation {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Resize the reservation to `new_size` bytes.
+    ///
+    /// Returns false if growing the reservation would go over the memory
+    /// limit, in which case the reservation is left unchanged. Shrinking always
+    /// succeeds.
+    pub fn try_resize(&mut self, new_size: usize) -> bool {
+        if new_size > self.size {
+            if !self.tracker.try_track(new_size - self.size) {
+                return false;
+            }
+        } else {
+            self.tracker.untrack(self.size - new_size);
+        }
+        self.size = new_size;
+        true
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.tracker.untrack(self.size);
+    }
+}
+
 /// Default buffer manager that just freely allocates everything.
 #[derive(Debug, Clone)]
 pub struct DefaultBufferManager;
@@ -283,4 +397,23 @@ mod tests {
         assert_eq!(2, reservation.align());
         DefaultBufferManager.free_reservation(&mut reservation);
     }
+
+    #[test]
+    fn memory_reservation_shares_limit() {
+        let tracker = MemoryTracker::new(MemoryLimit::new(16));
+        let mut a = tracker.new_reservation();
+        let mut b = tracker.new_reservation();
+
+        assert!(a.try_resize(10));
+        assert!(!b.try_resize(10));
+        assert_eq!(0, b.size());
+
+        assert!(a.try_resize(2));
+        assert!(b.try_resize(10));
+        assert_eq!(12, tracker.reserved_bytes());
+
+        drop(a);
+        drop(b);
+        assert_eq!(0, tracker.reserved_bytes());
+    }
 }
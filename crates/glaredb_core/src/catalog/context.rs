@@ -5,11 +5,12 @@ use std::sync::Arc;
 use glaredb_error::{DbError, Result};
 
 use super::database::{AccessMode, Database};
+use super::datasource::DataSourceRegistry;
 use super::memory::MemoryCatalog;
 use super::profile::ProfileCollector;
-use crate::catalog::Catalog;
 use crate::catalog::create::{CreateSchemaInfo, OnConflict};
 use crate::storage::storage_manager::StorageManager;
+use crate::transaction::{Transaction, TransactionManager};
 
 pub const SYSTEM_CATALOG: &str = "system";
 pub const TEMP_CATALOG: &str = "temp";
@@ -18,11 +19,19 @@ pub const TEMP_CATALOG: &str = "temp";
 #[derive(Debug)]
 pub struct DatabaseContext {
     databases: HashMap<String, Arc<Database>>,
+    datasources: Arc<DataSourceRegistry>,
     profiles: Arc<ProfileCollector>,
+    transactions: Arc<TransactionManager>,
+    /// Transaction the current statement is executing in.
+    transaction: Arc<Transaction>,
 }
 
 impl DatabaseContext {
-    pub fn new(system_catalog: Arc<Database>) -> Result<Self> {
+    pub fn new(
+        system_catalog: Arc<Database>,
+        datasources: Arc<DataSourceRegistry>,
+        transactions: Arc<TransactionManager>,
+    ) -> Result<Self> {
         let mut databases = HashMap::new();
         databases.insert(system_catalog.name.clone(), system_catalog);
 
@@ -34,23 +43,77 @@ impl DatabaseContext {
             attach_info: None,
         });
 
-        temp_db.catalog.create_schema(&CreateSchemaInfo {
-            name: "temp".to_string(),
-            on_conflict: OnConflict::Error,
-        })?;
+        temp_db.catalog.create_schema(
+            &Transaction::system(),
+            &CreateSchemaInfo {
+                name: "temp".to_string(),
+                on_conflict: OnConflict::Error,
+            },
+        )?;
 
         databases.insert(temp_db.name.clone(), temp_db);
 
         Ok(DatabaseContext {
             databases,
+            datasources,
             profiles: Arc::new(ProfileCollector::default()),
+            transactions,
+            transaction: Arc::new(Transaction::system()),
         })
     }
 
+    pub fn transactions(&self) -> &Arc<TransactionManager> {
+        &self.transactions
+    }
+
+    /// Get the transaction the current statement is executing in.
+    pub fn transaction(&self) -> &Arc<Transaction> {
+        &self.transaction
+    }
+
+    /// Set the transaction to use for subsequent statements.
+    pub fn set_transaction(&mut self, transaction: Arc<Transaction>) {
+        self.transaction = transaction;
+    }
+
     pub fn profiles(&self) -> &Arc<ProfileCollector> {
         &self.profiles
     }
 
+    pub fn datasources(&self) -> &Arc<DataSourceRegistry> {
+        &self.datasources
+    }
+
+    /// Attach a database to this context.
+    ///
+    /// Errors if a database with the same name already exists.
+    pub fn attach_database(&mut self, database: Database) -> Result<()> {
+        if self.databases.contains_key(&database.name) {
+            return Err(DbError::new(format!(
+                "Database with name '{}' already exists",
+                database.name
+            )));
+        }
+        self.databases
+            .insert(database.name.clone(), Arc::new(database));
+        Ok(())
+    }
+
+    /// Detach a database from this context.
+    ///
+    /// The system and temp databases cannot be detached.
+    pub fn detach_database(&mut self, name: &str) -> Result<()> {
+        if name == SYSTEM_CATALOG || name == TEMP_CATALOG {
+            return Err(DbError::new(format!("Cannot detach database '{name}'")));
+        }
+        if self.databases.remove(name).is_none() {
+            return Err(DbError::new(format!(
+                "Database with name '{name}' doesn't exist"
+            )));
+        }
+        Ok(())
+    }
+
     pub fn get_database(&self, name: &str) -> Option<&Arc<Database>> {
         self.databases.get(name)
     }
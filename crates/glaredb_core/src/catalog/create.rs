@@ -1,10 +1,18 @@
 //! Various create messages/structs.
 
+use serde::{Deserialize, Serialize};
+
 use crate::arrays::field::Field;
-use crate::functions::function_set::{AggregateFunctionSet, ScalarFunctionSet, TableFunctionSet};
+use crate::functions::copy_to::CopyToFunctionSet;
+use crate::functions::function_set::{
+    AggregateFunctionSet,
+    ScalarFunctionSet,
+    TableFunctionSet,
+    WindowFunctionSet,
+};
 
 /// Behavior on create conflict.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OnConflict {
     /// Ignore and return ok.
     ///
@@ -19,20 +27,44 @@ pub enum OnConflict {
     Error,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreateTableInfo {
     pub name: String,
     pub columns: Vec<Field>,
+    /// UNIQUE and PRIMARY KEY constraints on the table.
+    #[serde(default)]
+    pub unique_constraints: Vec<UniqueConstraint>,
+    pub on_conflict: OnConflict,
+}
+
+/// A UNIQUE or PRIMARY KEY constraint on a table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UniqueConstraint {
+    /// Indices of the columns making up the key.
+    pub columns: Vec<usize>,
+    /// If this is the primary key. Primary key columns may not contain NULLs.
+    pub primary_key: bool,
+}
+
+/// Create a table whose data lives outside of the database.
+#[derive(Debug, Clone)]
+pub struct CreateExternalTableInfo {
+    pub name: String,
+    pub columns: Vec<Field>,
+    /// Scan function to use for reading the table.
+    pub function: &'static TableFunctionSet,
+    /// Location of the table, passed to the scan function.
+    pub location: String,
     pub on_conflict: OnConflict,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreateSchemaInfo {
     pub name: String,
     pub on_conflict: OnConflict,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreateViewInfo {
     pub name: String,
     pub column_aliases: Option<Vec<String>>,
@@ -58,6 +90,13 @@ pub struct CreateAggregateFunctionInfo {
     pub on_conflict: OnConflict,
 }
 
+#[derive(Debug)]
+pub struct CreateWindowFunctionInfo {
+    pub name: String,
+    pub implementation: &'static WindowFunctionSet,
+    pub on_conflict: OnConflict,
+}
+
 /// Information needed for adding a table function to the catalog.
 #[derive(Debug)]
 pub struct CreateTableFunctionInfo {
@@ -67,6 +106,15 @@ pub struct CreateTableFunctionInfo {
     pub on_conflict: OnConflict,
 }
 
+/// Information needed for adding a COPY TO function to the catalog.
+#[derive(Debug)]
+pub struct CreateCopyToFunctionInfo {
+    pub name: String,
+    pub implementation: &'static CopyToFunctionSet,
+    pub infer_copy_to: Option<FileInferCopyTo>,
+    pub on_conflict: OnConflict,
+}
+
 /// Allow inferring the table function to use for a file path.
 ///
 /// If `can_handle` returns true, then the table function will be used in the
@@ -76,3 +124,12 @@ pub struct CreateTableFunctionInfo {
 pub struct FileInferScan {
     pub can_handle: fn(path: &str) -> bool,
 }
+
+/// Allow inferring the COPY TO function to use for a file path.
+///
+/// If `can_handle` returns true, then the COPY TO function will be used for
+/// writing the file when no explicit format is provided.
+#[derive(Debug, Clone, Copy)]
+pub struct FileInferCopyTo {
+    pub can_handle: fn(path: &str) -> bool,
+}
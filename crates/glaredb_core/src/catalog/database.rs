@@ -8,11 +8,20 @@ use super::Catalog;
 use super::create::{CreateSchemaInfo, CreateTableInfo, CreateViewInfo};
 use super::drop::DropInfo;
 use super::entry::CatalogEntry;
-use super::memory::MemoryCatalog;
 use crate::arrays::scalar::ScalarValue;
 use crate::execution::operators::PlannedOperator;
+use crate::execution::operators::catalog::create_schema::PhysicalCreateSchema;
+use crate::execution::operators::catalog::create_table::PhysicalCreateTable;
+use crate::execution::operators::catalog::create_table_as::PhysicalCreateTableAs;
+use crate::execution::operators::catalog::create_view::PhysicalCreateView;
+use crate::execution::operators::catalog::delete::PhysicalDelete;
+use crate::execution::operators::catalog::drop::PhysicalDrop;
+use crate::execution::operators::catalog::insert::PhysicalInsert;
+use crate::execution::operators::catalog::merge::PhysicalMerge;
 use crate::execution::planner::OperatorIdGen;
-use crate::storage::storage_manager::StorageManager;
+use crate::logical::logical_merge::ConflictBehavior;
+use crate::storage::Storage;
+use crate::transaction::Transaction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AccessMode {
@@ -48,10 +57,8 @@ pub struct AttachInfo {
 pub struct Database {
     pub(crate) name: String,
     pub(crate) mode: AccessMode,
-    // TODO: Allow other catalog types.
-    pub(crate) catalog: Arc<MemoryCatalog>,
-    // TODO: Allow other storage managers.
-    pub(crate) storage: Arc<StorageManager>,
+    pub(crate) catalog: Arc<dyn Catalog>,
+    pub(crate) storage: Arc<dyn Storage>,
     pub(crate) attach_info: Option<AttachInfo>,
 }
 
@@ -59,58 +66,126 @@ impl Database {
     pub fn plan_create_view(
         &self,
         id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
         schema: &str,
         create: CreateViewInfo,
     ) -> Result<PlannedOperator> {
         self.check_can_write()?;
-        self.catalog.plan_create_view(id_gen, schema, create)
+        let operator = PhysicalCreateView {
+            transaction: transaction.clone(),
+            schema: self.catalog.require_get_schema(transaction, schema)?,
+            info: create,
+        };
+        Ok(PlannedOperator::new_pull(id_gen.next_id(), operator))
     }
 
     pub fn plan_create_table(
         &self,
         id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
         schema: &str,
         create: CreateTableInfo,
     ) -> Result<PlannedOperator> {
         self.check_can_write()?;
-        self.catalog
-            .plan_create_table(&self.storage, id_gen, schema, create)
+        let operator = PhysicalCreateTable {
+            transaction: transaction.clone(),
+            storage: self.storage.clone(),
+            schema: self.catalog.require_get_schema(transaction, schema)?,
+            info: create,
+        };
+        Ok(PlannedOperator::new_pull(id_gen.next_id(), operator))
     }
 
     pub fn plan_create_table_as(
         &self,
         id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
         schema: &str,
         create: CreateTableInfo,
     ) -> Result<PlannedOperator> {
         self.check_can_write()?;
-        self.catalog
-            .plan_create_table_as(&self.storage, id_gen, schema, create)
+        let schema = self.catalog.require_get_schema(transaction, schema)?;
+        let operator =
+            PhysicalCreateTableAs::new(transaction.clone(), self.storage.clone(), schema, create);
+        Ok(PlannedOperator::new_execute(id_gen.next_id(), operator))
     }
 
     pub fn plan_insert(
         &self,
+        id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
+        table: Arc<CatalogEntry>,
+    ) -> Result<PlannedOperator> {
+        self.check_can_write()?;
+        let operator = PhysicalInsert {
+            transaction: transaction.clone(),
+            storage: self.storage.clone(),
+            entry: table,
+        };
+        Ok(PlannedOperator::new_execute(id_gen.next_id(), operator))
+    }
 
+    pub fn plan_delete(
+        &self,
         id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
         table: Arc<CatalogEntry>,
     ) -> Result<PlannedOperator> {
         self.check_can_write()?;
-        self.catalog.plan_insert(&self.storage, id_gen, table)
+        let operator = PhysicalDelete {
+            transaction: transaction.clone(),
+            storage: self.storage.clone(),
+            entry: table,
+        };
+        Ok(PlannedOperator::new_execute(id_gen.next_id(), operator))
     }
 
-    pub fn plan_create_schema(
+    pub fn plan_merge(
         &self,
+        id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
+        table: Arc<CatalogEntry>,
+        conflict: ConflictBehavior,
+    ) -> Result<PlannedOperator> {
+        self.check_can_write()?;
+        let operator = PhysicalMerge {
+            transaction: transaction.clone(),
+            storage: self.storage.clone(),
+            entry: table,
+            conflict,
+        };
+        Ok(PlannedOperator::new_execute(id_gen.next_id(), operator))
+    }
 
+    pub fn plan_create_schema(
+        &self,
         id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
         create: CreateSchemaInfo,
     ) -> Result<PlannedOperator> {
         self.check_can_write()?;
-        self.catalog.plan_create_schema(id_gen, create)
+        let operator = PhysicalCreateSchema {
+            transaction: transaction.clone(),
+            catalog: self.catalog.clone(),
+            info: create,
+        };
+        Ok(PlannedOperator::new_pull(id_gen.next_id(), operator))
     }
 
-    pub fn plan_drop(&self, id_gen: &mut OperatorIdGen, drop: DropInfo) -> Result<PlannedOperator> {
+    pub fn plan_drop(
+        &self,
+        id_gen: &mut OperatorIdGen,
+        transaction: &Arc<Transaction>,
+        drop: DropInfo,
+    ) -> Result<PlannedOperator> {
         self.check_can_write()?;
-        self.catalog.plan_drop(&self.storage, id_gen, drop)
+        let operator = PhysicalDrop {
+            transaction: transaction.clone(),
+            storage: self.storage.clone(),
+            catalog: self.catalog.clone(),
+            info: drop,
+        };
+        Ok(PlannedOperator::new_pull(id_gen.next_id(), operator))
     }
 
     fn check_can_write(&self) -> Result<()> {
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use glaredb_error::{DbError, Result};
+use parking_lot::RwLock;
+
+use super::Catalog;
+use super::context::DatabaseContext;
+use super::database::AccessMode;
+use super::memory::MemoryCatalog;
+use crate::arrays::scalar::ScalarValue;
+use crate::runtime::filesystem::dispatch::FileSystemDispatch;
+use crate::storage::Storage;
+use crate::storage::storage_manager::StorageManager;
+
+pub type DataSourceFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Sync + Send + 'a>>;
+
+/// Context providing dependencies for connecting to a datasource.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectContext<'a> {
+    pub database_context: &'a DatabaseContext,
+    pub dispatch: &'a FileSystemDispatch,
+}
+
+/// Name of the built-in in-memory datasource.
+pub const MEMORY_DATASOURCE_NAME: &str = "memory";
+
+/// Provides the catalog and storage for an attached database.
+///
+/// `ATTACH 'path' AS name (TYPE datasource, ...)` and `ATTACH datasource
+/// DATABASE AS name (...)` look up the datasource by its registered name, and
+/// connect to it using the provided options.
+pub trait DataSource: Debug + Sync + Send {
+    /// Connect to the datasource, returning the catalog and storage to use for
+    /// the attached database.
+    ///
+    /// Options are the normalized options provided to ATTACH. The path, if
+    /// provided, will be under the 'path' key.
+    fn connect<'a>(
+        &'a self,
+        context: ConnectContext<'a>,
+        options: &'a HashMap<String, ScalarValue>,
+    ) -> DataSourceFuture<'a, Result<AttachedDatabase>>;
+}
+
+/// Catalog and storage returned from connecting to a datasource.
+#[derive(Debug)]
+pub struct AttachedDatabase {
+    pub mode: AccessMode,
+    pub catalog: Arc<dyn Catalog>,
+    pub storage: Arc<dyn Storage>,
+}
+
+/// Datasource for attaching an empty, writable in-memory database.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemoryDataSource;
+
+impl DataSource for MemoryDataSource {
+    fn connect<'a>(
+        &'a self,
+        _context: ConnectContext<'a>,
+        options: &'a HashMap<String, ScalarValue>,
+    ) -> DataSourceFuture<'a, Result<AttachedDatabase>> {
+        Box::pin(async move {
+            if !options.is_empty() {
+                return Err(DbError::new("Memory datasource takes no options"));
+            }
+
+            Ok(AttachedDatabase {
+                mode: AccessMode::ReadWrite,
+                catalog: Arc::new(MemoryCatalog::empty()),
+                storage: Arc::new(StorageManager::empty()),
+            })
+        })
+    }
+}
+
+/// Registered datasources keyed by name.
+///
+/// Shared between the engine and all sessions created from it.
+#[derive(Debug, Default)]
+pub struct DataSourceRegistry {
+    datasources: RwLock<HashMap<String, Arc<dyn DataSource>>>,
+}
+
+impl DataSourceRegistry {
+    /// Register a datasource with the given name.
+    ///
+    /// Names are case insensitive.
+    pub fn register(&self, name: &str, datasource: Arc<dyn DataSource>) -> Result<()> {
+        let name = name.to_lowercase();
+        let mut datasources = self.datasources.write();
+        if datasources.contains_key(&name) {
+            return Err(DbError::new(format!(
+                "Datasource '{name}' already registered"
+            )));
+        }
+        datasources.insert(name, datasource);
+        Ok(())
+    }
+
+    /// Get a datasource by name, erroring if it doesn't exist.
+    pub fn require_get(&self, name: &str) -> Result<Arc<dyn DataSource>> {
+        self.datasources
+            .read()
+            .get(&name.to_lowercase())
+            .cloned()
+            .ok_or_else(|| DbError::new(format!("Unknown datasource '{name}'")))
+    }
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// The object we're dropping.
 ///
 /// Most objects are namespaced with a schema, and so will also have their names
@@ -5,7 +7,7 @@
 ///
 /// If we're dropping a scheme, that schema's name is already included in the
 /// drop info.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DropObject {
     Index(String),
     Function(String),
@@ -14,7 +16,7 @@ pub enum DropObject {
     Schema,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DropInfo {
     pub schema: String,
     pub object: DropObject,
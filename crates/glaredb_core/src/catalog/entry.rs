@@ -3,9 +3,15 @@ use std::sync::Arc;
 
 use glaredb_error::{DbError, Result};
 
-use super::create::FileInferScan;
+use super::create::{FileInferCopyTo, FileInferScan, UniqueConstraint};
 use crate::arrays::field::Field;
-use crate::functions::function_set::{AggregateFunctionSet, ScalarFunctionSet, TableFunctionSet};
+use crate::functions::copy_to::CopyToFunctionSet;
+use crate::functions::function_set::{
+    AggregateFunctionSet,
+    ScalarFunctionSet,
+    TableFunctionSet,
+    WindowFunctionSet,
+};
 use crate::storage::storage_manager::StorageTableId;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +21,7 @@ pub enum CatalogEntryType {
     View,
     ScalarFunction,
     AggregateFunction,
+    WindowFunction,
     TableFunction,
     CopyToFunction,
     CastFunction,
@@ -28,6 +35,7 @@ impl fmt::Display for CatalogEntryType {
             Self::View => write!(f, "view"),
             Self::ScalarFunction => write!(f, "scalar function"),
             Self::AggregateFunction => write!(f, "aggregate function"),
+            Self::WindowFunction => write!(f, "window function"),
             Self::TableFunction => write!(f, "table function"),
             Self::CopyToFunction => write!(f, "copy to function"),
             Self::CastFunction => write!(f, "cast function"),
@@ -50,7 +58,9 @@ pub enum CatalogEntryInner {
     View(ViewEntry),
     ScalarFunction(ScalarFunctionEntry),
     AggregateFunction(AggregateFunctionEntry),
+    WindowFunction(WindowFunctionEntry),
     TableFunction(TableFunctionEntry),
+    CopyToFunction(CopyToFunctionEntry),
 }
 
 #[derive(Debug)]
@@ -63,6 +73,11 @@ pub struct AggregateFunctionEntry {
     pub function: &'static AggregateFunctionSet,
 }
 
+#[derive(Debug)]
+pub struct WindowFunctionEntry {
+    pub function: &'static WindowFunctionSet,
+}
+
 #[derive(Debug)]
 pub struct TableFunctionEntry {
     /// The table function.
@@ -70,19 +85,52 @@ pub struct TableFunctionEntry {
     pub infer_scan: Option<FileInferScan>,
 }
 
+#[derive(Debug)]
+pub struct CopyToFunctionEntry {
+    /// The COPY TO function.
+    pub function: &'static CopyToFunctionSet,
+    pub infer_copy_to: Option<FileInferCopyTo>,
+}
+
 #[derive(Debug, Clone)]
 pub struct TableEntry {
     /// Columns in this table.
     pub columns: Vec<Field>,
     /// Scan function to use for reading the table.
     ///
-    /// This should currently expect to accept 'catalog', 'schema', and 'table'
-    /// as string arguments.
+    /// For managed tables, this should expect to accept 'catalog', 'schema',
+    /// and 'table' as string arguments. For external tables, this should
+    /// accept the location of the table.
     pub function: &'static TableFunctionSet,
-    /// Identifier for getting the data table from the storage manager.
+    /// Where the data for this table lives.
+    pub storage: TableStorage,
+    /// UNIQUE and PRIMARY KEY constraints checked when modifying the table.
+    pub unique_constraints: Vec<UniqueConstraint>,
+}
+
+impl TableEntry {
+    /// Get the identifier for the table in the storage manager, erroring if
+    /// the table is external.
+    pub fn storage_id(&self) -> Result<StorageTableId> {
+        match &self.storage {
+            TableStorage::Managed(id) => Ok(*id),
+            TableStorage::External { location } => Err(DbError::new(format!(
+                "Table at '{location}' is external and not backed by the storage manager"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableStorage {
+    /// Table data is held by the database's storage manager.
     // TODO: This should be opaque. Different storage managers should be able to
     // different identifiers here.
-    pub storage_id: StorageTableId,
+    Managed(StorageTableId),
+    /// Table data lives outside of the database (e.g. a table in an attached
+    /// lakehouse catalog). The location is passed to the table's scan
+    /// function.
+    External { location: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -102,7 +150,9 @@ impl CatalogEntry {
             CatalogEntryInner::View(_) => CatalogEntryType::View,
             CatalogEntryInner::ScalarFunction(_) => CatalogEntryType::ScalarFunction,
             CatalogEntryInner::AggregateFunction(_) => CatalogEntryType::AggregateFunction,
+            CatalogEntryInner::WindowFunction(_) => CatalogEntryType::WindowFunction,
             CatalogEntryInner::TableFunction(_) => CatalogEntryType::TableFunction,
+            CatalogEntryInner::CopyToFunction(_) => CatalogEntryType::CopyToFunction,
         }
     }
 
@@ -141,10 +191,24 @@ impl CatalogEntry {
         }
     }
 
+    pub fn try_as_window_function_entry(&self) -> Result<&WindowFunctionEntry> {
+        match &self.entry {
+            CatalogEntryInner::WindowFunction(ent) => Ok(ent),
+            _ => Err(DbError::new("Entry not a window function")),
+        }
+    }
+
     pub fn try_as_table_function_entry(&self) -> Result<&TableFunctionEntry> {
         match &self.entry {
             CatalogEntryInner::TableFunction(ent) => Ok(ent),
             _ => Err(DbError::new("Entry not a table function")),
         }
     }
+
+    pub fn try_as_copy_to_function_entry(&self) -> Result<&CopyToFunctionEntry> {
+        match &self.entry {
+            CatalogEntryInner::CopyToFunction(ent) => Ok(ent),
+            _ => Err(DbError::new("Entry not a COPY TO function")),
+        }
+    }
 }
@@ -1,17 +1,22 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{self, AtomicU64};
 
-use futures::{Stream, stream};
+use futures::stream;
 use glaredb_error::{DbError, Result};
-use scc::HashIndex;
+use parking_lot::Mutex;
 use scc::ebr::Guard;
 
 use super::create::{
     CreateAggregateFunctionInfo,
+    CreateCopyToFunctionInfo,
+    CreateExternalTableInfo,
     CreateScalarFunctionInfo,
     CreateSchemaInfo,
     CreateTableFunctionInfo,
     CreateTableInfo,
     CreateViewInfo,
+    CreateWindowFunctionInfo,
     OnConflict,
 };
 use super::drop::{DropInfo, DropObject};
@@ -20,206 +25,94 @@ use super::entry::{
     CatalogEntry,
     CatalogEntryInner,
     CatalogEntryType,
+    CopyToFunctionEntry,
     ScalarFunctionEntry,
     TableEntry,
     TableFunctionEntry,
+    TableStorage,
     ViewEntry,
+    WindowFunctionEntry,
 };
-use super::{Catalog, Schema};
+use super::{Catalog, CatalogStream, Schema};
 use crate::catalog::entry::SchemaEntry;
-use crate::execution::operators::PlannedOperator;
-use crate::execution::operators::catalog::create_schema::PhysicalCreateSchema;
-use crate::execution::operators::catalog::create_table::PhysicalCreateTable;
-use crate::execution::operators::catalog::create_table_as::PhysicalCreateTableAs;
-use crate::execution::operators::catalog::create_view::PhysicalCreateView;
-use crate::execution::operators::catalog::drop::PhysicalDrop;
-use crate::execution::operators::catalog::insert::PhysicalInsert;
-use crate::execution::planner::OperatorIdGen;
 use crate::functions::table::builtin::memory_scan::FUNCTION_SET_MEMORY_SCAN;
-use crate::storage::storage_manager::{StorageManager, StorageTableId};
+use crate::storage::storage_manager::StorageTableId;
+use crate::transaction::{ABORTED_VERSION, NOT_DELETED, Snapshot, Transaction, TransactionChange};
 
 #[derive(Debug)]
 pub struct MemoryCatalog {
-    schemas: scc::HashIndex<String, Arc<MemorySchema>>,
+    schemas: VersionedMap<Arc<MemorySchema>>,
 }
 
 impl MemoryCatalog {
     pub fn empty() -> Self {
         MemoryCatalog {
-            schemas: HashIndex::new(),
+            schemas: VersionedMap::default(),
         }
     }
 }
 
 impl Catalog for MemoryCatalog {
-    type Schema = MemorySchema;
-
-    fn create_schema(&self, create: &CreateSchemaInfo) -> Result<Arc<Self::Schema>> {
+    fn create_schema(
+        &self,
+        tx: &Transaction,
+        create: &CreateSchemaInfo,
+    ) -> Result<Arc<dyn Schema>> {
         let schema = Arc::new(MemorySchema {
             schema: Arc::new(CatalogEntry {
                 name: create.name.clone(),
                 entry: CatalogEntryInner::Schema(SchemaEntry {}),
                 child: None,
             }),
-            tables: CatalogMap::default(),
+            tables: VersionedMap::default(),
             table_functions: CatalogMap::default(),
             functions: CatalogMap::default(),
+            copy_to_functions: CatalogMap::default(),
         });
 
-        use scc::hash_index::Entry;
+        let schema = self
+            .schemas
+            .create(tx, &create.name, schema, create.on_conflict, || {
+                DbError::new(format!("Duplicate schema name: '{}'", create.name))
+            })?;
 
-        match (self.schemas.entry(create.name.clone()), create.on_conflict) {
-            (Entry::Vacant(ent), _) => {
-                ent.insert_entry(schema.clone());
-                Ok(schema)
-            }
-            (Entry::Occupied(ent), OnConflict::Ignore) => {
-                // Return existing entry.
-                Ok(ent.get().clone())
-            }
-            (Entry::Occupied(ent), OnConflict::Replace) => {
-                // TODO: Drop then replace.
-                ent.update(schema.clone());
-                Ok(schema)
-            }
-            (Entry::Occupied(_), OnConflict::Error) => Err(DbError::new(format!(
-                "Duplicate schema name: '{}'",
-                create.name,
-            ))),
-        }
+        Ok(schema)
     }
 
-    fn get_schema(&self, name: &str) -> Result<Option<Arc<Self::Schema>>> {
-        let guard = Guard::new();
-        Ok(self.schemas.peek(name, &guard).cloned())
+    fn get_schema(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<dyn Schema>>> {
+        Ok(self.schemas.get(tx, name).map(|schema| schema as _))
     }
 
-    fn drop_entry(&self, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
+    fn drop_entry(&self, tx: &Transaction, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
         if drop.object == DropObject::Schema {
             if drop.cascade {
                 return Err(DbError::new("CASCADE not yet supported"));
             }
 
-            // TODO: Schemas should be implemented as a CatalogMap.
-
-            // TODO: The separate clone here seems to be needed in order to
-            // avoid deadlock...
-            //
-            // Check if this happens on the latest version of scc.
-            let schema = self.schemas.get(&drop.schema).map(|ent| ent.clone());
-
-            if !self.schemas.remove(&drop.schema) && !drop.if_exists {
-                return Err(DbError::new(format!("Missing schema: {}", drop.schema)));
-            }
-
-            return Ok(schema.map(|s| s.schema.clone()));
+            return match self.schemas.drop(tx, &drop.schema)? {
+                Some(schema) => Ok(Some(schema.schema.clone())),
+                None if drop.if_exists => Ok(None),
+                None => Err(DbError::new(format!("Missing schema: {}", drop.schema))),
+            };
         }
 
         let schema = self
             .schemas
-            .get(&drop.schema)
+            .get(tx, &drop.schema)
             .ok_or_else(|| DbError::new(format!("Missing schema: {}", drop.schema)))?;
 
-        schema.drop_entry(drop)
+        schema.drop_entry(tx, drop)
     }
 
-    fn plan_create_view(
-        self: &Arc<Self>,
-        id_gen: &mut OperatorIdGen,
-        schema: &str,
-        create: CreateViewInfo,
-    ) -> Result<PlannedOperator> {
-        let schema = self.require_get_schema(schema)?;
-        let operator = PhysicalCreateView {
-            schema,
-            info: create,
-        };
-
-        Ok(PlannedOperator::new_pull(id_gen.next_id(), operator))
-    }
-
-    fn plan_create_table(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        schema: &str,
-        create: CreateTableInfo,
-    ) -> Result<PlannedOperator> {
-        let schema = self.require_get_schema(schema)?;
-        let operator = PhysicalCreateTable {
-            storage: storage.clone(),
-            schema,
-            info: create,
-        };
-
-        Ok(PlannedOperator::new_pull(id_gen.next_id(), operator))
-    }
-
-    fn plan_insert(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        table: Arc<CatalogEntry>,
-    ) -> Result<PlannedOperator> {
-        Ok(PlannedOperator::new_execute(
-            id_gen.next_id(),
-            PhysicalInsert {
-                storage: storage.clone(),
-                entry: table,
-            },
-        ))
-    }
-
-    fn plan_create_table_as(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        schema: &str,
-        create: CreateTableInfo,
-    ) -> Result<PlannedOperator> {
-        let schema = self.require_get_schema(schema)?;
-        let operator = PhysicalCreateTableAs::new(storage.clone(), schema, create);
-
-        Ok(PlannedOperator::new_execute(id_gen.next_id(), operator))
-    }
-
-    fn plan_create_schema(
-        self: &Arc<Self>,
-        id_gen: &mut OperatorIdGen,
-        create: CreateSchemaInfo,
-    ) -> Result<PlannedOperator> {
-        Ok(PlannedOperator::new_pull(
-            id_gen.next_id(),
-            PhysicalCreateSchema {
-                catalog: self.clone(),
-                info: create,
-            },
-        ))
-    }
-
-    fn plan_drop(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        drop: DropInfo,
-    ) -> Result<PlannedOperator> {
-        Ok(PlannedOperator::new_pull(
-            id_gen.next_id(),
-            PhysicalDrop {
-                storage: storage.clone(),
-                catalog: self.clone(),
-                info: drop,
-            },
-        ))
-    }
-
-    fn list_schemas(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<Self::Schema>>>> + Sync + Send + 'static {
+    fn list_schemas(&self, tx: &Transaction) -> CatalogStream<Arc<dyn Schema>> {
         // TODO: Don't care yet.
-        let g = Guard::new();
-        let schemas: Vec<_> = self.schemas.iter(&g).map(|(_, v)| v.clone()).collect();
-        stream::once(async move { Ok(schemas) })
+        let schemas: Vec<_> = self
+            .schemas
+            .visible(tx)
+            .into_iter()
+            .map(|schema| schema as Arc<dyn Schema>)
+            .collect();
+        Box::pin(stream::once(async move { Ok(schemas) }))
     }
 }
 
@@ -228,13 +121,13 @@ pub struct MemorySchema {
     /// Catalog entry representing this schema.
     schema: Arc<CatalogEntry>,
     /// All tables and views in the schema.
-    tables: CatalogMap,
+    tables: VersionedMap<Arc<CatalogEntry>>,
     /// All table functions in the schema.
     table_functions: CatalogMap,
     /// All scalar and aggregate functions in the schema.
     functions: CatalogMap,
-    // /// All functions implementing COPY TO for a fomat in the schema.
-    // copy_to_functions: CatalogMap,
+    /// All functions implementing COPY TO for a format in the schema.
+    copy_to_functions: CatalogMap,
 }
 
 impl Schema for MemorySchema {
@@ -244,6 +137,7 @@ impl Schema for MemorySchema {
 
     fn create_table(
         &self,
+        tx: &Transaction,
         create: &CreateTableInfo,
         storage_id: StorageTableId,
     ) -> Result<Arc<CatalogEntry>> {
@@ -252,15 +146,37 @@ impl Schema for MemorySchema {
             entry: CatalogEntryInner::Table(TableEntry {
                 columns: create.columns.clone(),
                 function: &FUNCTION_SET_MEMORY_SCAN,
-                storage_id,
+                storage: TableStorage::Managed(storage_id),
+                unique_constraints: create.unique_constraints.clone(),
+            }),
+            child: None,
+        };
+
+        self.create_table_or_view(tx, table, create.on_conflict)
+    }
+
+    fn create_external_table(
+        &self,
+        tx: &Transaction,
+        create: &CreateExternalTableInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        let table = CatalogEntry {
+            name: create.name.clone(),
+            entry: CatalogEntryInner::Table(TableEntry {
+                columns: create.columns.clone(),
+                function: create.function,
+                storage: TableStorage::External {
+                    location: create.location.clone(),
+                },
+                unique_constraints: Vec::new(),
             }),
             child: None,
         };
 
-        Self::create_entry(&self.tables, table, create.on_conflict)
+        self.create_table_or_view(tx, table, create.on_conflict)
     }
 
-    fn create_view(&self, create: &CreateViewInfo) -> Result<Arc<CatalogEntry>> {
+    fn create_view(&self, tx: &Transaction, create: &CreateViewInfo) -> Result<Arc<CatalogEntry>> {
         let view = CatalogEntry {
             name: create.name.clone(),
             entry: CatalogEntryInner::View(ViewEntry {
@@ -270,7 +186,7 @@ impl Schema for MemorySchema {
             child: None,
         };
 
-        Self::create_entry(&self.tables, view, create.on_conflict)
+        self.create_table_or_view(tx, view, create.on_conflict)
     }
 
     fn create_scalar_function(
@@ -303,6 +219,21 @@ impl Schema for MemorySchema {
         Self::create_entry(&self.functions, ent, create.on_conflict)
     }
 
+    fn create_window_function(
+        &self,
+        create: &CreateWindowFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        let ent = CatalogEntry {
+            name: create.name.clone(),
+            entry: CatalogEntryInner::WindowFunction(WindowFunctionEntry {
+                function: create.implementation,
+            }),
+            child: None,
+        };
+
+        Self::create_entry(&self.functions, ent, create.on_conflict)
+    }
+
     fn create_table_function(&self, create: &CreateTableFunctionInfo) -> Result<Arc<CatalogEntry>> {
         let ent = CatalogEntry {
             name: create.name.clone(),
@@ -316,8 +247,24 @@ impl Schema for MemorySchema {
         Self::create_entry(&self.table_functions, ent, create.on_conflict)
     }
 
-    fn get_table_or_view(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
-        self.tables.get_entry(name)
+    fn create_copy_to_function(
+        &self,
+        create: &CreateCopyToFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        let ent = CatalogEntry {
+            name: create.name.clone(),
+            entry: CatalogEntryInner::CopyToFunction(CopyToFunctionEntry {
+                function: create.implementation,
+                infer_copy_to: create.infer_copy_to,
+            }),
+            child: None,
+        };
+
+        Self::create_entry(&self.copy_to_functions, ent, create.on_conflict)
+    }
+
+    fn get_table_or_view(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        Ok(self.tables.get(tx, name))
     }
 
     fn get_table_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
@@ -337,6 +284,23 @@ impl Schema for MemorySchema {
         Ok(None)
     }
 
+    fn get_copy_to_function(&self, format: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.copy_to_functions.get_entry(format)
+    }
+
+    fn get_inferred_copy_to_function(&self, path: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        let guard = Guard::new();
+        for (_, ent) in self.copy_to_functions.entries.iter(&guard) {
+            let fn_ent = ent.try_as_copy_to_function_entry()?;
+            if let Some(infer) = fn_ent.infer_copy_to {
+                if (infer.can_handle)(path) {
+                    return Ok(Some(ent.clone()));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     fn get_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
         self.functions.get_entry(name)
     }
@@ -362,8 +326,19 @@ impl Schema for MemorySchema {
         Ok(ent)
     }
 
+    fn get_window_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        let ent = self.functions.get_entry(name)?;
+        let ent = ent.and_then(|ent| match &ent.entry {
+            CatalogEntryInner::WindowFunction(_) => Some(ent),
+            _ => None,
+        });
+
+        Ok(ent)
+    }
+
     fn find_similar_entry(
         &self,
+        tx: &Transaction,
         entry_types: &[CatalogEntryType],
         name: &str,
     ) -> Result<Option<Arc<CatalogEntry>>> {
@@ -371,10 +346,11 @@ impl Schema for MemorySchema {
 
         for typ in entry_types {
             match typ {
-                CatalogEntryType::Table => self.tables.for_each_entry(&mut |_, ent| {
-                    SimilarEntry::maybe_update(&mut similar, ent, name);
-                    Ok(())
-                })?,
+                CatalogEntryType::Table => {
+                    for ent in self.tables.visible(tx) {
+                        SimilarEntry::maybe_update(&mut similar, &ent, name);
+                    }
+                }
                 CatalogEntryType::ScalarFunction => {
                     self.functions.for_each_entry(&mut |_, ent| {
                         SimilarEntry::maybe_update(&mut similar, ent, name);
@@ -387,12 +363,24 @@ impl Schema for MemorySchema {
                         Ok(())
                     })?
                 }
+                CatalogEntryType::WindowFunction => {
+                    self.functions.for_each_entry(&mut |_, ent| {
+                        SimilarEntry::maybe_update(&mut similar, ent, name);
+                        Ok(())
+                    })?
+                }
                 CatalogEntryType::TableFunction => {
                     self.table_functions.for_each_entry(&mut |_, ent| {
                         SimilarEntry::maybe_update(&mut similar, ent, name);
                         Ok(())
                     })?
                 }
+                CatalogEntryType::CopyToFunction => {
+                    self.copy_to_functions.for_each_entry(&mut |_, ent| {
+                        SimilarEntry::maybe_update(&mut similar, ent, name);
+                        Ok(())
+                    })?
+                }
                 _ => (),
             }
         }
@@ -400,9 +388,7 @@ impl Schema for MemorySchema {
         Ok(similar.map(|similar| similar.entry))
     }
 
-    fn list_entries(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<CatalogEntry>>>> + Sync + Send + 'static {
+    fn list_entries(&self, tx: &Transaction) -> CatalogStream<Arc<CatalogEntry>> {
         // TODO: Dont' really care about efficiency right now for the memory
         // catalog, these are all cheap and it's only used for the various
         // `list_...` functions.
@@ -412,56 +398,67 @@ impl Schema for MemorySchema {
 
         let g = Guard::new();
 
-        let tables: Vec<_> = self
-            .tables
+        let tables = self.tables.visible(tx);
+
+        let functions: Vec<_> = self
+            .functions
             .entries
             .iter(&g)
             .map(|(_, v)| v)
             .cloned()
             .collect();
 
-        let functions: Vec<_> = self
-            .functions
+        let table_functions: Vec<_> = self
+            .table_functions
             .entries
             .iter(&g)
             .map(|(_, v)| v)
             .cloned()
             .collect();
 
-        let table_functions: Vec<_> = self
-            .table_functions
+        let copy_to_functions: Vec<_> = self
+            .copy_to_functions
             .entries
             .iter(&g)
             .map(|(_, v)| v)
             .cloned()
             .collect();
 
-        stream::iter([Ok(tables), Ok(functions), Ok(table_functions)])
+        Box::pin(stream::iter([
+            Ok(tables),
+            Ok(functions),
+            Ok(table_functions),
+            Ok(copy_to_functions),
+        ]))
     }
 
-    fn list_tables(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<CatalogEntry>>>> + Sync + Send + 'static {
+    fn list_tables(&self, tx: &Transaction) -> CatalogStream<Arc<CatalogEntry>> {
         // TODO: Also don't care here.
-        let g = Guard::new();
         let tables: Vec<_> = self
             .tables
-            .entries
-            .iter(&g)
-            .filter_map(|(_, v)| {
-                if v.entry_type() == CatalogEntryType::Table {
-                    Some(v.clone())
-                } else {
-                    None
-                }
-            })
+            .visible(tx)
+            .into_iter()
+            .filter(|ent| ent.entry_type() == CatalogEntryType::Table)
             .collect();
-        stream::once(async move { Ok(tables) })
+        Box::pin(stream::once(async move { Ok(tables) }))
     }
 }
 
 impl MemorySchema {
-    /// Internal helper for inserting entries into the schema while obeying
+    fn create_table_or_view(
+        &self,
+        tx: &Transaction,
+        entry: CatalogEntry,
+        on_conflict: OnConflict,
+    ) -> Result<Arc<CatalogEntry>> {
+        let name = entry.name.clone();
+        self.tables
+            .create(tx, &name, Arc::new(entry), on_conflict, || {
+                DbError::new(format!("Duplicate entry: {name}"))
+            })
+    }
+
+    /// Internal helper for inserting functions into the schema while obeying
     /// conflict rules.
     fn create_entry(
         map: &CatalogMap,
@@ -494,39 +491,22 @@ impl MemorySchema {
         Ok(ent)
     }
 
-    fn drop_entry(&self, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
+    fn drop_entry(&self, tx: &Transaction, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
         match &drop.object {
             DropObject::Index(_) => Err(DbError::new("Dropping indexes not yet supported")),
             DropObject::Function(_) => Err(DbError::new("Dropping functions not yet supported")),
-            DropObject::Table(name) => {
-                Self::drop_entry_inner(&self.tables, name, drop.if_exists, drop.cascade)
-            }
-            DropObject::View(name) => {
-                Self::drop_entry_inner(&self.tables, name, drop.if_exists, drop.cascade)
-            }
-            DropObject::Schema => Err(DbError::new("Cannot drop schema from inside schema")),
-        }
-    }
-
-    fn drop_entry_inner(
-        map: &CatalogMap,
-        name: &str,
-        if_exists: bool,
-        cascade: bool,
-    ) -> Result<Option<Arc<CatalogEntry>>> {
-        if cascade {
-            return Err(DbError::new("CASCADE not yet supported"));
-        }
-
-        let ent = map.get_entry(name)?;
+            DropObject::Table(name) | DropObject::View(name) => {
+                if drop.cascade {
+                    return Err(DbError::new("CASCADE not yet supported"));
+                }
 
-        match (ent, if_exists) {
-            (Some(ent), _) => {
-                map.drop_entry(ent.as_ref())?;
-                Ok(Some(ent))
+                match self.tables.drop(tx, name)? {
+                    Some(ent) => Ok(Some(ent)),
+                    None if drop.if_exists => Ok(None),
+                    None => Err(DbError::new("Missing entry, cannot drop")),
+                }
             }
-            (None, true) => Ok(None),
-            (None, false) => Err(DbError::new("Missing entry, cannot drop")),
+            DropObject::Schema => Err(DbError::new("Cannot drop schema from inside schema")),
         }
     }
 }
@@ -566,6 +546,8 @@ impl SimilarEntry {
 }
 
 /// Maps a name to some catalog entry.
+///
+/// Used for functions, which aren't versioned.
 #[derive(Debug, Default)]
 struct CatalogMap {
     entries: scc::HashIndex<String, Arc<CatalogEntry>>,
@@ -584,13 +566,6 @@ impl CatalogMap {
         }
     }
 
-    fn drop_entry(&self, entry: &CatalogEntry) -> Result<()> {
-        if !self.entries.remove(&entry.name) {
-            return Err(DbError::new(format!("Missing entry '{}'", entry.name)));
-        }
-        Ok(())
-    }
-
     fn get_entry(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
         let guard = Guard::new();
         let ent = self.entries.peek(name, &guard).cloned();
@@ -609,18 +584,229 @@ impl CatalogMap {
     }
 }
 
+/// Created and dropped versions of an entry in a `VersionedMap`.
+#[derive(Debug)]
+struct EntryVersions {
+    created: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl EntryVersions {
+    fn is_visible(&self, snapshot: &Snapshot) -> bool {
+        snapshot.is_visible(self.created.load(atomic::Ordering::Acquire))
+            && !snapshot.is_visible(self.dropped.load(atomic::Ordering::Acquire))
+    }
+
+    /// Returns if the entry was created or dropped by a transaction the
+    /// snapshot can't see.
+    fn is_concurrently_modified(&self, snapshot: &Snapshot) -> bool {
+        let created = self.created.load(atomic::Ordering::Acquire);
+        let dropped = self.dropped.load(atomic::Ordering::Acquire);
+        (created != ABORTED_VERSION && !snapshot.is_visible(created))
+            || (dropped != NOT_DELETED && !snapshot.is_visible(dropped))
+    }
+}
+
+/// Entry created by a transaction.
+#[derive(Debug)]
+struct CreatedEntry(Arc<EntryVersions>);
+
+impl TransactionChange for CreatedEntry {
+    fn commit(&self, commit_ts: u64) {
+        self.0.created.store(commit_ts, atomic::Ordering::Release);
+    }
+
+    fn rollback(&self) {
+        self.0
+            .created
+            .store(ABORTED_VERSION, atomic::Ordering::Release);
+    }
+}
+
+/// Entry dropped by a transaction.
+#[derive(Debug)]
+struct DroppedEntry(Arc<EntryVersions>);
+
+impl TransactionChange for DroppedEntry {
+    fn commit(&self, commit_ts: u64) {
+        self.0.dropped.store(commit_ts, atomic::Ordering::Release);
+    }
+
+    fn rollback(&self) {
+        self.0.dropped.store(NOT_DELETED, atomic::Ordering::Release);
+    }
+}
+
+#[derive(Debug)]
+struct VersionedEntry<T> {
+    value: T,
+    versions: Arc<EntryVersions>,
+}
+
+/// Maps a name to versions of an entry, with each transaction seeing the
+/// version visible to its snapshot.
+///
+/// Creating or dropping an entry that's been modified by a concurrent
+/// transaction errors.
+// TODO: Versions dropped before the oldest active transaction started are
+// never removed.
+#[derive(Debug)]
+struct VersionedMap<T> {
+    /// Versions of each entry, oldest first.
+    entries: Mutex<HashMap<String, Vec<VersionedEntry<T>>>>,
+}
+
+impl<T> Default for VersionedMap<T> {
+    fn default() -> Self {
+        VersionedMap {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T: Clone> VersionedMap<T> {
+    /// Get the version of the entry visible to the transaction.
+    fn get(&self, tx: &Transaction, name: &str) -> Option<T> {
+        let snapshot = tx.snapshot();
+        let entries = self.entries.lock();
+        entries.get(name).and_then(|versions| {
+            versions
+                .iter()
+                .rev()
+                .find(|ent| ent.versions.is_visible(&snapshot))
+                .map(|ent| ent.value.clone())
+        })
+    }
+
+    /// Create a new version of an entry.
+    ///
+    /// `duplicate_err` is called to create the error to return if the entry
+    /// exists and `on_conflict` is `Error`.
+    fn create(
+        &self,
+        tx: &Transaction,
+        name: &str,
+        value: T,
+        on_conflict: OnConflict,
+        duplicate_err: impl FnOnce() -> DbError,
+    ) -> Result<T> {
+        let snapshot = tx.snapshot();
+        let mut entries = self.entries.lock();
+        let versions = entries.entry(name.to_string()).or_default();
+        Self::check_concurrent_modification(versions, &snapshot, name)?;
+
+        // Entries from rolled back transactions will never be visible.
+        versions
+            .retain(|ent| ent.versions.created.load(atomic::Ordering::Acquire) != ABORTED_VERSION);
+
+        if let Some(existing) = versions
+            .iter()
+            .find(|ent| ent.versions.is_visible(&snapshot))
+        {
+            match on_conflict {
+                OnConflict::Ignore => return Ok(existing.value.clone()),
+                OnConflict::Error => return Err(duplicate_err()),
+                OnConflict::Replace => {
+                    existing
+                        .versions
+                        .dropped
+                        .store(tx.id(), atomic::Ordering::Release);
+                    tx.add_change(Box::new(DroppedEntry(existing.versions.clone())));
+                }
+            }
+        }
+
+        let entry_versions = Arc::new(EntryVersions {
+            created: AtomicU64::new(tx.id()),
+            dropped: AtomicU64::new(NOT_DELETED),
+        });
+        versions.push(VersionedEntry {
+            value: value.clone(),
+            versions: entry_versions.clone(),
+        });
+        tx.add_change(Box::new(CreatedEntry(entry_versions)));
+
+        Ok(value)
+    }
+
+    /// Drop the version of the entry visible to the transaction.
+    ///
+    /// Returns None if there's no visible entry.
+    fn drop(&self, tx: &Transaction, name: &str) -> Result<Option<T>> {
+        let snapshot = tx.snapshot();
+        let entries = self.entries.lock();
+        let versions = match entries.get(name) {
+            Some(versions) => versions,
+            None => return Ok(None),
+        };
+        Self::check_concurrent_modification(versions, &snapshot, name)?;
+
+        let existing = match versions
+            .iter()
+            .find(|ent| ent.versions.is_visible(&snapshot))
+        {
+            Some(existing) => existing,
+            None => return Ok(None),
+        };
+
+        existing
+            .versions
+            .dropped
+            .store(tx.id(), atomic::Ordering::Release);
+        tx.add_change(Box::new(DroppedEntry(existing.versions.clone())));
+
+        Ok(Some(existing.value.clone()))
+    }
+
+    /// Get all entries visible to the transaction.
+    fn visible(&self, tx: &Transaction) -> Vec<T> {
+        let snapshot = tx.snapshot();
+        let entries = self.entries.lock();
+        entries
+            .values()
+            .filter_map(|versions| {
+                versions
+                    .iter()
+                    .rev()
+                    .find(|ent| ent.versions.is_visible(&snapshot))
+                    .map(|ent| ent.value.clone())
+            })
+            .collect()
+    }
+
+    fn check_concurrent_modification(
+        versions: &[VersionedEntry<T>],
+        snapshot: &Snapshot,
+        name: &str,
+    ) -> Result<()> {
+        if versions
+            .iter()
+            .any(|ent| ent.versions.is_concurrently_modified(snapshot))
+        {
+            return Err(DbError::new(format!(
+                "Catalog entry '{name}' was modified by a concurrent transaction"
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::functions::aggregate::builtin::sum::FUNCTION_SET_SUM;
+    use crate::transaction::TransactionManager;
 
     fn create_test_catalog() -> MemoryCatalog {
         let catalog = MemoryCatalog::empty();
         let _schema = catalog
-            .create_schema(&CreateSchemaInfo {
-                name: "test".to_string(),
-                on_conflict: OnConflict::Error,
-            })
+            .create_schema(
+                &Transaction::system(),
+                &CreateSchemaInfo {
+                    name: "test".to_string(),
+                    on_conflict: OnConflict::Error,
+                },
+            )
             .unwrap();
 
         catalog
@@ -629,7 +815,8 @@ mod tests {
     #[test]
     fn similarity_function_name() {
         let catalog = create_test_catalog();
-        let schema = catalog.get_schema("test").unwrap().unwrap();
+        let tx = Transaction::system();
+        let schema = catalog.get_schema(&tx, "test").unwrap().unwrap();
 
         schema
             .create_aggregate_function(&CreateAggregateFunctionInfo {
@@ -640,20 +827,136 @@ mod tests {
             .unwrap();
 
         let similar = schema
-            .find_similar_entry(&[CatalogEntryType::AggregateFunction], "summ")
+            .find_similar_entry(&tx, &[CatalogEntryType::AggregateFunction], "summ")
             .unwrap()
             .unwrap();
         assert_eq!("sum", similar.name);
 
         let similar = schema
-            .find_similar_entry(&[CatalogEntryType::AggregateFunction], "sim")
+            .find_similar_entry(&tx, &[CatalogEntryType::AggregateFunction], "sim")
             .unwrap()
             .unwrap();
         assert_eq!("sum", similar.name);
 
         let similar = schema
-            .find_similar_entry(&[CatalogEntryType::AggregateFunction], "ham")
+            .find_similar_entry(&tx, &[CatalogEntryType::AggregateFunction], "ham")
             .unwrap();
         assert!(similar.is_none());
     }
+
+    fn create_view_info(name: &str) -> CreateViewInfo {
+        CreateViewInfo {
+            name: name.to_string(),
+            column_aliases: None,
+            on_conflict: OnConflict::Error,
+            query_string: "SELECT 1".to_string(),
+        }
+    }
+
+    #[test]
+    fn create_visible_after_commit() {
+        let catalog = create_test_catalog();
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx1 = manager.begin();
+        let tx2 = manager.begin();
+        let schema = catalog.get_schema(&tx1, "test").unwrap().unwrap();
+        schema.create_view(&tx1, &create_view_info("v1")).unwrap();
+
+        assert!(schema.get_table_or_view(&tx1, "v1").unwrap().is_some());
+        assert!(schema.get_table_or_view(&tx2, "v1").unwrap().is_none());
+
+        // Concurrent create conflicts.
+        schema
+            .create_view(&tx2, &create_view_info("v1"))
+            .unwrap_err();
+
+        tx1.commit().unwrap();
+
+        // Still not visible to the older transaction.
+        assert!(schema.get_table_or_view(&tx2, "v1").unwrap().is_none());
+        let tx3 = manager.begin();
+        assert!(schema.get_table_or_view(&tx3, "v1").unwrap().is_some());
+    }
+
+    #[test]
+    fn create_rollback() {
+        let catalog = create_test_catalog();
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx1 = manager.begin();
+        let schema = catalog.get_schema(&tx1, "test").unwrap().unwrap();
+        schema.create_view(&tx1, &create_view_info("v1")).unwrap();
+        tx1.rollback().unwrap();
+
+        let tx2 = manager.begin();
+        assert!(schema.get_table_or_view(&tx2, "v1").unwrap().is_none());
+
+        // Name can be reused.
+        schema.create_view(&tx2, &create_view_info("v1")).unwrap();
+        tx2.commit().unwrap();
+    }
+
+    #[test]
+    fn drop_rollback() {
+        let catalog = create_test_catalog();
+        let manager = Arc::new(TransactionManager::new());
+        let schema = catalog
+            .get_schema(&Transaction::system(), "test")
+            .unwrap()
+            .unwrap();
+        schema
+            .create_view(&Transaction::system(), &create_view_info("v1"))
+            .unwrap();
+
+        let tx1 = manager.begin();
+        let drop = DropInfo {
+            schema: "test".to_string(),
+            object: DropObject::View("v1".to_string()),
+            cascade: false,
+            if_exists: false,
+        };
+        catalog.drop_entry(&tx1, &drop).unwrap();
+        assert!(schema.get_table_or_view(&tx1, "v1").unwrap().is_none());
+
+        let tx2 = manager.begin();
+        assert!(schema.get_table_or_view(&tx2, "v1").unwrap().is_some());
+
+        tx1.rollback().unwrap();
+        assert!(schema.get_table_or_view(&tx2, "v1").unwrap().is_some());
+
+        // Drop again and commit.
+        let tx3 = manager.begin();
+        catalog.drop_entry(&tx3, &drop).unwrap();
+        tx3.commit().unwrap();
+
+        assert!(schema.get_table_or_view(&tx2, "v1").unwrap().is_some());
+        let tx4 = manager.begin();
+        assert!(schema.get_table_or_view(&tx4, "v1").unwrap().is_none());
+    }
+
+    #[test]
+    fn schema_create_visible_after_commit() {
+        let catalog = create_test_catalog();
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx1 = manager.begin();
+        catalog
+            .create_schema(
+                &tx1,
+                &CreateSchemaInfo {
+                    name: "s1".to_string(),
+                    on_conflict: OnConflict::Error,
+                },
+            )
+            .unwrap();
+
+        let tx2 = manager.begin();
+        assert!(catalog.get_schema(&tx1, "s1").unwrap().is_some());
+        assert!(catalog.get_schema(&tx2, "s1").unwrap().is_none());
+
+        tx1.commit().unwrap();
+        let tx3 = manager.begin();
+        assert!(catalog.get_schema(&tx3, "s1").unwrap().is_some());
+    }
 }
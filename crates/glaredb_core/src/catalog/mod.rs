@@ -1,6 +1,7 @@
 pub mod context;
 pub mod create;
 pub mod database;
+pub mod datasource;
 pub mod drop;
 pub mod entry;
 pub mod memory;
@@ -8,99 +9,91 @@ pub mod profile;
 pub mod system;
 
 use std::fmt::Debug;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use create::{
     CreateAggregateFunctionInfo,
+    CreateCopyToFunctionInfo,
+    CreateExternalTableInfo,
     CreateScalarFunctionInfo,
     CreateSchemaInfo,
     CreateTableFunctionInfo,
     CreateTableInfo,
     CreateViewInfo,
+    CreateWindowFunctionInfo,
 };
 use drop::DropInfo;
 use entry::{CatalogEntry, CatalogEntryType};
 use futures::Stream;
 use glaredb_error::{DbError, Result};
 
-use crate::execution::operators::PlannedOperator;
-use crate::execution::planner::OperatorIdGen;
-use crate::storage::storage_manager::{StorageManager, StorageTableId};
-
-// TODO: This will probably undergo the same "manual dynamic dispatch" as many
-// of the other things in this code base.
-//
-// We'll need to make a distinction between what can actually be implemented by
-// external catalogs (e.g. fetching tables) vs what can't and require
-// "in-memory" implementations (e.g. storing functions).
+use crate::storage::storage_manager::StorageTableId;
+use crate::transaction::Transaction;
+
+/// Stream of catalog objects, returned in batches.
+pub type CatalogStream<T> = Pin<Box<dyn Stream<Item = Result<Vec<T>>> + Sync + Send + 'static>>;
+
+/// A boxed future for catalog operations that may need to reach out to a
+/// remote service.
+pub type CatalogFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Sync + Send + 'a>>;
+
+/// A catalog containing schemas.
+///
+/// Catalogs are dynamically dispatched through `Database`, allowing attached
+/// databases to provide their own implementations (e.g. a catalog backed by a
+/// remote service).
+///
+/// Schemas, tables, and views are accessed through a transaction. Changes made
+/// by a transaction are only visible to other transactions once it commits.
+/// Functions aren't transactional.
+// TODO: We'll need to make a distinction between what can actually be
+// implemented by external catalogs (e.g. fetching tables) vs what can't and
+// require "in-memory" implementations (e.g. storing functions).
 pub trait Catalog: Debug + Sync + Send {
-    type Schema: Schema;
-
     /// Create a schema in the catalog.
-    fn create_schema(&self, create: &CreateSchemaInfo) -> Result<Arc<Self::Schema>>;
+    fn create_schema(&self, tx: &Transaction, create: &CreateSchemaInfo)
+    -> Result<Arc<dyn Schema>>;
 
     /// Get a schema in the catalog.
     ///
     /// Returns Ok(None) if a schema with the given name doesn't exist.
-    fn get_schema(&self, name: &str) -> Result<Option<Arc<Self::Schema>>>;
+    fn get_schema(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<dyn Schema>>>;
 
     /// Get a schema, returning an error if it doesn't exist.
-    fn require_get_schema(&self, name: &str) -> Result<Arc<Self::Schema>> {
-        self.get_schema(name)?
+    fn require_get_schema(&self, tx: &Transaction, name: &str) -> Result<Arc<dyn Schema>> {
+        self.get_schema(tx, name)?
             .ok_or_else(|| DbError::new(format!("Missing schema '{name}'")))
     }
 
     /// Drop an entry in the catalog.
     ///
     /// Returns the dropped entry, if it exists.
-    fn drop_entry(&self, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>>;
-
-    fn plan_create_view(
-        self: &Arc<Self>,
-        id_gen: &mut OperatorIdGen,
-        schema: &str,
-        create: CreateViewInfo,
-    ) -> Result<PlannedOperator>;
-
-    fn plan_create_table(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        schema: &str,
-        create: CreateTableInfo,
-    ) -> Result<PlannedOperator>;
-
-    fn plan_create_table_as(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        schema: &str,
-        create: CreateTableInfo,
-    ) -> Result<PlannedOperator>;
-
-    fn plan_insert(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        entry: Arc<CatalogEntry>,
-    ) -> Result<PlannedOperator>;
-
-    fn plan_create_schema(
-        self: &Arc<Self>,
-        id_gen: &mut OperatorIdGen,
-        create: CreateSchemaInfo,
-    ) -> Result<PlannedOperator>;
-
-    fn plan_drop(
-        self: &Arc<Self>,
-        storage: &Arc<StorageManager>,
-        id_gen: &mut OperatorIdGen,
-        drop: DropInfo,
-    ) -> Result<PlannedOperator>;
-
-    fn list_schemas(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<Self::Schema>>>> + Sync + Send + 'static;
+    fn drop_entry(&self, tx: &Transaction, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>>;
+
+    /// List all schemas in the catalog.
+    fn list_schemas(&self, tx: &Transaction) -> CatalogStream<Arc<dyn Schema>>;
+
+    /// Resolve a table or view referenced in a query.
+    ///
+    /// Returns Ok(None) if the schema or table doesn't exist.
+    ///
+    /// Catalogs backed by a remote service can override this to load tables
+    /// when they're referenced instead of up front. Defaults to looking up the
+    /// table in the schema.
+    fn resolve_table_or_view<'a>(
+        &'a self,
+        tx: &'a Transaction,
+        schema: &'a str,
+        name: &'a str,
+    ) -> CatalogFuture<'a, Result<Option<Arc<CatalogEntry>>>> {
+        Box::pin(async move {
+            match self.get_schema(tx, schema)? {
+                Some(schema) => schema.get_table_or_view(tx, name),
+                None => Ok(None),
+            }
+        })
+    }
 }
 
 pub trait Schema: Debug + Sync + Send {
@@ -110,12 +103,20 @@ pub trait Schema: Debug + Sync + Send {
     // TODO: Storage id should be opaque.
     fn create_table(
         &self,
+        tx: &Transaction,
         create: &CreateTableInfo,
         storage_id: StorageTableId,
     ) -> Result<Arc<CatalogEntry>>;
 
+    /// Create an external table in the schema.
+    fn create_external_table(
+        &self,
+        tx: &Transaction,
+        create: &CreateExternalTableInfo,
+    ) -> Result<Arc<CatalogEntry>>;
+
     /// Create a view in the schema.
-    fn create_view(&self, create: &CreateViewInfo) -> Result<Arc<CatalogEntry>>;
+    fn create_view(&self, tx: &Transaction, create: &CreateViewInfo) -> Result<Arc<CatalogEntry>>;
 
     /// Create a scalar function in the schema.
     fn create_scalar_function(
@@ -129,15 +130,27 @@ pub trait Schema: Debug + Sync + Send {
         create: &CreateAggregateFunctionInfo,
     ) -> Result<Arc<CatalogEntry>>;
 
+    /// Create a window function in the schema.
+    fn create_window_function(
+        &self,
+        create: &CreateWindowFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>>;
+
     /// Create a table function in the schema.
     fn create_table_function(&self, create: &CreateTableFunctionInfo) -> Result<Arc<CatalogEntry>>;
 
+    /// Create a COPY TO function in the schema.
+    fn create_copy_to_function(
+        &self,
+        create: &CreateCopyToFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>>;
+
     /// Get a table or view in the schema.
-    fn get_table_or_view(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>>;
+    fn get_table_or_view(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<CatalogEntry>>>;
 
-    fn require_get_table(&self, name: &str) -> Result<Arc<CatalogEntry>> {
+    fn require_get_table(&self, tx: &Transaction, name: &str) -> Result<Arc<CatalogEntry>> {
         let ent = self
-            .get_table_or_view(name)?
+            .get_table_or_view(tx, name)?
             .ok_or_else(|| DbError::new(format!("Missing table '{name}'")))?;
         if ent.entry_type() != CatalogEntryType::Table {
             return Err(DbError::new(format!("'{name}' is not a table")));
@@ -152,6 +165,13 @@ pub trait Schema: Debug + Sync + Send {
     /// provided path.
     fn get_inferred_table_function(&self, path: &str) -> Result<Option<Arc<CatalogEntry>>>;
 
+    /// Get a COPY TO function for a format in the schema.
+    fn get_copy_to_function(&self, format: &str) -> Result<Option<Arc<CatalogEntry>>>;
+
+    /// Get a COPY TO function by trying to infer which one to use from the
+    /// provided path.
+    fn get_inferred_copy_to_function(&self, path: &str) -> Result<Option<Arc<CatalogEntry>>>;
+
     /// Get a scalar or aggregate function from the schema.
     fn get_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>>;
 
@@ -161,19 +181,20 @@ pub trait Schema: Debug + Sync + Send {
     /// Get an aggregate function from the schema.
     fn get_aggregate_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>>;
 
+    /// Get a window function from the schema.
+    fn get_window_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>>;
+
     /// Find a similar entry in the catalog that's of the given entry type.
     fn find_similar_entry(
         &self,
+        tx: &Transaction,
         entry_types: &[CatalogEntryType],
         name: &str,
     ) -> Result<Option<Arc<CatalogEntry>>>;
 
     /// List all entries in the schema.
-    fn list_entries(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<CatalogEntry>>>> + Sync + Send + 'static;
+    fn list_entries(&self, tx: &Transaction) -> CatalogStream<Arc<CatalogEntry>>;
 
-    fn list_tables(
-        self: &Arc<Self>,
-    ) -> impl Stream<Item = Result<Vec<Arc<CatalogEntry>>>> + Sync + Send + 'static;
+    /// List all tables in the schema.
+    fn list_tables(&self, tx: &Transaction) -> CatalogStream<Arc<CatalogEntry>>;
 }
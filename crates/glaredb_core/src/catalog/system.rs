@@ -1,18 +1,21 @@
 use glaredb_error::Result;
 
+use super::Catalog;
 use super::create::{
     CreateAggregateFunctionInfo,
     CreateScalarFunctionInfo,
     CreateSchemaInfo,
     CreateTableFunctionInfo,
     CreateViewInfo,
+    CreateWindowFunctionInfo,
     OnConflict,
 };
 use super::memory::MemoryCatalog;
-use super::{Catalog, Schema};
 use crate::functions::aggregate::builtin::BUILTIN_AGGREGATE_FUNCTION_SETS;
 use crate::functions::scalar::builtin::BUILTIN_SCALAR_FUNCTION_SETS;
 use crate::functions::table::builtin::BUILTIN_TABLE_FUNCTION_SETS;
+use crate::functions::window::builtin::BUILTIN_WINDOW_FUNCTION_SETS;
+use crate::transaction::Transaction;
 
 pub const DEFAULT_SCHEMA: &str = "default";
 
@@ -22,21 +25,31 @@ pub const DEFAULT_SCHEMA: &str = "default";
 /// reading external sources.
 pub fn new_system_catalog() -> Result<MemoryCatalog> {
     let catalog = MemoryCatalog::empty();
+    let tx = Transaction::system();
 
-    let builtin = catalog.create_schema(&CreateSchemaInfo {
-        name: DEFAULT_SCHEMA.to_string(),
-        on_conflict: OnConflict::Error,
-    })?;
+    let builtin = catalog.create_schema(
+        &tx,
+        &CreateSchemaInfo {
+            name: DEFAULT_SCHEMA.to_string(),
+            on_conflict: OnConflict::Error,
+        },
+    )?;
 
-    let _pg_catalog = catalog.create_schema(&CreateSchemaInfo {
-        name: "pg_catalog".to_string(),
-        on_conflict: OnConflict::Error,
-    })?;
+    let _pg_catalog = catalog.create_schema(
+        &tx,
+        &CreateSchemaInfo {
+            name: "pg_catalog".to_string(),
+            on_conflict: OnConflict::Error,
+        },
+    )?;
 
-    let _information_schema_catalog = catalog.create_schema(&CreateSchemaInfo {
-        name: "information_schema".to_string(),
-        on_conflict: OnConflict::Error,
-    })?;
+    let _information_schema_catalog = catalog.create_schema(
+        &tx,
+        &CreateSchemaInfo {
+            name: "information_schema".to_string(),
+            on_conflict: OnConflict::Error,
+        },
+    )?;
 
     // Add builtin scalars.
     for func in BUILTIN_SCALAR_FUNCTION_SETS.iter() {
@@ -72,6 +85,23 @@ pub fn new_system_catalog() -> Result<MemoryCatalog> {
         }
     }
 
+    // Add builtin window functions.
+    for func in BUILTIN_WINDOW_FUNCTION_SETS.iter() {
+        builtin.create_window_function(&CreateWindowFunctionInfo {
+            name: func.name.to_string(),
+            implementation: func,
+            on_conflict: OnConflict::Error,
+        })?;
+
+        for alias in func.aliases {
+            builtin.create_window_function(&CreateWindowFunctionInfo {
+                name: alias.to_string(),
+                implementation: func,
+                on_conflict: OnConflict::Error,
+            })?;
+        }
+    }
+
     // Add builtin table functions.
     for func in BUILTIN_TABLE_FUNCTION_SETS.iter() {
         builtin.create_table_function(&CreateTableFunctionInfo {
@@ -93,12 +123,15 @@ pub fn new_system_catalog() -> Result<MemoryCatalog> {
 
     // Add builtin views.
     for view in BUILTIN_VIEWS {
-        builtin.create_view(&CreateViewInfo {
-            name: view.name.to_string(),
-            column_aliases: None,
-            on_conflict: OnConflict::Error,
-            query_string: view.view.to_string(),
-        })?;
+        builtin.create_view(
+            &tx,
+            &CreateViewInfo {
+                name: view.name.to_string(),
+                column_aliases: None,
+                on_conflict: OnConflict::Error,
+                query_string: view.view.to_string(),
+            },
+        )?;
     }
 
     Ok(catalog)
@@ -1,3 +1,7 @@
+use std::sync::Arc;
+
+use crate::execution::spill::SpillContext;
+
 /// Configuration for intermediate pipeline planning.
 #[derive(Debug, Clone)]
 pub struct OperatorPlanConfig {
@@ -6,6 +10,10 @@ pub struct OperatorPlanConfig {
     pub per_partition_counts: bool,
     /// If hash joins are enabled.
     pub enable_hash_joins: bool,
+    /// Maximum number of iterations for recursive CTEs.
+    pub max_recursion_depth: usize,
+    /// Context for spilling to disk if a memory limit is configured.
+    pub spill: Option<Arc<SpillContext>>,
 }
 
 #[derive(Debug, Clone)]
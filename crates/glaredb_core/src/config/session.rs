@@ -3,6 +3,7 @@ use std::sync::LazyLock;
 
 use glaredb_error::{DbError, Result};
 
+use crate::arrays::scalar::timezone::TimeZone;
 use crate::arrays::scalar::{BorrowedScalarValue, ScalarValue};
 use crate::runtime::pipeline::PipelineRuntime;
 use crate::runtime::system::SystemRuntime;
@@ -18,6 +19,10 @@ pub struct SessionConfig {
     pub enable_hash_joins: bool,
     pub enable_function_chaining: bool,
     pub per_partition_counts: bool,
+    pub max_recursion_depth: u64,
+    pub memory_limit: u64,
+    pub temp_directory: String,
+    pub timezone: String,
 }
 
 impl SessionConfig {
@@ -35,6 +40,10 @@ impl SessionConfig {
             enable_hash_joins: true,
             enable_function_chaining: true,
             per_partition_counts: false,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH as u64,
+            memory_limit: 0,
+            temp_directory: String::new(),
+            timezone: DEFAULT_TIMEZONE.to_string(),
         }
     }
 
@@ -111,6 +120,10 @@ static GET_SET_FUNCTIONS: LazyLock<HashMap<&'static str, SettingFunctions>> = La
     insert_setting::<EnableFunctionChaining>(&mut map);
     insert_setting::<PerPartitionCounts>(&mut map);
     insert_setting::<EnableHashJoins>(&mut map);
+    insert_setting::<MaxRecursionDepth>(&mut map);
+    insert_setting::<MemoryLimit>(&mut map);
+    insert_setting::<TempDirectory>(&mut map);
+    insert_setting::<Timezone>(&mut map);
 
     map
 });
@@ -302,6 +315,118 @@ impl SessionSetting for EnableHashJoins {
     }
 }
 
+pub const DEFAULT_MAX_RECURSION_DEPTH: usize = 1000;
+
+pub struct MaxRecursionDepth;
+
+impl SessionSetting for MaxRecursionDepth {
+    const NAME: &'static str = "max_recursion_depth";
+    const DESCRIPTION: &'static str =
+        "Maximum number of iterations allowed when evaluating a recursive CTE.";
+
+    fn set_from_scalar(scalar: BorrowedScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_usize()?;
+        if val < 1 {
+            return Err(DbError::new("Max recursion depth cannot be less than 1"));
+        }
+
+        conf.max_recursion_depth = val as u64;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> ScalarValue {
+        conf.max_recursion_depth.into()
+    }
+}
+
+pub struct MemoryLimit;
+
+impl MemoryLimit {
+    /// Parse a memory size like '512MB' into a number of bytes.
+    ///
+    /// Units are powers of 1024. A plain number is interpreted as bytes.
+    pub fn parse_size(s: &str) -> Result<u64> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (num, unit) = s.split_at(split);
+
+        let num: u64 = num
+            .parse()
+            .map_err(|_| DbError::new(format!("Invalid memory size: '{s}'")))?;
+        let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+            "" | "B" => 1,
+            "KB" | "KIB" => 1 << 10,
+            "MB" | "MIB" => 1 << 20,
+            "GB" | "GIB" => 1 << 30,
+            "TB" | "TIB" => 1 << 40,
+            other => {
+                return Err(DbError::new(format!(
+                    "Invalid memory size unit '{other}', expected one of B, KB, MB, GB, TB"
+                )));
+            }
+        };
+
+        num.checked_mul(multiplier)
+            .ok_or_else(|| DbError::new(format!("Memory size too large: '{s}'")))
+    }
+}
+
+impl SessionSetting for MemoryLimit {
+    const NAME: &'static str = "memory_limit";
+    const DESCRIPTION: &'static str = "Memory threshold in bytes at which sorts, aggregates, and joins spill to disk. Other allocations are not limited. Accepts sizes like '512MB'. Zero disables spilling.";
+
+    fn set_from_scalar(scalar: BorrowedScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = match &scalar {
+            BorrowedScalarValue::Utf8(s) => Self::parse_size(s)?,
+            other => other.try_as_usize()? as u64,
+        };
+        conf.memory_limit = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> ScalarValue {
+        conf.memory_limit.into()
+    }
+}
+
+pub struct TempDirectory;
+
+impl SessionSetting for TempDirectory {
+    const NAME: &'static str = "temp_directory";
+    const DESCRIPTION: &'static str =
+        "Directory to write spill files to. Defaults to the system temp directory.";
+
+    fn set_from_scalar(scalar: BorrowedScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_into_string()?;
+        conf.temp_directory = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> ScalarValue {
+        conf.temp_directory.clone().into()
+    }
+}
+
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+
+pub struct Timezone;
+
+impl SessionSetting for Timezone {
+    const NAME: &'static str = "timezone";
+    const DESCRIPTION: &'static str = "Time zone used for TIMESTAMPTZ values. Accepts IANA zone names like 'America/New_York' or UTC offsets like '+05:30'.";
+
+    fn set_from_scalar(scalar: BorrowedScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_into_string()?;
+        let tz: TimeZone = val.parse()?;
+        conf.timezone = tz.to_string();
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> ScalarValue {
+        conf.timezone.clone().into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,6 +441,10 @@ mod tests {
             enable_hash_joins: false,
             enable_function_chaining: true,
             per_partition_counts: false,
+            max_recursion_depth: 1000,
+            memory_limit: 0,
+            temp_directory: String::new(),
+            timezone: DEFAULT_TIMEZONE.to_string(),
         }
     }
 
@@ -344,4 +473,38 @@ mod tests {
         let val = conf.get_as_scalar("partitions").unwrap();
         assert_eq!(BorrowedScalarValue::UInt64(13), val);
     }
+
+    #[test]
+    fn set_memory_limit() {
+        let mut conf = new_test_config();
+        conf.set_from_scalar("memory_limit", "64KB".into()).unwrap();
+        assert_eq!(64 * 1024, conf.memory_limit);
+
+        conf.set_from_scalar("memory_limit", " 2 gb".into())
+            .unwrap();
+        assert_eq!(2 * 1024 * 1024 * 1024, conf.memory_limit);
+
+        conf.set_from_scalar("memory_limit", 4096.into()).unwrap();
+        assert_eq!(4096, conf.memory_limit);
+
+        conf.set_from_scalar("memory_limit", "12XB".into())
+            .unwrap_err();
+        conf.set_from_scalar("memory_limit", "MB".into())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn set_timezone() {
+        let mut conf = new_test_config();
+        conf.set_from_scalar("timezone", "america/new_york".into())
+            .unwrap();
+        assert_eq!("America/New_York", conf.timezone);
+
+        conf.set_from_scalar("timezone", "+05:30".into()).unwrap();
+        assert_eq!("+05:30", conf.timezone);
+
+        conf.set_from_scalar("timezone", "Nowhere/Special".into())
+            .unwrap_err();
+        assert_eq!("+05:30", conf.timezone);
+    }
 }
@@ -10,22 +10,32 @@ use session::Session;
 use crate::catalog::context::{DatabaseContext, SYSTEM_CATALOG};
 use crate::catalog::create::{
     CreateAggregateFunctionInfo,
+    CreateCopyToFunctionInfo,
     CreateScalarFunctionInfo,
     CreateSchemaInfo,
     CreateTableFunctionInfo,
     OnConflict,
 };
 use crate::catalog::database::{AccessMode, Database};
+use crate::catalog::datasource::{
+    DataSource,
+    DataSourceRegistry,
+    MEMORY_DATASOURCE_NAME,
+    MemoryDataSource,
+};
 use crate::catalog::system::{DEFAULT_SCHEMA, new_system_catalog};
-use crate::catalog::{Catalog, Schema};
 use crate::extension::Extension;
 use crate::runtime::pipeline::PipelineRuntime;
 use crate::runtime::system::SystemRuntime;
+use crate::storage::persistent::{GLARE_DATASOURCE_NAME, GlareDataSource};
 use crate::storage::storage_manager::StorageManager;
+use crate::transaction::{Transaction, TransactionManager};
 
 #[derive(Debug)]
 pub struct Engine<P: PipelineRuntime, R: SystemRuntime> {
     system_catalog: Arc<Database>,
+    datasources: Arc<DataSourceRegistry>,
+    transactions: Arc<TransactionManager>,
     executor: P,
     runtime: R,
 }
@@ -44,19 +54,33 @@ where
             attach_info: None,
         });
 
+        let datasources = Arc::new(DataSourceRegistry::default());
+        datasources.register(MEMORY_DATASOURCE_NAME, Arc::new(MemoryDataSource))?;
+        datasources.register(GLARE_DATASOURCE_NAME, Arc::new(GlareDataSource))?;
+
         Ok(Engine {
             system_catalog,
+            datasources,
+            transactions: Arc::new(TransactionManager::new()),
             executor,
             runtime,
         })
     }
 
+    pub fn runtime(&self) -> &R {
+        &self.runtime
+    }
+
     /// Creates a new database context that contains only the system catalog and
     /// a temporary catalog.
     ///
     /// This should be the base of all session catalogs.
     pub fn new_base_database_context(&self) -> Result<DatabaseContext> {
-        DatabaseContext::new(self.system_catalog.clone())
+        DatabaseContext::new(
+            self.system_catalog.clone(),
+            self.datasources.clone(),
+            self.transactions.clone(),
+        )
     }
 
     /// Create a new session.
@@ -69,6 +93,19 @@ where
         ))
     }
 
+    /// Register a datasource that can be attached with `ATTACH ... (TYPE
+    /// name)`.
+    ///
+    /// Datasources are visible to all sessions created by this engine,
+    /// including sessions created before registering.
+    pub fn register_datasource(
+        &self,
+        name: &str,
+        datasource: impl DataSource + 'static,
+    ) -> Result<()> {
+        self.datasources.register(name, Arc::new(datasource))
+    }
+
     /// Register a new extension for this engine.
     pub fn register_extension<E>(&self, ext: E) -> Result<()>
     where
@@ -77,18 +114,19 @@ where
         let schema = match E::FUNCTION_NAMESPACE {
             Some(namespace) => {
                 // Create a new schema for these functions.
-                self.system_catalog
-                    .catalog
-                    .create_schema(&CreateSchemaInfo {
+                self.system_catalog.catalog.create_schema(
+                    &Transaction::system(),
+                    &CreateSchemaInfo {
                         name: namespace.to_string(),
                         on_conflict: OnConflict::Error,
-                    })?
+                    },
+                )?
             }
             None => {
                 // Use the default schema.
                 self.system_catalog
                     .catalog
-                    .get_schema(DEFAULT_SCHEMA)?
+                    .get_schema(&Transaction::system(), DEFAULT_SCHEMA)?
                     .required("default schema")?
             }
         };
@@ -149,7 +187,15 @@ where
             }
         }
 
-        // TODO: File handlers
+        // Register COPY TO functions.
+        for copy_to in ext.copy_to_functions() {
+            schema.create_copy_to_function(&CreateCopyToFunctionInfo {
+                name: copy_to.function.name.to_string(),
+                implementation: copy_to.function,
+                infer_copy_to: copy_to.infer_copy_to,
+                on_conflict: OnConflict::Error,
+            })?;
+        }
 
         Ok(())
     }
@@ -14,6 +14,7 @@ use crate::config::session::DEFAULT_BATCH_SIZE;
 use crate::execution::operators::results::streaming::ResultStream;
 use crate::runtime::pipeline::QueryHandle;
 use crate::runtime::profile_buffer::ProfileBuffer;
+use crate::transaction::Transaction;
 
 #[derive(Debug)]
 pub struct QueryResult {
@@ -29,6 +30,7 @@ pub struct StreamOutput {
     profiles: Arc<ProfileCollector>,
     handle: Arc<dyn QueryHandle>,
     stream: ResultStream,
+    transaction: StatementTransaction,
 }
 
 impl StreamOutput {
@@ -37,18 +39,21 @@ impl StreamOutput {
         profile: QueryProfile,
         handle: Arc<dyn QueryHandle>,
         profiles: Arc<ProfileCollector>,
+        transaction: StatementTransaction,
     ) -> Self {
         StreamOutput {
             profile: Some(profile),
             profiles,
             handle,
             stream,
+            transaction,
         }
     }
 
     pub async fn collect(&mut self) -> Result<Vec<Batch>> {
         let stream = &mut self.stream;
-        let batches = stream.try_collect().await?;
+        let result = stream.try_collect().await;
+        let batches = self.transaction.finish(result)?;
 
         if let Some(mut profile) = self.profile.take() {
             match self.handle.generate_final_execution_profile() {
@@ -91,10 +96,17 @@ pub struct VerifyStreamOutput {
 
     /// The combined left and right handles.
     pub(crate) handle: Arc<VerifyQueryHandle>,
+
+    pub(crate) transaction: StatementTransaction,
 }
 
 impl VerifyStreamOutput {
     pub async fn collect(&mut self) -> Result<Vec<Batch>> {
+        let result = self.collect_and_verify().await;
+        self.transaction.finish(result)
+    }
+
+    async fn collect_and_verify(&mut self) -> Result<Vec<Batch>> {
         // Collect the left.
         let mut left_append = self.left_collection.init_append_state();
         while let Some(batch) = self.left_stream.try_next().await? {
@@ -142,6 +154,68 @@ impl VerifyStreamOutput {
     }
 }
 
+/// The transaction a statement is executing in.
+#[derive(Debug)]
+pub struct StatementTransaction {
+    transaction: Arc<Transaction>,
+    /// If the transaction was started for only this statement.
+    ///
+    /// Autocommit transactions are committed once the output has been fully
+    /// read, while explicit transactions are left open for the next statement.
+    autocommit: bool,
+    finished: bool,
+}
+
+impl StatementTransaction {
+    pub(crate) fn new(transaction: Arc<Transaction>, autocommit: bool) -> Self {
+        StatementTransaction {
+            transaction,
+            autocommit,
+            finished: false,
+        }
+    }
+
+    /// Complete the transaction using the result of the statement.
+    ///
+    /// Autocommit transactions are committed on success and rolled back on
+    /// failure. Explicit transactions are marked as aborted on failure.
+    fn finish<T>(&mut self, result: Result<T>) -> Result<T> {
+        if self.finished {
+            return result;
+        }
+        self.finished = true;
+
+        match result {
+            Ok(v) => {
+                if self.autocommit {
+                    self.transaction.commit()?;
+                }
+                Ok(v)
+            }
+            Err(e) => {
+                if self.autocommit {
+                    self.transaction.rollback()?;
+                } else {
+                    self.transaction.abort();
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Drop for StatementTransaction {
+    fn drop(&mut self) {
+        // Output was never read, don't leave the changes hanging around.
+        if !self.finished
+            && self.autocommit
+            && let Err(e) = self.transaction.rollback()
+        {
+            error!(%e, "failed to roll back transaction");
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct VerifyQueryHandle {
     pub(crate) left: Arc<dyn QueryHandle>,
@@ -9,13 +9,17 @@ use uuid::Uuid;
 use super::query_result::{
     Output,
     QueryResult,
+    StatementTransaction,
     StreamOutput,
     VerifyQueryHandle,
     VerifyStreamOutput,
 };
 use crate::arrays::collection::concurrent::ConcurrentColumnCollection;
 use crate::arrays::field::{ColumnSchema, Field};
+use crate::buffer::buffer_manager::MemoryLimit;
 use crate::catalog::context::DatabaseContext;
+use crate::catalog::database::{AttachInfo, Database};
+use crate::catalog::datasource::ConnectContext;
 use crate::catalog::profile::{PlanningProfile, QueryProfile};
 use crate::config::execution::OperatorPlanConfig;
 use crate::config::session::{DEFAULT_BATCH_SIZE, SessionConfig};
@@ -23,17 +27,23 @@ use crate::execution::operators::results::streaming::{PhysicalStreamingResults,
 use crate::execution::operators::{ExecutionProperties, PushOperator};
 use crate::execution::pipeline::ExecutablePipelineGraph;
 use crate::execution::planner::{OperatorPlanner, PlannedQueryGraph};
+use crate::execution::spill::{SpillContext, default_spill_directory};
 use crate::explain::node::ExplainedPlan;
 use crate::logical::binder::bind_statement::StatementBinder;
+use crate::logical::logical_attach::LogicalAttachDatabase;
 use crate::logical::logical_set::VariableOrAll;
+use crate::logical::logical_transaction::LogicalTransaction;
 use crate::logical::operator::LogicalOperator;
 use crate::logical::planner::plan_statement::StatementPlanner;
 use crate::logical::resolver::resolve_context::ResolveContext;
 use crate::logical::resolver::{ResolveConfig, ResolveMode, ResolvedStatement, Resolver};
 use crate::optimizer::Optimizer;
+use crate::runtime::filesystem::FileOpenContext;
+use crate::runtime::filesystem::blocking::check_synchronous;
 use crate::runtime::pipeline::PipelineRuntime;
 use crate::runtime::system::SystemRuntime;
 use crate::runtime::time::Timer;
+use crate::transaction::{Transaction, TransactionStatus};
 
 /// A "client" session capable of executing queries from arbitrary sql
 /// statements.
@@ -76,6 +86,11 @@ pub struct Session<P: PipelineRuntime, R: SystemRuntime> {
     prepared: HashMap<String, PreparedStatement>,
     /// Portals for statements ready to be executed.
     portals: HashMap<String, ExecutablePortal>,
+    /// Transaction started with BEGIN, if any.
+    ///
+    /// Statements outside of an explicit transaction each run in their own
+    /// autocommit transaction.
+    transaction: Option<Arc<Transaction>>,
 }
 
 #[derive(Debug, Clone)]
@@ -132,6 +147,8 @@ struct ExecutablePortal {
     optimized: bool,
     /// Optional verification state.
     verification: Option<VerificationState>,
+    /// Transaction the statement is executing in.
+    transaction: StatementTransaction,
 }
 
 /// State for query verification.
@@ -161,6 +178,7 @@ where
             config,
             prepared: HashMap::new(),
             portals: HashMap::new(),
+            transaction: None,
         }
     }
 
@@ -208,11 +226,51 @@ where
         prepared_name: &str,
         portal_name: impl Into<String>,
     ) -> Result<()> {
-        let stmt = self.get_prepared_by_name(prepared_name)?;
+        let stmt = self.get_prepared_by_name(prepared_name)?.clone();
+        let is_control = matches!(stmt.statement, RawStatement::Transaction(_));
+
+        // Use the explicit transaction if we're in one, otherwise the
+        // statement runs in its own transaction.
+        let (transaction, autocommit) = match &self.transaction {
+            Some(transaction) => {
+                if transaction.status() == TransactionStatus::Aborted && !is_control {
+                    return Err(DbError::new(
+                        "Current transaction is aborted, commands ignored until end of transaction block",
+                    ));
+                }
+                (transaction.clone(), false)
+            }
+            None => (self.context.transactions().begin(), true),
+        };
+        self.context.set_transaction(transaction.clone());
+
+        match self.bind_in_transaction(stmt, autocommit).await {
+            Ok(portal) => {
+                self.portals.insert(portal_name.into(), portal);
+                Ok(())
+            }
+            Err(e) => {
+                if autocommit && self.transaction.is_none() {
+                    transaction.rollback()?;
+                } else {
+                    transaction.abort();
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn bind_in_transaction(
+        &mut self,
+        stmt: PreparedStatement,
+        autocommit: bool,
+    ) -> Result<ExecutablePortal> {
         let is_query = matches!(stmt.statement, RawStatement::Query(_));
 
         let profile = PlanningProfile::default();
-        let mut portal = self.bind_prepared(profile, stmt.clone()).await?;
+        let mut portal = self
+            .bind_prepared(profile, stmt.clone(), autocommit)
+            .await?;
 
         // If we're verifying the query, go ahead and plan it with optimization
         // disabled, and attach to this portal.
@@ -225,15 +283,15 @@ where
             self.config.enable_optimizer = false;
             self.config.enable_hash_joins = false;
 
-            let stmt = self.get_prepared_by_name(prepared_name)?;
-
             // Note we don't return the error here, we need to reset the config
             // before doing so.
             //
+            // Completing the transaction is left to the portal being verified.
+            //
             // TODO: We should be able to pass in a materializing sink here
             // instead of getting a result stream.
             let result = self
-                .bind_prepared(PlanningProfile::default(), stmt.clone())
+                .bind_prepared(PlanningProfile::default(), stmt, false)
                 .await;
             self.config.enable_optimizer = orig_optimizer;
             self.config.enable_hash_joins = orig_hash_joins;
@@ -251,15 +309,14 @@ where
             });
         }
 
-        self.portals.insert(portal_name.into(), portal);
-
-        Ok(())
+        Ok(portal)
     }
 
     async fn bind_prepared(
         &mut self,
         mut profile: PlanningProfile,
         stmt: PreparedStatement,
+        autocommit: bool,
     ) -> Result<ExecutablePortal> {
         let resolve_mode = ResolveMode::Normal;
         let timer = Timer::<R::Instant>::start();
@@ -269,6 +326,8 @@ where
             &self.runtime,
             ResolveConfig {
                 enable_function_chaining: self.config.enable_function_chaining,
+                timezone: self.config.timezone.clone(),
+                statement_time: chrono::Utc::now().timestamp_micros(),
             },
         )
         .resolve_statement(stmt.statement.clone())
@@ -305,9 +364,101 @@ where
             profile,
             optimized: intermediate_portal.optimized,
             verification: None,
+            // Statements like BEGIN and COMMIT change the session's
+            // transaction, only commit if we're still outside of one.
+            transaction: StatementTransaction::new(
+                self.context.transaction().clone(),
+                autocommit && self.transaction.is_none(),
+            ),
         })
     }
 
+    /// Creates the context for spilling operator state to disk.
+    ///
+    /// Returns None if no memory limit is configured.
+    async fn create_spill_context(&self, query_id: Uuid) -> Result<Option<Arc<SpillContext>>> {
+        if self.config.memory_limit == 0 {
+            return Ok(None);
+        }
+
+        let directory = if self.config.temp_directory.is_empty() {
+            default_spill_directory()?
+        } else {
+            self.config.temp_directory.clone()
+        };
+
+        let named = std::collections::HashMap::new();
+        let filesystem = self
+            .runtime
+            .filesystem_dispatch()
+            .filesystem_for_path(&directory)?
+            .load_state(FileOpenContext::new(&self.context, &named))
+            .await?;
+        // Spill files are read and written from within operators.
+        check_synchronous(&filesystem)?;
+
+        Ok(Some(Arc::new(SpillContext::new(
+            MemoryLimit::new(self.config.memory_limit as usize),
+            filesystem,
+            directory,
+            format!("glaredb_spill_{query_id}"),
+        ))))
+    }
+
+    /// Attach a database using the catalog and storage provided by the
+    /// datasource.
+    async fn handle_attach_database(&mut self, attach: LogicalAttachDatabase) -> Result<()> {
+        if self.context.get_database(&attach.name).is_some() {
+            return Err(DbError::new(format!(
+                "Database with name '{}' already exists",
+                attach.name
+            )));
+        }
+
+        let datasource = self.context.datasources().require_get(&attach.datasource)?;
+        let context = ConnectContext {
+            database_context: &self.context,
+            dispatch: self.runtime.filesystem_dispatch(),
+        };
+        let attached = datasource.connect(context, &attach.options).await?;
+
+        self.context.attach_database(Database {
+            name: attach.name,
+            mode: attached.mode,
+            catalog: attached.catalog,
+            storage: attached.storage,
+            attach_info: Some(AttachInfo {
+                options: attach.options,
+            }),
+        })
+    }
+
+    /// Begin, commit, or roll back the session's explicit transaction.
+    fn handle_transaction(&mut self, transaction: LogicalTransaction) -> Result<()> {
+        match transaction {
+            LogicalTransaction::Begin => {
+                if self.transaction.is_some() {
+                    return Err(DbError::new("Transaction already in progress"));
+                }
+                // The transaction this statement is running in becomes the
+                // explicit transaction.
+                self.transaction = Some(self.context.transaction().clone());
+                Ok(())
+            }
+            LogicalTransaction::Commit => match self.transaction.take() {
+                // Committing an aborted transaction rolls it back, matching
+                // postgres.
+                Some(tx) if tx.status() == TransactionStatus::Aborted => tx.rollback(),
+                Some(tx) => tx.commit(),
+                None => Ok(()),
+            },
+            LogicalTransaction::Rollback => match self.transaction.take() {
+                Some(tx) => tx.rollback(),
+                None => Ok(()),
+            },
+        }
+    }
+
     /// Plans the intermediate pipelines from a resolved statement.
     ///
     /// If the resolve context indicates that not all objects were resolved,
@@ -378,25 +529,44 @@ where
                 );
 
                 let query_id = Uuid::new_v4();
+                let spill = self.create_spill_context(query_id).await?;
                 let planner = OperatorPlanner::new(
                     OperatorPlanConfig {
                         per_partition_counts: self.config.per_partition_counts,
                         enable_hash_joins: self.config.enable_hash_joins,
+                        max_recursion_depth: self.config.max_recursion_depth as usize,
+                        spill,
                     },
                     query_id,
                 );
 
                 let query_graph = match logical {
-                    LogicalOperator::AttachDatabase(_) => {
-                        not_implemented!("Attach database")
-                        // self.handle_attach_database(attach).await?;
-                        // planner.plan(LogicalOperator::EMPTY, bind_context, sink)?
+                    LogicalOperator::AttachDatabase(attach) => {
+                        self.handle_attach_database(attach.node).await?;
+                        planner.plan(
+                            LogicalOperator::SINGLE_ROW,
+                            &self.context,
+                            bind_context,
+                            sink,
+                        )?
                     }
-                    LogicalOperator::DetachDatabase(_) => {
-                        not_implemented!("Detach database")
-                        // let empty = planner.plan(LogicalOperator::EMPTY, bind_context, sink)?; // Here to avoid lifetime issues.
-                        // self.context.detach_database(&detach.as_ref().name)?;
-                        // empty
+                    LogicalOperator::DetachDatabase(detach) => {
+                        self.context.detach_database(&detach.node.name)?;
+                        planner.plan(
+                            LogicalOperator::SINGLE_ROW,
+                            &self.context,
+                            bind_context,
+                            sink,
+                        )?
+                    }
+                    LogicalOperator::Transaction(transaction) => {
+                        self.handle_transaction(transaction.node)?;
+                        planner.plan(
+                            LogicalOperator::SINGLE_ROW,
+                            &self.context,
+                            bind_context,
+                            sink,
+                        )?
                     }
                     LogicalOperator::SetVar(set_var) => {
                         // TODO: Do we want this logic to exist here?
@@ -539,6 +709,8 @@ where
                         left: left_handle,
                         right: handle,
                     }),
+
+                    transaction: portal.transaction,
                 })
             }
             None => {
@@ -548,6 +720,7 @@ where
                     profile,
                     handle,
                     self.context.profiles().clone(),
+                    portal.transaction,
                 ))
             }
         };
@@ -9,6 +9,7 @@ use glaredb_parser::statement::RawStatement;
 use super::Engine;
 use super::query_result::QueryResult;
 use super::session::Session;
+use crate::catalog::datasource::DataSource;
 use crate::extension::Extension;
 use crate::runtime::pipeline::PipelineRuntime;
 use crate::runtime::system::SystemRuntime;
@@ -46,6 +47,14 @@ where
     {
         self.engine.register_extension(ext)
     }
+
+    pub fn register_datasource(
+        &self,
+        name: &str,
+        datasource: impl DataSource + 'static,
+    ) -> Result<()> {
+        self.engine.register_datasource(name, datasource)
+    }
 }
 
 /// Session connected to the above engine.
@@ -1,6 +1,7 @@
 //! Implementation of the "query graph" design.
 pub mod operators;
 pub mod planner;
+pub mod spill;
 
 pub mod partition_pipeline;
 pub mod pipeline;
@@ -7,9 +7,9 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::catalog::Catalog;
 use crate::catalog::create::CreateSchemaInfo;
-use crate::catalog::memory::MemoryCatalog;
 use crate::execution::operators::{BaseOperator, ExecutionProperties, PollPull, PullOperator};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::transaction::Transaction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CreateSchemaPartitionState {
@@ -19,7 +19,8 @@ pub enum CreateSchemaPartitionState {
 
 #[derive(Debug)]
 pub struct PhysicalCreateSchema {
-    pub(crate) catalog: Arc<MemoryCatalog>,
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) catalog: Arc<dyn Catalog>,
     pub(crate) info: CreateSchemaInfo,
 }
 
@@ -61,7 +62,7 @@ impl PullOperator for PhysicalCreateSchema {
         output: &mut Batch,
     ) -> Result<PollPull> {
         if *state == CreateSchemaPartitionState::Create {
-            self.catalog.create_schema(&self.info)?;
+            self.catalog.create_schema(&self.transaction, &self.info)?;
         }
         output.set_num_rows(0)?;
         Ok(PollPull::Exhausted)
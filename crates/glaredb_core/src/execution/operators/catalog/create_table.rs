@@ -7,12 +7,10 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::catalog::Schema;
 use crate::catalog::create::CreateTableInfo;
-use crate::catalog::memory::MemorySchema;
-use crate::config::session::DEFAULT_BATCH_SIZE;
 use crate::execution::operators::{BaseOperator, ExecutionProperties, PollPull, PullOperator};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
-use crate::storage::datatable::DataTable;
-use crate::storage::storage_manager::StorageManager;
+use crate::storage::Storage;
+use crate::transaction::Transaction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CreateTablePartitionState {
@@ -22,8 +20,9 @@ pub enum CreateTablePartitionState {
 
 #[derive(Debug)]
 pub struct PhysicalCreateTable {
-    pub(crate) storage: Arc<StorageManager>,
-    pub(crate) schema: Arc<MemorySchema>,
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) storage: Arc<dyn Storage>,
+    pub(crate) schema: Arc<dyn Schema>,
     pub(crate) info: CreateTableInfo,
 }
 
@@ -65,14 +64,15 @@ impl PullOperator for PhysicalCreateTable {
         output: &mut Batch,
     ) -> Result<PollPull> {
         if *state == CreateTablePartitionState::Create {
-            // TODO: How do we want to configure datatables?
-            let datatable = DataTable::new(
-                self.info.columns.iter().map(|f| f.datatype.clone()),
-                16,
-                DEFAULT_BATCH_SIZE,
-            );
-            let storage_id = self.storage.insert_table(Arc::new(datatable))?;
-            self.schema.create_table(&self.info, storage_id)?;
+            let datatypes: Vec<_> = self
+                .info
+                .columns
+                .iter()
+                .map(|f| f.datatype.clone())
+                .collect();
+            let (storage_id, _) = self.storage.create_table(&self.transaction, &datatypes)?;
+            self.schema
+                .create_table(&self.transaction, &self.info, storage_id)?;
         }
         output.set_num_rows(0)?;
         Ok(PollPull::Exhausted)
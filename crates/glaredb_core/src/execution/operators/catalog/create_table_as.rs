@@ -8,8 +8,6 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::catalog::Schema;
 use crate::catalog::create::CreateTableInfo;
-use crate::catalog::memory::MemorySchema;
-use crate::config::session::DEFAULT_BATCH_SIZE;
 use crate::execution::operators::util::partition_wakers::PartitionWakers;
 use crate::execution::operators::{
     BaseOperator,
@@ -19,8 +17,9 @@ use crate::execution::operators::{
     PollFinalize,
 };
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::storage::Storage;
 use crate::storage::datatable::{DataTable, DataTableAppendState};
-use crate::storage::storage_manager::StorageManager;
+use crate::transaction::Transaction;
 
 #[derive(Debug)]
 pub struct CreateTableAsOperatorState {
@@ -54,18 +53,21 @@ struct PartitionStateInner {
 
 #[derive(Debug)]
 pub struct PhysicalCreateTableAs {
-    pub(crate) storage: Arc<StorageManager>,
-    pub(crate) schema: Arc<MemorySchema>,
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) storage: Arc<dyn Storage>,
+    pub(crate) schema: Arc<dyn Schema>,
     pub(crate) info: CreateTableInfo,
 }
 
 impl PhysicalCreateTableAs {
     pub fn new(
-        storage: Arc<StorageManager>,
-        schema: Arc<MemorySchema>,
+        transaction: Arc<Transaction>,
+        storage: Arc<dyn Storage>,
+        schema: Arc<dyn Schema>,
         info: CreateTableInfo,
     ) -> Self {
         PhysicalCreateTableAs {
+            transaction,
             storage,
             schema,
             info,
@@ -149,15 +151,16 @@ impl ExecuteOperator for PhysicalCreateTableAs {
                 op_state.creating = true;
                 std::mem::drop(op_state);
 
-                // TODO: Same as CreateTable, figure out how we want to
-                // configure these.
-                let datatable = Arc::new(DataTable::new(
-                    self.info.columns.iter().map(|f| f.datatype.clone()),
-                    16,
-                    DEFAULT_BATCH_SIZE,
-                ));
-                let storage_id = self.storage.insert_table(datatable.clone())?;
-                self.schema.create_table(&self.info, storage_id)?;
+                let datatypes: Vec<_> = self
+                    .info
+                    .columns
+                    .iter()
+                    .map(|f| f.datatype.clone())
+                    .collect();
+                let (storage_id, datatable) =
+                    self.storage.create_table(&self.transaction, &datatypes)?;
+                self.schema
+                    .create_table(&self.transaction, &self.info, storage_id)?;
 
                 op_state = operator_state.inner.lock();
                 op_state.creating = false;
@@ -177,7 +180,7 @@ impl ExecuteOperator for PhysicalCreateTableAs {
                 .expect("datatable to exist");
             std::mem::drop(op_state);
 
-            let append_state = datatable.init_append_state();
+            let append_state = datatable.init_append_state(&self.transaction);
             state.inner = Some(PartitionStateInner {
                 table: datatable,
                 state: append_state,
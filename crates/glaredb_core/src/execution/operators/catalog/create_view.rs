@@ -7,9 +7,9 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::catalog::Schema;
 use crate::catalog::create::CreateViewInfo;
-use crate::catalog::memory::MemorySchema;
 use crate::execution::operators::{BaseOperator, ExecutionProperties, PollPull, PullOperator};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::transaction::Transaction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CreateViewPartitionState {
@@ -19,7 +19,8 @@ pub enum CreateViewPartitionState {
 
 #[derive(Debug)]
 pub struct PhysicalCreateView {
-    pub(crate) schema: Arc<MemorySchema>,
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) schema: Arc<dyn Schema>,
     pub(crate) info: CreateViewInfo,
 }
 
@@ -61,7 +62,7 @@ impl PullOperator for PhysicalCreateView {
         output: &mut Batch,
     ) -> Result<PollPull> {
         if *state == CreateViewPartitionState::Create {
-            self.schema.create_view(&self.info)?;
+            self.schema.create_view(&self.transaction, &self.info)?;
         }
         output.set_num_rows(0)?;
         Ok(PollPull::Exhausted)
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use std::task::Context;
+
+use glaredb_error::Result;
+use parking_lot::Mutex;
+
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::catalog::entry::CatalogEntry;
+use crate::execution::operators::util::delayed_count::DelayedPartitionCount;
+use crate::execution::operators::{
+    BaseOperator,
+    ExecuteOperator,
+    ExecutionProperties,
+    PollExecute,
+    PollFinalize,
+};
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::storage::Storage;
+use crate::storage::datatable::DataTable;
+use crate::transaction::Transaction;
+
+#[derive(Debug)]
+pub struct DeleteOperatorState {
+    datatable: Arc<DataTable>,
+    inner: Mutex<DeleteOperatorStateInner>,
+}
+
+#[derive(Debug)]
+struct DeleteOperatorStateInner {
+    /// Row ids collected from all partitions.
+    row_ids: Vec<usize>,
+    /// Partitions still collecting row ids.
+    remaining: DelayedPartitionCount,
+}
+
+#[derive(Debug)]
+pub struct DeletePartitionState {
+    finished: bool,
+    count: i64,
+    row_ids: Vec<usize>,
+}
+
+/// Deletes rows from a table using row ids from the input.
+///
+/// Rows are only deleted once all partitions have finished reading their
+/// input, the last partition to finish deletes rows for all partitions. This
+/// ensures the scan of the table sees the table as it was at the start of the
+/// statement.
+#[derive(Debug)]
+pub struct PhysicalDelete {
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) storage: Arc<dyn Storage>,
+    pub(crate) entry: Arc<CatalogEntry>,
+}
+
+impl BaseOperator for PhysicalDelete {
+    const OPERATOR_NAME: &str = "Delete";
+
+    type OperatorState = DeleteOperatorState;
+
+    fn create_operator_state(&self, _props: ExecutionProperties) -> Result<Self::OperatorState> {
+        let ent = self.entry.try_as_table_entry()?;
+        let datatable = self.storage.get_table(ent.storage_id()?)?;
+
+        Ok(DeleteOperatorState {
+            datatable,
+            inner: Mutex::new(DeleteOperatorStateInner {
+                row_ids: Vec::new(),
+                remaining: DelayedPartitionCount::uninit(),
+            }),
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        const OUTPUT_TYPES: &[DataType] = &[DataType::int64()];
+        OUTPUT_TYPES
+    }
+}
+
+impl ExecuteOperator for PhysicalDelete {
+    type PartitionExecuteState = DeletePartitionState;
+
+    fn create_partition_execute_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionExecuteState>> {
+        operator_state.inner.lock().remaining.set(partitions)?;
+
+        let states = (0..partitions)
+            .map(|_| DeletePartitionState {
+                finished: false,
+                count: 0,
+                row_ids: Vec::new(),
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_execute(
+        &self,
+        _cx: &mut Context,
+        _operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+        input: &mut Batch,
+        output: &mut Batch,
+    ) -> Result<PollExecute> {
+        if state.finished {
+            output.arrays[0].set_value(0, &state.count.into())?;
+            output.set_num_rows(1)?;
+            return Ok(PollExecute::Exhausted);
+        }
+
+        UnaryExecutor::for_each_flat::<PhysicalI64, _>(
+            &input.arrays[0],
+            0..input.num_rows(),
+            |_, row_id| {
+                if let Some(&row_id) = row_id {
+                    state.row_ids.push(row_id as usize);
+                }
+            },
+        )?;
+
+        Ok(PollExecute::NeedsMore)
+    }
+
+    fn poll_finalize_execute(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+    ) -> Result<PollFinalize> {
+        let mut inner = operator_state.inner.lock();
+        inner.row_ids.append(&mut state.row_ids);
+
+        let remaining = inner.remaining.dec_by_one()?;
+        if remaining == 0 {
+            let mut row_ids = std::mem::take(&mut inner.row_ids);
+            row_ids.sort_unstable();
+            row_ids.dedup();
+
+            let mut delete_state = operator_state
+                .datatable
+                .init_delete_state(&self.transaction);
+            let deleted = operator_state
+                .datatable
+                .delete_rows(&mut delete_state, &row_ids)?;
+            state.count = deleted as i64;
+        }
+
+        state.finished = true;
+
+        Ok(PollFinalize::NeedsDrain)
+    }
+}
+
+impl Explainable for PhysicalDelete {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf).build()
+    }
+}
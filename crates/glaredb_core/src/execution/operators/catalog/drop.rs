@@ -7,11 +7,11 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::catalog::Catalog;
 use crate::catalog::drop::DropInfo;
-use crate::catalog::entry::CatalogEntryInner;
-use crate::catalog::memory::MemoryCatalog;
+use crate::catalog::entry::{CatalogEntryInner, TableStorage};
 use crate::execution::operators::{BaseOperator, ExecutionProperties, PollPull, PullOperator};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
-use crate::storage::storage_manager::StorageManager;
+use crate::storage::Storage;
+use crate::transaction::Transaction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DropPartitionState {
@@ -21,8 +21,9 @@ pub enum DropPartitionState {
 
 #[derive(Debug)]
 pub struct PhysicalDrop {
-    pub(crate) storage: Arc<StorageManager>,
-    pub(crate) catalog: Arc<MemoryCatalog>,
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) storage: Arc<dyn Storage>,
+    pub(crate) catalog: Arc<dyn Catalog>,
     pub(crate) info: DropInfo,
 }
 
@@ -64,13 +65,14 @@ impl PullOperator for PhysicalDrop {
         output: &mut Batch,
     ) -> Result<PollPull> {
         if *state == DropPartitionState::Drop {
-            // TODO: Trasactions and stuff.
-            let ent = self.catalog.drop_entry(&self.info)?;
+            let ent = self.catalog.drop_entry(&self.transaction, &self.info)?;
 
             if let Some(ent) = ent {
                 if let CatalogEntryInner::Table(table) = &ent.entry {
-                    // TODO: Drop on commit instead of here.
-                    self.storage.drop_table(table.storage_id)?;
+                    // Storage is only dropped once the transaction commits.
+                    if let TableStorage::Managed(storage_id) = &table.storage {
+                        self.storage.drop_table(&self.transaction, *storage_id)?;
+                    }
                 }
             }
         }
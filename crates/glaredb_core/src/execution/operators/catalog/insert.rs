@@ -14,8 +14,9 @@ use crate::execution::operators::{
     PollFinalize,
 };
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::storage::Storage;
 use crate::storage::datatable::{DataTable, DataTableAppendState};
-use crate::storage::storage_manager::StorageManager;
+use crate::transaction::Transaction;
 
 #[derive(Debug)]
 pub struct InsertOperatorState {
@@ -31,7 +32,8 @@ pub struct InsertPartitionState {
 
 #[derive(Debug)]
 pub struct PhysicalInsert {
-    pub(crate) storage: Arc<StorageManager>,
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) storage: Arc<dyn Storage>,
     pub(crate) entry: Arc<CatalogEntry>,
 }
 
@@ -42,7 +44,7 @@ impl BaseOperator for PhysicalInsert {
 
     fn create_operator_state(&self, _props: ExecutionProperties) -> Result<Self::OperatorState> {
         let ent = self.entry.try_as_table_entry()?;
-        let datatable = self.storage.get_table(ent.storage_id)?;
+        let datatable = self.storage.get_table(ent.storage_id()?)?;
 
         Ok(InsertOperatorState { datatable })
     }
@@ -66,7 +68,9 @@ impl ExecuteOperator for PhysicalInsert {
             .map(|_| InsertPartitionState {
                 finished: false,
                 count: 0,
-                state: operator_state.datatable.init_append_state(),
+                state: operator_state
+                    .datatable
+                    .init_append_state(&self.transaction),
             })
             .collect();
 
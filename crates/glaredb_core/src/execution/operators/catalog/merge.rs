@@ -0,0 +1,426 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::task::Context;
+
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+
+use crate::arrays::array::physical_type::{PhysicalBool, PhysicalI64};
+use crate::arrays::batch::Batch;
+use crate::arrays::collection::META_PROJECTION_ROWID;
+use crate::arrays::collection::concurrent::{
+    ColumnCollectionAppendState,
+    ConcurrentColumnCollection,
+};
+use crate::arrays::datatype::DataType;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::field::Field;
+use crate::arrays::scalar::ScalarValue;
+use crate::catalog::create::UniqueConstraint;
+use crate::catalog::entry::CatalogEntry;
+use crate::execution::operators::util::delayed_count::DelayedPartitionCount;
+use crate::execution::operators::{
+    BaseOperator,
+    ExecuteOperator,
+    ExecutionProperties,
+    PollExecute,
+    PollFinalize,
+};
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::logical::logical_merge::ConflictBehavior;
+use crate::storage::Storage;
+use crate::storage::datatable::DataTable;
+use crate::storage::projections::Projections;
+use crate::transaction::{Snapshot, Transaction};
+
+/// Index of the row id column in the input.
+const ROW_ID_IDX: usize = 0;
+/// Index of the insert flag column in the input.
+const INSERT_IDX: usize = 1;
+/// Number of columns in the input before the table values.
+const VALUES_OFFSET: usize = 2;
+
+/// Change to a single row in the input.
+#[derive(Debug, Clone, Copy)]
+struct RowChange {
+    /// If this row deletes an existing row.
+    delete: bool,
+    /// If the values for this row should be inserted.
+    insert: bool,
+}
+
+#[derive(Debug)]
+pub struct MergeOperatorState {
+    datatable: Arc<DataTable>,
+    /// Row ids, insert flags, and values for every row in the input.
+    changes: ConcurrentColumnCollection,
+    batch_size: usize,
+    remaining: Mutex<DelayedPartitionCount>,
+}
+
+#[derive(Debug)]
+pub struct MergePartitionState {
+    finished: bool,
+    count: i64,
+    state: ColumnCollectionAppendState,
+}
+
+/// Deletes, updates, and inserts rows in a table.
+///
+/// The input contains the row id of an existing row to delete (NULL for new
+/// rows), a flag for if the values should be inserted, followed by the values
+/// for every column in the table. An update is a row with both a row id and the
+/// insert flag set.
+///
+/// Changes are applied once all partitions have finished reading their input.
+/// Unique constraints on the table are checked against the rows in the table
+/// that aren't being deleted before any changes are made.
+#[derive(Debug)]
+pub struct PhysicalMerge {
+    pub(crate) transaction: Arc<Transaction>,
+    pub(crate) storage: Arc<dyn Storage>,
+    pub(crate) entry: Arc<CatalogEntry>,
+    pub(crate) conflict: ConflictBehavior,
+}
+
+impl BaseOperator for PhysicalMerge {
+    const OPERATOR_NAME: &str = "Merge";
+
+    type OperatorState = MergeOperatorState;
+
+    fn create_operator_state(&self, props: ExecutionProperties) -> Result<Self::OperatorState> {
+        let ent = self.entry.try_as_table_entry()?;
+        let datatable = self.storage.get_table(ent.storage_id()?)?;
+
+        let datatypes = [DataType::int64(), DataType::boolean()]
+            .into_iter()
+            .chain(datatable.datatypes().iter().cloned());
+        let changes = ConcurrentColumnCollection::new(datatypes, 4, props.batch_size);
+
+        Ok(MergeOperatorState {
+            datatable,
+            changes,
+            batch_size: props.batch_size,
+            remaining: Mutex::new(DelayedPartitionCount::uninit()),
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        const OUTPUT_TYPES: &[DataType] = &[DataType::int64()];
+        OUTPUT_TYPES
+    }
+}
+
+impl ExecuteOperator for PhysicalMerge {
+    type PartitionExecuteState = MergePartitionState;
+
+    fn create_partition_execute_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionExecuteState>> {
+        operator_state.remaining.lock().set(partitions)?;
+
+        let states = (0..partitions)
+            .map(|_| MergePartitionState {
+                finished: false,
+                count: 0,
+                state: operator_state.changes.init_append_state(),
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_execute(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+        input: &mut Batch,
+        output: &mut Batch,
+    ) -> Result<PollExecute> {
+        if state.finished {
+            output.arrays[0].set_value(0, &state.count.into())?;
+            output.set_num_rows(1)?;
+            return Ok(PollExecute::Exhausted);
+        }
+
+        operator_state
+            .changes
+            .append_batch(&mut state.state, input)?;
+
+        Ok(PollExecute::NeedsMore)
+    }
+
+    fn poll_finalize_execute(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+    ) -> Result<PollFinalize> {
+        operator_state.changes.flush(&mut state.state)?;
+        state.finished = true;
+
+        let remaining = operator_state.remaining.lock().dec_by_one()?;
+        if remaining == 0 {
+            state.count = self.apply_changes(operator_state)? as i64;
+        }
+
+        Ok(PollFinalize::NeedsDrain)
+    }
+}
+
+impl PhysicalMerge {
+    /// Apply all collected changes to the table, returning the number of rows
+    /// modified.
+    fn apply_changes(&self, operator_state: &MergeOperatorState) -> Result<usize> {
+        let datatable = &operator_state.datatable;
+        let changes = &operator_state.changes;
+        let constraints = &self.entry.try_as_table_entry()?.unique_constraints;
+
+        let _guard = datatable.lock_writes();
+
+        let projections = Projections::new(0..changes.datatypes().len());
+        let mut scanned = Batch::new(changes.datatypes().to_vec(), operator_state.batch_size)?;
+
+        // Collect the rows being deleted, and which rows should be inserted.
+        let mut row_ids = Vec::new();
+        let mut deleted = HashSet::new();
+        let mut rows = Vec::with_capacity(changes.flushed_rows());
+        let mut scan_state = changes.init_scan_state();
+        while changes.scan(&projections, &mut scan_state, &mut scanned)? > 0 {
+            let offset = rows.len();
+            let mut duplicate = false;
+            UnaryExecutor::for_each_flat::<PhysicalI64, _>(
+                &scanned.arrays[ROW_ID_IDX],
+                0..scanned.num_rows(),
+                |_, row_id| {
+                    if let Some(&row_id) = row_id {
+                        let row_id = row_id as usize;
+                        duplicate |= !deleted.insert(row_id);
+                        row_ids.push(row_id);
+                    }
+                    rows.push(RowChange {
+                        delete: row_id.is_some(),
+                        insert: false,
+                    });
+                },
+            )?;
+            if duplicate {
+                return Err(DbError::new(
+                    "Cannot modify the same row in a table more than once",
+                ));
+            }
+
+            UnaryExecutor::for_each_flat::<PhysicalBool, _>(
+                &scanned.arrays[INSERT_IDX],
+                0..scanned.num_rows(),
+                |idx, insert| rows[offset + idx].insert = insert.copied().unwrap_or(false),
+            )?;
+        }
+
+        if !constraints.is_empty() {
+            self.check_constraints(operator_state, &deleted, &mut rows)?;
+        }
+
+        let mut delete_state = datatable.init_delete_state(&self.transaction);
+        datatable.delete_rows(&mut delete_state, &row_ids)?;
+
+        let mut values = Batch::new(datatable.datatypes().to_vec(), operator_state.batch_size)?;
+        let mut append_state = datatable.init_append_state(&self.transaction);
+        let mut scan_state = changes.init_scan_state();
+        let mut offset = 0;
+        while changes.scan(&projections, &mut scan_state, &mut scanned)? > 0 {
+            let num_rows = scanned.num_rows();
+            let selection: Vec<_> = (0..num_rows)
+                .filter(|&idx| rows[offset + idx].insert)
+                .collect();
+            offset += num_rows;
+
+            if selection.is_empty() {
+                continue;
+            }
+
+            for idx in 0..values.arrays.len() {
+                values.clone_array_from(idx, (&mut scanned, idx + VALUES_OFFSET))?;
+            }
+            values.set_num_rows(num_rows)?;
+            values.select(selection.iter().copied())?;
+
+            datatable.append_batch(&mut append_state, &values)?;
+        }
+        datatable.flush(&mut append_state)?;
+
+        Ok(rows.iter().filter(|row| row.delete || row.insert).count())
+    }
+
+    /// Check that the rows being inserted don't violate any unique
+    /// constraints.
+    ///
+    /// New rows that conflict are either skipped or cause an error depending on
+    /// the conflict behavior. Updated rows that conflict always error.
+    fn check_constraints(
+        &self,
+        operator_state: &MergeOperatorState,
+        deleted: &HashSet<usize>,
+        rows: &mut [RowChange],
+    ) -> Result<()> {
+        let ent = self.entry.try_as_table_entry()?;
+        let constraints = &ent.unique_constraints;
+
+        // Keys for the rows remaining in the table.
+        //
+        // Includes rows inserted by uncommitted transactions, otherwise
+        // concurrent transactions could both insert the same key.
+        let snapshot = Snapshot::dirty(self.transaction.id());
+        let mut existing = Vec::with_capacity(constraints.len());
+        for constraint in constraints {
+            existing.push(existing_keys(
+                operator_state,
+                snapshot,
+                constraint,
+                deleted,
+            )?);
+        }
+
+        let changes = &operator_state.changes;
+        let projections = Projections::new(0..changes.datatypes().len());
+        let mut scanned = Batch::new(changes.datatypes().to_vec(), operator_state.batch_size)?;
+        let mut scan_state = changes.init_scan_state();
+        let mut offset = 0;
+
+        while changes.scan(&projections, &mut scan_state, &mut scanned)? > 0 {
+            'rows: for row in 0..scanned.num_rows() {
+                let change = &mut rows[offset + row];
+                if !change.insert {
+                    continue;
+                }
+
+                let mut keys = Vec::with_capacity(constraints.len());
+                for (constraint_idx, constraint) in constraints.iter().enumerate() {
+                    let key = row_key(&scanned, row, constraint)?;
+                    if key.iter().any(|v| v.is_null()) {
+                        if constraint.primary_key {
+                            return Err(DbError::new(format!(
+                                "NULL value in primary key {}",
+                                format_columns(&ent.columns, constraint),
+                            )));
+                        }
+                        // NULLs never conflict.
+                        keys.push(None);
+                        continue;
+                    }
+
+                    if existing[constraint_idx].contains(&key) {
+                        let skip = match self.conflict {
+                            ConflictBehavior::Error => false,
+                            ConflictBehavior::DoNothing { constraint } => {
+                                !change.delete && constraint.is_none_or(|idx| idx == constraint_idx)
+                            }
+                        };
+                        if skip {
+                            change.insert = false;
+                            continue 'rows;
+                        }
+
+                        return Err(DbError::new(format!(
+                            "Duplicate key {}=({}) violates unique constraint",
+                            format_columns(&ent.columns, constraint),
+                            key.iter()
+                                .map(|v| v.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        )));
+                    }
+
+                    keys.push(Some(key));
+                }
+
+                // Row will be inserted, later rows must not conflict with it.
+                for (set, key) in existing.iter_mut().zip(keys) {
+                    if let Some(key) = key {
+                        set.insert(key);
+                    }
+                }
+            }
+
+            offset += scanned.num_rows();
+        }
+
+        Ok(())
+    }
+}
+
+/// Collect the keys for a constraint from the rows in the table, excluding
+/// deleted rows and keys containing NULLs.
+fn existing_keys(
+    operator_state: &MergeOperatorState,
+    snapshot: Snapshot,
+    constraint: &UniqueConstraint,
+    deleted: &HashSet<usize>,
+) -> Result<HashSet<Vec<ScalarValue>>> {
+    let datatable = &operator_state.datatable;
+
+    let projections =
+        Projections::new_with_meta(constraint.columns.iter().copied(), [META_PROJECTION_ROWID]);
+    let datatypes: Vec<_> = constraint
+        .columns
+        .iter()
+        .map(|&idx| datatable.datatypes()[idx].clone())
+        .chain([DataType::int64()])
+        .collect();
+    let mut batch = Batch::new(datatypes, operator_state.batch_size)?;
+    let row_id_idx = constraint.columns.len();
+
+    let mut keys = HashSet::new();
+    let mut scan_state = datatable.init_scan_state(snapshot);
+    while datatable.scan(&projections, &mut scan_state, &mut batch)? > 0 {
+        for row in 0..batch.num_rows() {
+            let row_id = batch.arrays[row_id_idx].get_value(row)?.try_as_i64()?;
+            if deleted.contains(&(row_id as usize)) {
+                continue;
+            }
+
+            let key = (0..constraint.columns.len())
+                .map(|idx| Ok(batch.arrays[idx].get_value(row)?.into_owned()))
+                .collect::<Result<Vec<_>>>()?;
+            if key.iter().any(|v| v.is_null()) {
+                continue;
+            }
+
+            keys.insert(key);
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Get the key for a constraint from a row in the input.
+fn row_key(batch: &Batch, row: usize, constraint: &UniqueConstraint) -> Result<Vec<ScalarValue>> {
+    constraint
+        .columns
+        .iter()
+        .map(|&idx| {
+            Ok(batch.arrays[idx + VALUES_OFFSET]
+                .get_value(row)?
+                .into_owned())
+        })
+        .collect()
+}
+
+fn format_columns(columns: &[Field], constraint: &UniqueConstraint) -> String {
+    let names: Vec<_> = constraint
+        .columns
+        .iter()
+        .map(|&idx| columns[idx].name.as_str())
+        .collect();
+    format!("({})", names.join(", "))
+}
+
+impl Explainable for PhysicalMerge {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf).build()
+    }
+}
@@ -2,5 +2,7 @@ pub mod create_schema;
 pub mod create_table;
 pub mod create_table_as;
 pub mod create_view;
+pub mod delete;
 pub mod drop;
 pub mod insert;
+pub mod merge;
@@ -0,0 +1,290 @@
+use std::task::{Context, Poll};
+
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::execution::operators::util::delayed_count::DelayedPartitionCount;
+use crate::execution::operators::util::partition_wakers::PartitionWakers;
+use crate::execution::operators::{
+    BaseOperator,
+    ExecuteOperator,
+    ExecutionProperties,
+    PollExecute,
+    PollFinalize,
+};
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::functions::copy_to::{AnyCopyToSink, PlannedCopyToFunction};
+use crate::runtime::filesystem::{
+    AnyFile,
+    FileSystemFuture,
+    FileSystemWithState,
+    OpenFlags,
+};
+
+#[derive(Debug)]
+pub struct CopyToOperatorState {
+    inner: Mutex<OperatorStateInner>,
+}
+
+#[derive(Debug)]
+struct OperatorStateInner {
+    file: FileState,
+    /// Sink for encoding batches.
+    sink: AnyCopyToSink,
+    /// Encoded bytes waiting to be written to the file.
+    buf: Vec<u8>,
+    /// Number of bytes in `buf` that have already been written.
+    buf_written: usize,
+    /// If we've called finish on the sink.
+    sink_finished: bool,
+    /// Partitions that still need to finalize. The last partition to finalize
+    /// is responsible for finishing and flushing the file.
+    remaining: DelayedPartitionCount,
+    /// Wakers for partitions waiting on some other partition to finish opening
+    /// the file or writing the buffer.
+    wakers: PartitionWakers,
+}
+
+enum FileState {
+    /// File not yet opened.
+    Uninit,
+    /// File currently being opened.
+    Opening(FileSystemFuture<'static, Result<AnyFile>>),
+    /// File ready for writing.
+    Open(AnyFile),
+}
+
+impl std::fmt::Debug for FileState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uninit => write!(f, "Uninit"),
+            Self::Opening(_) => write!(f, "Opening"),
+            Self::Open(file) => f.debug_tuple("Open").field(file).finish(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CopyToPartitionState {
+    partition_idx: usize,
+    /// Number of rows this partition encoded.
+    count: i64,
+    /// If this partition has been counted towards finalization.
+    finalized: bool,
+    /// If we're done and only need to emit the count.
+    finished: bool,
+}
+
+/// Writes its input to a single file.
+///
+/// All partitions encode into the same sink, and writes to the file are
+/// serialized through the operator state. Each partition outputs the number of
+/// rows it wrote.
+#[derive(Debug)]
+pub struct PhysicalCopyTo {
+    pub(crate) location: String,
+    pub(crate) copy_to: PlannedCopyToFunction,
+    pub(crate) filesystem: FileSystemWithState,
+}
+
+impl PhysicalCopyTo {
+    pub fn new(
+        location: impl Into<String>,
+        copy_to: PlannedCopyToFunction,
+        filesystem: FileSystemWithState,
+    ) -> Self {
+        PhysicalCopyTo {
+            location: location.into(),
+            copy_to,
+            filesystem,
+        }
+    }
+}
+
+impl BaseOperator for PhysicalCopyTo {
+    const OPERATOR_NAME: &str = "CopyTo";
+
+    type OperatorState = CopyToOperatorState;
+
+    fn create_operator_state(&self, _props: ExecutionProperties) -> Result<Self::OperatorState> {
+        let sink = self.copy_to.call_create_sink()?;
+
+        Ok(CopyToOperatorState {
+            inner: Mutex::new(OperatorStateInner {
+                file: FileState::Uninit,
+                sink,
+                buf: Vec::new(),
+                buf_written: 0,
+                sink_finished: false,
+                remaining: DelayedPartitionCount::uninit(),
+                wakers: PartitionWakers::empty(), // Init when creating partition states.
+            }),
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        const OUTPUT_TYPES: &[DataType] = &[DataType::int64()];
+        OUTPUT_TYPES
+    }
+}
+
+impl ExecuteOperator for PhysicalCopyTo {
+    type PartitionExecuteState = CopyToPartitionState;
+
+    fn create_partition_execute_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionExecuteState>> {
+        let mut inner = operator_state.inner.lock();
+        inner.wakers.init_for_partitions(partitions);
+        inner.remaining.set(partitions)?;
+
+        let states = (0..partitions)
+            .map(|partition_idx| CopyToPartitionState {
+                partition_idx,
+                count: 0,
+                finalized: false,
+                finished: false,
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_execute(
+        &self,
+        cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+        input: &mut Batch,
+        output: &mut Batch,
+    ) -> Result<PollExecute> {
+        if state.finished {
+            output.arrays[0].set_value(0, &state.count.into())?;
+            output.set_num_rows(1)?;
+            return Ok(PollExecute::Exhausted);
+        }
+
+        let mut inner = operator_state.inner.lock();
+
+        // Write out anything previously encoded before encoding more. Keeps
+        // the buffer from growing unbounded if the file is slow.
+        if self.poll_write_buffered(cx, &mut inner)?.is_pending() {
+            inner.wakers.store(cx.waker(), state.partition_idx);
+            return Ok(PollExecute::Pending);
+        }
+
+        let inner = &mut *inner;
+        inner.sink.call_encode(input, &mut inner.buf)?;
+        state.count += input.num_rows() as i64;
+
+        Ok(PollExecute::NeedsMore)
+    }
+
+    fn poll_finalize_execute(
+        &self,
+        cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+    ) -> Result<PollFinalize> {
+        let mut inner = operator_state.inner.lock();
+
+        if !state.finalized {
+            state.finalized = true;
+            let remaining = inner.remaining.dec_by_one()?;
+            if remaining != 0 {
+                // Some other partition will finish the file.
+                state.finished = true;
+                return Ok(PollFinalize::NeedsDrain);
+            }
+        }
+
+        // We're the last partition, finish up the file.
+        if !inner.sink_finished {
+            let inner = &mut *inner;
+            inner.sink.call_finish(&mut inner.buf)?;
+            inner.sink_finished = true;
+        }
+
+        if self.poll_write_buffered(cx, &mut inner)?.is_pending() {
+            return Ok(PollFinalize::Pending);
+        }
+
+        let file = match &mut inner.file {
+            FileState::Open(file) => file,
+            _ => unreachable!("file opened when writing buffer"),
+        };
+        if file.call_poll_flush(cx)?.is_pending() {
+            return Ok(PollFinalize::Pending);
+        }
+
+        state.finished = true;
+
+        Ok(PollFinalize::NeedsDrain)
+    }
+}
+
+impl PhysicalCopyTo {
+    /// Writes all buffered bytes to the file, opening the file if needed.
+    ///
+    /// The file is always opened even if there's nothing to write so that
+    /// COPY TO with no input still produces a file.
+    ///
+    /// Wakes up all waiting partitions once the buffer is written.
+    fn poll_write_buffered(
+        &self,
+        cx: &mut Context,
+        inner: &mut OperatorStateInner,
+    ) -> Result<Poll<()>> {
+        loop {
+            match &mut inner.file {
+                FileState::Uninit => {
+                    let fut = self.filesystem.open_static(
+                        OpenFlags::new([OpenFlags::WRITE, OpenFlags::CREATE, OpenFlags::TRUNCATE]),
+                        self.location.clone(),
+                    );
+                    inner.file = FileState::Opening(fut);
+                }
+                FileState::Opening(fut) => match fut.as_mut().poll(cx) {
+                    Poll::Ready(file) => inner.file = FileState::Open(file?),
+                    Poll::Pending => return Ok(Poll::Pending),
+                },
+                FileState::Open(file) => {
+                    while inner.buf_written < inner.buf.len() {
+                        match file.call_poll_write(cx, &inner.buf[inner.buf_written..])? {
+                            Poll::Ready(0) => {
+                                return Err(DbError::new(format!(
+                                    "Failed to write to '{}', wrote zero bytes",
+                                    self.location
+                                )));
+                            }
+                            Poll::Ready(n) => inner.buf_written += n,
+                            Poll::Pending => return Ok(Poll::Pending),
+                        }
+                    }
+
+                    if !inner.buf.is_empty() {
+                        inner.buf.clear();
+                        inner.buf_written = 0;
+                    }
+                    inner.wakers.wake_all();
+
+                    return Ok(Poll::Ready(()));
+                }
+            }
+        }
+    }
+}
+
+impl Explainable for PhysicalCopyTo {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf)
+            .with_value("format", self.copy_to.name)
+            .with_value("location", &self.location)
+            .build()
+    }
+}
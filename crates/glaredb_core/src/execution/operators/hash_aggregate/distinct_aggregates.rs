@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::sync::Arc;
 
 use glaredb_error::Result;
 
@@ -10,6 +11,7 @@ use super::hash_table::partitioned::{
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::execution::operators::hash_aggregate::Aggregates;
+use crate::execution::spill::SpillContext;
 use crate::expr::physical::PhysicalAggregateExpression;
 use crate::expr::physical::column_expr::PhysicalColumnExpr;
 
@@ -166,11 +168,12 @@ impl DistinctCollection {
     pub fn create_operator_state(
         &self,
         batch_size: usize,
+        spill: Option<Arc<SpillContext>>,
     ) -> Result<DistinctCollectionOperatorState> {
         let states = self
             .tables
             .iter()
-            .map(|table| table.table.create_operator_state(batch_size))
+            .map(|table| table.table.create_operator_state(batch_size, spill.clone()))
             .collect::<Result<Vec<_>>>()?;
 
         Ok(DistinctCollectionOperatorState { states })
@@ -300,7 +303,7 @@ mod tests {
         assert_eq!(1, collection.num_distinct_tables());
         assert_eq!(&[0], collection.aggregates_for_table(0));
 
-        let op_state = collection.create_operator_state(16).unwrap();
+        let op_state = collection.create_operator_state(16, None).unwrap();
         let mut part_states = collection.create_partition_states(&op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
@@ -333,7 +336,7 @@ mod tests {
         assert_eq!(1, collection.num_distinct_tables());
         assert_eq!(&[0], collection.aggregates_for_table(0));
 
-        let op_state = collection.create_operator_state(16).unwrap();
+        let op_state = collection.create_operator_state(16, None).unwrap();
         let mut part_states = collection.create_partition_states(&op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
@@ -366,7 +369,7 @@ mod tests {
         assert_eq!(1, collection.num_distinct_tables());
         assert_eq!(&[0], collection.aggregates_for_table(0));
 
-        let op_state = collection.create_operator_state(16).unwrap();
+        let op_state = collection.create_operator_state(16, None).unwrap();
         let mut part_states = collection.create_partition_states(&op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
@@ -407,7 +410,7 @@ mod tests {
         assert_eq!(&[0], collection.aggregates_for_table(0));
         assert_eq!(&[1], collection.aggregates_for_table(1));
 
-        let op_state = collection.create_operator_state(16).unwrap();
+        let op_state = collection.create_operator_state(16, None).unwrap();
         let mut part_states = collection.create_partition_states(&op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
@@ -455,7 +458,7 @@ mod tests {
         assert_eq!(1, collection.num_distinct_tables());
         assert_eq!(&[0, 1], collection.aggregates_for_table(0));
 
-        let op_state = collection.create_operator_state(16).unwrap();
+        let op_state = collection.create_operator_state(16, None).unwrap();
         let mut part_states = collection.create_partition_states(&op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
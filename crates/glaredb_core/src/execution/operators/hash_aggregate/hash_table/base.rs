@@ -126,6 +126,11 @@ impl BaseHashTable {
         self.data.num_groups()
     }
 
+    /// Returns the approximate number of bytes used by this table.
+    pub fn size_bytes(&self) -> usize {
+        self.directory.size_bytes() + self.data.size_bytes()
+    }
+
     /// Insert groups into the table with precomputed hash values.
     pub fn insert_with_hashes(
         &mut self,
@@ -62,6 +62,11 @@ impl Directory {
         self.entries.len()
     }
 
+    /// Returns the number of bytes allocated for the entries.
+    pub fn size_bytes(&self) -> usize {
+        self.entries.len() * std::mem::size_of::<Entry>()
+    }
+
     /// Resizes the directory to at least `new_capacity`.
     ///
     /// This will ensure the new capacity of the directory is a power of two.
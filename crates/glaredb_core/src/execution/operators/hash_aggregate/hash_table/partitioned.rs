@@ -3,6 +3,7 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::{Arc, atomic};
 
 use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
 
 use super::base::{BaseHashTable, BaseHashTableInsertState};
 use crate::arrays::array::Array;
@@ -14,10 +15,11 @@ use crate::arrays::datatype::DataType;
 use crate::arrays::row::aggregate_layout::AggregateLayout;
 use crate::arrays::row::row_scan::RowScanState;
 use crate::arrays::scalar::ScalarValue;
-use crate::buffer::buffer_manager::DefaultBufferManager;
+use crate::buffer::buffer_manager::{DefaultBufferManager, MemoryReservation};
 use crate::execution::operators::hash_aggregate::Aggregates;
 use crate::execution::operators::hash_aggregate::grouping_value::compute_grouping_value;
 use crate::execution::operators::hash_aggregate::hash_table::base::NO_GROUPS_HASH_VALUE;
+use crate::execution::spill::{SpillContext, SpillReader, SpillWriter};
 use crate::expr::physical::column_expr::PhysicalColumnExpr;
 use crate::util::cell::UnsafeSyncOnceCell;
 use crate::util::iter::IntoExactSizeIterator;
@@ -68,6 +70,38 @@ pub struct LocalBuildingState {
     groups: Batch,
     /// Batch for holding aggregate inputs.
     inputs: Batch,
+    /// Memory reserved for the local tables.
+    ///
+    /// None if there's no memory limit.
+    reservation: Option<MemoryReservation>,
+    /// If the local tables have gone over the memory limit.
+    ///
+    /// Once set, all further inputs for this partition are written to spill
+    /// files instead of the local tables.
+    spilling: bool,
+    /// Open spill files, one per distinct aggregate selection.
+    spills: Vec<LocalSpill>,
+}
+
+/// Spill files for inputs with a given aggregate selection.
+#[derive(Debug)]
+struct LocalSpill {
+    agg_selection: Vec<usize>,
+    /// Batch for holding rows to write for a single output partition.
+    ///
+    /// Layout: [groups, selected_agg_inputs, hash]
+    rows: Batch,
+    /// Writer per output partition, lazily created.
+    writers: Vec<Option<SpillWriter>>,
+}
+
+/// Rows spilled to disk for a single output partition.
+///
+/// Layout: [groups, selected_agg_inputs, hash]
+#[derive(Debug)]
+struct SpilledRows {
+    agg_selection: Vec<usize>,
+    reader: SpillReader,
 }
 
 #[derive(Debug)]
@@ -90,6 +124,8 @@ pub struct PartitionedHashTableOperatorState {
     result_types: Vec<DataType>,
     /// Batch size to use when scanning.
     batch_size: usize,
+    /// Context for spilling inputs if we go over the memory limit.
+    spill: Option<Arc<SpillContext>>,
     /// State initialized when we create the partition states.
     state: UnsafeSyncOnceCell<InitializedOperatorState>,
 }
@@ -103,6 +139,11 @@ struct InitializedOperatorState {
     flushed: Vec<FlushedTables>,
     /// The final aggregate tables.
     final_tables: Vec<FinalTable>,
+    /// Rows spilled by each partition, indexed by output partition.
+    ///
+    /// These are inserted into the final table for the partition after
+    /// merging the flushed tables.
+    spilled: Vec<Mutex<Vec<SpilledRows>>>,
 
     /// Remaining partitions that still need to flush their tables.
     ///
@@ -191,6 +232,7 @@ impl PartitionedHashTable {
     pub fn create_operator_state(
         &self,
         batch_size: usize,
+        spill: Option<Arc<SpillContext>>,
     ) -> Result<PartitionedHashTableOperatorState> {
         let result_types: Vec<_> = self
             .layout
@@ -202,6 +244,7 @@ impl PartitionedHashTable {
         Ok(PartitionedHashTableOperatorState {
             result_types,
             batch_size,
+            spill,
             state: UnsafeSyncOnceCell::new(), // Initialized when we create the partition states.
         })
     }
@@ -226,6 +269,7 @@ impl PartitionedHashTable {
                     table: UnsafeSyncOnceCell::new(),
                 })
                 .collect(),
+            spilled: (0..partitions).map(|_| Mutex::new(Vec::new())).collect(),
             remaining_flushers: AtomicUsize::new(partitions),
             remaining_mergers: AtomicUsize::new(partitions),
         };
@@ -246,13 +290,7 @@ impl PartitionedHashTable {
                 // still have a valid (and the same) hash.
                 let groups = Batch::new(self.group_types_no_hash_iter(), op_state.batch_size)?;
 
-                let agg_input_types: Vec<_> = self
-                    .layout
-                    .aggregates
-                    .iter()
-                    .flat_map(|agg| agg.columns.iter().map(|col| col.datatype.clone()))
-                    .collect();
-                let inputs = Batch::new(agg_input_types, op_state.batch_size)?;
+                let inputs = Batch::new(self.agg_input_types(), op_state.batch_size)?;
 
                 Ok(PartitionedHashTablePartitionState::Initializing(
                     LocalInitializingState {
@@ -276,6 +314,51 @@ impl PartitionedHashTable {
         types.iter().take(types.len() - 1).cloned()
     }
 
+    /// Returns the types of all aggregate inputs.
+    fn agg_input_types(&self) -> Vec<DataType> {
+        self.layout
+            .aggregates
+            .iter()
+            .flat_map(|agg| agg.columns.iter().map(|col| col.datatype.clone()))
+            .collect()
+    }
+
+    /// Returns the indices of the aggregate input columns used by the selected
+    /// aggregates.
+    fn selected_input_columns(&self, agg_selection: &[usize]) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(self.layout.aggregates.len());
+        let mut offset = 0;
+        for agg in &self.layout.aggregates {
+            offsets.push(offset);
+            offset += agg.columns.len();
+        }
+
+        agg_selection
+            .iter()
+            .flat_map(|&sel| {
+                let start = offsets[sel];
+                start..(start + self.layout.aggregates[sel].columns.len())
+            })
+            .collect()
+    }
+
+    /// Returns the types of rows written to spill files for the given aggregate
+    /// selection.
+    ///
+    /// Layout: [groups, selected_agg_inputs, hash]
+    fn spill_types(&self, agg_selection: &[usize]) -> Vec<DataType> {
+        let agg_input_types = self.agg_input_types();
+        self.group_types_no_hash_iter()
+            .into_exact_size_iter()
+            .chain(
+                self.selected_input_columns(agg_selection)
+                    .into_iter()
+                    .map(|col| agg_input_types[col].clone()),
+            )
+            .chain([DataType::uint64()])
+            .collect()
+    }
+
     /// Similar to `insert_partition_local`, but with the input batches already
     /// containing the groups and inputs in the right order.
     ///
@@ -344,7 +427,7 @@ impl PartitionedHashTable {
         state.groups.set_num_rows(distinct_input.num_rows())?;
         state.inputs.set_num_rows(distinct_input.num_rows())?;
 
-        self.insert_local(state, agg_selection)
+        self.insert_local(op_state, state, agg_selection)
     }
 
     /// Insert a batch into the local hash table.
@@ -394,7 +477,7 @@ impl PartitionedHashTable {
         state.groups.set_num_rows(input.num_rows())?;
         state.inputs.set_num_rows(input.num_rows())?;
 
-        self.insert_local(state, agg_selection)
+        self.insert_local(op_state, state, agg_selection)
     }
 
     /// Inserts a batch into this partition's local hash tables.
@@ -403,7 +486,15 @@ impl PartitionedHashTable {
     /// local build state from the aggregate input.
     ///
     /// Internally partitions the input.
-    fn insert_local(&self, state: &mut LocalBuildingState, agg_selection: &[usize]) -> Result<()> {
+    ///
+    /// If the local tables have gone over the memory limit, the partitioned
+    /// inputs are written to spill files instead.
+    fn insert_local(
+        &self,
+        op_state: &PartitionedHashTableOperatorState,
+        state: &mut LocalBuildingState,
+        agg_selection: &[usize],
+    ) -> Result<()> {
         let groups = &mut state.groups;
         let inputs = &mut state.inputs;
 
@@ -435,6 +526,10 @@ impl PartitionedHashTable {
 
         debug_assert_eq!(state.tables.len(), state.states.len());
 
+        if state.spilling {
+            return self.spill_local(op_state, state, agg_selection, hashes_arr);
+        }
+
         // Now insert into each partition table.
         for (partition_idx, (table, table_state)) in
             state.tables.iter_mut().zip(&mut state.states).enumerate()
@@ -457,6 +552,138 @@ impl PartitionedHashTable {
             table.insert_with_hashes(table_state, agg_selection, &groups, &inputs, &hashes_arr)?;
         }
 
+        if let (Some(spill), Some(reservation)) = (&op_state.spill, &mut state.reservation) {
+            let size: usize = state.tables.iter().map(|table| table.size_bytes()).sum();
+            if !reservation.try_resize(size) {
+                // Check against all inputs since later inserts may use a
+                // different aggregate selection.
+                let mut input_types: Vec<_> = self.group_types_no_hash_iter().into_iter().collect();
+                input_types.extend(self.agg_input_types());
+                spill.check_can_spill(&input_types)?;
+
+                state.spilling = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the current groups and inputs to this partition's spill files,
+    /// split by output partition.
+    fn spill_local(
+        &self,
+        op_state: &PartitionedHashTableOperatorState,
+        state: &mut LocalBuildingState,
+        agg_selection: &[usize],
+        hashes_arr: Array,
+    ) -> Result<()> {
+        let spill = op_state
+            .spill
+            .as_ref()
+            .ok_or_else(|| DbError::new("Missing spill context for spilling aggregate"))?;
+
+        let spill_idx = match state
+            .spills
+            .iter()
+            .position(|local| local.agg_selection == agg_selection)
+        {
+            Some(idx) => idx,
+            None => {
+                state.spills.push(LocalSpill {
+                    agg_selection: agg_selection.to_vec(),
+                    rows: Batch::new(self.spill_types(agg_selection), op_state.batch_size)?,
+                    writers: (0..state.tables.len()).map(|_| None).collect(),
+                });
+                state.spills.len() - 1
+            }
+        };
+        let local = &mut state.spills[spill_idx];
+
+        // [groups, selected_agg_inputs, hash]
+        let sources: Vec<&Array> = state
+            .groups
+            .arrays
+            .iter()
+            .chain(
+                self.selected_input_columns(agg_selection)
+                    .into_iter()
+                    .map(|col| &state.inputs.arrays[col]),
+            )
+            .chain([&hashes_arr])
+            .collect();
+
+        for (partition_idx, writer) in local.writers.iter_mut().enumerate() {
+            let row_sel = &state.partition_selector.buckets[partition_idx];
+            if row_sel.is_empty() {
+                continue;
+            }
+
+            local.rows.reset_for_write()?;
+            let mapping = row_sel
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(to, from)| (from, to));
+            for (src, dest) in sources.iter().zip(&mut local.rows.arrays) {
+                src.copy_rows(mapping.clone(), dest)?;
+            }
+            local.rows.set_num_rows(row_sel.len())?;
+
+            let writer = match writer {
+                Some(writer) => writer,
+                None => writer.insert(spill.create_file()?),
+            };
+            writer.write_batch(&local.rows)?;
+        }
+
+        Ok(())
+    }
+
+    /// Inserts rows spilled for this partition into the partition's global
+    /// table.
+    fn insert_spilled(
+        &self,
+        op_state: &PartitionedHashTableOperatorState,
+        global: &mut BaseHashTable,
+        insert_state: &mut BaseHashTableInsertState,
+        mut spilled: SpilledRows,
+    ) -> Result<()> {
+        let batch_size = op_state.batch_size;
+        let input_cols = self.selected_input_columns(&spilled.agg_selection);
+
+        let mut groups = Batch::new(self.group_types_no_hash_iter(), batch_size)?;
+        let mut inputs = Batch::new(self.agg_input_types(), batch_size)?;
+        let num_groups = groups.arrays.len();
+        let hash_idx = num_groups + input_cols.len();
+
+        let mut rows = Batch::new(self.spill_types(&spilled.agg_selection), batch_size)?;
+
+        while spilled.reader.read_batch(&mut rows)? {
+            let num_rows = rows.num_rows();
+
+            groups.reset_for_write()?;
+            inputs.reset_for_write()?;
+            for idx in 0..num_groups {
+                groups.clone_array_from(idx, (&mut rows, idx))?;
+            }
+            for (idx, &col) in input_cols.iter().enumerate() {
+                inputs.clone_array_from(col, (&mut rows, num_groups + idx))?;
+            }
+            groups.set_num_rows(num_rows)?;
+            inputs.set_num_rows(num_rows)?;
+
+            let mut hashes = Array::new(&DefaultBufferManager, DataType::uint64(), num_rows)?;
+            rows.arrays[hash_idx].copy_rows((0..num_rows).map(|idx| (idx, idx)), &mut hashes)?;
+
+            global.insert_with_hashes(
+                insert_state,
+                &spilled.agg_selection,
+                &groups,
+                &inputs,
+                &hashes,
+            )?;
+        }
+
         Ok(())
     }
 
@@ -480,6 +707,19 @@ impl PartitionedHashTable {
 
         debug_assert_eq!(building.tables.len(), op_state.get().flushed.len());
 
+        for local in building.spills.drain(..) {
+            for (partition_idx, writer) in local.writers.into_iter().enumerate() {
+                if let Some(writer) = writer {
+                    op_state.get().spilled[partition_idx]
+                        .lock()
+                        .push(SpilledRows {
+                            agg_selection: local.agg_selection.clone(),
+                            reader: writer.finish()?,
+                        });
+                }
+            }
+        }
+
         for (partition_idx, table) in building.tables.drain(..).enumerate() {
             // Get the flushed table for the _output_ partition.
             let flushed = &op_state.get().flushed[partition_idx];
@@ -575,6 +815,12 @@ impl PartitionedHashTable {
             global.merge_from(&mut insert_state, 0..self.layout.aggregates.len(), other)?;
         }
 
+        // Insert anything that was spilled for this partition.
+        let spilled = std::mem::take(&mut *op_state.get().spilled[partition_idx].lock());
+        for rows in spilled {
+            self.insert_spilled(op_state, &mut global, &mut insert_state, rows)?;
+        }
+
         // Now put it in the global state.
         let final_table = &op_state.get().final_tables[partition_idx];
         // SAFETY: As above, this partition should be the only one initialing
@@ -759,6 +1005,9 @@ impl PartitionedHashTable {
             states,
             groups: initializing.groups,
             inputs: initializing.inputs,
+            reservation: op_state.spill.as_ref().map(|spill| spill.new_reservation()),
+            spilling: false,
+            spills: Vec::new(),
         });
 
         Ok(())
@@ -946,7 +1195,7 @@ mod tests {
 
         let grouping_set: BTreeSet<usize> = [0].into();
         let table = PartitionedHashTable::try_new(&aggs, grouping_set).unwrap();
-        let op_state = table.create_operator_state(16).unwrap();
+        let op_state = table.create_operator_state(16, None).unwrap();
         let mut part_states = table.create_partition_states(&op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
@@ -992,7 +1241,7 @@ mod tests {
 
         let grouping_set: BTreeSet<usize> = [1].into(); // '1' relative to groups (real column index of 2)
         let table = PartitionedHashTable::try_new(&aggs, grouping_set).unwrap();
-        let mut op_state = table.create_operator_state(16).unwrap();
+        let mut op_state = table.create_operator_state(16, None).unwrap();
         let mut part_states = table.create_partition_states(&mut op_state, 1).unwrap();
         assert_eq!(1, part_states.len());
 
@@ -4,6 +4,7 @@ mod grouping_value;
 mod hash_table;
 
 use std::collections::BTreeSet;
+use std::sync::Arc;
 use std::task::Context;
 
 use distinct_aggregates::{
@@ -26,6 +27,7 @@ use super::util::partition_wakers::PartitionWakers;
 use super::{BaseOperator, ExecuteOperator, ExecutionProperties, PollExecute, PollFinalize};
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
+use crate::execution::spill::SpillContext;
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::physical::PhysicalAggregateExpression;
 use crate::expr::physical::column_expr::PhysicalColumnExpr;
@@ -152,10 +154,16 @@ pub struct PhysicalHashAggregate {
     pub(crate) output_types: Vec<DataType>,
     /// Distinct/non-distinct aggregate indices.
     pub(crate) agg_selection: AggregateSelection,
+    /// Context for spilling to disk if a memory limit is configured.
+    pub(crate) spill: Option<Arc<SpillContext>>,
 }
 
 impl PhysicalHashAggregate {
-    pub fn new(aggregates: Aggregates, grouping_sets: Vec<BTreeSet<usize>>) -> Self {
+    pub fn new(
+        aggregates: Aggregates,
+        grouping_sets: Vec<BTreeSet<usize>>,
+        spill: Option<Arc<SpillContext>>,
+    ) -> Self {
         let mut output_types = Vec::new();
 
         let agg_selection = AggregateSelection::new(&aggregates.aggregates);
@@ -175,6 +183,7 @@ impl PhysicalHashAggregate {
             aggregates,
             output_types,
             agg_selection,
+            spill,
         }
     }
 }
@@ -196,7 +205,7 @@ impl BaseOperator for PhysicalHashAggregate {
 
         let table_states = tables
             .iter()
-            .map(|table| table.create_operator_state(props.batch_size))
+            .map(|table| table.create_operator_state(props.batch_size, self.spill.clone()))
             .collect::<Result<Vec<_>>>()?;
 
         // Create distinct collection per grouping set.
@@ -221,7 +230,7 @@ impl BaseOperator for PhysicalHashAggregate {
 
         let distinct_states = distinct_collections
             .iter()
-            .map(|col| col.create_operator_state(props.batch_size))
+            .map(|col| col.create_operator_state(props.batch_size, self.spill.clone()))
             .collect::<Result<Vec<_>>>()?;
 
         let inner = HashAggregateOperatoreStateInner {
@@ -768,6 +777,7 @@ mod tests {
     use crate::functions::aggregate::builtin::sum::FUNCTION_SET_SUM;
     use crate::testutil::arrays::{assert_batches_eq, generate_batch};
     use crate::testutil::operator::OperatorWrapper;
+    use crate::testutil::spill::test_spill_context;
 
     #[test]
     fn hash_aggregate_single_partition() {
@@ -793,6 +803,7 @@ mod tests {
         let wrapper = OperatorWrapper::new(PhysicalHashAggregate::new(
             aggs,
             vec![[0].into_iter().collect()],
+            None,
         ));
 
         let props = ExecutionProperties { batch_size: 16 };
@@ -839,4 +850,93 @@ mod tests {
         assert_eq!(PollExecute::Exhausted, poll);
         assert_eq!(0, output.num_rows);
     }
+
+    #[test]
+    fn hash_aggregate_spill_single_partition() {
+        // SUM(col0) GROUP BY (col1)
+        let sum_agg = bind_aggregate_function(
+            &FUNCTION_SET_SUM,
+            vec![expr::column((0, 0), DataType::int64()).into()],
+        )
+        .unwrap();
+
+        let aggs = Aggregates {
+            groups: vec![(1, DataType::utf8()).into()],
+            grouping_functions: Vec::new(),
+            aggregates: vec![PhysicalAggregateExpression::new(
+                sum_agg,
+                [(0, DataType::int64())],
+            )],
+        };
+
+        // Limit small enough that everything after the first batch gets
+        // spilled.
+        let (spill, fs) = test_spill_context(1);
+        let wrapper = OperatorWrapper::new(PhysicalHashAggregate::new(
+            aggs,
+            vec![[0].into_iter().collect()],
+            Some(spill),
+        ));
+
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 1)
+            .unwrap();
+
+        let mut output = Batch::new(wrapper.operator.output_types.clone(), 16).unwrap();
+        let inputs = [
+            generate_batch!(
+                [1_i64, 2, 3, 4],
+                ["group_a", "group_b", "group_a", "group_c"],
+            ),
+            generate_batch!([5_i64, 6, 7], ["group_c", "group_d", "group_a"]),
+        ];
+        for mut input in inputs {
+            let poll = wrapper
+                .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+                .unwrap();
+            assert_eq!(PollExecute::NeedsMore, poll);
+        }
+        assert_eq!(1, fs.num_files());
+
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+
+        let mut input = Batch::new(wrapper.operator.output_types.clone(), 0).unwrap();
+        let mut rows = Vec::new();
+        loop {
+            let poll = wrapper
+                .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+                .unwrap();
+            for idx in 0..output.num_rows() {
+                rows.push((
+                    output.arrays[0].get_value(idx).unwrap().to_string(),
+                    output.arrays[1].get_value(idx).unwrap().to_string(),
+                ));
+            }
+            if poll == PollExecute::Exhausted {
+                break;
+            }
+            assert_eq!(PollExecute::HasMore, poll);
+        }
+
+        rows.sort();
+        let expected: Vec<_> = [
+            ("group_a", "11"),
+            ("group_b", "2"),
+            ("group_c", "9"),
+            ("group_d", "6"),
+        ]
+        .into_iter()
+        .map(|(group, sum)| (group.to_string(), sum.to_string()))
+        .collect();
+        assert_eq!(expected, rows);
+
+        // Spill files removed once read back in.
+        assert_eq!(0, fs.num_files());
+    }
 }
@@ -17,7 +17,7 @@ pub const fn needs_drain(join_type: JoinType) -> bool {
     }
 }
 
-/// Drain state for LEFT/OUTER/MARK joins.
+/// Drain state for LEFT/OUTER/SEMI/ANTI/MARK joins.
 #[derive(Debug)]
 pub struct HashTablePartitionDrainState {
     pub partition_idx: usize,
@@ -40,9 +40,12 @@ impl HashTablePartitionDrainState {
         output: &mut Batch,
     ) -> Result<()> {
         match table.join_type {
-            JoinType::LeftSemi => self.drain_left_semi(table, op_state, output),
+            JoinType::LeftSemi => self.drain_left_semi_anti(table, op_state, output, true),
+            JoinType::LeftAnti => self.drain_left_semi_anti(table, op_state, output, false),
             JoinType::LeftMark { .. } => self.drain_left_mark(table, op_state, output),
-            JoinType::Left => self.drain_left(table, op_state, output),
+            // FULL drains the same as LEFT, unmatched right rows are handled
+            // during the scan.
+            JoinType::Left | JoinType::Full => self.drain_left(table, op_state, output),
             other => Err(DbError::new(format!(
                 "Unexpected join type for drain: {other}"
             ))),
@@ -126,17 +129,22 @@ impl HashTablePartitionDrainState {
         Ok(())
     }
 
-    fn drain_left_semi(
+    /// Drains rows for LEFT SEMI and LEFT ANTI joins.
+    ///
+    /// If `matched` is true, only rows that matched will be drained (SEMI),
+    /// otherwise only rows that didn't match will be drained (ANTI).
+    fn drain_left_semi_anti(
         &mut self,
         table: &JoinHashTable,
         op_state: &HashTableOperatorState,
         output: &mut Batch,
+        matched: bool,
     ) -> Result<()> {
         output.reset_for_write()?;
 
-        // We want to drain matched rows. This guarantees we're only producing a
-        // single row per visited row.
-        self.load_row_ptrs(table, op_state, output, |did_match| did_match)?;
+        // Only drain rows with the wanted match state. This also guarantees
+        // we're only producing a single row per left row.
+        self.load_row_ptrs(table, op_state, output, |did_match| did_match == matched)?;
 
         debug_assert_eq!(output.arrays.len(), table.data_column_count);
 
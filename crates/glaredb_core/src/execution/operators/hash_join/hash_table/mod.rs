@@ -453,6 +453,44 @@ impl JoinHashTable {
         Ok(())
     }
 
+    /// Computes the hashes of the build-side join keys for the input.
+    ///
+    /// Produces the same hashes that `collect_build` would store for the rows.
+    pub fn build_hashes(
+        &self,
+        state: &mut HashTableBuildPartitionState,
+        input: &mut Batch,
+        hashes: &mut Vec<u64>,
+    ) -> Result<()> {
+        state
+            .join_keys_evaluator
+            .eval_batch(input, input.selection(), &mut state.join_keys)?;
+
+        let hash_inputs = self
+            .equality_columns
+            .iter()
+            .map(|&idx| &state.join_keys.arrays[idx]);
+
+        hashes.clear();
+        hashes.resize(input.num_rows(), 0);
+        hash_many_arrays(hash_inputs, 0..input.num_rows(), hashes)?;
+
+        Ok(())
+    }
+
+    /// Returns the number of bytes collected by this partition so far.
+    pub fn build_size_bytes(
+        &self,
+        op_state: &HashTableOperatorState,
+        state: &HashTableBuildPartitionState,
+    ) -> usize {
+        // SAFETY: This is reading the row collection this partition is
+        // responsible for.
+        let row_collection =
+            unsafe { op_state.partitioned_row_collection().collections[state.partition_idx].get() };
+        row_collection.blocks().size_bytes()
+    }
+
     /// Indicates that this partition is finished building.
     ///
     /// Should be called for all partitions, and before we initialized the
@@ -895,4 +933,129 @@ mod tests {
         let expected = generate_batch!(["c"], [3], [3]);
         assert_batches_eq(&expected, &out);
     }
+
+    #[test]
+    fn left_anti_join_drain_unmatched() {
+        let table = JoinHashTable::try_new(
+            JoinType::LeftAnti,
+            [DataType::utf8(), DataType::int32()],
+            [DataType::int32()],
+            [HashJoinCondition {
+                left: PhysicalScalarExpression::Column((1, DataType::int32()).into()),
+                right: PhysicalScalarExpression::Column((0, DataType::int32()).into()),
+                op: ComparisonOperator::Eq,
+            }],
+            16,
+        )
+        .unwrap();
+        let op_state = table.create_operator_state().unwrap();
+        let mut build_states = table.create_build_partition_states(&op_state, 1).unwrap();
+
+        let mut input = generate_batch!(["a", "b", "c", "d"], [1, 2, 3, 3]);
+        table
+            .collect_build(&op_state, &mut build_states[0], &mut input)
+            .unwrap();
+        let is_last = table.finish_build(&op_state, &mut build_states[0]).unwrap();
+        assert!(is_last);
+
+        unsafe { table.init_directory(&op_state).unwrap() };
+        unsafe {
+            table
+                .process_hashes(&op_state, &mut build_states[0])
+                .unwrap()
+        };
+
+        let mut scan_states = table.create_probe_partition_states(&op_state, 1).unwrap();
+        let mut rhs = generate_batch!([3, 4]);
+        table
+            .probe(&op_state, &mut scan_states[0], &mut rhs)
+            .unwrap();
+
+        // Scanning only marks matches.
+        let mut out = Batch::new([DataType::utf8(), DataType::int32()], 16).unwrap();
+        scan_states[0]
+            .scan_next(&table, &op_state, &mut rhs, &mut out)
+            .unwrap();
+        assert_eq!(0, out.num_rows());
+
+        let mut drain_state = table
+            .create_drain_state_from_scan_state(&op_state, &mut scan_states[0])
+            .unwrap();
+        drain_state.drain_next(&table, &op_state, &mut out).unwrap();
+
+        let expected = generate_batch!(["a", "b"], [1, 2]);
+        assert_batches_eq(&expected, &out);
+
+        drain_state.drain_next(&table, &op_state, &mut out).unwrap();
+        assert_eq!(0, out.num_rows());
+    }
+
+    #[test]
+    fn full_join_scan_and_drain() {
+        let table = JoinHashTable::try_new(
+            JoinType::Full,
+            [DataType::utf8(), DataType::int32()],
+            [DataType::int32()],
+            [HashJoinCondition {
+                left: PhysicalScalarExpression::Column((1, DataType::int32()).into()),
+                right: PhysicalScalarExpression::Column((0, DataType::int32()).into()),
+                op: ComparisonOperator::Eq,
+            }],
+            16,
+        )
+        .unwrap();
+        let op_state = table.create_operator_state().unwrap();
+        let mut build_states = table.create_build_partition_states(&op_state, 1).unwrap();
+
+        let mut input = generate_batch!(["a", "b", "c"], [1, 2, 3]);
+        table
+            .collect_build(&op_state, &mut build_states[0], &mut input)
+            .unwrap();
+        let is_last = table.finish_build(&op_state, &mut build_states[0]).unwrap();
+        assert!(is_last);
+
+        unsafe { table.init_directory(&op_state).unwrap() };
+        unsafe {
+            table
+                .process_hashes(&op_state, &mut build_states[0])
+                .unwrap()
+        };
+
+        let mut scan_states = table.create_probe_partition_states(&op_state, 1).unwrap();
+        let mut rhs = generate_batch!([2, 4]);
+        table
+            .probe(&op_state, &mut scan_states[0], &mut rhs)
+            .unwrap();
+
+        let mut out =
+            Batch::new([DataType::utf8(), DataType::int32(), DataType::int32()], 16).unwrap();
+
+        // Matched rows.
+        scan_states[0]
+            .scan_next(&table, &op_state, &mut rhs, &mut out)
+            .unwrap();
+        let expected = generate_batch!(["b"], [2], [2]);
+        assert_batches_eq(&expected, &out);
+
+        // Unmatched rows from the right.
+        scan_states[0]
+            .scan_next(&table, &op_state, &mut rhs, &mut out)
+            .unwrap();
+        let expected = generate_batch!([None as Option<&str>], [None as Option<i32>], [4]);
+        assert_batches_eq(&expected, &out);
+
+        scan_states[0]
+            .scan_next(&table, &op_state, &mut rhs, &mut out)
+            .unwrap();
+        assert_eq!(0, out.num_rows());
+
+        // Unmatched rows from the left.
+        let mut drain_state = table
+            .create_drain_state_from_scan_state(&op_state, &mut scan_states[0])
+            .unwrap();
+        drain_state.drain_next(&table, &op_state, &mut out).unwrap();
+
+        let expected = generate_batch!(["a", "c"], [1, 3], [None as Option<i32>, None]);
+        assert_batches_eq(&expected, &out);
+    }
 }
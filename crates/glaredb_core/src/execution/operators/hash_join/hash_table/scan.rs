@@ -1,4 +1,4 @@
-use glaredb_error::{Result, not_implemented};
+use glaredb_error::Result;
 
 use super::{HashTableOperatorState, JoinHashTable};
 use crate::arrays::array::Array;
@@ -90,8 +90,12 @@ impl HashTablePartitionScanState {
                 // draining.
                 self.scan_next_left_mark(table, op_state, rhs, output)
             }
+            JoinType::LeftAnti => {
+                // Same as LEFT SEMI, we mark visited rows and only produce
+                // rows when draining. Draining will return the unvisited rows.
+                self.scan_next_left_mark(table, op_state, rhs, output)
+            }
             JoinType::LeftMark { .. } => self.scan_next_left_mark(table, op_state, rhs, output),
-            other => not_implemented!("scan join type: {other}"),
         }
     }
 
@@ -1,6 +1,8 @@
 mod hash_table;
+mod spill;
 
 use std::fmt;
+use std::sync::Arc;
 use std::task::Context;
 
 use glaredb_error::{DbError, Result};
@@ -8,6 +10,7 @@ use hash_table::drain::{HashTablePartitionDrainState, needs_drain};
 use hash_table::scan::HashTablePartitionScanState;
 use hash_table::{HashTableBuildPartitionState, HashTableOperatorState, JoinHashTable};
 use parking_lot::Mutex;
+use spill::{JoinSpillState, PartitionedSpillWriter, SpilledPartitionJoin};
 
 use super::util::delayed_count::DelayedPartitionCount;
 use super::util::partition_wakers::PartitionWakers;
@@ -22,6 +25,8 @@ use super::{
 };
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
+use crate::buffer::buffer_manager::MemoryReservation;
+use crate::execution::spill::SpillContext;
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::comparison_expr::ComparisonOperator;
 use crate::expr::physical::PhysicalScalarExpression;
@@ -50,6 +55,10 @@ pub struct HashJoinOperatorState {
     table: JoinHashTable,
     /// Operator state for the table.
     table_state: HashTableOperatorState,
+    /// State for spilling to disk.
+    ///
+    /// None if there's no memory limit.
+    spill: Option<JoinSpillState>,
     shared: Mutex<SharedState>,
 }
 
@@ -84,6 +93,18 @@ pub struct HashJoinPartitionBuildState {
     finalize_phase: BuildFinalizePhase,
     /// State used for inserting into the hash table.
     build_state: HashTableBuildPartitionState,
+    /// Spill state for this partition if there's a memory limit.
+    spill: Option<BuildSpillState>,
+}
+
+#[derive(Debug)]
+struct BuildSpillState {
+    /// Memory reserved for build rows collected by this partition.
+    reservation: MemoryReservation,
+    /// Writer for build rows, set once we've gone over the memory limit.
+    writer: Option<PartitionedSpillWriter>,
+    /// Reusable buffer for the hashes.
+    hashes: Vec<u64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -108,6 +129,11 @@ pub enum HashJoinPartitionExecuteState {
         rhs_needs_probe: bool,
         /// Scan state for the hash table.
         scan_state: Box<HashTablePartitionScanState>,
+        /// Writer for probe rows that need to be joined with spilled build
+        /// rows.
+        ///
+        /// Created on first write.
+        spill_writer: Option<PartitionedSpillWriter>,
     },
     /// Right side is draining.
     ///
@@ -120,6 +146,16 @@ pub enum HashJoinPartitionExecuteState {
         /// Drain state for the hash table.
         drain_state: HashTablePartitionDrainState,
     },
+    /// Joining partitions that were spilled to disk.
+    ///
+    /// Happens after draining the in-memory table.
+    DrainingSpilled {
+        partition_idx: usize,
+        /// If all probers have completed.
+        drain_ready: bool,
+        /// Spilled partition we're currently joining.
+        current: Option<Box<SpilledPartitionJoin>>,
+    },
 }
 
 #[derive(Debug)]
@@ -134,6 +170,8 @@ pub struct PhysicalHashJoin {
     pub(crate) output_types: Vec<DataType>,
     /// Join conditions.
     pub(crate) conditions: Vec<HashJoinCondition>,
+    /// Context for spilling to disk if a memory limit is configured.
+    pub(crate) spill: Option<Arc<SpillContext>>,
 }
 
 impl PhysicalHashJoin {
@@ -142,6 +180,7 @@ impl PhysicalHashJoin {
         left_types: impl IntoIterator<Item = DataType>,
         right_types: impl IntoIterator<Item = DataType>,
         conditions: impl IntoIterator<Item = HashJoinCondition>,
+        spill: Option<Arc<SpillContext>>,
     ) -> Result<Self> {
         let left_types: Vec<_> = left_types.into_iter().collect();
         let right_types: Vec<_> = right_types.into_iter().collect();
@@ -166,6 +205,7 @@ impl PhysicalHashJoin {
             right_types,
             output_types,
             conditions: conditions.into_iter().collect(),
+            spill,
         })
     }
 }
@@ -186,9 +226,15 @@ impl BaseOperator for PhysicalHashJoin {
 
         let table_state = table.create_operator_state()?;
 
+        let spill = self
+            .spill
+            .as_ref()
+            .map(|context| JoinSpillState::new(context.clone()));
+
         Ok(HashJoinOperatorState {
             table,
             table_state,
+            spill,
             shared: Mutex::new(SharedState {
                 hash_inserts_ready: false,
                 scan_ready: false,
@@ -233,6 +279,11 @@ impl PushOperator for PhysicalHashJoin {
             .map(|state| HashJoinPartitionBuildState {
                 finalize_phase: BuildFinalizePhase::Collecting,
                 build_state: state,
+                spill: operator_state.spill.as_ref().map(|spill| BuildSpillState {
+                    reservation: spill.context.new_reservation(),
+                    writer: None,
+                    hashes: Vec::new(),
+                }),
             })
             .collect();
 
@@ -249,7 +300,29 @@ impl PushOperator for PhysicalHashJoin {
         let table = &operator_state.table;
         let table_state = &operator_state.table_state;
 
-        table.collect_build(table_state, &mut state.build_state, input)?;
+        let (Some(spill), Some(build_spill)) = (&operator_state.spill, &mut state.spill) else {
+            table.collect_build(table_state, &mut state.build_state, input)?;
+            return Ok(PollPush::NeedsMore);
+        };
+
+        match &mut build_spill.writer {
+            Some(writer) => {
+                // Already over the limit, write everything to disk.
+                table.build_hashes(&mut state.build_state, input, &mut build_spill.hashes)?;
+                writer.write(&spill.context, input, &build_spill.hashes, |_| true)?;
+            }
+            None => {
+                table.collect_build(table_state, &mut state.build_state, input)?;
+                let size = table.build_size_bytes(table_state, &state.build_state);
+                if !build_spill.reservation.try_resize(size) {
+                    spill.check_can_spill(self.join_type, &self.left_types, &self.right_types)?;
+                    build_spill.writer = Some(PartitionedSpillWriter::try_new(
+                        self.left_types.clone(),
+                        table.batch_size,
+                    )?);
+                }
+            }
+        }
 
         Ok(PollPush::NeedsMore)
     }
@@ -266,6 +339,13 @@ impl PushOperator for PhysicalHashJoin {
         loop {
             match state.finalize_phase {
                 BuildFinalizePhase::Collecting => {
+                    // Make spilled rows visible before we finish. Probing
+                    // won't start until all partitions finish.
+                    let writer = state.spill.as_mut().and_then(|s| s.writer.take());
+                    if let (Some(spill), Some(writer)) = (&operator_state.spill, writer) {
+                        spill.add_build_files(writer.finish()?);
+                    }
+
                     // We're finalizing our build.
                     let is_last = table.finish_build(table_state, &mut state.build_state)?;
 
@@ -366,6 +446,7 @@ impl ExecuteOperator for PhysicalHashJoin {
                 scan_ready: false,
                 rhs_needs_probe: true,
                 scan_state: Box::new(state),
+                spill_writer: None,
             })
             .collect();
 
@@ -388,6 +469,7 @@ impl ExecuteOperator for PhysicalHashJoin {
                 scan_ready,
                 rhs_needs_probe,
                 scan_state,
+                spill_writer,
             } => {
                 if !*scan_ready {
                     // Check global state to see if we're actually ready for scanning.
@@ -408,6 +490,23 @@ impl ExecuteOperator for PhysicalHashJoin {
                     // New RHS batch, refresh scan state.
                     table.probe(table_state, scan_state, input)?;
                     *rhs_needs_probe = false;
+
+                    if let Some(spill) = &operator_state.spill {
+                        // Rows for partitions with spilled build rows need to
+                        // be joined with those rows later.
+                        if spill.any_spilled() {
+                            let writer = match spill_writer {
+                                Some(writer) => writer,
+                                None => spill_writer.insert(PartitionedSpillWriter::try_new(
+                                    self.right_types.clone(),
+                                    table.batch_size,
+                                )?),
+                            };
+                            writer.write(&spill.context, input, &scan_state.hashes, |p| {
+                                spill.is_spilled(p)
+                            })?;
+                        }
+                    }
                     // Continue...
                 }
 
@@ -456,6 +555,16 @@ impl ExecuteOperator for PhysicalHashJoin {
 
                 drain_state.drain_next(table, table_state, output)?;
                 if output.num_rows() == 0 {
+                    if operator_state.spill.is_some() {
+                        // Move on to joining spilled partitions.
+                        *state = HashJoinPartitionExecuteState::DrainingSpilled {
+                            partition_idx: drain_state.partition_idx,
+                            drain_ready: true,
+                            current: None,
+                        };
+                        return self.poll_execute(cx, operator_state, state, input, output);
+                    }
+
                     // We're done.
                     return Ok(PollExecute::Exhausted);
                 }
@@ -463,6 +572,48 @@ impl ExecuteOperator for PhysicalHashJoin {
                 // Keep draining.
                 Ok(PollExecute::HasMore)
             }
+            HashJoinPartitionExecuteState::DrainingSpilled {
+                partition_idx,
+                drain_ready,
+                current,
+            } => {
+                let spill = operator_state
+                    .spill
+                    .as_ref()
+                    .ok_or_else(|| DbError::new("Missing spill state for hash join"))?;
+
+                if !*drain_ready {
+                    // Same as above, need everything built and probed.
+                    let mut shared = operator_state.shared.lock();
+                    if !(shared.drain_ready && shared.scan_ready) {
+                        shared.pending_drainers.store(cx.waker(), *partition_idx);
+                        return Ok(PollExecute::Pending);
+                    }
+
+                    *drain_ready = true;
+                }
+
+                loop {
+                    let join = match current {
+                        Some(join) => join,
+                        None => match spill.next_spilled_partition(table, &self.right_types)? {
+                            Some(join) => current.insert(Box::new(join)),
+                            None => {
+                                // No more spilled partitions.
+                                output.set_num_rows(0)?;
+                                return Ok(PollExecute::Exhausted);
+                            }
+                        },
+                    };
+
+                    if join.next(table, output)? {
+                        return Ok(PollExecute::HasMore);
+                    }
+
+                    // Partition complete, try the next one.
+                    *current = None;
+                }
+            }
         }
     }
 
@@ -475,19 +626,38 @@ impl ExecuteOperator for PhysicalHashJoin {
         let table = &operator_state.table;
         let table_state = &operator_state.table_state;
 
-        if needs_drain(table.join_type) {
+        if needs_drain(table.join_type) || operator_state.spill.is_some() {
             // Need to drain, update our state.
-            let scan_state = match state {
-                HashJoinPartitionExecuteState::Probing { scan_state, .. } => scan_state,
+            let (scan_state, spill_writer) = match state {
+                HashJoinPartitionExecuteState::Probing {
+                    scan_state,
+                    spill_writer,
+                    ..
+                } => (scan_state, spill_writer),
                 _ => return Err(DbError::new("Prober in unexpected state")),
             };
 
-            let drain_state = table.create_drain_state_from_scan_state(table_state, scan_state)?;
+            if let (Some(spill), Some(writer)) = (&operator_state.spill, spill_writer.take()) {
+                spill.add_probe_files(writer.finish()?);
+            }
 
-            *state = HashJoinPartitionExecuteState::Draining {
-                drain_ready: false,
-                drain_state,
-            };
+            if needs_drain(table.join_type) {
+                let drain_state =
+                    table.create_drain_state_from_scan_state(table_state, scan_state)?;
+
+                *state = HashJoinPartitionExecuteState::Draining {
+                    drain_ready: false,
+                    drain_state,
+                };
+            } else {
+                // Nothing to drain from the in-memory table, just the spilled
+                // partitions.
+                *state = HashJoinPartitionExecuteState::DrainingSpilled {
+                    partition_idx: scan_state.partition_idx,
+                    drain_ready: false,
+                    current: None,
+                };
+            }
 
             let mut shared = operator_state.shared.lock();
             let remaining = shared.remaining_probers.dec_by_one()?;
@@ -514,3 +684,174 @@ impl Explainable for PhysicalHashJoin {
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logical::binder::table_list::TableList;
+    use crate::testutil::exprs::plan_scalar;
+    use crate::testutil::operator::OperatorWrapper;
+    use crate::testutil::spill::test_spill_context;
+    use crate::{expr, generate_batch};
+
+    /// Collect the output rows as (left, right) pairs.
+    fn output_rows(output: &Batch, rows: &mut Vec<(String, String)>) {
+        for idx in 0..output.num_rows() {
+            rows.push((
+                output.arrays[0].get_value(idx).unwrap().to_string(),
+                output.arrays[1].get_value(idx).unwrap().to_string(),
+            ));
+        }
+    }
+
+    #[test]
+    fn inner_join_spill_single_partition() {
+        // CONDITION: a = b
+        let mut left_list = TableList::empty();
+        let t0 = left_list
+            .push_table(None, [DataType::int32()], ["a"])
+            .unwrap();
+        let mut right_list = TableList::empty();
+        let t1 = right_list
+            .push_table(None, [DataType::int32()], ["b"])
+            .unwrap();
+        let condition = HashJoinCondition {
+            left: plan_scalar(&left_list, expr::column((t0, 0), DataType::int32())),
+            right: plan_scalar(&right_list, expr::column((t1, 0), DataType::int32())),
+            op: ComparisonOperator::Eq,
+        };
+
+        // Limit small enough that everything after the first build batch gets
+        // spilled.
+        let (spill, fs) = test_spill_context(1);
+        let wrapper = OperatorWrapper::new(
+            PhysicalHashJoin::new(
+                JoinType::Inner,
+                [DataType::int32()],
+                [DataType::int32()],
+                [condition],
+                Some(spill),
+            )
+            .unwrap(),
+        );
+
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut push_states = wrapper
+            .operator
+            .create_partition_push_states(&op_state, props, 1)
+            .unwrap();
+        let mut probe_states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 1)
+            .unwrap();
+
+        // Build
+        let build_inputs = [generate_batch!([1, 2, 3, 4]), generate_batch!([5, 6, 7, 8])];
+        for mut input in build_inputs {
+            let poll = wrapper
+                .poll_push(&op_state, &mut push_states[0], &mut input)
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll);
+        }
+        let poll = wrapper
+            .poll_finalize_push(&op_state, &mut push_states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::Finalized, poll);
+        let build_files = fs.num_files();
+        assert!(build_files > 0);
+
+        // Probe
+        let mut output = Batch::new([DataType::int32(), DataType::int32()], 16).unwrap();
+        let mut rows = Vec::new();
+
+        let mut input = generate_batch!([2, 4, 6, 8, 10]);
+        loop {
+            let poll = wrapper
+                .poll_execute(&op_state, &mut probe_states[0], &mut input, &mut output)
+                .unwrap();
+            output_rows(&output, &mut rows);
+            if poll == PollExecute::NeedsMore {
+                break;
+            }
+            assert_eq!(PollExecute::HasMore, poll);
+        }
+
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut probe_states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+        // Probe rows for spilled partitions written to disk too.
+        assert!(fs.num_files() > build_files);
+
+        loop {
+            let poll = wrapper
+                .poll_execute(&op_state, &mut probe_states[0], &mut input, &mut output)
+                .unwrap();
+            output_rows(&output, &mut rows);
+            if poll == PollExecute::Exhausted {
+                break;
+            }
+            assert_eq!(PollExecute::HasMore, poll);
+        }
+
+        rows.sort();
+        let expected: Vec<_> = [2, 4, 6, 8]
+            .into_iter()
+            .map(|v| (v.to_string(), v.to_string()))
+            .collect();
+        assert_eq!(expected, rows);
+
+        // Spill files removed once joined.
+        assert_eq!(0, fs.num_files());
+    }
+
+    #[test]
+    fn full_join_over_limit_errors() {
+        // CONDITION: a = b
+        let mut left_list = TableList::empty();
+        let t0 = left_list
+            .push_table(None, [DataType::int32()], ["a"])
+            .unwrap();
+        let mut right_list = TableList::empty();
+        let t1 = right_list
+            .push_table(None, [DataType::int32()], ["b"])
+            .unwrap();
+        let condition = HashJoinCondition {
+            left: plan_scalar(&left_list, expr::column((t0, 0), DataType::int32())),
+            right: plan_scalar(&right_list, expr::column((t1, 0), DataType::int32())),
+            op: ComparisonOperator::Eq,
+        };
+
+        let (spill, fs) = test_spill_context(1);
+        let wrapper = OperatorWrapper::new(
+            PhysicalHashJoin::new(
+                JoinType::Full,
+                [DataType::int32()],
+                [DataType::int32()],
+                [condition],
+                Some(spill),
+            )
+            .unwrap(),
+        );
+
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut push_states = wrapper
+            .operator
+            .create_partition_push_states(&op_state, props, 1)
+            .unwrap();
+
+        // FULL joins can't spill, error instead of going over the limit.
+        let mut input = generate_batch!([1, 2, 3, 4]);
+        let err = wrapper
+            .poll_push(&op_state, &mut push_states[0], &mut input)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Memory limit exceeded, and unable to spill FULL join to disk"),
+            "unexpected error: {err}"
+        );
+        assert_eq!(0, fs.num_files());
+    }
+}
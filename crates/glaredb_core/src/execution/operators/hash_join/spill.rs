@@ -0,0 +1,361 @@
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicBool, AtomicUsize};
+
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+
+use super::hash_table::drain::{HashTablePartitionDrainState, needs_drain};
+use super::hash_table::scan::HashTablePartitionScanState;
+use super::hash_table::{HashTableOperatorState, JoinHashTable};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::execution::spill::{SpillContext, SpillReader, SpillWriter};
+use crate::logical::logical_join::JoinType;
+
+/// Number of partitions rows get split into when spilling.
+pub const SPILL_PARTITIONS: usize = 16;
+
+/// Gets the spill partition for a hash.
+///
+/// Uses the high bits since the low bits are used for the directory.
+pub const fn spill_partition(hash: u64) -> usize {
+    (hash >> 60) as usize
+}
+
+/// If we can spill for this join type.
+///
+/// Joins that need to produce unmatched rows from the probe side can't be
+/// spilled since a probe row may match build rows both in memory and on disk.
+pub const fn can_spill_join(join_type: JoinType) -> bool {
+    match join_type {
+        JoinType::Inner
+        | JoinType::Left
+        | JoinType::LeftSemi
+        | JoinType::LeftAnti
+        | JoinType::LeftMark { .. } => true,
+        JoinType::Right | JoinType::Full => false,
+    }
+}
+
+/// Operator-level state for a hash join with a memory limit.
+///
+/// Once a build partition's memory reservation fails, all further rows for that
+/// partition are written to disk split by `spill_partition`. Rows already in
+/// the in-memory table stay there.
+///
+/// Probe rows are always probed against the in-memory table. Probe rows that
+/// fall into a spill partition containing build rows are additionally written
+/// to disk. After probing completes, each spilled partition is joined
+/// independently by loading its build rows into a fresh hash table and
+/// probing with its spilled probe rows.
+#[derive(Debug)]
+pub struct JoinSpillState {
+    pub context: Arc<SpillContext>,
+    /// Which spill partitions have build-side rows on disk.
+    spilled: [AtomicBool; SPILL_PARTITIONS],
+    /// Build-side spill files per spill partition.
+    build: Mutex<Vec<Vec<SpillReader>>>,
+    /// Probe-side spill files per spill partition.
+    probe: Mutex<Vec<Vec<SpillReader>>>,
+    /// Next spill partition to join.
+    next_partition: AtomicUsize,
+}
+
+impl JoinSpillState {
+    pub fn new(context: Arc<SpillContext>) -> Self {
+        JoinSpillState {
+            context,
+            spilled: std::array::from_fn(|_| AtomicBool::new(false)),
+            build: Mutex::new((0..SPILL_PARTITIONS).map(|_| Vec::new()).collect()),
+            probe: Mutex::new((0..SPILL_PARTITIONS).map(|_| Vec::new()).collect()),
+            next_partition: AtomicUsize::new(0),
+        }
+    }
+
+    /// Checks that we're able to spill this join once the build side goes
+    /// over the memory limit.
+    pub fn check_can_spill(
+        &self,
+        join_type: JoinType,
+        left_types: &[DataType],
+        right_types: &[DataType],
+    ) -> Result<()> {
+        if !can_spill_join(join_type) {
+            return Err(DbError::new(format!(
+                "Memory limit exceeded, and unable to spill {join_type} join to disk"
+            ))
+            .with_field("limit", self.context.limit().bytes()));
+        }
+        self.context
+            .check_can_spill(left_types.iter().chain(right_types))
+    }
+
+    /// Returns if any build rows have been spilled.
+    ///
+    /// Only accurate after the build side completes.
+    pub fn any_spilled(&self) -> bool {
+        self.spilled
+            .iter()
+            .any(|spilled| spilled.load(atomic::Ordering::Relaxed))
+    }
+
+    pub fn is_spilled(&self, partition: usize) -> bool {
+        self.spilled[partition].load(atomic::Ordering::Relaxed)
+    }
+
+    /// Adds finished build-side spill files.
+    pub fn add_build_files(&self, files: Vec<(usize, SpillReader)>) {
+        let mut build = self.build.lock();
+        for (partition, file) in files {
+            self.spilled[partition].store(true, atomic::Ordering::Relaxed);
+            build[partition].push(file);
+        }
+    }
+
+    /// Adds finished probe-side spill files.
+    pub fn add_probe_files(&self, files: Vec<(usize, SpillReader)>) {
+        let mut probe = self.probe.lock();
+        for (partition, file) in files {
+            probe[partition].push(file);
+        }
+    }
+
+    /// Claims the next spilled partition to join.
+    ///
+    /// Must only be called after all probing completes.
+    pub fn next_spilled_partition(
+        &self,
+        table: &JoinHashTable,
+        right_types: &[DataType],
+    ) -> Result<Option<SpilledPartitionJoin>> {
+        loop {
+            let partition = self.next_partition.fetch_add(1, atomic::Ordering::Relaxed);
+            if partition >= SPILL_PARTITIONS {
+                return Ok(None);
+            }
+            if !self.is_spilled(partition) {
+                continue;
+            }
+
+            let build = std::mem::take(&mut self.build.lock()[partition]);
+            let probe = std::mem::take(&mut self.probe.lock()[partition]);
+
+            let join = SpilledPartitionJoin::try_new(table, build, probe, right_types)?;
+            return Ok(Some(join));
+        }
+    }
+}
+
+/// Writes rows to spill files split by spill partition.
+#[derive(Debug)]
+pub struct PartitionedSpillWriter {
+    writers: Vec<Option<SpillWriter>>,
+    /// Row selection per spill partition.
+    buckets: Vec<Vec<usize>>,
+    /// Batch for holding the rows for a single partition.
+    rows: Batch,
+}
+
+impl PartitionedSpillWriter {
+    pub fn try_new(types: Vec<DataType>, batch_size: usize) -> Result<Self> {
+        Ok(PartitionedSpillWriter {
+            writers: (0..SPILL_PARTITIONS).map(|_| None).collect(),
+            buckets: vec![Vec::new(); SPILL_PARTITIONS],
+            rows: Batch::new(types, batch_size)?,
+        })
+    }
+
+    /// Writes rows from the input using the precomputed hashes.
+    ///
+    /// Only rows in partitions where `include` returns true are written.
+    pub fn write(
+        &mut self,
+        context: &SpillContext,
+        input: &Batch,
+        hashes: &[u64],
+        include: impl Fn(usize) -> bool,
+    ) -> Result<()> {
+        debug_assert_eq!(input.num_rows(), hashes.len());
+
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+        for (row_idx, &hash) in hashes.iter().enumerate() {
+            let partition = spill_partition(hash);
+            if include(partition) {
+                self.buckets[partition].push(row_idx);
+            }
+        }
+
+        for (bucket, writer) in self.buckets.iter().zip(&mut self.writers) {
+            if bucket.is_empty() {
+                continue;
+            }
+
+            self.rows.reset_for_write()?;
+            let mapping = bucket
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(to, from)| (from, to));
+            input.copy_rows(mapping, &mut self.rows)?;
+            self.rows.set_num_rows(bucket.len())?;
+
+            let writer = match writer {
+                Some(writer) => writer,
+                None => writer.insert(context.create_file()?),
+            };
+            writer.write_batch(&self.rows)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finish writing, returning readers for all partitions that were written
+    /// to.
+    pub fn finish(self) -> Result<Vec<(usize, SpillReader)>> {
+        self.writers
+            .into_iter()
+            .enumerate()
+            .filter_map(|(partition, writer)| {
+                writer.map(|writer| Ok((partition, writer.finish()?)))
+            })
+            .collect()
+    }
+}
+
+/// Join for a single spilled partition.
+///
+/// Build rows are loaded into their own hash table using the same
+/// `JoinHashTable` as the in-memory join.
+///
+/// Note that the build rows for a partition must fit in memory.
+#[derive(Debug)]
+pub struct SpilledPartitionJoin {
+    op_state: HashTableOperatorState,
+    probe_files: Vec<SpillReader>,
+    probe_batch: Batch,
+    scan_state: HashTablePartitionScanState,
+    phase: SpilledJoinPhase,
+}
+
+#[derive(Debug)]
+enum SpilledJoinPhase {
+    /// Probing the table, with a bool indicating if we need to read the next
+    /// probe batch.
+    Probing {
+        needs_probe: bool,
+    },
+    Draining(HashTablePartitionDrainState),
+    Finished,
+}
+
+impl SpilledPartitionJoin {
+    fn try_new(
+        table: &JoinHashTable,
+        build_files: Vec<SpillReader>,
+        probe_files: Vec<SpillReader>,
+        right_types: &[DataType],
+    ) -> Result<Self> {
+        let op_state = table.create_operator_state()?;
+        let mut build_state = table
+            .create_build_partition_states(&op_state, 1)?
+            .pop()
+            .expect("a single build state");
+
+        let left_types = table.layout.types[0..table.data_column_count].to_vec();
+        let mut build_batch = Batch::new(left_types, table.batch_size)?;
+        for mut file in build_files {
+            while file.read_batch(&mut build_batch)? {
+                table.collect_build(&op_state, &mut build_state, &mut build_batch)?;
+            }
+        }
+
+        let is_last = table.finish_build(&op_state, &mut build_state)?;
+        debug_assert!(is_last);
+
+        // SAFETY: We have the only build partition, and we've collected
+        // everything.
+        unsafe {
+            table.init_directory(&op_state)?;
+            table.process_hashes(&op_state, &mut build_state)?;
+        }
+
+        let scan_state = table
+            .create_probe_partition_states(&op_state, 1)?
+            .pop()
+            .expect("a single probe state");
+
+        Ok(SpilledPartitionJoin {
+            op_state,
+            probe_files,
+            probe_batch: Batch::new(right_types.to_vec(), table.batch_size)?,
+            scan_state,
+            phase: SpilledJoinPhase::Probing { needs_probe: true },
+        })
+    }
+
+    /// Produce the next output for this partition.
+    ///
+    /// Returns `false` once this partition has been completely joined.
+    pub fn next(&mut self, table: &JoinHashTable, output: &mut Batch) -> Result<bool> {
+        loop {
+            match &mut self.phase {
+                SpilledJoinPhase::Probing { needs_probe } => {
+                    if *needs_probe {
+                        if !read_probe_batch(&mut self.probe_files, &mut self.probe_batch)? {
+                            // All probe rows consumed.
+                            self.phase = if needs_drain(table.join_type) {
+                                let drain_state = table.create_drain_state_from_scan_state(
+                                    &self.op_state,
+                                    &mut self.scan_state,
+                                )?;
+                                SpilledJoinPhase::Draining(drain_state)
+                            } else {
+                                SpilledJoinPhase::Finished
+                            };
+                            continue;
+                        }
+
+                        table.probe(&self.op_state, &mut self.scan_state, &mut self.probe_batch)?;
+                        *needs_probe = false;
+                    }
+
+                    self.scan_state.scan_next(
+                        table,
+                        &self.op_state,
+                        &mut self.probe_batch,
+                        output,
+                    )?;
+                    if output.num_rows() == 0 {
+                        *needs_probe = true;
+                        continue;
+                    }
+
+                    return Ok(true);
+                }
+                SpilledJoinPhase::Draining(drain_state) => {
+                    drain_state.drain_next(table, &self.op_state, output)?;
+                    if output.num_rows() == 0 {
+                        self.phase = SpilledJoinPhase::Finished;
+                        continue;
+                    }
+
+                    return Ok(true);
+                }
+                SpilledJoinPhase::Finished => return Ok(false),
+            }
+        }
+    }
+}
+
+/// Reads the next probe batch from the spill files.
+fn read_probe_batch(files: &mut Vec<SpillReader>, output: &mut Batch) -> Result<bool> {
+    while let Some(file) = files.last_mut() {
+        if file.read_batch(output)? {
+            return Ok(true);
+        }
+        files.pop();
+    }
+    Ok(false)
+}
@@ -1,6 +1,7 @@
 //! Implementations of physical operators in an execution pipeline.
 
 pub mod catalog;
+pub mod copy_to;
 pub mod filter;
 pub mod hash_aggregate;
 pub mod hash_join;
@@ -9,6 +10,7 @@ pub mod materialize;
 pub mod nested_loop_join;
 pub mod no_rows;
 pub mod project;
+pub mod recursive_cte;
 pub mod results;
 pub mod scan;
 pub mod single_row;
@@ -17,6 +19,7 @@ pub mod table_execute;
 pub mod ungrouped_aggregate;
 pub mod union;
 pub mod values;
+pub mod window;
 
 pub(crate) mod util;
 
@@ -145,6 +145,26 @@ impl MatchTracker {
         Ok(())
     }
 
+    /// Write the result of a left anti join to `output`.
+    pub fn left_anti_result(
+        &self,
+        left_offset: usize,
+        left: &mut Batch,
+        output: &mut Batch,
+    ) -> Result<()> {
+        debug_assert!(left.num_rows() + left_offset <= self.matches.len());
+
+        // Slice matches to only the ones for this batch.
+        let matches = &self.matches[left_offset..(left_offset + left.num_rows())];
+
+        let not_match_iter = NotMatchIter::new(matches);
+
+        output.clone_from_other(left)?;
+        output.select(not_match_iter)?;
+
+        Ok(())
+    }
+
     /// Write the output of a left mark join to `output`.
     pub fn left_mark_result(
         &self,
@@ -422,6 +442,52 @@ mod tests {
         assert_batches_eq(&expected, &output);
     }
 
+    #[test]
+    fn left_anti_all_rows_match() {
+        // COLLECTION SIZE: 4
+        // OUTPUT SIZE: 2
+        //
+        // No output produced
+
+        let mut tracker = MatchTracker::empty();
+        tracker.ensure_initialized(4);
+        tracker.set_matches(0..4);
+
+        let mut output = Batch::new([DataType::int32()], 2).unwrap();
+
+        let mut left = generate_batch!([1, 2]);
+        tracker.left_anti_result(0, &mut left, &mut output).unwrap();
+        assert_eq!(0, output.num_rows());
+
+        let mut left = generate_batch!([3, 4]);
+        tracker.left_anti_result(2, &mut left, &mut output).unwrap();
+        assert_eq!(0, output.num_rows());
+    }
+
+    #[test]
+    fn left_anti_some_rows_match() {
+        // COLLECTION SIZE: 4
+        // OUTPUT SIZE: 2
+
+        let mut tracker = MatchTracker::empty();
+        tracker.ensure_initialized(4);
+
+        // Should produce rows 1 and 3
+        tracker.set_matches([0, 2]);
+
+        let mut output = Batch::new([DataType::int32()], 2).unwrap();
+
+        let mut left = generate_batch!([1, 2]);
+        tracker.left_anti_result(0, &mut left, &mut output).unwrap();
+        let expected = generate_batch!([2]);
+        assert_batches_eq(&expected, &output);
+
+        let mut left = generate_batch!([3, 4]);
+        tracker.left_anti_result(2, &mut left, &mut output).unwrap();
+        let expected = generate_batch!([4]);
+        assert_batches_eq(&expected, &output);
+    }
+
     #[test]
     fn left_mark_no_rows_match() {
         // COLLECTION SIZE: 4
@@ -112,7 +112,9 @@ impl PhysicalNestedLoopJoin {
             JoinType::Inner
                 | JoinType::Right
                 | JoinType::Left
+                | JoinType::Full
                 | JoinType::LeftSemi
+                | JoinType::LeftAnti
                 | JoinType::LeftMark { .. }
         ) {
             return Err(DbError::new(format!(
@@ -374,6 +376,13 @@ impl ExecuteOperator for PhysicalNestedLoopJoin {
                         output,
                     )?;
                 }
+                JoinType::LeftAnti => {
+                    inner.left_matches.left_anti_result(
+                        state.left_drain_state.state.relative_scan_offset(),
+                        &mut state.cross_state.batch,
+                        output,
+                    )?;
+                }
                 JoinType::LeftMark { .. } => inner.left_matches.left_mark_result(
                     state.left_drain_state.state.relative_scan_offset(),
                     &mut state.cross_state.batch,
@@ -386,7 +395,7 @@ impl ExecuteOperator for PhysicalNestedLoopJoin {
             return Ok(PollExecute::HasMore);
         }
 
-        if matches!(self.join_type, JoinType::Right) {
+        if matches!(self.join_type, JoinType::Right | JoinType::Full) {
             // Note this doesn't clear the existing values. This essentially
             // acts as an initialization step during new right-side batches.
             state.right_matches.ensure_initialized(input.num_rows());
@@ -421,7 +430,7 @@ impl ExecuteOperator for PhysicalNestedLoopJoin {
                 // We're done scanning the right batch. Finish up any remaining
                 // scanning and request a new batch.
 
-                if matches!(self.join_type, JoinType::Right) {
+                if matches!(self.join_type, JoinType::Right | JoinType::Full) {
                     // Find rows that didn't match from the right, and flush
                     // them out with null left side values.
                     state
@@ -450,7 +459,7 @@ impl ExecuteOperator for PhysicalNestedLoopJoin {
                         continue;
                     }
 
-                    if matches!(self.join_type, JoinType::Right) {
+                    if matches!(self.join_type, JoinType::Right | JoinType::Full) {
                         // Mark right rows as matched.
                         state.right_matches.set_matches(selection.iter().copied());
                     }
@@ -458,6 +467,7 @@ impl ExecuteOperator for PhysicalNestedLoopJoin {
                     if matches!(
                         self.join_type,
                         JoinType::Left
+                            | JoinType::Full
                             | JoinType::LeftSemi
                             | JoinType::LeftAnti
                             | JoinType::LeftMark { .. }
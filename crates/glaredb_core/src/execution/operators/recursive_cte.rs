@@ -0,0 +1,724 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+
+use super::util::delayed_count::DelayedPartitionCount;
+use super::{
+    BaseOperator,
+    ExecuteOperator,
+    ExecutionProperties,
+    PlannedOperatorWithChildren,
+    PollExecute,
+    PollFinalize,
+    PollPull,
+    PollPush,
+    PullOperator,
+    PushOperator,
+};
+use crate::arrays::batch::Batch;
+use crate::arrays::collection::concurrent::{
+    ColumnCollectionAppendState,
+    ColumnCollectionScanState,
+    ConcurrentColumnCollection,
+    ParallelColumnCollectionScanState,
+};
+use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::ScalarValue;
+use crate::execution::partition_pipeline::ExecutablePartitionPipeline;
+use crate::execution::pipeline::ExecutablePipelineGraph;
+use crate::execution::planner::PlannedQueryGraph;
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::runtime::time::RuntimeInstant;
+use crate::storage::projections::Projections;
+
+/// Rows passed between iterations of a recursive CTE.
+///
+/// Shared between the recursive CTE operator, and the scan and sink operators
+/// inside its recursive term.
+#[derive(Debug, Default)]
+pub struct WorkingTable {
+    inner: Mutex<Option<WorkingTableInner>>,
+}
+
+#[derive(Debug)]
+struct WorkingTableInner {
+    datatypes: Vec<DataType>,
+    batch_size: usize,
+    /// Rows produced by the previous iteration, read by the working table
+    /// scans.
+    current: Arc<ConcurrentColumnCollection>,
+    /// Rows being produced by the current iteration.
+    next: Arc<ConcurrentColumnCollection>,
+}
+
+impl WorkingTable {
+    /// Resets the working table to be empty.
+    fn reset(&self, datatypes: &[DataType], batch_size: usize) {
+        let mut inner = self.inner.lock();
+        *inner = Some(WorkingTableInner {
+            datatypes: datatypes.to_vec(),
+            batch_size,
+            current: Arc::new(new_collection(datatypes, batch_size)),
+            next: Arc::new(new_collection(datatypes, batch_size)),
+        });
+    }
+
+    fn current(&self) -> Result<Arc<ConcurrentColumnCollection>> {
+        self.with_inner(|inner| inner.current.clone())
+    }
+
+    fn next(&self) -> Result<Arc<ConcurrentColumnCollection>> {
+        self.with_inner(|inner| inner.next.clone())
+    }
+
+    /// Sets the rows that should be read by the next iteration, and resets the
+    /// collection the next iteration writes to.
+    fn advance(&self, current: Arc<ConcurrentColumnCollection>) -> Result<()> {
+        self.with_inner(|inner| {
+            inner.current = current;
+            inner.next = Arc::new(new_collection(&inner.datatypes, inner.batch_size));
+        })
+    }
+
+    fn with_inner<T>(&self, f: impl FnOnce(&mut WorkingTableInner) -> T) -> Result<T> {
+        let mut inner = self.inner.lock();
+        match inner.as_mut() {
+            Some(inner) => Ok(f(inner)),
+            None => Err(DbError::new("Working table not initialized")),
+        }
+    }
+}
+
+fn new_collection(datatypes: &[DataType], batch_size: usize) -> ConcurrentColumnCollection {
+    // TODO: Configurable segment size.
+    ConcurrentColumnCollection::new(datatypes.iter().cloned(), 4, batch_size)
+}
+
+#[derive(Debug)]
+pub struct RecursiveCteOperatorState {
+    inner: Mutex<OperatorStateInner>,
+}
+
+#[derive(Debug)]
+struct OperatorStateInner {
+    /// Partitions still producing anchor rows. The last partition to finalize
+    /// is responsible for evaluating the recursive term.
+    remaining: DelayedPartitionCount,
+    /// All rows produced so far. Only used for UNION.
+    seen: HashSet<Vec<ScalarValue>>,
+}
+
+#[derive(Debug)]
+pub struct RecursiveCtePartitionState {
+    props: ExecutionProperties,
+    /// Collection anchor rows are written to.
+    working: Arc<ConcurrentColumnCollection>,
+    append_state: ColumnCollectionAppendState,
+    /// If this partition has been counted towards finalization.
+    finalized: bool,
+    /// State for evaluating the recursive term. Only set on the last
+    /// partition to finalize.
+    recursion: Option<RecursionState>,
+}
+
+#[derive(Debug)]
+struct RecursionState {
+    /// Number of iterations of the recursive term so far, including the one
+    /// currently executing.
+    depth: usize,
+    phase: RecursionPhase,
+}
+
+#[derive(Debug)]
+enum RecursionPhase {
+    /// Start the next iteration if the working table isn't empty.
+    Start,
+    /// Executing pipelines for the recursive term. Pipelines are set to None
+    /// once complete.
+    Executing {
+        pipelines: Vec<Option<ExecutablePartitionPipeline>>,
+    },
+    /// Emitting the rows produced by the last iteration.
+    Emitting {
+        rows: Arc<ConcurrentColumnCollection>,
+        scan_state: ColumnCollectionScanState,
+    },
+}
+
+/// Evaluates a recursive CTE.
+///
+/// Anchor rows are passed through as they're received and written to the
+/// working table. Once all anchor partitions complete, the recursive term is
+/// repeatedly executed in a single partition, reading from the working table
+/// produced by the previous iteration, until an iteration produces no rows.
+///
+/// For UNION (not ALL), rows that have already been produced are removed from
+/// the output of each iteration before being emitted and written to the working
+/// table.
+#[derive(Debug)]
+pub struct PhysicalRecursiveCte {
+    pub(crate) datatypes: Vec<DataType>,
+    pub(crate) all: bool,
+    /// Max number of iterations of the recursive term that may produce rows.
+    pub(crate) max_depth: usize,
+    pub(crate) working_table: Arc<WorkingTable>,
+    /// Plan for the recursive term, with a `PhysicalWorkingTableSink` as the
+    /// root.
+    pub(crate) recursive: PlannedOperatorWithChildren,
+}
+
+impl BaseOperator for PhysicalRecursiveCte {
+    const OPERATOR_NAME: &str = "RecursiveCte";
+
+    type OperatorState = RecursiveCteOperatorState;
+
+    fn create_operator_state(&self, props: ExecutionProperties) -> Result<Self::OperatorState> {
+        self.working_table.reset(&self.datatypes, props.batch_size);
+
+        Ok(RecursiveCteOperatorState {
+            inner: Mutex::new(OperatorStateInner {
+                remaining: DelayedPartitionCount::uninit(),
+                seen: HashSet::new(),
+            }),
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        &self.datatypes
+    }
+}
+
+impl ExecuteOperator for PhysicalRecursiveCte {
+    type PartitionExecuteState = RecursiveCtePartitionState;
+
+    fn create_partition_execute_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionExecuteState>> {
+        operator_state.inner.lock().remaining.set(partitions)?;
+
+        let working = self.working_table.current()?;
+
+        let states = (0..partitions)
+            .map(|_| RecursiveCtePartitionState {
+                props,
+                working: working.clone(),
+                append_state: working.init_append_state(),
+                finalized: false,
+                recursion: None,
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_execute(
+        &self,
+        cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+        input: &mut Batch,
+        output: &mut Batch,
+    ) -> Result<PollExecute> {
+        if let Some(recursion) = &mut state.recursion {
+            return self.poll_recurse(cx, operator_state, state.props, recursion, output);
+        }
+
+        if state.finalized {
+            // Another partition is handling the recursion.
+            output.set_num_rows(0)?;
+            return Ok(PollExecute::Exhausted);
+        }
+
+        // Anchor rows.
+        if self.all {
+            state.working.append_batch(&mut state.append_state, input)?;
+            output.clone_from_other(input)?;
+            return Ok(PollExecute::Ready);
+        }
+
+        let selection = {
+            let mut inner = operator_state.inner.lock();
+            insert_unseen_rows(&mut inner.seen, input)?
+        };
+        if selection.is_empty() {
+            return Ok(PollExecute::NeedsMore);
+        }
+
+        output.clone_from_other(input)?;
+        output.select(selection)?;
+        state
+            .working
+            .append_batch(&mut state.append_state, output)?;
+
+        Ok(PollExecute::Ready)
+    }
+
+    fn poll_finalize_execute(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+    ) -> Result<PollFinalize> {
+        if !state.finalized {
+            state.finalized = true;
+            state.working.flush(&mut state.append_state)?;
+
+            let remaining = operator_state.inner.lock().remaining.dec_by_one()?;
+            if remaining != 0 {
+                // Some other partition will evaluate the recursive term.
+                return Ok(PollFinalize::Finalized);
+            }
+
+            state.recursion = Some(RecursionState {
+                depth: 0,
+                phase: RecursionPhase::Start,
+            });
+        }
+
+        Ok(PollFinalize::NeedsDrain)
+    }
+}
+
+impl PhysicalRecursiveCte {
+    /// Evaluates the recursive term until no more rows are produced, emitting
+    /// the rows produced by each iteration.
+    fn poll_recurse(
+        &self,
+        cx: &mut Context,
+        operator_state: &RecursiveCteOperatorState,
+        props: ExecutionProperties,
+        recursion: &mut RecursionState,
+        output: &mut Batch,
+    ) -> Result<PollExecute> {
+        loop {
+            match &mut recursion.phase {
+                RecursionPhase::Start => {
+                    if self.working_table.current()?.flushed_rows() == 0 {
+                        // Previous iteration produced nothing, we're done.
+                        output.set_num_rows(0)?;
+                        return Ok(PollExecute::Exhausted);
+                    }
+
+                    recursion.depth += 1;
+
+                    // Pipelines (and their states) are created fresh for each
+                    // iteration.
+                    let graph = ExecutablePipelineGraph::plan_from_graph(
+                        props,
+                        PlannedQueryGraph {
+                            materializations: BTreeMap::new(),
+                            root: self.recursive.clone(),
+                        },
+                    )?;
+                    let pipelines = graph.create_partition_pipelines(props, 1)?;
+
+                    recursion.phase = RecursionPhase::Executing {
+                        pipelines: pipelines.into_iter().map(Some).collect(),
+                    };
+                }
+                RecursionPhase::Executing { pipelines } => {
+                    if poll_pipelines(cx, pipelines)?.is_pending() {
+                        return Ok(PollExecute::Pending);
+                    }
+
+                    let produced = self.working_table.next()?;
+                    let rows = if self.all {
+                        produced
+                    } else {
+                        let mut inner = operator_state.inner.lock();
+                        Arc::new(self.remove_seen_rows(&mut inner.seen, &produced, props)?)
+                    };
+
+                    if recursion.depth > self.max_depth && rows.flushed_rows() > 0 {
+                        return Err(DbError::new(format!(
+                            "Recursive CTE exceeded the maximum recursion depth of {}",
+                            self.max_depth
+                        ))
+                        .with_field("setting", "max_recursion_depth"));
+                    }
+
+                    self.working_table.advance(rows.clone())?;
+
+                    recursion.phase = RecursionPhase::Emitting {
+                        scan_state: rows.init_scan_state(),
+                        rows,
+                    };
+                }
+                RecursionPhase::Emitting { rows, scan_state } => {
+                    output.reset_for_write()?;
+                    let projections = Projections::new(0..self.datatypes.len());
+                    let count = rows.scan(&projections, scan_state, output)?;
+                    if count > 0 {
+                        return Ok(PollExecute::HasMore);
+                    }
+
+                    recursion.phase = RecursionPhase::Start;
+                }
+            }
+        }
+    }
+
+    /// Copies rows from `produced` that haven't been seen before into a new
+    /// collection.
+    fn remove_seen_rows(
+        &self,
+        seen: &mut HashSet<Vec<ScalarValue>>,
+        produced: &ConcurrentColumnCollection,
+        props: ExecutionProperties,
+    ) -> Result<ConcurrentColumnCollection> {
+        let rows = new_collection(&self.datatypes, props.batch_size);
+        let mut append_state = rows.init_append_state();
+
+        let projections = Projections::new(0..self.datatypes.len());
+        let mut scan_state = produced.init_scan_state();
+        let mut batch = Batch::new(self.datatypes.clone(), props.batch_size)?;
+
+        loop {
+            batch.reset_for_write()?;
+            let count = produced.scan(&projections, &mut scan_state, &mut batch)?;
+            if count == 0 {
+                break;
+            }
+
+            let selection = insert_unseen_rows(seen, &batch)?;
+            if selection.is_empty() {
+                continue;
+            }
+
+            batch.select(selection)?;
+            rows.append_batch(&mut append_state, &batch)?;
+        }
+
+        rows.flush(&mut append_state)?;
+
+        Ok(rows)
+    }
+}
+
+/// Polls all incomplete pipelines until they're all complete, or none can make
+/// progress.
+///
+/// Pipelines are polled in the order they were created in, which matches the
+/// order of their dependencies.
+fn poll_pipelines(
+    cx: &mut Context,
+    pipelines: &mut [Option<ExecutablePartitionPipeline>],
+) -> Result<Poll<()>> {
+    loop {
+        let mut pending = false;
+        let mut completed = false;
+
+        for slot in pipelines.iter_mut() {
+            if let Some(pipeline) = slot {
+                match pipeline.poll_execute::<UntimedInstant>(cx) {
+                    Poll::Ready(Ok(_)) => {
+                        *slot = None;
+                        completed = true;
+                    }
+                    Poll::Ready(Err(e)) => return Err(e),
+                    Poll::Pending => pending = true,
+                }
+            }
+        }
+
+        if !pending {
+            return Ok(Poll::Ready(()));
+        }
+        if !completed {
+            // Pending pipelines will wake us up when they can make progress.
+            return Ok(Poll::Pending);
+        }
+    }
+}
+
+/// Inserts the rows of the batch into the set of seen rows, returning the
+/// indices of rows that weren't already in the set.
+fn insert_unseen_rows(seen: &mut HashSet<Vec<ScalarValue>>, batch: &Batch) -> Result<Vec<usize>> {
+    let mut selection = Vec::new();
+    for row_idx in 0..batch.num_rows() {
+        let row = batch
+            .arrays()
+            .iter()
+            .map(|arr| arr.get_value(row_idx).map(|v| v.into_owned()))
+            .collect::<Result<Vec<_>>>()?;
+
+        if seen.insert(row) {
+            selection.push(row_idx);
+        }
+    }
+    Ok(selection)
+}
+
+/// Instant used when executing pipelines for the recursive term.
+///
+/// Profiles for those pipelines are discarded, so there's no reason to time
+/// them.
+#[derive(Debug, Clone, Copy)]
+struct UntimedInstant;
+
+impl RuntimeInstant for UntimedInstant {
+    fn now() -> Self {
+        UntimedInstant
+    }
+
+    fn duration_since(&self, _earlier: Self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+impl Explainable for PhysicalRecursiveCte {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf)
+            .with_value("union_all", self.all)
+            .with_value("max_depth", self.max_depth)
+            .build()
+    }
+}
+
+#[derive(Debug)]
+pub struct WorkingTableScanOperatorState {
+    collection: Arc<ConcurrentColumnCollection>,
+}
+
+#[derive(Debug)]
+pub struct WorkingTableScanPartitionState {
+    scan_state: ParallelColumnCollectionScanState,
+}
+
+/// Reads rows produced by the previous iteration of a recursive CTE.
+#[derive(Debug)]
+pub struct PhysicalWorkingTableScan {
+    pub(crate) datatypes: Vec<DataType>,
+    pub(crate) working_table: Arc<WorkingTable>,
+}
+
+impl BaseOperator for PhysicalWorkingTableScan {
+    const OPERATOR_NAME: &str = "WorkingTableScan";
+
+    type OperatorState = WorkingTableScanOperatorState;
+
+    fn create_operator_state(&self, _props: ExecutionProperties) -> Result<Self::OperatorState> {
+        Ok(WorkingTableScanOperatorState {
+            collection: self.working_table.current()?,
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        &self.datatypes
+    }
+}
+
+impl PullOperator for PhysicalWorkingTableScan {
+    type PartitionPullState = WorkingTableScanPartitionState;
+
+    fn create_partition_pull_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionPullState>> {
+        let states = operator_state
+            .collection
+            .init_parallel_scan_states(partitions)
+            .map(|scan_state| WorkingTableScanPartitionState { scan_state })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_pull(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionPullState,
+        output: &mut Batch,
+    ) -> Result<PollPull> {
+        output.reset_for_write()?;
+
+        let projections = Projections::new(0..self.datatypes.len());
+        let count =
+            operator_state
+                .collection
+                .parallel_scan(&projections, &mut state.scan_state, output)?;
+
+        if count == 0 {
+            return Ok(PollPull::Exhausted);
+        }
+
+        Ok(PollPull::HasMore)
+    }
+}
+
+impl Explainable for PhysicalWorkingTableScan {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf).build()
+    }
+}
+
+#[derive(Debug)]
+pub struct WorkingTableSinkOperatorState {
+    collection: Arc<ConcurrentColumnCollection>,
+}
+
+#[derive(Debug)]
+pub struct WorkingTableSinkPartitionState {
+    append_state: ColumnCollectionAppendState,
+}
+
+/// Writes the output of the recursive term to the working table for the next
+/// iteration.
+#[derive(Debug)]
+pub struct PhysicalWorkingTableSink {
+    pub(crate) datatypes: Vec<DataType>,
+    pub(crate) working_table: Arc<WorkingTable>,
+}
+
+impl BaseOperator for PhysicalWorkingTableSink {
+    const OPERATOR_NAME: &str = "WorkingTableSink";
+
+    type OperatorState = WorkingTableSinkOperatorState;
+
+    fn create_operator_state(&self, _props: ExecutionProperties) -> Result<Self::OperatorState> {
+        Ok(WorkingTableSinkOperatorState {
+            collection: self.working_table.next()?,
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        &self.datatypes
+    }
+}
+
+impl PushOperator for PhysicalWorkingTableSink {
+    type PartitionPushState = WorkingTableSinkPartitionState;
+
+    fn create_partition_push_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        _props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionPushState>> {
+        let states = (0..partitions)
+            .map(|_| WorkingTableSinkPartitionState {
+                append_state: operator_state.collection.init_append_state(),
+            })
+            .collect();
+
+        Ok(states)
+    }
+
+    fn poll_push(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionPushState,
+        input: &mut Batch,
+    ) -> Result<PollPush> {
+        operator_state
+            .collection
+            .append_batch(&mut state.append_state, input)?;
+        Ok(PollPush::NeedsMore)
+    }
+
+    fn poll_finalize_push(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionPushState,
+    ) -> Result<PollFinalize> {
+        operator_state.collection.flush(&mut state.append_state)?;
+        Ok(PollFinalize::Finalized)
+    }
+}
+
+impl Explainable for PhysicalWorkingTableSink {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_batch;
+    use crate::testutil::arrays::assert_batches_eq;
+    use crate::testutil::operator::OperatorWrapper;
+
+    #[test]
+    fn insert_unseen_rows_dedups_across_batches() {
+        let mut seen = HashSet::new();
+
+        let batch = generate_batch!([1, 2, 1, 3], ["a", "b", "a", "c"]);
+        let selection = insert_unseen_rows(&mut seen, &batch).unwrap();
+        assert_eq!(vec![0, 1, 3], selection);
+
+        let batch = generate_batch!([3, 1, 4], ["c", "b", "d"]);
+        let selection = insert_unseen_rows(&mut seen, &batch).unwrap();
+        assert_eq!(vec![1, 2], selection);
+    }
+
+    #[test]
+    fn working_table_sink_then_scan() {
+        let props = ExecutionProperties { batch_size: 16 };
+        let working_table = Arc::new(WorkingTable::default());
+        working_table.reset(&[DataType::int32()], props.batch_size);
+
+        let sink = OperatorWrapper::new(PhysicalWorkingTableSink {
+            datatypes: vec![DataType::int32()],
+            working_table: working_table.clone(),
+        });
+        let sink_state = sink.operator.create_operator_state(props).unwrap();
+        let mut push_states = sink
+            .operator
+            .create_partition_push_states(&sink_state, props, 1)
+            .unwrap();
+
+        let mut input = generate_batch!([4, 5, 6]);
+        let poll = sink
+            .poll_push(&sink_state, &mut push_states[0], &mut input)
+            .unwrap();
+        assert_eq!(PollPush::NeedsMore, poll);
+        let poll = sink
+            .poll_finalize_push(&sink_state, &mut push_states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::Finalized, poll);
+
+        // Rows only visible to scans after advancing.
+        let next = working_table.next().unwrap();
+        assert_eq!(3, next.flushed_rows());
+        assert_eq!(0, working_table.current().unwrap().flushed_rows());
+        working_table.advance(next).unwrap();
+        assert_eq!(0, working_table.next().unwrap().flushed_rows());
+
+        let scan = OperatorWrapper::new(PhysicalWorkingTableScan {
+            datatypes: vec![DataType::int32()],
+            working_table,
+        });
+        let scan_state = scan.operator.create_operator_state(props).unwrap();
+        let mut pull_states = scan
+            .operator
+            .create_partition_pull_states(&scan_state, props, 1)
+            .unwrap();
+
+        let mut out = Batch::new([DataType::int32()], 16).unwrap();
+        let poll = scan
+            .poll_pull(&scan_state, &mut pull_states[0], &mut out)
+            .unwrap();
+        assert_eq!(PollPull::HasMore, poll);
+
+        let expected = generate_batch!([4, 5, 6]);
+        assert_batches_eq(&expected, &out);
+
+        let poll = scan
+            .poll_pull(&scan_state, &mut pull_states[0], &mut out)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll);
+    }
+}
@@ -1,14 +1,18 @@
+use std::sync::Arc;
 use std::task::Context;
 
 use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
 
 use super::merge_queue::{MergeQueue, PollMerge};
+use super::spill_merge::SpilledRunMerger;
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
 use crate::arrays::row::row_layout::RowLayout;
 use crate::arrays::sort::partial_sort::{PartialSortedRowCollection, SortedRowAppendState};
 use crate::arrays::sort::sort_layout::{SortColumn, SortLayout};
 use crate::arrays::sort::sorted_segment::{SortedSegment, SortedSegmentScanState};
+use crate::buffer::buffer_manager::MemoryReservation;
 use crate::execution::operators::{
     BaseOperator,
     ExecuteOperator,
@@ -16,6 +20,7 @@ use crate::execution::operators::{
     PollExecute,
     PollFinalize,
 };
+use crate::execution::spill::{SpillContext, SpillReader};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::physical::PhysicalSortExpression;
 use crate::expr::physical::evaluator::ExpressionEvaluator;
@@ -45,6 +50,10 @@ pub struct SortCollectingState {
     append: SortedRowAppendState,
     /// Hold partially sorted data for this partition.
     collection: Box<PartialSortedRowCollection>,
+    /// Memory reserved for the collection.
+    ///
+    /// None if there's no memory limit.
+    reservation: Option<MemoryReservation>,
 }
 
 #[derive(Debug)]
@@ -53,9 +62,14 @@ pub struct SortMergingState {
 }
 
 #[derive(Debug)]
-pub struct SortDrainingState {
-    scan_state: SortedSegmentScanState,
-    sorted_run: SortedSegment,
+pub enum SortDrainingState {
+    /// Everything fit in memory, scan the final sorted run.
+    InMemory {
+        scan_state: SortedSegmentScanState,
+        sorted_run: SortedSegment,
+    },
+    /// Merging sorted runs spilled to disk with the final in-memory run.
+    Spilled(Box<SpilledRunMerger>),
 }
 
 impl SortDrainingState {
@@ -63,17 +77,24 @@ impl SortDrainingState {
     ///
     /// Returns a bool indicating if we should keep polling for more output.
     fn drain_next(&mut self, op_state: &SortOperatorState, output: &mut Batch) -> Result<bool> {
-        output.reset_for_write()?;
-
-        let count =
-            self.sorted_run
-                .scan_data(&mut self.scan_state, &op_state.queue.data_layout, output)?;
+        match self {
+            Self::InMemory {
+                scan_state,
+                sorted_run,
+            } => {
+                output.reset_for_write()?;
+
+                let count =
+                    sorted_run.scan_data(scan_state, &op_state.queue.data_layout, output)?;
+
+                if count == 0 {
+                    return Ok(false);
+                }
 
-        if count == 0 {
-            return Ok(false);
+                Ok(true)
+            }
+            Self::Spilled(merger) => merger.merge_next(output),
         }
-
-        Ok(true)
     }
 }
 
@@ -81,6 +102,8 @@ impl SortDrainingState {
 pub struct SortOperatorState {
     /// Queue for sorting sorted runs.
     queue: MergeQueue,
+    /// Sorted runs that have been spilled to disk.
+    spilled_runs: Mutex<Vec<SpillReader>>,
 }
 
 #[derive(Debug)]
@@ -88,6 +111,7 @@ pub struct PhysicalGlobalSort {
     pub(crate) sort_exprs: Vec<PhysicalSortExpression>,
     pub(crate) output_types: Vec<DataType>,
     pub(crate) limit_hint: Option<usize>,
+    pub(crate) spill: Option<Arc<SpillContext>>,
 }
 
 impl PhysicalGlobalSort {
@@ -95,13 +119,94 @@ impl PhysicalGlobalSort {
         sort_exprs: Vec<PhysicalSortExpression>,
         output_types: Vec<DataType>,
         limit_hint: Option<usize>,
+        spill: Option<Arc<SpillContext>>,
     ) -> Self {
         PhysicalGlobalSort {
             sort_exprs,
             output_types,
             limit_hint,
+            spill,
         }
     }
+
+    fn create_key_evaluator(&self, batch_size: usize) -> Result<ExpressionEvaluator> {
+        ExpressionEvaluator::try_new(
+            self.sort_exprs
+                .iter()
+                .map(|sort_expr| sort_expr.column.clone()),
+            batch_size,
+        )
+    }
+
+    /// Grows the partition's reservation to cover its collection, spilling the
+    /// collection if that would go over the memory limit.
+    fn reserve_or_spill(
+        &self,
+        operator_state: &SortOperatorState,
+        state: &mut SortCollectingState,
+        spill: &SpillContext,
+    ) -> Result<()> {
+        let Some(reservation) = &mut state.reservation else {
+            return Ok(());
+        };
+        if reservation.try_resize(state.collection.size_bytes()) {
+            return Ok(());
+        }
+
+        spill.check_can_spill(&self.output_types)?;
+        self.spill_collection(operator_state, state, spill)?;
+
+        // Shrinking always succeeds.
+        let size = state.collection.size_bytes();
+        if let Some(reservation) = &mut state.reservation {
+            reservation.try_resize(size);
+        }
+
+        Ok(())
+    }
+
+    /// Sorts and writes a partition's collection to a spill file, replacing it
+    /// with an empty collection.
+    fn spill_collection(
+        &self,
+        operator_state: &SortOperatorState,
+        state: &mut SortCollectingState,
+        spill: &SpillContext,
+    ) -> Result<()> {
+        let queue = &operator_state.queue;
+        let block_capacity = state.collection.key_block_capacity();
+        let collection = std::mem::replace(
+            &mut state.collection,
+            Box::new(PartialSortedRowCollection::new(
+                queue.key_layout.clone(),
+                queue.data_layout.clone(),
+                block_capacity,
+            )),
+        );
+        state.append = state.collection.init_append_state();
+
+        let blocks = collection.try_into_sorted_blocks(self.limit_hint)?;
+        let sorted_run = match queue.merge_sorted_blocks(blocks)? {
+            Some(run) => run,
+            None => return Ok(()),
+        };
+
+        let mut writer = spill.create_file()?;
+        let mut scan_state = sorted_run.init_scan_state();
+        let mut batch = Batch::new(queue.data_layout.types.clone(), block_capacity)?;
+        loop {
+            batch.reset_for_write()?;
+            let count = sorted_run.scan_data(&mut scan_state, &queue.data_layout, &mut batch)?;
+            if count == 0 {
+                break;
+            }
+            writer.write_batch(&batch)?;
+        }
+
+        operator_state.spilled_runs.lock().push(writer.finish()?);
+
+        Ok(())
+    }
 }
 
 impl BaseOperator for PhysicalGlobalSort {
@@ -120,6 +225,7 @@ impl BaseOperator for PhysicalGlobalSort {
 
         Ok(SortOperatorState {
             queue: MergeQueue::new(key_layout, data_layout, props.batch_size, self.limit_hint),
+            spilled_runs: Mutex::new(Vec::new()),
         })
     }
 
@@ -139,12 +245,7 @@ impl ExecuteOperator for PhysicalGlobalSort {
     ) -> Result<Vec<Self::PartitionExecuteState>> {
         let states = (0..partitions)
             .map(|idx| {
-                let evaluator = ExpressionEvaluator::try_new(
-                    self.sort_exprs
-                        .iter()
-                        .map(|sort_expr| sort_expr.column.clone()),
-                    props.batch_size,
-                )?;
+                let evaluator = self.create_key_evaluator(props.batch_size)?;
 
                 let collection = PartialSortedRowCollection::new(
                     operator_state.queue.key_layout.clone(),
@@ -162,6 +263,7 @@ impl ExecuteOperator for PhysicalGlobalSort {
                     )?,
                     collection: Box::new(collection),
                     append,
+                    reservation: self.spill.as_ref().map(|spill| spill.new_reservation()),
                 }))
             })
             .collect::<Result<Vec<_>>>()?;
@@ -206,6 +308,10 @@ impl ExecuteOperator for PhysicalGlobalSort {
                     }
                 }
 
+                if let Some(spill) = &self.spill {
+                    self.reserve_or_spill(operator_state, state, spill)?;
+                }
+
                 Ok(PollExecute::NeedsMore)
             }
             SortPartitionState::Merging(merge_state) => {
@@ -226,14 +332,39 @@ impl ExecuteOperator for PhysicalGlobalSort {
                         Ok(PollExecute::Pending)
                     }
                     PollMerge::Finished => {
-                        match operator_state.queue.take_sorted_run()? {
-                            Some(sorted_run) => {
+                        // Take the final run and spilled runs together so that
+                        // only a single partition is responsible for
+                        // draining.
+                        let (sorted_run, spilled) = {
+                            let mut spilled = operator_state.spilled_runs.lock();
+                            (
+                                operator_state.queue.take_sorted_run()?,
+                                std::mem::take(&mut *spilled),
+                            )
+                        };
+
+                        let drain_state = if spilled.is_empty() {
+                            sorted_run.map(|sorted_run| SortDrainingState::InMemory {
+                                scan_state: sorted_run.init_scan_state(),
+                                sorted_run,
+                            })
+                        } else {
+                            let batch_size = output.write_capacity()?;
+                            let merger = SpilledRunMerger::try_new(
+                                operator_state.queue.key_layout.clone(),
+                                operator_state.queue.data_layout.clone(),
+                                self.create_key_evaluator(batch_size)?,
+                                sorted_run,
+                                spilled,
+                                batch_size,
+                            )?;
+                            Some(SortDrainingState::Spilled(Box::new(merger)))
+                        };
+
+                        match drain_state {
+                            Some(mut drain_state) => {
                                 // This partition is responsible for draining.
-                                let mut drain_state = SortDrainingState {
-                                    scan_state: sorted_run.init_scan_state(),
-                                    sorted_run,
-                                };
-
+                                //
                                 // TODO: We could probably drop the key blocks
                                 // here since we don't scan those.
 
@@ -331,6 +462,7 @@ mod tests {
     use crate::testutil::arrays::assert_batches_eq;
     use crate::testutil::exprs::plan_scalar;
     use crate::testutil::operator::OperatorWrapper;
+    use crate::testutil::spill::test_spill_context;
     use crate::{expr, generate_batch};
 
     fn new_sort_operator(
@@ -346,6 +478,7 @@ mod tests {
             sort_exprs.into_iter().collect(),
             output_types.into_iter().collect(),
             None,
+            None,
         ));
         let props = ExecutionProperties { batch_size: 16 };
         let op_state = wrapper.operator.create_operator_state(props).unwrap();
@@ -401,6 +534,71 @@ mod tests {
         assert_eq!(0, output.num_rows);
     }
 
+    #[test]
+    fn sort_spill_single_partition() {
+        // SORT: c1
+        let mut list = TableList::empty();
+        let t0 = list.push_table(None, [DataType::int32()], ["c1"]).unwrap();
+
+        let sort_exprs = vec![PhysicalSortExpression {
+            column: plan_scalar(&list, expr::column((t0, 0), DataType::int32())),
+            desc: false,
+            nulls_first: false,
+        }];
+
+        // Limit small enough that every batch gets spilled.
+        let (spill, fs) = test_spill_context(1);
+        let wrapper = OperatorWrapper::new(PhysicalGlobalSort::new(
+            sort_exprs,
+            vec![DataType::int32()],
+            None,
+            Some(spill),
+        ));
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 1)
+            .unwrap();
+
+        let mut output = Batch::new([DataType::int32()], 16).unwrap();
+
+        let inputs = [
+            generate_batch!([4, 3, 1, 5]),
+            generate_batch!([9, 2, 8]),
+            generate_batch!([7, 6, 0]),
+        ];
+        for (idx, mut input) in inputs.into_iter().enumerate() {
+            let poll = wrapper
+                .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+                .unwrap();
+            assert_eq!(PollExecute::NeedsMore, poll);
+            assert_eq!(idx + 1, fs.num_files());
+        }
+
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+
+        let mut input = Batch::new([DataType::int32()], 0).unwrap();
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::HasMore, poll);
+
+        let expected = generate_batch!([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_batches_eq(&expected, &output);
+
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::Exhausted, poll);
+
+        // Spill files removed once fully read.
+        assert_eq!(0, fs.num_files());
+    }
+
     #[test]
     fn sort_single_column_multiple_partitions() {
         // SORT: c1
@@ -185,6 +185,35 @@ impl MergeQueue {
         Ok(PollMerge::Merged)
     }
 
+    /// Merges sorted blocks into a single sorted run outside of the queue.
+    ///
+    /// Used when spilling a partition's sorted blocks to disk. Returns None if
+    /// there are no blocks.
+    pub fn merge_sorted_blocks(&self, blocks: Vec<SortedBlock>) -> Result<Option<SortedSegment>> {
+        let mut runs: VecDeque<_> = blocks
+            .into_iter()
+            .map(SortedSegment::from_sorted_block)
+            .collect();
+
+        let merger = BinaryMerger::new(
+            &DefaultBufferManager,
+            &self.key_layout,
+            &self.data_layout,
+            self.block_capacity,
+        );
+
+        while runs.len() > 1 {
+            let left = runs.pop_front().unwrap();
+            let right = runs.pop_front().unwrap();
+
+            let mut state = merger.init_merge_state();
+            let out = merger.merge(&mut state, left, right, self.limit_hint)?;
+            runs.push_back(out);
+        }
+
+        Ok(runs.pop_front())
+    }
+
     /// Takes the final sorted run from the queue.
     ///
     /// Errors if the queue isn't finished.
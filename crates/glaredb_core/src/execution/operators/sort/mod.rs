@@ -1,3 +1,4 @@
 pub mod global_sort;
 
 mod merge_queue;
+mod spill_merge;
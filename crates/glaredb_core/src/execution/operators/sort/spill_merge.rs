@@ -0,0 +1,281 @@
+use std::cmp::Ordering;
+
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{Addressable, PhysicalBinary, ScalarStorage};
+use crate::arrays::batch::Batch;
+use crate::arrays::row::row_blocks::BlockAppendState;
+use crate::arrays::row::row_layout::RowLayout;
+use crate::arrays::sort::sort_layout::SortLayout;
+use crate::arrays::sort::sorted_segment::{SortedSegment, SortedSegmentScanState};
+use crate::execution::spill::SpillReader;
+use crate::expr::physical::evaluator::ExpressionEvaluator;
+
+/// Merges sorted runs that have been spilled to disk along with the final
+/// in-memory sorted run.
+///
+/// Each run is read a batch at a time. Sort keys are recomputed from the data
+/// and encoded using the same sort layout used for in-memory sorting so that
+/// merged output is ordered identically to what an in-memory sort would
+/// produce.
+#[derive(Debug)]
+pub struct SpilledRunMerger {
+    key_layout: SortLayout,
+    data_layout: RowLayout,
+    /// Evaluator for computing sort keys from data batches.
+    evaluator: ExpressionEvaluator,
+    /// Reusable state for encoding keys.
+    append: BlockAppendState,
+    sources: Vec<MergeSource>,
+}
+
+#[derive(Debug)]
+enum MergeInput {
+    InMemory {
+        segment: SortedSegment,
+        scan_state: SortedSegmentScanState,
+    },
+    Spilled(SpillReader),
+}
+
+#[derive(Debug)]
+struct MergeSource {
+    input: MergeInput,
+    /// Current batch of data for this run.
+    data: Batch,
+    /// Sort keys computed from `data`.
+    keys: Batch,
+    /// Encoded sort keys for each row in `keys`.
+    encoded: Vec<u8>,
+    /// Current row in the batch.
+    row_idx: usize,
+    /// Rows from the current batch that have been picked, but not yet copied
+    /// to the output.
+    ///
+    /// (source_row, output_row)
+    pending: Vec<(usize, usize)>,
+    exhausted: bool,
+}
+
+impl MergeSource {
+    fn has_current_row(&self) -> bool {
+        self.row_idx < self.data.num_rows()
+    }
+
+    fn flush_pending(&mut self, output: &mut Batch) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        self.data.copy_rows(self.pending.iter().copied(), output)?;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+impl SpilledRunMerger {
+    pub fn try_new(
+        key_layout: SortLayout,
+        data_layout: RowLayout,
+        evaluator: ExpressionEvaluator,
+        in_memory: Option<SortedSegment>,
+        spilled: Vec<SpillReader>,
+        batch_size: usize,
+    ) -> Result<Self> {
+        let inputs = in_memory
+            .map(|segment| MergeInput::InMemory {
+                scan_state: segment.init_scan_state(),
+                segment,
+            })
+            .into_iter()
+            .chain(spilled.into_iter().map(MergeInput::Spilled));
+
+        let sources = inputs
+            .map(|input| {
+                Ok(MergeSource {
+                    input,
+                    data: Batch::new(data_layout.types.clone(), batch_size)?,
+                    keys: Batch::new(key_layout.datatypes(), batch_size)?,
+                    encoded: Vec::new(),
+                    row_idx: 0,
+                    pending: Vec::new(),
+                    exhausted: false,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(SpilledRunMerger {
+            key_layout,
+            data_layout,
+            evaluator,
+            append: BlockAppendState {
+                row_pointers: Vec::new(),
+                heap_pointers: Vec::new(),
+            },
+            sources,
+        })
+    }
+
+    /// Merges the next set of rows into the output batch.
+    ///
+    /// Returns a bool indicating if we should keep polling for more output.
+    pub fn merge_next(&mut self, output: &mut Batch) -> Result<bool> {
+        output.reset_for_write()?;
+        let capacity = output.write_capacity()?;
+
+        let mut count = 0;
+        while count < capacity {
+            for source_idx in 0..self.sources.len() {
+                if !self.sources[source_idx].exhausted
+                    && !self.sources[source_idx].has_current_row()
+                {
+                    self.sources[source_idx].flush_pending(output)?;
+                    self.load_next(source_idx)?;
+                }
+            }
+
+            let mut min: Option<usize> = None;
+            for (source_idx, source) in self.sources.iter().enumerate() {
+                if source.exhausted {
+                    continue;
+                }
+                min = match min {
+                    Some(curr) if self.compare(curr, source_idx)? != Ordering::Greater => {
+                        Some(curr)
+                    }
+                    _ => Some(source_idx),
+                };
+            }
+
+            let source = match min {
+                Some(idx) => &mut self.sources[idx],
+                None => break,
+            };
+
+            source.pending.push((source.row_idx, count));
+            source.row_idx += 1;
+            count += 1;
+        }
+
+        for source in &mut self.sources {
+            source.flush_pending(output)?;
+        }
+        output.set_num_rows(count)?;
+
+        Ok(count > 0)
+    }
+
+    /// Loads the next batch for a source, computing and encoding the keys for
+    /// the batch.
+    fn load_next(&mut self, source_idx: usize) -> Result<()> {
+        let source = &mut self.sources[source_idx];
+        source.row_idx = 0;
+
+        source.data.reset_for_write()?;
+        let has_more = match &mut source.input {
+            MergeInput::InMemory {
+                segment,
+                scan_state,
+            } => segment.scan_data(scan_state, &self.data_layout, &mut source.data)? > 0,
+            MergeInput::Spilled(reader) => reader.read_batch(&mut source.data)?,
+        };
+
+        if !has_more {
+            source.exhausted = true;
+            source.data.set_num_rows(0)?;
+            return Ok(());
+        }
+
+        let num_rows = source.data.num_rows();
+        let selection = source.data.selection();
+        self.evaluator
+            .eval_batch(&mut source.data, selection, &mut source.keys)?;
+
+        let row_width = self.key_layout.row_width;
+        source.encoded.clear();
+        source.encoded.resize(row_width * num_rows, 0);
+
+        let ptr = source.encoded.as_mut_ptr();
+        self.append.row_pointers.clear();
+        self.append
+            .row_pointers
+            .extend((0..num_rows).map(|row_idx| unsafe { ptr.byte_add(row_idx * row_width) }));
+
+        // SAFETY: Row pointers point to rows of `row_width` bytes in the encoded
+        // buffer we just resized.
+        unsafe {
+            self.key_layout
+                .write_key_arrays(&mut self.append, &source.keys.arrays, num_rows)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compares the current rows of two sources.
+    fn compare(&self, left_idx: usize, right_idx: usize) -> Result<Ordering> {
+        let left = &self.sources[left_idx];
+        let right = &self.sources[right_idx];
+
+        let row_width = self.key_layout.row_width;
+        let left_row = &left.encoded[left.row_idx * row_width..(left.row_idx + 1) * row_width];
+        let right_row = &right.encoded[right.row_idx * row_width..(right.row_idx + 1) * row_width];
+
+        if !self.key_layout.any_requires_heap() {
+            let width = self.key_layout.compare_width;
+            return Ok(left_row[..width].cmp(&right_row[..width]));
+        }
+
+        for col_idx in 0..self.key_layout.num_columns() {
+            let offset = self.key_layout.offsets[col_idx];
+            let width = self.key_layout.column_widths[col_idx];
+
+            let mut ord = left_row[offset..offset + width].cmp(&right_row[offset..offset + width]);
+            if ord == Ordering::Equal && self.key_layout.column_requires_heap(col_idx) {
+                // Prefixes are equal, compare the full values.
+                ord = compare_binary_values(
+                    &left.keys.arrays[col_idx],
+                    left.row_idx,
+                    &right.keys.arrays[col_idx],
+                    right.row_idx,
+                )?;
+                if self.key_layout.columns[col_idx].desc {
+                    ord = ord.reverse();
+                }
+            }
+
+            if ord != Ordering::Equal {
+                return Ok(ord);
+            }
+        }
+
+        Ok(Ordering::Equal)
+    }
+}
+
+/// Compares varlen values in two arrays.
+///
+/// If the prefixes were equal, then either both values are valid or both are
+/// NULL.
+fn compare_binary_values(
+    left: &Array,
+    left_idx: usize,
+    right: &Array,
+    right_idx: usize,
+) -> Result<Ordering> {
+    if !left.validity.is_valid(left_idx) || !right.validity.is_valid(right_idx) {
+        return Ok(Ordering::Equal);
+    }
+
+    let left_buf = PhysicalBinary::downcast_execution_format(&left.data)?.into_selection_format()?;
+    let right_buf =
+        PhysicalBinary::downcast_execution_format(&right.data)?.into_selection_format()?;
+
+    let left_val = PhysicalBinary::addressable(left_buf.buffer)
+        .get(left_buf.selection.get(left_idx).unwrap())
+        .unwrap();
+    let right_val = PhysicalBinary::addressable(right_buf.buffer)
+        .get(right_buf.selection.get(right_idx).unwrap())
+        .unwrap();
+
+    Ok(left_val.cmp(right_val))
+}
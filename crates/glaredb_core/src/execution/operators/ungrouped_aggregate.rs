@@ -173,7 +173,7 @@ impl BaseOperator for PhysicalUngroupedAggregate {
                 }
             }))?;
         let distinct_collection_op_state =
-            distinct_collection.create_operator_state(props.batch_size)?;
+            distinct_collection.create_operator_state(props.batch_size, None)?;
 
         Ok(UngroupedAggregateOperatorState {
             batch_size: props.batch_size,
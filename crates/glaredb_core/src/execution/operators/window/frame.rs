@@ -0,0 +1,685 @@
+use std::fmt;
+use std::ops::Range;
+
+use chrono::{DateTime, Months};
+use glaredb_error::{DbError, Result};
+
+use super::PeerGroup;
+use crate::arrays::array::Array;
+use crate::arrays::datatype::TimeUnit;
+use crate::arrays::scalar::interval::Interval;
+use crate::arrays::scalar::{BorrowedScalarValue, ScalarValue};
+use crate::expr::window_expr::{
+    WindowFrameBound,
+    WindowFrameExclusion,
+    WindowFrameUnit,
+    scalar_as_f64,
+};
+
+const MICROSECONDS_IN_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+
+/// Physical representation of a window frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowFrame {
+    pub unit: WindowFrameUnit,
+    pub start: FrameBound,
+    pub end: FrameBound,
+    pub exclude: WindowFrameExclusion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(FrameOffset),
+    CurrentRow,
+    Following(FrameOffset),
+    UnboundedFollowing,
+}
+
+/// Offset for a PRECEDING or FOLLOWING bound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameOffset {
+    /// Number of rows (ROWS) or peer groups (GROUPS).
+    Count(usize),
+    /// Offset for a RANGE frame ordered by a numeric column.
+    Numeric(f64),
+    /// Offset for a RANGE frame ordered by a date or timestamp column.
+    Interval(Interval),
+}
+
+impl WindowFrame {
+    /// The default frame, `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`.
+    pub const DEFAULT: Self = WindowFrame {
+        unit: WindowFrameUnit::Range,
+        start: FrameBound::UnboundedPreceding,
+        end: FrameBound::CurrentRow,
+        exclude: WindowFrameExclusion::ExcludeNoOthers,
+    };
+
+    pub fn try_from_bounds(
+        start: &WindowFrameBound,
+        end: &WindowFrameBound,
+        exclude: WindowFrameExclusion,
+    ) -> Result<Self> {
+        Ok(WindowFrame {
+            unit: start.unit(),
+            start: FrameBound::try_from_bound(start)?,
+            end: FrameBound::try_from_bound(end)?,
+            exclude,
+        })
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::DEFAULT
+    }
+
+    /// Returns if computing this frame requires searching on the values of
+    /// the ORDER BY column.
+    pub fn has_range_offset(&self) -> bool {
+        self.unit == WindowFrameUnit::Range && (self.start.has_offset() || self.end.has_offset())
+    }
+}
+
+impl FrameBound {
+    fn try_from_bound(bound: &WindowFrameBound) -> Result<Self> {
+        Ok(match bound {
+            WindowFrameBound::UnboundedPreceding(_) => FrameBound::UnboundedPreceding,
+            WindowFrameBound::Preceding(unit, offset) => {
+                FrameBound::Preceding(FrameOffset::try_new(*unit, offset)?)
+            }
+            WindowFrameBound::CurrentRow(_) => FrameBound::CurrentRow,
+            WindowFrameBound::Following(unit, offset) => {
+                FrameBound::Following(FrameOffset::try_new(*unit, offset)?)
+            }
+            WindowFrameBound::UnboundedFollowing(_) => FrameBound::UnboundedFollowing,
+        })
+    }
+
+    const fn has_offset(&self) -> bool {
+        matches!(self, FrameBound::Preceding(_) | FrameBound::Following(_))
+    }
+}
+
+impl FrameOffset {
+    fn try_new(unit: WindowFrameUnit, offset: &ScalarValue) -> Result<Self> {
+        match (unit, offset) {
+            (WindowFrameUnit::Range, ScalarValue::Interval(interval)) => {
+                Ok(FrameOffset::Interval(*interval))
+            }
+            (WindowFrameUnit::Range, other) => scalar_as_f64(other)
+                .map(FrameOffset::Numeric)
+                .ok_or_else(|| DbError::new(format!("Invalid RANGE frame offset: {other}"))),
+            (_, other) => Ok(FrameOffset::Count(other.try_as_usize()?)),
+        }
+    }
+}
+
+impl fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} BETWEEN {} AND {}", self.unit, self.start, self.end)?;
+        if self.exclude != WindowFrameExclusion::ExcludeNoOthers {
+            write!(f, " {}", self.exclude)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for FrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            Self::Preceding(offset) => write!(f, "{offset} PRECEDING"),
+            Self::CurrentRow => write!(f, "CURRENT ROW"),
+            Self::Following(offset) => write!(f, "{offset} FOLLOWING"),
+            Self::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl fmt::Display for FrameOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Count(count) => write!(f, "{count}"),
+            Self::Numeric(offset) => write!(f, "{offset}"),
+            Self::Interval(interval) => write!(f, "INTERVAL '{interval}'"),
+        }
+    }
+}
+
+/// Values of the single ORDER BY column, used for finding the bounds of RANGE
+/// frames with offsets.
+#[derive(Debug)]
+enum RangeKeys {
+    Numeric(Vec<Option<f64>>),
+    /// Dates and timestamps as microseconds since the epoch.
+    Temporal(Vec<Option<i64>>),
+}
+
+impl RangeKeys {
+    fn try_from_array(array: &Array, num_rows: usize) -> Result<Self> {
+        if array.datatype().is_numeric() {
+            let keys = (0..num_rows)
+                .map(|row| {
+                    let value = array.get_value(row)?;
+                    if value.is_null() {
+                        return Ok(None);
+                    }
+                    scalar_as_f64(&value).map(Some).ok_or_else(|| {
+                        DbError::new(format!("Unexpected RANGE ORDER BY value: {value}"))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(RangeKeys::Numeric(keys));
+        }
+
+        let keys = (0..num_rows)
+            .map(|row| {
+                Ok(match array.get_value(row)? {
+                    BorrowedScalarValue::Null => None,
+                    BorrowedScalarValue::Date32(days) => Some((days as i64) * MICROSECONDS_IN_DAY),
+                    BorrowedScalarValue::Date64(millis) => Some(millis.saturating_mul(1_000)),
+                    BorrowedScalarValue::Time(micros) => Some(micros),
+                    BorrowedScalarValue::Timestamp(ts) => Some(match ts.unit {
+                        TimeUnit::Second => ts.value.saturating_mul(1_000_000),
+                        TimeUnit::Millisecond => ts.value.saturating_mul(1_000),
+                        TimeUnit::Microsecond => ts.value,
+                        TimeUnit::Nanosecond => ts.value.div_euclid(1_000),
+                    }),
+                    other => {
+                        return Err(DbError::new(format!(
+                            "Unexpected RANGE ORDER BY value: {other}"
+                        )));
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RangeKeys::Temporal(keys))
+    }
+
+    fn is_null(&self, row: usize) -> bool {
+        match self {
+            RangeKeys::Numeric(keys) => keys[row].is_none(),
+            RangeKeys::Temporal(keys) => keys[row].is_none(),
+        }
+    }
+}
+
+/// A window partition within a bucket of sorted rows.
+#[derive(Debug)]
+struct FramePartition {
+    /// Rows in this partition.
+    rows: Range<usize>,
+    /// Peer groups in this partition.
+    groups: Range<usize>,
+    /// Rows with a non-null ORDER BY value. Only used for RANGE frames with
+    /// offsets.
+    non_null: Range<usize>,
+}
+
+/// Computes window frames for rows in a bucket of sorted rows.
+#[derive(Debug)]
+pub(crate) struct FrameRanges<'a> {
+    groups: &'a [PeerGroup],
+    /// Peer group index for each row.
+    row_groups: Vec<usize>,
+    /// Partition index for each peer group.
+    group_partitions: Vec<usize>,
+    partitions: Vec<FramePartition>,
+    /// ORDER BY values if we have a RANGE frame with offsets.
+    keys: Option<RangeKeys>,
+    /// If the ORDER BY column is sorted descending.
+    desc: bool,
+}
+
+impl<'a> FrameRanges<'a> {
+    /// Create frame state for sorted rows.
+    ///
+    /// `range_key` should be the first ORDER BY column along with its sort
+    /// direction and null ordering if any frame is a RANGE frame with an
+    /// offset.
+    pub fn try_new(
+        groups: &'a [PeerGroup],
+        num_rows: usize,
+        range_key: Option<(&Array, bool, bool)>,
+    ) -> Result<Self> {
+        let mut row_groups = Vec::with_capacity(num_rows);
+        let mut group_partitions = Vec::with_capacity(groups.len());
+        let mut partitions: Vec<FramePartition> = Vec::new();
+
+        for (idx, group) in groups.iter().enumerate() {
+            if group.new_partition {
+                partitions.push(FramePartition {
+                    rows: group.start..group.end,
+                    groups: idx..(idx + 1),
+                    non_null: group.start..group.end,
+                });
+            } else {
+                let partition = partitions
+                    .last_mut()
+                    .ok_or_else(|| DbError::new("First peer group must start a partition"))?;
+                partition.rows.end = group.end;
+                partition.groups.end = idx + 1;
+                partition.non_null.end = group.end;
+            }
+            group_partitions.push(partitions.len() - 1);
+            row_groups.extend(std::iter::repeat_n(idx, group.end - group.start));
+        }
+
+        let (keys, desc) = match range_key {
+            Some((array, desc, nulls_first)) => {
+                let keys = RangeKeys::try_from_array(array, num_rows)?;
+                // Nulls are contiguous at either the start or end of each
+                // partition.
+                for partition in &mut partitions {
+                    if nulls_first {
+                        while partition.non_null.start < partition.non_null.end
+                            && keys.is_null(partition.non_null.start)
+                        {
+                            partition.non_null.start += 1;
+                        }
+                    } else {
+                        while partition.non_null.start < partition.non_null.end
+                            && keys.is_null(partition.non_null.end - 1)
+                        {
+                            partition.non_null.end -= 1;
+                        }
+                    }
+                }
+                (Some(keys), desc)
+            }
+            None => (None, false),
+        };
+
+        Ok(FrameRanges {
+            groups,
+            row_groups,
+            group_partitions,
+            partitions,
+            keys,
+            desc,
+        })
+    }
+
+    /// Writes the ranges of rows making up the frame for `row` to `out`.
+    ///
+    /// Ranges are written in row order and will not be empty. There may be
+    /// multiple ranges if the frame excludes rows.
+    pub fn frame_ranges(
+        &self,
+        frame: &WindowFrame,
+        row: usize,
+        out: &mut Vec<Range<usize>>,
+    ) -> Result<()> {
+        out.clear();
+
+        let group_idx = self.row_groups[row];
+        let group = &self.groups[group_idx];
+        let partition = &self.partitions[self.group_partitions[group_idx]];
+
+        let start = self.bound_position(frame, frame.start, true, row, group_idx, partition)?;
+        let end = self.bound_position(frame, frame.end, false, row, group_idx, partition)?;
+        let end = usize::max(start, end);
+
+        let mut push = |range: Range<usize>| {
+            if range.start < range.end {
+                out.push(range);
+            }
+        };
+
+        match frame.exclude {
+            WindowFrameExclusion::ExcludeNoOthers => push(start..end),
+            WindowFrameExclusion::ExcludeCurrentRow => {
+                push(start..usize::min(end, row));
+                push(usize::max(start, row + 1)..end);
+            }
+            WindowFrameExclusion::ExcludeGroup => {
+                push(start..usize::min(end, group.start));
+                push(usize::max(start, group.end)..end);
+            }
+            WindowFrameExclusion::ExcludeTies => {
+                push(start..usize::min(end, group.start));
+                push(usize::max(start, row)..usize::min(end, row + 1));
+                push(usize::max(start, group.end)..end);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compute the position of a frame bound for a row.
+    ///
+    /// For the start bound, this is the first row in the frame. For the end
+    /// bound, this is one past the last row in the frame.
+    fn bound_position(
+        &self,
+        frame: &WindowFrame,
+        bound: FrameBound,
+        is_start: bool,
+        row: usize,
+        group_idx: usize,
+        partition: &FramePartition,
+    ) -> Result<usize> {
+        let group = &self.groups[group_idx];
+
+        let (offset, preceding) = match bound {
+            FrameBound::UnboundedPreceding => return Ok(partition.rows.start),
+            FrameBound::UnboundedFollowing => return Ok(partition.rows.end),
+            FrameBound::CurrentRow => {
+                return Ok(match (frame.unit, is_start) {
+                    (WindowFrameUnit::Rows, true) => row,
+                    (WindowFrameUnit::Rows, false) => row + 1,
+                    (_, true) => group.start,
+                    (_, false) => group.end,
+                });
+            }
+            FrameBound::Preceding(offset) => (offset, true),
+            FrameBound::Following(offset) => (offset, false),
+        };
+
+        match (frame.unit, offset) {
+            (WindowFrameUnit::Rows, FrameOffset::Count(n)) => {
+                let target = offset_index(row, n, preceding);
+                let pos = if is_start {
+                    target
+                } else {
+                    target.saturating_add(1)
+                };
+                Ok(pos.clamp(partition.rows.start as i64, partition.rows.end as i64) as usize)
+            }
+            (WindowFrameUnit::Groups, FrameOffset::Count(n)) => {
+                let target = offset_index(group_idx, n, preceding);
+                if target < partition.groups.start as i64 {
+                    Ok(partition.rows.start)
+                } else if target >= partition.groups.end as i64 {
+                    Ok(partition.rows.end)
+                } else if is_start {
+                    Ok(self.groups[target as usize].start)
+                } else {
+                    Ok(self.groups[target as usize].end)
+                }
+            }
+            (WindowFrameUnit::Range, offset) => {
+                let keys = self
+                    .keys
+                    .as_ref()
+                    .ok_or_else(|| DbError::new("Missing ORDER BY values for RANGE frame"))?;
+
+                // Rows with a NULL ORDER BY value only have their peers in the
+                // frame.
+                if keys.is_null(row) {
+                    return Ok(if is_start { group.start } else { group.end });
+                }
+
+                // Whether the offset moves towards smaller values.
+                let subtract = preceding != self.desc;
+                let search = partition.non_null.clone();
+
+                match (keys, offset) {
+                    (RangeKeys::Numeric(keys), FrameOffset::Numeric(offset)) => {
+                        let key = keys[row].unwrap_or_default();
+                        let target = if subtract { key - offset } else { key + offset };
+                        Ok(search_sorted(keys, search, target, self.desc, is_start))
+                    }
+                    (RangeKeys::Temporal(keys), FrameOffset::Interval(interval)) => {
+                        let key = keys[row].unwrap_or_default();
+                        let target = add_interval(key, interval, subtract);
+                        Ok(search_sorted(keys, search, target, self.desc, is_start))
+                    }
+                    (keys, offset) => Err(DbError::new(format!(
+                        "Invalid RANGE frame offset {offset:?} for ORDER BY values {keys:?}"
+                    ))),
+                }
+            }
+            (unit, offset) => Err(DbError::new(format!(
+                "Invalid frame offset {offset:?} for unit {unit}"
+            ))),
+        }
+    }
+}
+
+/// Move `idx` by `n` positions, returning the new (possibly out of bounds)
+/// position.
+fn offset_index(idx: usize, n: usize, preceding: bool) -> i64 {
+    let n = i64::try_from(n).unwrap_or(i64::MAX);
+    if preceding {
+        (idx as i64).saturating_sub(n)
+    } else {
+        (idx as i64).saturating_add(n)
+    }
+}
+
+/// Search sorted keys for a frame bound.
+///
+/// For the start bound, returns the first row with a key not before `target`.
+/// For the end bound, returns the first row with a key after `target`.
+fn search_sorted<T>(
+    keys: &[Option<T>],
+    range: Range<usize>,
+    target: T,
+    desc: bool,
+    is_start: bool,
+) -> usize
+where
+    T: PartialOrd + Copy,
+{
+    let pos = keys[range.clone()].partition_point(|key| {
+        key.is_some_and(|key| match (desc, is_start) {
+            (false, true) => key < target,
+            (false, false) => key <= target,
+            (true, true) => key > target,
+            (true, false) => key >= target,
+        })
+    });
+    range.start + pos
+}
+
+/// Add (or subtract) an interval to a timestamp in microseconds, saturating on
+/// overflow.
+fn add_interval(micros: i64, interval: Interval, subtract: bool) -> i64 {
+    let saturated = if subtract { i64::MIN } else { i64::MAX };
+
+    let mut value = micros;
+    if interval.months != 0 {
+        let months = Months::new(interval.months.unsigned_abs());
+        let months_added = DateTime::from_timestamp_micros(value).and_then(|dt| {
+            if subtract == (interval.months > 0) {
+                dt.checked_sub_months(months)
+            } else {
+                dt.checked_add_months(months)
+            }
+        });
+        value = match months_added {
+            Some(dt) => dt.timestamp_micros(),
+            None => return saturated,
+        };
+    }
+
+    let rest = (interval.days as i64)
+        .saturating_mul(MICROSECONDS_IN_DAY)
+        .saturating_add(interval.nanos / 1_000);
+
+    if subtract {
+        value.saturating_sub(rest)
+    } else {
+        value.saturating_add(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn frame(unit: WindowFrameUnit, start: FrameBound, end: FrameBound) -> WindowFrame {
+        WindowFrame {
+            unit,
+            start,
+            end,
+            exclude: WindowFrameExclusion::ExcludeNoOthers,
+        }
+    }
+
+    fn all_ranges(
+        ranges: &FrameRanges,
+        frame: &WindowFrame,
+        num_rows: usize,
+    ) -> Vec<Vec<Range<usize>>> {
+        let mut out = Vec::new();
+        (0..num_rows)
+            .map(|row| {
+                ranges.frame_ranges(frame, row, &mut out).unwrap();
+                out.clone()
+            })
+            .collect()
+    }
+
+    /// Two partitions, rows 0..4 and 4..6, with peers 1 and 2.
+    const GROUPS: &[PeerGroup] = &[
+        PeerGroup {
+            start: 0,
+            end: 1,
+            new_partition: true,
+        },
+        PeerGroup {
+            start: 1,
+            end: 3,
+            new_partition: false,
+        },
+        PeerGroup {
+            start: 3,
+            end: 4,
+            new_partition: false,
+        },
+        PeerGroup {
+            start: 4,
+            end: 5,
+            new_partition: true,
+        },
+        PeerGroup {
+            start: 5,
+            end: 6,
+            new_partition: false,
+        },
+    ];
+
+    #[test]
+    fn display_frame() {
+        assert_eq!(
+            "RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW",
+            WindowFrame::DEFAULT.to_string()
+        );
+
+        let frame = WindowFrame {
+            unit: WindowFrameUnit::Rows,
+            start: FrameBound::Preceding(FrameOffset::Count(2)),
+            end: FrameBound::Following(FrameOffset::Count(1)),
+            exclude: WindowFrameExclusion::ExcludeTies,
+        };
+        assert_eq!(
+            "ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING EXCLUDE TIES",
+            frame.to_string()
+        );
+    }
+
+    #[test]
+    fn rows_preceding_and_following() {
+        let ranges = FrameRanges::try_new(GROUPS, 6, None).unwrap();
+        let frame = frame(
+            WindowFrameUnit::Rows,
+            FrameBound::Preceding(FrameOffset::Count(1)),
+            FrameBound::Following(FrameOffset::Count(1)),
+        );
+
+        let got = all_ranges(&ranges, &frame, 6);
+        let expected = vec![
+            vec![0..2],
+            vec![0..3],
+            vec![1..4],
+            vec![2..4],
+            vec![4..6],
+            vec![4..6],
+        ];
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn groups_preceding() {
+        let ranges = FrameRanges::try_new(GROUPS, 6, None).unwrap();
+        let frame = frame(
+            WindowFrameUnit::Groups,
+            FrameBound::Preceding(FrameOffset::Count(1)),
+            FrameBound::CurrentRow,
+        );
+
+        let got = all_ranges(&ranges, &frame, 6);
+        let expected = vec![
+            vec![0..1],
+            vec![0..3],
+            vec![0..3],
+            vec![1..4],
+            vec![4..5],
+            vec![4..6],
+        ];
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn exclude_ties_and_current_row() {
+        let ranges = FrameRanges::try_new(GROUPS, 6, None).unwrap();
+        let mut frame = frame(
+            WindowFrameUnit::Rows,
+            FrameBound::UnboundedPreceding,
+            FrameBound::UnboundedFollowing,
+        );
+
+        frame.exclude = WindowFrameExclusion::ExcludeTies;
+        let got = all_ranges(&ranges, &frame, 6);
+        assert_eq!(vec![0..1, 1..2, 3..4], got[1]);
+        assert_eq!(vec![0..1, 2..3, 3..4], got[2]);
+
+        frame.exclude = WindowFrameExclusion::ExcludeCurrentRow;
+        let got = all_ranges(&ranges, &frame, 6);
+        assert_eq!(vec![1..4], got[0]);
+        assert_eq!(vec![0..2, 3..4], got[2]);
+    }
+
+    #[test]
+    fn range_numeric_offset_with_nulls() {
+        // Partition of [NULL, 1, 2, 4, 7], nulls first.
+        let groups: Vec<_> = (0..5)
+            .map(|idx| PeerGroup {
+                start: idx,
+                end: idx + 1,
+                new_partition: idx == 0,
+            })
+            .collect();
+        let keys = Array::try_from_iter([None, Some(1_i64), Some(2), Some(4), Some(7)]).unwrap();
+        assert_eq!(&DataType::int64(), keys.datatype());
+
+        let ranges = FrameRanges::try_new(&groups, 5, Some((&keys, false, true))).unwrap();
+        let frame = frame(
+            WindowFrameUnit::Range,
+            FrameBound::Preceding(FrameOffset::Numeric(2.0)),
+            FrameBound::CurrentRow,
+        );
+
+        let got = all_ranges(&ranges, &frame, 5);
+        let expected = vec![vec![0..1], vec![1..2], vec![1..3], vec![2..4], vec![4..5]];
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn add_interval_months() {
+        // 2024-03-31 00:00:00
+        let micros = 1711843200 * 1_000_000;
+        // Minus one month clamps to the end of February.
+        let got = add_interval(micros, Interval::new(1, 0, 0), true);
+        // 2024-02-29 00:00:00
+        assert_eq!(1709164800 * 1_000_000, got);
+
+        let got = add_interval(micros, Interval::new(0, 7, 0), false);
+        assert_eq!(micros + 7 * MICROSECONDS_IN_DAY, got);
+    }
+}
@@ -0,0 +1,1228 @@
+pub mod frame;
+
+mod segment_tree;
+
+use std::ops::Range;
+use std::task::Context;
+
+use frame::{FrameRanges, WindowFrame};
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+use segment_tree::{FramePiece, SegmentTree};
+
+use super::util::delayed_count::DelayedPartitionCount;
+use super::util::partition_wakers::PartitionWakers;
+use super::{BaseOperator, ExecuteOperator, ExecutionProperties, PollExecute, PollFinalize};
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::cache::NopCache;
+use crate::arrays::compute::hash::hash_many_arrays;
+use crate::arrays::datatype::DataType;
+use crate::arrays::row::row_layout::RowLayout;
+use crate::arrays::sort::partial_sort::PartialSortedRowCollection;
+use crate::arrays::sort::sort_layout::{SortColumn, SortLayout};
+use crate::arrays::sort::sorted_segment::SortedSegment;
+use crate::buffer::buffer_manager::DefaultBufferManager;
+use crate::buffer::db_vec::DbVec;
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::evaluator::ExpressionEvaluator;
+use crate::expr::physical::{
+    PhysicalScalarExpression,
+    PhysicalSortExpression,
+    PhysicalWindowExpression,
+};
+use crate::expr::window_expr::WindowExprFunction;
+use crate::functions::aggregate::PlannedAggregateFunction;
+use crate::functions::window::{PlannedWindowFunction, WindowPartition};
+
+#[derive(Debug)]
+pub enum WindowPartitionState {
+    /// Partition is collecting rows, writing them to the bucket determined by
+    /// the partition keys.
+    Collecting(WindowCollectingState),
+    /// Partition is waiting for all other partitions to finish collecting.
+    Waiting { partition_idx: usize },
+    /// Partition is draining the computed window values for its bucket.
+    Draining(WindowDrainingState),
+    /// Partition is finished.
+    Finished,
+}
+
+#[derive(Debug)]
+pub struct WindowCollectingState {
+    partition_idx: usize,
+    /// Evaluator for partition keys, sort keys, and window inputs.
+    evaluator: ExpressionEvaluator,
+    /// Output of the evaluator.
+    evaluated: Batch,
+    /// Reusable buffer for partition key hashes.
+    hashes: Vec<u64>,
+    /// Reusable buffers for the row selection for each bucket.
+    selections: Vec<Vec<usize>>,
+    /// Collected rows for each bucket.
+    ///
+    /// Bucket 'i' will be computed by partition 'i'.
+    buckets: Vec<Vec<Batch>>,
+}
+
+#[derive(Debug)]
+pub struct WindowDrainingState {
+    /// All rows for this partition's bucket, sorted by partition and sort keys.
+    rows: Batch,
+    /// Computed window values, one array per window expression.
+    ///
+    /// Each array has the same length as `rows`.
+    values: Vec<Array>,
+    /// Offset of the next row to drain.
+    offset: usize,
+}
+
+#[derive(Debug)]
+pub struct WindowOperatorState {
+    batch_size: usize,
+    inner: Mutex<WindowOperatorStateInner>,
+}
+
+#[derive(Debug)]
+struct WindowOperatorStateInner {
+    /// Number of partitions still collecting.
+    remaining: DelayedPartitionCount,
+    /// Partitions waiting for collection to complete.
+    pending: PartitionWakers,
+    /// Buckets with batches flushed from all partitions.
+    buckets: Vec<Vec<Batch>>,
+}
+
+/// Computes window aggregates for a set of window expressions sharing the same
+/// PARTITION BY and ORDER BY.
+///
+/// Rows are hashed on the partition keys and distributed to buckets, with each
+/// partition being responsible for sorting and computing the values for a
+/// single bucket. Rows within the same window partition will always be in the
+/// same bucket.
+///
+/// The output of this operator is the input columns followed by a column for
+/// each window expression.
+#[derive(Debug)]
+pub struct PhysicalWindow {
+    /// Expressions for partitioning the input.
+    pub(crate) partition_by: Vec<PhysicalScalarExpression>,
+    /// Ordering within a window partition.
+    pub(crate) order_by: Vec<PhysicalSortExpression>,
+    /// Window expressions to compute.
+    pub(crate) windows: Vec<PhysicalWindowExpression>,
+    /// Number of columns in the input.
+    input_columns: usize,
+    /// Types for collected rows.
+    ///
+    /// Input columns, partition keys, sort keys, then inputs to each window
+    /// function.
+    collected_types: Vec<DataType>,
+    /// Output types, input types followed by the window return types.
+    output_types: Vec<DataType>,
+}
+
+impl PhysicalWindow {
+    pub fn new(
+        input_types: impl IntoIterator<Item = DataType>,
+        partition_by: Vec<PhysicalScalarExpression>,
+        order_by: Vec<PhysicalSortExpression>,
+        windows: Vec<PhysicalWindowExpression>,
+    ) -> Self {
+        let input_types: Vec<_> = input_types.into_iter().collect();
+        let input_columns = input_types.len();
+
+        let mut collected_types = input_types.clone();
+        collected_types.extend(partition_by.iter().map(|expr| expr.datatype()));
+        collected_types.extend(order_by.iter().map(|expr| expr.column.datatype()));
+        collected_types.extend(
+            windows
+                .iter()
+                .flat_map(|window| window.inputs.iter().map(|input| input.datatype())),
+        );
+
+        let mut output_types = input_types;
+        output_types.extend(
+            windows
+                .iter()
+                .map(|window| window.function.state().return_type.clone()),
+        );
+
+        PhysicalWindow {
+            partition_by,
+            order_by,
+            windows,
+            input_columns,
+            collected_types,
+            output_types,
+        }
+    }
+
+    /// Expressions that get evaluated for every input batch, in the order they
+    /// appear in the collected rows.
+    fn evaluated_expressions(&self) -> impl Iterator<Item = PhysicalScalarExpression> + '_ {
+        self.partition_by
+            .iter()
+            .chain(self.order_by.iter().map(|expr| &expr.column))
+            .chain(self.windows.iter().flat_map(|window| window.inputs.iter()))
+            .cloned()
+    }
+
+    fn num_keys(&self) -> usize {
+        self.partition_by.len() + self.order_by.len()
+    }
+
+    /// Sort and compute window values for a single bucket.
+    ///
+    /// Returns None if the bucket has no rows.
+    fn compute_bucket(
+        &self,
+        batches: Vec<Batch>,
+        batch_size: usize,
+    ) -> Result<Option<WindowDrainingState>> {
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        if num_rows == 0 {
+            return Ok(None);
+        }
+
+        let mut rows = Batch::new(self.collected_types.clone(), num_rows)?;
+
+        if self.num_keys() == 0 {
+            // No partitioning or ordering, all rows are peers.
+            for batch in &batches {
+                rows.append(batch)?;
+            }
+        } else {
+            let key_layout = SortLayout::try_new(
+                self.partition_by
+                    .iter()
+                    .map(|expr| SortColumn {
+                        desc: false,
+                        nulls_first: true,
+                        datatype: expr.datatype(),
+                    })
+                    .chain(self.order_by.iter().map(|expr| SortColumn {
+                        desc: expr.desc,
+                        nulls_first: expr.nulls_first,
+                        datatype: expr.column.datatype(),
+                    })),
+            )?;
+            let data_layout = RowLayout::try_new(self.collected_types.clone())?;
+
+            let mut collection =
+                PartialSortedRowCollection::new(key_layout, data_layout.clone(), batch_size);
+            let mut append = collection.init_append_state();
+
+            let keys = self.input_columns..(self.input_columns + self.num_keys());
+            for batch in &batches {
+                collection.append_unsorted_keys_and_data(
+                    &mut append,
+                    &batch.arrays[keys.clone()],
+                    &batch.arrays,
+                    batch.num_rows(),
+                )?;
+            }
+            std::mem::drop(batches);
+
+            // All unsorted rows get sorted together, producing a single block.
+            let blocks = collection.try_into_sorted_blocks(None)?;
+            debug_assert_eq!(1, blocks.len());
+
+            for block in blocks {
+                let segment = SortedSegment::from_sorted_block(block);
+                let mut scan_state = segment.init_scan_state();
+                segment.scan_data(&mut scan_state, &data_layout, &mut rows)?;
+            }
+
+            if rows.num_rows() != num_rows {
+                return Err(DbError::new("Unexpected number of rows after sort")
+                    .with_field("expected", num_rows)
+                    .with_field("got", rows.num_rows()));
+            }
+        }
+
+        let groups = self.find_peer_groups(&rows)?;
+
+        // Keys are only read for computing frames, inputs may be mutated when
+        // selecting rows for updates.
+        let (keys, inputs) = rows
+            .arrays
+            .split_at_mut(self.input_columns + self.num_keys());
+
+        // Only needed if we have windows with non-default frames, or window
+        // functions (which always compute over full partitions).
+        let frame_ranges = if self.windows.iter().any(|window| {
+            !window.frame.is_default() || matches!(window.function, WindowExprFunction::Window(_))
+        }) {
+            let range_key = if self
+                .windows
+                .iter()
+                .any(|window| window.frame.has_range_offset())
+            {
+                // Binder ensures we have exactly one ORDER BY for RANGE frames
+                // with offsets.
+                let order_by = &self.order_by[0];
+                let col = self.input_columns + self.partition_by.len();
+                Some((&keys[col], order_by.desc, order_by.nulls_first))
+            } else {
+                None
+            };
+            Some(FrameRanges::try_new(&groups, num_rows, range_key)?)
+        } else {
+            None
+        };
+
+        let mut input_offset = 0;
+        let mut values = Vec::with_capacity(self.windows.len());
+        for window in &self.windows {
+            let inputs = &mut inputs[input_offset..(input_offset + window.inputs.len())];
+            input_offset += window.inputs.len();
+
+            let value = match (&window.function, &frame_ranges) {
+                (WindowExprFunction::Window(function), Some(frame_ranges)) => {
+                    compute_window_function(
+                        function,
+                        &window.frame,
+                        inputs,
+                        &groups,
+                        frame_ranges,
+                        num_rows,
+                    )?
+                }
+                (WindowExprFunction::Aggregate(agg), Some(frame_ranges))
+                    if !window.frame.is_default() =>
+                {
+                    compute_framed_window_aggregate(
+                        agg,
+                        &window.frame,
+                        inputs,
+                        frame_ranges,
+                        num_rows,
+                    )?
+                }
+                (WindowExprFunction::Aggregate(agg), _) => {
+                    compute_window_aggregate(agg, inputs, &groups, num_rows)?
+                }
+                (WindowExprFunction::Window(_), None) => {
+                    return Err(DbError::new("Missing frame ranges for window function"));
+                }
+            };
+            values.push(value);
+        }
+
+        Ok(Some(WindowDrainingState {
+            rows,
+            values,
+            offset: 0,
+        }))
+    }
+
+    /// Find peer groups in sorted rows.
+    ///
+    /// Rows are peers if they have equal partition and sort keys.
+    fn find_peer_groups(&self, rows: &Batch) -> Result<Vec<PeerGroup>> {
+        let partition_start = self.input_columns;
+        let order_start = partition_start + self.partition_by.len();
+        let order_end = order_start + self.order_by.len();
+
+        let partition_keys = &rows.arrays[partition_start..order_start];
+        let order_keys = &rows.arrays[order_start..order_end];
+
+        let mut groups: Vec<PeerGroup> = Vec::new();
+
+        // TODO: Comparing scalar values for every row is slow. We should be
+        // able to compare the encoded sort keys instead.
+        for row in 0..rows.num_rows() {
+            if row == 0 {
+                groups.push(PeerGroup {
+                    start: 0,
+                    end: 1,
+                    new_partition: true,
+                });
+                continue;
+            }
+
+            let new_partition = !rows_equal(partition_keys, row - 1, row)?;
+            if new_partition || !rows_equal(order_keys, row - 1, row)? {
+                groups.push(PeerGroup {
+                    start: row,
+                    end: row + 1,
+                    new_partition,
+                });
+            } else {
+                // Always have at least one group at this point.
+                groups.last_mut().unwrap().end += 1;
+            }
+        }
+
+        Ok(groups)
+    }
+}
+
+/// A range of rows that are peers of each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PeerGroup {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// If this group is the start of a new window partition.
+    pub(crate) new_partition: bool,
+}
+
+/// Compute the values of a window function.
+///
+/// The function is called once for each window partition, writing values for
+/// the partition's rows directly into the output.
+fn compute_window_function(
+    function: &PlannedWindowFunction,
+    frame: &WindowFrame,
+    inputs: &[Array],
+    groups: &[PeerGroup],
+    frame_ranges: &FrameRanges,
+    num_rows: usize,
+) -> Result<Array> {
+    let datatype = function.state.return_type.clone();
+    let mut values = Array::new(&DefaultBufferManager, datatype, num_rows)?;
+
+    let mut start = 0;
+    while start < groups.len() {
+        let end = groups[(start + 1)..]
+            .iter()
+            .position(|group| group.new_partition)
+            .map(|pos| start + 1 + pos)
+            .unwrap_or(groups.len());
+
+        let partition = WindowPartition::new(inputs, &groups[start..end], frame_ranges, frame);
+        function.call_compute(&partition, &mut values)?;
+
+        start = end;
+    }
+
+    Ok(values)
+}
+
+/// Compute the value of an aggregate using the default window frame (RANGE
+/// BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW).
+///
+/// Each peer group gets its own aggregate state, initialized from a clone of
+/// the previous group's state if they're in the same window partition, then
+/// updated with the group's own rows. Every row in a group shares the same
+/// value.
+fn compute_window_aggregate(
+    function: &PlannedAggregateFunction,
+    inputs: &mut [Array],
+    groups: &[PeerGroup],
+    num_rows: usize,
+) -> Result<Array> {
+    let info = function.aggregate_state_info();
+    let stride = usize::max(info.size, 1).div_ceil(info.align) * info.align;
+
+    let mut state_buf = DbVec::<u8>::new_uninit_with_align(
+        &DefaultBufferManager,
+        stride * groups.len(),
+        info.align,
+    )?;
+
+    let mut states = AggregateStates::new(function);
+
+    let mut group_inputs = inputs
+        .iter()
+        .map(|input| Array::new(&DefaultBufferManager, input.datatype().clone(), 1))
+        .collect::<Result<Vec<_>>>()?;
+    let mut update_ptrs: Vec<*mut u8> = Vec::new();
+
+    for (idx, group) in groups.iter().enumerate() {
+        // SAFETY: Buffer allocated with enough space for each group's state
+        // with correct alignment.
+        unsafe {
+            let ptr = state_buf.as_mut_ptr().byte_add(idx * stride);
+            if group.new_partition {
+                states.push_new(ptr);
+            } else {
+                states.push_clone(states.ptrs[idx - 1], ptr);
+            }
+        }
+
+        for (group_input, input) in group_inputs.iter_mut().zip(inputs.iter_mut()) {
+            group_input.select_from_other(
+                &DefaultBufferManager,
+                input,
+                group.start..group.end,
+                &mut NopCache,
+            )?;
+        }
+
+        let count = group.end - group.start;
+        update_ptrs.clear();
+        update_ptrs.resize(count, states.ptrs[idx]);
+
+        unsafe { function.call_update(&group_inputs, count, &mut update_ptrs)? };
+    }
+
+    let datatype = function.state.return_type.clone();
+    let mut group_values = Array::new(&DefaultBufferManager, datatype.clone(), groups.len())?;
+    states.finalize(&mut group_values)?;
+
+    let mut values = Array::new(&DefaultBufferManager, datatype, num_rows)?;
+    group_values.copy_rows(
+        groups
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, group)| (group.start..group.end).map(move |row| (idx, row))),
+        &mut values,
+    )?;
+
+    Ok(values)
+}
+
+/// Initialized aggregate states.
+///
+/// States are dropped if computing the aggregate errors before they're
+/// finalized or combined into other states.
+struct AggregateStates<'a> {
+    function: &'a PlannedAggregateFunction,
+    /// Pointers to all initialized states.
+    ptrs: Vec<*mut u8>,
+}
+
+impl<'a> AggregateStates<'a> {
+    fn new(function: &'a PlannedAggregateFunction) -> Self {
+        AggregateStates {
+            function,
+            ptrs: Vec::new(),
+        }
+    }
+
+    /// Initialize a new state at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to uninitialized memory with the size and alignment
+    /// of the aggregate's state.
+    unsafe fn push_new(&mut self, ptr: *mut u8) {
+        unsafe { self.function.call_new_aggregate_state(ptr) };
+        self.ptrs.push(ptr);
+    }
+
+    /// Initialize a state at `ptr` by cloning `src`.
+    ///
+    /// # Safety
+    ///
+    /// Same as `push_new`, and `src` must point to an initialized state.
+    unsafe fn push_clone(&mut self, src: *const u8, ptr: *mut u8) {
+        unsafe { self.function.call_clone_state(src, ptr) };
+        self.ptrs.push(ptr);
+    }
+
+    /// Finalize all states into the output array.
+    fn finalize(&mut self, output: &mut Array) -> Result<()> {
+        // Finalize drops the states.
+        let mut ptrs = std::mem::take(&mut self.ptrs);
+        unsafe { self.function.call_finalize(&mut ptrs, output) }
+    }
+
+    /// Combine all states into `dest`.
+    fn combine_into(&mut self, dest: &mut [*mut u8]) -> Result<()> {
+        // Combine consumes the states.
+        let mut ptrs = std::mem::take(&mut self.ptrs);
+        unsafe { self.function.call_combine(&mut ptrs, dest) }
+    }
+}
+
+impl Drop for AggregateStates<'_> {
+    fn drop(&mut self) {
+        for &ptr in &self.ptrs {
+            // SAFETY: States are only tracked while they're initialized.
+            unsafe { self.function.call_drop_state(ptr) }
+        }
+    }
+}
+
+/// Number of rows to compute framed aggregates for at a time.
+const FRAME_CHUNK_SIZE: usize = 1024;
+
+/// Compute the value of an aggregate using an explicit window frame.
+///
+/// Each row's frame is split into pieces using a segment tree built over all
+/// rows. Pieces are either rows that get aggregated directly, or nodes whose
+/// states get cloned and combined into the row's result state.
+///
+/// Rows are processed in chunks, with the nth piece for every row in the chunk
+/// being handled together. This keeps the number of calls to update and
+/// combine low while ensuring pieces are aggregated in row order for each row
+/// (needed for order-sensitive aggregates like `string_agg`).
+fn compute_framed_window_aggregate(
+    function: &PlannedAggregateFunction,
+    frame: &WindowFrame,
+    inputs: &mut [Array],
+    frame_ranges: &FrameRanges,
+    num_rows: usize,
+) -> Result<Array> {
+    let tree = SegmentTree::try_new(function, inputs, num_rows)?;
+
+    let info = function.aggregate_state_info();
+    let stride = usize::max(info.size, 1).div_ceil(info.align) * info.align;
+
+    // States for each row's result, and temporary states for nodes cloned from
+    // the tree (consumed on combine).
+    let mut results = DbVec::<u8>::new_uninit_with_align(
+        &DefaultBufferManager,
+        stride * FRAME_CHUNK_SIZE,
+        info.align,
+    )?;
+    let mut temps = DbVec::<u8>::new_uninit_with_align(
+        &DefaultBufferManager,
+        stride * FRAME_CHUNK_SIZE,
+        info.align,
+    )?;
+
+    let mut piece_inputs = inputs
+        .iter()
+        .map(|input| Array::new(&DefaultBufferManager, input.datatype().clone(), 1))
+        .collect::<Result<Vec<_>>>()?;
+
+    let datatype = function.state.return_type.clone();
+    let mut values = Array::new(&DefaultBufferManager, datatype.clone(), num_rows)?;
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut pieces: Vec<FramePiece> = Vec::new();
+    let mut piece_offsets: Vec<usize> = Vec::new();
+
+    let mut update_rows: Vec<usize> = Vec::new();
+    let mut update_ptrs: Vec<*mut u8> = Vec::new();
+    let mut combine_dest: Vec<*mut u8> = Vec::new();
+
+    // States for each row in the current chunk, and temporary states cloned
+    // from the tree that haven't been combined yet.
+    let mut result_states = AggregateStates::new(function);
+    let mut temp_states = AggregateStates::new(function);
+
+    for chunk_start in (0..num_rows).step_by(FRAME_CHUNK_SIZE) {
+        let chunk_end = usize::min(chunk_start + FRAME_CHUNK_SIZE, num_rows);
+        let chunk_len = chunk_end - chunk_start;
+
+        // SAFETY: Buffers allocated with enough space for a full chunk of
+        // states with correct alignment.
+        for idx in 0..chunk_len {
+            unsafe { result_states.push_new(results.as_mut_ptr().byte_add(idx * stride)) };
+        }
+        let temp_ptrs: Vec<*mut u8> = (0..chunk_len)
+            .map(|idx| unsafe { temps.as_mut_ptr().byte_add(idx * stride) })
+            .collect();
+
+        pieces.clear();
+        piece_offsets.clear();
+        piece_offsets.push(0);
+        for row in chunk_start..chunk_end {
+            frame_ranges.frame_ranges(frame, row, &mut ranges)?;
+            for range in &ranges {
+                tree.push_pieces(range.clone(), &mut pieces);
+            }
+            piece_offsets.push(pieces.len());
+        }
+
+        let max_pieces = piece_offsets
+            .windows(2)
+            .map(|offsets| offsets[1] - offsets[0])
+            .max()
+            .unwrap_or(0);
+
+        for piece_idx in 0..max_pieces {
+            update_rows.clear();
+            update_ptrs.clear();
+            combine_dest.clear();
+
+            for idx in 0..chunk_len {
+                let row_pieces = &pieces[piece_offsets[idx]..piece_offsets[idx + 1]];
+                match row_pieces.get(piece_idx) {
+                    Some(FramePiece::Rows(rows)) => {
+                        update_rows.extend(rows.clone());
+                        update_ptrs
+                            .extend(std::iter::repeat_n(result_states.ptrs[idx], rows.len()));
+                    }
+                    Some(FramePiece::Node { level, idx: node }) => {
+                        unsafe {
+                            temp_states.push_clone(tree.node_ptr(*level, *node), temp_ptrs[idx])
+                        };
+                        combine_dest.push(result_states.ptrs[idx]);
+                    }
+                    None => (),
+                }
+            }
+
+            if !update_rows.is_empty() {
+                for (piece_input, input) in piece_inputs.iter_mut().zip(inputs.iter_mut()) {
+                    piece_input.select_from_other(
+                        &DefaultBufferManager,
+                        input,
+                        update_rows.iter().copied(),
+                        &mut NopCache,
+                    )?;
+                }
+                unsafe {
+                    function.call_update(&piece_inputs, update_rows.len(), &mut update_ptrs)?
+                };
+            }
+
+            if !combine_dest.is_empty() {
+                temp_states.combine_into(&mut combine_dest)?;
+            }
+        }
+
+        let mut chunk_values = Array::new(&DefaultBufferManager, datatype.clone(), chunk_len)?;
+        result_states.finalize(&mut chunk_values)?;
+        chunk_values.copy_rows((0..chunk_len).zip(chunk_start..chunk_end), &mut values)?;
+    }
+
+    Ok(values)
+}
+
+/// Check if two rows have equal values for all arrays.
+///
+/// NULLs are considered equal.
+fn rows_equal(arrays: &[Array], a: usize, b: usize) -> Result<bool> {
+    for array in arrays {
+        if array.get_value(a)? != array.get_value(b)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Compute the bucket to use for a given hash value.
+const fn bucket_for_hash(hash: u64, buckets: usize) -> usize {
+    ((hash as u128 * buckets as u128) >> 64) as usize
+}
+
+impl WindowCollectingState {
+    /// Append rows from the input and evaluated batch to the bucket indicated
+    /// by `bucket_idx` using the given selection.
+    fn append_to_bucket(
+        &mut self,
+        input: &Batch,
+        bucket_idx: usize,
+        collected_types: &[DataType],
+        batch_size: usize,
+    ) -> Result<()> {
+        let bucket = &mut self.buckets[bucket_idx];
+        let mut selection = self.selections[bucket_idx].as_slice();
+
+        while !selection.is_empty() {
+            let needs_new = match bucket.last() {
+                Some(batch) => batch.num_rows() >= batch.write_capacity()?,
+                None => true,
+            };
+            if needs_new {
+                bucket.push(Batch::new(collected_types.iter().cloned(), batch_size)?);
+            }
+
+            let batch = bucket.last_mut().unwrap();
+            let offset = batch.num_rows();
+            let count = usize::min(batch.write_capacity()? - offset, selection.len());
+            let mapping = selection[..count].iter().copied().zip(offset..);
+
+            for (src, dest) in input
+                .arrays
+                .iter()
+                .chain(self.evaluated.arrays.iter())
+                .zip(batch.arrays.iter_mut())
+            {
+                src.copy_rows(mapping.clone(), dest)?;
+            }
+
+            batch.set_num_rows(offset + count)?;
+            selection = &selection[count..];
+        }
+
+        Ok(())
+    }
+}
+
+impl WindowDrainingState {
+    /// Drains the next set of rows into the output.
+    ///
+    /// Returns a bool indicating if there's more rows to drain.
+    fn drain_next(&mut self, input_columns: usize, output: &mut Batch) -> Result<bool> {
+        output.reset_for_write()?;
+
+        let count = usize::min(output.write_capacity()?, self.rows.num_rows() - self.offset);
+        let mapping = (self.offset..(self.offset + count)).zip(0..count);
+
+        let sources = self.rows.arrays[..input_columns]
+            .iter()
+            .chain(self.values.iter());
+        for (src, dest) in sources.zip(output.arrays.iter_mut()) {
+            src.copy_rows(mapping.clone(), dest)?;
+        }
+
+        output.set_num_rows(count)?;
+        self.offset += count;
+
+        Ok(self.offset < self.rows.num_rows())
+    }
+}
+
+impl BaseOperator for PhysicalWindow {
+    const OPERATOR_NAME: &str = "Window";
+
+    type OperatorState = WindowOperatorState;
+
+    fn create_operator_state(&self, props: ExecutionProperties) -> Result<Self::OperatorState> {
+        Ok(WindowOperatorState {
+            batch_size: props.batch_size,
+            inner: Mutex::new(WindowOperatorStateInner {
+                remaining: DelayedPartitionCount::uninit(),
+                pending: PartitionWakers::empty(),
+                buckets: Vec::new(),
+            }),
+        })
+    }
+
+    fn output_types(&self) -> &[DataType] {
+        &self.output_types
+    }
+}
+
+impl ExecuteOperator for PhysicalWindow {
+    type PartitionExecuteState = WindowPartitionState;
+
+    fn create_partition_execute_states(
+        &self,
+        operator_state: &Self::OperatorState,
+        props: ExecutionProperties,
+        partitions: usize,
+    ) -> Result<Vec<Self::PartitionExecuteState>> {
+        let mut inner = operator_state.inner.lock();
+        inner.remaining.set(partitions)?;
+        inner.pending.init_for_partitions(partitions);
+        inner.buckets = (0..partitions).map(|_| Vec::new()).collect();
+
+        let evaluated_types: Vec<_> = self.collected_types[self.input_columns..].to_vec();
+
+        (0..partitions)
+            .map(|idx| {
+                Ok(WindowPartitionState::Collecting(WindowCollectingState {
+                    partition_idx: idx,
+                    evaluator: ExpressionEvaluator::try_new(
+                        self.evaluated_expressions(),
+                        props.batch_size,
+                    )?,
+                    evaluated: Batch::new(evaluated_types.clone(), props.batch_size)?,
+                    hashes: Vec::new(),
+                    selections: vec![Vec::new(); partitions],
+                    buckets: (0..partitions).map(|_| Vec::new()).collect(),
+                }))
+            })
+            .collect()
+    }
+
+    fn poll_execute(
+        &self,
+        cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+        input: &mut Batch,
+        output: &mut Batch,
+    ) -> Result<PollExecute> {
+        match state {
+            WindowPartitionState::Collecting(state) => {
+                state
+                    .evaluator
+                    .eval_batch(input, input.selection(), &mut state.evaluated)?;
+
+                let num_rows = input.num_rows();
+                let num_buckets = state.buckets.len();
+
+                for selection in &mut state.selections {
+                    selection.clear();
+                }
+
+                if self.partition_by.is_empty() {
+                    // Everything is part of the same window partition.
+                    state.selections[0].extend(0..num_rows);
+                } else {
+                    state.hashes.clear();
+                    state.hashes.resize(num_rows, 0);
+                    hash_many_arrays(
+                        &state.evaluated.arrays[..self.partition_by.len()],
+                        0..num_rows,
+                        &mut state.hashes,
+                    )?;
+
+                    for (row, &hash) in state.hashes.iter().enumerate() {
+                        state.selections[bucket_for_hash(hash, num_buckets)].push(row);
+                    }
+                }
+
+                for bucket_idx in 0..num_buckets {
+                    state.append_to_bucket(
+                        input,
+                        bucket_idx,
+                        &self.collected_types,
+                        operator_state.batch_size,
+                    )?;
+                }
+
+                Ok(PollExecute::NeedsMore)
+            }
+            WindowPartitionState::Waiting { partition_idx } => {
+                let batches = {
+                    let mut inner = operator_state.inner.lock();
+                    if inner.remaining.current()? != 0 {
+                        inner.pending.store(cx.waker(), *partition_idx);
+                        return Ok(PollExecute::Pending);
+                    }
+                    std::mem::take(&mut inner.buckets[*partition_idx])
+                };
+
+                match self.compute_bucket(batches, operator_state.batch_size)? {
+                    Some(mut drain_state) => {
+                        let has_more = drain_state.drain_next(self.input_columns, output)?;
+                        if has_more {
+                            *state = WindowPartitionState::Draining(drain_state);
+                            Ok(PollExecute::HasMore)
+                        } else {
+                            *state = WindowPartitionState::Finished;
+                            Ok(PollExecute::Exhausted)
+                        }
+                    }
+                    None => {
+                        *state = WindowPartitionState::Finished;
+                        output.set_num_rows(0)?;
+                        Ok(PollExecute::Exhausted)
+                    }
+                }
+            }
+            WindowPartitionState::Draining(drain_state) => {
+                let has_more = drain_state.drain_next(self.input_columns, output)?;
+                if has_more {
+                    Ok(PollExecute::HasMore)
+                } else {
+                    *state = WindowPartitionState::Finished;
+                    Ok(PollExecute::Exhausted)
+                }
+            }
+            WindowPartitionState::Finished => {
+                output.set_num_rows(0)?;
+                Ok(PollExecute::Exhausted)
+            }
+        }
+    }
+
+    fn poll_finalize_execute(
+        &self,
+        _cx: &mut Context,
+        operator_state: &Self::OperatorState,
+        state: &mut Self::PartitionExecuteState,
+    ) -> Result<PollFinalize> {
+        match state {
+            WindowPartitionState::Collecting(collect_state) => {
+                let partition_idx = collect_state.partition_idx;
+                let buckets = std::mem::take(&mut collect_state.buckets);
+                *state = WindowPartitionState::Waiting { partition_idx };
+
+                let mut inner = operator_state.inner.lock();
+                for (global, local) in inner.buckets.iter_mut().zip(buckets) {
+                    global.extend(local);
+                }
+
+                let remaining = inner.remaining.dec_by_one()?;
+                if remaining == 0 {
+                    inner.pending.wake_all();
+                }
+
+                Ok(PollFinalize::NeedsDrain)
+            }
+            other => Err(DbError::new(format!(
+                "Window partition state in invalid state: {other:?}",
+            ))),
+        }
+    }
+}
+
+impl Explainable for PhysicalWindow {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(Self::OPERATOR_NAME, conf)
+            .with_values(
+                "windows",
+                self.windows.iter().map(|window| window.function.name()),
+            )
+            .with_values("partition_by", &self.partition_by)
+            .with_values(
+                "order_by",
+                self.order_by.iter().map(|expr| {
+                    format!(
+                        "{} {} {}",
+                        expr.column,
+                        if expr.desc { "desc" } else { "asc" },
+                        if expr.nulls_first {
+                            "nulls_first"
+                        } else {
+                            "nulls_last"
+                        }
+                    )
+                }),
+            )
+            .with_values("frames", self.windows.iter().map(|window| window.frame))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::operators::window::frame::{FrameBound, FrameOffset};
+    use crate::expr::physical::column_expr::PhysicalColumnExpr;
+    use crate::expr::window_expr::{WindowFrameExclusion, WindowFrameUnit};
+    use crate::expr::{self, bind_aggregate_function};
+    use crate::functions::aggregate::builtin::sum::FUNCTION_SET_SUM;
+    use crate::generate_batch;
+    use crate::testutil::arrays::assert_batches_eq;
+    use crate::testutil::operator::OperatorWrapper;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn sum_window(col: usize) -> PhysicalWindowExpression {
+        sum_window_with_frame(col, WindowFrame::DEFAULT)
+    }
+
+    fn sum_window_with_frame(col: usize, frame: WindowFrame) -> PhysicalWindowExpression {
+        let sum_agg = bind_aggregate_function(
+            &FUNCTION_SET_SUM,
+            vec![expr::column((0, col), DataType::int64())],
+        )
+        .unwrap();
+
+        PhysicalWindowExpression {
+            function: WindowExprFunction::Aggregate(sum_agg),
+            inputs: vec![PhysicalColumnExpr::new(col, DataType::int64()).into()],
+            frame,
+        }
+    }
+
+    #[test]
+    fn window_sum_no_partition_single_partition() {
+        // SUM(col0) OVER ()
+        let wrapper = OperatorWrapper::new(PhysicalWindow::new(
+            [DataType::int64()],
+            Vec::new(),
+            Vec::new(),
+            vec![sum_window(0)],
+        ));
+
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 1)
+            .unwrap();
+
+        let mut output = Batch::new(wrapper.operator.output_types.clone(), 16).unwrap();
+        let mut input = generate_batch!([1_i64, 2, 3]);
+
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::NeedsMore, poll);
+
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::Exhausted, poll);
+
+        let expected = generate_batch!([1_i64, 2, 3], [6_i64, 6, 6]);
+        assert_batches_eq(&expected, &output);
+    }
+
+    #[test]
+    fn window_running_sum_partitioned() {
+        // SUM(col0) OVER (PARTITION BY col1 ORDER BY col0)
+        let wrapper = OperatorWrapper::new(PhysicalWindow::new(
+            [DataType::int64(), DataType::utf8()],
+            vec![PhysicalColumnExpr::new(1, DataType::utf8()).into()],
+            vec![PhysicalSortExpression {
+                column: PhysicalColumnExpr::new(0, DataType::int64()).into(),
+                desc: false,
+                nulls_first: false,
+            }],
+            vec![sum_window(0)],
+        ));
+
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 1)
+            .unwrap();
+
+        let mut output = Batch::new(wrapper.operator.output_types.clone(), 16).unwrap();
+        let mut input = generate_batch!([4_i64, 1, 3, 2, 3], ["b", "a", "a", "b", "a"]);
+
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::NeedsMore, poll);
+
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::Exhausted, poll);
+
+        // Peers (the two 3s in partition 'a') share a value.
+        let expected = generate_batch!(
+            [1_i64, 3, 3, 2, 4],
+            ["a", "a", "a", "b", "b"],
+            [1_i64, 7, 7, 2, 6],
+        );
+        assert_batches_eq(&expected, &output);
+    }
+
+    #[test]
+    fn window_sum_no_partition_multiple_partitions() {
+        // SUM(col0) OVER ()
+        let wrapper = OperatorWrapper::new(PhysicalWindow::new(
+            [DataType::int64()],
+            Vec::new(),
+            Vec::new(),
+            vec![sum_window(0)],
+        ));
+
+        let props = ExecutionProperties { batch_size: 16 };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 3)
+            .unwrap();
+
+        let mut outputs: Vec<_> = (0..3)
+            .map(|_| Batch::new(wrapper.operator.output_types.clone(), 16).unwrap())
+            .collect();
+        let mut inputs = [
+            generate_batch!([1_i64, 2]),
+            generate_batch!([3_i64]),
+            generate_batch!([4_i64, 5]),
+        ];
+
+        for idx in 0..3 {
+            let poll = wrapper
+                .poll_execute(
+                    &op_state,
+                    &mut states[idx],
+                    &mut inputs[idx],
+                    &mut outputs[idx],
+                )
+                .unwrap();
+            assert_eq!(PollExecute::NeedsMore, poll);
+        }
+
+        // First partition should be pending until all others have finalized.
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut inputs[0], &mut outputs[0])
+            .unwrap();
+        assert_eq!(PollExecute::Pending, poll);
+
+        for state in &mut states[1..] {
+            let poll = wrapper.poll_finalize_execute(&op_state, state).unwrap();
+            assert_eq!(PollFinalize::NeedsDrain, poll);
+        }
+
+        let mut total_rows = 0;
+        for idx in 0..3 {
+            let poll = wrapper
+                .poll_execute(
+                    &op_state,
+                    &mut states[idx],
+                    &mut inputs[idx],
+                    &mut outputs[idx],
+                )
+                .unwrap();
+            assert_eq!(PollExecute::Exhausted, poll);
+
+            if outputs[idx].num_rows() > 0 {
+                // Without partitioning, everything goes to the first bucket.
+                assert_eq!(0, idx);
+                let expected = generate_batch!([1_i64, 2, 3, 4, 5], [15_i64, 15, 15, 15, 15]);
+                assert_batches_eq(&expected, &outputs[idx]);
+            }
+            total_rows += outputs[idx].num_rows();
+        }
+        assert_eq!(5, total_rows);
+    }
+
+    #[test]
+    fn window_rows_frame_sum_large_input() {
+        // SUM(col0) OVER (ORDER BY col0 ROWS BETWEEN 100 PRECEDING AND 50 FOLLOWING)
+        //
+        // Enough rows to use multiple levels of the segment tree.
+        let frame = WindowFrame {
+            unit: WindowFrameUnit::Rows,
+            start: FrameBound::Preceding(FrameOffset::Count(100)),
+            end: FrameBound::Following(FrameOffset::Count(50)),
+            exclude: WindowFrameExclusion::ExcludeNoOthers,
+        };
+        let wrapper = OperatorWrapper::new(PhysicalWindow::new(
+            [DataType::int64()],
+            Vec::new(),
+            vec![PhysicalSortExpression {
+                column: PhysicalColumnExpr::new(0, DataType::int64()).into(),
+                desc: false,
+                nulls_first: false,
+            }],
+            vec![sum_window_with_frame(0, frame)],
+        ));
+
+        let num_rows = 1000;
+        let props = ExecutionProperties {
+            batch_size: num_rows,
+        };
+        let op_state = wrapper.operator.create_operator_state(props).unwrap();
+        let mut states = wrapper
+            .operator
+            .create_partition_execute_states(&op_state, props, 1)
+            .unwrap();
+
+        let mut output = Batch::new(wrapper.operator.output_types.clone(), num_rows).unwrap();
+        let mut input = Batch::from_arrays([Array::try_from_iter(
+            (0..num_rows as i64).rev().collect::<Vec<_>>(),
+        )
+        .unwrap()])
+        .unwrap();
+
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::NeedsMore, poll);
+        let poll = wrapper
+            .poll_finalize_execute(&op_state, &mut states[0])
+            .unwrap();
+        assert_eq!(PollFinalize::NeedsDrain, poll);
+        let poll = wrapper
+            .poll_execute(&op_state, &mut states[0], &mut input, &mut output)
+            .unwrap();
+        assert_eq!(PollExecute::Exhausted, poll);
+
+        let expected_sums = (0..num_rows as i64).map(|row| {
+            let start = i64::max(0, row - 100);
+            let end = i64::min(num_rows as i64 - 1, row + 50);
+            (start..=end).sum::<i64>()
+        });
+        let expected = Batch::from_arrays([
+            Array::try_from_iter((0..num_rows as i64).collect::<Vec<_>>()).unwrap(),
+            Array::try_from_iter(expected_sums.collect::<Vec<_>>()).unwrap(),
+        ])
+        .unwrap();
+        assert_batches_eq(&expected, &output);
+    }
+}
@@ -0,0 +1,237 @@
+use std::ops::Range;
+
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::buffer::buffer_manager::DefaultBufferManager;
+use crate::buffer::db_vec::DbVec;
+use crate::functions::aggregate::PlannedAggregateFunction;
+
+/// Number of children for each node in the tree.
+const FANOUT: usize = 16;
+
+/// A piece of a frame to aggregate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FramePiece {
+    /// Rows that need to be aggregated directly from the input.
+    Rows(Range<usize>),
+    /// A node in the tree containing an already aggregated state.
+    Node { level: usize, idx: usize },
+}
+
+/// A segment tree of aggregate states for computing aggregates over arbitrary
+/// window frames.
+///
+/// Nodes in level 0 contain the aggregate for `FANOUT` consecutive rows, nodes
+/// in level 1 contain the aggregate for `FANOUT^2` consecutive rows, and so on.
+/// Only full nodes are stored. Rows not covered by a full node get aggregated
+/// from the input directly.
+///
+/// This allows computing the aggregate for any frame with at most
+/// `2 * FANOUT` states or rows per level.
+#[derive(Debug)]
+pub(crate) struct SegmentTree<'a> {
+    function: &'a PlannedAggregateFunction,
+    /// Size in bytes of each state, including padding for alignment.
+    stride: usize,
+    levels: Vec<SegmentTreeLevel>,
+}
+
+#[derive(Debug)]
+struct SegmentTreeLevel {
+    /// Initialized states for this level.
+    states: DbVec<u8>,
+    /// Number of states in this level.
+    len: usize,
+}
+
+impl<'a> SegmentTree<'a> {
+    /// Build a segment tree using the first `num_rows` rows from `inputs`.
+    pub fn try_new(
+        function: &'a PlannedAggregateFunction,
+        inputs: &[Array],
+        num_rows: usize,
+    ) -> Result<Self> {
+        let info = function.aggregate_state_info();
+        let stride = usize::max(info.size, 1).div_ceil(info.align) * info.align;
+
+        let mut tree = SegmentTree {
+            function,
+            stride,
+            levels: Vec::new(),
+        };
+
+        let mut rows_per_node = FANOUT;
+        let mut ptrs: Vec<*mut u8> = Vec::new();
+
+        loop {
+            let len = num_rows / rows_per_node;
+            if len == 0 {
+                break;
+            }
+
+            let mut states = DbVec::<u8>::new_uninit_with_align(
+                &DefaultBufferManager,
+                stride * len,
+                info.align,
+            )?;
+            // SAFETY: Buffer allocated with enough space for `len` states with
+            // the correct alignment.
+            let node_ptrs: Vec<*mut u8> = (0..len)
+                .map(|idx| unsafe { states.as_mut_ptr().byte_add(idx * stride) })
+                .collect();
+            for &ptr in &node_ptrs {
+                unsafe { function.call_new_aggregate_state(ptr) };
+            }
+            // Push before updating so states get dropped if the update fails.
+            tree.levels.push(SegmentTreeLevel { states, len });
+
+            // Each node is updated directly from the rows it covers.
+            let count = len * rows_per_node;
+            ptrs.clear();
+            ptrs.extend((0..count).map(|row| node_ptrs[row / rows_per_node]));
+            unsafe { function.call_update(inputs, count, &mut ptrs)? };
+
+            rows_per_node = match rows_per_node.checked_mul(FANOUT) {
+                Some(n) => n,
+                None => break,
+            };
+        }
+
+        Ok(tree)
+    }
+
+    /// Get a pointer to a node's state.
+    pub fn node_ptr(&self, level: usize, idx: usize) -> *const u8 {
+        let level = &self.levels[level];
+        assert!(idx < level.len);
+        // SAFETY: Index checked to be in bounds.
+        unsafe { level.states.as_ptr().byte_add(idx * self.stride) }
+    }
+
+    /// Push the pieces needed to aggregate `rows` to `out`.
+    ///
+    /// Pieces are pushed in row order.
+    pub fn push_pieces(&self, rows: Range<usize>, out: &mut Vec<FramePiece>) {
+        self.push_pieces_for_level(0, rows.start, rows.end, out);
+    }
+
+    /// Push pieces for the range `start..end` where the range is in units of
+    /// `level`.
+    ///
+    /// Level 0 units are rows, level 1 units are nodes in the first level of
+    /// the tree, etc.
+    fn push_pieces_for_level(
+        &self,
+        level: usize,
+        start: usize,
+        end: usize,
+        out: &mut Vec<FramePiece>,
+    ) {
+        if level < self.levels.len() {
+            // Full parent nodes covered by this range.
+            let parent_start = start.div_ceil(FANOUT);
+            let parent_end = end / FANOUT;
+
+            if parent_start < parent_end {
+                Self::push_units(level, start, parent_start * FANOUT, out);
+                self.push_pieces_for_level(level + 1, parent_start, parent_end, out);
+                Self::push_units(level, parent_end * FANOUT, end, out);
+                return;
+            }
+        }
+
+        Self::push_units(level, start, end, out);
+    }
+
+    fn push_units(level: usize, start: usize, end: usize, out: &mut Vec<FramePiece>) {
+        if start >= end {
+            return;
+        }
+
+        if level == 0 {
+            out.push(FramePiece::Rows(start..end));
+        } else {
+            out.extend((start..end).map(|idx| FramePiece::Node {
+                level: level - 1,
+                idx,
+            }));
+        }
+    }
+}
+
+impl Drop for SegmentTree<'_> {
+    fn drop(&mut self) {
+        for level in &mut self.levels {
+            for idx in 0..level.len {
+                // SAFETY: All states in a level are initialized on creation,
+                // and are only ever cloned from.
+                unsafe {
+                    let ptr = level.states.as_mut_ptr().byte_add(idx * self.stride);
+                    self.function.call_drop_state(ptr);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::expr::{self, bind_aggregate_function};
+    use crate::functions::aggregate::builtin::sum::FUNCTION_SET_SUM;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn sum_function() -> PlannedAggregateFunction {
+        bind_aggregate_function(
+            &FUNCTION_SET_SUM,
+            vec![expr::column((0, 0), DataType::int64())],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn pieces_small_input_no_levels() {
+        let function = sum_function();
+        let input = Array::try_from_iter((0..10).collect::<Vec<i64>>()).unwrap();
+        let tree = SegmentTree::try_new(&function, &[input], 10).unwrap();
+
+        let mut pieces = Vec::new();
+        tree.push_pieces(2..8, &mut pieces);
+        assert_eq!(vec![FramePiece::Rows(2..8)], pieces);
+    }
+
+    #[test]
+    fn pieces_multiple_levels() {
+        let function = sum_function();
+        let num_rows = 600;
+        let input = Array::try_from_iter((0..num_rows as i64).collect::<Vec<_>>()).unwrap();
+        let tree = SegmentTree::try_new(&function, &[input], num_rows).unwrap();
+
+        // 600 / 16 = 37 level 0 nodes, 37 / 16 = 2 level 1 nodes.
+        assert_eq!(2, tree.levels.len());
+        assert_eq!(37, tree.levels[0].len);
+        assert_eq!(2, tree.levels[1].len);
+
+        let mut pieces = Vec::new();
+        tree.push_pieces(10..590, &mut pieces);
+
+        let mut expected = vec![FramePiece::Rows(10..16)];
+        expected.extend((1..16).map(|idx| FramePiece::Node { level: 0, idx }));
+        expected.push(FramePiece::Node { level: 1, idx: 1 });
+        expected.extend((32..36).map(|idx| FramePiece::Node { level: 0, idx }));
+        expected.push(FramePiece::Rows(576..590));
+        assert_eq!(expected, pieces);
+
+        // Pieces should cover the exact range.
+        let covered: usize = pieces
+            .iter()
+            .map(|piece| match piece {
+                FramePiece::Rows(rows) => rows.len(),
+                FramePiece::Node { level, .. } => FANOUT.pow(*level as u32 + 1),
+            })
+            .sum();
+        assert_eq!(580, covered);
+    }
+}
@@ -1,7 +1,9 @@
 mod plan_aggregate;
+mod plan_copy_to;
 mod plan_create_schema;
 mod plan_create_table;
 mod plan_create_view;
+mod plan_delete;
 mod plan_describe;
 mod plan_distinct;
 mod plan_drop;
@@ -13,8 +15,10 @@ mod plan_join;
 mod plan_limit;
 mod plan_magic_scan;
 mod plan_materialize_scan;
+mod plan_merge;
 mod plan_no_rows;
 mod plan_project;
+mod plan_recursive_cte;
 mod plan_scan;
 mod plan_set_operation;
 mod plan_show_var;
@@ -22,20 +26,23 @@ mod plan_single_row;
 mod plan_sort;
 mod plan_table_execute;
 mod plan_unnest;
+mod plan_window;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
+use std::sync::Arc;
 
 use glaredb_error::{DbError, Result, not_implemented};
 use uuid::Uuid;
 
 use super::operators::materialize::PhysicalMaterialize;
+use super::operators::recursive_cte::WorkingTable;
 use super::operators::{PlannedOperatorWithChildren, PushOperator};
 use crate::catalog::context::DatabaseContext;
 use crate::config::execution::OperatorPlanConfig;
 use crate::execution::operators::PlannedOperator;
 use crate::expr::physical::planner::PhysicalExpressionPlanner;
-use crate::logical::binder::bind_context::{BindContext, MaterializationRef};
+use crate::logical::binder::bind_context::{BindContext, CteRef, MaterializationRef};
 use crate::logical::operator::{self, LogicalNode, LogicalOperator};
 
 /// Output of physical planning.
@@ -151,6 +158,10 @@ struct OperatorPlanState<'a> {
     /// might depend on another). We want to make sure we iterate in the same
     /// order when creating the operator states.
     materializations: BTreeMap<MaterializationRef, PlannedOperatorWithChildren>,
+    /// Working tables for recursive CTEs currently being planned.
+    ///
+    /// Only populated while planning the recursive term of the CTE.
+    recursive_ctes: HashMap<CteRef, Arc<WorkingTable>>,
     /// Generate unique ids for all operators.
     id_gen: OperatorIdGen,
 }
@@ -169,6 +180,7 @@ impl<'a> OperatorPlanState<'a> {
             bind_context,
             expr_planner,
             materializations: BTreeMap::new(),
+            recursive_ctes: HashMap::new(),
             id_gen: OperatorIdGen { next: 0 },
         }
     }
@@ -224,6 +236,7 @@ impl<'a> OperatorPlanState<'a> {
             LogicalOperator::TableExecute(node) => self.plan_table_execute(node),
             LogicalOperator::SetOp(node) => self.plan_set_operation(node),
             LogicalOperator::Unnest(node) => self.plan_unnest(node),
+            LogicalOperator::Window(node) => self.plan_window(node),
             LogicalOperator::Distinct(node) => self.plan_distinct(node),
             LogicalOperator::Describe(node) => self.plan_describe(node),
             LogicalOperator::ShowVar(node) => self.plan_show_var(node),
@@ -232,6 +245,8 @@ impl<'a> OperatorPlanState<'a> {
             LogicalOperator::MagicMaterializationScan(node) => {
                 self.plan_magic_materialize_scan(node)
             }
+            LogicalOperator::RecursiveCte(node) => self.plan_recursive_cte(node),
+            LogicalOperator::RecursiveCteScan(node) => self.plan_recursive_cte_scan(node),
             LogicalOperator::SingleRow(node) => self.plan_single_row(node),
             LogicalOperator::NoRows(node) => self.plan_no_rows(node),
             LogicalOperator::SetVar(_) => Err(DbError::new("SET should be handled in the session")),
@@ -241,11 +256,17 @@ impl<'a> OperatorPlanState<'a> {
             LogicalOperator::DetachDatabase(_) | LogicalOperator::AttachDatabase(_) => Err(
                 DbError::new("ATTACH/DETACH should be handled in the session"),
             ),
+            LogicalOperator::Transaction(_) => Err(DbError::new(
+                "Transaction statements should be handled in the session",
+            )),
             LogicalOperator::CreateView(node) => self.plan_create_view(node),
             LogicalOperator::CreateSchema(node) => self.plan_create_schema(node),
             LogicalOperator::CreateTable(node) => self.plan_create_table(node),
             LogicalOperator::Drop(node) => self.plan_drop(node),
             LogicalOperator::Insert(node) => self.plan_insert(node),
+            LogicalOperator::Delete(node) => self.plan_delete(node),
+            LogicalOperator::Merge(node) => self.plan_merge(node),
+            LogicalOperator::CopyTo(node) => self.plan_copy_to(node),
             other => not_implemented!("logical plan to physical plan: {}", other.name()),
         }
     }
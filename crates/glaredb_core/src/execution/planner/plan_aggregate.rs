@@ -95,7 +95,11 @@ impl OperatorPlanState<'_> {
                     grouping_functions: agg.node.grouping_functions,
                     aggregates: phys_aggs,
                 };
-                let operator = PhysicalHashAggregate::new(aggregates, grouping_sets);
+                let operator = PhysicalHashAggregate::new(
+                    aggregates,
+                    grouping_sets,
+                    self.config.spill.clone(),
+                );
 
                 Ok(PlannedOperatorWithChildren {
                     operator: PlannedOperator::new_execute(self.id_gen.next_id(), operator),
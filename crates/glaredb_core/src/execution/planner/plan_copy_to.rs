@@ -0,0 +1,53 @@
+use glaredb_error::Result;
+
+use super::OperatorPlanState;
+use crate::arrays::datatype::DataType;
+use crate::execution::operators::copy_to::PhysicalCopyTo;
+use crate::execution::operators::ungrouped_aggregate::PhysicalUngroupedAggregate;
+use crate::execution::operators::{PlannedOperator, PlannedOperatorWithChildren};
+use crate::expr;
+use crate::expr::physical::PhysicalAggregateExpression;
+use crate::functions::aggregate::builtin::sum::FUNCTION_SET_SUM;
+use crate::logical::logical_copy::LogicalCopyTo;
+use crate::logical::operator::Node;
+
+impl OperatorPlanState<'_> {
+    pub fn plan_copy_to(
+        &mut self,
+        mut copy_to: Node<LogicalCopyTo>,
+    ) -> Result<PlannedOperatorWithChildren> {
+        let input = copy_to.take_one_child_exact()?;
+        let child = self.plan(input)?;
+
+        let operator = PhysicalCopyTo::new(
+            copy_to.node.location,
+            copy_to.node.copy_to,
+            copy_to.node.filesystem,
+        );
+
+        let mut planned = PlannedOperatorWithChildren {
+            operator: PlannedOperator::new_execute(self.id_gen.next_id(), operator),
+            children: vec![child],
+        };
+
+        if !self.config.per_partition_counts {
+            // Sum counts across partitions.
+            let sum = expr::bind_aggregate_function(
+                &FUNCTION_SET_SUM,
+                vec![expr::column((0, 0), DataType::int64())],
+            )?;
+
+            let agg = PhysicalUngroupedAggregate::try_new([PhysicalAggregateExpression::new(
+                sum,
+                [(0, DataType::int64())],
+            )])?;
+
+            planned = PlannedOperatorWithChildren {
+                operator: PlannedOperator::new_execute(self.id_gen.next_id(), agg),
+                children: vec![planned],
+            }
+        }
+
+        Ok(planned)
+    }
+}
@@ -18,7 +18,8 @@ impl OperatorPlanState<'_> {
             on_conflict: create.node.on_conflict,
         };
 
-        let operator = db.plan_create_schema(&mut self.id_gen, info)?;
+        let operator =
+            db.plan_create_schema(&mut self.id_gen, self.db_context.transaction(), info)?;
 
         Ok(PlannedOperatorWithChildren {
             operator,
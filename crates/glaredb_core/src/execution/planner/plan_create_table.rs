@@ -23,10 +23,16 @@ impl OperatorPlanState<'_> {
             let info = CreateTableInfo {
                 name: create.node.name,
                 columns: create.node.columns,
+                unique_constraints: create.node.unique_constraints,
                 on_conflict: create.node.on_conflict,
             };
 
-            let operator = db.plan_create_table(&mut self.id_gen, &create.node.schema, info)?;
+            let operator = db.plan_create_table(
+                &mut self.id_gen,
+                self.db_context.transaction(),
+                &create.node.schema,
+                info,
+            )?;
             Ok(PlannedOperatorWithChildren {
                 operator,
                 children: Vec::new(),
@@ -39,10 +45,16 @@ impl OperatorPlanState<'_> {
             let info = CreateTableInfo {
                 name: create.node.name,
                 columns: create.node.columns,
+                unique_constraints: create.node.unique_constraints,
                 on_conflict: create.node.on_conflict,
             };
 
-            let operator = db.plan_create_table_as(&mut self.id_gen, &create.node.schema, info)?;
+            let operator = db.plan_create_table_as(
+                &mut self.id_gen,
+                self.db_context.transaction(),
+                &create.node.schema,
+                info,
+            )?;
             let mut planned = PlannedOperatorWithChildren {
                 operator,
                 children: vec![child],
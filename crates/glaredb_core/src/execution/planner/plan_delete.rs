@@ -0,0 +1,30 @@
+use glaredb_error::Result;
+
+use super::OperatorPlanState;
+use crate::execution::operators::PlannedOperatorWithChildren;
+use crate::logical::logical_delete::LogicalDelete;
+use crate::logical::operator::Node;
+
+impl OperatorPlanState<'_> {
+    pub fn plan_delete(
+        &mut self,
+        mut delete: Node<LogicalDelete>,
+    ) -> Result<PlannedOperatorWithChildren> {
+        let input = delete.take_one_child_exact()?;
+        let child = self.plan(input)?;
+
+        let db = self.db_context.require_get_database(&delete.node.catalog)?;
+        let operator = db.plan_delete(
+            &mut self.id_gen,
+            self.db_context.transaction(),
+            delete.node.table,
+        )?;
+
+        let planned = PlannedOperatorWithChildren {
+            operator,
+            children: vec![child],
+        };
+
+        self.sum_partition_counts(planned)
+    }
+}
@@ -41,7 +41,8 @@ impl OperatorPlanState<'_> {
         // when adding aggregates for ON (FIRST_VALUE, ARBITRARY).
         //
         // For now, we can just keep the groups vals as-is.
-        let operator = PhysicalHashAggregate::new(aggregates, vec![grouping_set]);
+        let operator =
+            PhysicalHashAggregate::new(aggregates, vec![grouping_set], self.config.spill.clone());
 
         Ok(PlannedOperatorWithChildren {
             operator: PlannedOperator::new_execute(self.id_gen.next_id(), operator),
@@ -19,31 +19,44 @@ impl OperatorPlanState<'_> {
         let child = self.plan(input)?;
 
         let db = self.db_context.require_get_database(&insert.node.catalog)?;
-        let operator = db.plan_insert(&mut self.id_gen, insert.node.table)?;
+        let operator = db.plan_insert(
+            &mut self.id_gen,
+            self.db_context.transaction(),
+            insert.node.table,
+        )?;
 
-        let mut planned = PlannedOperatorWithChildren {
+        let planned = PlannedOperatorWithChildren {
             operator,
             children: vec![child],
         };
 
-        if !self.config.per_partition_counts {
-            // Sum counts across partitions.
-            let sum = expr::bind_aggregate_function(
-                &FUNCTION_SET_SUM,
-                vec![expr::column((0, 0), DataType::int64())],
-            )?;
-
-            let agg = PhysicalUngroupedAggregate::try_new([PhysicalAggregateExpression::new(
-                sum,
-                [(0, DataType::int64())],
-            )])?;
-
-            planned = PlannedOperatorWithChildren {
-                operator: PlannedOperator::new_execute(self.id_gen.next_id(), agg),
-                children: vec![planned],
-            }
+        self.sum_partition_counts(planned)
+    }
+
+    /// Sum the per-partition row counts output by an operator modifying a
+    /// table, unless we're configured to output counts per partition.
+    pub(super) fn sum_partition_counts(
+        &mut self,
+        planned: PlannedOperatorWithChildren,
+    ) -> Result<PlannedOperatorWithChildren> {
+        if self.config.per_partition_counts {
+            return Ok(planned);
         }
 
-        Ok(planned)
+        // Sum counts across partitions.
+        let sum = expr::bind_aggregate_function(
+            &FUNCTION_SET_SUM,
+            vec![expr::column((0, 0), DataType::int64())],
+        )?;
+
+        let agg = PhysicalUngroupedAggregate::try_new([PhysicalAggregateExpression::new(
+            sum,
+            [(0, DataType::int64())],
+        )])?;
+
+        Ok(PlannedOperatorWithChildren {
+            operator: PlannedOperator::new_execute(self.id_gen.next_id(), agg),
+            children: vec![planned],
+        })
     }
 }
@@ -121,7 +121,13 @@ impl OperatorPlanState<'_> {
         let left_types = left.operator.call_output_types();
         let right_types = right.operator.call_output_types();
 
-        let hash_join = PhysicalHashJoin::new(join.join_type, left_types, right_types, conditions)?;
+        let hash_join = PhysicalHashJoin::new(
+            join.join_type,
+            left_types,
+            right_types,
+            conditions,
+            self.config.spill.clone(),
+        )?;
 
         Ok(PlannedOperatorWithChildren {
             operator: PlannedOperator::new_push_execute(self.id_gen.next_id(), hash_join),
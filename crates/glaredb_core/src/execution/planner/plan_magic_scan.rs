@@ -61,7 +61,8 @@ impl OperatorPlanState<'_> {
             aggregates: Vec::new(),
         };
         // Ouput has GROUP_VALS first, so the projection ordering is preserved.
-        let agg = PhysicalHashAggregate::new(aggregates, vec![grouping_set]);
+        let agg =
+            PhysicalHashAggregate::new(aggregates, vec![grouping_set], self.config.spill.clone());
 
         let agg_op = PlannedOperatorWithChildren {
             operator: PlannedOperator::new_execute(self.id_gen.next_id(), agg),
@@ -0,0 +1,31 @@
+use glaredb_error::Result;
+
+use super::OperatorPlanState;
+use crate::execution::operators::PlannedOperatorWithChildren;
+use crate::logical::logical_merge::LogicalMerge;
+use crate::logical::operator::Node;
+
+impl OperatorPlanState<'_> {
+    pub fn plan_merge(
+        &mut self,
+        mut merge: Node<LogicalMerge>,
+    ) -> Result<PlannedOperatorWithChildren> {
+        let input = merge.take_one_child_exact()?;
+        let child = self.plan(input)?;
+
+        let db = self.db_context.require_get_database(&merge.node.catalog)?;
+        let operator = db.plan_merge(
+            &mut self.id_gen,
+            self.db_context.transaction(),
+            merge.node.table,
+            merge.node.conflict,
+        )?;
+
+        let planned = PlannedOperatorWithChildren {
+            operator,
+            children: vec![child],
+        };
+
+        self.sum_partition_counts(planned)
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use glaredb_error::{DbError, Result};
+
+use super::OperatorPlanState;
+use crate::execution::operators::recursive_cte::{
+    PhysicalRecursiveCte,
+    PhysicalWorkingTableScan,
+    PhysicalWorkingTableSink,
+    WorkingTable,
+};
+use crate::execution::operators::{PlannedOperator, PlannedOperatorWithChildren};
+use crate::logical::logical_materialization::{LogicalRecursiveCte, LogicalRecursiveCteScan};
+use crate::logical::operator::Node;
+
+impl OperatorPlanState<'_> {
+    pub fn plan_recursive_cte(
+        &mut self,
+        mut cte: Node<LogicalRecursiveCte>,
+    ) -> Result<PlannedOperatorWithChildren> {
+        let [anchor, recursive] = cte.take_two_children_exact()?;
+        let anchor = self.plan(anchor)?;
+        let datatypes = anchor.operator.call_output_types();
+
+        // Working table needs to be visible to scans in the recursive term.
+        let working_table = Arc::new(WorkingTable::default());
+        if self
+            .recursive_ctes
+            .insert(cte.node.cte_ref, working_table.clone())
+            .is_some()
+        {
+            return Err(DbError::new(format!(
+                "Duplicate recursive CTE ref: {}",
+                cte.node.cte_ref
+            )));
+        }
+        let recursive = self.plan(recursive);
+        self.recursive_ctes.remove(&cte.node.cte_ref);
+        let recursive = recursive?;
+
+        let sink = PhysicalWorkingTableSink {
+            datatypes: datatypes.clone(),
+            working_table: working_table.clone(),
+        };
+        let recursive = PlannedOperatorWithChildren {
+            operator: PlannedOperator::new_push(self.id_gen.next_id(), sink),
+            children: vec![recursive],
+        };
+
+        let operator = PhysicalRecursiveCte {
+            datatypes,
+            all: cte.node.all,
+            max_depth: self.config.max_recursion_depth,
+            working_table,
+            recursive,
+        };
+
+        Ok(PlannedOperatorWithChildren {
+            operator: PlannedOperator::new_execute(self.id_gen.next_id(), operator),
+            children: vec![anchor],
+        })
+    }
+
+    pub fn plan_recursive_cte_scan(
+        &mut self,
+        scan: Node<LogicalRecursiveCteScan>,
+    ) -> Result<PlannedOperatorWithChildren> {
+        let working_table = self
+            .recursive_ctes
+            .get(&scan.node.cte_ref)
+            .ok_or_else(|| {
+                DbError::new(format!(
+                    "Missing working table for recursive CTE: {}",
+                    scan.node.cte_ref
+                ))
+            })?
+            .clone();
+
+        let datatypes = self
+            .bind_context
+            .get_table(scan.node.table_ref)?
+            .column_types
+            .clone();
+
+        let operator = PhysicalWorkingTableScan {
+            datatypes,
+            working_table,
+        };
+
+        Ok(PlannedOperatorWithChildren {
+            operator: PlannedOperator::new_pull(self.id_gen.next_id(), operator),
+            children: Vec::new(),
+        })
+    }
+}
@@ -53,7 +53,7 @@ impl OperatorPlanState<'_> {
             operator = PlannedOperatorWithChildren {
                 operator: PlannedOperator::new_execute(
                     self.id_gen.next_id(),
-                    PhysicalHashAggregate::new(aggregates, grouping_set),
+                    PhysicalHashAggregate::new(aggregates, grouping_set, self.config.spill.clone()),
                 ),
                 children: vec![operator],
             };
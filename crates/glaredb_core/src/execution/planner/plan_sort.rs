@@ -25,6 +25,7 @@ impl OperatorPlanState<'_> {
             sort_exprs,
             child.operator.call_output_types(),
             order.node.limit_hint,
+            self.config.spill.clone(),
         );
 
         Ok(PlannedOperatorWithChildren {
@@ -0,0 +1,154 @@
+use glaredb_error::{DbError, Result, ResultExt};
+
+use super::OperatorPlanState;
+use crate::execution::operators::project::PhysicalProject;
+use crate::execution::operators::window::PhysicalWindow;
+use crate::execution::operators::window::frame::WindowFrame;
+use crate::execution::operators::{PlannedOperator, PlannedOperatorWithChildren};
+use crate::expr::Expression;
+use crate::expr::physical::column_expr::PhysicalColumnExpr;
+use crate::expr::physical::{PhysicalScalarExpression, PhysicalWindowExpression};
+use crate::expr::window_expr::WindowExpr;
+use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
+use crate::logical::logical_window::LogicalWindow;
+use crate::logical::operator::{LogicalNode, Node};
+
+/// Window expressions sharing the same PARTITION BY and ORDER BY.
+///
+/// Frames may differ between windows in the same group.
+#[derive(Debug)]
+struct WindowGroup<'a> {
+    partition_by: &'a [Expression],
+    order_by: &'a [BoundOrderByExpr],
+    /// Indices of the windows (relative to the logical window node) in this
+    /// group.
+    windows: Vec<usize>,
+}
+
+impl OperatorPlanState<'_> {
+    pub fn plan_window(
+        &mut self,
+        mut window: Node<LogicalWindow>,
+    ) -> Result<PlannedOperatorWithChildren> {
+        let _location = window.location;
+
+        let input = window.take_one_child_exact()?;
+        let input_refs = input.get_output_table_refs(self.bind_context);
+        let mut child = self.plan(input)?;
+
+        let input_types = child.operator.call_output_types();
+        let num_input = input_types.len();
+
+        let windows = window
+            .node
+            .windows
+            .iter()
+            .map(|expr| match expr {
+                Expression::Window(window) => Ok(window),
+                other => Err(DbError::new(format!(
+                    "Expected window expression, got {other}"
+                ))),
+            })
+            .collect::<Result<Vec<&WindowExpr>>>()?;
+
+        // Group windows with the same partitioning and ordering so that each
+        // group only requires a single sort.
+        let mut groups: Vec<WindowGroup> = Vec::new();
+        for (idx, window) in windows.iter().enumerate() {
+            match groups.iter_mut().find(|group| {
+                group.partition_by == window.partition_by.as_slice()
+                    && group.order_by == window.order_by.as_slice()
+            }) {
+                Some(group) => group.windows.push(idx),
+                None => groups.push(WindowGroup {
+                    partition_by: &window.partition_by,
+                    order_by: &window.order_by,
+                    windows: vec![idx],
+                }),
+            }
+        }
+
+        // Each window operator appends its outputs to its input, and since
+        // window expressions only reference the original input columns, all
+        // expressions can be planned using the original input refs.
+        for group in &groups {
+            let partition_by = self
+                .expr_planner
+                .plan_scalars(&input_refs, group.partition_by)
+                .context("Failed to plan window partition expressions")?;
+            let order_by = self
+                .expr_planner
+                .plan_sorts(&input_refs, group.order_by)
+                .context("Failed to plan window order by expressions")?;
+
+            let physical_windows = group
+                .windows
+                .iter()
+                .map(|&idx| {
+                    let window = windows[idx];
+                    let inputs = self
+                        .expr_planner
+                        .plan_scalars(&input_refs, &window.function.state().inputs)
+                        .context("Failed to plan window function inputs")?;
+                    let frame =
+                        WindowFrame::try_from_bounds(&window.start, &window.end, window.exclude)?;
+                    Ok(PhysicalWindowExpression {
+                        function: window.function.clone(),
+                        inputs,
+                        frame,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let operator = PhysicalWindow::new(
+                child.operator.call_output_types(),
+                partition_by,
+                order_by,
+                physical_windows,
+            );
+
+            child = PlannedOperatorWithChildren {
+                operator: PlannedOperator::new_execute(self.id_gen.next_id(), operator),
+                children: vec![child],
+            };
+        }
+
+        // Windows are now output in group order. Project them back to the
+        // order expected by the windows table if needed.
+        let output_order: Vec<usize> = groups
+            .iter()
+            .flat_map(|group| group.windows.iter().copied())
+            .collect();
+
+        if output_order
+            .iter()
+            .enumerate()
+            .any(|(pos, &idx)| pos != idx)
+        {
+            let output_types = child.operator.call_output_types();
+
+            let mut projections: Vec<PhysicalScalarExpression> = (0..num_input)
+                .map(|idx| PhysicalColumnExpr::new(idx, output_types[idx].clone()).into())
+                .collect();
+
+            for window_idx in 0..windows.len() {
+                let pos = output_order
+                    .iter()
+                    .position(|&idx| idx == window_idx)
+                    .expect("window to be in output order");
+                let col = num_input + pos;
+                projections.push(PhysicalColumnExpr::new(col, output_types[col].clone()).into());
+            }
+
+            child = PlannedOperatorWithChildren {
+                operator: PlannedOperator::new_execute(
+                    self.id_gen.next_id(),
+                    PhysicalProject::new(projections),
+                ),
+                children: vec![child],
+            };
+        }
+
+        Ok(child)
+    }
+}
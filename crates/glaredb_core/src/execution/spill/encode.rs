@@ -0,0 +1,334 @@
+//! Simple columnar encoding for batches written to spill files.
+//!
+//! Each array is written as a validity bitmap followed by the values for all
+//! valid rows. Fixed-size values are written using their in-memory
+//! representation, varlen values are written with a u32 length prefix.
+//!
+//! This encoding is also used for table data in database files. Values are
+//! written using their in-memory representation, so files are only portable
+//! between machines with the same endianness.
+
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{
+    AddressableMut,
+    MutableScalarStorage,
+    PhysicalBinary,
+    PhysicalBool,
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI8,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI128,
+    PhysicalInterval,
+    PhysicalType,
+    PhysicalU8,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU128,
+    PhysicalUntypedNull,
+    PhysicalUtf8,
+    ScalarStorage,
+};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::arrays::executor::scalar::UnaryExecutor;
+
+/// Checks if arrays of the given types can be written to a spill file,
+/// returning an error for the first type that can't be.
+///
+/// Nested types are not yet supported.
+pub fn check_spill_types<'a>(types: impl IntoIterator<Item = &'a DataType>) -> Result<()> {
+    for datatype in types {
+        if matches!(
+            datatype.physical_type()?,
+            PhysicalType::List | PhysicalType::Struct | PhysicalType::Map
+        ) {
+            return Err(DbError::new(format!(
+                "Spilling {datatype} values not yet supported"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Encodes the rows in a batch, appending the encoded bytes to `buf`.
+pub fn encode_batch(batch: &Batch, buf: &mut Vec<u8>) -> Result<()> {
+    let num_rows = batch.num_rows();
+    for array in &batch.arrays {
+        encode_array(array, num_rows, buf)?;
+    }
+    Ok(())
+}
+
+/// Decodes `num_rows` rows from `buf` into `output`.
+///
+/// The output batch should have been reset for writes.
+pub fn decode_batch(buf: &[u8], num_rows: usize, output: &mut Batch) -> Result<()> {
+    let capacity = output.write_capacity()?;
+    if num_rows > capacity {
+        return Err(
+            DbError::new("Spilled batch contains more rows than output capacity")
+                .with_field("num_rows", num_rows)
+                .with_field("capacity", capacity),
+        );
+    }
+
+    let mut buf = buf;
+    for array in &mut output.arrays {
+        decode_array(&mut buf, num_rows, array)?;
+    }
+
+    if !buf.is_empty() {
+        return Err(DbError::new("Unexpected trailing bytes in spilled batch")
+            .with_field("remaining", buf.len()));
+    }
+
+    output.set_num_rows(num_rows)?;
+
+    Ok(())
+}
+
+fn encode_array(array: &Array, num_rows: usize, buf: &mut Vec<u8>) -> Result<()> {
+    match array.physical_type()? {
+        PhysicalType::UntypedNull => encode_fixed::<PhysicalUntypedNull>(array, num_rows, buf),
+        PhysicalType::Boolean => encode_fixed::<PhysicalBool>(array, num_rows, buf),
+        PhysicalType::Int8 => encode_fixed::<PhysicalI8>(array, num_rows, buf),
+        PhysicalType::Int16 => encode_fixed::<PhysicalI16>(array, num_rows, buf),
+        PhysicalType::Int32 => encode_fixed::<PhysicalI32>(array, num_rows, buf),
+        PhysicalType::Int64 => encode_fixed::<PhysicalI64>(array, num_rows, buf),
+        PhysicalType::Int128 => encode_fixed::<PhysicalI128>(array, num_rows, buf),
+        PhysicalType::UInt8 => encode_fixed::<PhysicalU8>(array, num_rows, buf),
+        PhysicalType::UInt16 => encode_fixed::<PhysicalU16>(array, num_rows, buf),
+        PhysicalType::UInt32 => encode_fixed::<PhysicalU32>(array, num_rows, buf),
+        PhysicalType::UInt64 => encode_fixed::<PhysicalU64>(array, num_rows, buf),
+        PhysicalType::UInt128 => encode_fixed::<PhysicalU128>(array, num_rows, buf),
+        PhysicalType::Float16 => encode_fixed::<PhysicalF16>(array, num_rows, buf),
+        PhysicalType::Float32 => encode_fixed::<PhysicalF32>(array, num_rows, buf),
+        PhysicalType::Float64 => encode_fixed::<PhysicalF64>(array, num_rows, buf),
+        PhysicalType::Interval => encode_fixed::<PhysicalInterval>(array, num_rows, buf),
+        PhysicalType::Utf8 => encode_varlen::<PhysicalUtf8>(array, num_rows, buf, |v| v.as_bytes()),
+        PhysicalType::Binary => encode_varlen::<PhysicalBinary>(array, num_rows, buf, |v| v),
+        other => Err(DbError::new(format!(
+            "Spilling arrays with physical type {other} not supported"
+        ))),
+    }
+}
+
+fn decode_array(buf: &mut &[u8], num_rows: usize, array: &mut Array) -> Result<()> {
+    match array.physical_type()? {
+        PhysicalType::UntypedNull => decode_fixed::<PhysicalUntypedNull>(buf, num_rows, array),
+        PhysicalType::Boolean => decode_fixed::<PhysicalBool>(buf, num_rows, array),
+        PhysicalType::Int8 => decode_fixed::<PhysicalI8>(buf, num_rows, array),
+        PhysicalType::Int16 => decode_fixed::<PhysicalI16>(buf, num_rows, array),
+        PhysicalType::Int32 => decode_fixed::<PhysicalI32>(buf, num_rows, array),
+        PhysicalType::Int64 => decode_fixed::<PhysicalI64>(buf, num_rows, array),
+        PhysicalType::Int128 => decode_fixed::<PhysicalI128>(buf, num_rows, array),
+        PhysicalType::UInt8 => decode_fixed::<PhysicalU8>(buf, num_rows, array),
+        PhysicalType::UInt16 => decode_fixed::<PhysicalU16>(buf, num_rows, array),
+        PhysicalType::UInt32 => decode_fixed::<PhysicalU32>(buf, num_rows, array),
+        PhysicalType::UInt64 => decode_fixed::<PhysicalU64>(buf, num_rows, array),
+        PhysicalType::UInt128 => decode_fixed::<PhysicalU128>(buf, num_rows, array),
+        PhysicalType::Float16 => decode_fixed::<PhysicalF16>(buf, num_rows, array),
+        PhysicalType::Float32 => decode_fixed::<PhysicalF32>(buf, num_rows, array),
+        PhysicalType::Float64 => decode_fixed::<PhysicalF64>(buf, num_rows, array),
+        PhysicalType::Interval => decode_fixed::<PhysicalInterval>(buf, num_rows, array),
+        PhysicalType::Utf8 => decode_varlen::<PhysicalUtf8>(buf, num_rows, array, |bs| {
+            std::str::from_utf8(bs)
+                .map_err(|_| DbError::new("Spilled string value is not valid utf8"))
+        }),
+        PhysicalType::Binary => decode_varlen::<PhysicalBinary>(buf, num_rows, array, |bs| Ok(bs)),
+        other => Err(DbError::new(format!(
+            "Spilling arrays with physical type {other} not supported"
+        ))),
+    }
+}
+
+/// Reserves space for the validity bitmap, returning the offset of the bitmap
+/// in the buffer.
+fn reserve_validity(num_rows: usize, buf: &mut Vec<u8>) -> usize {
+    let offset = buf.len();
+    buf.resize(offset + num_rows.div_ceil(8), 0);
+    offset
+}
+
+fn set_valid(buf: &mut [u8], validity_offset: usize, idx: usize) {
+    buf[validity_offset + idx / 8] |= 1 << (idx % 8);
+}
+
+fn encode_fixed<S>(array: &Array, num_rows: usize, buf: &mut Vec<u8>) -> Result<()>
+where
+    S: ScalarStorage,
+    S::StorageType: Copy + Sized,
+{
+    let validity_offset = reserve_validity(num_rows, buf);
+    UnaryExecutor::for_each_flat::<S, _>(array, 0..num_rows, |idx, v| {
+        if let Some(v) = v {
+            set_valid(buf, validity_offset, idx);
+            // SAFETY: All fixed-size storage types are plain data without
+            // padding. We only ever read back these bytes as the same type.
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    (v as *const S::StorageType).cast::<u8>(),
+                    std::mem::size_of::<S::StorageType>(),
+                )
+            };
+            buf.extend_from_slice(bytes);
+        }
+    })
+}
+
+fn encode_varlen<S>(
+    array: &Array,
+    num_rows: usize,
+    buf: &mut Vec<u8>,
+    as_bytes: impl Fn(&S::StorageType) -> &[u8],
+) -> Result<()>
+where
+    S: ScalarStorage,
+{
+    let validity_offset = reserve_validity(num_rows, buf);
+    UnaryExecutor::for_each_flat::<S, _>(array, 0..num_rows, |idx, v| {
+        if let Some(v) = v {
+            set_valid(buf, validity_offset, idx);
+            let bytes = as_bytes(v);
+            buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            buf.extend_from_slice(bytes);
+        }
+    })
+}
+
+fn take_bytes<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(DbError::new("Unexpected end of spilled batch")
+            .with_field("needed", len)
+            .with_field("remaining", buf.len()));
+    }
+    let (bytes, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(bytes)
+}
+
+fn is_valid(validity: &[u8], idx: usize) -> bool {
+    validity[idx / 8] & (1 << (idx % 8)) != 0
+}
+
+fn decode_fixed<S>(buf: &mut &[u8], num_rows: usize, array: &mut Array) -> Result<()>
+where
+    S: MutableScalarStorage,
+    S::StorageType: Copy + Sized,
+{
+    let validity = take_bytes(buf, num_rows.div_ceil(8))?;
+    let size = std::mem::size_of::<S::StorageType>();
+
+    let mut values = S::get_addressable_mut(&mut array.data)?;
+    for idx in 0..num_rows {
+        if !is_valid(validity, idx) {
+            array.validity.set_invalid(idx);
+            continue;
+        }
+
+        let bytes = take_bytes(buf, size)?;
+        // SAFETY: Bytes were written from a value of the same type in
+        // `encode_fixed`, and we checked the length above.
+        let v = unsafe { bytes.as_ptr().cast::<S::StorageType>().read_unaligned() };
+        values.put(idx, &v);
+    }
+
+    Ok(())
+}
+
+fn decode_varlen<S>(
+    buf: &mut &[u8],
+    num_rows: usize,
+    array: &mut Array,
+    from_bytes: impl Fn(&[u8]) -> Result<&S::StorageType>,
+) -> Result<()>
+where
+    S: MutableScalarStorage,
+{
+    let validity = take_bytes(buf, num_rows.div_ceil(8))?;
+
+    let mut values = S::get_addressable_mut(&mut array.data)?;
+    for idx in 0..num_rows {
+        if !is_valid(validity, idx) {
+            array.validity.set_invalid(idx);
+            continue;
+        }
+
+        let len = take_bytes(buf, 4)?;
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        let bytes = take_bytes(buf, len)?;
+        values.put(idx, from_bytes(bytes)?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_batch;
+    use crate::testutil::arrays::assert_batches_eq;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn roundtrip(batch: &Batch) -> Batch {
+        let mut buf = Vec::new();
+        encode_batch(batch, &mut buf).unwrap();
+
+        let types = batch.arrays.iter().map(|arr| arr.datatype().clone());
+        let mut output = Batch::new(types, 16).unwrap();
+        decode_batch(&buf, batch.num_rows(), &mut output).unwrap();
+
+        output
+    }
+
+    #[test]
+    fn roundtrip_primitives_and_strings() {
+        let batch = generate_batch!([1, 2, 3], ["a", "bb", "ccc"], [1.5, 2.5, 3.5]);
+        let output = roundtrip(&batch);
+        assert_batches_eq(&batch, &output);
+    }
+
+    #[test]
+    fn roundtrip_with_nulls() {
+        let batch = Batch::from_arrays([
+            Array::try_from_iter([Some(1_i64), None, Some(3)]).unwrap(),
+            Array::try_from_iter([None, Some("hello"), None]).unwrap(),
+        ])
+        .unwrap();
+        let output = roundtrip(&batch);
+        assert_batches_eq(&batch, &output);
+    }
+
+    #[test]
+    fn roundtrip_with_selection() {
+        let mut batch = generate_batch!([1, 2, 3, 4], ["a", "b", "c", "d"]);
+        batch.select([3, 1]).unwrap();
+
+        let output = roundtrip(&batch);
+        let expected = generate_batch!([4, 2], ["d", "b"]);
+        assert_batches_eq(&expected, &output);
+    }
+
+    #[test]
+    fn decode_rows_exceed_capacity() {
+        let batch = generate_batch!([1, 2, 3]);
+        let mut buf = Vec::new();
+        encode_batch(&batch, &mut buf).unwrap();
+
+        let mut output = Batch::new([DataType::int32()], 2).unwrap();
+        decode_batch(&buf, 3, &mut output).unwrap_err();
+    }
+
+    #[test]
+    fn cannot_spill_lists() {
+        check_spill_types(&[DataType::int32(), DataType::utf8()]).unwrap();
+        check_spill_types(&[DataType::list(DataType::int32())]).unwrap_err();
+    }
+}
@@ -0,0 +1,300 @@
+//! Spilling intermediate operator state to temporary files.
+//!
+//! Operators that may hold more data than fits in memory (sorts, hash
+//! aggregates, hash joins) get an optional `SpillContext` when a memory limit
+//! is configured. The limit is shared by all operators in the query and tracked
+//! by the context's `MemoryTracker`. Each operator partition holds a
+//! `MemoryReservation` for the data it's collected, and once growing that
+//! reservation fails, it writes batches to spill files and reads them back once
+//! all input has been received.
+//!
+//! The limit is a threshold for spilling, not a cap on the query's memory
+//! usage. Only data held by spilling operators is counted, other allocations
+//! aren't limited.
+//!
+//! Operators that can't spill (e.g. inputs containing nested types) return an
+//! error instead of going over the limit.
+//!
+//! Each spill file is a sequence of frames:
+//!
+//! ```text
+//! [num_rows: u32][len: u64][encoded batch: len bytes]
+//! ```
+//!
+//! See `encode` for the batch encoding.
+pub mod encode;
+
+use std::fmt;
+use std::io::SeekFrom;
+use std::sync::atomic::{self, AtomicUsize};
+
+use glaredb_error::{DbError, Result, ResultExt};
+
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::buffer::buffer_manager::{MemoryLimit, MemoryReservation, MemoryTracker};
+use crate::runtime::filesystem::blocking::{complete, flush, write_all};
+use crate::runtime::filesystem::{AnyFile, FileSystemWithState, OpenFlags};
+
+/// Size of the frame header (row count + payload length).
+const FRAME_HEADER_SIZE: usize = 4 + 8;
+
+/// Shared context for spilling within a single query.
+pub struct SpillContext {
+    /// Tracks memory for the query.
+    tracker: MemoryTracker,
+    filesystem: FileSystemWithState,
+    /// Directory to create spill files in.
+    directory: String,
+    /// Prefix for all spill files, unique per query.
+    prefix: String,
+    next_file: AtomicUsize,
+}
+
+impl fmt::Debug for SpillContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpillContext")
+            .field("limit", &self.tracker.limit())
+            .field("directory", &self.directory)
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SpillContext {
+    pub fn new(
+        limit: MemoryLimit,
+        filesystem: FileSystemWithState,
+        directory: impl Into<String>,
+        prefix: impl Into<String>,
+    ) -> Self {
+        SpillContext {
+            tracker: MemoryTracker::new(limit),
+            filesystem,
+            directory: directory.into(),
+            prefix: prefix.into(),
+            next_file: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn limit(&self) -> MemoryLimit {
+        self.tracker.limit()
+    }
+
+    /// Creates a new empty memory reservation for an operator partition.
+    pub fn new_reservation(&self) -> MemoryReservation {
+        self.tracker.new_reservation()
+    }
+
+    /// Checks that batches with the given types can be spilled.
+    ///
+    /// Called once an operator's reservation fails. If we can't spill, the
+    /// returned error indicates the memory limit was exceeded.
+    pub fn check_can_spill<'a>(&self, types: impl IntoIterator<Item = &'a DataType>) -> Result<()> {
+        encode::check_spill_types(types).map_err(|e| {
+            DbError::with_source(
+                "Memory limit exceeded, and unable to spill to disk",
+                Box::new(e),
+            )
+            .with_field("limit", self.tracker.limit().bytes())
+        })
+    }
+
+    /// Creates a new spill file for writing.
+    pub fn create_file(&self) -> Result<SpillWriter> {
+        let n = self.next_file.fetch_add(1, atomic::Ordering::Relaxed);
+        let path = format!(
+            "{}/{}_{n}.spill",
+            self.directory.trim_end_matches('/'),
+            self.prefix
+        );
+
+        let flags = OpenFlags::new([
+            OpenFlags::READ,
+            OpenFlags::WRITE,
+            OpenFlags::CREATE,
+            OpenFlags::TRUNCATE,
+        ]);
+        let file = complete(self.filesystem.open(flags, &path))
+            .context_fn(|| format!("Failed to create spill file '{path}'"))?;
+
+        Ok(SpillWriter {
+            file,
+            handle: SpillFileHandle {
+                filesystem: self.filesystem.clone(),
+                path,
+            },
+            buf: Vec::new(),
+            num_batches: 0,
+        })
+    }
+}
+
+/// Returns the directory to use for spill files when one isn't configured.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn default_spill_directory() -> Result<String> {
+    Ok(std::env::temp_dir().to_string_lossy().into_owned())
+}
+
+/// Returns the directory to use for spill files when one isn't configured.
+#[cfg(target_arch = "wasm32")]
+pub fn default_spill_directory() -> Result<String> {
+    Err(DbError::new(
+        "No default temp directory, set 'temp_directory' to enable spilling",
+    ))
+}
+
+/// Deletes the spill file when dropped.
+#[derive(Debug)]
+struct SpillFileHandle {
+    filesystem: FileSystemWithState,
+    path: String,
+}
+
+impl Drop for SpillFileHandle {
+    fn drop(&mut self) {
+        // Best effort, the file is in a temp directory anyways.
+        let _ = complete(self.filesystem.delete(&self.path));
+    }
+}
+
+/// Writes batches to a spill file.
+#[derive(Debug)]
+pub struct SpillWriter {
+    // Note the file needs to be closed before the handle is dropped.
+    file: AnyFile,
+    handle: SpillFileHandle,
+    /// Reusable buffer for encoding batches.
+    buf: Vec<u8>,
+    num_batches: usize,
+}
+
+impl SpillWriter {
+    /// Writes the rows in a batch to the file.
+    ///
+    /// Empty batches are skipped.
+    pub fn write_batch(&mut self, batch: &Batch) -> Result<()> {
+        let num_rows = batch.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+
+        self.buf.clear();
+        self.buf.resize(FRAME_HEADER_SIZE, 0);
+        encode::encode_batch(batch, &mut self.buf)?;
+
+        let len = (self.buf.len() - FRAME_HEADER_SIZE) as u64;
+        self.buf[0..4].copy_from_slice(&(num_rows as u32).to_le_bytes());
+        self.buf[4..FRAME_HEADER_SIZE].copy_from_slice(&len.to_le_bytes());
+
+        write_all(&mut self.file, &self.buf)
+            .context_fn(|| format!("Failed to write spill file '{}'", self.handle.path))?;
+        self.num_batches += 1;
+
+        Ok(())
+    }
+
+    pub fn num_batches(&self) -> usize {
+        self.num_batches
+    }
+
+    /// Finish writing, returning a reader for reading batches back from the
+    /// start of the file.
+    pub fn finish(mut self) -> Result<SpillReader> {
+        flush(&mut self.file)
+            .and_then(|_| complete(self.file.call_seek(SeekFrom::Start(0))))
+            .context_fn(|| format!("Failed to finish spill file '{}'", self.handle.path))?;
+
+        Ok(SpillReader {
+            file: self.file,
+            handle: self.handle,
+            buf: self.buf,
+            remaining: self.num_batches,
+        })
+    }
+}
+
+/// Reads batches from a spill file in the order they were written.
+#[derive(Debug)]
+pub struct SpillReader {
+    file: AnyFile,
+    handle: SpillFileHandle,
+    buf: Vec<u8>,
+    /// Number of batches remaining in the file.
+    remaining: usize,
+}
+
+impl SpillReader {
+    /// Reads the next batch into `output`.
+    ///
+    /// Returns `false` when there's no more batches, `output` will be left
+    /// untouched.
+    pub fn read_batch(&mut self, output: &mut Batch) -> Result<bool> {
+        if self.remaining == 0 {
+            return Ok(false);
+        }
+
+        let path = &self.handle.path;
+        let mut header = [0; FRAME_HEADER_SIZE];
+        complete(self.file.call_read_exact(&mut header))
+            .context_fn(|| format!("Failed to read spill file '{path}'"))?;
+        let num_rows = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let len = u64::from_le_bytes(header[4..].try_into().unwrap()) as usize;
+
+        self.buf.resize(len, 0);
+        complete(self.file.call_read_exact(&mut self.buf))
+            .context_fn(|| format!("Failed to read spill file '{path}'"))?;
+
+        output.reset_for_write()?;
+        encode::decode_batch(&self.buf, num_rows, output)?;
+        self.remaining -= 1;
+
+        Ok(true)
+    }
+
+    pub fn remaining_batches(&self) -> usize {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_batch;
+    use crate::testutil::arrays::assert_batches_eq;
+    use crate::testutil::spill::{test_spill_context, test_spill_filesystem};
+
+    #[test]
+    fn write_read_removes_file() {
+        let (context, fs) = test_spill_context(1024);
+
+        let mut writer = context.create_file().unwrap();
+        writer.write_batch(&generate_batch!([1, 2, 3])).unwrap();
+        writer.write_batch(&generate_batch!([4, 5])).unwrap();
+        assert_eq!(1, fs.num_files());
+
+        let mut reader = writer.finish().unwrap();
+        let mut output = Batch::new([DataType::int32()], 16).unwrap();
+        assert!(reader.read_batch(&mut output).unwrap());
+        assert_batches_eq(&generate_batch!([1, 2, 3]), &output);
+        assert!(reader.read_batch(&mut output).unwrap());
+        assert_batches_eq(&generate_batch!([4, 5]), &output);
+        assert!(!reader.read_batch(&mut output).unwrap());
+
+        std::mem::drop(reader);
+        assert_eq!(0, fs.num_files());
+    }
+
+    #[test]
+    fn create_file_error_includes_path() {
+        // Memory filesystem doesn't support directories.
+        let (filesystem, _fs) = test_spill_filesystem();
+        let context = SpillContext::new(MemoryLimit::new(1024), filesystem, "/missing", "test");
+
+        let err = context.create_file().unwrap_err();
+        assert!(
+            err.to_string().contains("'/missing/test_0.spill'"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -166,8 +166,11 @@ impl ExplainNode {
             LogicalOperator::ShowVar(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::AttachDatabase(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::DetachDatabase(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Transaction(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Drop(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Insert(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Delete(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Merge(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::CreateSchema(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::CreateTable(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::CreateView(n) => (n.explain_entry(config), &n.children),
@@ -181,6 +184,8 @@ impl ExplainNode {
             LogicalOperator::Unnest(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Window(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::TableExecute(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::RecursiveCte(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::RecursiveCteScan(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::MaterializationScan(n) => {
                 // Materialization displayed separately.
                 return ExplainNode {
@@ -23,7 +23,7 @@ use std::fmt::{self, Debug};
 use aggregate_expr::AggregateExpr;
 use arith_expr::{ArithExpr, ArithOperator};
 use between_expr::BetweenExpr;
-use case_expr::CaseExpr;
+use case_expr::{CaseExpr, WhenThen};
 use cast_expr::CastExpr;
 use column_expr::{ColumnExpr, ColumnReference};
 use comparison_expr::{ComparisonExpr, ComparisonOperator};
@@ -49,7 +49,9 @@ use crate::functions::function_set::{
     FunctionSet,
     ScalarFunctionSet,
     TableFunctionSet,
+    WindowFunctionSet,
 };
+use crate::functions::scalar::builtin::is::FUNCTION_SET_IS_NOT_NULL;
 use crate::functions::scalar::{FunctionVolatility, PlannedScalarFunction};
 use crate::functions::table::scan::ScanContext;
 use crate::functions::table::{
@@ -58,6 +60,7 @@ use crate::functions::table::{
     TableFunctionInput,
     TableFunctionType,
 };
+use crate::functions::window::PlannedWindowFunction;
 use crate::logical::binder::table_list::TableRef;
 
 /// A logical expression.
@@ -167,7 +170,7 @@ impl Expression {
             }
             Self::Subquery(_) => (),
             Self::Window(window) => {
-                for input in &mut window.agg.state.inputs {
+                for input in &mut window.function.state_mut().inputs {
                     func(input)?;
                 }
                 for partition in &mut window.partition_by {
@@ -239,7 +242,7 @@ impl Expression {
             }
             Self::Subquery(_) => (),
             Self::Window(window) => {
-                for input in &window.agg.state.inputs {
+                for input in &window.function.state().inputs {
                     func(input)?;
                 }
                 for partition in &window.partition_by {
@@ -649,6 +652,34 @@ pub fn lit(scalar: impl Into<ScalarValue>) -> LiteralExpr {
     LiteralExpr(scalar.into())
 }
 
+/// Constructs a COALESCE of the inputs, returning the first non-NULL value.
+///
+/// This is rewritten to a CASE expression:
+///
+/// ```text
+/// COALESCE(a,b,c)
+/// =>
+/// CASE
+///   a IS NOT NULL THEN a
+///   b IS NOT NULL THEN b
+///   c IS NOT NULL THEN c
+/// ```
+pub fn coalesce(inputs: impl IntoIterator<Item = Expression>) -> Result<CaseExpr> {
+    let cases = inputs
+        .into_iter()
+        .map(|input| {
+            let is_not_null = scalar_function(&FUNCTION_SET_IS_NOT_NULL, vec![input.clone()])?;
+            Ok(WhenThen {
+                when: is_not_null.into(),
+                then: input,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Default cast rules.
+    CaseExpr::try_new(cases, None)
+}
+
 /// Wraps an expression in a cast using the default casting rules.
 ///
 /// Errors if no default casting rule can handle the types.
@@ -715,6 +746,32 @@ pub fn bind_aggregate_function(
     })
 }
 
+/// Binds a window function with the given inputs.
+///
+/// This will cast the inputs as needed. Errors if IGNORE NULLS is requested for
+/// a function that doesn't support it.
+pub fn bind_window_function(
+    function: &'static WindowFunctionSet,
+    inputs: Vec<Expression>,
+    ignore_nulls: bool,
+) -> Result<PlannedWindowFunction> {
+    let (func, inputs) = bind_function_signature_from_expressions(function, inputs)?;
+    if ignore_nulls && !func.supports_ignore_nulls() {
+        return Err(DbError::new(format!(
+            "IGNORE NULLS not supported for window function '{}'",
+            function.name
+        )));
+    }
+    let bind_state = func.call_bind(inputs, ignore_nulls)?;
+
+    Ok(PlannedWindowFunction {
+        name: function.name,
+        raw: func,
+        state: bind_state,
+        ignore_nulls,
+    })
+}
+
 pub fn scalar_function(
     function: &'static ScalarFunctionSet,
     inputs: Vec<Expression>,
@@ -21,6 +21,8 @@ use literal_expr::PhysicalLiteralExpr;
 use scalar_function_expr::PhysicalScalarFunctionExpr;
 
 use crate::arrays::datatype::DataType;
+use crate::execution::operators::window::frame::WindowFrame;
+use crate::expr::window_expr::WindowExprFunction;
 use crate::functions::aggregate::PlannedAggregateFunction;
 
 #[derive(Debug, Clone)]
@@ -134,6 +136,16 @@ impl PhysicalAggregateExpression {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct PhysicalWindowExpression {
+    /// The function used to compute values for the window.
+    pub function: WindowExprFunction,
+    /// Expressions producing the inputs to the function.
+    pub inputs: Vec<PhysicalScalarExpression>,
+    /// Frame to compute the function over.
+    pub frame: WindowFrame,
+}
+
 #[derive(Debug, Clone)]
 pub struct PhysicalSortExpression {
     /// Column this expression is for.
@@ -4,8 +4,11 @@ use glaredb_error::Result;
 
 use super::Expression;
 use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::{BorrowedScalarValue, ScalarValue};
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
 use crate::functions::aggregate::PlannedAggregateFunction;
+use crate::functions::bind_state::RawBindState;
+use crate::functions::window::PlannedWindowFunction;
 use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
 use crate::util::fmt::displayable::IntoDisplayableSlice;
 
@@ -16,6 +19,16 @@ pub enum WindowFrameUnit {
     Groups,
 }
 
+impl fmt::Display for WindowFrameUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rows => write!(f, "ROWS"),
+            Self::Range => write!(f, "RANGE"),
+            Self::Groups => write!(f, "GROUPS"),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WindowFrameExclusion {
     /// Exclude the current row.
@@ -29,13 +42,29 @@ pub enum WindowFrameExclusion {
     ExcludeNoOthers,
 }
 
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExcludeCurrentRow => write!(f, "EXCLUDE CURRENT ROW"),
+            Self::ExcludeGroup => write!(f, "EXCLUDE GROUP"),
+            Self::ExcludeTies => write!(f, "EXCLUDE TIES"),
+            Self::ExcludeNoOthers => write!(f, "EXCLUDE NO OTHERS"),
+        }
+    }
+}
+
 /// The window frame bound.
+///
+/// Offsets for PRECEDING and FOLLOWING are constant non-negative values. For
+/// ROWS and GROUPS, the offset is always an Int64. For RANGE, the offset is
+/// either a numeric value (numeric ORDER BY) or an interval (date or timestamp
+/// ORDER BY).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WindowFrameBound {
     UnboundedPreceding(WindowFrameUnit),
-    Preceding(WindowFrameUnit, i64),
+    Preceding(WindowFrameUnit, ScalarValue),
     UnboundedFollowing(WindowFrameUnit),
-    Following(WindowFrameUnit, i64),
+    Following(WindowFrameUnit, ScalarValue),
     CurrentRow(WindowFrameUnit),
 }
 
@@ -56,13 +85,66 @@ impl WindowFrameBound {
     pub const fn default_end() -> Self {
         WindowFrameBound::CurrentRow(WindowFrameUnit::Range)
     }
+
+    pub const fn unit(&self) -> WindowFrameUnit {
+        match self {
+            Self::UnboundedPreceding(unit)
+            | Self::Preceding(unit, _)
+            | Self::UnboundedFollowing(unit)
+            | Self::Following(unit, _)
+            | Self::CurrentRow(unit) => *unit,
+        }
+    }
+}
+
+impl fmt::Display for WindowFrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnboundedPreceding(_) => write!(f, "UNBOUNDED PRECEDING"),
+            Self::Preceding(_, offset) => write!(f, "{offset} PRECEDING"),
+            Self::UnboundedFollowing(_) => write!(f, "UNBOUNDED FOLLOWING"),
+            Self::Following(_, offset) => write!(f, "{offset} FOLLOWING"),
+            Self::CurrentRow(_) => write!(f, "CURRENT ROW"),
+        }
+    }
+}
+
+/// Function computed over a window.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowExprFunction {
+    /// An aggregate function computed over the window frame.
+    Aggregate(PlannedAggregateFunction),
+    /// A window-only function (e.g. `rank`).
+    Window(PlannedWindowFunction),
+}
+
+impl WindowExprFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Aggregate(agg) => agg.name,
+            Self::Window(window) => window.name,
+        }
+    }
+
+    pub fn state(&self) -> &RawBindState {
+        match self {
+            Self::Aggregate(agg) => &agg.state,
+            Self::Window(window) => &window.state,
+        }
+    }
+
+    pub fn state_mut(&mut self) -> &mut RawBindState {
+        match self {
+            Self::Aggregate(agg) => &mut agg.state,
+            Self::Window(window) => &mut window.state,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WindowExpr {
-    /// The aggregate function.
-    // TODO: May need to adjust to allow for window-only functions.
-    pub agg: PlannedAggregateFunction,
+    /// The function to compute.
+    pub function: WindowExprFunction,
     /// How to partition the input to the function.
     pub partition_by: Vec<Expression>,
     /// How the input is ordered within a partition.
@@ -77,7 +159,14 @@ pub struct WindowExpr {
 
 impl WindowExpr {
     pub fn datatype(&self) -> Result<DataType> {
-        Ok(self.agg.state.return_type.clone())
+        Ok(self.function.state().return_type.clone())
+    }
+
+    /// Returns if this window uses the default frame.
+    pub fn has_default_frame(&self) -> bool {
+        self.start == WindowFrameBound::default_start()
+            && self.end == WindowFrameBound::default_end()
+            && self.exclude == WindowFrameExclusion::default()
     }
 }
 
@@ -87,16 +176,20 @@ impl ContextDisplay for WindowExpr {
         mode: ContextDisplayMode,
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
-        write!(f, "{}", self.agg.name)?;
+        write!(f, "{}", self.function.name())?;
         let inputs = self
-            .agg
-            .state
+            .function
+            .state()
             .inputs
             .iter()
             .map(|expr| ContextDisplayWrapper::with_mode(expr, mode).to_string())
             .collect::<Vec<_>>()
             .join(", ");
-        write!(f, "({}) OVER (", inputs)?;
+        write!(f, "({})", inputs)?;
+        if matches!(&self.function, WindowExprFunction::Window(window) if window.ignore_nulls) {
+            write!(f, " IGNORE NULLS")?;
+        }
+        write!(f, " OVER (")?;
 
         if !self.partition_by.is_empty() {
             write!(
@@ -114,10 +207,46 @@ impl ContextDisplay for WindowExpr {
             write!(f, "ORDER BY {} ", self.order_by.display_as_list())?;
         }
 
-        // TODO: Bounds
+        if !self.has_default_frame() {
+            write!(
+                f,
+                "{} BETWEEN {} AND {} ",
+                self.start.unit(),
+                self.start,
+                self.end
+            )?;
+            if self.exclude != WindowFrameExclusion::default() {
+                write!(f, "{} ", self.exclude)?;
+            }
+        }
 
         write!(f, ")")?;
 
         Ok(())
     }
 }
+
+/// Get a numeric scalar as an f64.
+///
+/// Used for RANGE frame offsets and ORDER BY values when the ORDER BY column is
+/// numeric. Returns None if the value isn't numeric.
+pub(crate) fn scalar_as_f64(value: &BorrowedScalarValue) -> Option<f64> {
+    Some(match value {
+        BorrowedScalarValue::Int8(v) => *v as f64,
+        BorrowedScalarValue::Int16(v) => *v as f64,
+        BorrowedScalarValue::Int32(v) => *v as f64,
+        BorrowedScalarValue::Int64(v) => *v as f64,
+        BorrowedScalarValue::Int128(v) => *v as f64,
+        BorrowedScalarValue::UInt8(v) => *v as f64,
+        BorrowedScalarValue::UInt16(v) => *v as f64,
+        BorrowedScalarValue::UInt32(v) => *v as f64,
+        BorrowedScalarValue::UInt64(v) => *v as f64,
+        BorrowedScalarValue::UInt128(v) => *v as f64,
+        BorrowedScalarValue::Float16(v) => f64::from(*v),
+        BorrowedScalarValue::Float32(v) => *v as f64,
+        BorrowedScalarValue::Float64(v) => *v,
+        BorrowedScalarValue::Decimal64(v) => v.value as f64 / 10_f64.powi(v.scale as i32),
+        BorrowedScalarValue::Decimal128(v) => v.value as f64 / 10_f64.powi(v.scale as i32),
+        _ => return None,
+    })
+}
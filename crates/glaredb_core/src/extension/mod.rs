@@ -1,4 +1,5 @@
-use crate::catalog::create::FileInferScan;
+use crate::catalog::create::{FileInferCopyTo, FileInferScan};
+use crate::functions::copy_to::CopyToFunctionSet;
 use crate::functions::function_set::{AggregateFunctionSet, ScalarFunctionSet, TableFunctionSet};
 
 pub trait Extension {
@@ -24,6 +25,10 @@ pub trait Extension {
     fn table_functions(&self) -> &[ExtensionTableFunction] {
         &[]
     }
+
+    fn copy_to_functions(&self) -> &[ExtensionCopyToFunction] {
+        &[]
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -40,3 +45,9 @@ impl ExtensionTableFunction {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExtensionCopyToFunction {
+    pub infer_copy_to: Option<FileInferCopyTo>,
+    pub function: &'static CopyToFunctionSet,
+}
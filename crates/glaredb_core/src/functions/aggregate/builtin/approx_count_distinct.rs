@@ -126,7 +126,7 @@ impl AggregateFunction for ApproxCountDistinct {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ApproxDistinctState {
     hll: HyperLogLog,
 }
@@ -143,7 +143,7 @@ where
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AvgStateDecimal<I> {
     /// Scale to use when finalizing the physical decimal value.
     scale: f64,
@@ -186,7 +186,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct AvgStateF64<I, T> {
     sum: T,
     count: i64,
@@ -107,7 +107,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct BitAndStatePrimitive<T: Default> {
     result: T,
     valid: bool,
@@ -52,7 +52,7 @@ impl UnaryAggregate for BoolAnd {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoolAndState {
     result: bool,
     valid: bool,
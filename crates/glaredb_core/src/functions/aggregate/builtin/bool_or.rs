@@ -52,7 +52,7 @@ impl UnaryAggregate for BoolOr {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct BoolOrState {
     result: bool,
     valid: bool,
@@ -58,7 +58,7 @@ impl BinaryAggregate for Corr {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CorrelationState {
     covar: CovarState<CovarPopFinalize>,
     stddev_x: VarianceState<StddevPopFinalize>,
@@ -126,7 +126,7 @@ impl AggregateFunction for Count {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CountNonNullState {
     count: i64,
 }
@@ -99,7 +99,7 @@ impl BinaryAggregate for CovarSamp {
     }
 }
 
-pub trait CovarFinalize: Sync + Send + Debug + Default + 'static {
+pub trait CovarFinalize: Sync + Send + Copy + Debug + Default + 'static {
     fn finalize(co_moment: f64, count: i64) -> Option<f64>;
 }
 
@@ -127,7 +127,7 @@ impl CovarFinalize for CovarPopFinalize {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CovarState<F: CovarFinalize> {
     pub count: i64,
     pub meanx: f64,
@@ -121,6 +121,14 @@ pub const FUNCTION_SET_FIRST: AggregateFunctionSet = AggregateFunctionSet {
             &Signature::new(&[DataTypeId::Date64], DataTypeId::Date64),
             &SimpleUnaryAggregate::new(&FirstPrimitive::<PhysicalI64>::new()),
         ),
+        RawAggregateFunction::new(
+            &Signature::new(&[DataTypeId::Time], DataTypeId::Time),
+            &SimpleUnaryAggregate::new(&FirstPrimitive::<PhysicalI64>::new()),
+        ),
+        RawAggregateFunction::new(
+            &Signature::new(&[DataTypeId::Uuid], DataTypeId::Uuid),
+            &SimpleUnaryAggregate::new(&FirstPrimitive::<PhysicalU128>::new()),
+        ),
         RawAggregateFunction::new(
             &Signature::new(&[DataTypeId::Timestamp], DataTypeId::Timestamp),
             &SimpleUnaryAggregate::new(&FirstPrimitive::<PhysicalI64>::new()),
@@ -199,7 +207,7 @@ impl UnaryAggregate for FirstBinary {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct FirstPrimitiveState<T> {
     value: Option<T>,
 }
@@ -236,7 +244,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct FirstBinaryState {
     value: Option<Vec<u8>>,
 }
@@ -126,6 +126,14 @@ pub const FUNCTION_SET_MIN: AggregateFunctionSet = AggregateFunctionSet {
             &Signature::new(&[DataTypeId::Date64], DataTypeId::Date64),
             &SimpleUnaryAggregate::new(&MinPrimitive::<PhysicalI64>::new()),
         ),
+        RawAggregateFunction::new(
+            &Signature::new(&[DataTypeId::Time], DataTypeId::Time),
+            &SimpleUnaryAggregate::new(&MinPrimitive::<PhysicalI64>::new()),
+        ),
+        RawAggregateFunction::new(
+            &Signature::new(&[DataTypeId::Uuid], DataTypeId::Uuid),
+            &SimpleUnaryAggregate::new(&MinPrimitive::<PhysicalU128>::new()),
+        ),
         RawAggregateFunction::new(
             &Signature::new(&[DataTypeId::Timestamp], DataTypeId::Timestamp),
             &SimpleUnaryAggregate::new(&MinPrimitive::<PhysicalI64>::new()),
@@ -290,6 +298,14 @@ pub const FUNCTION_SET_MAX: AggregateFunctionSet = AggregateFunctionSet {
             &Signature::new(&[DataTypeId::Date64], DataTypeId::Date64),
             &SimpleUnaryAggregate::new(&MaxPrimitive::<PhysicalI64>::new()),
         ),
+        RawAggregateFunction::new(
+            &Signature::new(&[DataTypeId::Time], DataTypeId::Time),
+            &SimpleUnaryAggregate::new(&MaxPrimitive::<PhysicalI64>::new()),
+        ),
+        RawAggregateFunction::new(
+            &Signature::new(&[DataTypeId::Uuid], DataTypeId::Uuid),
+            &SimpleUnaryAggregate::new(&MaxPrimitive::<PhysicalU128>::new()),
+        ),
         RawAggregateFunction::new(
             &Signature::new(&[DataTypeId::Timestamp], DataTypeId::Timestamp),
             &SimpleUnaryAggregate::new(&MaxPrimitive::<PhysicalI64>::new()),
@@ -368,7 +384,7 @@ impl UnaryAggregate for MaxBinary {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MaxStatePrimitive<T> {
     max: T,
     valid: bool,
@@ -426,7 +442,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MaxStateBinary {
     max: Vec<u8>,
     valid: bool,
@@ -481,7 +497,7 @@ impl AggregateState<&[u8], [u8]> for MaxStateBinary {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MinStatePrimitive<T> {
     min: T,
     valid: bool,
@@ -539,7 +555,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MinStateBinary {
     min: Vec<u8>,
     valid: bool,
@@ -113,7 +113,7 @@ impl RegrAvgInput for RegrAvgX {
     }
 }
 
-pub trait RegrAvgInput: Sync + Send + Debug + 'static {
+pub trait RegrAvgInput: Sync + Send + Copy + Debug + 'static {
     fn input(vals: (f64, f64)) -> f64;
 }
 
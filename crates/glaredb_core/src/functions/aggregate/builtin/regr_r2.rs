@@ -57,7 +57,7 @@ impl BinaryAggregate for RegrR2 {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct RegrR2State {
     corr: CorrelationState,
 }
@@ -58,7 +58,7 @@ impl BinaryAggregate for RegrSlope {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct RegrSlopeState {
     cov: CovarState<CovarPopFinalize>,
     var: VarianceState<VariancePopFinalize>,
@@ -77,7 +77,7 @@ impl BinaryAggregate for StringAgg {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct StringAggState {
     /// String build built up.
     ///
@@ -174,7 +174,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct SumStateCheckedAdd<S, I> {
     sum: S,
     valid: bool,
@@ -213,7 +213,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct SumStateAdd<T> {
     sum: T,
     valid: bool,
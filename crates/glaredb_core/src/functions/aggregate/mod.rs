@@ -63,6 +63,19 @@ impl PlannedAggregateFunction {
         unsafe { (self.raw.vtable.combine_fn)(self.state.state_as_any(), src, dest) }
     }
 
+    /// Clones the state in `src` into `dest`.
+    ///
+    /// `dest` must point to uninitialized memory for the state. The cloned
+    /// state is written without dropping any existing value.
+    pub(crate) unsafe fn call_clone_state(&self, src: *const u8, dest: *mut u8) {
+        unsafe { (self.raw.vtable.clone_state_fn)(src, dest) }
+    }
+
+    /// Drops the state pointed to by `state` without finalizing it.
+    pub(crate) unsafe fn call_drop_state(&self, state: *mut u8) {
+        unsafe { (self.raw.vtable.drop_state_fn)(state) }
+    }
+
     pub(crate) unsafe fn call_finalize(
         &self,
         agg_states: &mut [*mut u8],
@@ -148,6 +161,8 @@ pub struct RawAggregateFunctionVTable {
         states: &mut [*mut u8],
     ) -> Result<()>,
     combine_fn: unsafe fn(state: &dyn Any, src: &mut [*mut u8], dest: &mut [*mut u8]) -> Result<()>,
+    clone_state_fn: unsafe fn(src: *const u8, dest: *mut u8),
+    drop_state_fn: unsafe fn(state: *mut u8),
     finalize_fn:
         unsafe fn(state: &dyn Any, states: &mut [*mut u8], output: &mut Array) -> Result<()>,
 }
@@ -158,7 +173,10 @@ pub trait AggregateFunction: Debug + Copy + Sync + Send + Sized + 'static {
     type BindState: Sync + Send;
 
     /// The type for aggregate values for a single group.
-    type GroupState: Sync + Send;
+    ///
+    /// Cloning is used when an intermediate state needs to be finalized
+    /// without consuming it (e.g. running aggregates for window functions).
+    type GroupState: Clone + Sync + Send;
 
     fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::BindState>>;
 
@@ -201,7 +219,6 @@ pub trait AggregateFunction: Debug + Copy + Sync + Send + Sized + 'static {
 
 // TODO: More comprehensive drop checking (specifically we need to drop
 // arbitrarily on query errors).
-// TODO: Explicit drop function.
 trait AggregateFunctionVTable: AggregateFunction {
     const VTABLE: &'static RawAggregateFunctionVTable = &RawAggregateFunctionVTable {
         bind_fn: |function: *const (), inputs: Vec<Expression>| -> Result<RawBindState> {
@@ -256,6 +273,13 @@ trait AggregateFunctionVTable: AggregateFunction {
 
             Ok(())
         },
+        clone_state_fn: |src, dest| {
+            let src = unsafe { src.cast::<Self::GroupState>().as_ref().unwrap() };
+            unsafe { dest.cast::<Self::GroupState>().write(src.clone()) };
+        },
+        drop_state_fn: |state| {
+            unsafe { state.cast::<Self::GroupState>().drop_in_place() };
+        },
         finalize_fn: |state, states, output| {
             let state = state.downcast_ref::<Self::BindState>().unwrap();
             let typed_states: &mut [&mut Self::GroupState] = unsafe {
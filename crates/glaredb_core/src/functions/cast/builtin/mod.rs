@@ -5,6 +5,9 @@ pub mod to_decimal;
 pub mod to_interval;
 pub mod to_primitive;
 pub mod to_string;
+pub mod to_time;
+pub mod to_timestamp;
+pub mod to_uuid;
 
 use to_binary::FUNCTION_SET_TO_BINARY;
 use to_boolean::FUNCTION_SET_TO_BOOLEAN;
@@ -27,6 +30,9 @@ use to_primitive::{
     FUNCTION_SET_TO_UINT128,
 };
 use to_string::FUNCTION_SET_TO_STRING;
+use to_time::FUNCTION_SET_TO_TIME;
+use to_timestamp::FUNCTION_SET_TO_TIMESTAMP;
+use to_uuid::FUNCTION_SET_TO_UUID;
 
 use super::CastFunctionSet;
 
@@ -56,8 +62,14 @@ pub const BUILTIN_CAST_FUNCTION_SETS: &[CastFunctionSet] = &[
     FUNCTION_SET_TO_DECIMAL128,
     // Date
     FUNCTION_SET_TO_DATE32,
+    // Timestamp
+    FUNCTION_SET_TO_TIMESTAMP,
+    // Time
+    FUNCTION_SET_TO_TIME,
     // Interval
     FUNCTION_SET_TO_INTERVAL,
+    // Uuid
+    FUNCTION_SET_TO_UUID,
 ];
 
 mod null {
@@ -2,7 +2,7 @@ use glaredb_error::Result;
 
 use super::null::NullToAnything;
 use crate::arrays::array::Array;
-use crate::arrays::array::physical_type::{PhysicalBinary, PhysicalUtf8};
+use crate::arrays::array::physical_type::{PhysicalBinary, PhysicalU128, PhysicalUtf8};
 use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::executor::OutBuffer;
 use crate::arrays::executor::scalar::UnaryExecutor;
@@ -25,6 +25,8 @@ pub const FUNCTION_SET_TO_BINARY: CastFunctionSet = CastFunctionSet {
         RawCastFunction::new(DataTypeId::Null, &NullToAnything, CastRule::Explicit, CastFlatten::Safe),
         // String
         RawCastFunction::new(DataTypeId::Utf8, &StringToBinary, CastRule::Explicit, CastFlatten::Unsafe),
+        // Uuid
+        RawCastFunction::new(DataTypeId::Uuid, &UuidToBinary, CastRule::Explicit, CastFlatten::Unsafe),
     ],
 };
 
@@ -56,3 +58,32 @@ impl CastFunction for StringToBinary {
         )
     }
 }
+
+/// Casts a UUID to its 16 bytes.
+#[derive(Debug, Clone, Copy)]
+pub struct UuidToBinary;
+
+impl CastFunction for UuidToBinary {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _state: &Self::State,
+        _error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalU128, PhysicalBinary, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |v, buf| {
+                buf.put(&v.to_be_bytes());
+            },
+        )
+    }
+}
@@ -16,9 +16,11 @@ use crate::arrays::array::physical_type::{
     PhysicalU128,
     PhysicalUtf8,
 };
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::compute::date::naive_to_date32;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use crate::arrays::executor::OutBuffer;
 use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive};
 use crate::functions::cast::behavior::CastErrorState;
 use crate::functions::cast::parse::{Date32Parser, Parser};
 use crate::functions::cast::{
@@ -56,6 +58,8 @@ pub const FUNCTION_SET_TO_DATE32: CastFunctionSet = CastFunctionSet {
         RawCastFunction::new(DataTypeId::UInt128, &PrimToPrim::<PhysicalU128, PhysicalI32>::new(), CastRule::Explicit, CastFlatten::Unsafe),
         // Utf8 -> Date32
         RawCastFunction::new(DataTypeId::Utf8, &Utf8ToDate32, TO_DATE32_CAST_RULE, CastFlatten::Unsafe),
+        // Timestamp -> Date32
+        RawCastFunction::new(DataTypeId::Timestamp, &TimestampToDate32, CastRule::Explicit, CastFlatten::Unsafe),
     ],
 };
 
@@ -93,3 +97,57 @@ impl CastFunction for Utf8ToDate32 {
         error_state.into_result()
     }
 }
+
+#[derive(Debug)]
+pub struct TimestampToDate32State {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+}
+
+/// Casts a timestamp to its date.
+///
+/// Timestamps with a time zone produce the local date in that zone.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampToDate32;
+
+impl CastFunction for TimestampToDate32 {
+    type State = TimestampToDate32State;
+
+    fn bind(&self, src: &DataType, _target: &DataType) -> Result<Self::State> {
+        let m = src.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+        Ok(TimestampToDate32State { unit: m.unit, tz })
+    }
+
+    fn cast(
+        state: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        let convert = |v: i64| {
+            let datetime = timestamp_to_naive(state.unit, v)?;
+            let datetime = match state.tz {
+                Some(tz) => tz.utc_to_local(datetime),
+                None => datetime,
+            };
+            Some(naive_to_date32(datetime.date()))
+        };
+
+        UnaryExecutor::execute::<PhysicalI64, PhysicalI32, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |&v, buf| match convert(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state.set_error(|| DbError::new("Timestamp out of range"));
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
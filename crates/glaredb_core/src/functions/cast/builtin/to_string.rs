@@ -1,4 +1,4 @@
-use std::fmt::{Display, Write};
+use std::fmt::{Debug, Display, Write};
 use std::marker::PhantomData;
 
 use glaredb_error::{DbError, Result};
@@ -6,6 +6,8 @@ use glaredb_error::{DbError, Result};
 use super::null::NullToAnything;
 use crate::arrays::array::Array;
 use crate::arrays::array::physical_type::{
+    AddressableMut,
+    MutableScalarStorage,
     PhysicalBinary,
     PhysicalF16,
     PhysicalF32,
@@ -24,6 +26,7 @@ use crate::arrays::array::physical_type::{
     PhysicalUtf8,
     ScalarStorage,
 };
+use crate::arrays::array::validity::Validity;
 use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use crate::arrays::executor::OutBuffer;
 use crate::arrays::executor::scalar::UnaryExecutor;
@@ -33,19 +36,26 @@ use crate::arrays::scalar::decimal::{
     DecimalPrimitive,
     DecimalType,
 };
+use crate::arrays::scalar::timezone::TimeZone;
 use crate::functions::cast::behavior::CastErrorState;
 use crate::functions::cast::format::{
+    Date32Formatter,
+    Date64Formatter,
     DecimalFormatter,
     Formatter,
+    TimeFormatter,
     TimestampMicrosecondsFormatter,
     TimestampMillisecondsFormatter,
     TimestampNanosecondsFormatter,
     TimestampSecondsFormatter,
+    TimestampTzFormatter,
+    UuidFormatter,
 };
 use crate::functions::cast::{
     CastFlatten,
     CastFunction,
     CastFunctionSet,
+    CastRule,
     RawCastFunction,
     TO_STRING_CAST_RULE,
 };
@@ -76,10 +86,20 @@ pub const FUNCTION_SET_TO_STRING: CastFunctionSet = CastFunctionSet {
         // Decimals
         RawCastFunction::new(DataTypeId::Decimal64, &DecimalToString::<Decimal64Type>::new(), TO_STRING_CAST_RULE, CastFlatten::Unsafe),
         RawCastFunction::new(DataTypeId::Decimal128, &DecimalToString::<Decimal128Type>::new(), TO_STRING_CAST_RULE, CastFlatten::Unsafe),
+        // Date/time
+        RawCastFunction::new(DataTypeId::Date32, &FormatterToString::<PhysicalI32, _>::new(Date32Formatter), CastRule::Explicit, CastFlatten::Unsafe),
+        RawCastFunction::new(DataTypeId::Date64, &FormatterToString::<PhysicalI64, _>::new(Date64Formatter), CastRule::Explicit, CastFlatten::Unsafe),
+        RawCastFunction::new(DataTypeId::Time, &FormatterToString::<PhysicalI64, _>::new(TimeFormatter), CastRule::Explicit, CastFlatten::Unsafe),
         // Timestamp
         RawCastFunction::new(DataTypeId::Timestamp, &TimestampToString, TO_STRING_CAST_RULE, CastFlatten::Unsafe),
+        // Utf8
+        RawCastFunction::new(DataTypeId::Utf8, &Utf8ToString, CastRule::Explicit, CastFlatten::Safe),
         // Binary
         RawCastFunction::new(DataTypeId::Binary, &BinaryToString, TO_STRING_CAST_RULE, CastFlatten::Unsafe),
+        // Uuid
+        RawCastFunction::new(DataTypeId::Uuid, &FormatterToString::<PhysicalU128, _>::new(UuidFormatter), CastRule::Explicit, CastFlatten::Unsafe),
+        // Map
+        RawCastFunction::new(DataTypeId::Map, &MapToString, CastRule::Explicit, CastFlatten::Unsafe),
     ],
 };
 
@@ -134,6 +154,45 @@ where
     }
 }
 
+/// Casts to a string using a formatter that doesn't depend on the source
+/// type's metadata.
+#[derive(Debug, Clone, Copy)]
+pub struct FormatterToString<S, F> {
+    formatter: F,
+    _s: PhantomData<S>,
+}
+
+impl<S, F> FormatterToString<S, F> {
+    pub const fn new(formatter: F) -> Self {
+        FormatterToString {
+            formatter,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S, F> CastFunction for FormatterToString<S, F>
+where
+    S: ScalarStorage<StorageType = F::Type>,
+    F: Formatter + Debug + Copy + Sync + Send + 'static,
+{
+    type State = F;
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(self.formatter)
+    }
+
+    fn cast(
+        state: &Self::State,
+        error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        cast_with_formatter::<_, S>(error_state, state, src, sel, out)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DecimalToString<D> {
     _d: PhantomData<D>,
@@ -183,6 +242,7 @@ pub struct TimestampToString;
 #[derive(Debug)]
 pub struct TimestampToStringState {
     unit: TimeUnit,
+    tz: Option<TimeZone>,
 }
 
 impl CastFunction for TimestampToString {
@@ -190,7 +250,8 @@ impl CastFunction for TimestampToString {
 
     fn bind(&self, src: &DataType, _target: &DataType) -> Result<Self::State> {
         let m = src.try_get_timestamp_type_meta()?;
-        Ok(TimestampToStringState { unit: m.unit })
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+        Ok(TimestampToStringState { unit: m.unit, tz })
     }
 
     fn cast(
@@ -200,6 +261,14 @@ impl CastFunction for TimestampToString {
         sel: impl IntoExactSizeIterator<Item = usize>,
         out: &mut Array,
     ) -> Result<()> {
+        if let Some(tz) = state.tz {
+            let formatter = TimestampTzFormatter {
+                unit: state.unit,
+                tz,
+            };
+            return cast_with_formatter::<_, PhysicalI64>(error_state, &formatter, src, sel, out);
+        }
+
         match state.unit {
             TimeUnit::Second => cast_with_formatter::<_, PhysicalI64>(
                 error_state,
@@ -233,6 +302,33 @@ impl CastFunction for TimestampToString {
     }
 }
 
+/// Identity cast, e.g. for casting a string to an alias like JSON.
+#[derive(Debug, Clone, Copy)]
+pub struct Utf8ToString;
+
+impl CastFunction for Utf8ToString {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _state: &Self::State,
+        _error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |v, buf| buf.put(v),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BinaryToString;
 
@@ -266,6 +362,44 @@ impl CastFunction for BinaryToString {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct MapToString;
+
+impl CastFunction for MapToString {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _state: &Self::State,
+        _error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        let mut s_buf = String::new();
+        let sel = sel.into_exact_size_iter();
+        out.validity = Validity::new_all_valid(sel.len());
+        let mut strings = PhysicalUtf8::get_addressable_mut(&mut out.data)?;
+
+        for (output_idx, input_idx) in sel.enumerate() {
+            let value = src.get_value(input_idx)?;
+            if value.is_null() {
+                out.validity.set_invalid(output_idx);
+                continue;
+            }
+
+            s_buf.clear();
+            write!(s_buf, "{value}").map_err(|_| DbError::new("Failed to cast map to utf8"))?;
+            strings.put(output_idx, s_buf.as_str());
+        }
+
+        Ok(())
+    }
+}
+
 fn cast_with_formatter<F, S>(
     mut error_state: CastErrorState,
     formatter: &F,
@@ -0,0 +1,156 @@
+use glaredb_error::{DbError, Result};
+
+use super::null::NullToAnything;
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::compute::date::naive_to_time;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive};
+use crate::functions::cast::behavior::CastErrorState;
+use crate::functions::cast::parse::{Parser, TimeParser};
+use crate::functions::cast::{
+    CastFlatten,
+    CastFunction,
+    CastFunctionSet,
+    CastRule,
+    RawCastFunction,
+    TO_TIME_CAST_RULE,
+};
+use crate::util::iter::IntoExactSizeIterator;
+
+/// Function set for casting to Time.
+///
+/// Time is internally represented as the number of microseconds since
+/// midnight.
+pub const FUNCTION_SET_TO_TIME: CastFunctionSet = CastFunctionSet {
+    name: "to_time",
+    target: DataTypeId::Time,
+    #[rustfmt::skip]
+    functions: &[
+        // Null -> Time
+        RawCastFunction::new(DataTypeId::Null, &NullToAnything, TO_TIME_CAST_RULE, CastFlatten::Safe),
+        // Utf8 -> Time
+        RawCastFunction::new(DataTypeId::Utf8, &Utf8ToTime, TO_TIME_CAST_RULE, CastFlatten::Unsafe),
+        // Timestamp -> Time
+        RawCastFunction::new(DataTypeId::Timestamp, &TimestampToTime, CastRule::Explicit, CastFlatten::Unsafe),
+    ],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Utf8ToTime;
+
+impl CastFunction for Utf8ToTime {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalUtf8, PhysicalI64, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |v, buf| match TimeParser.parse(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state
+                        .set_error(|| DbError::new(format!("Failed to parse '{v}' into time")));
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+#[derive(Debug)]
+pub struct TimestampToTimeState {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+}
+
+/// Casts a timestamp to its time of day.
+///
+/// Timestamps with a time zone produce the local time in that zone.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampToTime;
+
+impl CastFunction for TimestampToTime {
+    type State = TimestampToTimeState;
+
+    fn bind(&self, src: &DataType, _target: &DataType) -> Result<Self::State> {
+        let m = src.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+        Ok(TimestampToTimeState { unit: m.unit, tz })
+    }
+
+    fn cast(
+        state: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        let convert = |v: i64| {
+            let datetime = timestamp_to_naive(state.unit, v)?;
+            let datetime = match state.tz {
+                Some(tz) => tz.utc_to_local(datetime),
+                None => datetime,
+            };
+            Some(naive_to_time(datetime.time()))
+        };
+
+        UnaryExecutor::execute::<PhysicalI64, PhysicalI64, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |&v, buf| match convert(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state.set_error(|| DbError::new("Timestamp out of range"));
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::TimestampTypeMeta;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::functions::cast::behavior::CastFailBehavior;
+    use crate::testutil::arrays::assert_arrays_eq;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    #[test]
+    fn cast_timestamp_to_time() {
+        let hour = 3_600_000_000_i64;
+        let src_type =
+            DataType::timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Microsecond, "+02"));
+        let mut arr = Array::try_from_iter([hour, 23 * hour]).unwrap();
+        arr.datatype = src_type.clone();
+
+        let mut out = Array::new(&DefaultBufferManager, DataType::time(), 2).unwrap();
+        let state = TimestampToTime.bind(&src_type, &DataType::time()).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        TimestampToTime::cast(&state, error_state, &arr, 0..2, &mut out).unwrap();
+
+        let mut expected = Array::try_from_iter([3 * hour, hour]).unwrap();
+        expected.datatype = DataType::time();
+        assert_arrays_eq(&expected, &out);
+    }
+}
@@ -0,0 +1,312 @@
+use chrono::{NaiveDateTime, NaiveTime};
+use glaredb_error::{DbError, Result};
+
+use super::null::NullToAnything;
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI32, PhysicalI64, PhysicalUtf8};
+use crate::arrays::compute::date::EPOCH_NAIVE_DATE;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::timezone::{
+    TimeZone,
+    naive_to_timestamp,
+    parse_timestamp,
+    timestamp_to_naive,
+};
+use crate::functions::cast::behavior::CastErrorState;
+use crate::functions::cast::{
+    CastFlatten,
+    CastFunction,
+    CastFunctionSet,
+    RawCastFunction,
+    TO_TIMESTAMP_CAST_RULE,
+};
+use crate::util::iter::IntoExactSizeIterator;
+
+/// Function set for casting to timestamps.
+///
+/// Timestamps with a time zone are stored as UTC. Casting a value without an
+/// explicit zone to a timestamp with a time zone interprets the value as local
+/// time in the target zone.
+pub const FUNCTION_SET_TO_TIMESTAMP: CastFunctionSet = CastFunctionSet {
+    name: "to_timestamp",
+    target: DataTypeId::Timestamp,
+    #[rustfmt::skip]
+    functions: &[
+        // Null -> Timestamp
+        RawCastFunction::new(DataTypeId::Null, &NullToAnything, TO_TIMESTAMP_CAST_RULE, CastFlatten::Safe),
+        // Utf8 -> Timestamp
+        RawCastFunction::new(DataTypeId::Utf8, &Utf8ToTimestamp, TO_TIMESTAMP_CAST_RULE, CastFlatten::Unsafe),
+        // Date32 -> Timestamp
+        RawCastFunction::new(DataTypeId::Date32, &Date32ToTimestamp, TO_TIMESTAMP_CAST_RULE, CastFlatten::Unsafe),
+        // Timestamp -> Timestamp
+        RawCastFunction::new(DataTypeId::Timestamp, &TimestampToTimestamp, TO_TIMESTAMP_CAST_RULE, CastFlatten::Unsafe),
+    ],
+};
+
+/// Unit and optional time zone for a timestamp type.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampCastMeta {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+}
+
+impl TimestampCastMeta {
+    fn try_from_datatype(datatype: &DataType) -> Result<Self> {
+        let m = datatype.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+        Ok(TimestampCastMeta { unit: m.unit, tz })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Utf8ToTimestamp;
+
+impl CastFunction for Utf8ToTimestamp {
+    type State = TimestampCastMeta;
+
+    fn bind(&self, _src: &DataType, target: &DataType) -> Result<Self::State> {
+        TimestampCastMeta::try_from_datatype(target)
+    }
+
+    fn cast(
+        state: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        let parse = |s: &str| {
+            let (datetime, explicit_tz) = parse_timestamp(s)?;
+            let datetime = match (state.tz, explicit_tz) {
+                // Naive timestamps ignore any zone in the string, matching
+                // Postgres.
+                (None, _) => datetime,
+                (Some(_), Some(explicit_tz)) => explicit_tz.local_to_utc(datetime),
+                (Some(tz), None) => tz.local_to_utc(datetime),
+            };
+            naive_to_timestamp(state.unit, datetime)
+        };
+
+        UnaryExecutor::execute::<PhysicalUtf8, PhysicalI64, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |v, buf| match parse(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state.set_error(|| {
+                        DbError::new(format!("Failed to parse '{v}' into timestamp"))
+                    });
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Date32ToTimestamp;
+
+impl CastFunction for Date32ToTimestamp {
+    type State = TimestampCastMeta;
+
+    fn bind(&self, _src: &DataType, target: &DataType) -> Result<Self::State> {
+        TimestampCastMeta::try_from_datatype(target)
+    }
+
+    fn cast(
+        state: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        let convert = |days: i32| {
+            let date = EPOCH_NAIVE_DATE.checked_add_signed(chrono::Duration::days(days as i64))?;
+            let datetime = NaiveDateTime::new(date, NaiveTime::MIN);
+            let datetime = match state.tz {
+                Some(tz) => tz.local_to_utc(datetime),
+                None => datetime,
+            };
+            naive_to_timestamp(state.unit, datetime)
+        };
+
+        UnaryExecutor::execute::<PhysicalI32, PhysicalI64, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |&v, buf| match convert(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state.set_error(|| DbError::new("Date out of range for timestamp"));
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+#[derive(Debug)]
+pub struct TimestampToTimestampState {
+    src: TimestampCastMeta,
+    target: TimestampCastMeta,
+}
+
+/// Casts between timestamps with different units or time zones.
+///
+/// Casting a naive timestamp to one with a time zone interprets the naive
+/// value as local time in that zone. Casting the other way produces the local
+/// time in the source zone. Casting between two zones keeps the same instant.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampToTimestamp;
+
+impl CastFunction for TimestampToTimestamp {
+    type State = TimestampToTimestampState;
+
+    fn bind(&self, src: &DataType, target: &DataType) -> Result<Self::State> {
+        Ok(TimestampToTimestampState {
+            src: TimestampCastMeta::try_from_datatype(src)?,
+            target: TimestampCastMeta::try_from_datatype(target)?,
+        })
+    }
+
+    fn cast(
+        state: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        let convert = |v: i64| {
+            let datetime = timestamp_to_naive(state.src.unit, v)?;
+            let datetime = match (state.src.tz, state.target.tz) {
+                (None, Some(tz)) => tz.local_to_utc(datetime),
+                (Some(tz), None) => tz.utc_to_local(datetime),
+                _ => datetime,
+            };
+            naive_to_timestamp(state.target.unit, datetime)
+        };
+
+        UnaryExecutor::execute::<PhysicalI64, PhysicalI64, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |&v, buf| match convert(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state.set_error(|| DbError::new("Timestamp out of range"));
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::TimestampTypeMeta;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::functions::cast::behavior::CastFailBehavior;
+    use crate::testutil::arrays::assert_arrays_eq;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn timestamp_type(tz: Option<&str>) -> DataType {
+        DataType::timestamp(TimestampTypeMeta {
+            unit: TimeUnit::Microsecond,
+            tz: tz.map(Into::into),
+        })
+    }
+
+    fn cast_utf8(strings: [&str; 3], target: DataType) -> Array {
+        let arr = Array::try_from_iter(strings).unwrap();
+        let mut out = Array::new(&DefaultBufferManager, target.clone(), 3).unwrap();
+
+        let state = Utf8ToTimestamp.bind(&DataType::utf8(), &target).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        Utf8ToTimestamp::cast(&state, error_state, &arr, 0..3, &mut out).unwrap();
+
+        out
+    }
+
+    #[test]
+    fn cast_utf8_to_naive() {
+        let out = cast_utf8(
+            [
+                "1970-01-01 00:00:01",
+                "1970-01-01 00:00:01+05",
+                "1970-01-02",
+            ],
+            timestamp_type(None),
+        );
+
+        let mut expected =
+            Array::try_from_iter([1_000_000_i64, 1_000_000, 86_400_000_000]).unwrap();
+        expected.datatype = timestamp_type(None);
+        assert_arrays_eq(&expected, &out);
+    }
+
+    #[test]
+    fn cast_utf8_to_zoned() {
+        let out = cast_utf8(
+            [
+                "1970-01-01 00:00:00",
+                "1970-01-01 00:00:00+01",
+                "1970-01-01 00:00:00 UTC",
+            ],
+            timestamp_type(Some("America/New_York")),
+        );
+
+        let hour = 3_600_000_000_i64;
+        let mut expected = Array::try_from_iter([5 * hour, -hour, 0]).unwrap();
+        expected.datatype = timestamp_type(Some("America/New_York"));
+        assert_arrays_eq(&expected, &out);
+    }
+
+    #[test]
+    fn cast_utf8_invalid() {
+        let arr = Array::try_from_iter(["1970-01-01", "not a timestamp"]).unwrap();
+        let target = timestamp_type(None);
+        let mut out = Array::new(&DefaultBufferManager, target.clone(), 2).unwrap();
+
+        let state = Utf8ToTimestamp.bind(&DataType::utf8(), &target).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        Utf8ToTimestamp::cast(&state, error_state, &arr, 0..2, &mut out).unwrap_err();
+    }
+
+    #[test]
+    fn cast_between_zones() {
+        let hour = 3_600_000_000_i64;
+        let naive = timestamp_type(None);
+        let zoned = timestamp_type(Some("+02"));
+
+        let mut arr = Array::try_from_iter([0_i64, hour]).unwrap();
+        arr.datatype = naive.clone();
+
+        // Naive -> zoned treats the input as local time.
+        let mut out = Array::new(&DefaultBufferManager, zoned.clone(), 2).unwrap();
+        let state = TimestampToTimestamp.bind(&naive, &zoned).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        TimestampToTimestamp::cast(&state, error_state, &arr, 0..2, &mut out).unwrap();
+
+        let mut expected = Array::try_from_iter([-2 * hour, -hour]).unwrap();
+        expected.datatype = zoned.clone();
+        assert_arrays_eq(&expected, &out);
+
+        // Zoned -> naive produces local time.
+        let mut back = Array::new(&DefaultBufferManager, naive.clone(), 2).unwrap();
+        let state = TimestampToTimestamp.bind(&zoned, &naive).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        TimestampToTimestamp::cast(&state, error_state, &out, 0..2, &mut back).unwrap();
+
+        assert_arrays_eq(&arr, &back);
+    }
+}
@@ -0,0 +1,183 @@
+use glaredb_error::{DbError, Result};
+
+use super::null::NullToAnything;
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalBinary, PhysicalU128, PhysicalUtf8};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::functions::cast::behavior::CastErrorState;
+use crate::functions::cast::parse::{Parser, UuidParser};
+use crate::functions::cast::{
+    CastFlatten,
+    CastFunction,
+    CastFunctionSet,
+    CastRule,
+    RawCastFunction,
+    TO_UUID_CAST_RULE,
+};
+use crate::util::iter::IntoExactSizeIterator;
+
+/// Function set for casting to Uuid.
+///
+/// UUIDs are internally represented as a big-endian u128.
+pub const FUNCTION_SET_TO_UUID: CastFunctionSet = CastFunctionSet {
+    name: "to_uuid",
+    target: DataTypeId::Uuid,
+    #[rustfmt::skip]
+    functions: &[
+        // Null -> Uuid
+        RawCastFunction::new(DataTypeId::Null, &NullToAnything, TO_UUID_CAST_RULE, CastFlatten::Safe),
+        // Utf8 -> Uuid
+        RawCastFunction::new(DataTypeId::Utf8, &Utf8ToUuid, TO_UUID_CAST_RULE, CastFlatten::Unsafe),
+        // Binary -> Uuid
+        RawCastFunction::new(DataTypeId::Binary, &BinaryToUuid, CastRule::Explicit, CastFlatten::Unsafe),
+        // Uuid -> Uuid
+        RawCastFunction::new(DataTypeId::Uuid, &UuidToUuid, CastRule::Explicit, CastFlatten::Safe),
+    ],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Utf8ToUuid;
+
+impl CastFunction for Utf8ToUuid {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalUtf8, PhysicalU128, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |v, buf| match UuidParser.parse(v) {
+                Some(v) => buf.put(&v),
+                None => {
+                    error_state
+                        .set_error(|| DbError::new(format!("Failed to parse '{v}' into uuid")));
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+/// Casts 16 bytes to a UUID.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryToUuid;
+
+impl CastFunction for BinaryToUuid {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _: &Self::State,
+        mut error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalBinary, PhysicalU128, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |v, buf| match <[u8; 16]>::try_from(v) {
+                Ok(bytes) => buf.put(&u128::from_be_bytes(bytes)),
+                Err(_) => {
+                    let len = v.len();
+                    error_state.set_error(|| {
+                        DbError::new(format!(
+                            "Cannot cast binary of length {len} to uuid, expected 16 bytes"
+                        ))
+                    });
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        error_state.into_result()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UuidToUuid;
+
+impl CastFunction for UuidToUuid {
+    type State = ();
+
+    fn bind(&self, _src: &DataType, _target: &DataType) -> Result<Self::State> {
+        Ok(())
+    }
+
+    fn cast(
+        _: &Self::State,
+        _error_state: CastErrorState,
+        src: &Array,
+        sel: impl IntoExactSizeIterator<Item = usize>,
+        out: &mut Array,
+    ) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalU128, PhysicalU128, _>(
+            src,
+            sel,
+            OutBuffer::from_array(out)?,
+            |&v, buf| buf.put(&v),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::scalar::BorrowedScalarValue;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::functions::cast::behavior::CastFailBehavior;
+    use crate::testutil::arrays::assert_arrays_eq;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn binary_array(bytes: &[u8]) -> Array {
+        let mut arr = Array::new(&DefaultBufferManager, DataType::binary(), 1).unwrap();
+        arr.set_value(0, &BorrowedScalarValue::Binary(bytes.into()))
+            .unwrap();
+        arr
+    }
+
+    #[test]
+    fn cast_binary_to_uuid() {
+        let bytes: [u8; 16] = [
+            0xa0, 0xee, 0xbc, 0x99, 0x9c, 0x0b, 0x4e, 0xf8, 0xbb, 0x6d, 0x6b, 0xb9, 0xbd, 0x38,
+            0x0a, 0x11,
+        ];
+        let arr = binary_array(&bytes);
+
+        let mut out = Array::new(&DefaultBufferManager, DataType::uuid(), 1).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        BinaryToUuid::cast(&(), error_state, &arr, 0..1, &mut out).unwrap();
+
+        let mut expected =
+            Array::try_from_iter([0xa0eebc99_9c0b_4ef8_bb6d_6bb9bd380a11_u128]).unwrap();
+        expected.datatype = DataType::uuid();
+        assert_arrays_eq(&expected, &out);
+    }
+
+    #[test]
+    fn cast_binary_to_uuid_wrong_length() {
+        let arr = binary_array(&[1, 2, 3]);
+
+        let mut out = Array::new(&DefaultBufferManager, DataType::uuid(), 1).unwrap();
+        let error_state = CastFailBehavior::Error.new_state();
+        BinaryToUuid::cast(&(), error_state, &arr, 0..1, &mut out).unwrap_err();
+    }
+}
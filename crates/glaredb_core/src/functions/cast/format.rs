@@ -5,9 +5,11 @@ use std::marker::PhantomData;
 use chrono::{DateTime, Utc};
 use half::f16;
 
-use crate::arrays::compute::date::SECONDS_IN_DAY;
+use crate::arrays::compute::date::{SECONDS_IN_DAY, time_to_naive};
+use crate::arrays::datatype::TimeUnit;
 use crate::arrays::scalar::decimal::DecimalPrimitive;
 use crate::arrays::scalar::interval::Interval;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive, write_utc_offset};
 
 /// Logic for formatting and writing a type to a buffer.
 pub trait Formatter {
@@ -163,6 +165,26 @@ impl<T: DateTimeFromTimestamp> Formatter for TimestampFormatter<T> {
     }
 }
 
+/// Formats timestamps that have a time zone.
+///
+/// Timestamps are written as the local time in the zone followed by the
+/// offset from UTC, e.g. '2024-01-15 10:00:00-05'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampTzFormatter {
+    pub unit: TimeUnit,
+    pub tz: TimeZone,
+}
+
+impl Formatter for TimestampTzFormatter {
+    type Type = i64;
+    fn write<W: fmt::Write>(&self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        let utc = timestamp_to_naive(self.unit, *val).ok_or(fmt::Error)?;
+        let offset = self.tz.offset_from_utc(&utc);
+        write!(buf, "{}", (utc + offset).format("%Y-%m-%d %H:%M:%S%.f"))?;
+        write_utc_offset(offset, buf)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Date32Formatter;
 
@@ -186,6 +208,30 @@ impl Formatter for Date64Formatter {
     }
 }
 
+/// Formats times as '13:45:30', including fractional seconds if present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeFormatter;
+
+impl Formatter for TimeFormatter {
+    type Type = i64;
+    fn write<W: fmt::Write>(&self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        let time = time_to_naive(*val).ok_or(fmt::Error)?;
+        write!(buf, "{}", time.format("%H:%M:%S%.f"))
+    }
+}
+
+/// Formats UUIDs in the lowercase hyphenated form, e.g.
+/// 'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidFormatter;
+
+impl Formatter for UuidFormatter {
+    type Type = u128;
+    fn write<W: fmt::Write>(&self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        write!(buf, "{}", uuid::Uuid::from_u128(*val).hyphenated())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IntervalFormatter;
 
@@ -197,44 +243,46 @@ impl Formatter for IntervalFormatter {
 
         let days = val.days;
 
-        let mut nanos = val.nanos;
-        let hours = nanos / Interval::NANOSECONDS_IN_HOUR;
-        nanos %= Interval::NANOSECONDS_IN_HOUR;
-        let minutes = nanos / Interval::NANOSECONDS_IN_MINUTE;
-        nanos %= Interval::NANOSECONDS_IN_MINUTE;
-        let seconds = nanos / Interval::NANOSECONDS_IN_SECOND;
-        nanos %= Interval::NANOSECONDS_IN_SECOND;
-        let millis = nanos / Interval::NANOSECONDS_IN_MILLISECOND;
+        // Time fields are written as a single signed value, e.g. "-01:30:00".
+        let negative_time = val.nanos < 0;
+        let mut nanos = val.nanos.unsigned_abs();
+        let hours = nanos / Interval::NANOSECONDS_IN_HOUR as u64;
+        nanos %= Interval::NANOSECONDS_IN_HOUR as u64;
+        let minutes = nanos / Interval::NANOSECONDS_IN_MINUTE as u64;
+        nanos %= Interval::NANOSECONDS_IN_MINUTE as u64;
+        let seconds = nanos / Interval::NANOSECONDS_IN_SECOND as u64;
+        nanos %= Interval::NANOSECONDS_IN_SECOND as u64;
+        let millis = nanos / Interval::NANOSECONDS_IN_MILLISECOND as u64;
 
         let mut pad = false;
 
-        if years > 0 {
+        if years != 0 {
             write!(buf, "{years} year")?;
-            if years > 1 {
+            if years.abs() > 1 {
                 write!(buf, "s")?;
             }
             pad = true;
         }
 
-        if months > 0 {
+        if months != 0 {
             if pad {
                 write!(buf, " ")?;
             }
 
             write!(buf, "{months} mon")?;
-            if months > 1 {
+            if months.abs() > 1 {
                 write!(buf, "s")?;
             }
             pad = true;
         }
 
-        if days > 0 {
+        if days != 0 {
             if pad {
                 write!(buf, " ")?;
             }
 
             write!(buf, "{days} day")?;
-            if days > 1 {
+            if days.abs() > 1 {
                 write!(buf, "s")?;
             }
             pad = true;
@@ -245,6 +293,9 @@ impl Formatter for IntervalFormatter {
             if pad {
                 write!(buf, " ")?;
             }
+            if negative_time {
+                write!(buf, "-")?;
+            }
 
             write!(buf, "{:02}:{:02}:{:02}", hours, minutes, seconds)?;
 
@@ -408,5 +459,14 @@ mod tests {
         let mut buf = String::new();
         IntervalFormatter.write(&interval, &mut buf).unwrap();
         assert_eq!("1 year 2 mons 11 days 03:00:24.982", buf);
+
+        let interval = Interval {
+            months: -1,
+            days: -14,
+            nanos: -(12 * Interval::NANOSECONDS_IN_HOUR + 30 * Interval::NANOSECONDS_IN_MINUTE),
+        };
+        let mut buf = String::new();
+        IntervalFormatter.write(&interval, &mut buf).unwrap();
+        assert_eq!("-1 mon -14 days -12:30:00", buf);
     }
 }
@@ -45,6 +45,9 @@ pub struct ImplicitCastScores {
     pub bool: u32,
     pub interval: u32,
     pub date32: u32,
+    pub timestamp: u32,
+    pub time: u32,
+    pub uuid: u32,
     pub decimal64: u32,
     pub decimal128: u32,
 }
@@ -81,9 +84,14 @@ pub const DEFAULT_IMPLICIT_CAST_SCORES: ImplicitCastScores = ImplicitCastScores
     decimal64: 141,
     decimal128: 140,
 
-    // Then date (and timestamp... when there's implicit casts)
+    // Then date and timestamp.
     interval: 132,
     date32: 131,
+    timestamp: 130,
+    time: 129,
+
+    // UUIDs are only implicitly cast to from strings.
+    uuid: 120,
 
     // Try to string last
     utf8: 80,
@@ -106,6 +114,10 @@ pub const TO_DECIMAL128_CAST_RULE: CastRule =
 pub const TO_BOOL_CAST_RULE: CastRule = CastRule::Implicit(DEFAULT_IMPLICIT_CAST_SCORES.bool);
 
 pub const TO_DATE32_CAST_RULE: CastRule = CastRule::Implicit(DEFAULT_IMPLICIT_CAST_SCORES.date32);
+pub const TO_TIMESTAMP_CAST_RULE: CastRule =
+    CastRule::Implicit(DEFAULT_IMPLICIT_CAST_SCORES.timestamp);
+pub const TO_TIME_CAST_RULE: CastRule = CastRule::Implicit(DEFAULT_IMPLICIT_CAST_SCORES.time);
+pub const TO_UUID_CAST_RULE: CastRule = CastRule::Implicit(DEFAULT_IMPLICIT_CAST_SCORES.uuid);
 pub const TO_INTERVAL_CAST_RULE: CastRule =
     CastRule::Implicit(DEFAULT_IMPLICIT_CAST_SCORES.interval);
 
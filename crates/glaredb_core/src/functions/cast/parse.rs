@@ -3,11 +3,11 @@ use std::fmt::Write;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveTime};
 use half::f16;
 use num_traits::PrimInt;
 
-use crate::arrays::compute::date::EPOCH_DAYS_FROM_CE;
+use crate::arrays::compute::date::{EPOCH_DAYS_FROM_CE, naive_to_time};
 use crate::arrays::scalar::interval::Interval;
 
 /// Logic for parsing a string into some type.
@@ -82,6 +82,43 @@ impl Parser for Date32Parser {
     }
 }
 
+/// Parse a string time into a number of microseconds since midnight.
+///
+/// Example formats:
+///
+/// '13:45:30'
+/// '13:45:30.123456'
+/// '13:45'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeParser;
+
+impl Parser for TimeParser {
+    type Type = i64;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        let s = s.trim();
+        let time = NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+            .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+            .ok()?;
+        Some(naive_to_time(time))
+    }
+}
+
+/// Parse a string UUID into a big-endian u128.
+///
+/// Accepts the hyphenated form ('a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'),
+/// the simple form without hyphens, and either form wrapped in braces or
+/// prefixed with 'urn:uuid:'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidParser;
+
+impl Parser for UuidParser {
+    type Type = u128;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        let uuid = uuid::Uuid::try_parse(s.trim()).ok()?;
+        Some(uuid.as_u128())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DecimalParser<T: PrimInt> {
     precision: u8,
@@ -384,6 +421,41 @@ mod tests {
         assert_eq!(None, Decimal64Parser::new(3, 1).parse("123.4")); // "overflow"
     }
 
+    #[test]
+    fn parse_times() {
+        assert_eq!(Some(0), TimeParser.parse("00:00:00"));
+        assert_eq!(Some(49_530_000_000), TimeParser.parse("13:45:30"));
+        assert_eq!(Some(49_530_250_000), TimeParser.parse("13:45:30.25"));
+        assert_eq!(Some(49_500_000_000), TimeParser.parse("13:45"));
+
+        assert_eq!(None, TimeParser.parse("24:00:00"));
+        assert_eq!(None, TimeParser.parse("13"));
+        assert_eq!(None, TimeParser.parse("noon"));
+    }
+
+    #[test]
+    fn parse_uuids() {
+        let expected = Some(0xa0eebc99_9c0b_4ef8_bb6d_6bb9bd380a11);
+        assert_eq!(
+            expected,
+            UuidParser.parse("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")
+        );
+        assert_eq!(
+            expected,
+            UuidParser.parse("A0EEBC999C0B4EF8BB6D6BB9BD380A11")
+        );
+        assert_eq!(
+            expected,
+            UuidParser.parse("{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}")
+        );
+
+        assert_eq!(None, UuidParser.parse("a0eebc99-9c0b-4ef8-bb6d"));
+        assert_eq!(
+            None,
+            UuidParser.parse("g0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")
+        );
+    }
+
     #[test]
     fn parse_intervals() {
         let expected = Interval {
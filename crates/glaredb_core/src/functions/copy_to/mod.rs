@@ -0,0 +1,226 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::batch::Batch;
+use crate::arrays::field::ColumnSchema;
+use crate::arrays::scalar::ScalarValue;
+
+/// A set of COPY TO functions for a single format.
+#[derive(Debug, Clone, Copy)]
+pub struct CopyToFunctionSet {
+    /// Name of the format this function writes (e.g. 'csv').
+    ///
+    /// This is the name that can be used with the FORMAT option.
+    pub name: &'static str,
+    /// The function implementation.
+    pub function: RawCopyToFunction,
+}
+
+/// Options provided to a COPY TO statement.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CopyToOptions {
+    pub options: HashMap<String, ScalarValue>,
+}
+
+impl CopyToOptions {
+    /// Get an option by its normalized name.
+    pub fn get(&self, key: &str) -> Option<&ScalarValue> {
+        self.options.get(key)
+    }
+
+    /// Get an option as a bool, erroring if the value isn't a bool.
+    pub fn get_bool(&self, key: &str) -> Result<Option<bool>> {
+        self.get(key)
+            .map(|v| {
+                v.try_as_bool()
+                    .map_err(|_| DbError::new(format!("COPY TO option '{key}' must be a boolean")))
+            })
+            .transpose()
+    }
+
+    /// Get an option as a usize, erroring if the value isn't a non-negative
+    /// integer.
+    pub fn get_usize(&self, key: &str) -> Result<Option<usize>> {
+        self.get(key)
+            .map(|v| {
+                v.try_as_usize().map_err(|_| {
+                    DbError::new(format!(
+                        "COPY TO option '{key}' must be a non-negative integer"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// Get an option as a string, erroring if the value isn't a string.
+    pub fn get_str(&self, key: &str) -> Result<Option<&str>> {
+        self.get(key)
+            .map(|v| {
+                v.try_as_str()
+                    .map_err(|_| DbError::new(format!("COPY TO option '{key}' must be a string")))
+            })
+            .transpose()
+    }
+}
+
+/// A planned COPY TO function with its bind state.
+#[derive(Debug, Clone)]
+pub struct PlannedCopyToFunction {
+    pub(crate) name: &'static str,
+    pub(crate) raw: &'static RawCopyToFunction,
+    pub(crate) state: Arc<dyn Any + Sync + Send>,
+    pub(crate) schema: ColumnSchema,
+}
+
+impl PlannedCopyToFunction {
+    /// Create a new sink for writing a single file.
+    pub fn call_create_sink(&self) -> Result<AnyCopyToSink> {
+        (self.raw.vtable.create_sink_fn)(self.state.as_ref(), &self.schema)
+    }
+}
+
+/// Assumes that functions with the same name and input schema are using the
+/// same options.
+impl PartialEq for PlannedCopyToFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.schema == other.schema
+    }
+}
+
+impl Eq for PlannedCopyToFunction {}
+
+/// Type erased sink for encoding batches for a single file.
+#[derive(Debug)]
+pub struct AnyCopyToSink {
+    sink: Box<dyn Any + Sync + Send>,
+    vtable: &'static RawCopyToFunctionVTable,
+}
+
+impl AnyCopyToSink {
+    /// Encode the batch, appending the bytes to write to `buf`.
+    pub fn call_encode(&mut self, batch: &Batch, buf: &mut Vec<u8>) -> Result<()> {
+        (self.vtable.encode_fn)(self.sink.as_mut(), batch, buf)
+    }
+
+    /// Finish encoding, appending any remaining bytes to write to `buf`.
+    pub fn call_finish(&mut self, buf: &mut Vec<u8>) -> Result<()> {
+        (self.vtable.finish_fn)(self.sink.as_mut(), buf)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RawCopyToFunction {
+    function: *const (),
+    vtable: &'static RawCopyToFunctionVTable,
+}
+
+unsafe impl Send for RawCopyToFunction {}
+unsafe impl Sync for RawCopyToFunction {}
+
+impl RawCopyToFunction {
+    pub const fn new<F>(function: &'static F) -> Self
+    where
+        F: CopyToFunction,
+    {
+        let function = (function as *const F).cast();
+        RawCopyToFunction {
+            function,
+            vtable: F::VTABLE,
+        }
+    }
+}
+
+/// Bind a COPY TO function using the schema of the input, and the options
+/// provided in the statement.
+pub fn bind_copy_to_function(
+    set: &'static CopyToFunctionSet,
+    schema: ColumnSchema,
+    options: &CopyToOptions,
+) -> Result<PlannedCopyToFunction> {
+    let state = unsafe { (set.function.vtable.bind_fn)(set.function.function, &schema, options)? };
+
+    Ok(PlannedCopyToFunction {
+        name: set.name,
+        raw: &set.function,
+        state,
+        schema,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::type_complexity)]
+pub struct RawCopyToFunctionVTable {
+    bind_fn: unsafe fn(
+        function: *const (),
+        schema: &ColumnSchema,
+        options: &CopyToOptions,
+    ) -> Result<Arc<dyn Any + Sync + Send>>,
+    create_sink_fn: fn(state: &dyn Any, schema: &ColumnSchema) -> Result<AnyCopyToSink>,
+    encode_fn: fn(sink: &mut dyn Any, batch: &Batch, buf: &mut Vec<u8>) -> Result<()>,
+    finish_fn: fn(sink: &mut dyn Any, buf: &mut Vec<u8>) -> Result<()>,
+}
+
+/// A function for encoding batches into a file format as part of COPY TO.
+///
+/// The function only handles encoding, writing the encoded bytes to the
+/// destination file is handled by the COPY TO operator. All batches for a
+/// single file will go through the same sink.
+pub trait CopyToFunction: Debug + Copy + Sync + Send + Sized + 'static {
+    /// State produced during binding.
+    type BindState: Sync + Send;
+    /// State for encoding a single file.
+    type Sink: Sync + Send;
+
+    /// Bind the function using the schema of the batches that will be written,
+    /// and the options provided in the COPY TO statement.
+    ///
+    /// Unrecognized options should be ignored as they may be used by the
+    /// filesystem (e.g. credentials).
+    fn bind(&self, schema: &ColumnSchema, options: &CopyToOptions) -> Result<Self::BindState>;
+
+    /// Create a sink for encoding batches for a single file.
+    fn create_sink(state: &Self::BindState, schema: &ColumnSchema) -> Result<Self::Sink>;
+
+    /// Encode a batch, appending the bytes to write to `buf`.
+    ///
+    /// Encoders are free to buffer data internally and not write anything to
+    /// `buf`.
+    fn encode(sink: &mut Self::Sink, batch: &Batch, buf: &mut Vec<u8>) -> Result<()>;
+
+    /// Finish the file, appending any remaining bytes to `buf`.
+    ///
+    /// Called once after all batches have been encoded.
+    fn finish(sink: &mut Self::Sink, buf: &mut Vec<u8>) -> Result<()>;
+}
+
+trait CopyToFunctionVTable: CopyToFunction {
+    const VTABLE: &'static RawCopyToFunctionVTable = &RawCopyToFunctionVTable {
+        bind_fn: |function, schema, options| {
+            let function = unsafe { function.cast::<Self>().as_ref().unwrap() };
+            let state = function.bind(schema, options)?;
+            Ok(Arc::new(state))
+        },
+        create_sink_fn: |state, schema| {
+            let state = state.downcast_ref::<Self::BindState>().unwrap();
+            let sink = Self::create_sink(state, schema)?;
+            Ok(AnyCopyToSink {
+                sink: Box::new(sink),
+                vtable: Self::VTABLE,
+            })
+        },
+        encode_fn: |sink, batch, buf| {
+            let sink = sink.downcast_mut::<Self::Sink>().unwrap();
+            Self::encode(sink, batch, buf)
+        },
+        finish_fn: |sink, buf| {
+            let sink = sink.downcast_mut::<Self::Sink>().unwrap();
+            Self::finish(sink, buf)
+        },
+    };
+}
+
+impl<F> CopyToFunctionVTable for F where F: CopyToFunction {}
@@ -4,12 +4,16 @@ pub enum Category {
     /// Functions that are used for implementing SQL operators.
     Operator(OperatorCategory),
     Aggregate(AggregateCategory),
+    Window,
     Numeric,
     DateTime,
     List,
+    Map,
+    Json,
     String,
     Regexp,
     Binary,
+    Uuid,
     Table,
     System,
     Debug,
@@ -46,12 +50,16 @@ impl Category {
             Self::Operator(OperatorCategory::Struct) => "struct_operator",
             Self::Aggregate(AggregateCategory::General) => "general_purpose_aggregate",
             Self::Aggregate(AggregateCategory::Statistics) => "statistics_aggregate",
+            Self::Window => "window",
             Self::Numeric => "numeric",
             Self::DateTime => "datetime",
             Self::List => "list",
+            Self::Map => "map",
+            Self::Json => "json",
             Self::String => "string",
             Self::Regexp => "regexp",
             Self::Binary => "binary",
+            Self::Uuid => "uuid",
             Self::Table => "table",
             Self::System => "system",
             Self::Debug => "debug",
@@ -5,6 +5,7 @@ use super::candidate::InputDataType;
 use super::documentation::Documentation;
 use super::scalar::RawScalarFunction;
 use super::table::{RawTableFunction, TableFunctionType};
+use super::window::RawWindowFunction;
 use super::{CandidateSignature, Signature};
 use crate::arrays::datatype::DataTypeId;
 use crate::util::fmt::displayable::IntoDisplayableSlice;
@@ -12,6 +13,7 @@ use crate::util::fmt::displayable::IntoDisplayableSlice;
 pub type ScalarFunctionSet = FunctionSet<RawScalarFunction>;
 pub type AggregateFunctionSet = FunctionSet<RawAggregateFunction>;
 pub type TableFunctionSet = FunctionSet<RawTableFunction>;
+pub type WindowFunctionSet = FunctionSet<RawWindowFunction>;
 
 #[derive(Debug, Clone, Copy)]
 pub struct FunctionSet<T: 'static> {
@@ -104,6 +106,12 @@ impl FunctionInfo for RawAggregateFunction {
     }
 }
 
+impl FunctionInfo for RawWindowFunction {
+    fn signature(&self) -> &Signature {
+        RawWindowFunction::signature(self)
+    }
+}
+
 impl FunctionInfo for RawTableFunction {
     fn signature(&self) -> &Signature {
         RawTableFunction::signature(self)
@@ -53,7 +53,7 @@ mod tests {
     #[test]
     fn implicit_cast_from_utf8() {
         assert!(implicit_cast_score(DataTypeId::Utf8, DataTypeId::Int32).is_some());
-        // assert!(implicit_cast_score(DataTypeId::Utf8, DataTypeId::Timestamp, conf).is_some()); TODO
+        assert!(implicit_cast_score(DataTypeId::Utf8, DataTypeId::Timestamp).is_some());
         // assert!(implicit_cast_score(DataTypeId::Utf8, DataTypeId::Interval, conf).is_some()); TODO
     }
 
@@ -10,11 +10,13 @@ pub mod aggregate;
 pub mod bind_state;
 pub mod candidate;
 pub mod cast;
+pub mod copy_to;
 pub mod documentation;
 pub mod function_set;
 pub mod implicit;
 pub mod scalar;
 pub mod table;
+pub mod window;
 
 use candidate::CandidateSignature;
 
@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 
 use glaredb_error::Result;
 
+use super::datetime_arith::{TimeIntervalArith, TimestampIntervalArith};
 use super::decimal_arith::common_add_sub_decimal_type_info;
 use super::decimal_sigs::D_SIGS;
 use crate::arrays::array::Array;
@@ -152,6 +153,32 @@ pub const FUNCTION_SET_ADD: ScalarFunctionSet = ScalarFunctionSet {
             &Signature::new(&[DataTypeId::Int32, DataTypeId::Date32], DataTypeId::Date32),
             &Add::<PhysicalI32>::new(DataType::DATE32),
         ),
+        // Timestamp + interval => timestamp
+        RawScalarFunction::new(
+            &Signature::new(
+                &[DataTypeId::Timestamp, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            &TimestampIntervalArith::new(false, false),
+        ),
+        // Interval + timestamp => timestamp
+        RawScalarFunction::new(
+            &Signature::new(
+                &[DataTypeId::Interval, DataTypeId::Timestamp],
+                DataTypeId::Timestamp,
+            ),
+            &TimestampIntervalArith::new(false, true),
+        ),
+        // Time + interval => time
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Time, DataTypeId::Interval], DataTypeId::Time),
+            &TimeIntervalArith::new(false, false),
+        ),
+        // Interval + time => time
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Interval, DataTypeId::Time], DataTypeId::Time),
+            &TimeIntervalArith::new(false, true),
+        ),
     ],
 };
 
@@ -0,0 +1,258 @@
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalInterval};
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::date::{MICROSECONDS_IN_DAY, timestamp_add_interval};
+use crate::arrays::datatype::{DataType, TimeUnit};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::interval::Interval;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive};
+use crate::expr::Expression;
+use crate::functions::scalar::{BindState, ScalarFunction};
+
+/// Create an interval from a duration in microseconds, using whole days where
+/// possible.
+fn micros_to_interval(micros: i64) -> Interval {
+    let days = micros / MICROSECONDS_IN_DAY;
+    let rem = micros % MICROSECONDS_IN_DAY;
+    Interval::new(0, days as i32, rem * Interval::NANOSECONDS_IN_MICROSECOND)
+}
+
+#[derive(Debug)]
+pub struct TimestampIntervalState {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+    negate: bool,
+}
+
+/// Adds or subtracts an interval from a timestamp.
+///
+/// Date arguments are implicitly cast to timestamps, matching Postgres where
+/// `date + interval` produces a timestamp.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampIntervalArith {
+    /// Subtract the interval instead of adding it.
+    negate: bool,
+    /// If the interval is the first argument.
+    interval_first: bool,
+}
+
+impl TimestampIntervalArith {
+    pub const fn new(negate: bool, interval_first: bool) -> Self {
+        TimestampIntervalArith {
+            negate,
+            interval_first,
+        }
+    }
+}
+
+impl ScalarFunction for TimestampIntervalArith {
+    type State = TimestampIntervalState;
+
+    fn bind(&self, mut inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        // Normalize the argument order so execution only has to handle
+        // (timestamp, interval).
+        if self.interval_first {
+            inputs.swap(0, 1);
+        }
+
+        let return_type = inputs[0].datatype()?;
+        let m = return_type.try_get_timestamp_type_meta()?;
+
+        Ok(BindState {
+            state: TimestampIntervalState {
+                unit: m.unit,
+                tz: m.tz.as_deref().map(str::parse).transpose()?,
+                negate: self.negate,
+            },
+            return_type,
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let mut error = None;
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalInterval, PhysicalI64, _>(
+            &input.arrays()[0],
+            sel,
+            &input.arrays()[1],
+            sel,
+            OutBuffer::from_array(output)?,
+            |&v, &interval, buf| {
+                let interval = if state.negate {
+                    Interval::new(-interval.months, -interval.days, -interval.nanos)
+                } else {
+                    interval
+                };
+                match timestamp_add_interval(state.unit, state.tz, v, interval) {
+                    Some(v) => buf.put(&v),
+                    None => {
+                        error.get_or_insert_with(|| DbError::new("Timestamp out of range"));
+                        buf.put_null();
+                    }
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TimestampSubState {
+    left: TimeUnit,
+    right: TimeUnit,
+}
+
+/// Subtracts two timestamps, producing an interval of days and time.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampSub;
+
+impl ScalarFunction for TimestampSub {
+    type State = TimestampSubState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let left = inputs[0].datatype()?.try_get_timestamp_type_meta()?.unit;
+        let right = inputs[1].datatype()?.try_get_timestamp_type_meta()?.unit;
+
+        Ok(BindState {
+            state: TimestampSubState { left, right },
+            return_type: DataType::interval(),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalI64, PhysicalInterval, _>(
+            &input.arrays()[0],
+            sel,
+            &input.arrays()[1],
+            sel,
+            OutBuffer::from_array(output)?,
+            |&a, &b, buf| {
+                let diff = (|| {
+                    let a = timestamp_to_naive(state.left, a)?;
+                    let b = timestamp_to_naive(state.right, b)?;
+                    (a - b).num_microseconds()
+                })();
+                match diff {
+                    Some(micros) => buf.put(&micros_to_interval(micros)),
+                    None => buf.put_null(),
+                }
+            },
+        )
+    }
+}
+
+/// Adds or subtracts an interval from a time, wrapping around midnight.
+///
+/// Only the time component of the interval is used.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeIntervalArith {
+    negate: bool,
+    interval_first: bool,
+}
+
+impl TimeIntervalArith {
+    pub const fn new(negate: bool, interval_first: bool) -> Self {
+        TimeIntervalArith {
+            negate,
+            interval_first,
+        }
+    }
+}
+
+impl ScalarFunction for TimeIntervalArith {
+    type State = bool;
+
+    fn bind(&self, mut inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        if self.interval_first {
+            inputs.swap(0, 1);
+        }
+
+        Ok(BindState {
+            state: self.negate,
+            return_type: DataType::time(),
+            inputs,
+        })
+    }
+
+    fn execute(negate: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalInterval, PhysicalI64, _>(
+            &input.arrays()[0],
+            sel,
+            &input.arrays()[1],
+            sel,
+            OutBuffer::from_array(output)?,
+            |&time, &interval, buf| {
+                let micros = interval.nanos / Interval::NANOSECONDS_IN_MICROSECOND;
+                let micros = if *negate { -micros } else { micros };
+                buf.put(&(time + micros).rem_euclid(MICROSECONDS_IN_DAY))
+            },
+        )
+    }
+}
+
+/// Subtracts two times, producing an interval.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeSub;
+
+impl ScalarFunction for TimeSub {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::interval(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalI64, PhysicalInterval, _>(
+            &input.arrays()[0],
+            sel,
+            &input.arrays()[1],
+            sel,
+            OutBuffer::from_array(output)?,
+            |&a, &b, buf| {
+                buf.put(&Interval::new(
+                    0,
+                    0,
+                    (a - b) * Interval::NANOSECONDS_IN_MICROSECOND,
+                ))
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn micros_to_interval_splits_days() {
+        let hour = 3_600_000_000;
+        assert_eq!(
+            Interval::new(0, 1, 2 * Interval::NANOSECONDS_IN_HOUR),
+            micros_to_interval(26 * hour)
+        );
+        assert_eq!(
+            Interval::new(0, -1, -2 * Interval::NANOSECONDS_IN_HOUR),
+            micros_to_interval(-26 * hour)
+        );
+    }
+}
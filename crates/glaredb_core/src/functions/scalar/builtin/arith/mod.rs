@@ -13,5 +13,6 @@ pub use mul::*;
 mod rem;
 pub use rem::*;
 
+mod datetime_arith;
 mod decimal_arith;
 mod decimal_sigs;
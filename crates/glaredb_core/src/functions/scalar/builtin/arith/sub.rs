@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 
 use glaredb_error::Result;
 
+use super::datetime_arith::{TimeIntervalArith, TimeSub, TimestampIntervalArith, TimestampSub};
 use super::decimal_sigs::D_SIGS;
 use crate::arrays::array::Array;
 use crate::arrays::array::physical_type::{
@@ -147,6 +148,37 @@ pub const FUNCTION_SET_SUB: ScalarFunctionSet = ScalarFunctionSet {
             &Signature::new(&[DataTypeId::Date32, DataTypeId::Int32], DataTypeId::Date32),
             &Sub::<PhysicalI32>::new(DataType::DATE32),
         ),
+        // date - date => days
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Date32, DataTypeId::Date32], DataTypeId::Int32),
+            &Sub::<PhysicalI32>::new(DataType::INT32),
+        ),
+        // timestamp - interval => timestamp
+        RawScalarFunction::new(
+            &Signature::new(
+                &[DataTypeId::Timestamp, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            &TimestampIntervalArith::new(true, false),
+        ),
+        // timestamp - timestamp => interval
+        RawScalarFunction::new(
+            &Signature::new(
+                &[DataTypeId::Timestamp, DataTypeId::Timestamp],
+                DataTypeId::Interval,
+            ),
+            &TimestampSub,
+        ),
+        // time - interval => time
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Time, DataTypeId::Interval], DataTypeId::Time),
+            &TimeIntervalArith::new(true, false),
+        ),
+        // time - time => interval
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Time, DataTypeId::Time], DataTypeId::Interval),
+            &TimeSub,
+        ),
     ],
 };
 
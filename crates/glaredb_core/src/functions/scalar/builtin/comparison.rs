@@ -360,8 +360,10 @@ struct Sigs {
     f64: Signature,
     date32: Signature,
     date64: Signature,
+    time: Signature,
     timestamp: Signature,
     interval: Signature,
+    uuid: Signature,
     decimal64: Signature,
     decimal128: Signature,
     binary: Signature,
@@ -389,8 +391,10 @@ const SIGS: Sigs = Sigs {
 
     date32: Signature::new(&[DataTypeId::Date32, DataTypeId::Date32], DataTypeId::Boolean),
     date64: Signature::new(&[DataTypeId::Date64, DataTypeId::Date64], DataTypeId::Boolean),
+    time: Signature::new(&[DataTypeId::Time, DataTypeId::Time], DataTypeId::Boolean),
     timestamp: Signature::new(&[DataTypeId::Timestamp, DataTypeId::Timestamp], DataTypeId::Boolean),
     interval: Signature::new(&[DataTypeId::Interval, DataTypeId::Interval], DataTypeId::Boolean),
+    uuid: Signature::new(&[DataTypeId::Uuid, DataTypeId::Uuid], DataTypeId::Boolean),
     decimal64: Signature::new(&[DataTypeId::Decimal64, DataTypeId::Decimal64], DataTypeId::Boolean),
     decimal128: Signature::new(&[DataTypeId::Decimal128, DataTypeId::Decimal128], DataTypeId::Boolean),
     binary: Signature::new(&[DataTypeId::Binary, DataTypeId::Binary], DataTypeId::Boolean),
@@ -399,7 +403,7 @@ const SIGS: Sigs = Sigs {
 
 /// Generate the "normal" comparison functions.
 #[rustfmt::skip]
-const fn generate_comparison_functions<O>() -> [RawScalarFunction; 22]
+const fn generate_comparison_functions<O>() -> [RawScalarFunction; 24]
 where
     O: ComparisonOperation,
 {
@@ -423,9 +427,12 @@ where
         // Date/times
         RawScalarFunction::new(&SIGS.date32, FlatComparison::<O, PhysicalI32>::new_static()),
         RawScalarFunction::new(&SIGS.date64, FlatComparison::<O, PhysicalI64>::new_static()),
+        RawScalarFunction::new(&SIGS.time, FlatComparison::<O, PhysicalI64>::new_static()),
         // TODO: Probably scale
         RawScalarFunction::new(&SIGS.timestamp, FlatComparison::<O, PhysicalI64>::new_static()),
         RawScalarFunction::new(&SIGS.interval, FlatComparison::<O, PhysicalInterval>::new_static()),
+        // Uuid
+        RawScalarFunction::new(&SIGS.uuid, FlatComparison::<O, PhysicalU128>::new_static()),
         // Decimals
         RawScalarFunction::new(&SIGS.decimal64, DecimalComparison::<O, Decimal64Type>::new_static()),
         RawScalarFunction::new(&SIGS.decimal128, DecimalComparison::<O, Decimal128Type>::new_static()),
@@ -437,7 +444,7 @@ where
 
 // Generate the distinct functions (IS DISTINCT FROM, IS NOT DISTINCT FROM).
 #[rustfmt::skip]
-const fn generate_distinct_functions<O>() -> [RawScalarFunction; 22]
+const fn generate_distinct_functions<O>() -> [RawScalarFunction; 24]
 where
     O: DistinctComparisonOperation,
 {
@@ -461,9 +468,12 @@ where
         // Date/times
         RawScalarFunction::new(&SIGS.date32, FlatDistinctComparison::<O, PhysicalI32>::new_static()),
         RawScalarFunction::new(&SIGS.date64, FlatDistinctComparison::<O, PhysicalI64>::new_static()),
+        RawScalarFunction::new(&SIGS.time, FlatDistinctComparison::<O, PhysicalI64>::new_static()),
         // TODO: Probably scale
         RawScalarFunction::new(&SIGS.timestamp, FlatDistinctComparison::<O, PhysicalI64>::new_static()),
         RawScalarFunction::new(&SIGS.interval, FlatDistinctComparison::<O, PhysicalInterval>::new_static()),
+        // Uuid
+        RawScalarFunction::new(&SIGS.uuid, FlatDistinctComparison::<O, PhysicalU128>::new_static()),
         // Decimals
         RawScalarFunction::new(&SIGS.decimal64, DecimalDistinctComparison::<O, Decimal64Type>::new_static()),
         RawScalarFunction::new(&SIGS.decimal128, DecimalDistinctComparison::<O, Decimal128Type>::new_static()),
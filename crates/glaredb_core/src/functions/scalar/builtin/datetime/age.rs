@@ -0,0 +1,235 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalInterval};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::arrays::scalar::interval::Interval;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_AGE: ScalarFunctionSet = ScalarFunctionSet {
+    name: "age",
+    aliases: &[],
+    doc: &[
+        &Documentation {
+            category: Category::DateTime,
+            description: "Subtract two timestamps, producing a symbolic interval using years and months instead of just days.",
+            arguments: &["end", "start"],
+            example: Some(Example {
+                example: "age(TIMESTAMP '2001-04-10', TIMESTAMP '1957-06-13')",
+                output: "43 years 9 mons 27 days",
+            }),
+        },
+        &Documentation {
+            category: Category::DateTime,
+            description: "Subtract a timestamp from midnight of the current date (UTC).",
+            arguments: &["start"],
+            example: None,
+        },
+    ],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(
+                &[DataTypeId::Timestamp, DataTypeId::Timestamp],
+                DataTypeId::Interval,
+            ),
+            &Age,
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Timestamp], DataTypeId::Interval),
+            &Age,
+        ),
+    ],
+};
+
+/// Unit and optional zone for a timestamp argument.
+#[derive(Debug, Clone, Copy)]
+pub struct AgeInput {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+}
+
+impl AgeInput {
+    fn try_from_datatype(datatype: &DataType) -> Result<Self> {
+        let m = datatype.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+        Ok(AgeInput { unit: m.unit, tz })
+    }
+
+    /// Convert a value to local date time.
+    fn to_local(self, v: i64) -> Option<NaiveDateTime> {
+        let datetime = timestamp_to_naive(self.unit, v)?;
+        Some(match self.tz {
+            Some(tz) => tz.utc_to_local(datetime),
+            None => datetime,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum AgeState {
+    /// Age between two timestamps.
+    Between { end: AgeInput, start: AgeInput },
+    /// Age relative to midnight of the current date.
+    FromToday {
+        today: NaiveDateTime,
+        start: AgeInput,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Age;
+
+impl ScalarFunction for Age {
+    type State = AgeState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let state = match inputs.len() {
+            1 => AgeState::FromToday {
+                today: Utc::now().date_naive().and_time(NaiveTime::MIN),
+                start: AgeInput::try_from_datatype(&inputs[0].datatype()?)?,
+            },
+            _ => AgeState::Between {
+                end: AgeInput::try_from_datatype(&inputs[0].datatype()?)?,
+                start: AgeInput::try_from_datatype(&inputs[1].datatype()?)?,
+            },
+        };
+
+        Ok(BindState {
+            state,
+            return_type: DataType::interval(),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+
+        match state {
+            AgeState::Between { end, start } => {
+                BinaryExecutor::execute::<PhysicalI64, PhysicalI64, PhysicalInterval, _>(
+                    &input.arrays()[0],
+                    sel,
+                    &input.arrays()[1],
+                    sel,
+                    OutBuffer::from_array(output)?,
+                    |&a, &b, buf| match (end.to_local(a), start.to_local(b)) {
+                        (Some(a), Some(b)) => buf.put(&age(a, b)),
+                        _ => buf.put_null(),
+                    },
+                )
+            }
+            AgeState::FromToday { today, start } => {
+                UnaryExecutor::execute::<PhysicalI64, PhysicalInterval, _>(
+                    &input.arrays()[0],
+                    sel,
+                    OutBuffer::from_array(output)?,
+                    |&v, buf| match start.to_local(v) {
+                        Some(v) => buf.put(&age(*today, v)),
+                        None => buf.put_null(),
+                    },
+                )
+            }
+        }
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> i32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    match (
+        NaiveDate::from_ymd_opt(year, month, 1),
+        NaiveDate::from_ymd_opt(next_year, next_month, 1),
+    ) {
+        (Some(start), Some(end)) => (end - start).num_days() as i32,
+        _ => 30,
+    }
+}
+
+/// Compute `end - start` as years, months, days, and time, matching Postgres'
+/// `age`.
+///
+/// Fields are subtracted individually, then borrowed from the next largest
+/// field if negative. Borrowed days use the length of the month `start` falls
+/// in.
+fn age(end: NaiveDateTime, start: NaiveDateTime) -> Interval {
+    if end < start {
+        let interval = age(start, end);
+        return Interval::new(-interval.months, -interval.days, -interval.nanos);
+    }
+
+    let time_micros = |dt: &NaiveDateTime| {
+        dt.num_seconds_from_midnight() as i64 * 1_000_000 + (dt.nanosecond() / 1_000) as i64
+    };
+
+    let mut micros = time_micros(&end) - time_micros(&start);
+    let mut days = end.day() as i32 - start.day() as i32;
+    let mut months = end.month() as i32 - start.month() as i32;
+    let mut years = end.year() - start.year();
+
+    if micros < 0 {
+        micros += 86_400_000_000;
+        days -= 1;
+    }
+    if days < 0 {
+        days += days_in_month(start.year(), start.month());
+        months -= 1;
+    }
+    if months < 0 {
+        months += 12;
+        years -= 1;
+    }
+
+    Interval::new(
+        years * 12 + months,
+        days,
+        micros * Interval::NANOSECONDS_IN_MICROSECOND,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn age_symbolic() {
+        let out = age(
+            datetime("2001-04-10 00:00:00"),
+            datetime("1957-06-13 00:00:00"),
+        );
+        assert_eq!(Interval::new(43 * 12 + 9, 27, 0), out);
+    }
+
+    #[test]
+    fn age_borrow_time() {
+        let out = age(
+            datetime("2024-03-01 01:00:00"),
+            datetime("2024-02-28 02:00:00"),
+        );
+        assert_eq!(Interval::new(0, 1, 23 * Interval::NANOSECONDS_IN_HOUR), out);
+    }
+
+    #[test]
+    fn age_negative() {
+        let out = age(
+            datetime("2024-01-01 00:00:00"),
+            datetime("2024-02-15 00:00:00"),
+        );
+        assert_eq!(Interval::new(-1, -14, 0), out);
+    }
+}
@@ -0,0 +1,293 @@
+use chrono::{Datelike, NaiveDateTime};
+use glaredb_error::{DbError, Result};
+
+use super::date_trunc::{TruncField, trunc_datetime};
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::date::timestamp_add_interval;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::interval::Interval;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+
+pub const FUNCTION_SET_DATE_DIFF: ScalarFunctionSet = ScalarFunctionSet {
+    name: "date_diff",
+    aliases: &["datediff"],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Count the number of unit boundaries crossed between two timestamps or dates.",
+        arguments: &["unit", "start", "end"],
+        example: Some(Example {
+            example: "date_diff('month', DATE '2024-01-31', DATE '2024-02-01')",
+            output: "1",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(
+            &[
+                DataTypeId::Utf8,
+                DataTypeId::Timestamp,
+                DataTypeId::Timestamp,
+            ],
+            DataTypeId::Int64,
+        ),
+        &DateDiff,
+    )],
+};
+
+pub const FUNCTION_SET_DATE_ADD: ScalarFunctionSet = ScalarFunctionSet {
+    name: "date_add",
+    aliases: &["dateadd"],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Add a number of units to a timestamp or date. Adding months or years clamps to the end of the month if needed.",
+        arguments: &["unit", "amount", "timestamp"],
+        example: Some(Example {
+            example: "date_add('month', 1, DATE '2024-01-31')",
+            output: "2024-02-29 00:00:00 UTC",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(
+            &[DataTypeId::Utf8, DataTypeId::Int64, DataTypeId::Timestamp],
+            DataTypeId::Timestamp,
+        ),
+        &DateAdd,
+    )],
+};
+
+/// Get the unit field from the first argument, requiring it to be constant.
+fn bind_field(input: &Expression) -> Result<TruncField> {
+    if !input.is_const_foldable() {
+        return Err(DbError::new("Unit argument must be constant"));
+    }
+    ConstFold::rewrite(input.clone())?
+        .try_into_scalar()?
+        .try_into_string()?
+        .to_lowercase()
+        .parse()
+}
+
+/// Unit and optional zone for a timestamp argument.
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampInput {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+}
+
+impl TimestampInput {
+    fn try_from_expr(expr: &Expression) -> Result<Self> {
+        let datatype = expr.datatype()?;
+        let m = datatype.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+        Ok(TimestampInput { unit: m.unit, tz })
+    }
+
+    fn to_local(self, v: i64) -> Option<NaiveDateTime> {
+        let datetime = timestamp_to_naive(self.unit, v)?;
+        Some(match self.tz {
+            Some(tz) => tz.utc_to_local(datetime),
+            None => datetime,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DateDiffState {
+    field: TruncField,
+    start: TimestampInput,
+    end: TimestampInput,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DateDiff;
+
+impl ScalarFunction for DateDiff {
+    type State = DateDiffState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: DateDiffState {
+                field: bind_field(&inputs[0])?,
+                start: TimestampInput::try_from_expr(&inputs[1])?,
+                end: TimestampInput::try_from_expr(&inputs[2])?,
+            },
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalI64, PhysicalI64, _>(
+            &input.arrays()[1],
+            sel,
+            &input.arrays()[2],
+            sel,
+            OutBuffer::from_array(output)?,
+            |&start, &end, buf| {
+                let diff = (|| {
+                    let start = state.start.to_local(start)?;
+                    let end = state.end.to_local(end)?;
+                    date_diff(state.field, start, end)
+                })();
+                match diff {
+                    Some(diff) => buf.put(&diff),
+                    None => buf.put_null(),
+                }
+            },
+        )
+    }
+}
+
+/// Count the number of `field` boundaries between `start` and `end`.
+///
+/// Both values are truncated to the field first, so `date_diff('year',
+/// '2023-12-31', '2024-01-01')` is 1.
+fn date_diff(field: TruncField, start: NaiveDateTime, end: NaiveDateTime) -> Option<i64> {
+    let start = trunc_datetime(field, start)?;
+    let end = trunc_datetime(field, end)?;
+
+    let months = |dt: &NaiveDateTime| dt.year() as i64 * 12 + dt.month0() as i64;
+    let month_diff = months(&end) - months(&start);
+    let micros_diff = || (end - start).num_microseconds();
+
+    Some(match field {
+        TruncField::Microseconds => micros_diff()?,
+        TruncField::Milliseconds => micros_diff()? / 1_000,
+        TruncField::Second => micros_diff()? / 1_000_000,
+        TruncField::Minute => (end - start).num_minutes(),
+        TruncField::Hour => (end - start).num_hours(),
+        TruncField::Day => (end - start).num_days(),
+        TruncField::Week => (end - start).num_days() / 7,
+        TruncField::Month => month_diff,
+        TruncField::Quarter => month_diff / 3,
+        TruncField::Year => month_diff / 12,
+        TruncField::Decade => month_diff / 120,
+        TruncField::Century => month_diff / 1_200,
+        TruncField::Millennium => month_diff / 12_000,
+    })
+}
+
+#[derive(Debug)]
+pub struct DateAddState {
+    field: TruncField,
+    input: TimestampInput,
+}
+
+impl DateAddState {
+    /// Create an interval for `amount` of the field.
+    fn interval(&self, amount: i64) -> Option<Interval> {
+        let months = |per: i64| {
+            Some(Interval::new(
+                i32::try_from(amount.checked_mul(per)?).ok()?,
+                0,
+                0,
+            ))
+        };
+        let nanos = |per: i64| Some(Interval::new(0, 0, amount.checked_mul(per)?));
+
+        match self.field {
+            TruncField::Microseconds => nanos(Interval::NANOSECONDS_IN_MICROSECOND),
+            TruncField::Milliseconds => nanos(Interval::NANOSECONDS_IN_MILLISECOND),
+            TruncField::Second => nanos(Interval::NANOSECONDS_IN_SECOND),
+            TruncField::Minute => nanos(Interval::NANOSECONDS_IN_MINUTE),
+            TruncField::Hour => nanos(Interval::NANOSECONDS_IN_HOUR),
+            TruncField::Day => Some(Interval::new(0, i32::try_from(amount).ok()?, 0)),
+            TruncField::Week => Some(Interval::new(
+                0,
+                i32::try_from(amount.checked_mul(7)?).ok()?,
+                0,
+            )),
+            TruncField::Month => months(1),
+            TruncField::Quarter => months(3),
+            TruncField::Year => months(12),
+            TruncField::Decade => months(120),
+            TruncField::Century => months(1_200),
+            TruncField::Millennium => months(12_000),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DateAdd;
+
+impl ScalarFunction for DateAdd {
+    type State = DateAddState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let input = TimestampInput::try_from_expr(&inputs[2])?;
+        let return_type = inputs[2].datatype()?;
+
+        Ok(BindState {
+            state: DateAddState {
+                field: bind_field(&inputs[0])?,
+                input,
+            },
+            return_type,
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let mut error = None;
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalI64, PhysicalI64, _>(
+            &input.arrays()[1],
+            sel,
+            &input.arrays()[2],
+            sel,
+            OutBuffer::from_array(output)?,
+            |&amount, &v, buf| {
+                let out = state.interval(amount).and_then(|interval| {
+                    timestamp_add_interval(state.input.unit, state.input.tz, v, interval)
+                });
+                match out {
+                    Some(v) => buf.put(&v),
+                    None => {
+                        error.get_or_insert_with(|| DbError::new("Timestamp out of range"));
+                        buf.put_null();
+                    }
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn diff_crosses_boundaries() {
+        let start = datetime("2023-12-31 23:59:00");
+        let end = datetime("2024-01-01 00:01:00");
+
+        assert_eq!(Some(1), date_diff(TruncField::Year, start, end));
+        assert_eq!(Some(1), date_diff(TruncField::Month, start, end));
+        assert_eq!(Some(1), date_diff(TruncField::Day, start, end));
+        assert_eq!(Some(2), date_diff(TruncField::Minute, start, end));
+        assert_eq!(Some(120), date_diff(TruncField::Second, start, end));
+        assert_eq!(Some(-1), date_diff(TruncField::Year, end, start));
+    }
+}
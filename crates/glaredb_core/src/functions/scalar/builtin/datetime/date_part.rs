@@ -49,6 +49,10 @@ pub const FUNCTION_SET_DATE_PART: ScalarFunctionSet = ScalarFunctionSet {
             ),
             &DatePart,
         ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Time], DataTypeId::Decimal64),
+            &DatePart,
+        ),
     ],
 };
 
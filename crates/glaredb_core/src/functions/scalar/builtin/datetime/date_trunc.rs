@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
-use glaredb_error::{DbError, Result, not_implemented};
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use glaredb_error::{DbError, Result};
 
 use crate::arrays::array::Array;
 use crate::arrays::array::physical_type::PhysicalI64;
@@ -8,6 +9,7 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use crate::arrays::executor::OutBuffer;
 use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::timezone::{TimeZone, naive_to_timestamp, timestamp_to_naive};
 use crate::expr::Expression;
 use crate::functions::Signature;
 use crate::functions::documentation::{Category, Documentation, Example};
@@ -28,7 +30,6 @@ pub const FUNCTION_SET_DATE_TRUNC: ScalarFunctionSet = ScalarFunctionSet {
             output: "2023-03-15 00:00:00",
         }),
     }],
-    // TODO: Date32/64
     functions: &[RawScalarFunction::new(
         &Signature::new(
             &[DataTypeId::Utf8, DataTypeId::Timestamp],
@@ -39,7 +40,7 @@ pub const FUNCTION_SET_DATE_TRUNC: ScalarFunctionSet = ScalarFunctionSet {
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum TruncField {
+pub(super) enum TruncField {
     Microseconds,
     Milliseconds,
     Second,
@@ -59,19 +60,19 @@ impl FromStr for TruncField {
     type Err = DbError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
-            "microseconds" => Self::Microseconds,
-            "milliseconds" => Self::Milliseconds,
-            "second" => Self::Second,
-            "minute" => Self::Minute,
-            "hour" => Self::Hour,
-            "day" => Self::Day,
-            "week" => Self::Week,
-            "month" => Self::Month,
-            "quarter" => Self::Quarter,
-            "year" => Self::Year,
-            "decade" => Self::Decade,
-            "century" => Self::Century,
-            "millennium" => Self::Millennium,
+            "microseconds" | "microsecond" => Self::Microseconds,
+            "milliseconds" | "millisecond" => Self::Milliseconds,
+            "second" | "seconds" => Self::Second,
+            "minute" | "minutes" => Self::Minute,
+            "hour" | "hours" => Self::Hour,
+            "day" | "days" => Self::Day,
+            "week" | "weeks" => Self::Week,
+            "month" | "months" => Self::Month,
+            "quarter" | "quarters" => Self::Quarter,
+            "year" | "years" => Self::Year,
+            "decade" | "decades" => Self::Decade,
+            "century" | "centuries" => Self::Century,
+            "millennium" | "millennia" => Self::Millennium,
             other => return Err(DbError::new(format!("Unexpected date field: {other}"))),
         })
     }
@@ -80,9 +81,83 @@ impl FromStr for TruncField {
 #[derive(Debug)]
 pub struct DateTruncState {
     input_unit: TimeUnit,
+    /// Time zone of the input, truncation happens on the local time in this
+    /// zone.
+    tz: Option<TimeZone>,
     field: TruncField,
 }
 
+impl DateTruncState {
+    /// Returns the width of the truncation in the input unit if it can be
+    /// done directly on the underlying value.
+    ///
+    /// This is only the case for naive timestamps with fields up to a day.
+    fn fixed_width(&self) -> Option<i64> {
+        if self.tz.is_some() {
+            return None;
+        }
+
+        let field_nanos: i64 = match self.field {
+            TruncField::Microseconds => 1_000,
+            TruncField::Milliseconds => 1_000_000,
+            TruncField::Second => 1_000_000_000,
+            TruncField::Minute => 60 * 1_000_000_000,
+            TruncField::Hour => 60 * 60 * 1_000_000_000,
+            TruncField::Day => 24 * 60 * 60 * 1_000_000_000,
+            _ => return None,
+        };
+        let unit_nanos: i64 = match self.input_unit {
+            TimeUnit::Second => 1_000_000_000,
+            TimeUnit::Millisecond => 1_000_000,
+            TimeUnit::Microsecond => 1_000,
+            TimeUnit::Nanosecond => 1,
+        };
+
+        Some(i64::max(field_nanos / unit_nanos, 1))
+    }
+
+    /// Truncate using the local date and time.
+    fn trunc_local(&self, val: i64) -> Option<i64> {
+        let datetime = timestamp_to_naive(self.input_unit, val)?;
+        let datetime = match self.tz {
+            Some(tz) => tz.local_to_utc(trunc_datetime(self.field, tz.utc_to_local(datetime))?),
+            None => trunc_datetime(self.field, datetime)?,
+        };
+        naive_to_timestamp(self.input_unit, datetime)
+    }
+}
+
+pub(super) fn trunc_datetime(field: TruncField, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+    let date = datetime.date();
+    let time = datetime.time();
+    let year_start = |year: i32| NaiveDate::from_ymd_opt(year, 1, 1);
+
+    let date = match field {
+        TruncField::Microseconds => {
+            let nanos = time.nanosecond() / 1_000 * 1_000;
+            return Some(date.and_time(time.with_nanosecond(nanos)?));
+        }
+        TruncField::Milliseconds => {
+            let nanos = time.nanosecond() / 1_000_000 * 1_000_000;
+            return Some(date.and_time(time.with_nanosecond(nanos)?));
+        }
+        TruncField::Second => return date.and_hms_opt(time.hour(), time.minute(), time.second()),
+        TruncField::Minute => return date.and_hms_opt(time.hour(), time.minute(), 0),
+        TruncField::Hour => return date.and_hms_opt(time.hour(), 0, 0),
+        TruncField::Day => date,
+        TruncField::Week => date - Days::new(date.weekday().num_days_from_monday() as u64),
+        TruncField::Month => NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?,
+        TruncField::Quarter => NaiveDate::from_ymd_opt(date.year(), date.month0() / 3 * 3 + 1, 1)?,
+        TruncField::Year => year_start(date.year())?,
+        TruncField::Decade => year_start(date.year() - date.year().rem_euclid(10))?,
+        // Centuries and millennia start on year 1, matching Postgres.
+        TruncField::Century => year_start((date.year() - 1).div_euclid(100) * 100 + 1)?,
+        TruncField::Millennium => year_start((date.year() - 1).div_euclid(1000) * 1000 + 1)?,
+    };
+
+    Some(date.and_time(NaiveTime::MIN))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DateTrunc;
 
@@ -99,10 +174,12 @@ impl ScalarFunction for DateTrunc {
         let field = field.parse::<TruncField>()?;
         let datatype = inputs[1].datatype()?;
         let time_m = datatype.try_get_timestamp_type_meta()?;
+        let tz = time_m.tz.as_deref().map(str::parse).transpose()?;
 
         Ok(BindState {
             state: DateTruncState {
                 input_unit: time_m.unit,
+                tz,
                 field,
             },
             return_type: DataType::timestamp(time_m.clone()),
@@ -115,50 +192,25 @@ impl ScalarFunction for DateTrunc {
         // First element is field name, skip.
         let input = &input.arrays()[1];
 
-        let trunc = match state.input_unit {
-            TimeUnit::Second => match state.field {
-                TruncField::Microseconds | TruncField::Milliseconds | TruncField::Second => 1,
-                TruncField::Minute => 60,
-                TruncField::Hour => 60 * 60,
-                TruncField::Day => 24 * 60 * 60,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-            TimeUnit::Millisecond => match state.field {
-                TruncField::Microseconds | TruncField::Milliseconds => 1,
-                TruncField::Second => 1000,
-                TruncField::Minute => 60 * 1000,
-                TruncField::Hour => 60 * 60 * 1000,
-                TruncField::Day => 24 * 60 * 60 * 1000,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-            TimeUnit::Microsecond => match state.field {
-                TruncField::Microseconds => 1,
-                TruncField::Milliseconds => 1000,
-                TruncField::Second => 1000 * 1000,
-                TruncField::Minute => 60 * 1000 * 1000,
-                TruncField::Hour => 60 * 60 * 1000 * 1000,
-                TruncField::Day => 24 * 60 * 60 * 1000 * 1000,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-            TimeUnit::Nanosecond => match state.field {
-                TruncField::Microseconds => 1000,
-                TruncField::Milliseconds => 1000 * 1000,
-                TruncField::Second => 1000 * 1000 * 1000,
-                TruncField::Minute => 60 * 1000 * 1000 * 1000,
-                TruncField::Hour => 60 * 60 * 1000 * 1000 * 1000,
-                TruncField::Day => 24 * 60 * 60 * 1000 * 1000 * 1000,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-        };
-
-        UnaryExecutor::execute::<PhysicalI64, PhysicalI64, _>(
-            input,
-            sel,
-            OutBuffer::from_array(output)?,
-            |&v, buf| {
-                let v = (v / trunc) * trunc;
-                buf.put(&v)
-            },
-        )
+        match state.fixed_width() {
+            Some(trunc) => UnaryExecutor::execute::<PhysicalI64, PhysicalI64, _>(
+                input,
+                sel,
+                OutBuffer::from_array(output)?,
+                |&v, buf| {
+                    let v = v.div_euclid(trunc) * trunc;
+                    buf.put(&v)
+                },
+            ),
+            None => UnaryExecutor::execute::<PhysicalI64, PhysicalI64, _>(
+                input,
+                sel,
+                OutBuffer::from_array(output)?,
+                |&v, buf| match state.trunc_local(v) {
+                    Some(v) => buf.put(&v),
+                    None => buf.put_null(),
+                },
+            ),
+        }
     }
 }
@@ -0,0 +1,120 @@
+use chrono::{Datelike, Months, NaiveDate};
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI32, PhysicalI64};
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::date::{date32_to_naive, naive_to_date32};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::timezone::{TimeZone, timestamp_to_naive};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_LAST_DAY: ScalarFunctionSet = ScalarFunctionSet {
+    name: "last_day",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Returns the last day of the month for a date or timestamp.",
+        arguments: &["date"],
+        example: Some(Example {
+            example: "last_day(DATE '2024-02-10')",
+            output: "2024-02-29",
+        }),
+    }],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Date32], DataTypeId::Date32),
+            &LastDayDate,
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Timestamp], DataTypeId::Date32),
+            &LastDayTimestamp,
+        ),
+    ],
+};
+
+fn last_day(date: NaiveDate) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?
+        .checked_add_months(Months::new(1))?
+        .pred_opt()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LastDayDate;
+
+impl ScalarFunction for LastDayDate {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::date32(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        UnaryExecutor::execute::<PhysicalI32, PhysicalI32, _>(
+            &input.arrays()[0],
+            input.selection(),
+            OutBuffer::from_array(output)?,
+            |&v, buf| match date32_to_naive(v).and_then(last_day) {
+                Some(date) => buf.put(&naive_to_date32(date)),
+                None => buf.put_null(),
+            },
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct LastDayTimestampState {
+    unit: TimeUnit,
+    /// Time zone of the input, the local date in this zone is used.
+    tz: Option<TimeZone>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LastDayTimestamp;
+
+impl ScalarFunction for LastDayTimestamp {
+    type State = LastDayTimestampState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let datatype = inputs[0].datatype()?;
+        let m = datatype.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+
+        Ok(BindState {
+            state: LastDayTimestampState { unit: m.unit, tz },
+            return_type: DataType::date32(),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let to_date = |v: i64| {
+            let datetime = timestamp_to_naive(state.unit, v)?;
+            let datetime = match state.tz {
+                Some(tz) => tz.utc_to_local(datetime),
+                None => datetime,
+            };
+            last_day(datetime.date())
+        };
+
+        UnaryExecutor::execute::<PhysicalI64, PhysicalI32, _>(
+            &input.arrays()[0],
+            input.selection(),
+            OutBuffer::from_array(output)?,
+            |&v, buf| match to_date(v) {
+                Some(date) => buf.put(&naive_to_date32(date)),
+                None => buf.put_null(),
+            },
+        )
+    }
+}
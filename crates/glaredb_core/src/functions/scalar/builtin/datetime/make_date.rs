@@ -0,0 +1,260 @@
+use chrono::{NaiveDate, NaiveTime};
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalF64, PhysicalI32, PhysicalI64};
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::date::{naive_to_date32, naive_to_time};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::UniformExecutor;
+use crate::arrays::scalar::timezone::naive_to_timestamp;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_MAKE_DATE: ScalarFunctionSet = ScalarFunctionSet {
+    name: "make_date",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Create a date from year, month, and day fields.",
+        arguments: &["year", "month", "day"],
+        example: Some(Example {
+            example: "make_date(2024, 2, 29)",
+            output: "2024-02-29",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(
+            &[DataTypeId::Int64, DataTypeId::Int64, DataTypeId::Int64],
+            DataTypeId::Date32,
+        ),
+        &MakeDate,
+    )],
+};
+
+pub const FUNCTION_SET_MAKE_TIME: ScalarFunctionSet = ScalarFunctionSet {
+    name: "make_time",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Create a time from hour, minute, and seconds fields. Seconds may contain a fractional part.",
+        arguments: &["hour", "minute", "seconds"],
+        example: Some(Example {
+            example: "make_time(13, 45, 30.5)",
+            output: "13:45:30.500",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(
+            &[
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+            ],
+            DataTypeId::Time,
+        ),
+        &MakeTime,
+    )],
+};
+
+pub const FUNCTION_SET_MAKE_TIMESTAMP: ScalarFunctionSet = ScalarFunctionSet {
+    name: "make_timestamp",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Create a timestamp from year, month, day, hour, minute, and seconds fields. Seconds may contain a fractional part.",
+        arguments: &["year", "month", "day", "hour", "minute", "seconds"],
+        example: Some(Example {
+            example: "make_timestamp(2024, 2, 29, 13, 45, 30.5)",
+            output: "2024-02-29 13:45:30.500 UTC",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(
+            &[
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+                DataTypeId::Float64,
+            ],
+            DataTypeId::Timestamp,
+        ),
+        &MakeTimestamp,
+    )],
+};
+
+fn make_naive_date(year: i64, month: i64, day: i64) -> Result<NaiveDate> {
+    let date = (|| {
+        NaiveDate::from_ymd_opt(
+            i32::try_from(year).ok()?,
+            u32::try_from(month).ok()?,
+            u32::try_from(day).ok()?,
+        )
+    })();
+    date.ok_or_else(|| {
+        DbError::new("Date field value out of range")
+            .with_field("year", year)
+            .with_field("month", month)
+            .with_field("day", day)
+    })
+}
+
+/// Get an integer field from a float argument, erroring if the value has a
+/// fractional part.
+fn integer_field(name: &str, v: f64) -> Result<i64> {
+    if v.fract() != 0.0 || !v.is_finite() {
+        return Err(
+            DbError::new(format!("Expected an integer for '{name}'")).with_field("value", v)
+        );
+    }
+    Ok(v as i64)
+}
+
+fn make_naive_time(hour: f64, minute: f64, seconds: f64) -> Result<NaiveTime> {
+    let hour = integer_field("hour", hour)?;
+    let minute = integer_field("minute", minute)?;
+
+    let time = (|| {
+        if !(0.0..60.0).contains(&seconds) {
+            return None;
+        }
+        let micros = (seconds * 1_000_000.0).round() as u32;
+        NaiveTime::from_hms_micro_opt(
+            u32::try_from(hour).ok()?,
+            u32::try_from(minute).ok()?,
+            micros / 1_000_000,
+            micros % 1_000_000,
+        )
+    })();
+    time.ok_or_else(|| {
+        DbError::new("Time field value out of range")
+            .with_field("hour", hour)
+            .with_field("minute", minute)
+            .with_field("seconds", seconds)
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MakeDate;
+
+impl ScalarFunction for MakeDate {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::date32(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let mut error = None;
+        UniformExecutor::execute::<PhysicalI64, PhysicalI32, _>(
+            input.arrays(),
+            input.selection(),
+            OutBuffer::from_array(output)?,
+            |fields, buf| match make_naive_date(*fields[0], *fields[1], *fields[2]) {
+                Ok(date) => buf.put(&naive_to_date32(date)),
+                Err(e) => {
+                    error.get_or_insert(e);
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MakeTime;
+
+impl ScalarFunction for MakeTime {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::time(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let mut error = None;
+        UniformExecutor::execute::<PhysicalF64, PhysicalI64, _>(
+            input.arrays(),
+            input.selection(),
+            OutBuffer::from_array(output)?,
+            |fields, buf| match make_naive_time(*fields[0], *fields[1], *fields[2]) {
+                Ok(time) => buf.put(&naive_to_time(time)),
+                Err(e) => {
+                    error.get_or_insert(e);
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MakeTimestamp;
+
+impl ScalarFunction for MakeTimestamp {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let make = |fields: &[&f64]| {
+            let date = make_naive_date(
+                integer_field("year", *fields[0])?,
+                integer_field("month", *fields[1])?,
+                integer_field("day", *fields[2])?,
+            )?;
+            let time = make_naive_time(*fields[3], *fields[4], *fields[5])?;
+            naive_to_timestamp(TimeUnit::Microsecond, date.and_time(time))
+                .ok_or_else(|| DbError::new("Timestamp out of range"))
+        };
+
+        let mut error = None;
+        UniformExecutor::execute::<PhysicalF64, PhysicalI64, _>(
+            input.arrays(),
+            input.selection(),
+            OutBuffer::from_array(output)?,
+            |fields, buf| match make(fields) {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    error.get_or_insert(e);
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
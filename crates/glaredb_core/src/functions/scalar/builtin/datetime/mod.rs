@@ -6,3 +6,24 @@ pub use epoch::*;
 
 mod date_trunc;
 pub use date_trunc::*;
+
+mod timezone;
+pub use timezone::*;
+
+mod now;
+pub use now::*;
+
+mod make_date;
+pub use make_date::*;
+
+mod strftime;
+pub use strftime::*;
+
+mod age;
+pub use age::*;
+
+mod date_diff;
+pub use date_diff::*;
+
+mod last_day;
+pub use last_day::*;
@@ -0,0 +1,230 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI32, PhysicalI64};
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::date::{naive_to_date32, naive_to_time};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::scalar::timezone::{TimeZone, naive_to_timestamp};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+
+pub const FUNCTION_SET_NOW: ScalarFunctionSet = ScalarFunctionSet {
+    name: "now",
+    aliases: &["current_timestamp"],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Returns the current timestamp in the session time zone. The value is fixed for the duration of the statement.",
+        arguments: &[],
+        example: Some(Example {
+            example: "now()",
+            output: "2025-03-04 15:12:08.123456+00",
+        }),
+    }],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[], DataTypeId::Timestamp),
+            &CurrentTime::<NowKind>::new(),
+        ),
+        RawScalarFunction::new(
+            &Signature::new(
+                &[DataTypeId::Utf8, DataTypeId::Int64],
+                DataTypeId::Timestamp,
+            ),
+            &CurrentTime::<NowKind>::new(),
+        ),
+    ],
+};
+
+pub const FUNCTION_SET_CURRENT_DATE: ScalarFunctionSet = ScalarFunctionSet {
+    name: "current_date",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Returns the current date in the session time zone.",
+        arguments: &[],
+        example: Some(Example {
+            example: "current_date",
+            output: "2025-03-04",
+        }),
+    }],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[], DataTypeId::Date32),
+            &CurrentTime::<DateKind>::new(),
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Int64], DataTypeId::Date32),
+            &CurrentTime::<DateKind>::new(),
+        ),
+    ],
+};
+
+pub const FUNCTION_SET_CURRENT_TIME: ScalarFunctionSet = ScalarFunctionSet {
+    name: "current_time",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Returns the current time of day in the session time zone.",
+        arguments: &[],
+        example: Some(Example {
+            example: "current_time",
+            output: "15:12:08.123456",
+        }),
+    }],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[], DataTypeId::Time),
+            &CurrentTime::<TimeKind>::new(),
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Int64], DataTypeId::Time),
+            &CurrentTime::<TimeKind>::new(),
+        ),
+    ],
+};
+
+/// Determines the output for a function returning the current time.
+pub trait CurrentTimeKind: Debug + Sync + Send + Copy + 'static {
+    fn return_type(tz: TimeZone) -> DataType;
+
+    /// Write the value to every row of the output.
+    fn fill(value: i64, input: &Batch, output: &mut Array) -> Result<()>;
+
+    /// Compute the value for `now` in the given zone.
+    fn value(now: DateTime<Utc>, tz: TimeZone) -> Result<i64>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NowKind;
+
+impl CurrentTimeKind for NowKind {
+    fn return_type(tz: TimeZone) -> DataType {
+        DataType::timestamp(TimestampTypeMeta::new_with_tz(
+            TimeUnit::Microsecond,
+            tz.to_string(),
+        ))
+    }
+
+    fn fill(value: i64, input: &Batch, output: &mut Array) -> Result<()> {
+        UnaryExecutor::execute_in_place::<PhysicalI64, _>(output, input.selection(), |v| *v = value)
+    }
+
+    fn value(now: DateTime<Utc>, _tz: TimeZone) -> Result<i64> {
+        // Timestamps with a zone are stored as UTC.
+        naive_to_timestamp(TimeUnit::Microsecond, now.naive_utc())
+            .ok_or_else(|| DbError::new("Current time out of range"))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DateKind;
+
+impl CurrentTimeKind for DateKind {
+    fn return_type(_tz: TimeZone) -> DataType {
+        DataType::date32()
+    }
+
+    fn fill(value: i64, input: &Batch, output: &mut Array) -> Result<()> {
+        let value = value as i32;
+        UnaryExecutor::execute_in_place::<PhysicalI32, _>(output, input.selection(), |v| *v = value)
+    }
+
+    fn value(now: DateTime<Utc>, tz: TimeZone) -> Result<i64> {
+        let local = tz.utc_to_local(now.naive_utc());
+        Ok(naive_to_date32(local.date()) as i64)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimeKind;
+
+impl CurrentTimeKind for TimeKind {
+    fn return_type(_tz: TimeZone) -> DataType {
+        DataType::time()
+    }
+
+    fn fill(value: i64, input: &Batch, output: &mut Array) -> Result<()> {
+        NowKind::fill(value, input, output)
+    }
+
+    fn value(now: DateTime<Utc>, tz: TimeZone) -> Result<i64> {
+        let local = tz.utc_to_local(now.naive_utc());
+        Ok(naive_to_time(local.time()))
+    }
+}
+
+fn constant_arg(input: &Expression) -> Result<ScalarValue> {
+    if !input.is_const_foldable() {
+        return Err(DbError::new(
+            "Arguments to current time functions must be constant",
+        ));
+    }
+    ConstFold::rewrite(input.clone())?.try_into_scalar()
+}
+
+/// Returns the current time, date, or timestamp.
+///
+/// The planner provides the session's time zone and the statement start time
+/// as arguments so that every reference within a statement sees the same
+/// value. Without arguments, UTC and the time of binding is used.
+#[derive(Debug, Clone, Copy)]
+pub struct CurrentTime<K: CurrentTimeKind> {
+    _kind: std::marker::PhantomData<K>,
+}
+
+impl<K> CurrentTime<K>
+where
+    K: CurrentTimeKind,
+{
+    pub const fn new() -> Self {
+        CurrentTime {
+            _kind: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<K> ScalarFunction for CurrentTime<K>
+where
+    K: CurrentTimeKind,
+{
+    type State = i64;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let (tz, now) = match inputs.as_slice() {
+            [zone, time] => {
+                let tz = match constant_arg(zone)? {
+                    ScalarValue::Null => TimeZone::UTC,
+                    scalar => scalar.try_as_str()?.parse::<TimeZone>()?,
+                };
+                let micros = constant_arg(time)?.try_as_i64()?;
+                let now = DateTime::from_timestamp_micros(micros)
+                    .ok_or_else(|| DbError::new("Statement time out of range"))?;
+                (tz, now)
+            }
+            _ => (TimeZone::UTC, Utc::now()),
+        };
+
+        let value = K::value(now, tz)?;
+
+        Ok(BindState {
+            state: value,
+            return_type: K::return_type(tz),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        K::fill(*state, input, output)
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt::Write as _;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::scalar::timezone::{TimeZone, naive_to_timestamp, timestamp_to_naive};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+
+pub const FUNCTION_SET_STRFTIME: ScalarFunctionSet = ScalarFunctionSet {
+    name: "strftime",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Format a timestamp as a string using strftime-style format specifiers. Timestamps with a time zone are formatted using their local time.",
+        arguments: &["timestamp", "format"],
+        example: Some(Example {
+            example: "strftime(TIMESTAMP '2024-03-15 13:45:30', '%d/%m/%Y %H:%M')",
+            output: "15/03/2024 13:45",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Timestamp, DataTypeId::Utf8], DataTypeId::Utf8),
+        &Strftime,
+    )],
+};
+
+pub const FUNCTION_SET_STRPTIME: ScalarFunctionSet = ScalarFunctionSet {
+    name: "strptime",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Parse a string into a timestamp using strftime-style format specifiers. Parsed values containing a UTC offset are converted to UTC.",
+        arguments: &["string", "format"],
+        example: Some(Example {
+            example: "strptime('15/03/2024 13:45', '%d/%m/%Y %H:%M')",
+            output: "2024-03-15 13:45:00 UTC",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Utf8, DataTypeId::Utf8], DataTypeId::Timestamp),
+        &Strptime,
+    )],
+};
+
+/// Check that a format string contains only valid specifiers.
+fn validate_format(format: &str) -> Result<()> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(DbError::new(format!("Invalid format string: '{format}'")));
+    }
+    Ok(())
+}
+
+/// Validate the format argument if it's constant.
+fn validate_constant_format(input: &Expression) -> Result<()> {
+    if !input.is_const_foldable() {
+        return Ok(());
+    }
+    match ConstFold::rewrite(input.clone())?.try_into_scalar()? {
+        ScalarValue::Null => Ok(()),
+        scalar => validate_format(scalar.try_as_str()?),
+    }
+}
+
+#[derive(Debug)]
+pub struct StrftimeState {
+    unit: TimeUnit,
+    tz: Option<TimeZone>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Strftime;
+
+impl ScalarFunction for Strftime {
+    type State = StrftimeState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        validate_constant_format(&inputs[1])?;
+
+        let datatype = inputs[0].datatype()?;
+        let m = datatype.try_get_timestamp_type_meta()?;
+        let tz = m.tz.as_deref().map(str::parse).transpose()?;
+
+        Ok(BindState {
+            state: StrftimeState { unit: m.unit, tz },
+            return_type: DataType::utf8(),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let timestamps = &input.arrays()[0];
+        let formats = &input.arrays()[1];
+
+        let mut error = None;
+        let mut str_buf = String::new();
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalUtf8, PhysicalUtf8, _>(
+            timestamps,
+            sel,
+            formats,
+            sel,
+            OutBuffer::from_array(output)?,
+            |&v, format, buf| {
+                let utc = match timestamp_to_naive(state.unit, v) {
+                    Some(utc) => utc,
+                    None => {
+                        buf.put_null();
+                        return;
+                    }
+                };
+                let offset = match state.tz {
+                    Some(tz) => tz.offset_from_utc(&utc),
+                    None => FixedOffset::east_opt(0).unwrap(),
+                };
+                let datetime = DateTime::<FixedOffset>::from_naive_utc_and_offset(utc, offset);
+
+                str_buf.clear();
+                match write!(str_buf, "{}", datetime.format(format)) {
+                    Ok(_) => buf.put(str_buf.as_str()),
+                    Err(_) => {
+                        error.get_or_insert_with(|| {
+                            DbError::new(format!("Invalid format string: '{format}'"))
+                        });
+                        buf.put_null();
+                    }
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parse a string using a format string.
+///
+/// Falls back to parsing only a date if the format contains no time fields.
+fn parse_with_format(s: &str, format: &str) -> Option<NaiveDateTime> {
+    if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+        return Some(datetime.naive_utc());
+    }
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format) {
+        return Some(datetime);
+    }
+    NaiveDate::parse_from_str(s, format)
+        .ok()
+        .map(|date| date.and_time(NaiveTime::MIN))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Strptime;
+
+impl ScalarFunction for Strptime {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        validate_constant_format(&inputs[1])?;
+
+        Ok(BindState {
+            state: (),
+            return_type: DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let strings = &input.arrays()[0];
+        let formats = &input.arrays()[1];
+
+        let mut error = None;
+
+        BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, PhysicalI64, _>(
+            strings,
+            sel,
+            formats,
+            sel,
+            OutBuffer::from_array(output)?,
+            |s, format, buf| match parse_with_format(s, format)
+                .and_then(|dt| naive_to_timestamp(TimeUnit::Microsecond, dt))
+            {
+                Some(v) => buf.put(&v),
+                None => {
+                    error.get_or_insert_with(|| {
+                        DbError::new(format!("Failed to parse '{s}' using format '{format}'"))
+                    });
+                    buf.put_null();
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_formats() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(13, 45, 0)
+            .unwrap();
+        assert_eq!(
+            Some(expected),
+            parse_with_format("15/03/2024 13:45", "%d/%m/%Y %H:%M")
+        );
+
+        // Offsets are converted to UTC.
+        assert_eq!(
+            Some(expected),
+            parse_with_format("2024-03-15 15:45 +0200", "%Y-%m-%d %H:%M %z")
+        );
+
+        // Date only.
+        let midnight = NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_time(NaiveTime::MIN);
+        assert_eq!(Some(midnight), parse_with_format("2024/03/15", "%Y/%m/%d"));
+
+        assert_eq!(None, parse_with_format("2024/03/15", "%d-%m-%Y"));
+    }
+
+    #[test]
+    fn invalid_format() {
+        validate_format("%Y-%m-%d").unwrap();
+        validate_format("%Q").unwrap_err();
+    }
+}
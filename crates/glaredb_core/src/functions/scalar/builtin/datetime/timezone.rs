@@ -0,0 +1,152 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::scalar::timezone::{TimeZone, naive_to_timestamp, timestamp_to_naive};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+
+pub const FUNCTION_SET_TIMEZONE: ScalarFunctionSet = ScalarFunctionSet {
+    name: "timezone",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::DateTime,
+        description: "Convert a timestamp using a time zone. A timestamp without a time zone is treated as local time in the given zone, producing a timestamp with a time zone. A timestamp with a time zone produces the local time in the given zone. Equivalent to `<timestamp> AT TIME ZONE <zone>`.",
+        arguments: &["zone", "timestamp"],
+        example: Some(Example {
+            example: "timezone('America/New_York', TIMESTAMP '2024-01-15 10:00:00')",
+            output: "2024-01-15 10:00:00-05",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(
+            &[DataTypeId::Utf8, DataTypeId::Timestamp],
+            DataTypeId::Timestamp,
+        ),
+        &Timezone,
+    )],
+};
+
+#[derive(Debug)]
+pub struct TimezoneState {
+    unit: TimeUnit,
+    /// If the input timestamp has a time zone.
+    ///
+    /// Inputs with a time zone are converted to local time, inputs without
+    /// are converted from local time.
+    input_has_tz: bool,
+    /// Zone to use if the zone argument is constant.
+    constant: Option<TimeZone>,
+}
+
+impl TimezoneState {
+    fn convert(&self, tz: TimeZone, val: i64) -> Option<i64> {
+        let datetime = timestamp_to_naive(self.unit, val)?;
+        let datetime = if self.input_has_tz {
+            tz.utc_to_local(datetime)
+        } else {
+            tz.local_to_utc(datetime)
+        };
+        naive_to_timestamp(self.unit, datetime)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timezone;
+
+impl ScalarFunction for Timezone {
+    type State = TimezoneState;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let constant = if inputs[0].is_const_foldable() {
+            match ConstFold::rewrite(inputs[0].clone())?.try_into_scalar()? {
+                ScalarValue::Null => None,
+                scalar => Some(scalar.try_as_str()?.parse::<TimeZone>()?),
+            }
+        } else {
+            None
+        };
+
+        let datatype = inputs[1].datatype()?;
+        let m = datatype.try_get_timestamp_type_meta()?;
+
+        let return_type = match (&m.tz, constant) {
+            (Some(_), _) => DataType::timestamp(TimestampTypeMeta::new(m.unit)),
+            (None, Some(tz)) => {
+                DataType::timestamp(TimestampTypeMeta::new_with_tz(m.unit, tz.to_string()))
+            }
+            // Zone varies per row, output is a UTC instant.
+            (None, None) => DataType::timestamp(TimestampTypeMeta::new_with_tz(
+                m.unit,
+                TimeZone::UTC.to_string(),
+            )),
+        };
+
+        Ok(BindState {
+            state: TimezoneState {
+                unit: m.unit,
+                input_has_tz: m.tz.is_some(),
+                constant,
+            },
+            return_type,
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let zones = &input.arrays()[0];
+        let timestamps = &input.arrays()[1];
+
+        match state.constant {
+            Some(tz) => UnaryExecutor::execute::<PhysicalI64, PhysicalI64, _>(
+                timestamps,
+                sel,
+                OutBuffer::from_array(output)?,
+                |&v, buf| match state.convert(tz, v) {
+                    Some(v) => buf.put(&v),
+                    None => buf.put_null(),
+                },
+            ),
+            None => {
+                let mut error = None;
+                BinaryExecutor::execute::<PhysicalUtf8, PhysicalI64, PhysicalI64, _>(
+                    zones,
+                    sel,
+                    timestamps,
+                    sel,
+                    OutBuffer::from_array(output)?,
+                    |zone, &v, buf| {
+                        let tz = match zone.parse::<TimeZone>() {
+                            Ok(tz) => tz,
+                            Err(e) => {
+                                error.get_or_insert(e);
+                                buf.put_null();
+                                return;
+                            }
+                        };
+                        match state.convert(tz, v) {
+                            Some(v) => buf.put(&v),
+                            None => buf.put_null(),
+                        }
+                    },
+                )?;
+
+                match error {
+                    Some(e) => Err(e),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use glaredb_error::Result;
+use serde_json::Value;
+
+use super::path::{JsonPath, parse_json};
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_JSON_ARRAY_LENGTH: ScalarFunctionSet = ScalarFunctionSet {
+    name: "json_array_length",
+    aliases: &[],
+    doc: &[
+        &Documentation {
+            category: Category::Json,
+            description: "Get the number of elements in a JSON array. Returns 0 if the value is not an array.",
+            arguments: &["json"],
+            example: Some(Example {
+                example: "json_array_length('[1, 2, 3]')",
+                output: "3",
+            }),
+        },
+        &Documentation {
+            category: Category::Json,
+            description: "Get the number of elements in a JSON array at the given path. Returns NULL if the value doesn't exist.",
+            arguments: &["json", "path"],
+            example: Some(Example {
+                example: r#"json_array_length('{"a": [1, 2]}', 'a')"#,
+                output: "2",
+            }),
+        },
+    ],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8], DataTypeId::Int64),
+            &JsonArrayLength,
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Utf8], DataTypeId::Int64),
+            &JsonArrayLength,
+        ),
+    ],
+};
+
+fn array_length(json: &str, path: &JsonPath) -> Result<Option<i64>> {
+    let doc = parse_json(json)?;
+    Ok(path.lookup(&doc).map(|value| match value {
+        Value::Array(arr) => arr.len() as i64,
+        _ => 0,
+    }))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsonArrayLength;
+
+impl ScalarFunction for JsonArrayLength {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let mut error = None;
+
+        match input.arrays().len() {
+            1 => UnaryExecutor::execute::<PhysicalUtf8, PhysicalI64, _>(
+                &input.arrays()[0],
+                sel,
+                OutBuffer::from_array(output)?,
+                |json, buf| match array_length(json, &JsonPath::default()) {
+                    Ok(Some(len)) => buf.put(&len),
+                    Ok(None) => buf.put_null(),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                        buf.put_null();
+                    }
+                },
+            )?,
+            _ => BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, PhysicalI64, _>(
+                &input.arrays()[0],
+                sel,
+                &input.arrays()[1],
+                sel,
+                OutBuffer::from_array(output)?,
+                |json, path, buf| match JsonPath::parse(path)
+                    .and_then(|path| array_length(json, &path))
+                {
+                    Ok(Some(len)) => buf.put(&len),
+                    Ok(None) => buf.put_null(),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                        buf.put_null();
+                    }
+                },
+            )?,
+        }
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
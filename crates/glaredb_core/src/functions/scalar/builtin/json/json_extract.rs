@@ -0,0 +1,181 @@
+use std::marker::PhantomData;
+
+use glaredb_error::Result;
+use serde_json::Value;
+
+use super::path::{JsonPath, PathStorage, parse_json};
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_JSON_EXTRACT: ScalarFunctionSet = ScalarFunctionSet {
+    name: "json_extract",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Json,
+        description: "Extract a value from a JSON string, returning it as JSON. The path may be an object key, an array index, or a JSONPath expression starting with '$'. Returns NULL if the value doesn't exist.",
+        arguments: &["json", "path"],
+        example: Some(Example {
+            example: r#"json_extract('{"a": {"b": ["x", "y"]}}', '$.a.b[1]')"#,
+            output: r#""y""#,
+        }),
+    }],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Utf8], DataTypeId::Utf8),
+            &JsonExtract::<PhysicalUtf8>::new(false),
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Int64], DataTypeId::Utf8),
+            &JsonExtract::<PhysicalI64>::new(false),
+        ),
+    ],
+};
+
+pub const FUNCTION_SET_JSON_EXTRACT_STRING: ScalarFunctionSet = ScalarFunctionSet {
+    name: "json_extract_string",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Json,
+        description: "Extract a value from a JSON string, returning it as text. Strings are returned without quotes, and JSON nulls are returned as NULL.",
+        arguments: &["json", "path"],
+        example: Some(Example {
+            example: r#"json_extract_string('{"a": {"b": ["x", "y"]}}', '$.a.b[1]')"#,
+            output: "y",
+        }),
+    }],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Utf8], DataTypeId::Utf8),
+            &JsonExtract::<PhysicalUtf8>::new(true),
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Int64], DataTypeId::Utf8),
+            &JsonExtract::<PhysicalI64>::new(true),
+        ),
+    ],
+};
+
+/// Extract a value from a JSON document.
+///
+/// Returns None if the value doesn't exist, or if `as_text` is set and the
+/// value is a JSON null.
+fn extract(json: &str, path: &JsonPath, as_text: bool) -> Result<Option<String>> {
+    let doc = parse_json(json)?;
+    let value = match path.lookup(&doc) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    if as_text {
+        match value {
+            Value::Null => Ok(None),
+            Value::String(s) => Ok(Some(s.clone())),
+            other => Ok(Some(other.to_string())),
+        }
+    } else {
+        Ok(Some(value.to_string()))
+    }
+}
+
+/// Extracts values from JSON with the path argument being of physical type
+/// `S`.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonExtract<S> {
+    /// Return the value as text instead of JSON.
+    as_text: bool,
+    _s: PhantomData<S>,
+}
+
+impl<S> JsonExtract<S> {
+    pub const fn new(as_text: bool) -> Self {
+        JsonExtract {
+            as_text,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunction for JsonExtract<S>
+where
+    S: PathStorage,
+{
+    type State = bool;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: self.as_text,
+            return_type: DataType::utf8(),
+            inputs,
+        })
+    }
+
+    fn execute(as_text: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let mut error = None;
+
+        BinaryExecutor::execute::<PhysicalUtf8, S, PhysicalUtf8, _>(
+            &input.arrays()[0],
+            sel,
+            &input.arrays()[1],
+            sel,
+            OutBuffer::from_array(output)?,
+            |json, path, buf| {
+                let extracted = S::to_path(path).and_then(|path| extract(json, &path, *as_text));
+                match extracted {
+                    Ok(Some(s)) => buf.put(s.as_str()),
+                    Ok(None) => buf.put_null(),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                        buf.put_null();
+                    }
+                }
+            },
+        )?;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_json_and_text() {
+        let doc = r#"{"a": "hello", "b": [1, {"c": true}], "d": null}"#;
+        let path = |s: &str| JsonPath::parse(s).unwrap();
+
+        assert_eq!(
+            Some(r#""hello""#.to_string()),
+            extract(doc, &path("a"), false).unwrap()
+        );
+        assert_eq!(
+            Some("hello".to_string()),
+            extract(doc, &path("a"), true).unwrap()
+        );
+        assert_eq!(
+            Some(r#"{"c":true}"#.to_string()),
+            extract(doc, &path("$.b[1]"), true).unwrap()
+        );
+        assert_eq!(
+            Some("null".to_string()),
+            extract(doc, &path("d"), false).unwrap()
+        );
+        assert_eq!(None, extract(doc, &path("d"), true).unwrap());
+        assert_eq!(None, extract(doc, &path("missing"), false).unwrap());
+
+        extract("{", &path("a"), false).unwrap_err();
+    }
+}
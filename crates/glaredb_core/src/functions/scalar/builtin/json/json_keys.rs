@@ -0,0 +1,103 @@
+use glaredb_error::Result;
+use serde_json::Value;
+
+use super::path::{JsonPath, parse_json};
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+use crate::util::iter::IntoExactSizeIterator;
+
+pub const FUNCTION_SET_JSON_KEYS: ScalarFunctionSet = ScalarFunctionSet {
+    name: "json_keys",
+    aliases: &[],
+    doc: &[
+        &Documentation {
+            category: Category::Json,
+            description: "Get the keys of a JSON object as a list. Returns an empty list if the value is not an object.",
+            arguments: &["json"],
+            example: Some(Example {
+                example: r#"json_keys('{"a": 1, "b": 2}')"#,
+                output: "[a, b]",
+            }),
+        },
+        &Documentation {
+            category: Category::Json,
+            description: "Get the keys of a JSON object at the given path as a list. Returns NULL if the value doesn't exist.",
+            arguments: &["json", "path"],
+            example: Some(Example {
+                example: r#"json_keys('{"a": {"b": 1}}', 'a')"#,
+                output: "[b]",
+            }),
+        },
+    ],
+    functions: &[
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8], DataTypeId::List),
+            &JsonKeys,
+        ),
+        RawScalarFunction::new(
+            &Signature::new(&[DataTypeId::Utf8, DataTypeId::Utf8], DataTypeId::List),
+            &JsonKeys,
+        ),
+    ],
+};
+
+/// Get the object keys for the value at `path`, in document order.
+fn object_keys(json: &str, path: &JsonPath) -> Result<Option<Vec<String>>> {
+    let doc = parse_json(json)?;
+    Ok(path.lookup(&doc).map(|value| match value {
+        Value::Object(obj) => obj.keys().cloned().collect(),
+        _ => Vec::new(),
+    }))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsonKeys;
+
+impl ScalarFunction for JsonKeys {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::list(DataType::utf8()),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let arrays = input.arrays();
+
+        for (output_idx, input_idx) in sel.into_exact_size_iter().enumerate() {
+            let json = arrays[0].get_value(input_idx)?;
+            let path = match arrays.get(1) {
+                Some(path) => path.get_value(input_idx)?,
+                None => ScalarValue::Utf8("$".into()),
+            };
+            if json.is_null() || path.is_null() {
+                output.set_value(output_idx, &ScalarValue::Null)?;
+                continue;
+            }
+
+            let path = JsonPath::parse(path.try_as_str()?)?;
+            let value = match object_keys(json.try_as_str()?, &path)? {
+                Some(keys) => ScalarValue::List(
+                    keys.into_iter()
+                        .map(|k| ScalarValue::Utf8(k.into()))
+                        .collect(),
+                ),
+                None => ScalarValue::Null,
+            };
+            output.set_value(output_idx, &value)?;
+        }
+
+        Ok(())
+    }
+}
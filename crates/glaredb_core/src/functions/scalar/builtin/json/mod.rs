@@ -0,0 +1,13 @@
+mod json_array_length;
+pub use json_array_length::*;
+
+mod json_extract;
+pub use json_extract::*;
+
+mod json_keys;
+pub use json_keys::*;
+
+mod path;
+
+mod to_json;
+pub use to_json::*;
@@ -0,0 +1,176 @@
+use glaredb_error::{DbError, Result};
+use serde_json::Value;
+
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalUtf8, ScalarStorage};
+
+/// Parse a string as a JSON document.
+pub fn parse_json(s: &str) -> Result<Value> {
+    serde_json::from_str(s).map_err(|e| DbError::with_source("Failed to parse JSON", Box::new(e)))
+}
+
+/// A single step in a JSON path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathElement {
+    /// Get a value from an object.
+    Key(String),
+    /// Get a value from an array. Negative indices count from the end.
+    Index(i64),
+}
+
+/// Path for navigating to a value inside a JSON document.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsonPath {
+    elements: Vec<PathElement>,
+}
+
+impl JsonPath {
+    /// Parse a path string.
+    ///
+    /// Strings starting with '$' are parsed as JSONPath expressions supporting
+    /// `.key`, `."quoted key"`, `['key']`, and `[index]` steps. Any other
+    /// string is used as a single object key.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut rest = match s.strip_prefix('$') {
+            Some(rest) => rest,
+            None => {
+                return Ok(JsonPath {
+                    elements: vec![PathElement::Key(s.to_string())],
+                });
+            }
+        };
+
+        let invalid = || DbError::new(format!("Invalid JSON path: '{s}'"));
+
+        let mut elements = Vec::new();
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('.') {
+                if let Some(r) = r.strip_prefix('"') {
+                    let end = r.find('"').ok_or_else(invalid)?;
+                    elements.push(PathElement::Key(r[..end].to_string()));
+                    rest = &r[end + 1..];
+                } else {
+                    let end = r.find(['.', '[']).unwrap_or(r.len());
+                    let key = &r[..end];
+                    if key.is_empty() || key == "*" {
+                        return Err(invalid());
+                    }
+                    elements.push(PathElement::Key(key.to_string()));
+                    rest = &r[end..];
+                }
+            } else if let Some(r) = rest.strip_prefix('[') {
+                let end = r.find(']').ok_or_else(invalid)?;
+                let inner = r[..end].trim();
+                let quoted = inner.len() >= 2
+                    && ((inner.starts_with('\'') && inner.ends_with('\''))
+                        || (inner.starts_with('"') && inner.ends_with('"')));
+                if quoted {
+                    elements.push(PathElement::Key(inner[1..inner.len() - 1].to_string()));
+                } else {
+                    let idx = inner.parse::<i64>().map_err(|_| invalid())?;
+                    elements.push(PathElement::Index(idx));
+                }
+                rest = &r[end + 1..];
+            } else {
+                return Err(invalid());
+            }
+        }
+
+        Ok(JsonPath { elements })
+    }
+
+    /// Create a path for getting a single element from an array.
+    pub fn index(idx: i64) -> Self {
+        JsonPath {
+            elements: vec![PathElement::Index(idx)],
+        }
+    }
+
+    /// Navigate to the value for this path.
+    ///
+    /// Returns None if the value doesn't exist.
+    pub fn lookup<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.elements
+            .iter()
+            .try_fold(value, |value, element| match (element, value) {
+                (PathElement::Key(key), Value::Object(obj)) => obj.get(key),
+                (PathElement::Index(idx), Value::Array(arr)) => {
+                    let idx = if *idx < 0 {
+                        arr.len().checked_sub(idx.unsigned_abs() as usize)?
+                    } else {
+                        *idx as usize
+                    };
+                    arr.get(idx)
+                }
+                _ => None,
+            })
+    }
+}
+
+/// Physical types that can be used for a path argument.
+pub trait PathStorage: ScalarStorage {
+    fn to_path(v: &Self::StorageType) -> Result<JsonPath>;
+}
+
+impl PathStorage for PhysicalUtf8 {
+    fn to_path(v: &Self::StorageType) -> Result<JsonPath> {
+        JsonPath::parse(v)
+    }
+}
+
+impl PathStorage for PhysicalI64 {
+    fn to_path(v: &Self::StorageType) -> Result<JsonPath> {
+        Ok(JsonPath::index(*v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_paths() {
+        assert_eq!(
+            vec![PathElement::Key("a.b".to_string())],
+            JsonPath::parse("a.b").unwrap().elements,
+        );
+        assert_eq!(
+            Vec::<PathElement>::new(),
+            JsonPath::parse("$").unwrap().elements
+        );
+        assert_eq!(
+            vec![
+                PathElement::Key("a".to_string()),
+                PathElement::Index(2),
+                PathElement::Key("b c".to_string()),
+                PathElement::Key("d".to_string()),
+                PathElement::Index(-1),
+            ],
+            JsonPath::parse("$.a[2].\"b c\"['d'][-1]").unwrap().elements,
+        );
+
+        JsonPath::parse("$a").unwrap_err();
+        JsonPath::parse("$.").unwrap_err();
+        JsonPath::parse("$.a[").unwrap_err();
+        JsonPath::parse("$[x]").unwrap_err();
+        JsonPath::parse("$.*").unwrap_err();
+    }
+
+    #[test]
+    fn lookup_values() {
+        let doc = json!({"a": {"b": [1, 2, 3]}, "c": null});
+
+        let get = |path: &str| JsonPath::parse(path).unwrap().lookup(&doc).cloned();
+
+        assert_eq!(Some(json!([1, 2, 3])), get("$.a.b"));
+        assert_eq!(Some(json!(2)), get("$.a.b[1]"));
+        assert_eq!(Some(json!(3)), get("$.a.b[-1]"));
+        assert_eq!(Some(json!(null)), get("c"));
+        assert_eq!(None, get("$.a.b[3]"));
+        assert_eq!(None, get("$.a.b[-4]"));
+        assert_eq!(None, get("$.a.x"));
+        assert_eq!(None, get("$.c.d"));
+        assert_eq!(Some(doc.clone()), get("$"));
+    }
+}
@@ -0,0 +1,158 @@
+use glaredb_error::Result;
+use serde_json::{Map, Value};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{AddressableMut, MutableScalarStorage, PhysicalUtf8};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::scalar::BorrowedScalarValue;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+use crate::util::iter::IntoExactSizeIterator;
+
+pub const FUNCTION_SET_TO_JSON: ScalarFunctionSet = ScalarFunctionSet {
+    name: "to_json",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Json,
+        description: "Convert a value to a JSON string. Lists become arrays, maps become objects, and temporal values and UUIDs become strings.",
+        arguments: &["value"],
+        example: Some(Example {
+            example: "to_json(map(['a', 'b'], [1, 2]))",
+            output: r#"{"a":1,"b":2}"#,
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Any], DataTypeId::Utf8),
+        &ToJson,
+    )],
+};
+
+/// Convert a scalar value to a JSON value.
+///
+/// Numbers that can't be represented exactly in JSON (e.g. decimals) are
+/// converted to floats.
+fn scalar_to_json(value: &BorrowedScalarValue, datatype: &DataType) -> Result<Value> {
+    Ok(match value {
+        BorrowedScalarValue::Null => Value::Null,
+        BorrowedScalarValue::Boolean(v) => Value::from(*v),
+        BorrowedScalarValue::Int8(v) => Value::from(*v),
+        BorrowedScalarValue::Int16(v) => Value::from(*v),
+        BorrowedScalarValue::Int32(v) => Value::from(*v),
+        BorrowedScalarValue::Int64(v) => Value::from(*v),
+        BorrowedScalarValue::UInt8(v) => Value::from(*v),
+        BorrowedScalarValue::UInt16(v) => Value::from(*v),
+        BorrowedScalarValue::UInt32(v) => Value::from(*v),
+        BorrowedScalarValue::UInt64(v) => Value::from(*v),
+        BorrowedScalarValue::Int128(v) => match i64::try_from(*v) {
+            Ok(v) => Value::from(v),
+            Err(_) => Value::from(*v as f64),
+        },
+        BorrowedScalarValue::UInt128(v) => match u64::try_from(*v) {
+            Ok(v) => Value::from(v),
+            Err(_) => Value::from(*v as f64),
+        },
+        BorrowedScalarValue::Float16(v) => Value::from(f64::from(*v)),
+        BorrowedScalarValue::Float32(v) => Value::from(*v),
+        BorrowedScalarValue::Float64(v) => Value::from(*v),
+        BorrowedScalarValue::Decimal64(_) | BorrowedScalarValue::Decimal128(_) => {
+            Value::from(value.to_string().parse::<f64>().unwrap_or(f64::NAN))
+        }
+        BorrowedScalarValue::Utf8(v) => Value::from(v.as_ref()),
+        BorrowedScalarValue::Binary(v) => Value::from(String::from_utf8_lossy(v)),
+        BorrowedScalarValue::Date32(_)
+        | BorrowedScalarValue::Date64(_)
+        | BorrowedScalarValue::Time(_)
+        | BorrowedScalarValue::Timestamp(_)
+        | BorrowedScalarValue::Interval(_)
+        | BorrowedScalarValue::Uuid(_) => Value::from(value.to_string()),
+        BorrowedScalarValue::List(values) => {
+            let child = &datatype.try_get_list_type_meta()?.datatype;
+            Value::Array(
+                values
+                    .iter()
+                    .map(|v| scalar_to_json(v, child))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        BorrowedScalarValue::Struct(values) => {
+            let fields = &datatype.try_get_struct_type_meta()?.fields;
+            let mut obj = Map::with_capacity(values.len());
+            for (field, v) in fields.iter().zip(values) {
+                obj.insert(field.name.clone(), scalar_to_json(v, &field.datatype)?);
+            }
+            Value::Object(obj)
+        }
+        BorrowedScalarValue::Map(entries) => {
+            let m = datatype.try_get_map_type_meta()?;
+            let mut obj = Map::with_capacity(entries.len());
+            for (k, v) in entries {
+                obj.insert(k.to_string(), scalar_to_json(v, &m.value)?);
+            }
+            Value::Object(obj)
+        }
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ToJson;
+
+impl ScalarFunction for ToJson {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::utf8(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let src = &input.arrays()[0];
+
+        let (data, validity) = output.data_and_validity_mut();
+        let mut strings = PhysicalUtf8::get_addressable_mut(data)?;
+
+        for (output_idx, input_idx) in sel.into_exact_size_iter().enumerate() {
+            let value = src.get_value(input_idx)?;
+            if value.is_null() {
+                validity.set_invalid(output_idx);
+                continue;
+            }
+
+            let json = scalar_to_json(&value, src.datatype())?;
+            strings.put(output_idx, json.to_string().as_str());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::scalar::ScalarValue;
+
+    #[test]
+    fn nested_list_to_json() {
+        let datatype = DataType::list(DataType::list(DataType::int32()));
+        let value = ScalarValue::List(vec![
+            ScalarValue::List(vec![ScalarValue::Int32(1), ScalarValue::Null]),
+            ScalarValue::List(vec![]),
+        ]);
+
+        let json = scalar_to_json(&value, &datatype).unwrap();
+        assert_eq!("[[1,null],[]]", json.to_string());
+    }
+
+    #[test]
+    fn string_escaped() {
+        let json = scalar_to_json(&ScalarValue::from("a\"b"), &DataType::utf8()).unwrap();
+        assert_eq!(r#""a\"b""#, json.to_string());
+    }
+}
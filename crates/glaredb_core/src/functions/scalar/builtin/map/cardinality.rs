@@ -0,0 +1,58 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{PhysicalI64, PhysicalMap};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::OutBuffer;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_CARDINALITY: ScalarFunctionSet = ScalarFunctionSet {
+    name: "cardinality",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Map,
+        description: "Get the number of entries in a map.",
+        arguments: &["map"],
+        example: Some(Example {
+            example: "cardinality(map(['a', 'b'], [1, 2]))",
+            output: "2",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Map], DataTypeId::Int64),
+        &Cardinality,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Cardinality;
+
+impl ScalarFunction for Cardinality {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let input = &input.arrays()[0];
+
+        UnaryExecutor::execute::<PhysicalMap, PhysicalI64, _>(
+            input,
+            sel,
+            OutBuffer::from_array(output)?,
+            |meta, buf| buf.put(&(meta.len as i64)),
+        )
+    }
+}
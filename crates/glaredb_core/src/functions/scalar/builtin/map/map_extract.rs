@@ -0,0 +1,61 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::map_extract::map_extract;
+use crate::arrays::datatype::DataTypeId;
+use crate::expr::{self, Expression};
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_MAP_EXTRACT: ScalarFunctionSet = ScalarFunctionSet {
+    name: "map_extract",
+    aliases: &["element_at"],
+    doc: &[&Documentation {
+        category: Category::Map,
+        description: "Get the value for a key from a map. Returns NULL if the key doesn't exist.",
+        arguments: &["map", "key"],
+        example: Some(Example {
+            example: "map_extract(map(['a', 'b'], [1, 2]), 'b')",
+            output: "2",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Map, DataTypeId::Any], DataTypeId::Any),
+        &MapExtract,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MapExtract;
+
+impl ScalarFunction for MapExtract {
+    type State = ();
+
+    fn bind(&self, mut inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let map_type = inputs[0].datatype()?;
+        let map_meta = map_type.try_get_map_type_meta()?;
+
+        // Ensure the key we're searching for is the same type as the map's
+        // keys.
+        if inputs[1].datatype()? != *map_meta.key {
+            let key = inputs.pop().unwrap();
+            inputs.push(expr::cast(key, map_meta.key.as_ref().clone())?.into());
+        }
+
+        Ok(BindState {
+            state: (),
+            return_type: map_meta.value.as_ref().clone(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let map = &input.arrays()[0];
+        let keys = &input.arrays()[1];
+        map_extract(map, keys, sel, output)
+    }
+}
@@ -0,0 +1,81 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::map_entries::{MapEntryPart, map_entries_to_list};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_MAP_KEYS: ScalarFunctionSet = ScalarFunctionSet {
+    name: "map_keys",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Map,
+        description: "Get a list of all keys in a map.",
+        arguments: &["map"],
+        example: Some(Example {
+            example: "map_keys(map(['a', 'b'], [1, 2]))",
+            output: "[a, b]",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Map], DataTypeId::List),
+        &MapEntries {
+            part: MapEntryPart::Keys,
+        },
+    )],
+};
+
+pub const FUNCTION_SET_MAP_VALUES: ScalarFunctionSet = ScalarFunctionSet {
+    name: "map_values",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Map,
+        description: "Get a list of all values in a map.",
+        arguments: &["map"],
+        example: Some(Example {
+            example: "map_values(map(['a', 'b'], [1, 2]))",
+            output: "[1, 2]",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::Map], DataTypeId::List),
+        &MapEntries {
+            part: MapEntryPart::Values,
+        },
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MapEntries {
+    part: MapEntryPart,
+}
+
+impl ScalarFunction for MapEntries {
+    type State = MapEntryPart;
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let map_type = inputs[0].datatype()?;
+        let map_meta = map_type.try_get_map_type_meta()?;
+        let child_type = match self.part {
+            MapEntryPart::Keys => map_meta.key.as_ref().clone(),
+            MapEntryPart::Values => map_meta.value.as_ref().clone(),
+        };
+
+        Ok(BindState {
+            state: self.part,
+            return_type: DataType::list(child_type),
+            inputs,
+        })
+    }
+
+    fn execute(state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let map = &input.arrays()[0];
+        map_entries_to_list(map, *state, sel, output)
+    }
+}
@@ -0,0 +1,54 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::make_map::make_map;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_MAP: ScalarFunctionSet = ScalarFunctionSet {
+    name: "map",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Map,
+        description: "Create a map from a list of keys and a list of values. Both lists must be the same length, and keys cannot be NULL.",
+        arguments: &["keys", "values"],
+        example: Some(Example {
+            example: "map(['a', 'b'], [1, 2])",
+            output: "{a=1, b=2}",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[DataTypeId::List, DataTypeId::List], DataTypeId::Map),
+        &MapValue,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MapValue;
+
+impl ScalarFunction for MapValue {
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        let key_type = inputs[0].datatype()?.try_get_list_type_meta()?.datatype.as_ref().clone();
+        let value_type = inputs[1].datatype()?.try_get_list_type_meta()?.datatype.as_ref().clone();
+
+        Ok(BindState {
+            state: (),
+            return_type: DataType::map(key_type, value_type),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        let keys = &input.arrays()[0];
+        let values = &input.arrays()[1];
+        make_map(keys, values, sel, output)
+    }
+}
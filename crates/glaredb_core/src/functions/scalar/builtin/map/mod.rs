@@ -0,0 +1,11 @@
+mod cardinality;
+pub use cardinality::*;
+
+mod map_extract;
+pub use map_extract::*;
+
+mod map_keys;
+pub use map_keys::*;
+
+mod map_value;
+pub use map_value::*;
@@ -5,13 +5,16 @@ pub mod comparison;
 pub mod datetime;
 pub mod debug;
 pub mod is;
+pub mod json;
 pub mod list;
+pub mod map;
 pub mod negate;
 pub mod numeric;
 pub mod random;
 pub mod similarity;
 pub mod string;
 pub mod struct_funcs;
+pub mod uuid_funcs;
 
 mod not_implemented;
 
@@ -35,10 +38,23 @@ use comparison::{
     FUNCTION_SET_NEQ,
 };
 use datetime::{
+    FUNCTION_SET_AGE,
+    FUNCTION_SET_CURRENT_DATE,
+    FUNCTION_SET_CURRENT_TIME,
+    FUNCTION_SET_DATE_ADD,
+    FUNCTION_SET_DATE_DIFF,
     FUNCTION_SET_DATE_PART,
     FUNCTION_SET_DATE_TRUNC,
     FUNCTION_SET_EPOCH,
     FUNCTION_SET_EPOCH_MS,
+    FUNCTION_SET_LAST_DAY,
+    FUNCTION_SET_MAKE_DATE,
+    FUNCTION_SET_MAKE_TIME,
+    FUNCTION_SET_MAKE_TIMESTAMP,
+    FUNCTION_SET_NOW,
+    FUNCTION_SET_STRFTIME,
+    FUNCTION_SET_STRPTIME,
+    FUNCTION_SET_TIMEZONE,
 };
 use debug::FUNCTION_SET_DEBUG_ERROR_ON_EXECUTE;
 use is::{
@@ -49,7 +65,21 @@ use is::{
     FUNCTION_SET_IS_NULL,
     FUNCTION_SET_IS_TRUE,
 };
+use json::{
+    FUNCTION_SET_JSON_ARRAY_LENGTH,
+    FUNCTION_SET_JSON_EXTRACT,
+    FUNCTION_SET_JSON_EXTRACT_STRING,
+    FUNCTION_SET_JSON_KEYS,
+    FUNCTION_SET_TO_JSON,
+};
 use list::{FUNCTION_SET_LIST_EXTRACT, FUNCTION_SET_LIST_VALUE};
+use map::{
+    FUNCTION_SET_CARDINALITY,
+    FUNCTION_SET_MAP,
+    FUNCTION_SET_MAP_EXTRACT,
+    FUNCTION_SET_MAP_KEYS,
+    FUNCTION_SET_MAP_VALUES,
+};
 use negate::{FUNCTION_SET_NEGATE, FUNCTION_SET_NOT};
 use numeric::{
     FUNCTION_SET_ABS,
@@ -118,6 +148,7 @@ use string::{
     FUNCTION_SET_UPPER,
 };
 use struct_funcs::{FUNCTION_SET_STRUCT_EXTRACT, FUNCTION_SET_STRUCT_PACK};
+use uuid_funcs::FUNCTION_SET_GEN_RANDOM_UUID;
 
 use crate::functions::function_set::ScalarFunctionSet;
 
@@ -214,14 +245,40 @@ pub const BUILTIN_SCALAR_FUNCTION_SETS: &[ScalarFunctionSet] = &[
     FUNCTION_SET_NOT,
     // Random
     FUNCTION_SET_RANDOM,
+    FUNCTION_SET_GEN_RANDOM_UUID,
     // List
     FUNCTION_SET_LIST_VALUE,
     FUNCTION_SET_LIST_EXTRACT,
+    // Map
+    FUNCTION_SET_MAP,
+    FUNCTION_SET_MAP_EXTRACT,
+    FUNCTION_SET_MAP_KEYS,
+    FUNCTION_SET_MAP_VALUES,
+    FUNCTION_SET_CARDINALITY,
+    // JSON
+    FUNCTION_SET_JSON_EXTRACT,
+    FUNCTION_SET_JSON_EXTRACT_STRING,
+    FUNCTION_SET_JSON_ARRAY_LENGTH,
+    FUNCTION_SET_JSON_KEYS,
+    FUNCTION_SET_TO_JSON,
     // Date/time
     FUNCTION_SET_DATE_PART,
     FUNCTION_SET_DATE_TRUNC,
     FUNCTION_SET_EPOCH,
     FUNCTION_SET_EPOCH_MS,
+    FUNCTION_SET_TIMEZONE,
+    FUNCTION_SET_NOW,
+    FUNCTION_SET_CURRENT_DATE,
+    FUNCTION_SET_CURRENT_TIME,
+    FUNCTION_SET_MAKE_DATE,
+    FUNCTION_SET_MAKE_TIME,
+    FUNCTION_SET_MAKE_TIMESTAMP,
+    FUNCTION_SET_STRFTIME,
+    FUNCTION_SET_STRPTIME,
+    FUNCTION_SET_AGE,
+    FUNCTION_SET_DATE_DIFF,
+    FUNCTION_SET_DATE_ADD,
+    FUNCTION_SET_LAST_DAY,
     // Is
     FUNCTION_SET_IS_NULL,
     FUNCTION_SET_IS_NOT_NULL,
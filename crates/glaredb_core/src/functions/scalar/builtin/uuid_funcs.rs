@@ -0,0 +1,54 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::PhysicalU128;
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::ScalarFunctionSet;
+use crate::functions::scalar::{BindState, FunctionVolatility, RawScalarFunction, ScalarFunction};
+
+pub const FUNCTION_SET_GEN_RANDOM_UUID: ScalarFunctionSet = ScalarFunctionSet {
+    name: "gen_random_uuid",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Uuid,
+        description: "Return a random (version 4) UUID.",
+        arguments: &[],
+        example: Some(Example {
+            example: "gen_random_uuid()",
+            output: "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+        }),
+    }],
+    functions: &[RawScalarFunction::new(
+        &Signature::new(&[], DataTypeId::Uuid),
+        &GenRandomUuid,
+    )],
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenRandomUuid;
+
+impl ScalarFunction for GenRandomUuid {
+    const VOLATILITY: FunctionVolatility = FunctionVolatility::Volatile;
+
+    type State = ();
+
+    fn bind(&self, inputs: Vec<Expression>) -> Result<BindState<Self::State>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::uuid(),
+            inputs,
+        })
+    }
+
+    fn execute(_state: &Self::State, input: &Batch, output: &mut Array) -> Result<()> {
+        let sel = input.selection();
+        UnaryExecutor::execute_in_place::<PhysicalU128, _>(output, sel, |v| {
+            *v = uuid::Uuid::new_v4().as_u128()
+        })
+    }
+}
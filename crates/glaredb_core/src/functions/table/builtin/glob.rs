@@ -80,6 +80,7 @@ impl TableScanFunction for Glob {
             data_schema: ColumnSchema::new([Field::new("filename", DataType::utf8(), false)]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -77,6 +77,7 @@ impl TableScanFunction for ListDatabases {
             ]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -13,7 +13,6 @@ use crate::arrays::field::{ColumnSchema, Field};
 use crate::arrays::scalar::ScalarValue;
 use crate::catalog::database::Database;
 use crate::catalog::entry::{CatalogEntry, CatalogEntryInner, CatalogEntryType};
-use crate::catalog::{Catalog, Schema};
 use crate::execution::operators::{ExecutionProperties, PollPull};
 use crate::functions::Signature;
 use crate::functions::documentation::{Category, Documentation};
@@ -23,6 +22,7 @@ use crate::functions::table::{RawTableFunction, TableFunctionBindState, TableFun
 use crate::statistics::value::StatisticsValue;
 use crate::storage::projections::{ProjectedColumn, Projections};
 use crate::storage::scan_filter::PhysicalScanFilter;
+use crate::transaction::Transaction;
 
 pub const FUNCTION_SET_LIST_TABLES: TableFunctionSet = TableFunctionSet {
     name: "list_tables",
@@ -79,6 +79,7 @@ impl ListEntriesBindState {
     // easiest for now. `poll_pull` could handle this, but it'd introduce a bit
     // of code complexity.
     async fn load_entries(
+        tx: &Transaction,
         databases: Vec<Arc<Database>>,
         db_filter: impl Fn(&Database) -> bool,
         schema_filter: impl Fn(&CatalogEntry) -> bool,
@@ -91,7 +92,7 @@ impl ListEntriesBindState {
             }
 
             let db_name: Arc<str> = database.name.clone().into();
-            let mut schema_stream = Box::pin(database.catalog.list_schemas());
+            let mut schema_stream = database.catalog.list_schemas(tx);
 
             while let Some(schemas) = schema_stream.try_next().await? {
                 for schema in schemas {
@@ -100,7 +101,7 @@ impl ListEntriesBindState {
                     }
 
                     let schema_name: Arc<str> = schema.as_entry().name.clone().into();
-                    let mut entry_stream = Box::pin(schema.list_entries());
+                    let mut entry_stream = schema.list_entries(tx);
 
                     while let Some(entries) = entry_stream.try_next().await? {
                         let entries = entries.into_iter().filter_map(|ent| {
@@ -165,6 +166,7 @@ impl TableScanFunction for ListTables {
             .cloned()
             .collect();
         let state = ListEntriesBindState::load_entries(
+            scan_context.database_context.transaction(),
             databases,
             |_| true,
             |_| true,
@@ -182,6 +184,7 @@ impl TableScanFunction for ListTables {
             ]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -281,6 +284,7 @@ impl TableScanFunction for ListViews {
             .cloned()
             .collect();
         let state = ListEntriesBindState::load_entries(
+            scan_context.database_context.transaction(),
             databases,
             |_| true,
             |_| true,
@@ -298,6 +302,7 @@ impl TableScanFunction for ListViews {
             ]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -412,6 +417,7 @@ impl ListFunctions {
         Ok(match ent.entry_type() {
             CatalogEntryType::ScalarFunction => "scalar",
             CatalogEntryType::AggregateFunction => "aggregate",
+            CatalogEntryType::WindowFunction => "window",
             CatalogEntryType::TableFunction => "table",
             CatalogEntryType::CopyToFunction => "copy",
             other => return Err(DbError::new(format!("Unexpected entry type: {other}"))),
@@ -436,6 +442,7 @@ impl TableScanFunction for ListFunctions {
             .collect();
         // TODO: Do we want COPY functions in the output? Not yet
         let state = ListEntriesBindState::load_entries(
+            scan_context.database_context.transaction(),
             databases,
             |_| true,
             |_| true,
@@ -445,6 +452,7 @@ impl TableScanFunction for ListFunctions {
                     CatalogEntryType::TableFunction
                         | CatalogEntryType::ScalarFunction
                         | CatalogEntryType::AggregateFunction
+                        | CatalogEntryType::WindowFunction
                 )
             },
         )
@@ -468,6 +476,7 @@ impl TableScanFunction for ListFunctions {
             ]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -515,6 +524,15 @@ impl TableScanFunction for ListFunctions {
                             });
                         }
                     }
+                    CatalogEntryInner::WindowFunction(f) => {
+                        for sig in f.function.functions.iter().map(|f| f.signature()) {
+                            states[part_idx].entries.push(NamespacedFunction {
+                                entry: entry.clone(),
+                                signature: sig,
+                                doc: get_doc_for_arity(f.function.doc, sig.positional_args.len()),
+                            });
+                        }
+                    }
                     CatalogEntryInner::TableFunction(f) => {
                         for sig in f.function.functions.iter().map(|f| f.signature()) {
                             states[part_idx].entries.push(NamespacedFunction {
@@ -1,8 +1,7 @@
-use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
-use futures::{Stream, StreamExt};
+use futures::StreamExt;
 use glaredb_error::Result;
 
 use crate::arrays::array::physical_type::{AddressableMut, MutableScalarStorage, PhysicalUtf8};
@@ -10,8 +9,7 @@ use crate::arrays::batch::Batch;
 use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::field::{ColumnSchema, Field};
 use crate::catalog::database::Database;
-use crate::catalog::memory::MemorySchema;
-use crate::catalog::{Catalog, Schema};
+use crate::catalog::{CatalogStream, Schema};
 use crate::execution::operators::{ExecutionProperties, PollPull};
 use crate::functions::Signature;
 use crate::functions::documentation::{Category, Documentation};
@@ -21,6 +19,7 @@ use crate::functions::table::{RawTableFunction, TableFunctionBindState, TableFun
 use crate::statistics::value::StatisticsValue;
 use crate::storage::projections::{ProjectedColumn, Projections};
 use crate::storage::scan_filter::PhysicalScanFilter;
+use crate::transaction::Transaction;
 
 pub const FUNCTION_SET_LIST_SCHEMAS: TableFunctionSet = TableFunctionSet {
     name: "list_schemas",
@@ -38,23 +37,24 @@ pub const FUNCTION_SET_LIST_SCHEMAS: TableFunctionSet = TableFunctionSet {
 };
 
 pub struct ListSchemasBindState {
+    transaction: Arc<Transaction>,
     databases: Vec<Arc<Database>>,
 }
 
 pub struct ListSchemasOperatorState {
     projections: Projections,
+    transaction: Arc<Transaction>,
     databases: Vec<Arc<Database>>,
 }
 
 // TODO: Make simpler.
-type SchemaStream = Pin<Box<dyn Stream<Item = Result<Vec<Arc<MemorySchema>>>> + Sync + Send>>;
 
 pub struct ListSchemasPartitionState {
     db_offset: usize,
     databases: Vec<Arc<Database>>,
-    curr_stream: Option<SchemaStream>,
+    curr_stream: Option<CatalogStream<Arc<dyn Schema>>>,
     schemas_offset: usize,
-    schemas: Vec<Arc<MemorySchema>>,
+    schemas: Vec<Arc<dyn Schema>>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -76,7 +76,10 @@ impl TableScanFunction for ListSchemas {
             .cloned()
             .collect();
         Ok(TableFunctionBindState {
-            state: ListSchemasBindState { databases },
+            state: ListSchemasBindState {
+                transaction: scan_context.database_context.transaction().clone(),
+                databases,
+            },
             input,
             data_schema: ColumnSchema::new([
                 Field::new("database_name", DataType::utf8(), false),
@@ -84,6 +87,7 @@ impl TableScanFunction for ListSchemas {
             ]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -95,6 +99,7 @@ impl TableScanFunction for ListSchemas {
     ) -> Result<Self::OperatorState> {
         Ok(ListSchemasOperatorState {
             projections,
+            transaction: bind_state.transaction.clone(),
             databases: bind_state.databases.clone(),
         })
     }
@@ -136,8 +141,10 @@ impl TableScanFunction for ListSchemas {
                     return Ok(PollPull::Exhausted);
                 }
 
-                let stream = state.databases[state.db_offset].catalog.list_schemas();
-                state.curr_stream = Some(Box::pin(stream));
+                let stream = state.databases[state.db_offset]
+                    .catalog
+                    .list_schemas(&op_state.transaction);
+                state.curr_stream = Some(stream);
             }
 
             // Stream should be Some here.
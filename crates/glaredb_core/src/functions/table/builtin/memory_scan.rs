@@ -4,19 +4,18 @@ use std::task::Context;
 use glaredb_error::Result;
 
 use crate::arrays::batch::Batch;
-use crate::arrays::datatype::DataTypeId;
-use crate::arrays::field::ColumnSchema;
-use crate::catalog::{Catalog, Schema};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::field::{ColumnSchema, Field};
 use crate::execution::operators::{ExecutionProperties, PollPull};
 use crate::functions::Signature;
 use crate::functions::documentation::{Category, Documentation};
 use crate::functions::function_set::TableFunctionSet;
 use crate::functions::table::scan::{ScanContext, TableScanFunction};
 use crate::functions::table::{RawTableFunction, TableFunctionBindState, TableFunctionInput};
-use crate::statistics::value::StatisticsValue;
-use crate::storage::datatable::{DataTable, ParallelDataTableScanState};
+use crate::storage::datatable::{DataTable, ParallelDataTableScanState, ROWID_COLUMN_NAME};
 use crate::storage::projections::Projections;
 use crate::storage::scan_filter::PhysicalScanFilter;
+use crate::transaction::Snapshot;
 
 pub const FUNCTION_SET_MEMORY_SCAN: TableFunctionSet = TableFunctionSet {
     name: "memory_scan",
@@ -39,12 +38,14 @@ pub const FUNCTION_SET_MEMORY_SCAN: TableFunctionSet = TableFunctionSet {
 #[derive(Debug)]
 pub struct MemoryScanBindState {
     table: Arc<DataTable>,
+    snapshot: Snapshot,
 }
 
 #[derive(Debug)]
 pub struct MemoryScanOperatorState {
     projections: Projections,
     table: Arc<DataTable>,
+    snapshot: Snapshot,
 }
 
 #[derive(Debug)]
@@ -84,20 +85,31 @@ impl TableScanFunction for MemoryScan {
             .database_context
             .require_get_database(&catalog)?;
 
+        let tx = scan_context.database_context.transaction();
         let ent = database
             .catalog
-            .require_get_schema(&schema)?
-            .require_get_table(&table)?;
+            .require_get_schema(tx, &schema)?
+            .require_get_table(tx, &table)?;
 
         let ent = ent.try_as_table_entry()?;
-        let datatable = database.storage.get_table(ent.storage_id)?;
+        let datatable = database.storage.get_table(ent.storage_id()?)?;
+        let cardinality = datatable.estimated_row_count();
+        let column_statistics = datatable.column_statistics();
 
         Ok(TableFunctionBindState {
-            state: MemoryScanBindState { table: datatable },
+            state: MemoryScanBindState {
+                table: datatable,
+                snapshot: tx.snapshot(),
+            },
             input,
             data_schema: ColumnSchema::new(ent.columns.clone()),
-            meta_schema: None,
-            cardinality: StatisticsValue::Unknown, // TODO
+            meta_schema: Some(ColumnSchema::new([Field::new(
+                ROWID_COLUMN_NAME,
+                DataType::int64(),
+                false,
+            )])),
+            cardinality,
+            column_statistics,
         })
     }
 
@@ -110,6 +122,7 @@ impl TableScanFunction for MemoryScan {
         Ok(MemoryScanOperatorState {
             projections,
             table: bind_state.table.clone(),
+            snapshot: bind_state.snapshot,
         })
     }
 
@@ -120,7 +133,7 @@ impl TableScanFunction for MemoryScan {
     ) -> Result<Vec<Self::PartitionState>> {
         let states = op_state
             .table
-            .init_parallel_scan_states(partitions)
+            .init_parallel_scan_states(op_state.snapshot, partitions)
             .map(|state| MemoryScanPartitionState { state })
             .collect();
 
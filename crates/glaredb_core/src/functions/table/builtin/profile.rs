@@ -592,6 +592,7 @@ where
             data_schema: T::column_schema(),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -109,6 +109,7 @@ impl TableScanFunction for ReadText {
             data_schema: ColumnSchema::new([Field::new("content", DataType::utf8(), false)]),
             meta_schema: Some(provider.meta_schema()),
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
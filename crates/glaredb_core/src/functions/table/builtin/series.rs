@@ -138,6 +138,7 @@ impl TableExecuteFunction for GenerateSeriesI64 {
             )]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -68,6 +68,7 @@ impl TableExecuteFunction for UnnestList {
             data_schema: ColumnSchema::new([Field::new("unnest", out_type, true)]),
             meta_schema: None,
             cardinality: StatisticsValue::Unknown,
+            column_statistics: Vec::new(),
         })
     }
 
@@ -20,6 +20,7 @@ use crate::arrays::batch::Batch;
 use crate::arrays::field::ColumnSchema;
 use crate::execution::operators::{ExecutionProperties, PollExecute, PollFinalize, PollPull};
 use crate::expr::Expression;
+use crate::statistics::column::ColumnStatistics;
 use crate::statistics::value::StatisticsValue;
 use crate::storage::projections::Projections;
 use crate::storage::scan_filter::PhysicalScanFilter;
@@ -52,6 +53,7 @@ pub struct RawTableFunctionBindState {
     pub data_schema: ColumnSchema,
     pub meta_schema: Option<ColumnSchema>,
     pub cardinality: StatisticsValue<usize>,
+    pub column_statistics: Vec<ColumnStatistics>,
 }
 
 #[derive(Debug)]
@@ -70,6 +72,10 @@ pub struct TableFunctionBindState<S> {
     pub meta_schema: Option<ColumnSchema>,
     /// Output cardinality.
     pub cardinality: StatisticsValue<usize>,
+    /// Statistics for each column in the data schema.
+    ///
+    /// Empty if the function doesn't provide column statistics.
+    pub column_statistics: Vec<ColumnStatistics>,
 }
 
 #[derive(Debug, Clone)]
@@ -349,6 +355,7 @@ where
                 data_schema: state.data_schema,
                 meta_schema: state.meta_schema,
                 cardinality: state.cardinality,
+                column_statistics: state.column_statistics,
             })
         },
         create_pull_operator_state_fn: |_bind_state, _projections, _filters, _props| {
@@ -422,6 +429,7 @@ where
                     data_schema: state.data_schema,
                     meta_schema: state.meta_schema,
                     cardinality: state.cardinality,
+                    column_statistics: state.column_statistics,
                 })
             }))
         },
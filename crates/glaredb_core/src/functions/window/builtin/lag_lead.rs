@@ -0,0 +1,210 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::datatype::DataTypeId;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::expr::{self, Expression};
+use crate::functions::Signature;
+use crate::functions::bind_state::BindState;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::function_set::WindowFunctionSet;
+use crate::functions::window::{RawWindowFunction, WindowFunction, WindowPartition};
+
+pub const FUNCTION_SET_LAG: WindowFunctionSet = WindowFunctionSet {
+    name: "lag",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Value of the row `offset` rows before the current row in the partition, or `default` if there is no such row. `offset` defaults to 1 and `default` defaults to NULL.",
+        arguments: &["value", "offset", "default"],
+        example: Some(Example {
+            example: "lag(v) OVER (ORDER BY v) FROM (VALUES (1), (2), (3)) t(v)",
+            output: "NULL, 1, 2",
+        }),
+    }],
+    functions: &[
+        RawWindowFunction::new(
+            &Signature::new(&[DataTypeId::Any], DataTypeId::Any),
+            &LagLead::LAG,
+        ),
+        RawWindowFunction::new(
+            &Signature::new(&[DataTypeId::Any, DataTypeId::Int64], DataTypeId::Any),
+            &LagLead::LAG,
+        ),
+        RawWindowFunction::new(
+            &Signature::new(
+                &[DataTypeId::Any, DataTypeId::Int64, DataTypeId::Any],
+                DataTypeId::Any,
+            ),
+            &LagLead::LAG,
+        ),
+    ],
+};
+
+pub const FUNCTION_SET_LEAD: WindowFunctionSet = WindowFunctionSet {
+    name: "lead",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Value of the row `offset` rows after the current row in the partition, or `default` if there is no such row. `offset` defaults to 1 and `default` defaults to NULL.",
+        arguments: &["value", "offset", "default"],
+        example: Some(Example {
+            example: "lead(v) OVER (ORDER BY v) FROM (VALUES (1), (2), (3)) t(v)",
+            output: "2, 3, NULL",
+        }),
+    }],
+    functions: &[
+        RawWindowFunction::new(
+            &Signature::new(&[DataTypeId::Any], DataTypeId::Any),
+            &LagLead::LEAD,
+        ),
+        RawWindowFunction::new(
+            &Signature::new(&[DataTypeId::Any, DataTypeId::Int64], DataTypeId::Any),
+            &LagLead::LEAD,
+        ),
+        RawWindowFunction::new(
+            &Signature::new(
+                &[DataTypeId::Any, DataTypeId::Int64, DataTypeId::Any],
+                DataTypeId::Any,
+            ),
+            &LagLead::LEAD,
+        ),
+    ],
+};
+
+/// Implementation for both `lag` and `lead`.
+///
+/// `lag` is equivalent to `lead` with a negated offset.
+#[derive(Debug, Clone, Copy)]
+pub struct LagLead {
+    lead: bool,
+}
+
+impl LagLead {
+    pub const LAG: Self = LagLead { lead: false };
+    pub const LEAD: Self = LagLead { lead: true };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LagLeadState {
+    lead: bool,
+    ignore_nulls: bool,
+}
+
+impl WindowFunction for LagLead {
+    type BindState = LagLeadState;
+
+    const SUPPORTS_IGNORE_NULLS: bool = true;
+
+    fn bind(
+        &self,
+        mut inputs: Vec<Expression>,
+        ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        let return_type = inputs[0].datatype()?;
+
+        // Default needs to be the same type as the value.
+        if inputs.len() == 3 && inputs[2].datatype()? != return_type {
+            let default = inputs.pop().unwrap();
+            inputs.push(expr::cast(default, return_type.clone())?.into());
+        }
+
+        Ok(BindState {
+            state: LagLeadState {
+                lead: self.lead,
+                ignore_nulls,
+            },
+            return_type,
+            inputs,
+        })
+    }
+
+    fn compute(
+        state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let inputs = partition.inputs();
+        let values = &inputs[0];
+        let rows = partition.rows();
+
+        let mut offsets: Vec<Option<i64>> = Vec::with_capacity(rows.len());
+        match inputs.get(1) {
+            Some(offset) => {
+                UnaryExecutor::for_each_flat::<PhysicalI64, _>(offset, rows.clone(), |_, v| {
+                    offsets.push(v.copied())
+                })?
+            }
+            None => offsets.resize(rows.len(), Some(1)),
+        }
+
+        // Positions of rows with non-null values, only needed when ignoring
+        // nulls.
+        let non_null: Vec<usize> = if state.ignore_nulls {
+            rows.clone()
+                .filter(|&row| values.validity.is_valid(row))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut from_values: Vec<(usize, usize)> = Vec::new();
+        let mut from_default: Vec<(usize, usize)> = Vec::new();
+        let mut nulls: Vec<usize> = Vec::new();
+
+        for (row, offset) in rows.clone().zip(offsets) {
+            let offset = match offset {
+                Some(offset) if state.lead => offset as i128,
+                Some(offset) => -(offset as i128),
+                None => {
+                    nulls.push(row);
+                    continue;
+                }
+            };
+
+            let target = if offset == 0 {
+                Some(row)
+            } else if state.ignore_nulls {
+                if offset > 0 {
+                    // Non-null rows after the current row.
+                    let after = non_null.partition_point(|&r| r <= row);
+                    usize::try_from(offset - 1)
+                        .ok()
+                        .and_then(|n| after.checked_add(n))
+                        .and_then(|idx| non_null.get(idx).copied())
+                } else {
+                    // Non-null rows before the current row.
+                    let before = non_null.partition_point(|&r| r < row);
+                    usize::try_from(-offset)
+                        .ok()
+                        .and_then(|n| before.checked_sub(n))
+                        .map(|idx| non_null[idx])
+                }
+            } else {
+                let target = row as i128 + offset;
+                if target >= rows.start as i128 && target < rows.end as i128 {
+                    Some(target as usize)
+                } else {
+                    None
+                }
+            };
+
+            match target {
+                Some(target) => from_values.push((target, row)),
+                None if inputs.len() == 3 => from_default.push((row, row)),
+                None => nulls.push(row),
+            }
+        }
+
+        values.copy_rows(from_values, output)?;
+        if let Some(default) = inputs.get(2) {
+            default.copy_rows(from_default, output)?;
+        }
+        for row in nulls {
+            output.validity.set_invalid(row);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+pub mod lag_lead;
+pub mod ntile;
+pub mod rank;
+pub mod row_number;
+pub mod value;
+
+use lag_lead::{FUNCTION_SET_LAG, FUNCTION_SET_LEAD};
+use ntile::FUNCTION_SET_NTILE;
+use rank::{
+    FUNCTION_SET_CUME_DIST,
+    FUNCTION_SET_DENSE_RANK,
+    FUNCTION_SET_PERCENT_RANK,
+    FUNCTION_SET_RANK,
+};
+use row_number::FUNCTION_SET_ROW_NUMBER;
+use value::{FUNCTION_SET_FIRST_VALUE, FUNCTION_SET_LAST_VALUE, FUNCTION_SET_NTH_VALUE};
+
+use crate::functions::function_set::WindowFunctionSet;
+
+pub const BUILTIN_WINDOW_FUNCTION_SETS: &[WindowFunctionSet] = &[
+    FUNCTION_SET_ROW_NUMBER,
+    FUNCTION_SET_RANK,
+    FUNCTION_SET_DENSE_RANK,
+    FUNCTION_SET_PERCENT_RANK,
+    FUNCTION_SET_CUME_DIST,
+    FUNCTION_SET_NTILE,
+    FUNCTION_SET_LAG,
+    FUNCTION_SET_LEAD,
+    FUNCTION_SET_FIRST_VALUE,
+    FUNCTION_SET_LAST_VALUE,
+    FUNCTION_SET_NTH_VALUE,
+];
@@ -0,0 +1,135 @@
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{AddressableMut, MutableScalarStorage, PhysicalI64};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::bind_state::BindState;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::function_set::WindowFunctionSet;
+use crate::functions::window::{RawWindowFunction, WindowFunction, WindowPartition};
+
+pub const FUNCTION_SET_NTILE: WindowFunctionSet = WindowFunctionSet {
+    name: "ntile",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Divide the partition into the given number of buckets as equally as possible, returning the bucket number for the current row, counting from 1.",
+        arguments: &["num_buckets"],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[DataTypeId::Int64], DataTypeId::Int64),
+        &Ntile,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ntile;
+
+impl WindowFunction for Ntile {
+    type BindState = ();
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        _ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn compute(
+        _state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let rows = partition.rows();
+
+        // Number of buckets is taken from the first row in the partition.
+        let num_buckets = partition.inputs()[0].get_value(rows.start)?;
+        if num_buckets.is_null() {
+            for row in rows {
+                output.validity.set_invalid(row);
+            }
+            return Ok(());
+        }
+
+        let num_buckets = num_buckets.try_as_i64()?;
+        if num_buckets <= 0 {
+            return Err(DbError::new("Argument of ntile must be greater than zero")
+                .with_field("num_buckets", num_buckets));
+        }
+
+        let num_rows = rows.len();
+        let num_buckets = usize::min(num_buckets as usize, num_rows);
+
+        // The first `num_large` buckets get one extra row.
+        let small = num_rows / num_buckets;
+        let num_large = num_rows % num_buckets;
+        let large_rows = num_large * (small + 1);
+
+        let mut out = PhysicalI64::get_addressable_mut(&mut output.data)?;
+        for row in rows.clone() {
+            let idx = row - rows.start;
+            let bucket = if idx < large_rows {
+                idx / (small + 1)
+            } else {
+                num_large + (idx - large_rows) / small
+            };
+            out.put(row, &((bucket + 1) as i64));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::execution::operators::window::PeerGroup;
+    use crate::execution::operators::window::frame::{FrameRanges, WindowFrame};
+    use crate::testutil::arrays::assert_arrays_eq;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    fn ntile(num_rows: usize, num_buckets: i64) -> Array {
+        let groups = [PeerGroup {
+            start: 0,
+            end: num_rows,
+            new_partition: true,
+        }];
+        let frames = FrameRanges::try_new(&groups, num_rows, None).unwrap();
+        let inputs = [Array::try_from_iter(vec![num_buckets; num_rows]).unwrap()];
+        let partition = WindowPartition::new(&inputs, &groups, &frames, &WindowFrame::DEFAULT);
+
+        let mut out = Array::new(&DefaultBufferManager, DataType::int64(), num_rows).unwrap();
+        Ntile::compute(&(), &partition, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn ntile_even() {
+        let out = ntile(6, 3);
+        let expected = Array::try_from_iter([1_i64, 1, 2, 2, 3, 3]).unwrap();
+        assert_arrays_eq(&expected, &out);
+    }
+
+    #[test]
+    fn ntile_uneven() {
+        let out = ntile(7, 3);
+        let expected = Array::try_from_iter([1_i64, 1, 1, 2, 2, 3, 3]).unwrap();
+        assert_arrays_eq(&expected, &out);
+    }
+
+    #[test]
+    fn ntile_more_buckets_than_rows() {
+        let out = ntile(3, 5);
+        let expected = Array::try_from_iter([1_i64, 2, 3]).unwrap();
+        assert_arrays_eq(&expected, &out);
+    }
+}
@@ -0,0 +1,221 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{
+    AddressableMut,
+    MutableScalarStorage,
+    PhysicalF64,
+    PhysicalI64,
+};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::bind_state::BindState;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::function_set::WindowFunctionSet;
+use crate::functions::window::{RawWindowFunction, WindowFunction, WindowPartition};
+
+pub const FUNCTION_SET_RANK: WindowFunctionSet = WindowFunctionSet {
+    name: "rank",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Rank of the current row with gaps. Peer rows receive the same rank.",
+        arguments: &[],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[], DataTypeId::Int64),
+        &Rank,
+    )],
+};
+
+pub const FUNCTION_SET_DENSE_RANK: WindowFunctionSet = WindowFunctionSet {
+    name: "dense_rank",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Rank of the current row without gaps. Peer rows receive the same rank.",
+        arguments: &[],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[], DataTypeId::Int64),
+        &DenseRank,
+    )],
+};
+
+pub const FUNCTION_SET_PERCENT_RANK: WindowFunctionSet = WindowFunctionSet {
+    name: "percent_rank",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Relative rank of the current row, computed as (rank - 1) / (rows in partition - 1).",
+        arguments: &[],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[], DataTypeId::Float64),
+        &PercentRank,
+    )],
+};
+
+pub const FUNCTION_SET_CUME_DIST: WindowFunctionSet = WindowFunctionSet {
+    name: "cume_dist",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Cumulative distribution of the current row, computed as (rows preceding or peers of the current row) / (rows in partition).",
+        arguments: &[],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[], DataTypeId::Float64),
+        &CumeDist,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rank;
+
+impl WindowFunction for Rank {
+    type BindState = ();
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        _ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn compute(
+        _state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let mut out = PhysicalI64::get_addressable_mut(&mut output.data)?;
+        let start = partition.rows().start;
+        for group in partition.peer_groups() {
+            let rank = (group.start - start + 1) as i64;
+            for row in group {
+                out.put(row, &rank);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DenseRank;
+
+impl WindowFunction for DenseRank {
+    type BindState = ();
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        _ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn compute(
+        _state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let mut out = PhysicalI64::get_addressable_mut(&mut output.data)?;
+        for (idx, group) in partition.peer_groups().enumerate() {
+            let rank = (idx + 1) as i64;
+            for row in group {
+                out.put(row, &rank);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PercentRank;
+
+impl WindowFunction for PercentRank {
+    type BindState = ();
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        _ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::float64(),
+            inputs,
+        })
+    }
+
+    fn compute(
+        _state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let mut out = PhysicalF64::get_addressable_mut(&mut output.data)?;
+        let start = partition.rows().start;
+        let num_rows = partition.num_rows();
+        for group in partition.peer_groups() {
+            let percent = if num_rows > 1 {
+                (group.start - start) as f64 / (num_rows - 1) as f64
+            } else {
+                0.0
+            };
+            for row in group {
+                out.put(row, &percent);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CumeDist;
+
+impl WindowFunction for CumeDist {
+    type BindState = ();
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        _ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::float64(),
+            inputs,
+        })
+    }
+
+    fn compute(
+        _state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let mut out = PhysicalF64::get_addressable_mut(&mut output.data)?;
+        let start = partition.rows().start;
+        let num_rows = partition.num_rows() as f64;
+        for group in partition.peer_groups() {
+            let dist = (group.end - start) as f64 / num_rows;
+            for row in group {
+                out.put(row, &dist);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use glaredb_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{AddressableMut, MutableScalarStorage, PhysicalI64};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::bind_state::BindState;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::function_set::WindowFunctionSet;
+use crate::functions::window::{RawWindowFunction, WindowFunction, WindowPartition};
+
+pub const FUNCTION_SET_ROW_NUMBER: WindowFunctionSet = WindowFunctionSet {
+    name: "row_number",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Number of the current row within its partition, counting from 1.",
+        arguments: &[],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[], DataTypeId::Int64),
+        &RowNumber,
+    )],
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct RowNumber;
+
+impl WindowFunction for RowNumber {
+    type BindState = ();
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        _ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: (),
+            return_type: DataType::int64(),
+            inputs,
+        })
+    }
+
+    fn compute(
+        _state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let mut out = PhysicalI64::get_addressable_mut(&mut output.data)?;
+        let rows = partition.rows();
+        for row in rows.clone() {
+            out.put(row, &((row - rows.start + 1) as i64));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+use std::ops::Range;
+
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::datatype::DataTypeId;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::expr::Expression;
+use crate::functions::Signature;
+use crate::functions::bind_state::BindState;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::function_set::WindowFunctionSet;
+use crate::functions::window::{RawWindowFunction, WindowFunction, WindowPartition};
+
+pub const FUNCTION_SET_FIRST_VALUE: WindowFunctionSet = WindowFunctionSet {
+    name: "first_value",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Value of the first row in the window frame.",
+        arguments: &["value"],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[DataTypeId::Any], DataTypeId::Any),
+        &FrameValue::FIRST,
+    )],
+};
+
+pub const FUNCTION_SET_LAST_VALUE: WindowFunctionSet = WindowFunctionSet {
+    name: "last_value",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Value of the last row in the window frame.",
+        arguments: &["value"],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[DataTypeId::Any], DataTypeId::Any),
+        &FrameValue::LAST,
+    )],
+};
+
+pub const FUNCTION_SET_NTH_VALUE: WindowFunctionSet = WindowFunctionSet {
+    name: "nth_value",
+    aliases: &[],
+    doc: &[&Documentation {
+        category: Category::Window,
+        description: "Value of the nth row in the window frame, counting from 1. Returns NULL if the frame has fewer rows.",
+        arguments: &["value", "n"],
+        example: None,
+    }],
+    functions: &[RawWindowFunction::new(
+        &Signature::new(&[DataTypeId::Any, DataTypeId::Int64], DataTypeId::Any),
+        &FrameValue::NTH,
+    )],
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValuePosition {
+    First,
+    Last,
+    Nth,
+}
+
+/// Implementation for `first_value`, `last_value`, and `nth_value`.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameValue {
+    position: ValuePosition,
+}
+
+impl FrameValue {
+    pub const FIRST: Self = FrameValue {
+        position: ValuePosition::First,
+    };
+    pub const LAST: Self = FrameValue {
+        position: ValuePosition::Last,
+    };
+    pub const NTH: Self = FrameValue {
+        position: ValuePosition::Nth,
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FrameValueState {
+    position: ValuePosition,
+    ignore_nulls: bool,
+}
+
+impl WindowFunction for FrameValue {
+    type BindState = FrameValueState;
+
+    const SUPPORTS_IGNORE_NULLS: bool = true;
+
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>> {
+        Ok(BindState {
+            state: FrameValueState {
+                position: self.position,
+                ignore_nulls,
+            },
+            return_type: inputs[0].datatype()?,
+            inputs,
+        })
+    }
+
+    fn compute(
+        state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        let inputs = partition.inputs();
+        let values = &inputs[0];
+        let rows = partition.rows();
+
+        // 1-based position of the value to get, NULL positions produce NULL
+        // values.
+        let mut positions: Vec<Option<i64>> = Vec::with_capacity(rows.len());
+        match state.position {
+            ValuePosition::Nth => {
+                UnaryExecutor::for_each_flat::<PhysicalI64, _>(
+                    &inputs[1],
+                    rows.clone(),
+                    |_, v| positions.push(v.copied()),
+                )?;
+            }
+            _ => positions.resize(rows.len(), Some(1)),
+        }
+
+        let non_null: Vec<usize> = if state.ignore_nulls {
+            rows.clone()
+                .filter(|&row| values.validity.is_valid(row))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let non_null = state.ignore_nulls.then_some(non_null.as_slice());
+
+        let mut from_values: Vec<(usize, usize)> = Vec::new();
+        let mut nulls: Vec<usize> = Vec::new();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+
+        for (row, position) in rows.zip(positions) {
+            let position = match position {
+                Some(position) if position > 0 => position as usize,
+                Some(position) => {
+                    return Err(
+                        DbError::new("Argument of nth_value must be greater than zero")
+                            .with_field("n", position),
+                    );
+                }
+                None => {
+                    nulls.push(row);
+                    continue;
+                }
+            };
+
+            partition.frame_ranges(row, &mut ranges)?;
+            let from_end = state.position == ValuePosition::Last;
+
+            match nth_in_frame(&ranges, position - 1, from_end, non_null) {
+                Some(target) => from_values.push((target, row)),
+                None => nulls.push(row),
+            }
+        }
+
+        values.copy_rows(from_values, output)?;
+        for row in nulls {
+            output.validity.set_invalid(row);
+        }
+
+        Ok(())
+    }
+}
+
+/// Find the row for the nth (0-based) value in a frame.
+///
+/// If `non_null` is provided, only rows in that sorted slice are counted.
+fn nth_in_frame(
+    ranges: &[Range<usize>],
+    mut n: usize,
+    from_end: bool,
+    non_null: Option<&[usize]>,
+) -> Option<usize> {
+    let mut find_in_range = |range: &Range<usize>| -> Option<usize> {
+        let (start, end, rows) = match non_null {
+            Some(non_null) => (
+                non_null.partition_point(|&r| r < range.start),
+                non_null.partition_point(|&r| r < range.end),
+                Some(non_null),
+            ),
+            None => (range.start, range.end, None),
+        };
+
+        let count = end - start;
+        if n >= count {
+            n -= count;
+            return None;
+        }
+
+        let idx = if from_end { end - 1 - n } else { start + n };
+        Some(match rows {
+            Some(rows) => rows[idx],
+            None => idx,
+        })
+    };
+
+    if from_end {
+        ranges.iter().rev().find_map(&mut find_in_range)
+    } else {
+        ranges.iter().find_map(&mut find_in_range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nth_in_frame_multiple_ranges() {
+        let ranges = [0..2, 4..6];
+        assert_eq!(Some(0), nth_in_frame(&ranges, 0, false, None));
+        assert_eq!(Some(4), nth_in_frame(&ranges, 2, false, None));
+        assert_eq!(Some(5), nth_in_frame(&ranges, 0, true, None));
+        assert_eq!(Some(1), nth_in_frame(&ranges, 2, true, None));
+        assert_eq!(None, nth_in_frame(&ranges, 4, false, None));
+    }
+
+    #[test]
+    fn nth_in_frame_ignore_nulls() {
+        let ranges = [0..3, 4..8];
+        let non_null = [1, 3, 5, 7];
+        assert_eq!(Some(1), nth_in_frame(&ranges, 0, false, Some(&non_null)));
+        assert_eq!(Some(5), nth_in_frame(&ranges, 1, false, Some(&non_null)));
+        assert_eq!(Some(7), nth_in_frame(&ranges, 0, true, Some(&non_null)));
+        assert_eq!(Some(1), nth_in_frame(&ranges, 2, true, Some(&non_null)));
+        assert_eq!(None, nth_in_frame(&ranges, 3, false, Some(&non_null)));
+    }
+}
@@ -0,0 +1,231 @@
+pub mod builtin;
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Range;
+use std::sync::Arc;
+
+use glaredb_error::Result;
+
+use super::Signature;
+use super::bind_state::{BindState, RawBindState};
+use crate::arrays::array::Array;
+use crate::execution::operators::window::PeerGroup;
+use crate::execution::operators::window::frame::{FrameRanges, WindowFrame};
+use crate::expr::Expression;
+
+#[derive(Debug, Clone)]
+pub struct PlannedWindowFunction {
+    pub(crate) name: &'static str,
+    pub(crate) raw: &'static RawWindowFunction,
+    pub(crate) state: RawBindState,
+    /// If NULL values should be skipped (IGNORE NULLS).
+    pub(crate) ignore_nulls: bool,
+}
+
+impl PlannedWindowFunction {
+    /// Compute the window values for a single window partition, writing them
+    /// to `output`.
+    pub(crate) fn call_compute(
+        &self,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()> {
+        unsafe { (self.raw.vtable.compute_fn)(self.state.state_as_any(), partition, output) }
+    }
+}
+
+/// Assumes that a function with same inputs and return type is using the same
+/// function implementation.
+impl PartialEq for PlannedWindowFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.ignore_nulls == other.ignore_nulls
+            && self.state.return_type == other.state.return_type
+            && self.state.inputs == other.state.inputs
+    }
+}
+
+impl Eq for PlannedWindowFunction {}
+
+impl Hash for PlannedWindowFunction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.ignore_nulls.hash(state);
+        self.state.return_type.hash(state);
+        self.state.inputs.hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RawWindowFunction {
+    function: *const (),
+    signature: &'static Signature,
+    vtable: &'static RawWindowFunctionVTable,
+    supports_ignore_nulls: bool,
+}
+
+unsafe impl Send for RawWindowFunction {}
+unsafe impl Sync for RawWindowFunction {}
+
+impl RawWindowFunction {
+    pub const fn new<F>(sig: &'static Signature, function: &'static F) -> Self
+    where
+        F: WindowFunction,
+    {
+        let function = (function as *const F).cast();
+        RawWindowFunction {
+            function,
+            signature: sig,
+            vtable: F::VTABLE,
+            supports_ignore_nulls: F::SUPPORTS_IGNORE_NULLS,
+        }
+    }
+
+    pub fn call_bind(&self, inputs: Vec<Expression>, ignore_nulls: bool) -> Result<RawBindState> {
+        unsafe { (self.vtable.bind_fn)(self.function, inputs, ignore_nulls) }
+    }
+
+    pub fn signature(&self) -> &Signature {
+        self.signature
+    }
+
+    pub fn supports_ignore_nulls(&self) -> bool {
+        self.supports_ignore_nulls
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::type_complexity)]
+pub struct RawWindowFunctionVTable {
+    bind_fn: unsafe fn(
+        function: *const (),
+        inputs: Vec<Expression>,
+        ignore_nulls: bool,
+    ) -> Result<RawBindState>,
+    compute_fn:
+        unsafe fn(state: &dyn Any, partition: &WindowPartition, output: &mut Array) -> Result<()>,
+}
+
+/// A function that can only be used with an OVER clause.
+///
+/// Unlike aggregates, window functions have access to all rows in a window
+/// partition at once, allowing them to compute values based on a row's
+/// position (e.g. `rank`) or on the values of other rows (e.g. `lag`).
+pub trait WindowFunction: Debug + Copy + Sync + Send + Sized + 'static {
+    /// Bind state passed to compute.
+    type BindState: Sync + Send;
+
+    /// If this function accepts IGNORE NULLS.
+    const SUPPORTS_IGNORE_NULLS: bool = false;
+
+    /// Compute the return type from the expression inputs and return a function
+    /// state.
+    ///
+    /// This will only be called with expressions that match the signature this
+    /// function was registered with. `ignore_nulls` will only be true if the
+    /// function supports it.
+    fn bind(
+        &self,
+        inputs: Vec<Expression>,
+        ignore_nulls: bool,
+    ) -> Result<BindState<Self::BindState>>;
+
+    /// Compute values for every row in a window partition.
+    ///
+    /// Values should be written to `output` at the same indices as the rows in
+    /// the partition.
+    fn compute(
+        state: &Self::BindState,
+        partition: &WindowPartition,
+        output: &mut Array,
+    ) -> Result<()>;
+}
+
+trait WindowFunctionVTable: WindowFunction {
+    const VTABLE: &'static RawWindowFunctionVTable = &RawWindowFunctionVTable {
+        bind_fn: |function: *const (),
+                  inputs: Vec<Expression>,
+                  ignore_nulls: bool|
+         -> Result<RawBindState> {
+            let function = unsafe { function.cast::<Self>().as_ref().unwrap() };
+            let state = function.bind(inputs, ignore_nulls)?;
+
+            Ok(RawBindState {
+                state: Arc::new(state.state),
+                return_type: state.return_type,
+                inputs: state.inputs,
+            })
+        },
+        compute_fn: |state, partition, output| {
+            let state = state.downcast_ref::<Self::BindState>().unwrap();
+            Self::compute(state, partition, output)
+        },
+    };
+}
+
+impl<F> WindowFunctionVTable for F where F: WindowFunction {}
+
+/// Sorted rows for a single window partition.
+///
+/// Row indices are relative to the full set of rows being computed, and not to
+/// the start of the partition.
+#[derive(Debug)]
+pub struct WindowPartition<'a> {
+    inputs: &'a [Array],
+    rows: Range<usize>,
+    groups: &'a [PeerGroup],
+    frames: &'a FrameRanges<'a>,
+    frame: &'a WindowFrame,
+}
+
+impl<'a> WindowPartition<'a> {
+    pub(crate) fn new(
+        inputs: &'a [Array],
+        groups: &'a [PeerGroup],
+        frames: &'a FrameRanges<'a>,
+        frame: &'a WindowFrame,
+    ) -> Self {
+        let start = groups.first().map(|g| g.start).unwrap_or(0);
+        let end = groups.last().map(|g| g.end).unwrap_or(0);
+
+        WindowPartition {
+            inputs,
+            rows: start..end,
+            groups,
+            frames,
+            frame,
+        }
+    }
+
+    /// Input arrays to the function, containing values for all rows.
+    pub fn inputs(&self) -> &[Array] {
+        self.inputs
+    }
+
+    /// Rows in this partition.
+    pub fn rows(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+
+    /// Number of rows in this partition.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Iterate the peer groups in this partition in order.
+    ///
+    /// Rows are peers if they have equal ORDER BY values.
+    pub fn peer_groups(&self) -> impl ExactSizeIterator<Item = Range<usize>> + '_ {
+        self.groups.iter().map(|group| group.start..group.end)
+    }
+
+    /// Writes the ranges of rows making up the frame for `row` to `out`.
+    ///
+    /// Ranges are written in row order. `out` will be empty if the frame is
+    /// empty.
+    pub fn frame_ranges(&self, row: usize, out: &mut Vec<Range<usize>>) -> Result<()> {
+        self.frames.frame_ranges(self.frame, row, out)
+    }
+}
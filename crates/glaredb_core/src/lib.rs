@@ -14,6 +14,7 @@ pub mod runtime;
 pub mod shell;
 pub mod statistics;
 pub mod storage;
+pub mod transaction;
 
 pub mod util;
 
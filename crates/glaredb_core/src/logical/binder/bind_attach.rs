@@ -6,11 +6,13 @@ use glaredb_parser::ast;
 use super::bind_context::{BindContext, BindScopeRef};
 use super::column_binder::ErroringColumnBinder;
 use super::expr_binder::{BaseExpressionBinder, RecursionContext};
+use crate::arrays::scalar::ScalarValue;
 use crate::logical::logical_attach::{LogicalAttachDatabase, LogicalDetachDatabase};
 use crate::logical::operator::{LocationRequirement, Node};
 use crate::logical::resolver::ResolvedMeta;
 use crate::logical::resolver::resolve_context::ResolveContext;
 use crate::statistics::value::StatisticsValue;
+use crate::storage::persistent::{GLARE_DATASOURCE_NAME, GLARE_FILE_EXTENSION};
 
 #[derive(Debug)]
 pub enum BoundAttach {
@@ -35,7 +37,7 @@ impl AttachBinder {
     pub fn bind_attach(
         &self,
         bind_context: &mut BindContext,
-        mut attach: ast::Attach<ResolvedMeta>,
+        attach: ast::Attach<ResolvedMeta>,
     ) -> Result<BoundAttach> {
         match attach.attach_type {
             ast::AttachType::Database => {
@@ -64,14 +66,22 @@ impl AttachBinder {
                     options.insert(k, v);
                 }
 
-                if attach.alias.0.len() != 1 {
-                    return Err(DbError::new(format!(
-                        "Expected a single identifier, got '{}'",
-                        attach.alias
-                    )));
-                }
-                let name = attach.alias.pop()?;
-                let datasource = attach.datasource_name;
+                let name = match attach.alias {
+                    Some(mut alias) => {
+                        if alias.0.len() != 1 {
+                            return Err(DbError::new(format!(
+                                "Expected a single identifier, got '{alias}'"
+                            )));
+                        }
+                        alias.pop()?
+                    }
+                    None => name_from_path(&options)?,
+                };
+
+                let datasource = match attach.datasource_name {
+                    Some(datasource) => datasource.into_normalized_string(),
+                    None => datasource_from_path(&options)?,
+                };
 
                 // Currently this always has a "client local" requirement. This
                 // essentially means catalog management happens on the client
@@ -84,7 +94,7 @@ impl AttachBinder {
                 // catalog changes.
                 Ok(BoundAttach::Database(Node {
                     node: LogicalAttachDatabase {
-                        datasource: datasource.into_normalized_string(),
+                        datasource,
                         name,
                         options,
                     },
@@ -123,3 +133,43 @@ impl AttachBinder {
         }
     }
 }
+
+/// Get the path provided to ATTACH.
+fn attach_path(options: &HashMap<String, ScalarValue>) -> Result<&str> {
+    match options.get("path") {
+        Some(path) => path.try_as_str(),
+        None => Err(DbError::new("Missing path for ATTACH")),
+    }
+}
+
+/// Derive the database name from the file stem of the path.
+///
+/// 'data/sales.glare' => 'sales'
+fn name_from_path(options: &HashMap<String, ScalarValue>) -> Result<String> {
+    let path = attach_path(options)?;
+    let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    let stem = match file_name.split_once('.') {
+        Some((stem, _)) => stem,
+        None => file_name,
+    };
+
+    if stem.is_empty() {
+        return Err(DbError::new(format!(
+            "Cannot derive database name from path '{path}', provide one with AS"
+        )));
+    }
+
+    Ok(stem.to_string())
+}
+
+/// Infer the datasource to use from the path's extension.
+fn datasource_from_path(options: &HashMap<String, ScalarValue>) -> Result<String> {
+    let path = attach_path(options)?;
+    if path.ends_with(GLARE_FILE_EXTENSION) {
+        return Ok(GLARE_DATASOURCE_NAME.to_string());
+    }
+
+    Err(DbError::new(format!(
+        "Cannot infer datasource for path '{path}', provide one with the TYPE option"
+    )))
+}
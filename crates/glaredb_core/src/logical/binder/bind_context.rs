@@ -2,6 +2,7 @@ use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use glaredb_error::{DbError, Result};
+use glaredb_parser::ast;
 use serde::{Deserialize, Serialize};
 
 use super::bind_query::BoundQuery;
@@ -11,6 +12,7 @@ use crate::arrays::datatype::DataType;
 use crate::expr::Expression;
 use crate::expr::column_expr::ColumnReference;
 use crate::logical::operator::{LogicalNode, LogicalOperator};
+use crate::logical::resolver::ResolvedMeta;
 
 /// Reference to a child bind scope.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -102,6 +104,13 @@ pub struct BoundCte {
     /// If `materialized` is false and this is None, we need to plan the bound
     /// query first.
     pub mat_ref: Option<MaterializationRef>,
+    /// Set while binding the recursive term of a recursive CTE.
+    ///
+    /// References to the CTE during this time read from the working table
+    /// instead of the materialized CTE.
+    pub binding_recursive_term: bool,
+    /// Number of times the CTE was referenced from its own recursive term.
+    pub recursive_references: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +121,27 @@ pub struct UsingColumn {
     pub table_ref: TableRef,
     /// Column index inside the table.
     pub col_idx: usize,
+    /// Expression to use for the column instead of the above reference.
+    ///
+    /// Set for FULL joins. Either side of the join may be NULL, so the column
+    /// is the COALESCE of both sides.
+    pub coalesced: Option<Expression>,
+}
+
+impl UsingColumn {
+    /// Get the expression representing this column.
+    pub fn as_expr(&self, bind_context: &BindContext) -> Result<Expression> {
+        match &self.coalesced {
+            Some(expr) => Ok(expr.clone()),
+            None => Ok(bind_context
+                .get_table_list()
+                .column_as_expr(ColumnReference {
+                    table_scope: self.table_ref,
+                    column: self.col_idx,
+                })?
+                .into()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -128,6 +158,10 @@ struct BindScope {
     tables: Vec<TableRef>,
     /// CTEs in scope. Keyed by normalized CTE name.
     ctes: HashMap<BinderIdent, CteRef>,
+    /// Named windows from the WINDOW clause. Keyed by normalized window name.
+    ///
+    /// Definitions are stored with any referenced window already merged in.
+    windows: HashMap<BinderIdent, ast::WindowDefinition<ResolvedMeta>>,
 }
 
 /// A node in the logical plan that will be materialized to allow for multiple
@@ -156,6 +190,7 @@ impl BindContext {
                 correlated_columns: Vec::new(),
                 using_columns: Vec::new(),
                 ctes: HashMap::new(),
+                windows: HashMap::new(),
             }],
             tables: TableList::empty(),
             ctes: Vec::new(),
@@ -183,6 +218,7 @@ impl BindContext {
             correlated_columns: Vec::new(),
             using_columns: Vec::new(),
             ctes: HashMap::new(),
+            windows: HashMap::new(),
         });
 
         BindScopeRef { context_idx: idx }
@@ -198,6 +234,7 @@ impl BindContext {
             correlated_columns: Vec::new(),
             using_columns: Vec::new(),
             ctes: HashMap::new(),
+            windows: HashMap::new(),
         });
 
         BindScopeRef { context_idx: idx }
@@ -242,6 +279,40 @@ impl BindContext {
         }
     }
 
+    /// Adds a named window definition to the current scope.
+    ///
+    /// Errors on duplicate window name.
+    pub fn add_named_window(
+        &mut self,
+        current: BindScopeRef,
+        name: BinderIdent,
+        window: ast::WindowDefinition<ResolvedMeta>,
+    ) -> Result<()> {
+        let scope = self.get_scope_mut(current)?;
+        if scope.windows.contains_key(&name) {
+            return Err(DbError::new(format!("Duplicate window name '{name}'")));
+        }
+        scope.windows.insert(name, window);
+
+        Ok(())
+    }
+
+    /// Try to find a named window definition.
+    ///
+    /// Only the current scope is searched. Named windows are not visible to
+    /// nested queries.
+    pub fn find_named_window(
+        &self,
+        current: BindScopeRef,
+        name: &str,
+    ) -> Result<&ast::WindowDefinition<ResolvedMeta>> {
+        let scope = self.get_scope(current)?;
+        scope
+            .windows
+            .get(name)
+            .ok_or_else(|| DbError::new(format!("Missing window '{name}'")))
+    }
+
     pub fn get_cte(&self, cte_ref: CteRef) -> Result<&BoundCte> {
         self.ctes
             .get(cte_ref.cte_idx)
@@ -580,6 +651,9 @@ impl BindContext {
     /// This first searches any USING columns if `alias` is None, then proceeds
     /// to search all tables in this scope. Outer scopes are not searched.
     ///
+    /// USING columns that are coalesced from both sides of a FULL join are
+    /// skipped, see `find_coalesced_using_column`.
+    ///
     /// Returns the table reference containing the column, and the relative
     /// index of the column within that table.
     ///
@@ -600,7 +674,7 @@ impl BindContext {
             let using = self
                 .get_using_columns(current)?
                 .iter()
-                .find(|&using| using.column.strict_eq(lookup));
+                .find(|&using| using.coalesced.is_none() && using.column.strict_eq(lookup));
             if let Some(using) = using {
                 return Ok(Some((using.table_ref, using.col_idx)));
             }
@@ -632,6 +706,22 @@ impl BindContext {
         Ok(found)
     }
 
+    /// Tries to find a USING column from a FULL join with the given name in
+    /// this scope.
+    ///
+    /// These columns don't map to a single table column, and need to be bound
+    /// using the coalesced expression.
+    pub fn find_coalesced_using_column(
+        &self,
+        current: BindScopeRef,
+        lookup: &BinderIdent,
+    ) -> Result<Option<&UsingColumn>> {
+        Ok(self
+            .get_using_columns(current)?
+            .iter()
+            .find(|&using| using.coalesced.is_some() && using.column.strict_eq(lookup)))
+    }
+
     /// Iterate tables in the given bind scope.
     pub fn iter_tables_in_scope(
         &self,
@@ -645,9 +735,19 @@ impl BindContext {
     }
 
     /// Appends a USING column to the current scope.
+    ///
+    /// Replaces an existing USING column with the same name, e.g. from a
+    /// previous join on the same column.
     pub fn append_using_column(&mut self, current: BindScopeRef, col: UsingColumn) -> Result<()> {
         let scope = self.get_scope_mut(current)?;
-        scope.using_columns.push(col);
+        match scope
+            .using_columns
+            .iter_mut()
+            .find(|existing| existing.column == col.column)
+        {
+            Some(existing) => *existing = col,
+            None => scope.using_columns.push(col),
+        }
         Ok(())
     }
 
@@ -1,4 +1,4 @@
-use glaredb_error::{Result, not_implemented};
+use glaredb_error::{DbError, Result};
 use glaredb_parser::ast;
 
 use super::bind_context::{BindContext, BindScopeRef};
@@ -6,10 +6,12 @@ use super::bind_query::BoundQuery;
 use super::bind_query::bind_from::BoundFrom;
 use crate::arrays::datatype::DataType;
 use crate::arrays::field::{ColumnSchema, Field};
+use crate::functions::copy_to::{PlannedCopyToFunction, bind_copy_to_function};
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::binder::bind_query::bind_from::FromBinder;
 use crate::logical::resolver::ResolvedMeta;
 use crate::logical::resolver::resolve_context::ResolveContext;
+use crate::runtime::filesystem::FileSystemWithState;
 
 #[derive(Debug)]
 pub enum BoundCopyToSource {
@@ -21,8 +23,12 @@ pub enum BoundCopyToSource {
 pub struct BoundCopyTo {
     pub source: BoundCopyToSource,
     pub source_schema: ColumnSchema,
-    // pub location: FileLocation,
-    // pub copy_to: Box<dyn CopyToFunction>,
+    /// Path of the file to write to.
+    pub location: String,
+    /// Function for encoding the output.
+    pub copy_to: PlannedCopyToFunction,
+    /// Filesystem to use for writing the file.
+    pub filesystem: FileSystemWithState,
 }
 
 #[derive(Debug)]
@@ -48,7 +54,7 @@ impl<'a> CopyBinder<'a> {
 
         let source_scope = bind_context.new_orphan_scope();
 
-        let _source = match copy_to.source {
+        let source = match copy_to.source {
             ast::CopyToSource::Query(query) => {
                 let query_binder = QueryBinder::new(source_scope, self.resolve_context);
                 let bound_query = query_binder.bind(bind_context, *query)?;
@@ -67,7 +73,7 @@ impl<'a> CopyBinder<'a> {
             }
         };
 
-        let _source_schema = ColumnSchema::new(
+        let source_schema = ColumnSchema::new(
             bind_context
                 .iter_tables_in_scope(source_scope)?
                 .flat_map(|t| {
@@ -80,19 +86,26 @@ impl<'a> CopyBinder<'a> {
                 }),
         );
 
-        // let resolved_copy_to = self
-        //     .resolve_context
-        //     .copy_to
-        //     .as_ref()
-        //     .ok_or_else(|| RayexecError::new("Missing COPY TO function"))?
-        //     .clone();
+        let resolved_copy_to = self
+            .resolve_context
+            .copy_to
+            .as_ref()
+            .ok_or_else(|| DbError::new("Missing COPY TO function"))?;
 
-        not_implemented!("Bind COPY TO")
-        // Ok(BoundCopyTo {
-        //     source,
-        //     source_schema,
-        //     location: copy_to.target,
-        //     copy_to: resolved_copy_to.func,
-        // })
+        let ast::CopyToTarget::File(location) = copy_to.target;
+
+        let function = bind_copy_to_function(
+            resolved_copy_to.function,
+            source_schema.clone(),
+            &resolved_copy_to.options,
+        )?;
+
+        Ok(BoundCopyTo {
+            source,
+            source_schema,
+            location,
+            copy_to: function,
+            filesystem: resolved_copy_to.filesystem.clone(),
+        })
     }
 }
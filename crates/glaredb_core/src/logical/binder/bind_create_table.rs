@@ -5,8 +5,9 @@ use super::bind_context::{BindContext, BindScopeRef};
 use super::bind_query::BoundQuery;
 use crate::arrays::datatype::DataType;
 use crate::arrays::field::Field;
-use crate::catalog::create::OnConflict;
+use crate::catalog::create::{OnConflict, UniqueConstraint};
 use crate::logical::binder::bind_query::QueryBinder;
+use crate::logical::binder::ident::BinderIdent;
 use crate::logical::resolver::ResolvedMeta;
 use crate::logical::resolver::resolve_context::ResolveContext;
 
@@ -16,6 +17,7 @@ pub struct BoundCreateTable {
     pub schema: String,
     pub name: String,
     pub columns: Vec<Field>,
+    pub unique_constraints: Vec<UniqueConstraint>,
     pub on_conflict: OnConflict,
     pub source: Option<BoundQuery>,
 }
@@ -51,6 +53,7 @@ impl<'a> CreateTableBinder<'a> {
         };
 
         // TODO: Verify column constraints.
+        let unique_constraints = Self::bind_unique_constraints(&create)?;
         let mut columns: Vec<_> = create
             .columns
             .into_iter()
@@ -111,8 +114,69 @@ impl<'a> CreateTableBinder<'a> {
             schema,
             name,
             columns,
+            unique_constraints,
             on_conflict,
             source: input,
         })
     }
+
+    /// Collect UNIQUE and PRIMARY KEY constraints from both column options
+    /// and table constraints.
+    fn bind_unique_constraints(
+        create: &ast::CreateTable<ResolvedMeta>,
+    ) -> Result<Vec<UniqueConstraint>> {
+        let mut constraints = Vec::new();
+
+        for (idx, col) in create.columns.iter().enumerate() {
+            for opt in &col.opts {
+                let primary_key = match opt {
+                    ast::ColumnOption::PrimaryKey => true,
+                    ast::ColumnOption::Unique => false,
+                    _ => continue,
+                };
+                constraints.push(UniqueConstraint {
+                    columns: vec![idx],
+                    primary_key,
+                });
+            }
+        }
+
+        for constraint in &create.constraints {
+            let (idents, primary_key) = match constraint {
+                ast::TableConstraint::PrimaryKey(idents) => (idents, true),
+                ast::TableConstraint::Unique(idents) => (idents, false),
+            };
+
+            let mut columns = Vec::with_capacity(idents.len());
+            for ident in idents {
+                let ident = BinderIdent::from(ident.clone());
+                let idx = create
+                    .columns
+                    .iter()
+                    .position(|col| BinderIdent::from(col.name.value.as_str()).strict_eq(&ident))
+                    .ok_or_else(|| {
+                        DbError::new(format!("Column '{ident}' in constraint does not exist"))
+                    })?;
+                if columns.contains(&idx) {
+                    return Err(DbError::new(format!(
+                        "Column '{ident}' appears more than once in constraint"
+                    )));
+                }
+                columns.push(idx);
+            }
+
+            constraints.push(UniqueConstraint {
+                columns,
+                primary_key,
+            });
+        }
+
+        if constraints.iter().filter(|c| c.primary_key).count() > 1 {
+            return Err(DbError::new(
+                "Multiple primary keys for table are not allowed",
+            ));
+        }
+
+        Ok(constraints)
+    }
 }
@@ -0,0 +1,198 @@
+use glaredb_error::{DbError, Result};
+use glaredb_parser::ast;
+
+use super::bind_context::{BindContext, BindScopeRef};
+use super::bind_query::bind_from::{BoundFrom, BoundFromItem, FromBinder};
+use super::table_list::TableRef;
+use crate::arrays::datatype::DataType;
+use crate::expr::Expression;
+use crate::expr::column_expr::{ColumnExpr, ColumnReference};
+use crate::logical::binder::column_binder::DefaultColumnBinder;
+use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
+use crate::logical::operator::LocationRequirement;
+use crate::logical::resolver::ResolvedMeta;
+use crate::logical::resolver::resolve_context::{ResolveContext, ResolveListIdx};
+use crate::logical::resolver::resolved_table::{
+    ResolvedTableOrCteReference,
+    ResolvedTableReference,
+};
+use crate::storage::datatable::ROWID_COLUMN_NAME;
+
+/// Scan of the table being modified by a DELETE or UPDATE.
+#[derive(Debug, Clone)]
+pub struct BoundTargetScan {
+    /// Scan of the table, including the metadata table for row ids.
+    pub from: BoundFrom,
+    /// Expression for WHERE.
+    pub filter: Option<Expression>,
+    /// Row id column from the scan.
+    pub row_id: Expression,
+    /// Table ref for the data columns in the scan.
+    pub data_table_ref: TableRef,
+    /// Table being modified.
+    pub table: ResolvedTableReference,
+    /// Location of the table being modified.
+    pub table_location: LocationRequirement,
+}
+
+impl BoundTargetScan {
+    /// Bind a scan of the table being modified, and the WHERE expression
+    /// filtering the rows to modify.
+    ///
+    /// `current` should be a scope for the scan of the table.
+    pub fn bind(
+        bind_context: &mut BindContext,
+        current: BindScopeRef,
+        resolve_context: &ResolveContext,
+        table: ResolveListIdx,
+        alias: Option<ast::Ident>,
+        where_expr: Option<ast::Expr<ResolvedMeta>>,
+    ) -> Result<Self> {
+        let (reference, location) = resolve_target_table(resolve_context, table)?;
+
+        let from = FromBinder::new(current, resolve_context).bind_table(
+            bind_context,
+            ast::FromBaseTable { reference: table },
+            alias.map(|alias| ast::FromAlias {
+                alias,
+                columns: None,
+            }),
+        )?;
+
+        let (data_table_ref, meta_table_ref) = match &from.item {
+            BoundFromItem::BaseTable(table) => (table.data_table_ref, table.meta_table_ref),
+            other => {
+                return Err(DbError::new(format!(
+                    "Unexpected bound item for table being modified: {other:?}"
+                )));
+            }
+        };
+
+        let row_id = row_id_expression(bind_context, meta_table_ref, &reference)?;
+
+        let filter = where_expr
+            .map(|expr| {
+                BaseExpressionBinder::new(current, resolve_context).bind_expression(
+                    bind_context,
+                    &expr,
+                    &mut DefaultColumnBinder,
+                    RecursionContext {
+                        allow_windows: false,
+                        allow_aggregates: false,
+                        is_root: true,
+                    },
+                )
+            })
+            .transpose()?;
+
+        Ok(BoundTargetScan {
+            from,
+            filter,
+            row_id,
+            data_table_ref,
+            table: reference,
+            table_location: location,
+        })
+    }
+}
+
+/// Get the table being modified, erroring if it's not a table.
+pub fn resolve_target_table(
+    resolve_context: &ResolveContext,
+    table: ResolveListIdx,
+) -> Result<(ResolvedTableReference, LocationRequirement)> {
+    match resolve_context.tables.try_get_bound(table)? {
+        (ResolvedTableOrCteReference::Table(reference), location) => {
+            Ok((reference.clone(), location))
+        }
+        (ResolvedTableOrCteReference::Cte { .. }, _) => Err(DbError::new("Cannot modify a CTE")),
+        (ResolvedTableOrCteReference::View(_), _) => Err(DbError::new("Cannot modify a view")),
+    }
+}
+
+/// Get the row id column for the table being modified.
+///
+/// Row ids come from the metadata columns of the table scan. Only tables in
+/// memory and database files have them.
+pub fn row_id_expression(
+    bind_context: &BindContext,
+    meta_table_ref: Option<TableRef>,
+    reference: &ResolvedTableReference,
+) -> Result<Expression> {
+    meta_table_ref
+        .map(|table_ref| -> Result<_> {
+            let table = bind_context.get_table(table_ref)?;
+            let column = table
+                .column_names
+                .iter()
+                .position(|name| name.as_normalized_str() == ROWID_COLUMN_NAME);
+            Ok(column.map(|column| {
+                Expression::Column(ColumnExpr {
+                    reference: ColumnReference {
+                        table_scope: table_ref,
+                        column,
+                    },
+                    datatype: DataType::int64(),
+                })
+            }))
+        })
+        .transpose()?
+        .flatten()
+        .ok_or_else(|| {
+            DbError::new(format!(
+                "Table '{}' does not support DELETE or UPDATE",
+                reference.entry.name
+            ))
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct BoundDelete {
+    /// Scan producing rows to delete.
+    pub scan: BoundTargetScan,
+    /// Table for projecting the row ids out of the scan.
+    pub projection_table: TableRef,
+}
+
+#[derive(Debug)]
+pub struct DeleteBinder<'a> {
+    pub current: BindScopeRef,
+    pub resolve_context: &'a ResolveContext,
+}
+
+impl<'a> DeleteBinder<'a> {
+    pub fn new(current: BindScopeRef, resolve_context: &'a ResolveContext) -> Self {
+        DeleteBinder {
+            current,
+            resolve_context,
+        }
+    }
+
+    pub fn bind_delete(
+        &self,
+        bind_context: &mut BindContext,
+        delete: ast::Delete<ResolvedMeta>,
+    ) -> Result<BoundDelete> {
+        bind_context.push_table(self.current, None, [DataType::int64()], ["rows_deleted"])?;
+
+        let source_scope = bind_context.new_orphan_scope();
+        let scan = BoundTargetScan::bind(
+            bind_context,
+            source_scope,
+            self.resolve_context,
+            delete.table,
+            delete.alias,
+            delete.where_expr,
+        )?;
+
+        let projection_table = bind_context.new_ephemeral_table_with_columns(
+            vec![DataType::int64()],
+            ["__generated_delete_rowid"],
+        )?;
+
+        Ok(BoundDelete {
+            scan,
+            projection_table,
+        })
+    }
+}
@@ -5,9 +5,12 @@ use super::bind_context::{BindContext, BindScopeRef};
 use super::bind_query::BoundQuery;
 use super::table_list::TableRef;
 use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::ScalarValue;
 use crate::expr::column_expr::{ColumnExpr, ColumnReference};
-use crate::expr::{Expression, cast};
+use crate::expr::{Expression, cast, lit};
 use crate::logical::binder::bind_query::QueryBinder;
+use crate::logical::binder::ident::BinderIdent;
+use crate::logical::logical_merge::ConflictBehavior;
 use crate::logical::operator::LocationRequirement;
 use crate::logical::resolver::ResolvedMeta;
 use crate::logical::resolver::resolve_context::ResolveContext;
@@ -34,6 +37,12 @@ pub struct BoundInsert {
     ///
     /// None if no casts are needed.
     pub projections: Option<InsertProjections>,
+    /// How to handle rows conflicting with unique constraints on the table.
+    ///
+    /// None if the table has no constraints, and rows can be appended
+    /// directly. Otherwise the projections produce a NULL row id and a true
+    /// insert flag before the values for the merge.
+    pub conflict: Option<ConflictBehavior>,
 }
 
 #[derive(Debug)]
@@ -74,18 +83,41 @@ impl<'a> InsertBinder<'a> {
             }
         };
 
+        let table_ent = reference.entry.try_as_table_entry()?;
+        let conflict = match insert.on_conflict {
+            None if table_ent.unique_constraints.is_empty() => None,
+            None => Some(ConflictBehavior::Error),
+            Some(on_conflict) => match on_conflict.action {
+                ast::OnConflictAction::DoNothing => {
+                    if !on_conflict.columns.is_empty() {
+                        // Always check the target, even if the table has no
+                        // constraints for it to match.
+                        let constraint = find_conflict_constraint(reference, &on_conflict.columns)?;
+                        Some(ConflictBehavior::DoNothing {
+                            constraint: Some(constraint),
+                        })
+                    } else if table_ent.unique_constraints.is_empty() {
+                        // Nothing can conflict.
+                        None
+                    } else {
+                        Some(ConflictBehavior::DoNothing { constraint: None })
+                    }
+                }
+                ast::OnConflictAction::DoUpdate { .. } => {
+                    return Err(DbError::new(
+                        "ON CONFLICT DO UPDATE should be bound as a MERGE",
+                    ));
+                }
+            },
+        };
+
         // TODO: Handle specified columns. If provided, insert a projection that
         // maps the columns to the right position.
         //
         // Currently assumes we're inserting by position.
 
         // Check types, determine appropriate casts.
-        let table_types = reference
-            .entry
-            .try_as_table_entry()?
-            .columns
-            .iter()
-            .map(|c| &c.datatype);
+        let table_types = table_ent.columns.iter().map(|c| &c.datatype);
 
         // Types from the source plan.
         let source_types: Vec<(TableRef, usize, &DataType)> = bind_context
@@ -108,7 +140,13 @@ impl<'a> InsertBinder<'a> {
         }
 
         let mut has_cast = false;
-        let mut projections = Vec::with_capacity(source_types.len());
+        let mut projections = Vec::with_capacity(source_types.len() + 2);
+
+        if conflict.is_some() {
+            // All rows are new.
+            projections.push(cast(lit(ScalarValue::Null), DataType::int64())?.into());
+            projections.push(lit(true).into());
+        }
 
         for (have, want) in source_types.into_iter().zip(table_types) {
             let mut expr = Expression::Column(ColumnExpr {
@@ -127,8 +165,9 @@ impl<'a> InsertBinder<'a> {
             projections.push(expr);
         }
 
-        // Only use projections if there's a cast.
-        let projections = if has_cast {
+        // Only use projections if there's a cast, or if we're inserting through
+        // a merge.
+        let projections = if has_cast || conflict.is_some() {
             let projection_table = bind_context.new_ephemeral_table_with_columns(
                 projections
                     .iter()
@@ -150,6 +189,46 @@ impl<'a> InsertBinder<'a> {
             table: reference.clone(),
             table_location: location,
             projections,
+            conflict,
         })
     }
 }
+
+/// Find the index of the unique constraint on a table matching the columns in
+/// an ON CONFLICT target.
+pub fn find_conflict_constraint(
+    table: &ResolvedTableReference,
+    columns: &[ast::Ident],
+) -> Result<usize> {
+    let table_ent = table.entry.try_as_table_entry()?;
+
+    let mut indices = Vec::with_capacity(columns.len());
+    for column in columns {
+        let ident = BinderIdent::from(column.clone());
+        let idx = table_ent
+            .columns
+            .iter()
+            .position(|col| BinderIdent::from(col.name.as_str()).strict_eq(&ident))
+            .ok_or_else(|| {
+                DbError::new(format!(
+                    "Column '{ident}' not found in table '{}'",
+                    table.entry.name
+                ))
+            })?;
+        indices.push(idx);
+    }
+    indices.sort_unstable();
+    indices.dedup();
+
+    table_ent
+        .unique_constraints
+        .iter()
+        .position(|constraint| {
+            let mut constraint_cols = constraint.columns.clone();
+            constraint_cols.sort_unstable();
+            constraint_cols == indices
+        })
+        .ok_or_else(|| {
+            DbError::new("ON CONFLICT columns do not match any unique or primary key constraint")
+        })
+}
@@ -0,0 +1,482 @@
+use glaredb_error::{DbError, Result};
+use glaredb_parser::ast;
+
+use super::bind_context::{BindContext, BindScopeRef};
+use super::bind_delete::{BoundTargetScan, resolve_target_table, row_id_expression};
+use super::bind_insert::find_conflict_constraint;
+use super::bind_query::bind_from::{BoundFromItem, FromBinder};
+use super::bind_update::bind_assignments;
+use super::table_list::TableRef;
+use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::case_expr::{CaseExpr, WhenThen};
+use crate::expr::column_expr::{ColumnExpr, ColumnReference};
+use crate::expr::scalar_function_expr::ScalarFunctionExpr;
+use crate::expr::{self, Expression, cast, lit};
+use crate::functions::scalar::builtin::is::{FUNCTION_SET_IS_NOT_NULL, FUNCTION_SET_IS_NULL};
+use crate::logical::binder::column_binder::DefaultColumnBinder;
+use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
+use crate::logical::binder::ident::BinderIdent;
+use crate::logical::logical_merge::ConflictBehavior;
+use crate::logical::resolver::resolve_context::ResolveContext;
+use crate::logical::resolver::resolved_table::ResolvedTableReference;
+use crate::logical::resolver::{ResolvedMeta, ResolvedSubqueryOptions};
+
+#[derive(Debug, Clone)]
+pub struct BoundMerge {
+    /// Source left joined with the table being modified, filtered to rows with
+    /// an action to take.
+    pub scan: BoundTargetScan,
+    /// Projections producing the row id of the row to delete, the insert flag,
+    /// followed by the values for every column in the table.
+    pub projections: Vec<Expression>,
+    pub projection_table: TableRef,
+    pub conflict: ConflictBehavior,
+}
+
+/// A bound WHEN clause.
+#[derive(Debug)]
+struct BoundMergeClause {
+    /// Condition for when this clause applies, including if the source row
+    /// matched.
+    when: Expression,
+    /// Row id of the row to delete, NULL if not deleting.
+    row_id: Expression,
+    /// If the values should be inserted.
+    insert: bool,
+    /// Values for every column in the table.
+    values: Vec<Expression>,
+    /// If this clause does nothing.
+    do_nothing: bool,
+}
+
+#[derive(Debug)]
+pub struct MergeBinder<'a> {
+    pub current: BindScopeRef,
+    pub resolve_context: &'a ResolveContext,
+}
+
+impl<'a> MergeBinder<'a> {
+    pub fn new(current: BindScopeRef, resolve_context: &'a ResolveContext) -> Self {
+        MergeBinder {
+            current,
+            resolve_context,
+        }
+    }
+
+    pub fn bind_merge(
+        &self,
+        bind_context: &mut BindContext,
+        merge: ast::Merge<ResolvedMeta>,
+    ) -> Result<BoundMerge> {
+        bind_context.push_table(self.current, None, [DataType::int64()], ["rows_merged"])?;
+        self.bind_merge_inner(bind_context, merge)
+    }
+
+    /// Bind `INSERT ... ON CONFLICT (...) DO UPDATE` by rewriting it to a MERGE
+    /// with the rows being inserted available as `excluded`.
+    pub fn bind_insert_on_conflict(
+        &self,
+        bind_context: &mut BindContext,
+        insert: ast::Insert<ResolvedMeta>,
+    ) -> Result<BoundMerge> {
+        bind_context.push_table(self.current, None, [DataType::int64()], ["rows_inserted"])?;
+
+        let (assignments, where_expr, conflict_columns) = match insert.on_conflict {
+            Some(ast::InsertOnConflict {
+                columns,
+                action:
+                    ast::OnConflictAction::DoUpdate {
+                        assignments,
+                        where_expr,
+                    },
+            }) => (assignments, where_expr, columns),
+            _ => return Err(DbError::new("Expected ON CONFLICT DO UPDATE")),
+        };
+
+        if conflict_columns.is_empty() {
+            return Err(DbError::new(
+                "ON CONFLICT DO UPDATE requires specifying the conflict columns",
+            ));
+        }
+
+        let (reference, _) = resolve_target_table(self.resolve_context, insert.table)?;
+        let constraint_idx = find_conflict_constraint(&reference, &conflict_columns)?;
+        let table_ent = reference.entry.try_as_table_entry()?;
+
+        let quoted = |value: &str| ast::Ident {
+            value: value.to_string(),
+            quoted: true,
+        };
+        let excluded = ast::Ident::new_unquoted("excluded");
+        let table_name = quoted(&reference.entry.name);
+
+        let source = ast::FromNode {
+            alias: Some(ast::FromAlias {
+                alias: excluded.clone(),
+                columns: Some(table_ent.columns.iter().map(|c| quoted(&c.name)).collect()),
+            }),
+            body: ast::FromNodeBody::Subquery(ast::FromSubquery {
+                lateral: false,
+                options: ResolvedSubqueryOptions::Normal,
+                query: insert.source,
+            }),
+        };
+
+        // table.k1 = excluded.k1 AND table.k2 = excluded.k2 ...
+        let on = table_ent.unique_constraints[constraint_idx]
+            .columns
+            .iter()
+            .map(|&idx| {
+                let col = quoted(&table_ent.columns[idx].name);
+                ast::Expr::BinaryExpr {
+                    left: Box::new(ast::Expr::CompoundIdent(vec![
+                        table_name.clone(),
+                        col.clone(),
+                    ])),
+                    op: ast::BinaryOperator::Eq,
+                    right: Box::new(ast::Expr::CompoundIdent(vec![excluded.clone(), col])),
+                }
+            })
+            .reduce(|left, right| ast::Expr::BinaryExpr {
+                left: Box::new(left),
+                op: ast::BinaryOperator::And,
+                right: Box::new(right),
+            })
+            .ok_or_else(|| DbError::new("Constraint has no columns"))?;
+
+        let insert_values = table_ent
+            .columns
+            .iter()
+            .map(|c| ast::Expr::CompoundIdent(vec![excluded.clone(), quoted(&c.name)]))
+            .collect();
+
+        let merge = ast::Merge {
+            table: insert.table,
+            alias: None,
+            source,
+            on,
+            clauses: vec![
+                ast::MergeClause {
+                    matched: true,
+                    condition: where_expr,
+                    action: ast::MergeAction::Update { assignments },
+                },
+                ast::MergeClause {
+                    matched: false,
+                    condition: None,
+                    action: ast::MergeAction::Insert {
+                        columns: Vec::new(),
+                        values: insert_values,
+                    },
+                },
+            ],
+        };
+
+        self.bind_merge_inner(bind_context, merge)
+    }
+
+    fn bind_merge_inner(
+        &self,
+        bind_context: &mut BindContext,
+        merge: ast::Merge<ResolvedMeta>,
+    ) -> Result<BoundMerge> {
+        let (reference, location) = resolve_target_table(self.resolve_context, merge.table)?;
+        let columns = reference.entry.try_as_table_entry()?.columns.clone();
+
+        // Left join the source with the table. Source rows without a matching
+        // row in the table will have a NULL row id.
+        let join = ast::FromNode {
+            alias: None,
+            body: ast::FromNodeBody::Join(ast::FromJoin {
+                left: Box::new(merge.source),
+                right: Box::new(ast::FromNode {
+                    alias: merge.alias.map(|alias| ast::FromAlias {
+                        alias,
+                        columns: None,
+                    }),
+                    body: ast::FromNodeBody::BaseTable(ast::FromBaseTable {
+                        reference: merge.table,
+                    }),
+                }),
+                join_type: ast::JoinType::Left,
+                join_condition: ast::JoinCondition::On(merge.on),
+            }),
+        };
+
+        let source_scope = bind_context.new_orphan_scope();
+        let from =
+            FromBinder::new(source_scope, self.resolve_context).bind(bind_context, Some(join))?;
+
+        let (data_table_ref, meta_table_ref) = match &from.item {
+            BoundFromItem::Join(join) => match &join.right.item {
+                BoundFromItem::BaseTable(table) => (table.data_table_ref, table.meta_table_ref),
+                other => {
+                    return Err(DbError::new(format!(
+                        "Unexpected bound item for table being modified: {other:?}"
+                    )));
+                }
+            },
+            other => {
+                return Err(DbError::new(format!(
+                    "Unexpected bound item for MERGE source: {other:?}"
+                )));
+            }
+        };
+        let row_id = row_id_expression(bind_context, meta_table_ref, &reference)?;
+
+        let mut clauses = Vec::with_capacity(merge.clauses.len());
+        for clause in merge.clauses {
+            clauses.push(self.bind_clause(
+                bind_context,
+                source_scope,
+                &reference,
+                &row_id,
+                data_table_ref,
+                clause,
+            )?);
+        }
+
+        // Only keep rows where the first matching clause does something.
+        let filter = CaseExpr::try_new(
+            clauses
+                .iter()
+                .map(|clause| WhenThen {
+                    when: clause.when.clone(),
+                    then: lit(!clause.do_nothing).into(),
+                })
+                .collect(),
+            Some(Box::new(lit(false).into())),
+        )?;
+
+        let mut projections: Vec<Expression> = Vec::with_capacity(columns.len() + 2);
+        projections.push(
+            CaseExpr::try_new(
+                clauses
+                    .iter()
+                    .map(|clause| WhenThen {
+                        when: clause.when.clone(),
+                        then: clause.row_id.clone(),
+                    })
+                    .collect(),
+                Some(Box::new(null_of(DataType::int64())?)),
+            )?
+            .into(),
+        );
+        projections.push(
+            CaseExpr::try_new(
+                clauses
+                    .iter()
+                    .map(|clause| WhenThen {
+                        when: clause.when.clone(),
+                        then: lit(clause.insert).into(),
+                    })
+                    .collect(),
+                Some(Box::new(lit(false).into())),
+            )?
+            .into(),
+        );
+        for (col_idx, col) in columns.iter().enumerate() {
+            projections.push(
+                CaseExpr::try_new(
+                    clauses
+                        .iter()
+                        .map(|clause| WhenThen {
+                            when: clause.when.clone(),
+                            then: clause.values[col_idx].clone(),
+                        })
+                        .collect(),
+                    Some(Box::new(null_of(col.datatype.clone())?)),
+                )?
+                .into(),
+            );
+        }
+
+        let projection_table = bind_context.new_ephemeral_table_with_columns(
+            projections
+                .iter()
+                .map(|p| p.datatype())
+                .collect::<Result<Vec<_>>>()?,
+            (0..projections.len()).map(|idx| format!("__generated_merge_project_{idx}")),
+        )?;
+
+        Ok(BoundMerge {
+            scan: BoundTargetScan {
+                from,
+                filter: Some(filter.into()),
+                row_id,
+                data_table_ref,
+                table: reference,
+                table_location: location,
+            },
+            projections,
+            projection_table,
+            conflict: ConflictBehavior::Error,
+        })
+    }
+
+    fn bind_clause(
+        &self,
+        bind_context: &mut BindContext,
+        current: BindScopeRef,
+        table: &ResolvedTableReference,
+        row_id: &Expression,
+        data_table_ref: TableRef,
+        clause: ast::MergeClause<ResolvedMeta>,
+    ) -> Result<BoundMergeClause> {
+        let columns = &table.entry.try_as_table_entry()?.columns;
+
+        let function_set = if clause.matched {
+            &FUNCTION_SET_IS_NOT_NULL
+        } else {
+            &FUNCTION_SET_IS_NULL
+        };
+        let matched = Expression::ScalarFunction(ScalarFunctionExpr {
+            function: expr::bind_scalar_function(function_set, vec![row_id.clone()])?,
+        });
+        let when = match clause.condition {
+            Some(condition) => {
+                let condition = self.bind_expression(bind_context, current, &condition)?;
+                expr::and([matched, condition])?.into()
+            }
+            None => matched,
+        };
+
+        let nulls = || {
+            columns
+                .iter()
+                .map(|col| null_of(col.datatype.clone()))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        match clause.action {
+            ast::MergeAction::Update { assignments } => {
+                let values = bind_assignments(
+                    bind_context,
+                    current,
+                    self.resolve_context,
+                    table,
+                    assignments,
+                )?;
+                let values = values
+                    .into_iter()
+                    .zip(columns)
+                    .enumerate()
+                    .map(|(col_idx, (value, col))| {
+                        value.unwrap_or_else(|| {
+                            Expression::Column(ColumnExpr {
+                                reference: ColumnReference {
+                                    table_scope: data_table_ref,
+                                    column: col_idx,
+                                },
+                                datatype: col.datatype.clone(),
+                            })
+                        })
+                    })
+                    .collect();
+
+                Ok(BoundMergeClause {
+                    when,
+                    row_id: row_id.clone(),
+                    insert: true,
+                    values,
+                    do_nothing: false,
+                })
+            }
+            ast::MergeAction::Delete => Ok(BoundMergeClause {
+                when,
+                row_id: row_id.clone(),
+                insert: false,
+                values: nulls()?,
+                do_nothing: false,
+            }),
+            ast::MergeAction::Insert {
+                columns: insert_columns,
+                values: insert_values,
+            } => {
+                // Column indices for each value, defaulting to all columns in
+                // order.
+                let indices: Vec<usize> = if insert_columns.is_empty() {
+                    (0..columns.len()).collect()
+                } else {
+                    let mut indices = Vec::with_capacity(insert_columns.len());
+                    for column in insert_columns {
+                        let ident = BinderIdent::from(column);
+                        let idx = columns
+                            .iter()
+                            .position(|col| BinderIdent::from(col.name.as_str()).strict_eq(&ident))
+                            .ok_or_else(|| {
+                                DbError::new(format!(
+                                    "Column '{ident}' not found in table '{}'",
+                                    table.entry.name
+                                ))
+                            })?;
+                        if indices.contains(&idx) {
+                            return Err(DbError::new(format!(
+                                "Column '{ident}' specified more than once"
+                            )));
+                        }
+                        indices.push(idx);
+                    }
+                    indices
+                };
+
+                if indices.len() != insert_values.len() {
+                    return Err(DbError::new(format!(
+                        "Invalid number of values for INSERT. Expected {}, got {}",
+                        indices.len(),
+                        insert_values.len(),
+                    )));
+                }
+
+                let mut values = nulls()?;
+                for (idx, value) in indices.into_iter().zip(insert_values) {
+                    let mut expr = self.bind_expression(bind_context, current, &value)?;
+                    let want = &columns[idx].datatype;
+                    if &expr.datatype()? != want {
+                        expr = cast(expr, want.clone())?.into();
+                    }
+                    values[idx] = expr;
+                }
+
+                Ok(BoundMergeClause {
+                    when,
+                    row_id: null_of(DataType::int64())?,
+                    insert: true,
+                    values,
+                    do_nothing: false,
+                })
+            }
+            ast::MergeAction::DoNothing => Ok(BoundMergeClause {
+                when,
+                row_id: null_of(DataType::int64())?,
+                insert: false,
+                values: nulls()?,
+                do_nothing: true,
+            }),
+        }
+    }
+
+    fn bind_expression(
+        &self,
+        bind_context: &mut BindContext,
+        current: BindScopeRef,
+        expr: &ast::Expr<ResolvedMeta>,
+    ) -> Result<Expression> {
+        BaseExpressionBinder::new(current, self.resolve_context).bind_expression(
+            bind_context,
+            expr,
+            &mut DefaultColumnBinder,
+            RecursionContext {
+                allow_windows: false,
+                allow_aggregates: false,
+                is_root: true,
+            },
+        )
+    }
+}
+
+/// Typed NULL literal.
+fn null_of(datatype: DataType) -> Result<Expression> {
+    Ok(cast(lit(ScalarValue::Null), datatype)?.into())
+}
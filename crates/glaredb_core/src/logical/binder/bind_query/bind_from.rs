@@ -1,13 +1,12 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use glaredb_error::{DbError, Result, not_implemented};
+use glaredb_error::{DbError, Result};
 use glaredb_parser::ast;
 
 use super::{BoundQuery, QueryBinder};
 use crate::arrays::datatype::DataType;
 use crate::catalog::entry::CatalogEntry;
-use crate::expr::column_expr::ColumnReference;
 use crate::expr::comparison_expr::ComparisonOperator;
 use crate::expr::{self, Expression};
 use crate::functions::table::{PlannedTableFunction, TableFunctionInput};
@@ -42,6 +41,7 @@ pub enum BoundFromItem {
     TableFunction(BoundTableFunction),
     Subquery(BoundSubquery),
     MaterializedCte(BoundMaterializedCte),
+    RecursiveCteScan(BoundRecursiveCteScan),
     Empty,
 }
 
@@ -94,6 +94,13 @@ pub struct BoundMaterializedCte {
     pub cte_name: BinderIdent,
 }
 
+/// Reference to a recursive CTE from within its own recursive term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundRecursiveCteScan {
+    pub table_ref: TableRef,
+    pub cte_ref: CteRef,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoundJoin {
     /// Reference to binder for left side of join.
@@ -221,10 +228,8 @@ impl<'a> FromBinder<'a> {
                     table: BinderIdent::from(table.entry.name.clone()),
                 };
 
-                // Handle "metadata" columns if we have them. Currently this is
-                // always None, but will probably change with an iceberg catalog
-                // (which would be returning an iceberg_scan function which will
-                // have metadata).
+                // Handle "metadata" columns if we have them (e.g. `_rowid` for
+                // tables in memory or database files).
                 let meta_table_ref = match &table.scan_function.bind_state.meta_schema {
                     Some(meta_schema) => {
                         let (meta_column_types, meta_column_names): (Vec<_>, Vec<_>) = meta_schema
@@ -308,7 +313,23 @@ impl<'a> FromBinder<'a> {
         let names = cte.column_names.clone();
         let types = cte.column_types.clone();
 
-        if cte.materialized {
+        if cte.binding_recursive_term {
+            // Self-reference from the recursive term, read from the working
+            // table.
+            let table_ref = self.push_table_scope_with_from_alias(
+                bind_context,
+                Some(table_alias),
+                names,
+                types,
+                alias,
+            )?;
+            bind_context.get_cte_mut(cte_ref)?.recursive_references += 1;
+
+            Ok(BoundFrom {
+                bind_ref: self.current,
+                item: BoundFromItem::RecursiveCteScan(BoundRecursiveCteScan { table_ref, cte_ref }),
+            })
+        } else if cte.materialized {
             let cte_name = cte.name.clone();
             // Binds with the alias provided in the FROM.
             //
@@ -587,8 +608,7 @@ impl<'a> FromBinder<'a> {
                 (Vec::new(), using_cols)
             }
             ast::JoinCondition::Natural => {
-                // TODO: Need to check if this inadvertantly picks up metadata
-                // columns.
+                // Metadata columns (not star expandable) are never joined on.
 
                 // Get tables refs from the left.
                 //
@@ -602,6 +622,7 @@ impl<'a> FromBinder<'a> {
                 // Get columns from the left.
                 let left_cols: HashSet<_> = bind_context
                     .iter_tables_in_scope(left_idx)?
+                    .filter(|table| table.star_expandable)
                     .flat_map(|table| table.column_names.iter())
                     .collect();
 
@@ -609,7 +630,9 @@ impl<'a> FromBinder<'a> {
                 // would generate a lateral reference.
                 let right_cols = bind_context
                     .iter_tables_in_scope(right_idx)?
-                    .filter(|table| !left_tables.contains(&table.reference))
+                    .filter(|table| {
+                        table.star_expandable && !left_tables.contains(&table.reference)
+                    })
                     .flat_map(|table| table.column_names.iter());
 
                 let mut common = Vec::new();
@@ -629,14 +652,25 @@ impl<'a> FromBinder<'a> {
             ast::JoinCondition::None => (Vec::new(), Vec::new()),
         };
 
-        let join_type = match join.join_type {
-            ast::JoinType::Inner => JoinType::Inner,
-            ast::JoinType::Left => JoinType::Left,
-            ast::JoinType::Right => JoinType::Right,
-            ast::JoinType::LeftSemi => JoinType::LeftSemi,
-            other => not_implemented!("plan join type: {other:?}"),
+        // RIGHT SEMI/ANTI joins are bound as LEFT SEMI/ANTI joins with the
+        // inputs swapped.
+        let (join_type, swap_sides) = match join.join_type {
+            ast::JoinType::Inner => (JoinType::Inner, false),
+            ast::JoinType::Left => (JoinType::Left, false),
+            ast::JoinType::Right => (JoinType::Right, false),
+            ast::JoinType::Outer => (JoinType::Full, false),
+            ast::JoinType::LeftSemi => (JoinType::LeftSemi, false),
+            ast::JoinType::LeftAnti => (JoinType::LeftAnti, false),
+            ast::JoinType::RightSemi => (JoinType::LeftSemi, true),
+            ast::JoinType::RightAnti => (JoinType::LeftAnti, true),
         };
 
+        if swap_sides && !lateral_columns.is_empty() {
+            return Err(DbError::new(
+                "Lateral references not supported for RIGHT SEMI or RIGHT ANTI joins",
+            ));
+        }
+
         // Move left and right into current context.
         bind_context.append_context(self.current, left_idx)?;
         bind_context.append_context(self.current, right_idx)?;
@@ -662,67 +696,59 @@ impl<'a> FromBinder<'a> {
             };
 
             // TODO: Case sensitivity
-            let (left_table, left_col_idx) = bind_context
-                .find_table_for_column(left_idx, None, &using)?
+            let left = find_using_side(bind_context, left_idx, &using)?
                 .ok_or_else(|| missing_column("left"))?;
-            let (right_table, right_col_idx) = bind_context
-                .find_table_for_column(right_idx, None, &using)?
+            let right = find_using_side(bind_context, right_idx, &using)?
                 .ok_or_else(|| missing_column("right"))?;
 
+            // Generate additional equality condition.
+            let condition = expr::compare(
+                ComparisonOperator::Eq,
+                left.as_expr(bind_context)?,
+                right.as_expr(bind_context)?,
+            )?;
+
             let using_column = match join_type {
+                JoinType::Full => {
+                    // Neither side can be used on its own for the output
+                    // column as either may be NULL. Use the first non-NULL
+                    // value from either side.
+                    //
+                    // The (possibly casted) inputs to the condition are used
+                    // so that both sides have the same type.
+                    let coalesced = expr::coalesce([
+                        condition.left.as_ref().clone(),
+                        condition.right.as_ref().clone(),
+                    ])?;
+                    UsingColumn {
+                        coalesced: Some(coalesced.into()),
+                        ..left
+                    }
+                }
+                _ if swap_sides => right,
                 JoinType::Left
                 | JoinType::Inner
-                | JoinType::Full
                 | JoinType::LeftSemi
                 | JoinType::LeftAnti
-                | JoinType::LeftMark { .. } => UsingColumn {
-                    column: using,
-                    table_ref: left_table,
-                    col_idx: left_col_idx,
-                },
-                JoinType::Right => UsingColumn {
-                    column: using,
-                    table_ref: right_table,
-                    col_idx: right_col_idx,
-                },
+                | JoinType::LeftMark { .. } => left,
+                JoinType::Right => right,
             };
 
-            // Add USING column to _current_ scope if we don't already have an
-            // equivalent column in our using set.
-            let already_using = bind_context
-                .get_using_columns(self.current)?
-                .iter()
-                .any(|c| c.column == using_column.column);
-
-            if !already_using {
-                bind_context.append_using_column(self.current, using_column)?;
-            }
-
-            // Generate additional equality condition.
-            let left_reference = ColumnReference {
-                table_scope: left_table,
-                column: left_col_idx,
-            };
-            let right_reference = ColumnReference {
-                table_scope: right_table,
-                column: right_col_idx,
-            };
-
-            let condition = expr::compare(
-                ComparisonOperator::Eq,
-                bind_context
-                    .get_table_list()
-                    .column_as_expr(left_reference)?,
-                bind_context
-                    .get_table_list()
-                    .column_as_expr(right_reference)?,
-            )?;
+            // Add USING column to _current_ scope, replacing the column from a
+            // previous join if there is one.
+            bind_context.append_using_column(self.current, using_column)?;
 
             conditions.push(condition.into());
         }
 
-        // Remove right columns from scope for semi joins.
-        if join_type == JoinType::LeftSemi {
+        let (left_idx, left, right_idx, right) = if swap_sides {
+            (right_idx, right, left_idx, left)
+        } else {
+            (left_idx, left, right_idx, right)
+        };
+
+        // Remove right columns from scope for semi and anti joins.
+        if matches!(join_type, JoinType::LeftSemi | JoinType::LeftAnti) {
             let right_tables: Vec<_> = bind_context
                 .iter_tables_in_scope(right_idx)?
                 .map(|t| t.reference)
@@ -744,3 +770,29 @@ impl<'a> FromBinder<'a> {
         })
     }
 }
+
+/// Find the column on one side of a join for a USING condition.
+///
+/// This will find USING columns coalesced from a previous FULL join before
+/// searching tables.
+fn find_using_side(
+    bind_context: &BindContext,
+    side: BindScopeRef,
+    using: &BinderIdent,
+) -> Result<Option<UsingColumn>> {
+    if let Some(coalesced) = bind_context.find_coalesced_using_column(side, using)? {
+        return Ok(Some(UsingColumn {
+            column: using.clone(),
+            ..coalesced.clone()
+        }));
+    }
+
+    Ok(bind_context
+        .find_table_for_column(side, None, using)?
+        .map(|(table_ref, col_idx)| UsingColumn {
+            column: using.clone(),
+            table_ref,
+            col_idx,
+            coalesced: None,
+        }))
+}
@@ -1,10 +1,11 @@
 use std::collections::BTreeSet;
 
-use glaredb_error::{DbError, Result, not_implemented};
+use glaredb_error::{DbError, Result};
 use glaredb_parser::ast;
 
 use super::select_list::SelectList;
 use crate::expr::Expression;
+use crate::expr::column_expr::{ColumnExpr, ColumnReference};
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::column_binder::{DefaultColumnBinder, ExpressionColumnBinder};
 use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
@@ -34,13 +35,85 @@ impl<'a> GroupByBinder<'a> {
         }
     }
 
+    /// Binds the GROUP BY.
+    ///
+    /// Returns None if this is a GROUP BY ALL with every expression in the
+    /// select list being an aggregate, in which case the query should be
+    /// treated as an ungrouped aggregate.
     pub fn bind(
         &mut self,
         bind_context: &mut BindContext,
         select_list: &mut SelectList,
         group_by: ast::GroupByNode<ResolvedMeta>,
+    ) -> Result<Option<BoundGroupBy>> {
+        match group_by {
+            ast::GroupByNode::All => self.bind_all(bind_context, select_list),
+            ast::GroupByNode::Exprs { exprs } => {
+                let sets = GroupByWithSets::try_from_ast(exprs)?;
+                let group_by = self.bind_sets(bind_context, select_list, sets)?;
+                Ok(Some(group_by))
+            }
+        }
+    }
+
+    /// Binds GROUP BY ALL, grouping by all expressions in the select list that
+    /// don't contain an aggregate.
+    fn bind_all(
+        &mut self,
+        bind_context: &mut BindContext,
+        select_list: &mut SelectList,
+    ) -> Result<Option<BoundGroupBy>> {
+        let group_table = bind_context.new_ephemeral_table()?;
+
+        let mut expressions = Vec::new();
+        for (idx, expr) in select_list.projections.iter().enumerate() {
+            let tables = expr.get_table_references();
+            if tables.contains(&select_list.aggregates_table)
+                || tables.contains(&select_list.grouping_functions_table)
+                || tables.contains(&select_list.windows_table)
+            {
+                // Computed from the aggregate output.
+                continue;
+            }
+
+            // Reference the select list similar to grouping by ordinal, this
+            // gets updated to the actual expression when the select list is
+            // finalized.
+            let datatype = expr.datatype()?;
+            expressions.push(Expression::Column(ColumnExpr {
+                reference: ColumnReference {
+                    table_scope: select_list.projections_table,
+                    column: idx,
+                },
+                datatype: datatype.clone(),
+            }));
+
+            bind_context.push_column_for_table(
+                group_table,
+                format!("__generated_group_{}", expressions.len() - 1),
+                datatype,
+            )?;
+        }
+
+        if expressions.is_empty() {
+            return Ok(None);
+        }
+
+        let len = expressions.len();
+
+        Ok(Some(BoundGroupBy {
+            expressions,
+            group_exprs_table: group_table,
+            grouping_sets: vec![(0..len).collect()],
+        }))
+    }
+
+    fn bind_sets(
+        &mut self,
+        bind_context: &mut BindContext,
+        select_list: &mut SelectList,
+        sets: GroupByWithSets,
     ) -> Result<BoundGroupBy> {
-        let sets = GroupByWithSets::try_from_ast(group_by)?;
         let group_table = bind_context.new_ephemeral_table()?;
 
         let mut column_binder = GroupByColumnBinder { select_list };
@@ -147,54 +220,110 @@ struct GroupByWithSets {
 }
 
 impl GroupByWithSets {
-    fn try_from_ast(group_by: ast::GroupByNode<ResolvedMeta>) -> Result<Self> {
-        match group_by {
-            ast::GroupByNode::All => not_implemented!("GROUP BY ALL"),
-            ast::GroupByNode::Exprs { mut exprs } => {
-                let expr = match exprs.len() {
-                    1 => exprs.pop().unwrap(),
-                    _ => return Err(DbError::new("Invalid number of group by expressions")),
-                };
-
-                let (expressions, grouping_sets) = match expr {
-                    ast::GroupByExpr::Expr(exprs) => {
-                        let len = exprs.len();
-                        (exprs, vec![(0..len).collect()])
-                    }
-                    ast::GroupByExpr::Rollup(exprs) => {
-                        let len = exprs.len();
-                        let mut sets: Vec<_> = (0..len).map(|i| (0..(len - i)).collect()).collect();
-                        sets.push(BTreeSet::new()); // Empty set.
-                        (exprs, sets)
-                    }
-                    ast::GroupByExpr::Cube(exprs) => {
-                        let len = exprs.len();
-                        let mut sets = Vec::new();
-
-                        // Powerset
-                        for mask in 0..(1 << len) {
-                            let mut set = BTreeSet::new();
-                            let mut bitset = mask;
-                            while bitset > 0 {
-                                let right: u64 = bitset & { !(bitset - 1) };
-                                let idx = right.trailing_zeros() as usize;
-                                set.insert(idx);
-                                bitset &= bitset - 1;
-                            }
-                            sets.push(set);
-                        }
+    /// Create the group by expressions and grouping sets from the group by
+    /// items.
+    ///
+    /// Each item produces its own grouping sets, and the final grouping sets
+    /// are the cross product of all items' sets. E.g. `GROUP BY a, ROLLUP (b,
+    /// c)` produces the sets `(a, b, c), (a, b), (a)`.
+    fn try_from_ast(exprs: Vec<ast::GroupByExpr<ResolvedMeta>>) -> Result<Self> {
+        if exprs.is_empty() {
+            return Err(DbError::new("Invalid number of group by expressions"));
+        }
 
-                        (exprs, sets)
+        let mut sets = GroupByWithSets {
+            expressions: Vec::new(),
+            grouping_sets: vec![BTreeSet::new()],
+        };
+
+        for expr in exprs {
+            let item_sets = match expr {
+                ast::GroupByExpr::Expr(exprs) => {
+                    vec![sets.push_expressions(exprs)]
+                }
+                ast::GroupByExpr::Rollup(exprs) => {
+                    let indices: Vec<_> = exprs
+                        .into_iter()
+                        .map(|expr| sets.push_expression(expr))
+                        .collect();
+
+                    let len = indices.len();
+                    (0..=len)
+                        .map(|i| indices[0..(len - i)].iter().copied().collect())
+                        .collect()
+                }
+                ast::GroupByExpr::Cube(exprs) => {
+                    let indices: Vec<_> = exprs
+                        .into_iter()
+                        .map(|expr| sets.push_expression(expr))
+                        .collect();
+
+                    let len = indices.len();
+                    if len > 12 {
+                        return Err(DbError::new(format!(
+                            "CUBE supports at most 12 expressions, got {len}"
+                        )));
                     }
-                    ast::GroupByExpr::GroupingSets(_exprs) => {
-                        not_implemented!("GROUPING SETS")
+
+                    // Powerset
+                    let mut item_sets = Vec::with_capacity(1 << len);
+                    for mask in 0..(1_u64 << len) {
+                        let mut set = BTreeSet::new();
+                        let mut bitset = mask;
+                        while bitset > 0 {
+                            let right: u64 = bitset & { !(bitset - 1) };
+                            let idx = right.trailing_zeros() as usize;
+                            set.insert(indices[idx]);
+                            bitset &= bitset - 1;
+                        }
+                        item_sets.push(set);
                     }
-                };
 
-                Ok(GroupByWithSets {
-                    expressions,
-                    grouping_sets,
+                    item_sets
+                }
+                ast::GroupByExpr::GroupingSets(grouping_sets) => grouping_sets
+                    .into_iter()
+                    .map(|exprs| sets.push_expressions(exprs))
+                    .collect(),
+            };
+
+            // Cross product with the existing sets.
+            sets.grouping_sets = sets
+                .grouping_sets
+                .iter()
+                .flat_map(|set| {
+                    item_sets
+                        .iter()
+                        .map(move |item_set| set.union(item_set).copied().collect())
                 })
+                .collect();
+        }
+
+        Ok(sets)
+    }
+
+    /// Push expressions, returning a set containing their indices.
+    fn push_expressions(&mut self, exprs: Vec<ast::Expr<ResolvedMeta>>) -> BTreeSet<usize> {
+        exprs
+            .into_iter()
+            .map(|expr| self.push_expression(expr))
+            .collect()
+    }
+
+    /// Push an expression, returning its index.
+    ///
+    /// Expressions that appear multiple times across grouping sets will share
+    /// the same index.
+    fn push_expression(&mut self, expr: ast::Expr<ResolvedMeta>) -> usize {
+        match self
+            .expressions
+            .iter()
+            .position(|existing| existing == &expr)
+        {
+            Some(idx) => idx,
+            None => {
+                self.expressions.push(expr);
+                self.expressions.len() - 1
             }
         }
     }
@@ -207,13 +336,11 @@ mod tests {
     #[test]
     fn group_by_with_sets_from_group_by_single() {
         // GROUP BY a
-        let node = ast::GroupByNode::Exprs {
-            exprs: vec![ast::GroupByExpr::Expr(vec![ast::Expr::Ident(
-                ast::Ident::new_unquoted("a"),
-            )])],
-        };
+        let exprs = vec![ast::GroupByExpr::Expr(vec![ast::Expr::Ident(
+            ast::Ident::new_unquoted("a"),
+        )])];
 
-        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let sets = GroupByWithSets::try_from_ast(exprs).unwrap();
         let expected = GroupByWithSets {
             expressions: vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))],
             grouping_sets: vec![[0].into()],
@@ -225,14 +352,12 @@ mod tests {
     #[test]
     fn group_by_with_sets_from_group_by_many() {
         // GROUP BY a, b
-        let node = ast::GroupByNode::Exprs {
-            exprs: vec![ast::GroupByExpr::Expr(vec![
-                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
-                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
-            ])],
-        };
+        let exprs = vec![ast::GroupByExpr::Expr(vec![
+            ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+            ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+        ])];
 
-        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let sets = GroupByWithSets::try_from_ast(exprs).unwrap();
         let expected = GroupByWithSets {
             expressions: vec![
                 ast::Expr::Ident(ast::Ident::new_unquoted("a")),
@@ -247,15 +372,13 @@ mod tests {
     #[test]
     fn group_by_with_sets_from_rollup() {
         // GROUP BY ROLLUP a, b, c
-        let node = ast::GroupByNode::Exprs {
-            exprs: vec![ast::GroupByExpr::Rollup(vec![
-                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
-                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
-                ast::Expr::Ident(ast::Ident::new_unquoted("c")),
-            ])],
-        };
+        let exprs = vec![ast::GroupByExpr::Rollup(vec![
+            ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+            ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+        ])];
 
-        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let sets = GroupByWithSets::try_from_ast(exprs).unwrap();
         let expected = GroupByWithSets {
             expressions: vec![
                 ast::Expr::Ident(ast::Ident::new_unquoted("a")),
@@ -271,15 +394,13 @@ mod tests {
     #[test]
     fn group_by_with_sets_from_cube() {
         // GROUP BY CUBE a, b, c
-        let node = ast::GroupByNode::Exprs {
-            exprs: vec![ast::GroupByExpr::Cube(vec![
-                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
-                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
-                ast::Expr::Ident(ast::Ident::new_unquoted("c")),
-            ])],
-        };
+        let exprs = vec![ast::GroupByExpr::Cube(vec![
+            ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+            ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+        ])];
 
-        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let sets = GroupByWithSets::try_from_ast(exprs).unwrap();
         let expected = GroupByWithSets {
             expressions: vec![
                 ast::Expr::Ident(ast::Ident::new_unquoted("a")),
@@ -300,4 +421,52 @@ mod tests {
 
         assert_eq!(expected, sets)
     }
+
+    #[test]
+    fn group_by_with_sets_from_grouping_sets() {
+        // GROUP BY GROUPING SETS ((a, b), (b), ())
+        let exprs = vec![ast::GroupByExpr::GroupingSets(vec![
+            vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ],
+            vec![ast::Expr::Ident(ast::Ident::new_unquoted("b"))],
+            vec![],
+        ])];
+
+        let sets = GroupByWithSets::try_from_ast(exprs).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ],
+            grouping_sets: vec![[0, 1].into(), [1].into(), [].into()],
+        };
+
+        assert_eq!(expected, sets)
+    }
+
+    #[test]
+    fn group_by_with_sets_from_expr_and_rollup() {
+        // GROUP BY a, ROLLUP (b, c)
+        let exprs = vec![
+            ast::GroupByExpr::Expr(vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))]),
+            ast::GroupByExpr::Rollup(vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+            ]),
+        ];
+
+        let sets = GroupByWithSets::try_from_ast(exprs).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+            ],
+            grouping_sets: vec![[0, 1, 2].into(), [0, 1].into(), [0].into()],
+        };
+
+        assert_eq!(expected, sets)
+    }
 }
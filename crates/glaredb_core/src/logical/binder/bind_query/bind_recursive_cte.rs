@@ -0,0 +1,209 @@
+use glaredb_error::{DbError, Result};
+use glaredb_parser::ast;
+
+use super::bind_setop::{BoundSetOp, SetOpCastRequirement};
+use super::{BoundQuery, QueryBinder};
+use crate::functions::implicit::implicit_cast_score;
+use crate::logical::binder::bind_context::{BindContext, BindScopeRef, BoundCte, CteRef};
+use crate::logical::binder::ident::BinderIdent;
+use crate::logical::binder::table_list::TableRef;
+use crate::logical::logical_setop::SetOpKind;
+use crate::logical::resolver::ResolvedMeta;
+use crate::logical::resolver::resolve_context::ResolveContext;
+
+/// A CTE whose recursive term references itself.
+///
+/// Evaluation starts with the rows from the anchor, then repeatedly evaluates
+/// the recursive term against the rows produced by the previous iteration
+/// until no new rows are produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundRecursiveCte {
+    /// The non-recursive term.
+    pub anchor: Box<BoundQuery>,
+    pub anchor_scope: BindScopeRef,
+    /// The recursive term, references the CTE.
+    pub recursive: Box<BoundQuery>,
+    pub recursive_scope: BindScopeRef,
+    /// Table ref to use for casting the output of the recursive term to the
+    /// output of the anchor.
+    pub recursive_cast_ref: Option<TableRef>,
+    /// Output table for the CTE.
+    pub table_ref: TableRef,
+    /// Reference to the CTE that's being evaluated.
+    pub cte_ref: CteRef,
+    /// UNION ALL if true, UNION otherwise.
+    pub all: bool,
+}
+
+#[derive(Debug)]
+pub struct RecursiveCteBinder<'a> {
+    pub current: BindScopeRef,
+    pub resolve_context: &'a ResolveContext,
+}
+
+impl<'a> RecursiveCteBinder<'a> {
+    pub fn new(current: BindScopeRef, resolve_context: &'a ResolveContext) -> Self {
+        RecursiveCteBinder {
+            current,
+            resolve_context,
+        }
+    }
+
+    /// Checks if a CTE body is in the form accepted for recursive CTEs:
+    ///
+    /// `<anchor> UNION [ALL] <recursive>`
+    pub fn is_recursive_union(body: &ast::QueryNode<ResolvedMeta>) -> bool {
+        matches!(
+            &body.body,
+            ast::QueryNodeBody::Set(setop) if setop.operation == ast::SetOperation::Union
+        ) && body.ctes.is_none()
+            && body.order_by.is_none()
+            && body.limit.limit.is_none()
+            && body.limit.offset.is_none()
+    }
+
+    /// Binds the recursive CTE, adding it to the current scope.
+    pub fn bind(
+        &self,
+        bind_context: &mut BindContext,
+        name: BinderIdent,
+        column_aliases: Option<Vec<ast::Ident>>,
+        materialized: bool,
+        setop: ast::SetOp<ResolvedMeta>,
+    ) -> Result<()> {
+        let nested = bind_context.new_child_scope(self.current);
+
+        // Bind the anchor first, it determines the names and types of the
+        // CTE's columns. The CTE isn't visible yet.
+        let anchor_scope = bind_context.new_child_scope(nested);
+        let anchor = QueryBinder::new(anchor_scope, self.resolve_context).bind_body(
+            bind_context,
+            *setop.left,
+            None,
+            ast::LimitModifier {
+                limit: None,
+                offset: None,
+            },
+        )?;
+
+        let mut types = Vec::new();
+        let mut names = Vec::new();
+        for table in bind_context.iter_tables_in_scope(anchor_scope)? {
+            types.extend_from_slice(&table.column_types);
+            names.extend_from_slice(&table.column_names);
+        }
+        let output_names = names.clone();
+
+        if let Some(col_aliases) = &column_aliases {
+            super::apply_cte_column_aliases(&mut names, col_aliases)?;
+        }
+
+        // Add the CTE so that it's visible to the recursive term.
+        let cte_ref = bind_context.add_cte(
+            self.current,
+            BoundCte {
+                bind_scope: nested,
+                materialized: true,
+                name,
+                column_names: names,
+                column_types: types.clone(),
+                bound: Box::new(anchor.clone()),
+                mat_ref: None,
+                binding_recursive_term: true,
+                recursive_references: 0,
+            },
+        )?;
+
+        let recursive_scope = bind_context.new_child_scope(nested);
+        let recursive = QueryBinder::new(recursive_scope, self.resolve_context).bind_body(
+            bind_context,
+            *setop.right,
+            None,
+            ast::LimitModifier {
+                limit: None,
+                offset: None,
+            },
+        )?;
+
+        let cte = bind_context.get_cte_mut(cte_ref)?;
+        cte.binding_recursive_term = false;
+        let self_referenced = cte.recursive_references > 0;
+
+        let recursive_types: Vec<_> = bind_context
+            .iter_tables_in_scope(recursive_scope)?
+            .flat_map(|t| t.column_types.iter().cloned())
+            .collect();
+
+        if recursive_types.len() != types.len() {
+            return Err(DbError::new(format!(
+                "Recursive term of CTE '{}' returns {} columns, expected {}",
+                bind_context.get_cte(cte_ref)?.name,
+                recursive_types.len(),
+                types.len(),
+            )));
+        }
+
+        // Output types are always the types of the anchor, the recursive term
+        // is cast to match.
+        let mut needs_cast = false;
+        for (anchor_type, recursive_type) in types.iter().zip(&recursive_types) {
+            if anchor_type == recursive_type {
+                continue;
+            }
+            if implicit_cast_score(recursive_type.id(), anchor_type.id()).is_none() {
+                return Err(DbError::new(format!(
+                    "Cannot cast {recursive_type} to {anchor_type} in recursive term of CTE '{}'",
+                    bind_context.get_cte(cte_ref)?.name,
+                )));
+            }
+            needs_cast = true;
+        }
+
+        let recursive_cast_ref = if needs_cast {
+            Some(bind_context.new_ephemeral_table_from_types("__generated_right", types.clone())?)
+        } else {
+            None
+        };
+
+        let table_ref = bind_context.push_table(nested, None, types, output_names)?;
+
+        let bound = if self_referenced {
+            BoundQuery::RecursiveCte(BoundRecursiveCte {
+                anchor: Box::new(anchor),
+                anchor_scope,
+                recursive: Box::new(recursive),
+                recursive_scope,
+                recursive_cast_ref,
+                table_ref,
+                cte_ref,
+                all: setop.all,
+            })
+        } else {
+            // Recursive term doesn't actually reference the CTE, this is just
+            // a normal UNION.
+            BoundQuery::Setop(BoundSetOp {
+                left: Box::new(anchor),
+                left_scope: anchor_scope,
+                right: Box::new(recursive),
+                right_scope: recursive_scope,
+                setop_table: table_ref,
+                kind: SetOpKind::Union,
+                all: setop.all,
+                order_by: None,
+                limit: None,
+                cast_req: match recursive_cast_ref {
+                    Some(cast_ref) => SetOpCastRequirement::RightNeedsCast(cast_ref),
+                    None => SetOpCastRequirement::None,
+                },
+            })
+        };
+
+        let cte = bind_context.get_cte_mut(cte_ref)?;
+        *cte.bound = bound;
+        if !self_referenced {
+            cte.materialized = materialized;
+        }
+
+        Ok(())
+    }
+}
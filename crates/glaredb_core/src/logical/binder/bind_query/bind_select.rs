@@ -6,6 +6,7 @@ use super::bind_group_by::{BoundGroupBy, GroupByBinder};
 use super::bind_having::HavingBinder;
 use super::bind_modifier::{BoundLimit, BoundOrderBy, ModifierBinder};
 use super::bind_select_list::SelectListBinder;
+use super::bind_window::WindowBinder;
 use super::select_expr_expander::SelectExprExpander;
 use super::select_list::BoundSelectList;
 use crate::expr::Expression;
@@ -61,6 +62,12 @@ impl<'a> SelectBinder<'a> {
         let from =
             FromBinder::new(from_bind_ref, self.resolve_context).bind(bind_context, select.from)?;
 
+        // Handle WINDOW
+        //
+        // Named windows need to be in scope before binding any expressions
+        // that may reference them.
+        WindowBinder::new(from_bind_ref).bind_named_windows(bind_context, select.windows)?;
+
         // Expand SELECT
         let projections = SelectExprExpander::new(from_bind_ref, bind_context)
             .expand_all_select_exprs(select.projections)?;
@@ -107,7 +114,8 @@ impl<'a> SelectBinder<'a> {
                 let mut group_by_binder = GroupByBinder::new(from_bind_ref, self.resolve_context);
                 group_by_binder.bind(bind_context, &mut select_list, group_by)
             })
-            .transpose()?;
+            .transpose()?
+            .flatten();
 
         // Handle HAVING
         let mut having = select
@@ -186,6 +194,7 @@ mod tests {
             where_expr: None,
             group_by: None,
             having: None,
+            windows: Vec::new(),
         };
 
         let limit = ast::LimitModifier {
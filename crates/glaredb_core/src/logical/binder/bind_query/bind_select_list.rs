@@ -96,14 +96,25 @@ impl<'a> SelectListBinder<'a> {
                         },
                     )?;
 
-                    let name = generate_column_name(bind_context, &bound_expr)?;
+                    // USING columns from a FULL join bind to a COALESCE
+                    // expression, keep the column name for those.
+                    let name = match &expr {
+                        ast::Expr::Ident(ident) => {
+                            let ident = BinderIdent::from(ident.clone());
+                            match bind_context.find_coalesced_using_column(self.current, &ident)? {
+                                Some(_) => ident,
+                                None => generate_column_name(bind_context, &bound_expr)?.into(),
+                            }
+                        }
+                        _ => generate_column_name(bind_context, &bound_expr)?.into(),
+                    };
 
-                    names.push(BinderIdent::from(name));
+                    names.push(name);
                     exprs.push(bound_expr);
                 }
                 ExpandedSelectExpr::Column { expr, name } => {
                     names.push(name);
-                    exprs.push(Expression::Column(expr));
+                    exprs.push(expr);
                 }
             }
         }
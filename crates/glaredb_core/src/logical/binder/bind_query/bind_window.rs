@@ -0,0 +1,114 @@
+use std::borrow::Cow;
+
+use glaredb_error::{DbError, Result};
+use glaredb_parser::ast;
+
+use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
+use crate::logical::binder::ident::BinderIdent;
+use crate::logical::resolver::ResolvedMeta;
+
+/// Binds named windows from the WINDOW clause, and resolves references to
+/// named windows in OVER clauses.
+#[derive(Debug)]
+pub struct WindowBinder {
+    current: BindScopeRef,
+}
+
+impl WindowBinder {
+    pub fn new(current: BindScopeRef) -> Self {
+        WindowBinder { current }
+    }
+
+    /// Add windows from the WINDOW clause to the current scope.
+    ///
+    /// A window may reference windows defined before it in the same clause.
+    pub fn bind_named_windows(
+        &self,
+        bind_context: &mut BindContext,
+        windows: Vec<ast::NamedWindowDefinition<ResolvedMeta>>,
+    ) -> Result<()> {
+        for window in windows {
+            let definition = match &window.definition.existing {
+                Some(existing) => {
+                    self.inherit_existing(bind_context, existing, &window.definition)?
+                }
+                None => window.definition,
+            };
+
+            bind_context.add_named_window(self.current, window.name.into(), definition)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the full window definition for an OVER clause.
+    ///
+    /// The returned definition will never reference an existing window.
+    pub fn window_definition<'a>(
+        &self,
+        bind_context: &BindContext,
+        spec: &'a ast::WindowSpec<ResolvedMeta>,
+    ) -> Result<Cow<'a, ast::WindowDefinition<ResolvedMeta>>> {
+        match spec {
+            ast::WindowSpec::Named(name) => {
+                let name = BinderIdent::from(name.clone());
+                let window =
+                    bind_context.find_named_window(self.current, name.as_normalized_str())?;
+                Ok(Cow::Owned(window.clone()))
+            }
+            ast::WindowSpec::Definition(definition) => match &definition.existing {
+                Some(existing) => Ok(Cow::Owned(self.inherit_existing(
+                    bind_context,
+                    existing,
+                    definition,
+                )?)),
+                None => Ok(Cow::Borrowed(definition)),
+            },
+        }
+    }
+
+    /// Create a new window definition by refining an existing named window.
+    ///
+    /// Follows the SQL standard rules for copying windows: PARTITION BY is
+    /// always taken from the existing window, ORDER BY may only be specified
+    /// if the existing window doesn't have one, and the existing window must
+    /// not have a frame.
+    fn inherit_existing(
+        &self,
+        bind_context: &BindContext,
+        existing: &ast::Ident,
+        refinement: &ast::WindowDefinition<ResolvedMeta>,
+    ) -> Result<ast::WindowDefinition<ResolvedMeta>> {
+        let name = BinderIdent::from(existing.clone());
+        let base = bind_context.find_named_window(self.current, name.as_normalized_str())?;
+
+        if !refinement.partition_by.is_empty() {
+            return Err(DbError::new(format!(
+                "Cannot override PARTITION BY clause of window '{name}'"
+            )));
+        }
+        if !refinement.order_by.is_empty() && !base.order_by.is_empty() {
+            return Err(DbError::new(format!(
+                "Cannot override ORDER BY clause of window '{name}'"
+            )));
+        }
+        if base.frame.is_some() {
+            return Err(DbError::new(format!(
+                "Cannot copy window '{name}' because it has a frame clause"
+            )));
+        }
+
+        let order_by = if refinement.order_by.is_empty() {
+            base.order_by.clone()
+        } else {
+            refinement.order_by.clone()
+        };
+
+        Ok(ast::WindowDefinition {
+            existing: None,
+            partition_by: base.partition_by.clone(),
+            order_by,
+            frame: refinement.frame.clone(),
+        })
+    }
+}
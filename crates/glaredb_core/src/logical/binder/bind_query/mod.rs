@@ -2,18 +2,21 @@ pub mod bind_from;
 pub mod bind_group_by;
 pub mod bind_having;
 pub mod bind_modifier;
+pub mod bind_recursive_cte;
 pub mod bind_select;
 pub mod bind_select_list;
 pub mod bind_setop;
 pub mod bind_values;
+pub mod bind_window;
 
 pub mod select_expr_expander;
 pub mod select_list;
 
+use bind_recursive_cte::{BoundRecursiveCte, RecursiveCteBinder};
 use bind_select::{BoundSelect, SelectBinder};
 use bind_setop::{BoundSetOp, SetOpBinder};
 use bind_values::{BoundValues, ValuesBinder};
-use glaredb_error::{DbError, Result, not_implemented};
+use glaredb_error::{DbError, Result};
 use glaredb_parser::ast;
 
 use super::bind_context::{BindContext, BindScopeRef};
@@ -29,6 +32,7 @@ pub enum BoundQuery {
     Select(BoundSelect),
     Setop(BoundSetOp),
     Values(BoundValues),
+    RecursiveCte(BoundRecursiveCte),
 }
 
 impl BoundQuery {
@@ -40,6 +44,7 @@ impl BoundQuery {
             },
             Self::Setop(setop) => setop.setop_table,
             BoundQuery::Values(values) => values.expressions_table,
+            BoundQuery::RecursiveCte(cte) => cte.table_ref,
         }
     }
 }
@@ -104,12 +109,12 @@ impl<'a> QueryBinder<'a> {
         bind_context: &mut BindContext,
         ctes: ast::CommonTableExprs<ResolvedMeta>,
     ) -> Result<()> {
-        if ctes.recursive {
-            not_implemented!("recursive CTEs");
-        }
-
         for cte in ctes.ctes {
-            self.bind_cte(bind_context, cte)?
+            if ctes.recursive {
+                self.bind_recursive_cte(bind_context, cte)?
+            } else {
+                self.bind_cte(bind_context, cte)?
+            }
         }
 
         Ok(())
@@ -135,17 +140,7 @@ impl<'a> QueryBinder<'a> {
         //
         // WITH my_cte(alias1, alias2) AS ...
         if let Some(col_aliases) = &cte.column_aliases {
-            if col_aliases.len() > names.len() {
-                return Err(DbError::new(format!(
-                    "Expected at most {} column aliases, received {}",
-                    names.len(),
-                    col_aliases.len()
-                )));
-            }
-
-            for (idx, col_alias) in col_aliases.iter().enumerate() {
-                names[idx] = BinderIdent::from(col_alias.clone());
-            }
+            apply_cte_column_aliases(&mut names, col_aliases)?;
         }
 
         let cte = BoundCte {
@@ -156,6 +151,8 @@ impl<'a> QueryBinder<'a> {
             column_types: types,
             bound: Box::new(bound),
             mat_ref: None,
+            binding_recursive_term: false,
+            recursive_references: 0,
         };
 
         // Note that we bind the CTE in a nested scope, but add it to the
@@ -168,4 +165,49 @@ impl<'a> QueryBinder<'a> {
 
         Ok(())
     }
+
+    /// Binds a CTE in a WITH RECURSIVE.
+    ///
+    /// CTEs not in the form `<anchor> UNION [ALL] <recursive>` are bound as
+    /// normal CTEs.
+    fn bind_recursive_cte(
+        &self,
+        bind_context: &mut BindContext,
+        cte: ast::CommonTableExpr<ResolvedMeta>,
+    ) -> Result<()> {
+        if !RecursiveCteBinder::is_recursive_union(&cte.body) {
+            return self.bind_cte(bind_context, cte);
+        }
+
+        let setop = match cte.body.body {
+            ast::QueryNodeBody::Set(setop) => setop,
+            _ => unreachable!("body checked to be a set operation"),
+        };
+
+        RecursiveCteBinder::new(self.current, self.resolve_context).bind(
+            bind_context,
+            cte.alias.into(),
+            cte.column_aliases,
+            cte.materialized,
+            setop,
+        )
+    }
+}
+
+/// Replace CTE column names with the aliases provided where the CTE is
+/// defined.
+fn apply_cte_column_aliases(names: &mut [BinderIdent], col_aliases: &[ast::Ident]) -> Result<()> {
+    if col_aliases.len() > names.len() {
+        return Err(DbError::new(format!(
+            "Expected at most {} column aliases, received {}",
+            names.len(),
+            col_aliases.len()
+        )));
+    }
+
+    for (idx, col_alias) in col_aliases.iter().enumerate() {
+        names[idx] = BinderIdent::from(col_alias.clone());
+    }
+
+    Ok(())
 }
@@ -4,6 +4,7 @@ use glaredb_error::{DbError, Result, ResultExt};
 use glaredb_parser::ast;
 use regex::Regex;
 
+use crate::expr::Expression;
 use crate::expr::column_expr::{ColumnExpr, ColumnReference};
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::ident::BinderIdent;
@@ -11,6 +12,7 @@ use crate::logical::binder::table_list::TableAlias;
 use crate::logical::resolver::ResolvedMeta;
 
 /// An expanded select expression.
+#[allow(clippy::large_enum_variant)] // Short lived, only exists while binding the select list.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExpandedSelectExpr {
     /// A typical expression. Can be a reference to a column, or a more complex
@@ -27,7 +29,10 @@ pub enum ExpandedSelectExpr {
     /// scope.
     Column {
         /// The column expression representing a column in some scope.
-        expr: ColumnExpr,
+        ///
+        /// This is a plain column expression except for USING columns from a
+        /// FULL join, where it's the COALESCE of both sides.
+        expr: Expression,
         /// Name as it existed in the bind scope.
         name: BinderIdent,
     },
@@ -90,17 +95,8 @@ impl<'a> SelectExprExpander<'a> {
                 // TODO: Need to double check case-sensitivity handling here.
                 let mut handled = HashSet::new();
                 for using in self.bind_context.get_using_columns(self.current)? {
-                    let reference = ColumnReference {
-                        table_scope: using.table_ref,
-                        column: using.col_idx,
-                    };
-                    let datatype = self.bind_context.get_column_type(reference)?;
-
                     exprs.push(ExpandedSelectExpr::Column {
-                        expr: ColumnExpr {
-                            reference,
-                            datatype,
-                        },
+                        expr: using.as_expr(self.bind_context)?,
                         name: using.column.clone(),
                     });
 
@@ -129,7 +125,8 @@ impl<'a> SelectExprExpander<'a> {
                             expr: ColumnExpr {
                                 reference,
                                 datatype,
-                            },
+                            }
+                            .into(),
                             name: name.clone(),
                         })
                     }
@@ -178,7 +175,8 @@ impl<'a> SelectExprExpander<'a> {
                                 column: col_idx,
                             },
                             datatype: datatype.clone(),
-                        },
+                        }
+                        .into(),
                         name: name.clone(),
                     })
                 }
@@ -205,6 +203,10 @@ impl<'a> SelectExprExpander<'a> {
                             // Iter all columns in the context, select the ones
                             // that match the regex.
                             for table in self.bind_context.iter_tables_in_scope(self.current)? {
+                                if !table.star_expandable {
+                                    continue;
+                                }
+
                                 for (col_idx, (name, datatype)) in
                                     table.iter_names_and_types().enumerate()
                                 {
@@ -221,7 +223,8 @@ impl<'a> SelectExprExpander<'a> {
                                                 column: col_idx,
                                             },
                                             datatype: datatype.clone(),
-                                        },
+                                        }
+                                        .into(),
                                         name: name.clone(),
                                     })
                                 }
@@ -380,7 +383,8 @@ mod tests {
                         column: 0,
                     },
                     datatype: DataType::utf8(),
-                },
+                }
+                .into(),
                 name: "c1".into(),
             },
             ExpandedSelectExpr::Column {
@@ -390,7 +394,8 @@ mod tests {
                         column: 1,
                     },
                     datatype: DataType::utf8(),
-                },
+                }
+                .into(),
                 name: "c2".into(),
             },
         ];
@@ -448,7 +453,8 @@ mod tests {
                         column: 0,
                     },
                     datatype: DataType::utf8(),
-                },
+                }
+                .into(),
                 name: "c1".into(),
             },
             ExpandedSelectExpr::Column {
@@ -458,7 +464,8 @@ mod tests {
                         column: 1,
                     },
                     datatype: DataType::utf8(),
-                },
+                }
+                .into(),
                 name: "c2".into(),
             },
         ];
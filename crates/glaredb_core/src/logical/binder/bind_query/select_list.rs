@@ -231,23 +231,41 @@ impl SelectList {
             Ok(())
         }
 
+        // Windows are computed after aggregation, so the inputs to windows have
+        // the same restrictions as the projections.
         match group_by {
             Some(group_by) => {
-                for expr in self.projections.iter().chain(&self.appended) {
+                for expr in self
+                    .projections
+                    .iter()
+                    .chain(&self.appended)
+                    .chain(&self.windows)
+                {
                     // Expression needs to reference:
                     // - An aggregate
                     // - An expression in the group by
                     // - A GROUPING call
+                    // - A window
                     inner(
                         bind_context,
                         expr,
-                        &[agg_table, group_by.group_exprs_table, groupings_table],
+                        &[
+                            agg_table,
+                            group_by.group_exprs_table,
+                            groupings_table,
+                            self.windows_table,
+                        ],
                     )?
                 }
             }
             None => {
-                for expr in self.projections.iter().chain(&self.appended) {
-                    inner(bind_context, expr, &[agg_table])?
+                for expr in self
+                    .projections
+                    .iter()
+                    .chain(&self.appended)
+                    .chain(&self.windows)
+                {
+                    inner(bind_context, expr, &[agg_table, self.windows_table])?
                 }
             }
         }
@@ -500,7 +518,12 @@ impl SelectList {
                 datatype: group_by_expr.datatype()?,
             };
 
-            for expr in self.projections.iter_mut().chain(self.appended.iter_mut()) {
+            for expr in self
+                .projections
+                .iter_mut()
+                .chain(self.appended.iter_mut())
+                .chain(self.windows.iter_mut())
+            {
                 update_projection_expr(group_by_expr, &group_by_col, expr)?;
             }
         }
@@ -1,4 +1,5 @@
 use glaredb_error::Result;
+use glaredb_parser::ast;
 use glaredb_parser::statement::Statement;
 
 use super::bind_attach::{AttachBinder, BoundAttach, BoundDetach};
@@ -7,18 +8,23 @@ use super::bind_copy::{BoundCopyTo, CopyBinder};
 use super::bind_create_schema::CreateSchemaBinder;
 use super::bind_create_table::{BoundCreateTable, CreateTableBinder};
 use super::bind_create_view::CreateViewBinder;
+use super::bind_delete::{BoundDelete, DeleteBinder};
 use super::bind_describe::DescribeBinder;
 use super::bind_drop::DropBinder;
 use super::bind_explain::{BoundExplain, ExplainBinder};
 use super::bind_insert::{BoundInsert, InsertBinder};
+use super::bind_merge::{BoundMerge, MergeBinder};
 use super::bind_query::BoundQuery;
 use super::bind_set::SetVarBinder;
+use super::bind_transaction::TransactionBinder;
+use super::bind_update::{BoundUpdate, UpdateBinder};
 use crate::config::session::SessionConfig;
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::logical_create::{LogicalCreateSchema, LogicalCreateView};
 use crate::logical::logical_describe::LogicalDescribe;
 use crate::logical::logical_drop::LogicalDrop;
 use crate::logical::logical_set::{LogicalResetVar, LogicalSetVar, LogicalShowVar};
+use crate::logical::logical_transaction::LogicalTransaction;
 use crate::logical::operator::Node;
 use crate::logical::resolver::ResolvedMeta;
 use crate::logical::resolver::resolve_context::ResolveContext;
@@ -39,8 +45,12 @@ pub enum BoundStatement {
     ShowVar(Node<LogicalShowVar>),
     Attach(BoundAttach),
     Detach(BoundDetach),
+    Transaction(Node<LogicalTransaction>),
     Drop(Node<LogicalDrop>),
     Insert(BoundInsert),
+    Delete(BoundDelete),
+    Update(BoundUpdate),
+    Merge(BoundMerge),
     CreateSchema(Node<LogicalCreateSchema>),
     CreateTable(BoundCreateTable),
     CreateView(Node<LogicalCreateView>),
@@ -86,13 +96,42 @@ impl StatementBinder<'_> {
             Statement::Detach(detach) => BoundStatement::Detach(
                 AttachBinder::new(root_scope).bind_detach(&mut context, detach)?,
             ),
+            Statement::Transaction(stmt) => BoundStatement::Transaction(
+                TransactionBinder::new(root_scope).bind_transaction(&mut context, stmt)?,
+            ),
             Statement::Drop(drop) => {
                 BoundStatement::Drop(DropBinder::new(root_scope).bind_drop(&mut context, drop)?)
             }
+            Statement::Insert(insert)
+                if matches!(
+                    insert.on_conflict,
+                    Some(ast::InsertOnConflict {
+                        action: ast::OnConflictAction::DoUpdate { .. },
+                        ..
+                    })
+                ) =>
+            {
+                BoundStatement::Merge(
+                    MergeBinder::new(root_scope, self.resolve_context)
+                        .bind_insert_on_conflict(&mut context, insert)?,
+                )
+            }
             Statement::Insert(insert) => BoundStatement::Insert(
                 InsertBinder::new(root_scope, self.resolve_context)
                     .bind_insert(&mut context, insert)?,
             ),
+            Statement::Delete(delete) => BoundStatement::Delete(
+                DeleteBinder::new(root_scope, self.resolve_context)
+                    .bind_delete(&mut context, delete)?,
+            ),
+            Statement::Update(update) => BoundStatement::Update(
+                UpdateBinder::new(root_scope, self.resolve_context)
+                    .bind_update(&mut context, update)?,
+            ),
+            Statement::Merge(merge) => BoundStatement::Merge(
+                MergeBinder::new(root_scope, self.resolve_context)
+                    .bind_merge(&mut context, merge)?,
+            ),
             Statement::CreateSchema(create) => BoundStatement::CreateSchema(
                 CreateSchemaBinder::new(root_scope).bind_create_schema(&mut context, create)?,
             ),
@@ -0,0 +1,37 @@
+use glaredb_error::Result;
+use glaredb_parser::ast;
+
+use super::bind_context::{BindContext, BindScopeRef};
+use crate::logical::logical_transaction::LogicalTransaction;
+use crate::logical::operator::{LocationRequirement, Node};
+use crate::statistics::value::StatisticsValue;
+
+#[derive(Debug)]
+pub struct TransactionBinder {
+    pub current: BindScopeRef,
+}
+
+impl TransactionBinder {
+    pub fn new(current: BindScopeRef) -> Self {
+        TransactionBinder { current }
+    }
+
+    pub fn bind_transaction(
+        &self,
+        _bind_context: &mut BindContext,
+        stmt: ast::TransactionStatement,
+    ) -> Result<Node<LogicalTransaction>> {
+        let node = match stmt {
+            ast::TransactionStatement::Begin => LogicalTransaction::Begin,
+            ast::TransactionStatement::Commit => LogicalTransaction::Commit,
+            ast::TransactionStatement::Rollback => LogicalTransaction::Rollback,
+        };
+
+        Ok(Node {
+            node,
+            location: LocationRequirement::ClientLocal,
+            children: Vec::new(),
+            estimated_cardinality: StatisticsValue::Unknown,
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use glaredb_error::{DbError, Result};
+use glaredb_parser::ast;
+
+use super::bind_context::{BindContext, BindScopeRef};
+use super::bind_delete::BoundTargetScan;
+use super::table_list::TableRef;
+use crate::arrays::datatype::DataType;
+use crate::expr::column_expr::{ColumnExpr, ColumnReference};
+use crate::expr::{Expression, cast, lit};
+use crate::logical::binder::column_binder::DefaultColumnBinder;
+use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
+use crate::logical::binder::ident::BinderIdent;
+use crate::logical::resolver::ResolvedMeta;
+use crate::logical::resolver::resolve_context::ResolveContext;
+use crate::logical::resolver::resolved_table::ResolvedTableReference;
+
+#[derive(Debug, Clone)]
+pub struct BoundUpdate {
+    /// Scan producing rows to update.
+    pub scan: BoundTargetScan,
+    /// Projections producing the row id, a true insert flag, followed by the
+    /// updated values for every column in the table.
+    pub projections: Vec<Expression>,
+    pub projection_table: TableRef,
+}
+
+#[derive(Debug)]
+pub struct UpdateBinder<'a> {
+    pub current: BindScopeRef,
+    pub resolve_context: &'a ResolveContext,
+}
+
+impl<'a> UpdateBinder<'a> {
+    pub fn new(current: BindScopeRef, resolve_context: &'a ResolveContext) -> Self {
+        UpdateBinder {
+            current,
+            resolve_context,
+        }
+    }
+
+    pub fn bind_update(
+        &self,
+        bind_context: &mut BindContext,
+        update: ast::Update<ResolvedMeta>,
+    ) -> Result<BoundUpdate> {
+        bind_context.push_table(self.current, None, [DataType::int64()], ["rows_updated"])?;
+
+        let source_scope = bind_context.new_orphan_scope();
+        let scan = BoundTargetScan::bind(
+            bind_context,
+            source_scope,
+            self.resolve_context,
+            update.table,
+            update.alias,
+            update.where_expr,
+        )?;
+
+        let columns = &scan.table.entry.try_as_table_entry()?.columns;
+        let values = bind_assignments(
+            bind_context,
+            source_scope,
+            self.resolve_context,
+            &scan.table,
+            update.assignments,
+        )?;
+
+        let mut projections = Vec::with_capacity(columns.len() + 2);
+        projections.push(scan.row_id.clone());
+        projections.push(lit(true).into());
+        for (col_idx, (value, col)) in values.into_iter().zip(columns).enumerate() {
+            let expr = value.unwrap_or_else(|| {
+                Expression::Column(ColumnExpr {
+                    reference: ColumnReference {
+                        table_scope: scan.data_table_ref,
+                        column: col_idx,
+                    },
+                    datatype: col.datatype.clone(),
+                })
+            });
+            projections.push(expr);
+        }
+
+        let projection_table = bind_context.new_ephemeral_table_with_columns(
+            projections
+                .iter()
+                .map(|p| p.datatype())
+                .collect::<Result<Vec<_>>>()?,
+            (0..projections.len()).map(|idx| format!("__generated_update_project_{idx}")),
+        )?;
+
+        Ok(BoundUpdate {
+            scan,
+            projections,
+            projection_table,
+        })
+    }
+}
+
+/// Bind the assignments for an UPDATE, casting values to the column types.
+///
+/// Returns an expression for each column in the table, None if the column
+/// isn't assigned to.
+pub fn bind_assignments(
+    bind_context: &mut BindContext,
+    current: BindScopeRef,
+    resolve_context: &ResolveContext,
+    table: &ResolvedTableReference,
+    assignments: Vec<ast::Assignment<ResolvedMeta>>,
+) -> Result<Vec<Option<Expression>>> {
+    let columns = &table.entry.try_as_table_entry()?.columns;
+
+    // Start with all columns unchanged.
+    let mut values: Vec<Option<Expression>> = vec![None; columns.len()];
+
+    for assignment in assignments {
+        let ident = BinderIdent::from(assignment.column);
+        let col_idx = columns
+            .iter()
+            .position(|col| BinderIdent::from(col.name.as_str()).strict_eq(&ident))
+            .ok_or_else(|| {
+                DbError::new(format!(
+                    "Column '{ident}' not found in table '{}'",
+                    table.entry.name
+                ))
+            })?;
+
+        if values[col_idx].is_some() {
+            return Err(DbError::new(format!(
+                "Column '{ident}' assigned to multiple times"
+            )));
+        }
+
+        let mut expr = BaseExpressionBinder::new(current, resolve_context).bind_expression(
+            bind_context,
+            &assignment.expr,
+            &mut DefaultColumnBinder,
+            RecursionContext {
+                allow_windows: false,
+                allow_aggregates: false,
+                is_root: true,
+            },
+        )?;
+
+        let want = &columns[col_idx].datatype;
+        if &expr.datatype()? != want {
+            expr = cast(expr, want.clone())?.into();
+        }
+
+        values[col_idx] = Some(expr);
+    }
+
+    Ok(values)
+}
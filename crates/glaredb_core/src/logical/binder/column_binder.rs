@@ -102,6 +102,31 @@ impl DefaultColumnBinder {
     ) -> Result<Option<Expression>> {
         let mut current = bind_scope;
         loop {
+            // USING columns from FULL joins bind to the COALESCE of both sides
+            // instead of a single column.
+            let coalesced = match alias {
+                Some(_) => None,
+                None => bind_context.find_coalesced_using_column(current, col)?,
+            };
+            if let Some(using) = coalesced {
+                let expr = using.as_expr(bind_context)?;
+
+                if current != bind_scope {
+                    // Correlated, push correlations for both sides of the
+                    // join.
+                    for reference in expr.get_column_references() {
+                        let correlated = CorrelatedColumn {
+                            outer: current,
+                            table: reference.table_scope,
+                            col_idx: reference.column,
+                        };
+                        bind_context.push_correlation(bind_scope, correlated)?;
+                    }
+                }
+
+                return Ok(Some(expr));
+            }
+
             let table = bind_context.find_table_for_column(current, alias.as_ref(), col)?;
             match table {
                 Some((table, col_idx)) => {
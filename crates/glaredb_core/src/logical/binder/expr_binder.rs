@@ -2,9 +2,10 @@ use glaredb_error::{DbError, Result, not_implemented};
 use glaredb_parser::ast::{self, QueryNode};
 
 use super::bind_context::{BindContext, BindScopeRef};
+use super::bind_query::bind_window::WindowBinder;
 use super::column_binder::ExpressionColumnBinder;
 use super::ident::BinderIdent;
-use crate::arrays::datatype::DataType;
+use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::scalar::decimal::{
     Decimal64Scalar,
     Decimal64Type,
@@ -25,7 +26,14 @@ use crate::expr::negate_expr::NegateOperator;
 use crate::expr::scalar_function_expr::ScalarFunctionExpr;
 use crate::expr::subquery_expr::{SubqueryExpr, SubqueryType};
 use crate::expr::unnest_expr::UnnestExpr;
-use crate::expr::window_expr::{WindowExpr, WindowFrameBound, WindowFrameExclusion};
+use crate::expr::window_expr::{
+    WindowExpr,
+    WindowExprFunction,
+    WindowFrameBound,
+    WindowFrameExclusion,
+    WindowFrameUnit,
+    scalar_as_f64,
+};
 use crate::expr::{self, Expression, bind_aggregate_function};
 use crate::functions::aggregate::builtin::count::FUNCTION_SET_COUNT;
 use crate::functions::cast::parse::{Decimal64Parser, Decimal128Parser, Parser};
@@ -37,7 +45,7 @@ use crate::functions::scalar::builtin::binary::{
     FUNCTION_SET_SHR,
     FUNCTION_SET_XOR,
 };
-use crate::functions::scalar::builtin::datetime::FUNCTION_SET_DATE_PART;
+use crate::functions::scalar::builtin::datetime::{FUNCTION_SET_DATE_PART, FUNCTION_SET_TIMEZONE};
 use crate::functions::scalar::builtin::is::{
     FUNCTION_SET_IS_FALSE,
     FUNCTION_SET_IS_NOT_FALSE,
@@ -46,7 +54,12 @@ use crate::functions::scalar::builtin::is::{
     FUNCTION_SET_IS_NULL,
     FUNCTION_SET_IS_TRUE,
 };
+use crate::functions::scalar::builtin::json::{
+    FUNCTION_SET_JSON_EXTRACT,
+    FUNCTION_SET_JSON_EXTRACT_STRING,
+};
 use crate::functions::scalar::builtin::list::{FUNCTION_SET_LIST_EXTRACT, FUNCTION_SET_LIST_VALUE};
+use crate::functions::scalar::builtin::map::FUNCTION_SET_MAP_EXTRACT;
 use crate::functions::scalar::builtin::numeric::FUNCTION_SET_POWER;
 use crate::functions::scalar::builtin::string::{
     FUNCTION_SET_CONCAT,
@@ -56,6 +69,7 @@ use crate::functions::scalar::builtin::string::{
 };
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
+use crate::logical::binder::constant_binder::ConstantBinder;
 use crate::logical::resolver::ResolvedMeta;
 use crate::logical::resolver::resolve_context::ResolveContext;
 use crate::logical::resolver::resolved_function::{ResolvedFunction, SpecialBuiltinFunction};
@@ -210,10 +224,14 @@ impl<'a> BaseExpressionBinder<'a> {
                             },
                         )?;
 
-                        let function = expr::bind_scalar_function(
-                            &FUNCTION_SET_LIST_EXTRACT,
-                            vec![expr, index],
-                        )?;
+                        // Subscripting a map looks up the value for a key.
+                        let function_set = if expr.datatype()?.id() == DataTypeId::Map {
+                            &FUNCTION_SET_MAP_EXTRACT
+                        } else {
+                            &FUNCTION_SET_LIST_EXTRACT
+                        };
+
+                        let function = expr::bind_scalar_function(function_set, vec![expr, index])?;
 
                         Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
                     }
@@ -367,6 +385,20 @@ impl<'a> BaseExpressionBinder<'a> {
                         )?;
                         Expression::ScalarFunction(ScalarFunctionExpr { function })
                     }
+                    ast::BinaryOperator::JsonExtract => {
+                        let function = expr::bind_scalar_function(
+                            &FUNCTION_SET_JSON_EXTRACT,
+                            vec![left, right],
+                        )?;
+                        Expression::ScalarFunction(ScalarFunctionExpr { function })
+                    }
+                    ast::BinaryOperator::JsonExtractString => {
+                        let function = expr::bind_scalar_function(
+                            &FUNCTION_SET_JSON_EXTRACT_STRING,
+                            vec![left, right],
+                        )?;
+                        Expression::ScalarFunction(ScalarFunctionExpr { function })
+                    }
                     other => not_implemented!("binary operator {other:?}"),
                 })
             }
@@ -859,6 +891,17 @@ impl<'a> BaseExpressionBinder<'a> {
 
                 Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
             }
+            ast::Expr::AtTimeZone { expr, timezone } => {
+                let expr =
+                    self.bind_expression(bind_context, expr, column_binder, recur.not_root())?;
+                let timezone =
+                    self.bind_expression(bind_context, timezone, column_binder, recur.not_root())?;
+
+                let function =
+                    expr::bind_scalar_function(&FUNCTION_SET_TIMEZONE, vec![timezone, expr])?;
+
+                Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
+            }
             ast::Expr::Columns(_) => {
                 // TODO: This doens't need to be the case, but there's going to
                 // be slightly different handling if this is a top-level select
@@ -988,10 +1031,27 @@ impl<'a> BaseExpressionBinder<'a> {
                 allow_aggregates: false,
                 ..recur
             }
+        } else if reference.0.is_window() {
+            RecursionContext {
+                allow_windows: false,
+                ..recur
+            }
         } else {
             recur
         };
 
+        if let Some(null_treatment) = func.null_treatment {
+            if !reference.0.is_window() {
+                let clause = match null_treatment {
+                    ast::NullTreatment::IgnoreNulls => "IGNORE NULLS",
+                    ast::NullTreatment::RespectNulls => "RESPECT NULLS",
+                };
+                return Err(DbError::new(format!(
+                    "{clause} only supported for window functions"
+                )));
+            }
+        }
+
         let inputs = func
             .args
             .iter()
@@ -1119,29 +1179,7 @@ impl<'a> BaseExpressionBinder<'a> {
                             })
                             .collect::<Result<Vec<_>>>()?;
 
-                        // Rewrite COALESCE to CASE.
-                        //
-                        // COALESCE(a,b,c)
-                        // =>
-                        // CASE
-                        //   a IS NOT NULL THEN a
-                        //   b IS NOT NULL THEN b
-                        //   c IS NOT NULL THEN c
-                        let mut cases = Vec::with_capacity(inputs.len());
-                        for input in inputs {
-                            let is_not_null = expr::scalar_function(
-                                &FUNCTION_SET_IS_NOT_NULL,
-                                vec![input.clone()],
-                            )?;
-
-                            cases.push(WhenThen {
-                                when: is_not_null.into(),
-                                then: input,
-                            });
-                        }
-
-                        // Default cast rules.
-                        let case_expr = CaseExpr::try_new(cases, None)?;
+                        let case_expr = expr::coalesce(inputs)?;
 
                         Ok(case_expr.into())
                     }
@@ -1181,86 +1219,13 @@ impl<'a> BaseExpressionBinder<'a> {
                 };
 
                 match &func.over {
-                    Some(over) => {
-                        // Window
-
-                        match over {
-                            ast::WindowSpec::Named(_) => {
-                                not_implemented!("named window spec")
-                            }
-                            ast::WindowSpec::Definition(window_def) => {
-                                if window_def.existing.is_some() {
-                                    not_implemented!("inherit existing window spec definition")
-                                }
-
-                                let partition_by = self.bind_expressions(
-                                    bind_context,
-                                    &window_def.partition_by,
-                                    column_binder,
-                                    recur,
-                                )?;
-
-                                // Handle order by.
-                                //
-                                // Handled slightly different than statement
-                                // level ORDER BY in that it can't bind to an
-                                // output column.
-                                let order_by = window_def
-                                    .order_by
-                                    .iter()
-                                    .map(|order_by| {
-                                        let expr = self.bind_expression(
-                                            bind_context,
-                                            &order_by.expr,
-                                            column_binder,
-                                            recur,
-                                        )?;
-                                        Ok(BoundOrderByExpr {
-                                            expr,
-                                            desc: matches!(
-                                                order_by.typ.unwrap_or(ast::OrderByType::Asc),
-                                                ast::OrderByType::Desc
-                                            ),
-                                            nulls_first: matches!(
-                                                order_by.nulls.unwrap_or(ast::OrderByNulls::First),
-                                                ast::OrderByNulls::First
-                                            ),
-                                        })
-                                    })
-                                    .collect::<Result<Vec<_>>>()?;
-
-                                let start = match &window_def.frame {
-                                    Some(_frame) => {
-                                        not_implemented!("non-default window frame")
-                                    }
-                                    None => WindowFrameBound::default_start(),
-                                };
-
-                                let end = match &window_def.frame {
-                                    Some(_frame) => {
-                                        not_implemented!("non-default window frame")
-                                    }
-                                    None => WindowFrameBound::default_start(),
-                                };
-
-                                let exclude = match &window_def.frame {
-                                    Some(_frame) => {
-                                        not_implemented!("non-default window frame")
-                                    }
-                                    None => WindowFrameExclusion::default(),
-                                };
-
-                                Ok(Expression::Window(WindowExpr {
-                                    agg,
-                                    partition_by,
-                                    order_by,
-                                    start,
-                                    end,
-                                    exclude,
-                                }))
-                            }
-                        }
-                    }
+                    Some(over) => self.bind_window(
+                        bind_context,
+                        over,
+                        column_binder,
+                        recur,
+                        WindowExprFunction::Aggregate(agg),
+                    ),
                     None => {
                         // Normal aggregate.
                         Ok(Expression::Aggregate(AggregateExpr {
@@ -1271,6 +1236,251 @@ impl<'a> BaseExpressionBinder<'a> {
                     }
                 }
             }
+            (ResolvedFunction::Window(window), _) => {
+                if func.distinct {
+                    return Err(DbError::new(format!(
+                        "DISTINCT not supported for window function '{}'",
+                        window.name
+                    )));
+                }
+                if func.filter.is_some() {
+                    return Err(DbError::new(format!(
+                        "FILTER not supported for window function '{}'",
+                        window.name
+                    )));
+                }
+                if func.star {
+                    return Err(DbError::new(format!(
+                        "Cannot use '*' as an argument to '{}'",
+                        window.name
+                    )));
+                }
+
+                let over = func.over.as_ref().ok_or_else(|| {
+                    DbError::new(format!(
+                        "Window function '{}' requires an OVER clause",
+                        window.name
+                    ))
+                })?;
+
+                let ignore_nulls = func.null_treatment == Some(ast::NullTreatment::IgnoreNulls);
+                let function = expr::bind_window_function(window, inputs, ignore_nulls)?;
+
+                self.bind_window(
+                    bind_context,
+                    over,
+                    column_binder,
+                    recur,
+                    WindowExprFunction::Window(function),
+                )
+            }
+        }
+    }
+
+    /// Bind the OVER clause for a window function or windowed aggregate.
+    fn bind_window(
+        &self,
+        bind_context: &mut BindContext,
+        over: &ast::WindowSpec<ResolvedMeta>,
+        column_binder: &mut impl ExpressionColumnBinder,
+        recur: RecursionContext,
+        function: WindowExprFunction,
+    ) -> Result<Expression> {
+        let window_def = WindowBinder::new(self.current).window_definition(bind_context, over)?;
+
+        let partition_by =
+            self.bind_expressions(bind_context, &window_def.partition_by, column_binder, recur)?;
+
+        // Handle order by.
+        //
+        // Handled slightly different than statement level ORDER BY in that it
+        // can't bind to an output column.
+        let order_by = window_def
+            .order_by
+            .iter()
+            .map(|order_by| {
+                let expr =
+                    self.bind_expression(bind_context, &order_by.expr, column_binder, recur)?;
+                Ok(BoundOrderByExpr {
+                    expr,
+                    desc: matches!(
+                        order_by.typ.unwrap_or(ast::OrderByType::Asc),
+                        ast::OrderByType::Desc
+                    ),
+                    nulls_first: matches!(
+                        order_by.nulls.unwrap_or(ast::OrderByNulls::First),
+                        ast::OrderByNulls::First
+                    ),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (start, end, exclude) = match &window_def.frame {
+            Some(frame) => self.bind_window_frame(frame, &order_by)?,
+            None => (
+                WindowFrameBound::default_start(),
+                WindowFrameBound::default_end(),
+                WindowFrameExclusion::default(),
+            ),
+        };
+
+        Ok(Expression::Window(WindowExpr {
+            function,
+            partition_by,
+            order_by,
+            start,
+            end,
+            exclude,
+        }))
+    }
+
+    /// Bind an explicit window frame, validating the bounds against the
+    /// window's ORDER BY.
+    fn bind_window_frame(
+        &self,
+        frame: &ast::WindowFrame<ResolvedMeta>,
+        order_by: &[BoundOrderByExpr],
+    ) -> Result<(WindowFrameBound, WindowFrameBound, WindowFrameExclusion)> {
+        let unit = match frame.unit {
+            ast::WindowFrameUnit::Rows => WindowFrameUnit::Rows,
+            ast::WindowFrameUnit::Range => WindowFrameUnit::Range,
+            ast::WindowFrameUnit::Groups => WindowFrameUnit::Groups,
+        };
+
+        if unit == WindowFrameUnit::Groups && order_by.is_empty() {
+            return Err(DbError::new("GROUPS mode requires an ORDER BY clause"));
+        }
+
+        let start = self.bind_window_frame_bound(unit, &frame.start, order_by)?;
+        // Omitting the end bound is the same as 'BETWEEN <start> AND CURRENT
+        // ROW'.
+        let end = match &frame.end {
+            Some(end) => self.bind_window_frame_bound(unit, end, order_by)?,
+            None => WindowFrameBound::CurrentRow(unit),
+        };
+
+        match (&start, &end) {
+            (WindowFrameBound::UnboundedFollowing(_), _) => {
+                return Err(DbError::new("Frame start cannot be UNBOUNDED FOLLOWING"));
+            }
+            (_, WindowFrameBound::UnboundedPreceding(_)) => {
+                return Err(DbError::new("Frame end cannot be UNBOUNDED PRECEDING"));
+            }
+            (WindowFrameBound::CurrentRow(_), WindowFrameBound::Preceding(..)) => {
+                return Err(DbError::new(
+                    "Frame starting from current row cannot have preceding rows",
+                ));
+            }
+            (
+                WindowFrameBound::Following(..),
+                WindowFrameBound::Preceding(..) | WindowFrameBound::CurrentRow(_),
+            ) => {
+                return Err(DbError::new(
+                    "Frame starting from following row cannot have preceding rows",
+                ));
+            }
+            _ => (),
+        }
+
+        let exclude = match frame.exclusion {
+            Some(ast::WindowFrameExclusion::ExcludeCurrentRow) => {
+                WindowFrameExclusion::ExcludeCurrentRow
+            }
+            Some(ast::WindowFrameExclusion::ExcludeGroup) => WindowFrameExclusion::ExcludeGroup,
+            Some(ast::WindowFrameExclusion::ExcludeTies) => WindowFrameExclusion::ExcludeTies,
+            Some(ast::WindowFrameExclusion::ExcludeNoOthers) | None => {
+                WindowFrameExclusion::ExcludeNoOthers
+            }
+        };
+
+        Ok((start, end, exclude))
+    }
+
+    fn bind_window_frame_bound(
+        &self,
+        unit: WindowFrameUnit,
+        bound: &ast::WindowFrameBound<ResolvedMeta>,
+        order_by: &[BoundOrderByExpr],
+    ) -> Result<WindowFrameBound> {
+        Ok(match bound {
+            ast::WindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow(unit),
+            ast::WindowFrameBound::UnboundedPreceding => WindowFrameBound::UnboundedPreceding(unit),
+            ast::WindowFrameBound::UnboundedFollowing => WindowFrameBound::UnboundedFollowing(unit),
+            ast::WindowFrameBound::Preceding(offset) => WindowFrameBound::Preceding(
+                unit,
+                self.bind_window_frame_offset(unit, offset, order_by)?,
+            ),
+            ast::WindowFrameBound::Following(offset) => WindowFrameBound::Following(
+                unit,
+                self.bind_window_frame_offset(unit, offset, order_by)?,
+            ),
+        })
+    }
+
+    /// Binds a PRECEDING or FOLLOWING offset as a constant.
+    fn bind_window_frame_offset(
+        &self,
+        unit: WindowFrameUnit,
+        offset: &ast::Expr<ResolvedMeta>,
+        order_by: &[BoundOrderByExpr],
+    ) -> Result<ScalarValue> {
+        let offset = ConstantBinder::new(self.resolve_context)
+            .bind_constant_expression(offset)?
+            .try_into_scalar()?;
+
+        if offset.is_null() {
+            return Err(DbError::new("Frame offset cannot be NULL"));
+        }
+
+        match unit {
+            WindowFrameUnit::Rows | WindowFrameUnit::Groups => {
+                let offset = offset.try_as_i64().map_err(|_| {
+                    DbError::new(format!(
+                        "Frame offset for {unit} must be an integer, got {offset}"
+                    ))
+                })?;
+                if offset < 0 {
+                    return Err(DbError::new("Frame offset cannot be negative"));
+                }
+                Ok(ScalarValue::Int64(offset))
+            }
+            WindowFrameUnit::Range => {
+                if order_by.len() != 1 {
+                    return Err(DbError::new(
+                        "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column",
+                    ));
+                }
+
+                let datatype = order_by[0].expr.datatype()?;
+                match datatype.id() {
+                    _ if datatype.is_numeric() => {
+                        let value = scalar_as_f64(&offset).ok_or_else(|| {
+                            DbError::new(format!(
+                                "RANGE offset for ORDER BY column of type {datatype} must be numeric, got {offset}"
+                            ))
+                        })?;
+                        if value < 0.0 {
+                            return Err(DbError::new("Frame offset cannot be negative"));
+                        }
+                        Ok(offset)
+                    }
+                    DataTypeId::Date32 | DataTypeId::Date64 | DataTypeId::Timestamp => match offset
+                    {
+                        ScalarValue::Interval(interval) => {
+                            if interval.months < 0 || interval.days < 0 || interval.nanos < 0 {
+                                return Err(DbError::new("Frame offset cannot be negative"));
+                            }
+                            Ok(ScalarValue::Interval(interval))
+                        }
+                        other => Err(DbError::new(format!(
+                            "RANGE offset for ORDER BY column of type {datatype} must be an interval, got {other}"
+                        ))),
+                    },
+                    _ => Err(DbError::new(format!(
+                        "RANGE with offset PRECEDING/FOLLOWING is not supported for ORDER BY column of type {datatype}"
+                    ))),
+                }
+            }
         }
     }
 }
@@ -4,13 +4,17 @@ pub mod bind_copy;
 pub mod bind_create_schema;
 pub mod bind_create_table;
 pub mod bind_create_view;
+pub mod bind_delete;
 pub mod bind_describe;
 pub mod bind_drop;
 pub mod bind_explain;
 pub mod bind_insert;
+pub mod bind_merge;
 pub mod bind_query;
 pub mod bind_set;
 pub mod bind_statement;
+pub mod bind_transaction;
+pub mod bind_update;
 pub mod column_binder;
 pub mod constant_binder;
 pub mod expr_binder;
@@ -4,23 +4,42 @@ use super::binder::bind_context::BindContext;
 use super::binder::table_list::TableRef;
 use super::operator::{LogicalNode, Node};
 use crate::arrays::field::ColumnSchema;
+use crate::functions::copy_to::PlannedCopyToFunction;
+use crate::runtime::filesystem::FileSystemWithState;
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::Expression;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct LogicalCopyTo {
     /// Schema of input operator.
     ///
     /// Stored on this operator since the copy to sinks may need field names
     /// (e.g. writing out a header in csv).
     pub source_schema: ColumnSchema,
-    // pub location: FileLocation,
-    // pub copy_to: Box<dyn CopyToFunction>,
+    /// Path of the file to write to.
+    pub location: String,
+    /// Function for encoding batches.
+    pub copy_to: PlannedCopyToFunction,
+    /// Filesystem to write the file with.
+    pub filesystem: FileSystemWithState,
+}
+
+/// Filesystem state is determined by the location and options, so it's not
+/// included in the comparison.
+impl PartialEq for LogicalCopyTo {
+    fn eq(&self, other: &Self) -> bool {
+        self.source_schema == other.source_schema
+            && self.location == other.location
+            && self.copy_to == other.copy_to
+    }
 }
 
 impl Explainable for LogicalCopyTo {
     fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
-        EntryBuilder::new("CopyTo", conf).build()
+        EntryBuilder::new("CopyTo", conf)
+            .with_value("format", self.copy_to.name)
+            .with_value("location", &self.location)
+            .build()
     }
 }
 
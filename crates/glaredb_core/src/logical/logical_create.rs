@@ -4,7 +4,7 @@ use super::binder::bind_context::BindContext;
 use super::binder::table_list::TableRef;
 use super::operator::{LogicalNode, Node};
 use crate::arrays::field::Field;
-use crate::catalog::create::OnConflict;
+use crate::catalog::create::{OnConflict, UniqueConstraint};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::Expression;
 
@@ -54,6 +54,7 @@ pub struct LogicalCreateTable {
     pub schema: String,
     pub name: String,
     pub columns: Vec<Field>,
+    pub unique_constraints: Vec<UniqueConstraint>,
     pub on_conflict: OnConflict,
 }
 
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use glaredb_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::catalog::entry::CatalogEntry;
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// Delete rows from a table.
+///
+/// The child produces a single column containing the row ids of rows to
+/// delete.
+#[derive(Debug, Clone)]
+pub struct LogicalDelete {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+}
+
+impl Explainable for LogicalDelete {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new("Delete", conf).build()
+    }
+}
+
+impl LogicalNode for Node<LogicalDelete> {
+    fn name(&self) -> &'static str {
+        "Delete"
+    }
+
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        Vec::new()
+    }
+
+    fn for_each_expr<'a, F>(&'a self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<'a, F>(&'a mut self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
+
+impl PartialEq for LogicalDelete {
+    fn eq(&self, other: &Self) -> bool {
+        self.catalog == other.catalog
+            && self.schema == other.schema
+            && self.table.name == other.table.name
+    }
+}
+
+impl Eq for LogicalDelete {}
@@ -1,6 +1,6 @@
 use glaredb_error::Result;
 
-use super::binder::bind_context::{BindContext, MaterializationRef};
+use super::binder::bind_context::{BindContext, CteRef, MaterializationRef};
 use super::binder::table_list::TableRef;
 use super::operator::{LogicalNode, Node};
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
@@ -116,3 +116,100 @@ impl LogicalNode for Node<LogicalMagicMaterializationScan> {
         Ok(())
     }
 }
+
+/// Iteratively evaluates a recursive CTE.
+///
+/// The first child is the non-recursive (anchor) term, the second child is the
+/// recursive term. The recursive term reads the rows produced by the previous
+/// iteration through `LogicalRecursiveCteScan`, and iteration stops once an
+/// iteration produces no new rows.
+///
+/// The result of this operator is what gets materialized for the CTE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalRecursiveCte {
+    /// Table ref for the output of this operator.
+    pub table_ref: TableRef,
+    /// The CTE being evaluated. Scans in the recursive term reference this.
+    pub cte_ref: CteRef,
+    /// If this is UNION ALL. If false, duplicate rows are removed across all
+    /// iterations.
+    pub all: bool,
+}
+
+impl Explainable for LogicalRecursiveCte {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new("RecursiveCte", conf)
+            .with_value("cte_ref", self.cte_ref)
+            .with_value("union_all", self.all)
+            .with_value_if_verbose("table_ref", self.table_ref)
+            .build()
+    }
+}
+
+impl LogicalNode for Node<LogicalRecursiveCte> {
+    fn name(&self) -> &'static str {
+        "RecursiveCte"
+    }
+
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        vec![self.node.table_ref]
+    }
+
+    fn for_each_expr<'a, F>(&'a self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<'a, F>(&'a mut self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
+
+/// Scans the working table of a recursive CTE.
+///
+/// Only valid inside the recursive term of a `LogicalRecursiveCte`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalRecursiveCteScan {
+    /// The recursive CTE this is reading from.
+    pub cte_ref: CteRef,
+    /// Table ref for the output of this scan.
+    pub table_ref: TableRef,
+}
+
+impl Explainable for LogicalRecursiveCteScan {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new("RecursiveCteScan", conf)
+            .with_value("cte_ref", self.cte_ref)
+            .with_value_if_verbose("table_ref", self.table_ref)
+            .build()
+    }
+}
+
+impl LogicalNode for Node<LogicalRecursiveCteScan> {
+    fn name(&self) -> &'static str {
+        "RecursiveCteScan"
+    }
+
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        vec![self.node.table_ref]
+    }
+
+    fn for_each_expr<'a, F>(&'a self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<'a, F>(&'a mut self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
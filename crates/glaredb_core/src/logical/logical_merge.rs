@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use glaredb_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::catalog::entry::CatalogEntry;
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// How to handle new rows that conflict with a unique constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictBehavior {
+    /// Error if any row conflicts.
+    Error,
+    /// Skip inserting rows that conflict.
+    ///
+    /// If a constraint index is provided, only conflicts on that constraint are
+    /// skipped, and conflicts on other constraints still error.
+    DoNothing { constraint: Option<usize> },
+}
+
+/// Delete, update, and insert rows in a table.
+///
+/// The child produces the row id of an existing row to delete (NULL if the row
+/// is new), a boolean indicating if a row should be inserted, followed by the
+/// values to insert for every column in the table. Updates delete the old row
+/// and insert the new values.
+///
+/// UPDATE, MERGE, and INSERT into tables with unique constraints are all
+/// planned with this operator.
+#[derive(Debug, Clone)]
+pub struct LogicalMerge {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+    pub conflict: ConflictBehavior,
+}
+
+impl Explainable for LogicalMerge {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new("Merge", conf).build()
+    }
+}
+
+impl LogicalNode for Node<LogicalMerge> {
+    fn name(&self) -> &'static str {
+        "Merge"
+    }
+
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        Vec::new()
+    }
+
+    fn for_each_expr<'a, F>(&'a self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<'a, F>(&'a mut self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
+
+impl PartialEq for LogicalMerge {
+    fn eq(&self, other: &Self) -> bool {
+        self.catalog == other.catalog
+            && self.schema == other.schema
+            && self.table.name == other.table.name
+            && self.conflict == other.conflict
+    }
+}
+
+impl Eq for LogicalMerge {}
@@ -9,6 +9,7 @@ use crate::catalog::entry::CatalogEntry;
 use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::Expression;
 use crate::functions::table::PlannedTableFunction;
+use crate::statistics::column::ColumnStatistics;
 use crate::statistics::value::StatisticsValue;
 use crate::storage::scan_filter::ScanFilter;
 
@@ -58,6 +59,16 @@ impl ScanSource {
             Self::Function(func) => func.function.bind_state.cardinality,
         }
     }
+
+    /// Statistics for columns in the data schema.
+    ///
+    /// Empty if the source doesn't provide statistics.
+    pub fn column_statistics(&self) -> &[ColumnStatistics] {
+        match self {
+            Self::Table(table) => &table.function.bind_state.column_statistics,
+            Self::Function(func) => &func.function.bind_state.column_statistics,
+        }
+    }
 }
 
 /// Information about scanning a single table ref.
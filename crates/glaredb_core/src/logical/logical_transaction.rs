@@ -0,0 +1,54 @@
+use glaredb_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::explain::explainable::{EntryBuilder, ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalTransaction {
+    Begin,
+    Commit,
+    Rollback,
+}
+
+impl LogicalTransaction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Begin => "Begin",
+            Self::Commit => "Commit",
+            Self::Rollback => "Rollback",
+        }
+    }
+}
+
+impl Explainable for LogicalTransaction {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        EntryBuilder::new(self.as_str(), conf).build()
+    }
+}
+
+impl LogicalNode for Node<LogicalTransaction> {
+    fn name(&self) -> &'static str {
+        self.node.as_str()
+    }
+
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        Vec::new()
+    }
+
+    fn for_each_expr<'a, F>(&'a self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<'a, F>(&'a mut self, _func: F) -> Result<()>
+    where
+        F: FnMut(&'a mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
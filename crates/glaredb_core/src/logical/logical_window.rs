@@ -28,8 +28,11 @@ impl LogicalNode for Node<LogicalWindow> {
         "Window"
     }
 
-    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
-        vec![self.node.windows_table]
+    fn get_output_table_refs(&self, bind_context: &BindContext) -> Vec<TableRef> {
+        // Window outputs are appended to the input columns.
+        let mut refs = self.get_children_table_refs(bind_context);
+        refs.push(self.node.windows_table);
+        refs
     }
 
     fn for_each_expr<'a, F>(&'a self, mut func: F) -> Result<()>
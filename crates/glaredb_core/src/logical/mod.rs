@@ -46,6 +46,7 @@ pub mod logical_aggregate;
 pub mod logical_attach;
 pub mod logical_copy;
 pub mod logical_create;
+pub mod logical_delete;
 pub mod logical_describe;
 pub mod logical_distinct;
 pub mod logical_drop;
@@ -57,6 +58,7 @@ pub mod logical_insert;
 pub mod logical_join;
 pub mod logical_limit;
 pub mod logical_materialization;
+pub mod logical_merge;
 pub mod logical_no_rows;
 pub mod logical_order;
 pub mod logical_project;
@@ -64,5 +66,6 @@ pub mod logical_scan;
 pub mod logical_set;
 pub mod logical_setop;
 pub mod logical_single_row;
+pub mod logical_transaction;
 pub mod logical_unnest;
 pub mod logical_window;
@@ -9,6 +9,7 @@ use super::logical_aggregate::LogicalAggregate;
 use super::logical_attach::{LogicalAttachDatabase, LogicalDetachDatabase};
 use super::logical_copy::LogicalCopyTo;
 use super::logical_create::{LogicalCreateSchema, LogicalCreateTable, LogicalCreateView};
+use super::logical_delete::LogicalDelete;
 use super::logical_describe::LogicalDescribe;
 use super::logical_distinct::LogicalDistinct;
 use super::logical_drop::LogicalDrop;
@@ -24,7 +25,13 @@ use super::logical_join::{
     LogicalMagicJoin,
 };
 use super::logical_limit::LogicalLimit;
-use super::logical_materialization::{LogicalMagicMaterializationScan, LogicalMaterializationScan};
+use super::logical_materialization::{
+    LogicalMagicMaterializationScan,
+    LogicalMaterializationScan,
+    LogicalRecursiveCte,
+    LogicalRecursiveCteScan,
+};
+use super::logical_merge::LogicalMerge;
 use super::logical_no_rows::LogicalNoRows;
 use super::logical_order::LogicalOrder;
 use super::logical_project::LogicalProject;
@@ -32,6 +39,7 @@ use super::logical_scan::LogicalScan;
 use super::logical_set::{LogicalResetVar, LogicalSetVar, LogicalShowVar};
 use super::logical_setop::LogicalSetop;
 use super::logical_single_row::LogicalSingleRow;
+use super::logical_transaction::LogicalTransaction;
 use super::logical_unnest::LogicalUnnest;
 use super::logical_window::LogicalWindow;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, ExplainValue, Explainable};
@@ -282,6 +290,8 @@ pub enum LogicalOperator {
     ExpressionList(Node<LogicalExpressionList>),
     MaterializationScan(Node<LogicalMaterializationScan>),
     MagicMaterializationScan(Node<LogicalMagicMaterializationScan>),
+    RecursiveCte(Node<LogicalRecursiveCte>),
+    RecursiveCteScan(Node<LogicalRecursiveCteScan>),
     SingleRow(Node<LogicalSingleRow>),
     NoRows(Node<LogicalNoRows>),
     SetVar(Node<LogicalSetVar>),
@@ -289,8 +299,11 @@ pub enum LogicalOperator {
     ShowVar(Node<LogicalShowVar>),
     AttachDatabase(Node<LogicalAttachDatabase>),
     DetachDatabase(Node<LogicalDetachDatabase>),
+    Transaction(Node<LogicalTransaction>),
     Drop(Node<LogicalDrop>),
     Insert(Node<LogicalInsert>),
+    Delete(Node<LogicalDelete>),
+    Merge(Node<LogicalMerge>),
     CreateSchema(Node<LogicalCreateSchema>),
     CreateTable(Node<LogicalCreateTable>),
     CreateView(Node<LogicalCreateView>),
@@ -379,6 +392,8 @@ impl LogicalOperator {
             Self::ExpressionList(n) => &n.children,
             Self::MaterializationScan(n) => &n.children,
             Self::MagicMaterializationScan(n) => &n.children,
+            Self::RecursiveCte(n) => &n.children,
+            Self::RecursiveCteScan(n) => &n.children,
             Self::Aggregate(n) => &n.children,
             Self::SetOp(n) => &n.children,
             Self::SingleRow(n) => &n.children,
@@ -390,8 +405,11 @@ impl LogicalOperator {
             Self::ShowVar(n) => &n.children,
             Self::AttachDatabase(n) => &n.children,
             Self::DetachDatabase(n) => &n.children,
+            Self::Transaction(n) => &n.children,
             Self::Drop(n) => &n.children,
             Self::Insert(n) => &n.children,
+            Self::Delete(n) => &n.children,
+            Self::Merge(n) => &n.children,
             Self::CreateSchema(n) => &n.children,
             Self::CreateTable(n) => &n.children,
             Self::CreateView(n) => &n.children,
@@ -418,6 +436,8 @@ impl LogicalOperator {
             Self::ExpressionList(n) => &mut n.children,
             Self::MaterializationScan(n) => &mut n.children,
             Self::MagicMaterializationScan(n) => &mut n.children,
+            Self::RecursiveCte(n) => &mut n.children,
+            Self::RecursiveCteScan(n) => &mut n.children,
             Self::Aggregate(n) => &mut n.children,
             Self::SetOp(n) => &mut n.children,
             Self::SingleRow(n) => &mut n.children,
@@ -429,8 +449,11 @@ impl LogicalOperator {
             Self::ShowVar(n) => &mut n.children,
             Self::AttachDatabase(n) => &mut n.children,
             Self::DetachDatabase(n) => &mut n.children,
+            Self::Transaction(n) => &mut n.children,
             Self::Drop(n) => &mut n.children,
             Self::Insert(n) => &mut n.children,
+            Self::Delete(n) => &mut n.children,
+            Self::Merge(n) => &mut n.children,
             Self::CreateSchema(n) => &mut n.children,
             Self::CreateTable(n) => &mut n.children,
             Self::CreateView(n) => &mut n.children,
@@ -461,6 +484,8 @@ impl LogicalOperator {
             LogicalOperator::ExpressionList(n) => n.estimated_cardinality,
             LogicalOperator::MaterializationScan(n) => n.estimated_cardinality,
             LogicalOperator::MagicMaterializationScan(n) => n.estimated_cardinality,
+            LogicalOperator::RecursiveCte(n) => n.estimated_cardinality,
+            LogicalOperator::RecursiveCteScan(n) => n.estimated_cardinality,
             LogicalOperator::Aggregate(n) => n.estimated_cardinality,
             LogicalOperator::SetOp(n) => n.estimated_cardinality,
             LogicalOperator::SingleRow(n) => n.estimated_cardinality,
@@ -472,8 +497,11 @@ impl LogicalOperator {
             LogicalOperator::ShowVar(n) => n.estimated_cardinality,
             LogicalOperator::AttachDatabase(n) => n.estimated_cardinality,
             LogicalOperator::DetachDatabase(n) => n.estimated_cardinality,
+            LogicalOperator::Transaction(n) => n.estimated_cardinality,
             LogicalOperator::Drop(n) => n.estimated_cardinality,
             LogicalOperator::Insert(n) => n.estimated_cardinality,
+            LogicalOperator::Delete(n) => n.estimated_cardinality,
+            LogicalOperator::Merge(n) => n.estimated_cardinality,
             LogicalOperator::CreateSchema(n) => n.estimated_cardinality,
             LogicalOperator::CreateTable(n) => n.estimated_cardinality,
             LogicalOperator::CreateView(n) => n.estimated_cardinality,
@@ -502,6 +530,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ExpressionList(n) => n.name(),
             LogicalOperator::MaterializationScan(n) => n.name(),
             LogicalOperator::MagicMaterializationScan(n) => n.name(),
+            LogicalOperator::RecursiveCte(n) => n.name(),
+            LogicalOperator::RecursiveCteScan(n) => n.name(),
             LogicalOperator::Aggregate(n) => n.name(),
             LogicalOperator::SetOp(n) => n.name(),
             LogicalOperator::SingleRow(n) => n.name(),
@@ -513,8 +543,11 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ShowVar(n) => n.name(),
             LogicalOperator::AttachDatabase(n) => n.name(),
             LogicalOperator::DetachDatabase(n) => n.name(),
+            LogicalOperator::Transaction(n) => n.name(),
             LogicalOperator::Drop(n) => n.name(),
             LogicalOperator::Insert(n) => n.name(),
+            LogicalOperator::Delete(n) => n.name(),
+            LogicalOperator::Merge(n) => n.name(),
             LogicalOperator::CreateSchema(n) => n.name(),
             LogicalOperator::CreateTable(n) => n.name(),
             LogicalOperator::CreateView(n) => n.name(),
@@ -541,6 +574,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ExpressionList(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::MaterializationScan(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::MagicMaterializationScan(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::RecursiveCte(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::RecursiveCteScan(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Aggregate(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::SetOp(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::SingleRow(n) => n.get_output_table_refs(bind_context),
@@ -552,8 +587,11 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ShowVar(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::AttachDatabase(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::DetachDatabase(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Transaction(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Drop(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Insert(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Delete(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Merge(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::CreateSchema(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::CreateTable(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::CreateView(n) => n.get_output_table_refs(bind_context),
@@ -583,6 +621,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ExpressionList(n) => n.for_each_expr(func),
             LogicalOperator::MaterializationScan(n) => n.for_each_expr(func),
             LogicalOperator::MagicMaterializationScan(n) => n.for_each_expr(func),
+            LogicalOperator::RecursiveCte(n) => n.for_each_expr(func),
+            LogicalOperator::RecursiveCteScan(n) => n.for_each_expr(func),
             LogicalOperator::Aggregate(n) => n.for_each_expr(func),
             LogicalOperator::SetOp(n) => n.for_each_expr(func),
             LogicalOperator::SingleRow(n) => n.for_each_expr(func),
@@ -594,8 +634,11 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ShowVar(n) => n.for_each_expr(func),
             LogicalOperator::AttachDatabase(n) => n.for_each_expr(func),
             LogicalOperator::DetachDatabase(n) => n.for_each_expr(func),
+            LogicalOperator::Transaction(n) => n.for_each_expr(func),
             LogicalOperator::Drop(n) => n.for_each_expr(func),
             LogicalOperator::Insert(n) => n.for_each_expr(func),
+            LogicalOperator::Delete(n) => n.for_each_expr(func),
+            LogicalOperator::Merge(n) => n.for_each_expr(func),
             LogicalOperator::CreateSchema(n) => n.for_each_expr(func),
             LogicalOperator::CreateTable(n) => n.for_each_expr(func),
             LogicalOperator::CreateView(n) => n.for_each_expr(func),
@@ -625,6 +668,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ExpressionList(n) => n.for_each_expr_mut(func),
             LogicalOperator::MaterializationScan(n) => n.for_each_expr_mut(func),
             LogicalOperator::MagicMaterializationScan(n) => n.for_each_expr_mut(func),
+            LogicalOperator::RecursiveCte(n) => n.for_each_expr_mut(func),
+            LogicalOperator::RecursiveCteScan(n) => n.for_each_expr_mut(func),
             LogicalOperator::Aggregate(n) => n.for_each_expr_mut(func),
             LogicalOperator::SetOp(n) => n.for_each_expr_mut(func),
             LogicalOperator::SingleRow(n) => n.for_each_expr_mut(func),
@@ -636,8 +681,11 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::ShowVar(n) => n.for_each_expr_mut(func),
             LogicalOperator::AttachDatabase(n) => n.for_each_expr_mut(func),
             LogicalOperator::DetachDatabase(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Transaction(n) => n.for_each_expr_mut(func),
             LogicalOperator::Drop(n) => n.for_each_expr_mut(func),
             LogicalOperator::Insert(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Delete(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Merge(n) => n.for_each_expr_mut(func),
             LogicalOperator::CreateSchema(n) => n.for_each_expr_mut(func),
             LogicalOperator::CreateTable(n) => n.for_each_expr_mut(func),
             LogicalOperator::CreateView(n) => n.for_each_expr_mut(func),
@@ -33,10 +33,13 @@ pub mod plan_statement;
 
 mod plan_copy;
 mod plan_create_table;
+mod plan_delete;
 mod plan_explain;
 mod plan_insert;
+mod plan_merge;
 mod plan_query;
 mod plan_select;
 mod plan_setop;
 mod plan_subquery;
 mod plan_unnest;
+mod plan_update;
@@ -1,8 +1,10 @@
-use glaredb_error::{Result, not_implemented};
+use glaredb_error::Result;
 
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::bind_copy::{BoundCopyTo, BoundCopyToSource};
-use crate::logical::operator::LogicalOperator;
+use crate::logical::logical_copy::LogicalCopyTo;
+use crate::logical::operator::{LocationRequirement, LogicalOperator, Node};
+use crate::statistics::value::StatisticsValue;
 use crate::logical::planner::plan_from::FromPlanner;
 use crate::logical::planner::plan_query::QueryPlanner;
 
@@ -15,24 +17,22 @@ impl CopyPlanner {
         bind_context: &mut BindContext,
         copy_to: BoundCopyTo,
     ) -> Result<LogicalOperator> {
-        let _source = match copy_to.source {
+        let source = match copy_to.source {
             BoundCopyToSource::Query(query) => QueryPlanner.plan(bind_context, *query)?,
             BoundCopyToSource::Table(table) => FromPlanner.plan(bind_context, *table)?,
         };
 
         // Currently only support copying to local.
-
-        not_implemented!("Plan COPY TO")
-
-        // Ok(LogicalOperator::CopyTo(Node {
-        //     node: LogicalCopyTo {
-        //         source_schema: copy_to.source_schema,
-        //         location: copy_to.location,
-        //         copy_to: copy_to.copy_to,
-        //     },
-        //     location: LocationRequirement::ClientLocal,
-        //     children: vec![source],
-        //     estimated_cardinality: StatisticsValue::Unknown,
-        // }))
+        Ok(LogicalOperator::CopyTo(Node {
+            node: LogicalCopyTo {
+                source_schema: copy_to.source_schema,
+                location: copy_to.location,
+                copy_to: copy_to.copy_to,
+                filesystem: copy_to.filesystem,
+            },
+            location: LocationRequirement::ClientLocal,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
     }
 }
@@ -29,6 +29,7 @@ impl CreateTablePlanner {
                 schema: create.schema,
                 name: create.name,
                 columns: create.columns,
+                unique_constraints: create.unique_constraints,
                 on_conflict: create.on_conflict,
             },
             location: LocationRequirement::ClientLocal,
@@ -0,0 +1,83 @@
+use glaredb_error::Result;
+
+use super::plan_from::FromPlanner;
+use super::plan_subquery::SubqueryPlanner;
+use crate::expr::Expression;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_delete::{BoundDelete, BoundTargetScan};
+use crate::logical::binder::table_list::TableRef;
+use crate::logical::logical_delete::LogicalDelete;
+use crate::logical::logical_filter::LogicalFilter;
+use crate::logical::logical_project::LogicalProject;
+use crate::logical::operator::{LocationRequirement, LogicalOperator, Node};
+use crate::statistics::value::StatisticsValue;
+
+#[derive(Debug)]
+pub struct DeletePlanner;
+
+impl DeletePlanner {
+    pub fn plan(
+        &self,
+        bind_context: &mut BindContext,
+        delete: BoundDelete,
+    ) -> Result<LogicalOperator> {
+        let row_id = delete.scan.row_id.clone();
+        let catalog = delete.scan.table.catalog.clone();
+        let schema = delete.scan.table.schema.clone();
+        let table = delete.scan.table.entry.clone();
+        let location = delete.scan.table_location;
+
+        let source = plan_target_scan(
+            bind_context,
+            delete.scan,
+            vec![row_id],
+            delete.projection_table,
+        )?;
+
+        Ok(LogicalOperator::Delete(Node {
+            node: LogicalDelete {
+                catalog,
+                schema,
+                table,
+            },
+            location,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+}
+
+/// Plan the scan and filter for the table being modified by a DELETE, UPDATE,
+/// or MERGE, projecting out the given expressions.
+pub(super) fn plan_target_scan(
+    bind_context: &mut BindContext,
+    scan: BoundTargetScan,
+    mut projections: Vec<Expression>,
+    projection_table: TableRef,
+) -> Result<LogicalOperator> {
+    let mut plan = FromPlanner.plan(bind_context, scan.from)?;
+
+    if let Some(mut filter) = scan.filter {
+        plan = SubqueryPlanner.plan_expression(bind_context, &mut filter, plan)?;
+        plan = LogicalOperator::Filter(Node {
+            node: LogicalFilter { filter },
+            location: LocationRequirement::Any,
+            children: vec![plan],
+            estimated_cardinality: StatisticsValue::Unknown,
+        });
+    }
+
+    for expr in &mut projections {
+        plan = SubqueryPlanner.plan_expression(bind_context, expr, plan)?;
+    }
+
+    Ok(LogicalOperator::Project(Node {
+        node: LogicalProject {
+            projections,
+            projection_table,
+        },
+        location: LocationRequirement::Any,
+        children: vec![plan],
+        estimated_cardinality: StatisticsValue::Unknown,
+    }))
+}
@@ -18,7 +18,10 @@ use crate::logical::logical_join::{
     LogicalComparisonJoin,
     LogicalCrossJoin,
 };
-use crate::logical::logical_materialization::LogicalMaterializationScan;
+use crate::logical::logical_materialization::{
+    LogicalMaterializationScan,
+    LogicalRecursiveCteScan,
+};
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::logical_scan::{
     LogicalScan,
@@ -246,6 +249,15 @@ impl FromPlanner {
                     estimated_cardinality: StatisticsValue::Unknown,
                 }))
             }
+            BoundFromItem::RecursiveCteScan(scan) => Ok(LogicalOperator::RecursiveCteScan(Node {
+                node: LogicalRecursiveCteScan {
+                    cte_ref: scan.cte_ref,
+                    table_ref: scan.table_ref,
+                },
+                location: LocationRequirement::Any,
+                children: Vec::new(),
+                estimated_cardinality: StatisticsValue::Unknown,
+            })),
             BoundFromItem::Empty => Ok(LogicalOperator::SingleRow(Node {
                 node: LogicalSingleRow,
                 location: LocationRequirement::Any,
@@ -269,7 +281,11 @@ impl FromPlanner {
         //
         // Note that a CROSS JOIN LATERAL is implicitly an inner join, so we
         // need to keep planning that.
-        if !is_lateral && join.conditions.is_empty() {
+        //
+        // Non-inner joins without conditions still need the join semantics
+        // (e.g. emitting unmatched rows), so those go through normal join
+        // planning with an always true condition.
+        if !is_lateral && join.conditions.is_empty() && join.join_type == JoinType::Inner {
             if !join.conditions.is_empty() {
                 return Err(DbError::new("CROSS JOIN should not have conditions"));
             }
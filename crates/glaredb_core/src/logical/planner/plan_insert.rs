@@ -4,6 +4,7 @@ use super::plan_query::QueryPlanner;
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::bind_insert::BoundInsert;
 use crate::logical::logical_insert::LogicalInsert;
+use crate::logical::logical_merge::LogicalMerge;
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::operator::{LocationRequirement, LogicalOperator, Node};
 use crate::statistics::value::StatisticsValue;
@@ -31,6 +32,22 @@ impl InsertPlanner {
             })
         }
 
+        if let Some(conflict) = insert.conflict {
+            // Table has unique constraints, insert through a merge to check
+            // them.
+            return Ok(LogicalOperator::Merge(Node {
+                node: LogicalMerge {
+                    catalog: insert.table.catalog,
+                    schema: insert.table.schema,
+                    table: insert.table.entry,
+                    conflict,
+                },
+                location: insert.table_location,
+                children: vec![source],
+                estimated_cardinality: StatisticsValue::Unknown,
+            }));
+        }
+
         Ok(LogicalOperator::Insert(Node {
             node: LogicalInsert {
                 catalog: insert.table.catalog,
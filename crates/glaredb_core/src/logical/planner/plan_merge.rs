@@ -0,0 +1,43 @@
+use glaredb_error::Result;
+
+use super::plan_delete::plan_target_scan;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_merge::BoundMerge;
+use crate::logical::logical_merge::LogicalMerge;
+use crate::logical::operator::{LogicalOperator, Node};
+use crate::statistics::value::StatisticsValue;
+
+#[derive(Debug)]
+pub struct MergePlanner;
+
+impl MergePlanner {
+    pub fn plan(
+        &self,
+        bind_context: &mut BindContext,
+        merge: BoundMerge,
+    ) -> Result<LogicalOperator> {
+        let catalog = merge.scan.table.catalog.clone();
+        let schema = merge.scan.table.schema.clone();
+        let table = merge.scan.table.entry.clone();
+        let location = merge.scan.table_location;
+
+        let source = plan_target_scan(
+            bind_context,
+            merge.scan,
+            merge.projections,
+            merge.projection_table,
+        )?;
+
+        Ok(LogicalOperator::Merge(Node {
+            node: LogicalMerge {
+                catalog,
+                schema,
+                table,
+                conflict: merge.conflict,
+            },
+            location,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+}
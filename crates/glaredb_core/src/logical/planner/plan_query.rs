@@ -20,6 +20,7 @@ impl QueryPlanner {
         match query {
             BoundQuery::Select(select) => SelectPlanner.plan(bind_context, select),
             BoundQuery::Setop(setop) => SetOpPlanner.plan(bind_context, setop),
+            BoundQuery::RecursiveCte(cte) => SetOpPlanner.plan_recursive_cte(bind_context, cte),
             BoundQuery::Values(values) => {
                 let table = bind_context.get_table(values.expressions_table)?;
                 let card = values.rows.len();
@@ -3,9 +3,11 @@ use glaredb_error::{DbError, Result};
 use crate::expr::column_expr::{ColumnExpr, ColumnReference};
 use crate::expr::{self, Expression};
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
+use crate::logical::binder::bind_query::bind_recursive_cte::BoundRecursiveCte;
 use crate::logical::binder::bind_query::bind_setop::{BoundSetOp, SetOpCastRequirement};
 use crate::logical::binder::table_list::{Table, TableRef};
 use crate::logical::logical_limit::LogicalLimit;
+use crate::logical::logical_materialization::LogicalRecursiveCte;
 use crate::logical::logical_order::LogicalOrder;
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::logical_setop::LogicalSetop;
@@ -82,6 +84,34 @@ impl SetOpPlanner {
         Ok(plan)
     }
 
+    /// Plan a recursive CTE.
+    ///
+    /// The recursive term is cast to the output types of the anchor if
+    /// needed.
+    pub fn plan_recursive_cte(
+        &self,
+        bind_context: &mut BindContext,
+        cte: BoundRecursiveCte,
+    ) -> Result<LogicalOperator> {
+        let anchor = QueryPlanner.plan(bind_context, *cte.anchor)?;
+        let mut recursive = QueryPlanner.plan(bind_context, *cte.recursive)?;
+
+        if let Some(cast_ref) = cte.recursive_cast_ref {
+            recursive = self.wrap_cast(bind_context, recursive, cte.recursive_scope, cast_ref)?;
+        }
+
+        Ok(LogicalOperator::RecursiveCte(Node {
+            node: LogicalRecursiveCte {
+                table_ref: cte.table_ref,
+                cte_ref: cte.cte_ref,
+                all: cte.all,
+            },
+            location: LocationRequirement::Any,
+            children: vec![anchor, recursive],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+
     fn wrap_cast(
         &self,
         bind_context: &BindContext,
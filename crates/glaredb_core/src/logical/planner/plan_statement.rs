@@ -2,9 +2,12 @@ use glaredb_error::Result;
 
 use super::plan_copy::CopyPlanner;
 use super::plan_create_table::CreateTablePlanner;
+use super::plan_delete::DeletePlanner;
 use super::plan_explain::ExplainPlanner;
 use super::plan_insert::InsertPlanner;
+use super::plan_merge::MergePlanner;
 use super::plan_query::QueryPlanner;
+use super::plan_update::UpdatePlanner;
 use crate::logical::binder::bind_attach::{BoundAttach, BoundDetach};
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::bind_statement::BoundStatement;
@@ -30,8 +33,12 @@ impl StatementPlanner {
             BoundStatement::Detach(BoundDetach::Database(plan)) => {
                 Ok(LogicalOperator::DetachDatabase(plan))
             }
+            BoundStatement::Transaction(plan) => Ok(LogicalOperator::Transaction(plan)),
             BoundStatement::Drop(plan) => Ok(LogicalOperator::Drop(plan)),
             BoundStatement::Insert(insert) => InsertPlanner.plan(bind_context, insert),
+            BoundStatement::Delete(delete) => DeletePlanner.plan(bind_context, delete),
+            BoundStatement::Update(update) => UpdatePlanner.plan(bind_context, update),
+            BoundStatement::Merge(merge) => MergePlanner.plan(bind_context, merge),
             BoundStatement::CreateSchema(plan) => Ok(LogicalOperator::CreateSchema(plan)),
             BoundStatement::CreateTable(create) => CreateTablePlanner.plan(bind_context, create),
             BoundStatement::CreateView(create) => Ok(LogicalOperator::CreateView(create)),
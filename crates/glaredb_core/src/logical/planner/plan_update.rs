@@ -0,0 +1,43 @@
+use glaredb_error::Result;
+
+use super::plan_delete::plan_target_scan;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_update::BoundUpdate;
+use crate::logical::logical_merge::{ConflictBehavior, LogicalMerge};
+use crate::logical::operator::{LogicalOperator, Node};
+use crate::statistics::value::StatisticsValue;
+
+#[derive(Debug)]
+pub struct UpdatePlanner;
+
+impl UpdatePlanner {
+    pub fn plan(
+        &self,
+        bind_context: &mut BindContext,
+        update: BoundUpdate,
+    ) -> Result<LogicalOperator> {
+        let catalog = update.scan.table.catalog.clone();
+        let schema = update.scan.table.schema.clone();
+        let table = update.scan.table.entry.clone();
+        let location = update.scan.table_location;
+
+        let source = plan_target_scan(
+            bind_context,
+            update.scan,
+            update.projections,
+            update.projection_table,
+        )?;
+
+        Ok(LogicalOperator::Merge(Node {
+            node: LogicalMerge {
+                catalog,
+                schema,
+                table,
+                conflict: ConflictBehavior::Error,
+            },
+            location,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+}
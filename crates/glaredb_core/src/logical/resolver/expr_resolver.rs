@@ -11,7 +11,6 @@ use super::{ResolveContext, ResolvedMeta, Resolver, ast_datatype_to_exec_datatyp
 use crate::catalog::context::SYSTEM_CATALOG;
 use crate::catalog::entry::CatalogEntryType;
 use crate::catalog::system::DEFAULT_SCHEMA;
-use crate::catalog::{Catalog, Schema};
 use crate::logical::binder::expr_binder::BaseExpressionBinder;
 use crate::logical::operator::LocationRequirement;
 use crate::runtime::system::SystemRuntime;
@@ -90,9 +89,13 @@ where
             ast::GroupByExpr::Rollup(exprs) => {
                 ast::GroupByExpr::Rollup(self.resolve_expressions(exprs, resolve_context).await?)
             }
-            ast::GroupByExpr::GroupingSets(exprs) => ast::GroupByExpr::GroupingSets(
-                self.resolve_expressions(exprs, resolve_context).await?,
-            ),
+            ast::GroupByExpr::GroupingSets(sets) => {
+                let mut resolved = Vec::with_capacity(sets.len());
+                for exprs in sets {
+                    resolved.push(self.resolve_expressions(exprs, resolve_context).await?);
+                }
+                ast::GroupByExpr::GroupingSets(resolved)
+            }
         })
     }
 
@@ -240,12 +243,12 @@ where
                     .await
             }
             ast::Expr::TypedString { datatype, value } => {
-                let datatype = ast_datatype_to_exec_datatype(datatype)?;
+                let datatype = ast_datatype_to_exec_datatype(datatype, &self.resolver.config)?;
                 Ok(ast::Expr::TypedString { datatype, value })
             }
             ast::Expr::Cast { datatype, expr } => {
                 let expr = Box::pin(self.resolve_expression(*expr, resolve_context)).await?;
-                let datatype = ast_datatype_to_exec_datatype(datatype)?;
+                let datatype = ast_datatype_to_exec_datatype(datatype, &self.resolver.config)?;
                 Ok(ast::Expr::Cast {
                     datatype,
                     expr: Box::new(expr),
@@ -378,6 +381,15 @@ where
                     expr: Box::new(expr),
                 })
             }
+            ast::Expr::AtTimeZone { expr, timezone } => {
+                let expr = Box::pin(self.resolve_expression(*expr, resolve_context)).await?;
+                let timezone =
+                    Box::pin(self.resolve_expression(*timezone, resolve_context)).await?;
+                Ok(ast::Expr::AtTimeZone {
+                    expr: Box::new(expr),
+                    timezone: Box::new(timezone),
+                })
+            }
             ast::Expr::Columns(col) => Ok(ast::Expr::Columns(col)),
             other => not_implemented!("resolve expr {other:?}"),
         }
@@ -595,7 +607,7 @@ where
                 || context
                     .require_get_database(&catalog)?
                     .catalog
-                    .get_schema(&schema)?
+                    .get_schema(context.transaction(), &schema)?
                     .is_none())
         {
             let unqualified_name = func.reference.0.pop().unwrap(); // Length checked above.
@@ -622,6 +634,26 @@ where
             return Ok(resolved);
         }
 
+        // Functions for the current date or time are provided the session
+        // time zone and the statement start time.
+        if func.args.is_empty()
+            && matches!(
+                func_name.as_str(),
+                "now" | "current_timestamp" | "current_date" | "current_time"
+            )
+        {
+            func.args.push(ast::FunctionArg::Unnamed {
+                arg: ast::Expr::Literal(ast::Literal::SingleQuotedString(
+                    self.resolver.config.timezone.clone(),
+                )),
+            });
+            func.args.push(ast::FunctionArg::Unnamed {
+                arg: ast::Expr::Literal(ast::Literal::Number(
+                    self.resolver.config.statement_time.to_string(),
+                )),
+            });
+        }
+
         let filter = self
             .resolve_optional_expression(func.filter.map(|e| *e), resolve_context)
             .await?
@@ -636,7 +668,7 @@ where
         let schema_ent = context
             .require_get_database(&catalog)?
             .catalog
-            .get_schema(&schema)?
+            .get_schema(context.transaction(), &schema)?
             .ok_or_else(|| DbError::new(format!("Missing schema: {schema}")))?;
 
         // Check if this is a special function.
@@ -650,6 +682,7 @@ where
                 distinct: func.distinct,
                 star: func.star,
                 args,
+                null_treatment: func.null_treatment,
                 filter,
                 over,
             })));
@@ -669,6 +702,7 @@ where
                 distinct: func.distinct,
                 star: func.star,
                 args,
+                null_treatment: func.null_treatment,
                 filter,
                 over,
             })));
@@ -686,16 +720,36 @@ where
                 distinct: func.distinct,
                 star: func.star,
                 args,
+                null_treatment: func.null_treatment,
+                filter,
+                over,
+            })));
+        }
+
+        // Now check window functions.
+        if let Some(window) = schema_ent.get_window_function(&func_name)? {
+            let resolve_idx = resolve_context.functions.push_resolved(
+                ResolvedFunction::Window(window.try_as_window_function_entry()?.function),
+                LocationRequirement::Any,
+            );
+            return Ok(ast::Expr::Function(Box::new(ast::Function {
+                reference: resolve_idx,
+                distinct: func.distinct,
+                star: func.star,
+                args,
+                null_treatment: func.null_treatment,
                 filter,
                 over,
             })));
         }
 
         Err(create_user_facing_resolve_err(
-            Some(&schema_ent),
+            context.transaction(),
+            Some(schema_ent.as_ref()),
             &[
                 CatalogEntryType::ScalarFunction,
                 CatalogEntryType::AggregateFunction,
+                CatalogEntryType::WindowFunction,
             ],
             &func_name,
         ))
@@ -729,6 +783,22 @@ where
         over: ast::WindowSpec<Raw>,
         resolve_context: &mut ResolveContext,
     ) -> Result<ast::WindowSpec<ResolvedMeta>> {
+        match over {
+            ast::WindowSpec::Definition(window_def) => Ok(ast::WindowSpec::Definition(
+                self.resolve_window_definition(window_def, resolve_context)
+                    .await?,
+            )),
+            ast::WindowSpec::Named(ident) => Ok(ast::WindowSpec::Named(ident)),
+        }
+    }
+
+    /// Resolve a window definition, either from an OVER clause or from the
+    /// WINDOW clause.
+    pub async fn resolve_window_definition(
+        &self,
+        window_def: ast::WindowDefinition<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::WindowDefinition<ResolvedMeta>> {
         async fn resolve_window_frame_bound<R>(
             resolver: &ExpressionResolver<'_, R>,
             bound: ast::WindowFrameBound<Raw>,
@@ -758,53 +828,45 @@ where
             })
         }
 
-        match over {
-            ast::WindowSpec::Definition(window_def) => {
-                let partition_by =
-                    Box::pin(self.resolve_expressions(window_def.partition_by, resolve_context))
-                        .await?;
-
-                let mut order_by = Vec::with_capacity(window_def.order_by.len());
-                for order in window_def.order_by {
-                    let order = ast::OrderByNode {
-                        typ: order.typ,
-                        nulls: order.nulls,
-                        expr: Box::pin(self.resolve_expression(order.expr, resolve_context))
-                            .await?,
-                    };
+        let partition_by =
+            Box::pin(self.resolve_expressions(window_def.partition_by, resolve_context)).await?;
 
-                    order_by.push(order);
-                }
+        let mut order_by = Vec::with_capacity(window_def.order_by.len());
+        for order in window_def.order_by {
+            let order = ast::OrderByNode {
+                typ: order.typ,
+                nulls: order.nulls,
+                expr: Box::pin(self.resolve_expression(order.expr, resolve_context)).await?,
+            };
+
+            order_by.push(order);
+        }
 
-                let frame = match window_def.frame {
-                    Some(frame) => {
-                        let start =
-                            resolve_window_frame_bound(self, frame.start, resolve_context).await?;
-                        let end = match frame.end {
-                            Some(end) => {
-                                Some(resolve_window_frame_bound(self, end, resolve_context).await?)
-                            }
-                            None => None,
-                        };
-
-                        Some(ast::WindowFrame {
-                            unit: frame.unit,
-                            start,
-                            end,
-                            exclusion: frame.exclusion,
-                        })
+        let frame = match window_def.frame {
+            Some(frame) => {
+                let start = resolve_window_frame_bound(self, frame.start, resolve_context).await?;
+                let end = match frame.end {
+                    Some(end) => {
+                        Some(resolve_window_frame_bound(self, end, resolve_context).await?)
                     }
                     None => None,
                 };
 
-                Ok(ast::WindowSpec::Definition(ast::WindowDefinition {
-                    existing: window_def.existing,
-                    partition_by,
-                    order_by,
-                    frame,
-                }))
+                Some(ast::WindowFrame {
+                    unit: frame.unit,
+                    start,
+                    end,
+                    exclusion: frame.exclusion,
+                })
             }
-            ast::WindowSpec::Named(ident) => Ok(ast::WindowSpec::Named(ident)),
-        }
+            None => None,
+        };
+
+        Ok(ast::WindowDefinition {
+            existing: window_def.existing,
+            partition_by,
+            order_by,
+            frame,
+        })
     }
 }
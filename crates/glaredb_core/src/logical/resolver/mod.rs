@@ -1,6 +1,7 @@
 pub mod expr_resolver;
 pub mod resolve_context;
 pub mod resolve_normal;
+pub mod resolved_copy_to;
 pub mod resolved_cte;
 pub mod resolved_function;
 pub mod resolved_table;
@@ -16,8 +17,9 @@ use glaredb_parser::parser;
 use glaredb_parser::statement::{RawStatement, Statement};
 use resolve_context::{ItemReference, MaybeResolved, ResolveContext, ResolveListIdx};
 use resolve_normal::{MaybeResolvedTable, NormalResolver};
+use resolved_copy_to::ResolvedCopyTo;
 use resolved_cte::ResolvedCte;
-use resolved_table::ResolvedTableOrCteReference;
+use resolved_table::{ResolvedTableOrCteReference, UnresolvedTableReference};
 use resolved_table_function::ResolvedTableFunctionReference;
 use serde::{Deserialize, Serialize};
 
@@ -36,9 +38,11 @@ use crate::catalog::system::{
     SHOW_TABLES_VIEW,
 };
 use crate::expr;
+use crate::functions::copy_to::CopyToOptions;
 use crate::functions::table::TableFunctionInput;
 use crate::functions::table::scan::ScanContext;
 use crate::logical::operator::LocationRequirement;
+use crate::runtime::filesystem::FileOpenContext;
 use crate::runtime::system::SystemRuntime;
 
 /// An AST statement with references bound to data inside of the `resolve_context`.
@@ -59,7 +63,9 @@ impl AstMeta for ResolvedMeta {
     type FunctionReference = ResolveListIdx;
     type SubqueryOptions = ResolvedSubqueryOptions;
     type DataType = DataType;
-    type CopyToDestination = (); // TODO
+    type CopyToDestination = ast::CopyToTarget;
+    /// Options are resolved alongside the COPY TO function in the resolve
+    /// context.
     type CopyToOptions = ();
     /// SHOW statements will be converted to views if need during the resolve
     /// step (e.g. for SHOW DATABASES). If we produce a resolved SHOW, it will
@@ -110,6 +116,13 @@ impl ResolveMode {
 #[derive(Debug)]
 pub struct ResolveConfig {
     pub enable_function_chaining: bool,
+    /// Time zone to use for TIMESTAMPTZ types.
+    pub timezone: String,
+    /// Time the statement started, in microseconds since the Unix epoch.
+    ///
+    /// Used for functions returning the current time so that every call in a
+    /// statement sees the same value.
+    pub statement_time: i64,
 }
 
 /// Resolves references in a raw SQL AST with entries in the catalog.
@@ -175,6 +188,15 @@ where
             Statement::Insert(insert) => {
                 Statement::Insert(self.resolve_insert(insert, &mut resolve_context).await?)
             }
+            Statement::Delete(delete) => {
+                Statement::Delete(self.resolve_delete(delete, &mut resolve_context).await?)
+            }
+            Statement::Update(update) => {
+                Statement::Update(self.resolve_update(update, &mut resolve_context).await?)
+            }
+            Statement::Merge(merge) => {
+                Statement::Merge(self.resolve_merge(merge, &mut resolve_context).await?)
+            }
             Statement::CreateTable(create) => Statement::CreateTable(
                 self.resolve_create_table(create, &mut resolve_context)
                     .await?,
@@ -206,6 +228,7 @@ where
                 Statement::Attach(self.resolve_attach(attach, &mut resolve_context).await?)
             }
             Statement::Detach(detach) => Statement::Detach(self.resolve_detach(detach).await?),
+            Statement::Transaction(stmt) => Statement::Transaction(stmt),
         };
 
         Ok((bound, resolve_context))
@@ -273,7 +296,9 @@ where
         Ok(ast::Attach {
             datasource_name: attach.datasource_name,
             attach_type: attach.attach_type,
-            alias: Self::reference_to_strings(attach.alias).into(),
+            alias: attach
+                .alias
+                .map(|alias| Self::reference_to_strings(alias).into()),
             options,
         })
     }
@@ -292,7 +317,7 @@ where
         copy_to: ast::CopyTo<Raw>,
         resolve_context: &mut ResolveContext,
     ) -> Result<ast::CopyTo<ResolvedMeta>> {
-        let _source = match copy_to.source {
+        let source = match copy_to.source {
             ast::CopyToSource::Query(query) => ast::CopyToSource::Query(Box::new(
                 self.resolve_query(*query, resolve_context).await?,
             )),
@@ -354,61 +379,47 @@ where
             options.insert(key, val);
         }
 
-        not_implemented!("COPY TO")
-        // let mut options = CopyToArgs { named: options };
-
-        // let target = match copy_to.target {
-        //     ast::CopyToTarget::File(file_name) => {
-        //         let func = match options.try_remove_format() {
-        //             Some(BorrowedScalarValue::Utf8(format)) => {
-        //                 not_implemented!("COPY TO")
-        //                 // // User specified a format, lookup in system catalog.
-        //                 // let ent = self
-        //                 //     .context
-        //                 //     .system_catalog()?
-        //                 //     .get_schema(self.tx, FUNCTION_LOOKUP_CATALOG)?
-        //                 //     .required("function lookup schema")?
-        //                 //     .get_copy_to_function_for_format(self.tx, &format)?;
-
-        //                 // match ent {
-        //                 //     Some(ent) => ent.try_as_copy_to_function_entry()?.function.clone(),
-        //                 //     None => {
-        //                 //         return Err(RayexecError::new(format!(
-        //                 //             "No registered COPY TO function for format '{format}'"
-        //                 //         )))
-        //                 //     }
-        //                 // }
-        //             }
-        //             Some(other) => {
-        //                 return Err(RayexecError::new(format!(
-        //                     "Invalid format expression: {other}"
-        //                 )))
-        //             }
-        //             None => {
-        //                 // Infer from file name.
-        //                 let handler =
-        //                     self.file_handlers.find_match(&file_name).ok_or_else(|| {
-        //                         RayexecError::new(format!(
-        //                             "No registered file handler for file '{file_name}'"
-        //                         ))
-        //                     })?;
-        //                 handler
-        //                     .copy_to
-        //                     .as_ref()
-        //                     .ok_or_else(|| RayexecError::new("No registered COPY TO function"))?
-        //                     .clone()
-        //             }
-        //         };
-
-        //         FileLocation::parse(&file_name)
-        //     }
-        // };
-
-        // Ok(ast::CopyTo {
-        //     source,
-        //     target,
-        //     options,
-        // })
+        let ast::CopyToTarget::File(path) = copy_to.target;
+
+        let resolver = NormalResolver::new(self.context, self.runtime);
+        let function = match options.remove("format") {
+            // User specified a format, lookup in system catalog.
+            Some(ScalarValue::Utf8(format)) => {
+                resolver.require_resolve_copy_to_function(&format)?
+            }
+            Some(other) => {
+                return Err(DbError::new(format!(
+                    "Invalid FORMAT for COPY TO, expected a string: {other}"
+                )));
+            }
+            // Infer from file name.
+            None => resolver.require_resolve_copy_to_function_for_path(&path)?,
+        };
+
+        // Options are passed to the filesystem too to allow things like
+        // providing credentials.
+        let named: HashMap<_, _> = options
+            .iter()
+            .map(|(key, val)| (key.clone(), expr::lit(val.clone()).into()))
+            .collect();
+        let filesystem = self
+            .runtime
+            .filesystem_dispatch()
+            .filesystem_for_path(&path)?
+            .load_state(FileOpenContext::new(self.context, &named))
+            .await?;
+
+        resolve_context.copy_to = Some(ResolvedCopyTo {
+            function,
+            filesystem,
+            options: CopyToOptions { options },
+        });
+
+        Ok(ast::CopyTo {
+            source,
+            target: ast::CopyToTarget::File(path),
+            options: (),
+        })
     }
 
     async fn resolve_drop(
@@ -473,7 +484,9 @@ where
             if name.0.len() == 2 {
                 name.0.insert(0, "temp".to_string()); // Catalog
             }
-        } else {
+        } else if name.0.len() != 3 {
+            // Non-temp objects need to be fully qualified with an attached
+            // database until we have a default persistent database.
             return Err(DbError::new(
                 "Persistent tables not yet supported, use CREATE TEMP TABLE",
             ));
@@ -485,7 +498,7 @@ where
             .map(|col| {
                 Ok(ColumnDef::<ResolvedMeta> {
                     name: col.name,
-                    datatype: ast_datatype_to_exec_datatype(col.datatype)?,
+                    datatype: ast_datatype_to_exec_datatype(col.datatype, &self.config)?,
                     opts: col.opts,
                 })
             })
@@ -503,6 +516,7 @@ where
             external: create.external,
             name,
             columns,
+            constraints: create.constraints,
             source,
         })
     }
@@ -522,7 +536,9 @@ where
             if name.0.len() == 2 {
                 name.0.insert(0, "temp".to_string()); // Catalog
             }
-        } else {
+        } else if name.0.len() != 3 {
+            // Non-temp objects need to be fully qualified with an attached
+            // database until we have a default persistent database.
             return Err(DbError::new(
                 "Persistent views not yet supported, use CREATE TEMP VIEW",
             ));
@@ -545,16 +561,192 @@ where
         insert: ast::Insert<Raw>,
         resolve_context: &mut ResolveContext,
     ) -> Result<ast::Insert<ResolvedMeta>> {
+        let table = self
+            .resolve_table_for_write(&insert.table, resolve_context)
+            .await?;
+
+        let source = self.resolve_query(insert.source, resolve_context).await?;
+
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let on_conflict = match insert.on_conflict {
+            Some(on_conflict) => {
+                let action = match on_conflict.action {
+                    ast::OnConflictAction::DoNothing => ast::OnConflictAction::DoNothing,
+                    ast::OnConflictAction::DoUpdate {
+                        assignments,
+                        where_expr,
+                    } => ast::OnConflictAction::DoUpdate {
+                        assignments: self
+                            .resolve_assignments(assignments, resolve_context)
+                            .await?,
+                        where_expr: self
+                            .resolve_optional_expression(where_expr, resolve_context)
+                            .await?,
+                    },
+                };
+                Some(ast::InsertOnConflict {
+                    columns: on_conflict.columns,
+                    action,
+                })
+            }
+            None => None,
+        };
+
+        Ok(ast::Insert {
+            table: idx,
+            columns: insert.columns,
+            source,
+            on_conflict,
+        })
+    }
+
+    async fn resolve_merge(
+        &self,
+        merge: ast::Merge<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::Merge<ResolvedMeta>> {
+        let table = self
+            .resolve_table_for_write(&merge.table, resolve_context)
+            .await?;
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let source = self.resolve_from(merge.source, resolve_context).await?;
+        let on = ExpressionResolver::new(self)
+            .resolve_expression(merge.on, resolve_context)
+            .await?;
+
+        let mut clauses = Vec::with_capacity(merge.clauses.len());
+        for clause in merge.clauses {
+            let action = match clause.action {
+                ast::MergeAction::Update { assignments } => ast::MergeAction::Update {
+                    assignments: self
+                        .resolve_assignments(assignments, resolve_context)
+                        .await?,
+                },
+                ast::MergeAction::Delete => ast::MergeAction::Delete,
+                ast::MergeAction::Insert { columns, values } => ast::MergeAction::Insert {
+                    columns,
+                    values: ExpressionResolver::new(self)
+                        .resolve_expressions(values, resolve_context)
+                        .await?,
+                },
+                ast::MergeAction::DoNothing => ast::MergeAction::DoNothing,
+            };
+
+            clauses.push(ast::MergeClause {
+                matched: clause.matched,
+                condition: self
+                    .resolve_optional_expression(clause.condition, resolve_context)
+                    .await?,
+                action,
+            });
+        }
+
+        Ok(ast::Merge {
+            table: idx,
+            alias: merge.alias,
+            source,
+            on,
+            clauses,
+        })
+    }
+
+    async fn resolve_delete(
+        &self,
+        delete: ast::Delete<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::Delete<ResolvedMeta>> {
+        let table = self
+            .resolve_table_for_write(&delete.table, resolve_context)
+            .await?;
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let where_expr = self
+            .resolve_optional_expression(delete.where_expr, resolve_context)
+            .await?;
+
+        Ok(ast::Delete {
+            table: idx,
+            alias: delete.alias,
+            where_expr,
+        })
+    }
+
+    async fn resolve_update(
+        &self,
+        update: ast::Update<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::Update<ResolvedMeta>> {
+        let table = self
+            .resolve_table_for_write(&update.table, resolve_context)
+            .await?;
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let assignments = self
+            .resolve_assignments(update.assignments, resolve_context)
+            .await?;
+        let where_expr = self
+            .resolve_optional_expression(update.where_expr, resolve_context)
+            .await?;
+
+        Ok(ast::Update {
+            table: idx,
+            alias: update.alias,
+            assignments,
+            where_expr,
+        })
+    }
+
+    async fn resolve_assignments(
+        &self,
+        assignments: Vec<ast::Assignment<Raw>>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<Vec<ast::Assignment<ResolvedMeta>>> {
+        let mut resolved = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            resolved.push(ast::Assignment {
+                column: assignment.column,
+                expr: ExpressionResolver::new(self)
+                    .resolve_expression(assignment.expr, resolve_context)
+                    .await?,
+            });
+        }
+        Ok(resolved)
+    }
+
+    async fn resolve_optional_expression(
+        &self,
+        expr: Option<ast::Expr<Raw>>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<Option<ast::Expr<ResolvedMeta>>> {
+        match expr {
+            Some(expr) => Ok(Some(
+                ExpressionResolver::new(self)
+                    .resolve_expression(expr, resolve_context)
+                    .await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolve the table being written to by an INSERT, DELETE, UPDATE, or
+    /// MERGE.
+    async fn resolve_table_for_write(
+        &self,
+        reference: &ObjectReference,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<MaybeResolved<ResolvedTableOrCteReference, UnresolvedTableReference>> {
         let table = match self.resolve_mode {
             ResolveMode::Normal => {
                 let table = NormalResolver::new(self.context, self.runtime)
-                    .require_resolve_table_or_cte(&insert.table, resolve_context)
+                    .require_resolve_table_or_cte(reference, resolve_context)
                     .await?;
                 MaybeResolved::Resolved(table, LocationRequirement::ClientLocal)
             }
             ResolveMode::Hybrid => {
                 let table = NormalResolver::new(self.context, self.runtime)
-                    .resolve_table_or_cte(&insert.table, resolve_context)
+                    .resolve_table_or_cte(reference, resolve_context)
                     .await?;
 
                 match table {
@@ -566,23 +758,14 @@ where
                     }
                     MaybeResolvedTable::Unresolved => {
                         return Err(DbError::new(format!(
-                            "Missing table or view for reference '{}'",
-                            insert.table
+                            "Missing table or view for reference '{reference}'"
                         )));
                     }
                 }
             }
         };
 
-        let source = self.resolve_query(insert.source, resolve_context).await?;
-
-        let idx = resolve_context.tables.push_maybe_resolved(table);
-
-        Ok(ast::Insert {
-            table: idx,
-            columns: insert.columns,
-            source,
-        })
+        Ok(table)
     }
 
     async fn resolve_query(
@@ -700,14 +883,21 @@ where
 
         for cte in ctes.ctes.into_iter() {
             let depth = resolve_context.current_depth;
-
-            let resolved_body = Box::pin(self.resolve_query(*cte.body, resolve_context)).await?;
             let resolved_cte = ResolvedCte {
                 name: cte.alias.as_normalized_string(),
                 depth,
             };
 
-            resolve_context.push_cte(resolved_cte);
+            // CTEs in a WITH RECURSIVE are visible to their own body, so need
+            // to be pushed before resolving it.
+            let resolved_body = if ctes.recursive {
+                resolve_context.push_cte(resolved_cte);
+                Box::pin(self.resolve_query(*cte.body, resolve_context)).await?
+            } else {
+                let body = Box::pin(self.resolve_query(*cte.body, resolve_context)).await?;
+                resolve_context.push_cte(resolved_cte);
+                body
+            };
 
             resolved_ctes.push(ast::CommonTableExpr {
                 alias: cte.alias,
@@ -797,6 +987,16 @@ where
             None => None,
         };
 
+        let mut windows = Vec::with_capacity(select.windows.len());
+        for window in select.windows {
+            windows.push(ast::NamedWindowDefinition {
+                name: window.name,
+                definition: ExpressionResolver::new(self)
+                    .resolve_window_definition(window.definition, resolve_context)
+                    .await?,
+            });
+        }
+
         Ok(ast::SelectNode {
             distinct,
             projections,
@@ -804,6 +1004,7 @@ where
             where_expr,
             group_by,
             having,
+            windows,
         })
     }
 
@@ -1081,7 +1282,12 @@ where
 }
 
 /// Convert an AST datatype to an internal datatype.
-pub fn ast_datatype_to_exec_datatype(datatype: ast::DataType) -> Result<DataType> {
+///
+/// TIMESTAMPTZ resolves to a timestamp in the configured time zone.
+pub fn ast_datatype_to_exec_datatype(
+    datatype: ast::DataType,
+    config: &ResolveConfig,
+) -> Result<DataType> {
     Ok(match datatype {
         ast::DataType::Varchar(_) => DataType::utf8(),
         ast::DataType::Binary(_) => DataType::binary(),
@@ -1140,10 +1346,16 @@ pub fn ast_datatype_to_exec_datatype(datatype: ast::DataType) -> Result<DataType
         }
         ast::DataType::Bool => DataType::boolean(),
         ast::DataType::Date => DataType::date32(),
+        ast::DataType::Time => DataType::time(),
         ast::DataType::Timestamp => {
             // Microsecond matches postgres default.
             DataType::timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
         }
+        ast::DataType::TimestampTz => DataType::timestamp(TimestampTypeMeta::new_with_tz(
+            TimeUnit::Microsecond,
+            config.timezone.as_str(),
+        )),
         ast::DataType::Interval => DataType::interval(),
+        ast::DataType::Uuid => DataType::uuid(),
     })
 }
@@ -5,6 +5,7 @@ use glaredb_parser::ast::{self};
 use glaredb_proto::ProtoConv;
 use serde::{Deserialize, Serialize};
 
+use super::resolved_copy_to::ResolvedCopyTo;
 use super::resolved_cte::ResolvedCte;
 use super::resolved_function::ResolvedFunction;
 use super::resolved_table::{ResolvedTableOrCteReference, UnresolvedTableReference};
@@ -30,14 +31,15 @@ pub struct ResolveContext {
     pub table_functions:
         ResolveList<ResolvedTableFunctionReference, UnresolvedTableFunctionReference>,
 
-    // /// An optional COPY TO for the query.
-    // ///
-    // /// Currently this only supports a local COPY TO (the result needs to be
-    // /// local, the inner query can be local or remote). Extending this to
-    // /// support remote COPY TO should be straightforward, we just have to figure
-    // /// out what the "unbound" variant should be since it's not directly
-    // /// referenced by the user (maybe file format?).
-    // pub copy_to: Option<ResolvedCopyTo>,
+    /// An optional COPY TO for the query.
+    ///
+    /// Currently this only supports a local COPY TO (the result needs to be
+    /// local, the inner query can be local or remote). Extending this to
+    /// support remote COPY TO should be straightforward, we just have to figure
+    /// out what the "unbound" variant should be since it's not directly
+    /// referenced by the user (maybe file format?).
+    pub copy_to: Option<ResolvedCopyTo>,
+
     /// How "deep" in the plan are we.
     ///
     /// Incremented everytime we dive into a subquery.
@@ -58,6 +60,7 @@ impl ResolveContext {
             tables: ResolveList::empty(),
             functions: ResolveList::empty(),
             table_functions: ResolveList::empty(),
+            copy_to: None,
             current_depth: 0,
             ctes: Vec::new(),
         }
@@ -1,5 +1,4 @@
 use std::collections::HashMap;
-use std::sync::Arc;
 
 use glaredb_error::{DbError, Result};
 use glaredb_parser::ast;
@@ -12,26 +11,27 @@ use super::resolved_table::{
     ResolvedViewReference,
     UnresolvedTableReference,
 };
+use crate::catalog::Schema;
 use crate::catalog::context::{DatabaseContext, SYSTEM_CATALOG};
-use crate::catalog::database::Database;
-use crate::catalog::entry::{CatalogEntry, CatalogEntryInner, CatalogEntryType};
-use crate::catalog::memory::MemorySchema;
+use crate::catalog::entry::{CatalogEntryInner, CatalogEntryType, TableStorage};
 use crate::catalog::system::DEFAULT_SCHEMA;
-use crate::catalog::{Catalog, Schema};
 use crate::expr;
+use crate::functions::copy_to::CopyToFunctionSet;
 use crate::functions::function_set::TableFunctionSet;
 use crate::functions::table::TableFunctionInput;
 use crate::functions::table::scan::ScanContext;
 use crate::runtime::system::SystemRuntime;
+use crate::transaction::Transaction;
 
 pub fn create_user_facing_resolve_err(
-    schema_ent: Option<&MemorySchema>,
+    tx: &Transaction,
+    schema_ent: Option<&dyn Schema>,
     object_types: &[CatalogEntryType],
     name: &str,
 ) -> DbError {
     // Find similar function to include in error message.
     let similar = match schema_ent {
-        Some(schema_ent) => match schema_ent.find_similar_entry(object_types, name) {
+        Some(schema_ent) => match schema_ent.find_similar_entry(tx, object_types, name) {
             Ok(maybe_similar) => maybe_similar,
             Err(e) => {
                 // Error shouldn't happen, but if it does, it shouldn't be user-facing.
@@ -122,7 +122,7 @@ where
             .context
             .require_get_database(&catalog)?
             .catalog
-            .get_schema(&schema)?
+            .get_schema(self.context.transaction(), &schema)?
         {
             Some(ent) => ent,
             None => return Ok(None),
@@ -187,20 +187,44 @@ where
 
         let database = self.context.require_get_database(&catalog)?;
 
-        // Try reading from in-memory catalog first.
-        if let Some(entry) = self.resolve_from_memory_catalog(database, &schema, &table)? {
+        // Try reading from the database's catalog first.
+        if let Some(entry) = database
+            .catalog
+            .resolve_table_or_view(self.context.transaction(), &schema, &table)
+            .await?
+        {
             match &entry.entry {
                 CatalogEntryInner::Table(table_ent) => {
                     // Base table, get the table scan function and use that.
-                    //
-                    // Arguments are (catalog, schema, table)
-                    let inputs = TableFunctionInput {
-                        positional: vec![
-                            expr::lit(catalog.clone()).into(),
-                            expr::lit(schema.clone()).into(),
-                            expr::lit(table.clone()).into(),
-                        ],
-                        named: HashMap::new(),
+                    let inputs = match &table_ent.storage {
+                        TableStorage::Managed(_) => {
+                            // Arguments are (catalog, schema, table)
+                            TableFunctionInput {
+                                positional: vec![
+                                    expr::lit(catalog.clone()).into(),
+                                    expr::lit(schema.clone()).into(),
+                                    expr::lit(table.clone()).into(),
+                                ],
+                                named: HashMap::new(),
+                            }
+                        }
+                        TableStorage::External { location } => {
+                            // Argument is the location, with the options used
+                            // to attach the database passed as named arguments
+                            // (e.g. credentials for object storage).
+                            let named = match &database.attach_info {
+                                Some(info) => info
+                                    .options
+                                    .iter()
+                                    .map(|(k, v)| (k.clone(), expr::lit(v.clone()).into()))
+                                    .collect(),
+                                None => HashMap::new(),
+                            };
+                            TableFunctionInput {
+                                positional: vec![expr::lit(location.clone()).into()],
+                                named,
+                            }
+                        }
                     };
 
                     let scan_context = ScanContext {
@@ -262,7 +286,7 @@ where
             .context
             .require_get_database(SYSTEM_CATALOG)?
             .catalog
-            .require_get_schema(DEFAULT_SCHEMA)?;
+            .require_get_schema(self.context.transaction(), DEFAULT_SCHEMA)?;
 
         let ent = schema_ent
             .get_inferred_table_function(path)?
@@ -277,18 +301,49 @@ where
         Ok(func)
     }
 
-    fn resolve_from_memory_catalog(
+    /// Get the COPY TO function to use for the given format.
+    pub fn require_resolve_copy_to_function(
         &self,
-        database: &Database,
-        schema: &str,
-        table: &str,
-    ) -> Result<Option<Arc<CatalogEntry>>> {
-        let schema_ent = match database.catalog.get_schema(schema)? {
-            Some(ent) => ent,
-            None => return Ok(None),
-        };
+        format: &str,
+    ) -> Result<&'static CopyToFunctionSet> {
+        let schema_ent = self
+            .context
+            .require_get_database(SYSTEM_CATALOG)?
+            .catalog
+            .require_get_schema(self.context.transaction(), DEFAULT_SCHEMA)?;
+
+        let ent = schema_ent.get_copy_to_function(format)?.ok_or_else(|| {
+            create_user_facing_resolve_err(
+                self.context.transaction(),
+                Some(schema_ent.as_ref()),
+                &[CatalogEntryType::CopyToFunction],
+                format,
+            )
+        })?;
+
+        Ok(ent.try_as_copy_to_function_entry()?.function)
+    }
+
+    /// Try to find a COPY TO function that can handle writing to `path`.
+    pub fn require_resolve_copy_to_function_for_path(
+        &self,
+        path: &str,
+    ) -> Result<&'static CopyToFunctionSet> {
+        let schema_ent = self
+            .context
+            .require_get_database(SYSTEM_CATALOG)?
+            .catalog
+            .require_get_schema(self.context.transaction(), DEFAULT_SCHEMA)?;
+
+        let ent = schema_ent
+            .get_inferred_copy_to_function(path)?
+            .ok_or_else(|| {
+                DbError::new(format!(
+                    "Could not find a suitable COPY TO function to use for path '{path}', specify one with the FORMAT option"
+                ))
+            })?;
 
-        schema_ent.get_table_or_view(table)
+        Ok(ent.try_as_copy_to_function_entry()?.function)
     }
 
     pub async fn require_resolve_table_or_cte(
@@ -0,0 +1,13 @@
+use crate::functions::copy_to::{CopyToFunctionSet, CopyToOptions};
+use crate::runtime::filesystem::FileSystemWithState;
+
+/// A resolved COPY TO function along with the filesystem to write to.
+#[derive(Debug, Clone)]
+pub struct ResolvedCopyTo {
+    /// Function to use for encoding the output.
+    pub function: &'static CopyToFunctionSet,
+    /// Filesystem with loaded state to use for writing the file.
+    pub filesystem: FileSystemWithState,
+    /// Options provided in the COPY TO statement, excluding FORMAT.
+    pub options: CopyToOptions,
+}
@@ -1,4 +1,4 @@
-use crate::functions::function_set::{AggregateFunctionSet, ScalarFunctionSet};
+use crate::functions::function_set::{AggregateFunctionSet, ScalarFunctionSet, WindowFunctionSet};
 
 /// "Builtin" functions that require special handling.
 // TODO: This should be genericized into "rewrite rules" which ideally can be
@@ -43,11 +43,12 @@ impl SpecialBuiltinFunction {
     }
 }
 
-/// A resolved aggregate or scalar function.
+/// A resolved scalar, aggregate, or window function.
 #[derive(Debug, Clone)]
 pub enum ResolvedFunction {
     Scalar(&'static ScalarFunctionSet),
     Aggregate(&'static AggregateFunctionSet),
+    Window(&'static WindowFunctionSet),
     Special(SpecialBuiltinFunction),
 }
 
@@ -56,6 +57,7 @@ impl ResolvedFunction {
         match self {
             Self::Scalar(f) => f.name,
             Self::Aggregate(f) => f.name,
+            Self::Window(f) => f.name,
             Self::Special(f) => f.name(),
         }
     }
@@ -63,4 +65,8 @@ impl ResolvedFunction {
     pub fn is_aggregate(&self) -> bool {
         matches!(self, ResolvedFunction::Aggregate(_))
     }
+
+    pub fn is_window(&self) -> bool {
+        matches!(self, ResolvedFunction::Window(_))
+    }
 }
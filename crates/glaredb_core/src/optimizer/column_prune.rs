@@ -310,8 +310,16 @@ impl PruneState {
 
                 // Allow removal of this node if it's just projecting its inputs
                 // without changes, or it's not actually projecting anything.
-                let can_remove =
-                    proj_references.is_empty() || projection_is_passthrough(project, bind_context)?;
+                //
+                // Projections on top of materialization scans are kept as
+                // they give each scan of the materialization its own table
+                // ref. Removing them would cause self-joins on the
+                // materialization to reference the same table on both sides.
+                let can_remove = !matches!(
+                    project.get_one_child_exact()?,
+                    LogicalOperator::MaterializationScan(_)
+                ) && (proj_references.is_empty()
+                    || projection_is_passthrough(project, bind_context)?);
 
                 // Special case for if we can remove this projection.
                 if !self.implicit_reference && can_remove {
@@ -222,7 +222,11 @@ where
                                 continue;
                             }
 
-                            // TODO: Do we need to push to arbitrary here?
+                            // Comparison can't be split, needs to be evaluated
+                            // as an arbitrary expression.
+                            extracted
+                                .arbitrary
+                                .push(Expression::Comparison(ComparisonExpr { left, right, op }));
                         }
                         other => {
                             extracted.arbitrary.push(other);
@@ -230,8 +234,11 @@ where
                     }
                 }
                 ExprJoinSide::Right => {
-                    if matches!(self.join_type, JoinType::Left | JoinType::Inner) {
-                        // Filter right input into LEFT join.
+                    if matches!(
+                        self.join_type,
+                        JoinType::Left | JoinType::Inner | JoinType::LeftSemi | JoinType::LeftAnti
+                    ) {
+                        // Filter right input into LEFT/SEMI/ANTI join.
                         extracted.right_filter.push(expr);
                     } else {
                         extracted.arbitrary.push(expr);
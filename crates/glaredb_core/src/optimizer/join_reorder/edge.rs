@@ -6,7 +6,9 @@ use glaredb_error::{DbError, Result};
 
 use super::ReorderableCondition;
 use super::graph::{BaseRelation, RelId, RelationSet};
+use super::statistics::{expression_column, find_column_statistics};
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode};
+use crate::expr::Expression;
 use crate::expr::column_expr::ColumnReference;
 use crate::expr::comparison_expr::ComparisonOperator;
 use crate::logical::binder::table_list::TableRef;
@@ -217,6 +219,17 @@ impl HyperEdges {
         self.0.push(hyp);
     }
 
+    /// Get the number of distinct values for a column expression from column
+    /// statistics, capped by the cardinality of the relation it's from.
+    fn column_ndv(expr: &Expression, base_relations: &HashMap<RelId, BaseRelation>) -> Option<f64> {
+        let column = expression_column(expr)?;
+        base_relations.values().find_map(|rel| {
+            let stats = find_column_statistics(&rel.operator, column)?;
+            let ndv = *stats.statistics.num_distinct.value()?;
+            Some(f64::min(ndv as f64, rel.cardinality))
+        })
+    }
+
     fn insert_condition_as_edge(
         &mut self,
         condition: ReorderableCondition,
@@ -248,6 +261,20 @@ impl HyperEdges {
             }
         }
 
+        // Use column statistics if we have them for both sides of the
+        // condition.
+        //
+        // Assumes the values on the side with fewer distinct values are
+        // contained in the other side, making the larger NDV the one that
+        // determines selectivity.
+        if let ReorderableCondition::Inner { condition } = &condition {
+            let left_ndv = Self::column_ndv(&condition.left, base_relations);
+            let right_ndv = Self::column_ndv(&condition.right, base_relations);
+            if let (Some(left_ndv), Some(right_ndv)) = (left_ndv, right_ndv) {
+                min_ndv = f64::max(left_ndv, right_ndv);
+            }
+        }
+
         // We have the "local" min_ndv, check existing hyper edges to see if
         // it can be added to one.
 
@@ -10,9 +10,15 @@
 //! relations and are not considered to be edges.
 //!
 //! Join conditions are converted into "hyper edges" which contains a min NDV (Num
-//! Distinct Values). This NDV is initialized to be the min cardinality of all base
-//! relations connected by the hyper edge. The min NDV is used when computing the
-//! estimated output cardinality of a join.
+//! Distinct Values). When column statistics are available for both sides of a
+//! condition, the NDV for the condition is the larger of the two column NDVs
+//! (each capped by its relation's cardinality). Otherwise it falls back to the
+//! min cardinality of the base relations connected by the condition. The min NDV
+//! across all conditions in the hyper edge is used when computing the estimated
+//! output cardinality of a join.
+//!
+//! Filters on base relations use column statistics to estimate selectivity when
+//! available.
 //!
 //! Estimating the cardinality is done by dividing the product of all base relation
 //! cardinalities taking part in the join by the a selectivity denominator. The
@@ -29,9 +35,15 @@ use glaredb_error::{DbError, Result};
 
 use super::ReorderableCondition;
 use super::edge::{EdgeId, EdgeType, HyperEdges, NeighborEdge};
-use super::statistics::propagate_estimated_cardinality;
+use super::statistics::{
+    ColumnStatisticsRef,
+    estimate_selectivity,
+    find_column_statistics,
+    propagate_estimated_cardinality,
+};
 use super::subgraph::Subgraph;
 use crate::expr::Expression;
+use crate::expr::column_expr::ColumnReference;
 use crate::expr::conjunction_expr::{ConjunctionExpr, ConjunctionOperator};
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::table_list::TableRef;
@@ -44,7 +56,6 @@ use crate::logical::logical_join::{
 };
 use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
 use crate::optimizer::filter_pushdown::extracted_filter::ExtractedFilter;
-use crate::statistics::assumptions::DEFAULT_SELECTIVITY;
 use crate::statistics::value::StatisticsValue;
 use crate::util::fmt::displayable::IntoDisplayableSlice;
 
@@ -559,8 +570,9 @@ impl Graph {
         //
         // We cannot assume that the filter is filtering on the same attributes
         // we're joining on, so avoid updating the denominator.
-        for _ in 0..left_filters.len() + right_filters.len() {
-            subgraph.numerator *= DEFAULT_SELECTIVITY;
+        for (_, filter) in left_filters.iter().chain(&right_filters) {
+            subgraph.numerator *=
+                estimate_selectivity(&filter.filter, &|col| self.find_column_statistics(col));
         }
 
         // Update denominator based on edges used for this join.
@@ -634,6 +646,13 @@ impl Graph {
         Ok(())
     }
 
+    /// Find statistics for a column from any of the base relations.
+    fn find_column_statistics(&self, column: ColumnReference) -> Option<ColumnStatisticsRef<'_>> {
+        self.base_relations
+            .values()
+            .find_map(|rel| find_column_statistics(&rel.operator, column))
+    }
+
     /// Find filters that apply fully to the given plan.
     fn find_filters(&self, node: &JoinNode) -> Vec<(&FilterId, &ExtractedFilter)> {
         self.filters
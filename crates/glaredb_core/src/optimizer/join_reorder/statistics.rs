@@ -1,10 +1,20 @@
 use glaredb_error::Result;
 
+use crate::expr::Expression;
+use crate::expr::column_expr::ColumnReference;
+use crate::expr::comparison_expr::ComparisonOperator;
+use crate::expr::conjunction_expr::ConjunctionOperator;
+use crate::expr::is_expr::IsOperator;
 use crate::logical::logical_aggregate::LogicalAggregate;
 use crate::logical::logical_filter::LogicalFilter;
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::operator::{LogicalOperator, Node};
-use crate::statistics::assumptions::DEFAULT_SELECTIVITY;
+use crate::statistics::assumptions::{
+    DEFAULT_SELECTIVITY,
+    EQUALITY_SELECTIVITY,
+    INEQUALITY_SELECTIVITY,
+};
+use crate::statistics::column::{ColumnStatistics, scalar_as_f64};
 use crate::statistics::value::StatisticsValue;
 
 /// Propagates estimated cardinalities from the bottom up.
@@ -31,10 +41,12 @@ fn propagate_filter(op: &mut Node<LogicalFilter>) -> Result<()> {
     let child = op.get_nth_child_mut(0)?;
     propagate_estimated_cardinality(child)?;
 
+    let child = op.get_nth_child(0)?;
     let estimated = match child.estimated_cardinality().value() {
         Some(v) => {
-            // TODO: Use more specific selectivities if we can.
-            let est = (*v as f64) * DEFAULT_SELECTIVITY;
+            let selectivity =
+                estimate_selectivity(&op.node.filter, &|col| find_column_statistics(child, col));
+            let est = (*v as f64) * selectivity;
             StatisticsValue::Estimated(est as usize)
         }
         None => StatisticsValue::Unknown,
@@ -56,10 +68,19 @@ fn propagate_aggregate(op: &mut Node<LogicalAggregate>) -> Result<()> {
     } else {
         let estimated = match child_card.value() {
             Some(v) => {
-                // TODO: Do something a bit more reasonable. We should be
-                // looking at the columns in the group expressions and checking
-                // distinc values.
-                let est = (*v as f64) * DEFAULT_SELECTIVITY;
+                // Number of groups is at most the product of distinct values
+                // for each group column. Fall back to a default selectivity if
+                // we're missing statistics for any of the columns.
+                let child = op.get_nth_child(0)?;
+                let groups = op.node.group_exprs.iter().try_fold(1.0, |acc, expr| {
+                    let stats = find_column_statistics(child, expression_column(expr)?)?;
+                    let ndv = *stats.statistics.num_distinct.value()?;
+                    Some(acc * ndv as f64)
+                });
+                let est = match groups {
+                    Some(groups) => f64::min(groups, *v as f64),
+                    None => (*v as f64) * DEFAULT_SELECTIVITY,
+                };
                 StatisticsValue::Estimated(est as usize)
             }
             None => StatisticsValue::Unknown,
@@ -70,3 +91,254 @@ fn propagate_aggregate(op: &mut Node<LogicalAggregate>) -> Result<()> {
 
     Ok(())
 }
+
+/// Statistics for a column along with the estimated cardinality of the table
+/// the column is from.
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnStatisticsRef<'a> {
+    pub statistics: &'a ColumnStatistics,
+    pub table_cardinality: usize,
+}
+
+impl ColumnStatisticsRef<'_> {
+    /// Fraction of rows in the table that are not null.
+    fn non_null_fraction(&self) -> f64 {
+        match self.statistics.null_count.value() {
+            Some(&nulls) if self.table_cardinality > 0 => {
+                1.0 - f64::min(nulls as f64 / self.table_cardinality as f64, 1.0)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Estimate the fraction of non-null values less than `value` using the
+    /// column's min and max, assuming values are uniformly distributed.
+    fn fraction_below(&self, value: &Expression) -> Option<f64> {
+        let value = match value {
+            Expression::Literal(lit) => scalar_as_f64(&lit.0)?,
+            _ => return None,
+        };
+        let min = scalar_as_f64(self.statistics.min.value()?)?;
+        let max = scalar_as_f64(self.statistics.max.value()?)?;
+
+        if max <= min {
+            return None;
+        }
+
+        Some(((value - min) / (max - min)).clamp(0.0, 1.0))
+    }
+}
+
+/// Find statistics for a column in the output of an operator.
+///
+/// Looks through filters and projections to find the scan producing the
+/// column.
+pub fn find_column_statistics(
+    op: &LogicalOperator,
+    column: ColumnReference,
+) -> Option<ColumnStatisticsRef<'_>> {
+    match op {
+        LogicalOperator::Scan(scan) => {
+            if scan.node.data_scan.table_ref != column.table_scope {
+                return None;
+            }
+            let idx = *scan.node.data_scan.projection.get(column.column)?;
+            let statistics = scan.node.source.column_statistics().get(idx)?;
+            let table_cardinality = *scan.node.source.cardinality().value()?;
+
+            Some(ColumnStatisticsRef {
+                statistics,
+                table_cardinality,
+            })
+        }
+        LogicalOperator::Filter(filter) => find_column_statistics(filter.children.first()?, column),
+        LogicalOperator::Project(project) => {
+            if project.node.projection_table != column.table_scope {
+                return None;
+            }
+            match project.node.projections.get(column.column)? {
+                Expression::Column(col) => {
+                    find_column_statistics(project.children.first()?, col.reference)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Get the column an expression is referencing, looking through casts.
+pub fn expression_column(expr: &Expression) -> Option<ColumnReference> {
+    match expr {
+        Expression::Column(col) => Some(col.reference),
+        Expression::Cast(cast) => expression_column(&cast.expr),
+        _ => None,
+    }
+}
+
+/// Estimate the fraction of rows that pass a filter.
+///
+/// `lookup` is used to find statistics for columns referenced in the filter.
+/// Falls back to our assumed selectivities when statistics aren't available.
+pub fn estimate_selectivity<'a, F>(filter: &Expression, lookup: &F) -> f64
+where
+    F: Fn(ColumnReference) -> Option<ColumnStatisticsRef<'a>>,
+{
+    match filter {
+        Expression::Conjunction(conj) => {
+            let selectivities = conj
+                .expressions
+                .iter()
+                .map(|expr| estimate_selectivity(expr, lookup));
+            match conj.op {
+                // Assume the filters are independent.
+                ConjunctionOperator::And => selectivities.product(),
+                ConjunctionOperator::Or => selectivities.fold(0.0, |acc, s| acc + s - acc * s),
+            }
+        }
+        Expression::Comparison(cmp) => {
+            // Normalize to `column <op> value`.
+            let (column, op, value) =
+                match (expression_column(&cmp.left), expression_column(&cmp.right)) {
+                    (Some(column), None) => (column, cmp.op, cmp.right.as_ref()),
+                    (None, Some(column)) => (column, cmp.op.flip(), cmp.left.as_ref()),
+                    _ => return default_comparison_selectivity(cmp.op),
+                };
+            let stats = match lookup(column) {
+                Some(stats) => stats,
+                None => return default_comparison_selectivity(cmp.op),
+            };
+
+            let selectivity = match op {
+                ComparisonOperator::Eq | ComparisonOperator::IsNotDistinctFrom => {
+                    match stats.statistics.num_distinct.value() {
+                        Some(&ndv) if ndv > 0 => 1.0 / ndv as f64,
+                        _ => EQUALITY_SELECTIVITY,
+                    }
+                }
+                ComparisonOperator::NotEq | ComparisonOperator::IsDistinctFrom => {
+                    match stats.statistics.num_distinct.value() {
+                        Some(&ndv) if ndv > 0 => 1.0 - 1.0 / ndv as f64,
+                        _ => 1.0 - EQUALITY_SELECTIVITY,
+                    }
+                }
+                ComparisonOperator::Lt | ComparisonOperator::LtEq => stats
+                    .fraction_below(value)
+                    .unwrap_or(INEQUALITY_SELECTIVITY),
+                ComparisonOperator::Gt | ComparisonOperator::GtEq => stats
+                    .fraction_below(value)
+                    .map(|frac| 1.0 - frac)
+                    .unwrap_or(INEQUALITY_SELECTIVITY),
+            };
+
+            selectivity * stats.non_null_fraction()
+        }
+        Expression::Between(between) => {
+            let stats = match expression_column(&between.input).and_then(lookup) {
+                Some(stats) => stats,
+                None => return INEQUALITY_SELECTIVITY,
+            };
+            match (
+                stats.fraction_below(&between.lower),
+                stats.fraction_below(&between.upper),
+            ) {
+                (Some(lower), Some(upper)) => {
+                    f64::max(upper - lower, 0.0) * stats.non_null_fraction()
+                }
+                _ => INEQUALITY_SELECTIVITY,
+            }
+        }
+        Expression::Is(is) => {
+            let stats = match expression_column(&is.input).and_then(lookup) {
+                Some(stats) => stats,
+                None => return DEFAULT_SELECTIVITY,
+            };
+            match is.op {
+                IsOperator::IsNull => 1.0 - stats.non_null_fraction(),
+                IsOperator::IsNotNull => stats.non_null_fraction(),
+                IsOperator::IsTrue | IsOperator::IsFalse => DEFAULT_SELECTIVITY,
+            }
+        }
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+fn default_comparison_selectivity(op: ComparisonOperator) -> f64 {
+    match op {
+        ComparisonOperator::Eq | ComparisonOperator::IsNotDistinctFrom => EQUALITY_SELECTIVITY,
+        _ => INEQUALITY_SELECTIVITY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::expr::is_expr::IsExpr;
+    use crate::expr::{self, column, lit};
+
+    fn test_statistics() -> ColumnStatistics {
+        // 100 rows, 10 nulls, 50 distinct values in [0, 100].
+        ColumnStatistics {
+            num_distinct: StatisticsValue::Estimated(50),
+            null_count: StatisticsValue::Estimated(10),
+            min: StatisticsValue::Estimated(0_i32.into()),
+            max: StatisticsValue::Estimated(100_i32.into()),
+        }
+    }
+
+    fn assert_approx_eq(expected: f64, got: f64) {
+        assert!(
+            (expected - got).abs() < 1e-9,
+            "expected: {expected}, got: {got}"
+        );
+    }
+
+    #[test]
+    fn selectivity_with_statistics() {
+        let stats = test_statistics();
+        let lookup = |col: ColumnReference| {
+            (col == ColumnReference::from((0, 0))).then_some(ColumnStatisticsRef {
+                statistics: &stats,
+                table_cardinality: 100,
+            })
+        };
+        let c0 = column((0, 0), DataType::int32());
+
+        let eq: Expression = expr::eq(c0.clone(), lit(5)).unwrap().into();
+        assert_approx_eq(0.9 / 50.0, estimate_selectivity(&eq, &lookup));
+
+        let lt: Expression = expr::lt(c0.clone(), lit(25)).unwrap().into();
+        assert_approx_eq(0.25 * 0.9, estimate_selectivity(&lt, &lookup));
+
+        // Literal on the left, '25 < c0'
+        let flipped: Expression = expr::lt(lit(25), c0.clone()).unwrap().into();
+        assert_approx_eq(0.75 * 0.9, estimate_selectivity(&flipped, &lookup));
+
+        let is_null = Expression::Is(IsExpr {
+            op: IsOperator::IsNull,
+            input: Box::new(c0),
+        });
+        assert_approx_eq(0.1, estimate_selectivity(&is_null, &lookup));
+    }
+
+    #[test]
+    fn selectivity_without_statistics() {
+        let lookup = |_col: ColumnReference| None;
+        let c0 = column((0, 0), DataType::int32());
+
+        let eq: Expression = expr::eq(c0.clone(), lit(5)).unwrap().into();
+        assert_approx_eq(EQUALITY_SELECTIVITY, estimate_selectivity(&eq, &lookup));
+
+        let both: Expression = expr::and([
+            expr::eq(c0.clone(), lit(5)).unwrap().into(),
+            expr::gt(c0, lit(5)).unwrap().into(),
+        ])
+        .unwrap()
+        .into();
+        assert_approx_eq(
+            EQUALITY_SELECTIVITY * INEQUALITY_SELECTIVITY,
+            estimate_selectivity(&both, &lookup),
+        );
+    }
+}
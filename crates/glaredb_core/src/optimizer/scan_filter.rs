@@ -37,7 +37,14 @@ fn optimize_inner(plan: &mut LogicalOperator) -> Result<()> {
     if let LogicalOperator::Filter(filter) = plan {
         debug_assert_eq!(1, filter.children.len());
         if let LogicalOperator::Scan(scan) = &mut filter.children[0] {
-            // TODO: Apply to metadata filters.
+            // TODO: Apply to metadata filters. Only filters referencing data
+            // columns can be pushed for now.
+            let data_table_ref = scan.node.data_scan.table_ref;
+            let only_data_columns = |expr: &Expression| {
+                expr.get_column_references()
+                    .iter()
+                    .all(|col| col.table_scope == data_table_ref)
+            };
 
             // Clone only epxressions that we know we can easily handle, at
             // least for now.
@@ -51,7 +58,7 @@ fn optimize_inner(plan: &mut LogicalOperator) -> Result<()> {
                     .iter()
                     .filter_map(|expr| {
                         let column_refs = expr.get_column_references();
-                        if column_refs.len() != 1 {
+                        if column_refs.len() != 1 || column_refs[0].table_scope != data_table_ref {
                             return None;
                         }
 
@@ -75,9 +82,10 @@ fn optimize_inner(plan: &mut LogicalOperator) -> Result<()> {
                         }
                     })
                     .collect(),
-                other => vec![ScanFilter {
+                other if only_data_columns(other) => vec![ScanFilter {
                     expression: other.clone(),
                 }],
+                _ => Vec::new(),
             };
 
             scan.node.data_scan.scan_filters.append(&mut filters);
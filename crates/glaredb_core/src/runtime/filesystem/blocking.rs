@@ -0,0 +1,83 @@
+//! Helpers for performing file operations from synchronous code.
+//!
+//! These can only be used with synchronous filesystems (e.g. the local
+//! filesystem) where every operation completes the first time it's polled.
+//! Operations are polled exactly once, returning an error instead of waiting
+//! if they don't complete.
+
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use glaredb_error::{DbError, Result};
+
+use super::{AnyFile, FileSystemWithState};
+
+/// Checks that the filesystem can be used with the blocking helpers.
+pub fn check_synchronous(filesystem: &FileSystemWithState) -> Result<()> {
+    if filesystem.fs.synchronous {
+        return Ok(());
+    }
+
+    Err(DbError::new(format!(
+        "Filesystem '{}' does not support synchronous operations",
+        filesystem.fs.name
+    )))
+}
+
+/// Complete a future on the current thread.
+///
+/// Errors if the future doesn't complete immediately.
+pub fn complete<T>(fut: impl Future<Output = Result<T>>) -> Result<T> {
+    let mut fut = pin!(fut);
+    poll_complete(|cx| fut.as_mut().poll(cx))
+}
+
+/// Poll the function once, erroring if it's not ready.
+pub fn poll_complete<T>(f: impl FnOnce(&mut Context) -> Poll<Result<T>>) -> Result<T> {
+    let mut cx = Context::from_waker(Waker::noop());
+    match f(&mut cx) {
+        Poll::Ready(v) => v,
+        Poll::Pending => Err(DbError::new(
+            "File operation did not complete synchronously",
+        )),
+    }
+}
+
+/// Write the entire buffer to the file.
+pub fn write_all(file: &mut AnyFile, mut buf: &[u8]) -> Result<()> {
+    while !buf.is_empty() {
+        let n = poll_complete(|cx| file.call_poll_write(cx, buf))?;
+        if n == 0 {
+            return Err(DbError::new("Failed to write to file")
+                .with_field("path", file.call_path().to_string()));
+        }
+        buf = &buf[n..];
+    }
+    Ok(())
+}
+
+/// Flush the file.
+pub fn flush(file: &mut AnyFile) -> Result<()> {
+    poll_complete(|cx| file.call_poll_flush(cx))
+}
+
+/// Flush the file and sync written data to durable storage.
+pub fn sync(file: &mut AnyFile) -> Result<()> {
+    poll_complete(|cx| file.call_poll_sync(cx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_ready() {
+        let v = complete(async { Ok(4) }).unwrap();
+        assert_eq!(4, v);
+    }
+
+    #[test]
+    fn complete_pending_errors() {
+        complete(std::future::pending::<Result<()>>()).unwrap_err();
+    }
+}
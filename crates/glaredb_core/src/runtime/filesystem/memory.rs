@@ -3,26 +3,33 @@ use std::path::{Component, Path};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
-use glaredb_error::{DbError, Result, not_implemented};
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
 
 use super::directory::DirHandleNotImplemented;
 use super::{FileHandle, FileOpenContext, FileStat, FileSystem, FileType, OpenFlags};
 use crate::buffer::buffer_manager::{AsRawBufferManager, RawBufferManager};
 use crate::buffer::db_vec::DbVec;
 
-#[derive(Debug)]
+/// Buffer for a single file, shared between all open handles.
+type FileBuffer = Arc<Mutex<DbVec<u8>>>;
+
+/// Filesystem with flat files stored in memory.
+///
+/// Clones share the same set of files.
+#[derive(Debug, Clone)]
 pub struct MemoryFileSystem {
     /// Manager to use for allocating file buffers.
     buffer_manager: RawBufferManager,
     /// Simple mapping of a flat name to byte buffer.
-    files: scc::HashMap<String, Arc<DbVec<u8>>>,
+    files: Arc<scc::HashMap<String, FileBuffer>>,
 }
 
 impl MemoryFileSystem {
     pub fn new(buffer_manager: &impl AsRawBufferManager) -> Self {
         MemoryFileSystem {
             buffer_manager: buffer_manager.as_raw_buffer_manager(),
-            files: scc::HashMap::new(),
+            files: Arc::new(scc::HashMap::new()),
         }
     }
 
@@ -31,14 +38,20 @@ impl MemoryFileSystem {
     // This is mostly useful for testing right now.
     pub fn insert(&self, path: impl Into<String>, file: impl AsRef<[u8]>) -> Result<()> {
         let bs = DbVec::new_from_slice(&self.buffer_manager, file)?;
-        self.files.upsert(path.into(), Arc::new(bs));
+        self.files.upsert(path.into(), Arc::new(Mutex::new(bs)));
 
         Ok(())
     }
+
+    /// Returns the number of files currently in the filesystem.
+    pub fn num_files(&self) -> usize {
+        self.files.len()
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
     const NAME: &str = "Memory";
+    const SYNCHRONOUS: bool = true;
 
     type FileHandle = MemoryFileHandle;
     type ReadDirHandle = DirHandleNotImplemented;
@@ -49,23 +62,28 @@ impl FileSystem for MemoryFileSystem {
     }
 
     async fn open(&self, flags: OpenFlags, path: &str, _state: &()) -> Result<Self::FileHandle> {
-        if flags.is_write() {
-            not_implemented!("write support for memory filesystem")
-        }
-        if flags.is_create() {
-            not_implemented!("create support for memory filesystem")
-        }
-
         let path = get_normalized_file_name(Path::new(path))?;
-        let buffer = self
-            .files
-            .get(path)
-            .map(|ent| ent.clone())
-            .ok_or_else(|| DbError::new(format!("Cannot find file '{path}'")))?;
+        let buffer = if flags.is_create() {
+            self.files
+                .entry(path.to_string())
+                .or_insert_with(|| Arc::new(Mutex::new(DbVec::empty(&self.buffer_manager))))
+                .get()
+                .clone()
+        } else {
+            self.files
+                .get(path)
+                .map(|ent| ent.clone())
+                .ok_or_else(|| DbError::new(format!("Cannot find file '{path}'")))?
+        };
+
+        if flags.is_truncate() {
+            *buffer.lock() = DbVec::empty(&self.buffer_manager);
+        }
 
         Ok(MemoryFileHandle {
             path: path.to_string(),
             pos: 0,
+            writable: flags.is_write(),
             buffer,
         })
     }
@@ -81,6 +99,14 @@ impl FileSystem for MemoryFileSystem {
         }))
     }
 
+    async fn delete(&self, path: &str, _state: &()) -> Result<()> {
+        let path = get_normalized_file_name(Path::new(path))?;
+        match self.files.remove(path) {
+            Some(_) => Ok(()),
+            None => Err(DbError::new(format!("Cannot find file '{path}'"))),
+        }
+    }
+
     fn can_handle_path(&self, path: &str) -> bool {
         let path = Path::new(path);
         // TODO: Have separate function that doesn't return error.
@@ -92,7 +118,9 @@ impl FileSystem for MemoryFileSystem {
 pub struct MemoryFileHandle {
     path: String,
     pos: usize,
-    buffer: Arc<DbVec<u8>>,
+    /// If this handle was opened for writing.
+    writable: bool,
+    buffer: FileBuffer,
 }
 
 impl MemoryFileHandle {
@@ -109,9 +137,51 @@ impl MemoryFileHandle {
         Ok(MemoryFileHandle {
             path: String::new(),
             pos: 0,
-            buffer: Arc::new(buffer),
+            writable: false,
+            buffer: Arc::new(Mutex::new(buffer)),
         })
     }
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if !self.writable {
+            return Err(DbError::new("Memory file not opened for writing")
+                .with_field("path", self.path.clone()));
+        }
+
+        let mut buffer = self.buffer.lock();
+        let len = buffer.len();
+        if self.pos > len {
+            return Err(DbError::new("Cannot write past the end of a memory file")
+                .with_field("pos", self.pos)
+                .with_field("len", len));
+        }
+
+        // Overwrite existing bytes, then append the rest.
+        let overlap = usize::min(buf.len(), len - self.pos);
+        buffer.as_slice_mut()[self.pos..(self.pos + overlap)].copy_from_slice(&buf[..overlap]);
+        buffer.push_slice(&buf[overlap..])?;
+
+        self.pos += buf.len();
+
+        Ok(buf.len())
+    }
+
+    fn seek(&mut self, seek: SeekFrom) -> Result<()> {
+        let (base, offset) = match seek {
+            SeekFrom::Start(pos) => {
+                self.pos = pos as usize;
+                return Ok(());
+            }
+            SeekFrom::End(offset) => (self.buffer.lock().len(), offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        self.pos = base
+            .checked_add_signed(offset as isize)
+            .ok_or_else(|| DbError::new("Cannot seek to a negative position"))?;
+
+        Ok(())
+    }
 }
 
 impl FileHandle for MemoryFileHandle {
@@ -120,14 +190,18 @@ impl FileHandle for MemoryFileHandle {
     }
 
     fn size(&self) -> usize {
-        self.buffer.len()
+        self.buffer.lock().len()
     }
 
     fn poll_read(&mut self, _cx: &mut Context, buf: &mut [u8]) -> Poll<Result<usize>> {
-        let rem = self.buffer.len() - self.pos;
+        let buffer = self.buffer.lock();
+        let rem = buffer.len().saturating_sub(self.pos);
         let count = usize::min(buf.len(), rem);
+        if count == 0 {
+            return Poll::Ready(Ok(0));
+        }
 
-        let slice = self.buffer.as_slice();
+        let slice = buffer.as_slice();
         let src = &slice[self.pos..(self.pos + count)];
         let dest = &mut buf[..count];
         dest.copy_from_slice(src);
@@ -137,20 +211,17 @@ impl FileHandle for MemoryFileHandle {
         Poll::Ready(Ok(count))
     }
 
-    fn poll_write(&mut self, _cx: &mut Context, _buf: &[u8]) -> Poll<Result<usize>> {
-        Poll::Ready(Err(DbError::new(
-            "Write unsupported for memory file handle",
-        ))) // For now
+    fn poll_write(&mut self, _cx: &mut Context, buf: &[u8]) -> Poll<Result<usize>> {
+        Poll::Ready(self.write(buf))
     }
 
-    fn poll_seek(&mut self, _cx: &mut Context, _seek: SeekFrom) -> Poll<Result<()>> {
-        Poll::Ready(Err(DbError::new("Seek unsupported for memory file handle"))) // For now
+    fn poll_seek(&mut self, _cx: &mut Context, seek: SeekFrom) -> Poll<Result<()>> {
+        Poll::Ready(self.seek(seek))
     }
 
     fn poll_flush(&mut self, _cx: &mut Context) -> Poll<Result<()>> {
-        Poll::Ready(Err(DbError::new(
-            "Flush unsupported for memory file handle",
-        ))) // For now
+        // Nothing to flush, writes go directly to the buffer.
+        Poll::Ready(Ok(()))
     }
 }
 
@@ -202,6 +273,7 @@ mod tests {
 
     use super::*;
     use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::util::future::block_on;
     use crate::util::task::noop_context;
 
     #[test]
@@ -269,6 +341,63 @@ mod tests {
         assert_eq!(Poll::Ready(0), poll);
     }
 
+    #[test]
+    fn memory_file_write_seek_read() {
+        let fs = MemoryFileSystem::new(&DefaultBufferManager);
+        let flags = OpenFlags::new([OpenFlags::READ, OpenFlags::WRITE, OpenFlags::CREATE]);
+        let mut handle = block_on(fs.open(flags, "/file", &())).unwrap();
+        assert_eq!(1, fs.num_files());
+
+        let poll = handle
+            .poll_write(&mut noop_context(), b"hello")
+            .map(|r| r.unwrap());
+        assert_eq!(Poll::Ready(5), poll);
+
+        // Overwrite the tail and extend.
+        let poll = handle
+            .poll_seek(&mut noop_context(), SeekFrom::Current(-2))
+            .map(|r| r.unwrap());
+        assert_eq!(Poll::Ready(()), poll);
+        let poll = handle
+            .poll_write(&mut noop_context(), b"p!!")
+            .map(|r| r.unwrap());
+        assert_eq!(Poll::Ready(3), poll);
+        assert_eq!(6, handle.size());
+
+        let poll = handle
+            .poll_seek(&mut noop_context(), SeekFrom::Start(0))
+            .map(|r| r.unwrap());
+        assert_eq!(Poll::Ready(()), poll);
+
+        let mut out = vec![0; 10];
+        let poll = handle
+            .poll_read(&mut noop_context(), &mut out)
+            .map(|r| r.unwrap());
+        assert_eq!(Poll::Ready(6), poll);
+        assert_eq!(b"help!!", &out[0..6]);
+
+        // Opening without truncate keeps the contents.
+        let handle = block_on(fs.open(OpenFlags::new([OpenFlags::READ]), "file", &())).unwrap();
+        assert_eq!(6, handle.size());
+
+        let flags = OpenFlags::new([OpenFlags::WRITE, OpenFlags::CREATE, OpenFlags::TRUNCATE]);
+        let handle = block_on(fs.open(flags, "file", &())).unwrap();
+        assert_eq!(0, handle.size());
+
+        block_on(fs.delete("file", &())).unwrap();
+        assert_eq!(0, fs.num_files());
+    }
+
+    #[test]
+    fn memory_file_write_read_only() {
+        let fs = MemoryFileSystem::new(&DefaultBufferManager);
+        fs.insert("file", b"hello").unwrap();
+
+        let mut handle = block_on(fs.open(OpenFlags::new([OpenFlags::READ]), "file", &())).unwrap();
+        let poll = handle.poll_write(&mut noop_context(), b"world");
+        assert!(matches!(poll, Poll::Ready(Err(_))));
+    }
+
     // #[test]
     // fn memory_list_prefix() {
     //     let fs = MemoryFileSystem::new(&DefaultBufferManager);
@@ -1,3 +1,4 @@
+pub mod blocking;
 pub mod directory;
 pub mod dispatch;
 pub mod file_ext;
@@ -48,6 +49,13 @@ pub trait FileHandle: Debug + Sync + Send + 'static {
 
     /// Flush the file to disk (or persistent storage).
     fn poll_flush(&mut self, cx: &mut Context) -> Poll<Result<()>>;
+
+    /// Flush the file and ensure written data is durable (e.g. `fsync`).
+    ///
+    /// Defaults to flushing for files where a flush is already durable.
+    fn poll_sync(&mut self, cx: &mut Context) -> Poll<Result<()>> {
+        self.poll_flush(cx)
+    }
 }
 
 #[derive(Debug)]
@@ -84,6 +92,18 @@ impl AnyFile {
         (self.vtable.poll_seek_fn)(self.file.as_mut(), cx, seek)
     }
 
+    pub fn call_poll_write(&mut self, cx: &mut Context, buf: &[u8]) -> Poll<Result<usize>> {
+        (self.vtable.poll_write_fn)(self.file.as_mut(), cx, buf)
+    }
+
+    pub fn call_poll_flush(&mut self, cx: &mut Context) -> Poll<Result<()>> {
+        (self.vtable.poll_flush_fn)(self.file.as_mut(), cx)
+    }
+
+    pub fn call_poll_sync(&mut self, cx: &mut Context) -> Poll<Result<()>> {
+        (self.vtable.poll_sync_fn)(self.file.as_mut(), cx)
+    }
+
     pub fn call_read<'a>(&'a mut self, buf: &'a mut [u8]) -> FileSystemFuture<'a, Result<usize>> {
         (self.vtable.read_fn)(self.file.as_mut(), buf)
     }
@@ -114,6 +134,9 @@ pub(crate) struct RawFileVTable {
     size_fn: fn(&dyn Any) -> usize,
     poll_read_fn: fn(&mut dyn Any, cx: &mut Context, buf: &mut [u8]) -> Poll<Result<usize>>,
     poll_seek_fn: fn(&mut dyn Any, cx: &mut Context, seek: io::SeekFrom) -> Poll<Result<()>>,
+    poll_write_fn: fn(&mut dyn Any, cx: &mut Context, buf: &[u8]) -> Poll<Result<usize>>,
+    poll_flush_fn: fn(&mut dyn Any, cx: &mut Context) -> Poll<Result<()>>,
+    poll_sync_fn: fn(&mut dyn Any, cx: &mut Context) -> Poll<Result<()>>,
     read_fn: for<'a> fn(&'a mut dyn Any, buf: &'a mut [u8]) -> FileSystemFuture<'a, Result<usize>>,
     read_fill_fn:
         for<'a> fn(&'a mut dyn Any, buf: &'a mut [u8]) -> FileSystemFuture<'a, Result<usize>>,
@@ -151,6 +174,21 @@ where
             file.poll_seek(cx, seek)
         },
 
+        poll_write_fn: |file, cx, buf| {
+            let file = file.downcast_mut::<Self>().unwrap();
+            file.poll_write(cx, buf)
+        },
+
+        poll_flush_fn: |file, cx| {
+            let file = file.downcast_mut::<Self>().unwrap();
+            file.poll_flush(cx)
+        },
+
+        poll_sync_fn: |file, cx| {
+            let file = file.downcast_mut::<Self>().unwrap();
+            file.poll_sync(cx)
+        },
+
         read_fn: |file, buf| {
             let file = file.downcast_mut::<Self>().unwrap();
             Box::pin(file.read(buf))
@@ -203,6 +241,8 @@ impl OpenFlags {
     pub const READ: OpenFlags = OpenFlags(1 << 0);
     pub const WRITE: OpenFlags = OpenFlags(1 << 1);
     pub const CREATE: OpenFlags = OpenFlags(1 << 2);
+    /// Truncate the file if it already exists. Requires WRITE.
+    pub const TRUNCATE: OpenFlags = OpenFlags(1 << 3);
 
     pub fn new(flags: impl IntoIterator<Item = OpenFlags>) -> Self {
         let mut result = 0;
@@ -223,6 +263,10 @@ impl OpenFlags {
     pub const fn is_create(&self) -> bool {
         self.0 & Self::CREATE.0 != 0
     }
+
+    pub const fn is_truncate(&self) -> bool {
+        self.0 & Self::TRUNCATE.0 != 0
+    }
 }
 
 /// Context used for creating state needed by the file system to open files.
@@ -271,6 +315,13 @@ pub trait FileSystem: Debug + Sync + Send + 'static {
     /// Human-readable name for the filesystem.
     const NAME: &str;
 
+    /// If every operation on this filesystem completes the first time it's
+    /// polled.
+    ///
+    /// Only synchronous filesystems can be used from blocking code (spilling,
+    /// persistent database files).
+    const SYNCHRONOUS: bool = false;
+
     type FileHandle: FileHandle;
     type ReadDirHandle: ReadDirHandle;
 
@@ -307,6 +358,20 @@ pub trait FileSystem: Debug + Sync + Send + 'static {
         state: &Self::State,
     ) -> impl Future<Output = Result<Option<FileStat>>> + Sync + Send;
 
+    /// Delete the file at the given path.
+    fn delete(
+        &self,
+        _path: &str,
+        _state: &Self::State,
+    ) -> impl Future<Output = Result<()>> + Sync + Send {
+        async {
+            Err(DbError::new(format!(
+                "{} filesystem does not support deleting files!",
+                Self::NAME
+            )))
+        }
+    }
+
     /// Returns a directory handle for reading entries within a directory.
     ///
     /// Does not recurse.
@@ -381,6 +446,10 @@ impl FileSystemWithState {
         (self.fs.vtable.stat_fn)(self.fs.filesystem.as_ref(), path, self.state.0.as_ref())
     }
 
+    pub fn delete<'a>(&'a self, path: &'a str) -> FileSystemFuture<'a, Result<()>> {
+        (self.fs.vtable.delete_fn)(self.fs.filesystem.as_ref(), path, self.state.0.as_ref())
+    }
+
     pub fn read_glob(&self, glob: &str) -> Result<Box<dyn FileProvider>> {
         (self.fs.vtable.read_glob_fn)(self.fs.filesystem.as_ref(), glob, self.state.0.as_ref())
     }
@@ -389,6 +458,7 @@ impl FileSystemWithState {
 #[derive(Debug, Clone)]
 pub struct AnyFileSystem {
     pub(crate) name: &'static str,
+    pub(crate) synchronous: bool,
     pub(crate) vtable: &'static RawFileSystemVTable,
     pub(crate) filesystem: Arc<dyn Any + Sync + Send>,
 }
@@ -400,6 +470,7 @@ impl AnyFileSystem {
     {
         AnyFileSystem {
             name: F::NAME,
+            synchronous: F::SYNCHRONOUS,
             vtable: F::VTABLE,
             filesystem: Arc::new(fs),
         }
@@ -456,6 +527,12 @@ pub(crate) struct RawFileSystemVTable {
         state: &'a dyn Any,
     ) -> FileSystemFuture<'a, Result<Option<FileStat>>>,
 
+    delete_fn: for<'a> fn(
+        fs: &'a dyn Any,
+        path: &'a str,
+        state: &'a dyn Any,
+    ) -> FileSystemFuture<'a, Result<()>>,
+
     // TODO: ... Doesn't really fit being a "vtable" with this.
     read_glob_fn: for<'a> fn(
         fs: &'a dyn Any,
@@ -507,6 +584,12 @@ where
             Box::pin(async { fs.stat(path, state).await })
         },
 
+        delete_fn: |fs, path, state| {
+            let fs = fs.downcast_ref::<Self>().unwrap();
+            let state = state.downcast_ref::<S::State>().unwrap();
+            Box::pin(async { fs.delete(path, state).await })
+        },
+
         read_glob_fn: |fs, glob, state| {
             let fs = fs.downcast_ref::<Self>().unwrap();
             let state = state.downcast_ref::<S::State>().unwrap();
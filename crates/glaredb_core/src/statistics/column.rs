@@ -0,0 +1,397 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+
+use glaredb_error::Result;
+
+use super::hll::HyperLogLog;
+use super::value::StatisticsValue;
+use crate::arrays::array::Array;
+use crate::arrays::array::physical_type::{
+    PhysicalBinary,
+    PhysicalBool,
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI8,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI128,
+    PhysicalInterval,
+    PhysicalType,
+    PhysicalU8,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU128,
+    PhysicalUtf8,
+    ScalarStorage,
+};
+use crate::arrays::compute::hash::hash_array;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::ScalarValue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    /// Number of distinct non-null values in the column.
+    pub num_distinct: StatisticsValue<usize>,
+    /// Number of nulls in the column.
+    pub null_count: StatisticsValue<usize>,
+    /// Minimum non-null value in the column.
+    pub min: StatisticsValue<ScalarValue>,
+    /// Maximum non-null value in the column.
+    pub max: StatisticsValue<ScalarValue>,
+}
+
+impl ColumnStatistics {
+    pub const fn unknown() -> Self {
+        ColumnStatistics {
+            num_distinct: StatisticsValue::Unknown,
+            null_count: StatisticsValue::Unknown,
+            min: StatisticsValue::Unknown,
+            max: StatisticsValue::Unknown,
+        }
+    }
+}
+
+/// Collects statistics for a column from the arrays appended to it.
+///
+/// Values are only ever added, so statistics for a column with deleted rows
+/// will overestimate.
+#[derive(Debug, Clone)]
+pub struct ColumnStatisticsCollector {
+    null_count: usize,
+    /// Sketch for distinct values, None if the column's type can't be hashed.
+    distinct: Option<HyperLogLog>,
+    min: Option<ScalarValue>,
+    max: Option<ScalarValue>,
+    /// Reusable buffers for indices of valid rows and their hashes.
+    valid: Vec<usize>,
+    hashes: Vec<u64>,
+}
+
+impl Default for ColumnStatisticsCollector {
+    fn default() -> Self {
+        ColumnStatisticsCollector {
+            null_count: 0,
+            distinct: Some(HyperLogLog::new(HyperLogLog::DEFAULT_P)),
+            min: None,
+            max: None,
+            valid: Vec::new(),
+            hashes: Vec::new(),
+        }
+    }
+}
+
+impl ColumnStatisticsCollector {
+    /// Update the statistics with the first `count` rows in the array.
+    pub fn update(&mut self, array: &Array, count: usize) -> Result<()> {
+        self.valid.clear();
+
+        match array.datatype().physical_type()? {
+            PhysicalType::UntypedNull => {
+                self.null_count += count;
+                return Ok(());
+            }
+            PhysicalType::Boolean => self.update_ordered::<PhysicalBool>(array, count)?,
+            PhysicalType::Int8 => self.update_ordered::<PhysicalI8>(array, count)?,
+            PhysicalType::Int16 => self.update_ordered::<PhysicalI16>(array, count)?,
+            PhysicalType::Int32 => self.update_ordered::<PhysicalI32>(array, count)?,
+            PhysicalType::Int64 => self.update_ordered::<PhysicalI64>(array, count)?,
+            PhysicalType::Int128 => self.update_ordered::<PhysicalI128>(array, count)?,
+            PhysicalType::UInt8 => self.update_ordered::<PhysicalU8>(array, count)?,
+            PhysicalType::UInt16 => self.update_ordered::<PhysicalU16>(array, count)?,
+            PhysicalType::UInt32 => self.update_ordered::<PhysicalU32>(array, count)?,
+            PhysicalType::UInt64 => self.update_ordered::<PhysicalU64>(array, count)?,
+            PhysicalType::UInt128 => self.update_ordered::<PhysicalU128>(array, count)?,
+            PhysicalType::Float16 => self.update_ordered::<PhysicalF16>(array, count)?,
+            PhysicalType::Float32 => self.update_ordered::<PhysicalF32>(array, count)?,
+            PhysicalType::Float64 => self.update_ordered::<PhysicalF64>(array, count)?,
+            PhysicalType::Utf8 => self.update_ordered::<PhysicalUtf8>(array, count)?,
+            PhysicalType::Binary => self.update_ordered::<PhysicalBinary>(array, count)?,
+            PhysicalType::Interval => {
+                UnaryExecutor::for_each_flat::<PhysicalInterval, _>(array, 0..count, |idx, v| {
+                    match v {
+                        Some(_) => self.valid.push(idx),
+                        None => self.null_count += 1,
+                    }
+                })?
+            }
+            PhysicalType::List | PhysicalType::Struct | PhysicalType::Map => {
+                for idx in 0..count {
+                    if array.get_value(idx)?.is_null() {
+                        self.null_count += 1;
+                    } else {
+                        self.valid.push(idx);
+                    }
+                }
+                if array.datatype().physical_type()? == PhysicalType::Struct {
+                    // Hashing structs isn't supported.
+                    self.distinct = None;
+                }
+            }
+        }
+
+        if let Some(distinct) = &mut self.distinct {
+            self.hashes.resize(self.valid.len(), 0);
+            hash_array(array, self.valid.iter().copied(), &mut self.hashes)?;
+            for &hash in &self.hashes {
+                distinct.insert(hash);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update null counts and min/max values for types with an ordering.
+    fn update_ordered<S>(&mut self, array: &Array, count: usize) -> Result<()>
+    where
+        S: ScalarStorage,
+        S::StorageType: PartialOrd + ToOwned,
+    {
+        // Min and max for this array, tracked by index and compared against
+        // the running values after.
+        let mut min: Option<(usize, <S::StorageType as ToOwned>::Owned)> = None;
+        let mut max: Option<(usize, <S::StorageType as ToOwned>::Owned)> = None;
+
+        UnaryExecutor::for_each_flat::<S, _>(array, 0..count, |idx, v| {
+            let v = match v {
+                Some(v) => v,
+                None => {
+                    self.null_count += 1;
+                    return;
+                }
+            };
+            self.valid.push(idx);
+
+            // Skip values without an ordering (NaN).
+            if v.partial_cmp(v).is_none() {
+                return;
+            }
+            if min.as_ref().is_none_or(|(_, min)| v < min.borrow()) {
+                min = Some((idx, v.to_owned()));
+            }
+            if max.as_ref().is_none_or(|(_, max)| v > max.borrow()) {
+                max = Some((idx, v.to_owned()));
+            }
+        })?;
+
+        if let Some((idx, _)) = min {
+            let v = array.get_value(idx)?.into_owned();
+            if self
+                .min
+                .as_ref()
+                .is_none_or(|min| compare_scalars(&v, min) == Some(Ordering::Less))
+            {
+                self.min = Some(v);
+            }
+        }
+        if let Some((idx, _)) = max {
+            let v = array.get_value(idx)?.into_owned();
+            if self
+                .max
+                .as_ref()
+                .is_none_or(|max| compare_scalars(&v, max) == Some(Ordering::Greater))
+            {
+                self.max = Some(v);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Merge statistics from another collector for the same column.
+    pub fn merge(&mut self, other: &ColumnStatisticsCollector) {
+        self.null_count += other.null_count;
+
+        match (&mut self.distinct, &other.distinct) {
+            (Some(distinct), Some(other)) => distinct.merge(other),
+            _ => self.distinct = None,
+        }
+
+        if let Some(other_min) = &other.min
+            && self
+                .min
+                .as_ref()
+                .is_none_or(|min| compare_scalars(other_min, min) == Some(Ordering::Less))
+        {
+            self.min = Some(other_min.clone());
+        }
+        if let Some(other_max) = &other.max
+            && self
+                .max
+                .as_ref()
+                .is_none_or(|max| compare_scalars(other_max, max) == Some(Ordering::Greater))
+        {
+            self.max = Some(other_max.clone());
+        }
+    }
+
+    /// Get the statistics collected so far.
+    ///
+    /// `row_count` is used to bound the distinct count estimate.
+    pub fn statistics(&self, row_count: usize) -> ColumnStatistics {
+        let num_distinct = match &self.distinct {
+            Some(distinct) => {
+                let non_null = row_count.saturating_sub(self.null_count);
+                let estimate = distinct.count().round() as usize;
+                StatisticsValue::Estimated(usize::min(estimate, non_null))
+            }
+            None => StatisticsValue::Unknown,
+        };
+
+        ColumnStatistics {
+            num_distinct,
+            null_count: StatisticsValue::Estimated(self.null_count),
+            min: self
+                .min
+                .clone()
+                .map(StatisticsValue::Estimated)
+                .unwrap_or_default(),
+            max: self
+                .max
+                .clone()
+                .map(StatisticsValue::Estimated)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Compare two scalars of the same type.
+///
+/// Returns None if the values can't be compared.
+pub fn compare_scalars(a: &ScalarValue, b: &ScalarValue) -> Option<Ordering> {
+    match (a, b) {
+        (ScalarValue::Boolean(a), ScalarValue::Boolean(b)) => Some(a.cmp(b)),
+        (ScalarValue::Utf8(a), ScalarValue::Utf8(b)) => Some(a.cmp(b)),
+        (ScalarValue::Binary(a), ScalarValue::Binary(b)) => Some(a.cmp(b)),
+        (ScalarValue::Decimal64(a), ScalarValue::Decimal64(b)) if a.scale == b.scale => {
+            Some(a.value.cmp(&b.value))
+        }
+        (ScalarValue::Decimal128(a), ScalarValue::Decimal128(b)) if a.scale == b.scale => {
+            Some(a.value.cmp(&b.value))
+        }
+        (ScalarValue::Timestamp(a), ScalarValue::Timestamp(b)) if a.unit == b.unit => {
+            Some(a.value.cmp(&b.value))
+        }
+        (a, b) => match (scalar_as_i128(a), scalar_as_i128(b)) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ => scalar_as_f64(a)?.partial_cmp(&scalar_as_f64(b)?),
+        },
+    }
+}
+
+fn scalar_as_i128(v: &ScalarValue) -> Option<i128> {
+    Some(match v {
+        ScalarValue::Int8(v) => *v as i128,
+        ScalarValue::Int16(v) => *v as i128,
+        ScalarValue::Int32(v) => *v as i128,
+        ScalarValue::Int64(v) => *v as i128,
+        ScalarValue::Int128(v) => *v,
+        ScalarValue::UInt8(v) => *v as i128,
+        ScalarValue::UInt16(v) => *v as i128,
+        ScalarValue::UInt32(v) => *v as i128,
+        ScalarValue::UInt64(v) => *v as i128,
+        ScalarValue::Date32(v) => *v as i128,
+        ScalarValue::Date64(v) => *v as i128,
+        ScalarValue::Time(v) => *v as i128,
+        _ => return None,
+    })
+}
+
+/// Get a numeric representation of a scalar for estimating where a value
+/// falls in a range.
+///
+/// Returns None for non-numeric scalars.
+pub fn scalar_as_f64(v: &ScalarValue) -> Option<f64> {
+    Some(match v {
+        ScalarValue::Float16(v) => f64::from(*v),
+        ScalarValue::Float32(v) => *v as f64,
+        ScalarValue::Float64(v) => *v,
+        ScalarValue::UInt128(v) => *v as f64,
+        ScalarValue::Decimal64(v) => v.value as f64 / 10_f64.powi(v.scale as i32),
+        ScalarValue::Decimal128(v) => v.value as f64 / 10_f64.powi(v.scale as i32),
+        ScalarValue::Timestamp(v) => v.value as f64,
+        other => scalar_as_i128(other)? as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::buffer::buffer_manager::DefaultBufferManager;
+    use crate::util::iter::TryFromExactSizeIterator;
+
+    #[test]
+    fn collect_int_statistics() {
+        let mut collector = ColumnStatisticsCollector::default();
+        let array = Array::try_from_iter([Some(4), None, Some(-2), Some(4), Some(8)]).unwrap();
+        collector.update(&array, 5).unwrap();
+
+        let stats = collector.statistics(5);
+        assert_eq!(StatisticsValue::Estimated(1), stats.null_count);
+        assert_eq!(StatisticsValue::Estimated(3), stats.num_distinct);
+        assert_eq!(
+            StatisticsValue::Estimated(ScalarValue::Int32(-2)),
+            stats.min
+        );
+        assert_eq!(StatisticsValue::Estimated(ScalarValue::Int32(8)), stats.max);
+    }
+
+    #[test]
+    fn collect_only_count_rows() {
+        let mut collector = ColumnStatisticsCollector::default();
+        let array = Array::try_from_iter([1, 2, 3, 100]).unwrap();
+        collector.update(&array, 3).unwrap();
+
+        let stats = collector.statistics(3);
+        assert_eq!(StatisticsValue::Estimated(ScalarValue::Int32(3)), stats.max);
+    }
+
+    #[test]
+    fn collect_string_statistics() {
+        let mut collector = ColumnStatisticsCollector::default();
+        let array = Array::try_from_iter(["b", "a", "c"]).unwrap();
+        collector.update(&array, 3).unwrap();
+
+        let stats = collector.statistics(3);
+        assert_eq!(
+            StatisticsValue::Estimated(ScalarValue::Utf8("a".into())),
+            stats.min
+        );
+        assert_eq!(
+            StatisticsValue::Estimated(ScalarValue::Utf8("c".into())),
+            stats.max
+        );
+    }
+
+    #[test]
+    fn collect_nulls() {
+        let mut collector = ColumnStatisticsCollector::default();
+        let array = Array::new_null(&DefaultBufferManager, DataType::int32(), 4).unwrap();
+        collector.update(&array, 4).unwrap();
+
+        let stats = collector.statistics(4);
+        assert_eq!(StatisticsValue::Estimated(4), stats.null_count);
+        assert_eq!(StatisticsValue::Estimated(0), stats.num_distinct);
+        assert_eq!(StatisticsValue::Unknown, stats.min);
+    }
+
+    #[test]
+    fn merge_collectors() {
+        let mut c1 = ColumnStatisticsCollector::default();
+        c1.update(&Array::try_from_iter([1, 2, 3]).unwrap(), 3)
+            .unwrap();
+        let mut c2 = ColumnStatisticsCollector::default();
+        c2.update(&Array::try_from_iter([Some(3), Some(9), None]).unwrap(), 3)
+            .unwrap();
+
+        c1.merge(&c2);
+        let stats = c1.statistics(6);
+        assert_eq!(StatisticsValue::Estimated(1), stats.null_count);
+        assert_eq!(StatisticsValue::Estimated(4), stats.num_distinct);
+        assert_eq!(StatisticsValue::Estimated(ScalarValue::Int32(1)), stats.min);
+        assert_eq!(StatisticsValue::Estimated(ScalarValue::Int32(9)), stats.max);
+    }
+}
@@ -1,5 +1,5 @@
 /// A tiny HyperLogLog sketch over out 64-bit hashes.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HyperLogLog {
     p: u8,
     m: usize,
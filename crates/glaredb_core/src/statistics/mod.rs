@@ -1,3 +1,4 @@
+pub mod column;
 pub mod hll;
 pub mod value;
 
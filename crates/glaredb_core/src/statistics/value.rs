@@ -57,9 +57,3 @@ where
         }
     }
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ColumnStatistics {
-    /// Number of distinct values in the column.
-    pub num_distinct: StatisticsValue<usize>,
-}
@@ -1,6 +1,8 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use glaredb_error::Result;
+use parking_lot::{Mutex, MutexGuard};
 
 use super::projections::Projections;
 use crate::arrays::batch::Batch;
@@ -8,9 +10,16 @@ use crate::arrays::collection::concurrent::{
     ColumnCollectionAppendState,
     ColumnCollectionScanState,
     ConcurrentColumnCollection,
+    DeletedRows,
     ParallelColumnCollectionScanState,
 };
 use crate::arrays::datatype::DataType;
+use crate::statistics::column::{ColumnStatistics, ColumnStatisticsCollector};
+use crate::statistics::value::StatisticsValue;
+use crate::transaction::{Snapshot, Transaction};
+
+/// Name of the metadata column containing row ids when scanning a table.
+pub const ROWID_COLUMN_NAME: &str = "_rowid";
 
 #[derive(Debug)]
 pub struct DataTableScanState {
@@ -25,12 +34,71 @@ pub struct ParallelDataTableScanState {
 #[derive(Debug)]
 pub struct DataTableAppendState {
     state: ColumnCollectionAppendState,
+    transaction: Arc<Transaction>,
+    /// Statistics for rows appended with this state, merged into the table's
+    /// statistics on flush.
+    statistics: TableStatistics,
+}
+
+#[derive(Debug)]
+pub struct DataTableDeleteState {
+    transaction: Arc<Transaction>,
+}
+
+/// Log for persisting changes to a table.
+///
+/// Changes are written to the log before they're visible in the table, and
+/// only take effect once the transaction that made them commits.
+pub trait WriteLog: Debug + Sync + Send {
+    /// Called when an append or delete state is created for the table.
+    fn begin_transaction(&self, tx: &Transaction);
+
+    /// Write rows that are about to be flushed to the table.
+    ///
+    /// Calls happen in the same order rows are assigned row ids.
+    fn log_append(&self, transaction_id: u64, batch: &Batch) -> Result<()>;
+
+    /// Write row ids that are about to be deleted from the table.
+    fn log_delete(&self, transaction_id: u64, row_ids: &[usize]) -> Result<()>;
 }
 
-/// A currently memory-only storage backend for table data.
+/// Statistics collected for all rows appended to a table.
+#[derive(Debug)]
+struct TableStatistics {
+    row_count: usize,
+    columns: Vec<ColumnStatisticsCollector>,
+}
+
+impl TableStatistics {
+    fn new(num_columns: usize) -> Self {
+        TableStatistics {
+            row_count: 0,
+            columns: vec![ColumnStatisticsCollector::default(); num_columns],
+        }
+    }
+}
+
+/// In-memory storage for table data.
+///
+/// Rows are identified by their row id, the offset of the row in the table.
+/// Row ids don't change while the table is loaded, deleted rows leave gaps.
+///
+/// Appended and deleted rows are stamped with the version of the transaction
+/// making the change, and scans only see rows visible to their snapshot.
+///
+/// Tables may optionally have a write log for persisting changes.
+///
+/// Per-column statistics are kept for the cost model. Statistics only track
+/// appends, with deletes reducing the row count, so they're estimates once
+/// rows are deleted or appends are rolled back.
 #[derive(Debug)]
 pub struct DataTable {
     collection: ConcurrentColumnCollection,
+    log: Option<Arc<dyn WriteLog>>,
+    statistics: Mutex<TableStatistics>,
+    /// Lock held while applying changes that depend on the existing rows in
+    /// the table, e.g. when checking unique constraints.
+    write_lock: Mutex<()>,
 }
 
 impl DataTable {
@@ -39,38 +107,179 @@ impl DataTable {
         segment_size: usize,
         chunk_capacity: usize,
     ) -> Self {
+        let collection = ConcurrentColumnCollection::new(datatypes, segment_size, chunk_capacity);
+        let statistics = Mutex::new(TableStatistics::new(collection.datatypes().len()));
         DataTable {
-            collection: ConcurrentColumnCollection::new(datatypes, segment_size, chunk_capacity),
+            collection,
+            log: None,
+            statistics,
+            write_lock: Mutex::new(()),
         }
     }
 
-    pub fn init_append_state(&self) -> DataTableAppendState {
+    /// Create a new table with all changes written to `log`.
+    pub fn new_with_log(
+        datatypes: impl IntoIterator<Item = DataType>,
+        segment_size: usize,
+        chunk_capacity: usize,
+        log: Arc<dyn WriteLog>,
+    ) -> Self {
+        let collection = ConcurrentColumnCollection::new(datatypes, segment_size, chunk_capacity);
+        let statistics = Mutex::new(TableStatistics::new(collection.datatypes().len()));
+        DataTable {
+            collection,
+            log: Some(log),
+            statistics,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    pub fn datatypes(&self) -> &[DataType] {
+        self.collection.datatypes()
+    }
+
+    /// Lock the table for writes that need a consistent view of the existing
+    /// rows.
+    ///
+    /// Plain appends and deletes don't take this lock.
+    pub fn lock_writes(&self) -> MutexGuard<'_, ()> {
+        self.write_lock.lock()
+    }
+
+    pub fn init_append_state(&self, tx: &Arc<Transaction>) -> DataTableAppendState {
+        if let Some(log) = &self.log {
+            log.begin_transaction(tx);
+        }
         DataTableAppendState {
-            state: self.collection.init_append_state(),
+            state: self.collection.init_versioned_append_state(tx.id()),
+            transaction: tx.clone(),
+            statistics: TableStatistics::new(self.collection.datatypes().len()),
         }
     }
 
-    pub fn init_scan_state(&self) -> DataTableScanState {
+    pub fn init_delete_state(&self, tx: &Arc<Transaction>) -> DataTableDeleteState {
+        if let Some(log) = &self.log {
+            log.begin_transaction(tx);
+        }
+        DataTableDeleteState {
+            transaction: tx.clone(),
+        }
+    }
+
+    pub fn init_scan_state(&self, snapshot: Snapshot) -> DataTableScanState {
         DataTableScanState {
-            state: self.collection.init_scan_state(),
+            state: self.collection.init_snapshot_scan_state(snapshot),
         }
     }
 
     pub fn init_parallel_scan_states(
         &self,
+        snapshot: Snapshot,
         num_parallel: usize,
     ) -> impl Iterator<Item = ParallelDataTableScanState> + '_ {
         self.collection
-            .init_parallel_scan_states(num_parallel)
+            .init_snapshot_parallel_scan_states(snapshot, num_parallel)
             .map(|state| ParallelDataTableScanState { state })
     }
 
     pub fn append_batch(&self, state: &mut DataTableAppendState, batch: &Batch) -> Result<()> {
-        self.collection.append_batch(&mut state.state, batch)
+        let num_rows = batch.num_rows();
+        for (collector, array) in state.statistics.columns.iter_mut().zip(&batch.arrays) {
+            collector.update(array, num_rows)?;
+        }
+        state.statistics.row_count += num_rows;
+
+        let transaction_id = state.transaction.id();
+        let result = match &self.log {
+            Some(log) => self
+                .collection
+                .append_batch_with(&mut state.state, batch, |rows| {
+                    log.log_append(transaction_id, rows)
+                }),
+            None => self.collection.append_batch(&mut state.state, batch),
+        };
+        Self::track_appended(state);
+        result
     }
 
     pub fn flush(&self, state: &mut DataTableAppendState) -> Result<()> {
-        self.collection.flush(&mut state.state)
+        // Rows are logged as they're flushed so that the order in the log
+        // matches the order of row ids.
+        let transaction_id = state.transaction.id();
+        let result = match &self.log {
+            Some(log) => self.collection.flush_with(&mut state.state, |rows| {
+                log.log_append(transaction_id, rows)
+            }),
+            None => self.collection.flush(&mut state.state),
+        };
+        Self::track_appended(state);
+
+        let mut statistics = self.statistics.lock();
+        statistics.row_count += state.statistics.row_count;
+        for (collector, appended) in statistics.columns.iter_mut().zip(&state.statistics.columns) {
+            collector.merge(appended);
+        }
+        std::mem::drop(statistics);
+        state.statistics = TableStatistics::new(state.statistics.columns.len());
+
+        result
+    }
+
+    /// Hand rows flushed by the append state to its transaction.
+    fn track_appended(state: &mut DataTableAppendState) {
+        let appended = state.state.take_appended();
+        if !appended.is_empty() {
+            state.transaction.add_change(Box::new(appended));
+        }
+    }
+
+    /// Delete rows from the table, returning the number of rows that weren't
+    /// already deleted.
+    ///
+    /// Errors if any of the rows were deleted by a concurrent transaction.
+    pub fn delete_rows(
+        &self,
+        state: &mut DataTableDeleteState,
+        row_ids: &[usize],
+    ) -> Result<usize> {
+        let tx = &state.transaction;
+        if let Some(log) = &self.log {
+            log.log_delete(tx.id(), row_ids)?;
+        }
+
+        let mut deleted = DeletedRows::new(tx.id());
+        let result =
+            self.collection
+                .delete_rows(&tx.snapshot(), row_ids.iter().copied(), &mut deleted);
+        if !deleted.is_empty() {
+            tx.add_change(Box::new(deleted));
+        }
+        if let Ok(count) = result {
+            let mut statistics = self.statistics.lock();
+            statistics.row_count = statistics.row_count.saturating_sub(count);
+        }
+        result
+    }
+
+    /// Get the estimated number of rows in the table.
+    pub fn estimated_row_count(&self) -> StatisticsValue<usize> {
+        StatisticsValue::Estimated(self.statistics.lock().row_count)
+    }
+
+    /// Get the statistics for each column in the table.
+    pub fn column_statistics(&self) -> Vec<ColumnStatistics> {
+        let statistics = self.statistics.lock();
+        statistics
+            .columns
+            .iter()
+            .map(|collector| collector.statistics(statistics.row_count))
+            .collect()
+    }
+
+    /// Returns the row ids of all rows not visible to the latest snapshot in
+    /// sorted order.
+    pub fn deleted_row_ids(&self) -> Vec<usize> {
+        self.collection.deleted_row_ids()
     }
 
     pub fn scan(
@@ -1,4 +1,37 @@
 pub mod datatable;
+pub mod persistent;
 pub mod projections;
 pub mod scan_filter;
 pub mod storage_manager;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use datatable::DataTable;
+use glaredb_error::Result;
+use storage_manager::StorageTableId;
+
+use crate::arrays::datatype::DataType;
+use crate::transaction::Transaction;
+
+/// Storage backend for tables managed by a database.
+///
+/// Dynamically dispatched through `Database` to allow attached databases to
+/// use different storage backends.
+pub trait Storage: Debug + Sync + Send {
+    /// Create a new empty table with the given column types, returning the
+    /// id the table can be retrieved with.
+    ///
+    /// The table is removed if the transaction rolls back.
+    fn create_table(
+        &self,
+        tx: &Transaction,
+        datatypes: &[DataType],
+    ) -> Result<(StorageTableId, Arc<DataTable>)>;
+
+    /// Drop the table with the given id once the transaction commits.
+    fn drop_table(&self, tx: &Transaction, id: StorageTableId) -> Result<()>;
+
+    /// Get the table with the given id.
+    fn get_table(&self, id: StorageTableId) -> Result<Arc<DataTable>>;
+}
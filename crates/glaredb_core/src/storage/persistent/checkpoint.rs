@@ -0,0 +1,334 @@
+//! Checkpoint files containing the full state of a database.
+//!
+//! ```text
+//! [magic: 8 bytes][version: u32]
+//! [segment][segment]...
+//! [catalog: json]
+//! [footer]
+//! ```
+//!
+//! Segments hold table rows encoded with the spill encoding. The catalog
+//! snapshot is written after all segments, and contains pointers to each
+//! table's segments. The footer is written last, and contains the location of
+//! the catalog:
+//!
+//! ```text
+//! [catalog_offset: u64][catalog_len: u64][generation: u64][catalog_crc32: u32][version: u32][magic: 8 bytes]
+//! ```
+//!
+//! A file without a valid footer is incomplete.
+
+use std::io::SeekFrom;
+
+use glaredb_error::{DbError, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::arrays::batch::Batch;
+use crate::arrays::field::Field;
+use crate::catalog::create::UniqueConstraint;
+use crate::execution::spill::encode;
+use crate::runtime::filesystem::blocking::{complete, sync, write_all};
+use crate::runtime::filesystem::{AnyFile, FileSystemWithState, OpenFlags};
+use crate::storage::storage_manager::StorageTableId;
+
+pub const FILE_MAGIC: &[u8; 8] = b"GLAREDB\0";
+
+/// Current version of the file format.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Size of the header at the start of the file (magic + version).
+const HEADER_SIZE: usize = 8 + 4;
+
+/// Size of the footer at the end of the file.
+const FOOTER_SIZE: usize = 8 + 8 + 8 + 4 + 4 + 8;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogSnapshot {
+    pub schemas: Vec<SchemaSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaSnapshot {
+    pub name: String,
+    pub tables: Vec<TableSnapshot>,
+    pub views: Vec<ViewSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSnapshot {
+    pub name: String,
+    pub columns: Vec<Field>,
+    #[serde(default)]
+    pub unique_constraints: Vec<UniqueConstraint>,
+    pub table_id: StorageTableId,
+    pub segments: Vec<SegmentPointer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewSnapshot {
+    pub name: String,
+    pub column_aliases: Option<Vec<String>>,
+    pub query_sql: String,
+}
+
+/// Location of an encoded segment in the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentPointer {
+    pub offset: u64,
+    pub len: u64,
+    pub num_rows: usize,
+    pub checksum: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Footer {
+    catalog_offset: u64,
+    catalog_len: u64,
+    generation: u64,
+    catalog_checksum: u32,
+}
+
+impl Footer {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.catalog_offset.to_le_bytes());
+        buf.extend_from_slice(&self.catalog_len.to_le_bytes());
+        buf.extend_from_slice(&self.generation.to_le_bytes());
+        buf.extend_from_slice(&self.catalog_checksum.to_le_bytes());
+        buf.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        buf.extend_from_slice(FILE_MAGIC);
+    }
+
+    fn decode(buf: &[u8; FOOTER_SIZE]) -> Result<Self> {
+        if &buf[32..40] != FILE_MAGIC {
+            return Err(DbError::new("Not a GlareDB database file"));
+        }
+        let version = u32::from_le_bytes(buf[28..32].try_into().unwrap());
+        if version != FORMAT_VERSION {
+            return Err(DbError::new("Unsupported database file version")
+                .with_field("version", version)
+                .with_field("supported", FORMAT_VERSION));
+        }
+
+        Ok(Footer {
+            catalog_offset: u64::from_le_bytes(buf[0..8].try_into().unwrap()),
+            catalog_len: u64::from_le_bytes(buf[8..16].try_into().unwrap()),
+            generation: u64::from_le_bytes(buf[16..24].try_into().unwrap()),
+            catalog_checksum: u32::from_le_bytes(buf[24..28].try_into().unwrap()),
+        })
+    }
+}
+
+/// Writes a new checkpoint file.
+#[derive(Debug)]
+pub struct CheckpointWriter {
+    file: AnyFile,
+    /// Current offset in the file.
+    offset: u64,
+    /// Reusable buffer for encoding segments.
+    buf: Vec<u8>,
+}
+
+impl CheckpointWriter {
+    /// Create a new checkpoint file, truncating the file if it exists.
+    pub fn create(filesystem: &FileSystemWithState, path: &str) -> Result<Self> {
+        let flags = OpenFlags::new([OpenFlags::WRITE, OpenFlags::CREATE, OpenFlags::TRUNCATE]);
+        let mut file = complete(filesystem.open(flags, path))?;
+
+        let mut header = Vec::with_capacity(HEADER_SIZE);
+        header.extend_from_slice(FILE_MAGIC);
+        header.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        write_all(&mut file, &header)?;
+
+        Ok(CheckpointWriter {
+            file,
+            offset: HEADER_SIZE as u64,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Write the rows in the batch as a segment.
+    pub fn write_segment(&mut self, batch: &Batch) -> Result<SegmentPointer> {
+        self.buf.clear();
+        encode::encode_batch(batch, &mut self.buf)?;
+        write_all(&mut self.file, &self.buf)?;
+
+        let segment = SegmentPointer {
+            offset: self.offset,
+            len: self.buf.len() as u64,
+            num_rows: batch.num_rows(),
+            checksum: crc32fast::hash(&self.buf),
+        };
+        self.offset += self.buf.len() as u64;
+
+        Ok(segment)
+    }
+
+    /// Write the catalog and footer, and flush the file.
+    pub fn finish(mut self, catalog: &CatalogSnapshot, generation: u64) -> Result<()> {
+        self.buf.clear();
+        serde_json::to_writer(&mut self.buf, catalog)
+            .context("Failed to serialize catalog snapshot")?;
+
+        let footer = Footer {
+            catalog_offset: self.offset,
+            catalog_len: self.buf.len() as u64,
+            generation,
+            catalog_checksum: crc32fast::hash(&self.buf),
+        };
+        footer.encode(&mut self.buf);
+
+        write_all(&mut self.file, &self.buf)?;
+        sync(&mut self.file)?;
+
+        Ok(())
+    }
+}
+
+/// Reads a checkpoint file.
+#[derive(Debug)]
+pub struct CheckpointReader {
+    file: AnyFile,
+    generation: u64,
+    catalog: CatalogSnapshot,
+    buf: Vec<u8>,
+}
+
+impl CheckpointReader {
+    /// Open a checkpoint file, reading the footer and catalog.
+    ///
+    /// Errors if the file is incomplete or corrupt.
+    pub fn open(filesystem: &FileSystemWithState, path: &str) -> Result<Self> {
+        let mut file = complete(filesystem.open(OpenFlags::READ, path))?;
+        let size = file.call_size();
+        if size < HEADER_SIZE + FOOTER_SIZE {
+            return Err(
+                DbError::new("Database file too small").with_field("path", path.to_string())
+            );
+        }
+
+        let mut footer = [0; FOOTER_SIZE];
+        complete(file.call_seek(SeekFrom::Start((size - FOOTER_SIZE) as u64)))?;
+        complete(file.call_read_exact(&mut footer))?;
+        let footer = Footer::decode(&footer)?;
+
+        if footer.catalog_offset + footer.catalog_len > (size - FOOTER_SIZE) as u64 {
+            return Err(DbError::new("Invalid catalog location in database file")
+                .with_field("path", path.to_string()));
+        }
+
+        let mut buf = vec![0; footer.catalog_len as usize];
+        complete(file.call_seek(SeekFrom::Start(footer.catalog_offset)))?;
+        complete(file.call_read_exact(&mut buf))?;
+        if crc32fast::hash(&buf) != footer.catalog_checksum {
+            return Err(DbError::new("Catalog checksum mismatch in database file")
+                .with_field("path", path.to_string()));
+        }
+        let catalog =
+            serde_json::from_slice(&buf).context("Failed to deserialize catalog snapshot")?;
+
+        Ok(CheckpointReader {
+            file,
+            generation: footer.generation,
+            catalog,
+            buf,
+        })
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn catalog(&self) -> &CatalogSnapshot {
+        &self.catalog
+    }
+
+    /// Read a segment into the output batch.
+    ///
+    /// The output batch should have capacity for all rows in the segment.
+    pub fn read_segment(&mut self, segment: &SegmentPointer, output: &mut Batch) -> Result<()> {
+        self.buf.resize(segment.len as usize, 0);
+        complete(self.file.call_seek(SeekFrom::Start(segment.offset)))?;
+        complete(self.file.call_read_exact(&mut self.buf))?;
+        if crc32fast::hash(&self.buf) != segment.checksum {
+            return Err(DbError::new("Segment checksum mismatch in database file")
+                .with_field("offset", segment.offset));
+        }
+
+        output.reset_for_write()?;
+        encode::decode_batch(&self.buf, segment.num_rows, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+
+    #[test]
+    fn footer_roundtrip() {
+        let footer = Footer {
+            catalog_offset: 1024,
+            catalog_len: 48,
+            generation: 7,
+            catalog_checksum: 0xDEADBEEF,
+        };
+
+        let mut buf = Vec::new();
+        footer.encode(&mut buf);
+        assert_eq!(FOOTER_SIZE, buf.len());
+
+        let decoded = Footer::decode(buf.as_slice().try_into().unwrap()).unwrap();
+        assert_eq!(footer, decoded);
+    }
+
+    #[test]
+    fn footer_bad_magic() {
+        let mut buf = Vec::new();
+        Footer {
+            catalog_offset: 0,
+            catalog_len: 0,
+            generation: 0,
+            catalog_checksum: 0,
+        }
+        .encode(&mut buf);
+        buf[FOOTER_SIZE - 1] = b'X';
+
+        Footer::decode(buf.as_slice().try_into().unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn catalog_snapshot_roundtrip() {
+        let snapshot = CatalogSnapshot {
+            schemas: vec![SchemaSnapshot {
+                name: "main".to_string(),
+                tables: vec![TableSnapshot {
+                    name: "t1".to_string(),
+                    columns: vec![
+                        Field::new("a", DataType::int32(), true),
+                        Field::new("b", DataType::utf8(), true),
+                    ],
+                    unique_constraints: vec![UniqueConstraint {
+                        columns: vec![0],
+                        primary_key: true,
+                    }],
+                    table_id: StorageTableId::from_usize(3),
+                    segments: vec![SegmentPointer {
+                        offset: 12,
+                        len: 100,
+                        num_rows: 4,
+                        checksum: 42,
+                    }],
+                }],
+                views: vec![ViewSnapshot {
+                    name: "v1".to_string(),
+                    column_aliases: Some(vec!["c1".to_string()]),
+                    query_sql: "SELECT a FROM t1".to_string(),
+                }],
+            }],
+        };
+
+        let buf = serde_json::to_vec(&snapshot).unwrap();
+        let decoded: CatalogSnapshot = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(snapshot, decoded);
+    }
+}
@@ -0,0 +1,917 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Weak};
+
+use futures::TryStreamExt;
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+use tracing::warn;
+
+use super::checkpoint::{
+    CatalogSnapshot,
+    CheckpointReader,
+    CheckpointWriter,
+    SchemaSnapshot,
+    TableSnapshot,
+    ViewSnapshot,
+};
+use super::wal::{CatalogRecord, WalReader, WalRecord, WalWriter};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::catalog::create::{
+    CreateAggregateFunctionInfo,
+    CreateCopyToFunctionInfo,
+    CreateExternalTableInfo,
+    CreateScalarFunctionInfo,
+    CreateSchemaInfo,
+    CreateTableFunctionInfo,
+    CreateTableInfo,
+    CreateViewInfo,
+    CreateWindowFunctionInfo,
+    OnConflict,
+};
+use crate::catalog::drop::DropInfo;
+use crate::catalog::entry::{CatalogEntry, CatalogEntryInner, CatalogEntryType, TableStorage};
+use crate::catalog::memory::MemoryCatalog;
+use crate::catalog::{Catalog, CatalogStream, Schema};
+use crate::config::session::DEFAULT_BATCH_SIZE;
+use crate::execution::spill::encode;
+use crate::runtime::filesystem::blocking::{check_synchronous, complete, sync, write_all};
+use crate::runtime::filesystem::{FileSystemWithState, OpenFlags};
+use crate::storage::Storage;
+use crate::storage::datatable::{DataTable, WriteLog};
+use crate::storage::projections::Projections;
+use crate::storage::storage_manager::{StorageManager, StorageTableId};
+use crate::transaction::{SYSTEM_VERSION, Transaction, TransactionLog, TransactionManager};
+
+/// Schema created in new database files.
+pub const DEFAULT_FILE_SCHEMA: &str = "main";
+
+/// Checkpoint once the WAL grows past this size.
+const CHECKPOINT_WAL_SIZE: usize = 16 * 1024 * 1024;
+
+/// Buffer size to use when copying files.
+const COPY_BUF_SIZE: usize = 1024 * 1024;
+
+/// Shared state for an open database file.
+///
+/// All changes are written to the WAL before being visible, and a commit
+/// record is written when the transaction making them commits. The WAL is
+/// periodically folded into a new checkpoint.
+#[derive(Debug)]
+pub struct DatabaseFile {
+    path: String,
+    filesystem: FileSystemWithState,
+    catalog: MemoryCatalog,
+    storage: StorageManager,
+    state: Mutex<FileState>,
+}
+
+#[derive(Debug)]
+struct FileState {
+    /// If we're currently loading the database.
+    ///
+    /// Appends aren't logged while loading.
+    loading: bool,
+    /// Writer for the WAL, None if we failed to create a new WAL during a
+    /// checkpoint.
+    wal: Option<WalWriter>,
+    /// Generation of the last checkpoint.
+    generation: u64,
+    /// Number of in-progress transactions that have written to this database.
+    ///
+    /// Checkpoints are skipped while there's active transactions since their
+    /// changes are in the WAL but may not be committed yet.
+    active_transactions: usize,
+    /// Row ids of deleted rows for each table as of the last checkpoint.
+    ///
+    /// Checkpoints only contain live rows, so row ids for tables loaded from
+    /// the checkpoint are shifted down for every deleted row before it. Row
+    /// ids written to the WAL need to be shifted the same way.
+    compacted: HashMap<StorageTableId, Vec<usize>>,
+}
+
+impl FileState {
+    fn require_wal(&mut self) -> Result<&mut WalWriter> {
+        self.wal.as_mut().ok_or_else(|| {
+            DbError::new("Database file cannot be written to after a failed checkpoint")
+        })
+    }
+}
+
+impl DatabaseFile {
+    /// Open the database file at the given path, creating it if it doesn't
+    /// exist.
+    pub fn open(filesystem: FileSystemWithState, path: impl Into<String>) -> Result<Arc<Self>> {
+        check_synchronous(&filesystem)?;
+
+        let db = Arc::new(DatabaseFile {
+            path: path.into(),
+            filesystem,
+            catalog: MemoryCatalog::empty(),
+            storage: StorageManager::empty(),
+            state: Mutex::new(FileState {
+                loading: true,
+                wal: None,
+                generation: 0,
+                active_transactions: 0,
+                compacted: HashMap::new(),
+            }),
+        });
+
+        db.recover_checkpoint_file()?;
+
+        let mut needs_checkpoint = false;
+        let generation = if complete(db.filesystem.stat(&db.path))?.is_some() {
+            db.load_checkpoint()?
+        } else {
+            db.catalog.create_schema(
+                &Transaction::system(),
+                &CreateSchemaInfo {
+                    name: DEFAULT_FILE_SCHEMA.to_string(),
+                    on_conflict: OnConflict::Error,
+                },
+            )?;
+            needs_checkpoint = true;
+            0
+        };
+
+        // Replay the WAL if it's for the checkpoint we just loaded. A WAL for
+        // any other generation has already been folded into a checkpoint.
+        let mut wal_size = None;
+        if !needs_checkpoint && complete(db.filesystem.stat(&db.wal_path()))?.is_some() {
+            let buf = db.read_file(&db.wal_path())?;
+            match WalReader::try_new(&buf) {
+                Some(mut reader) if reader.generation() == generation => {
+                    let committed = committed_transactions(reader.clone())?;
+                    let transactions = Arc::new(TransactionManager::new());
+                    let mut replayed = false;
+                    while let Some(record) = reader.next_record()? {
+                        db.replay_record(&committed, &transactions, record)?;
+                        replayed = true;
+                    }
+                    if replayed || !reader.is_exhausted() {
+                        needs_checkpoint = true;
+                    } else {
+                        wal_size = Some(reader.offset());
+                    }
+                }
+                _ => needs_checkpoint = true,
+            }
+        } else {
+            needs_checkpoint = true;
+        }
+
+        {
+            let mut state = db.state.lock();
+            state.loading = false;
+            state.generation = generation;
+            match wal_size {
+                Some(size) if !needs_checkpoint => {
+                    state.wal = Some(WalWriter::open_append(
+                        &db.filesystem,
+                        &db.wal_path(),
+                        size,
+                    )?)
+                }
+                _ => db.checkpoint(&mut state)?,
+            }
+        }
+
+        Ok(db)
+    }
+
+    pub fn catalog(self: &Arc<Self>) -> PersistentCatalog {
+        PersistentCatalog { db: self.clone() }
+    }
+
+    pub fn storage(self: &Arc<Self>) -> PersistentStorage {
+        PersistentStorage { db: self.clone() }
+    }
+
+    fn wal_path(&self) -> String {
+        format!("{}.wal", self.path)
+    }
+
+    fn checkpoint_path(&self) -> String {
+        format!("{}.checkpoint", self.path)
+    }
+
+    /// Handle a checkpoint file left over from a checkpoint that didn't
+    /// complete.
+    ///
+    /// A complete checkpoint file means we may have failed while copying it
+    /// over the main file, so finish the copy. An incomplete one is discarded.
+    fn recover_checkpoint_file(&self) -> Result<()> {
+        let checkpoint_path = self.checkpoint_path();
+        if complete(self.filesystem.stat(&checkpoint_path))?.is_none() {
+            return Ok(());
+        }
+
+        if CheckpointReader::open(&self.filesystem, &checkpoint_path).is_ok() {
+            self.copy_file(&checkpoint_path, &self.path)?;
+        }
+        complete(self.filesystem.delete(&checkpoint_path))
+    }
+
+    /// Load the catalog and tables from the checkpoint, returning the
+    /// checkpoint generation.
+    fn load_checkpoint(self: &Arc<Self>) -> Result<u64> {
+        let mut reader = CheckpointReader::open(&self.filesystem, &self.path)?;
+        let snapshot = reader.catalog().clone();
+        let tx = Arc::new(Transaction::system());
+
+        for schema in &snapshot.schemas {
+            let schema_ent = self.catalog.create_schema(
+                &tx,
+                &CreateSchemaInfo {
+                    name: schema.name.clone(),
+                    on_conflict: OnConflict::Error,
+                },
+            )?;
+
+            for table in &schema.tables {
+                let datatable = self.create_datatable(
+                    table.table_id,
+                    table.columns.iter().map(|f| f.datatype.clone()).collect(),
+                )?;
+                schema_ent.create_table(
+                    &tx,
+                    &CreateTableInfo {
+                        name: table.name.clone(),
+                        columns: table.columns.clone(),
+                        unique_constraints: table.unique_constraints.clone(),
+                        on_conflict: OnConflict::Error,
+                    },
+                    table.table_id,
+                )?;
+
+                let mut state = datatable.init_append_state(&tx);
+                for segment in &table.segments {
+                    let mut batch = Batch::new(datatable.datatypes().to_vec(), segment.num_rows)?;
+                    reader.read_segment(segment, &mut batch)?;
+                    datatable.append_batch(&mut state, &batch)?;
+                }
+                datatable.flush(&mut state)?;
+            }
+
+            for view in &schema.views {
+                schema_ent.create_view(
+                    &tx,
+                    &CreateViewInfo {
+                        name: view.name.clone(),
+                        column_aliases: view.column_aliases.clone(),
+                        on_conflict: OnConflict::Error,
+                        query_string: view.query_sql.clone(),
+                    },
+                )?;
+            }
+        }
+
+        Ok(reader.generation())
+    }
+
+    /// Replay a record from the WAL.
+    ///
+    /// Only changes from committed transactions are applied.
+    fn replay_record(
+        self: &Arc<Self>,
+        committed: &HashSet<u64>,
+        transactions: &Arc<TransactionManager>,
+        record: WalRecord,
+    ) -> Result<()> {
+        let is_committed = committed.contains(&record.transaction_id());
+
+        match record {
+            WalRecord::Catalog { record, .. } => {
+                if is_committed {
+                    self.apply_catalog_record(&record)?;
+                }
+                Ok(())
+            }
+            WalRecord::Append {
+                table_id,
+                num_rows,
+                data,
+                ..
+            } => {
+                // Table may have been created by a CREATE TABLE that failed
+                // after the id was assigned, skip.
+                let datatable = match self.storage.get_table(table_id) {
+                    Ok(table) => table,
+                    Err(_) => return Ok(()),
+                };
+
+                let mut batch = Batch::new(datatable.datatypes().to_vec(), num_rows)?;
+                encode::decode_batch(data, num_rows, &mut batch)?;
+
+                // Rows from uncommitted transactions are still appended so
+                // that row ids in later records line up, but are rolled back
+                // right away.
+                let tx = if is_committed {
+                    Arc::new(Transaction::system())
+                } else {
+                    transactions.begin()
+                };
+
+                let mut state = datatable.init_append_state(&tx);
+                datatable.append_batch(&mut state, &batch)?;
+                datatable.flush(&mut state)?;
+
+                if !is_committed {
+                    tx.rollback()?;
+                }
+
+                Ok(())
+            }
+            WalRecord::Delete {
+                table_id, row_ids, ..
+            } => {
+                if !is_committed {
+                    return Ok(());
+                }
+
+                let datatable = match self.storage.get_table(table_id) {
+                    Ok(table) => table,
+                    Err(_) => return Ok(()),
+                };
+
+                let mut state = datatable.init_delete_state(&Arc::new(Transaction::system()));
+                datatable.delete_rows(&mut state, &row_ids)?;
+                Ok(())
+            }
+            WalRecord::Commit { .. } => Ok(()),
+        }
+    }
+
+    /// Apply a catalog change without logging it.
+    fn apply_catalog_record(self: &Arc<Self>, record: &CatalogRecord) -> Result<()> {
+        let tx = Transaction::system();
+        match record {
+            CatalogRecord::CreateSchema(info) => {
+                self.catalog.create_schema(&tx, info)?;
+            }
+            CatalogRecord::CreateTable {
+                schema,
+                info,
+                table_id,
+            } => {
+                let datatypes = info.columns.iter().map(|f| f.datatype.clone()).collect();
+                self.create_datatable(*table_id, datatypes)?;
+                self.catalog
+                    .require_get_schema(&tx, schema)?
+                    .create_table(&tx, info, *table_id)?;
+            }
+            CatalogRecord::CreateView { schema, info } => {
+                self.catalog
+                    .require_get_schema(&tx, schema)?
+                    .create_view(&tx, info)?;
+            }
+            CatalogRecord::Drop(info) => {
+                if let Some(ent) = self.catalog.drop_entry(&tx, info)?
+                    && let CatalogEntryInner::Table(table) = &ent.entry
+                    && let TableStorage::Managed(id) = &table.storage
+                {
+                    self.storage.drop_table(&tx, *id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn create_datatable(
+        self: &Arc<Self>,
+        id: StorageTableId,
+        datatypes: Vec<DataType>,
+    ) -> Result<Arc<DataTable>> {
+        let log = Arc::new(TableLog {
+            id,
+            db: Arc::downgrade(self),
+        });
+        let table = Arc::new(StorageManager::new_datatable_with_log(datatypes, log));
+        self.storage.insert_table_with_id(id, table.clone())?;
+        Ok(table)
+    }
+
+    /// Track a transaction writing to this database.
+    ///
+    /// The transaction will write a commit record to the WAL when it commits.
+    fn register_transaction(self: &Arc<Self>, tx: &Transaction) {
+        if self.state.lock().loading {
+            return;
+        }
+        if tx.add_log(self.clone()) {
+            self.state.lock().active_transactions += 1;
+        }
+    }
+
+    /// Write a catalog record to the WAL.
+    ///
+    /// The state lock should be held while applying the change to the catalog
+    /// and logging it so that changes are logged in the order they're
+    /// applied.
+    fn log_catalog(
+        &self,
+        state: &mut FileState,
+        tx: &Transaction,
+        record: CatalogRecord,
+    ) -> Result<()> {
+        state.require_wal()?.write_catalog(tx.id(), &record)?;
+        self.maybe_checkpoint(state);
+        Ok(())
+    }
+
+    /// Checkpoint if the WAL is large enough and there's no active
+    /// transactions.
+    ///
+    /// The change that triggered this has already been logged, so errors are
+    /// logged instead of returned.
+    fn maybe_checkpoint(&self, state: &mut FileState) {
+        let wal_size = state.wal.as_ref().map(|wal| wal.size()).unwrap_or(0);
+        if state.loading || state.active_transactions > 0 || wal_size < CHECKPOINT_WAL_SIZE {
+            return;
+        }
+        if let Err(e) = self.checkpoint(state) {
+            warn!(%e, path = %self.path, "failed to checkpoint database file");
+        }
+    }
+
+    /// Write a new checkpoint containing all tables and views, and start a new
+    /// WAL.
+    ///
+    /// There's no rename available on all filesystems, so the new checkpoint
+    /// is written to a separate file first before being copied over the main
+    /// file. A WAL is only replayed if its generation matches the checkpoint,
+    /// so failing at any point leaves us with either the old checkpoint and
+    /// its WAL, or the new checkpoint.
+    fn checkpoint(&self, state: &mut FileState) -> Result<()> {
+        let generation = state.generation + 1;
+        let checkpoint_path = self.checkpoint_path();
+
+        let mut writer = CheckpointWriter::create(&self.filesystem, &checkpoint_path)?;
+        let (snapshot, compacted) = self.write_snapshot(&mut writer)?;
+        writer.finish(&snapshot, generation)?;
+
+        // Checkpoint complete, start a new WAL. If this fails, the old WAL
+        // can't be written to anymore since it may have been truncated.
+        state.wal = None;
+        let wal = WalWriter::create(&self.filesystem, &self.wal_path(), generation)?;
+        state.wal = Some(wal);
+        state.generation = generation;
+        state.compacted = compacted;
+
+        self.copy_file(&checkpoint_path, &self.path)?;
+        complete(self.filesystem.delete(&checkpoint_path))?;
+
+        Ok(())
+    }
+
+    /// Write all committed live rows to the checkpoint, returning the catalog
+    /// snapshot pointing to that data along with the deleted row ids for each
+    /// table.
+    fn write_snapshot(
+        &self,
+        writer: &mut CheckpointWriter,
+    ) -> Result<(CatalogSnapshot, HashMap<StorageTableId, Vec<usize>>)> {
+        let mut snapshot = CatalogSnapshot::default();
+        let mut compacted = HashMap::new();
+
+        let tx = Transaction::system();
+        let schemas = complete(self.catalog.list_schemas(&tx).try_concat())?;
+        for schema in schemas {
+            let mut schema_snapshot = SchemaSnapshot {
+                name: schema.as_entry().name.clone(),
+                tables: Vec::new(),
+                views: Vec::new(),
+            };
+
+            let entries = complete(schema.list_entries(&tx).try_concat())?;
+            for ent in entries {
+                match &ent.entry {
+                    CatalogEntryInner::Table(table) => {
+                        let table_id = match &table.storage {
+                            TableStorage::Managed(id) => *id,
+                            TableStorage::External { .. } => continue,
+                        };
+                        let datatable = self.storage.get_table(table_id)?;
+
+                        let projections = Projections::new(0..table.columns.len());
+                        let mut scan_state = datatable.init_scan_state(tx.snapshot());
+                        let mut batch =
+                            Batch::new(datatable.datatypes().to_vec(), DEFAULT_BATCH_SIZE)?;
+
+                        let mut segments = Vec::new();
+                        loop {
+                            batch.reset_for_write()?;
+                            let count =
+                                datatable.scan(&projections, &mut scan_state, &mut batch)?;
+                            if count == 0 {
+                                break;
+                            }
+                            segments.push(writer.write_segment(&batch)?);
+                        }
+
+                        let deleted = datatable.deleted_row_ids();
+                        if !deleted.is_empty() {
+                            compacted.insert(table_id, deleted);
+                        }
+
+                        schema_snapshot.tables.push(TableSnapshot {
+                            name: ent.name.clone(),
+                            columns: table.columns.clone(),
+                            unique_constraints: table.unique_constraints.clone(),
+                            table_id,
+                            segments,
+                        });
+                    }
+                    CatalogEntryInner::View(view) => {
+                        schema_snapshot.views.push(ViewSnapshot {
+                            name: ent.name.clone(),
+                            column_aliases: view.column_aliases.clone(),
+                            query_sql: view.query_sql.clone(),
+                        });
+                    }
+                    _ => (),
+                }
+            }
+
+            snapshot.schemas.push(schema_snapshot);
+        }
+
+        Ok((snapshot, compacted))
+    }
+
+    fn read_file(&self, path: &str) -> Result<Vec<u8>> {
+        let mut file = complete(self.filesystem.open(OpenFlags::READ, path))?;
+        let mut buf = vec![0; file.call_size()];
+        complete(file.call_read_exact(&mut buf))?;
+        Ok(buf)
+    }
+
+    fn copy_file(&self, from: &str, to: &str) -> Result<()> {
+        let mut src = complete(self.filesystem.open(OpenFlags::READ, from))?;
+        let flags = OpenFlags::new([OpenFlags::WRITE, OpenFlags::CREATE, OpenFlags::TRUNCATE]);
+        let mut dest = complete(self.filesystem.open(flags, to))?;
+
+        let mut buf = vec![0; COPY_BUF_SIZE];
+        loop {
+            let n = complete(src.call_read(&mut buf))?;
+            if n == 0 {
+                break;
+            }
+            write_all(&mut dest, &buf[..n])?;
+        }
+
+        sync(&mut dest)
+    }
+}
+
+/// Get the ids of all transactions with a commit record in the WAL.
+///
+/// Changes made with the system transaction are always considered committed.
+fn committed_transactions(mut reader: WalReader) -> Result<HashSet<u64>> {
+    let mut committed = HashSet::from([SYSTEM_VERSION]);
+    while let Some(record) = reader.next_record()? {
+        if let WalRecord::Commit { transaction_id } = record {
+            committed.insert(transaction_id);
+        }
+    }
+    Ok(committed)
+}
+
+impl Drop for DatabaseFile {
+    fn drop(&mut self) {
+        // Fold the WAL into the checkpoint so the next open doesn't need to
+        // replay it.
+        let mut state = self.state.lock();
+        let has_records = state.wal.as_ref().is_some_and(|wal| wal.has_records());
+        if !has_records || state.active_transactions > 0 {
+            return;
+        }
+        if let Err(e) = self.checkpoint(&mut state) {
+            warn!(%e, path = %self.path, "failed to checkpoint database file on close");
+        }
+    }
+}
+
+/// Writes changes for a single table to the WAL.
+#[derive(Debug)]
+struct TableLog {
+    id: StorageTableId,
+    /// Weak to avoid a cycle with the storage manager holding the table.
+    db: Weak<DatabaseFile>,
+}
+
+impl WriteLog for TableLog {
+    fn begin_transaction(&self, tx: &Transaction) {
+        if let Some(db) = self.db.upgrade() {
+            db.register_transaction(tx);
+        }
+    }
+
+    fn log_append(&self, transaction_id: u64, batch: &Batch) -> Result<()> {
+        let db = self
+            .db
+            .upgrade()
+            .ok_or_else(|| DbError::new("Database file has been closed"))?;
+        let mut state = db.state.lock();
+        if state.loading || batch.num_rows() == 0 {
+            return Ok(());
+        }
+        state
+            .require_wal()?
+            .write_append(transaction_id, self.id, batch)
+    }
+
+    fn log_delete(&self, transaction_id: u64, row_ids: &[usize]) -> Result<()> {
+        let db = self
+            .db
+            .upgrade()
+            .ok_or_else(|| DbError::new("Database file has been closed"))?;
+        let mut state = db.state.lock();
+        if state.loading || row_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Rows removed by the checkpoint are already deleted and aren't in
+        // the file, skip them.
+        let row_ids: Vec<_> = match state.compacted.get(&self.id) {
+            Some(compacted) => row_ids
+                .iter()
+                .filter_map(|&row_id| match compacted.binary_search(&row_id) {
+                    Ok(_) => None,
+                    Err(shift) => Some(row_id - shift),
+                })
+                .collect(),
+            None => row_ids.to_vec(),
+        };
+        state
+            .require_wal()?
+            .write_delete(transaction_id, self.id, &row_ids)
+    }
+}
+
+impl TransactionLog for DatabaseFile {
+    fn log_commit(&self, transaction_id: u64) -> Result<()> {
+        let mut state = self.state.lock();
+        let wal = state.require_wal()?;
+        wal.write_commit(transaction_id)?;
+        wal.sync()
+    }
+
+    fn end_transaction(&self, _transaction_id: u64) {
+        let mut state = self.state.lock();
+        state.active_transactions -= 1;
+        self.maybe_checkpoint(&mut state);
+    }
+}
+
+/// Catalog for a database file.
+#[derive(Debug)]
+pub struct PersistentCatalog {
+    db: Arc<DatabaseFile>,
+}
+
+impl PersistentCatalog {
+    fn wrap_schema(&self, inner: Arc<dyn Schema>) -> Arc<dyn Schema> {
+        Arc::new(PersistentSchema {
+            db: self.db.clone(),
+            inner,
+        })
+    }
+}
+
+impl Catalog for PersistentCatalog {
+    fn create_schema(
+        &self,
+        tx: &Transaction,
+        create: &CreateSchemaInfo,
+    ) -> Result<Arc<dyn Schema>> {
+        self.db.register_transaction(tx);
+        let mut state = self.db.state.lock();
+        let schema = self.db.catalog.create_schema(tx, create)?;
+        self.db
+            .log_catalog(&mut state, tx, CatalogRecord::CreateSchema(create.clone()))?;
+        Ok(self.wrap_schema(schema))
+    }
+
+    fn get_schema(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<dyn Schema>>> {
+        Ok(self
+            .db
+            .catalog
+            .get_schema(tx, name)?
+            .map(|schema| self.wrap_schema(schema)))
+    }
+
+    fn drop_entry(&self, tx: &Transaction, drop: &DropInfo) -> Result<Option<Arc<CatalogEntry>>> {
+        self.db.register_transaction(tx);
+        let mut state = self.db.state.lock();
+        let ent = self.db.catalog.drop_entry(tx, drop)?;
+        if ent.is_some() {
+            self.db
+                .log_catalog(&mut state, tx, CatalogRecord::Drop(drop.clone()))?;
+        }
+        Ok(ent)
+    }
+
+    fn list_schemas(&self, tx: &Transaction) -> CatalogStream<Arc<dyn Schema>> {
+        let db = self.db.clone();
+        Box::pin(self.db.catalog.list_schemas(tx).map_ok(move |schemas| {
+            schemas
+                .into_iter()
+                .map(|inner| {
+                    Arc::new(PersistentSchema {
+                        db: db.clone(),
+                        inner,
+                    }) as Arc<dyn Schema>
+                })
+                .collect()
+        }))
+    }
+}
+
+/// Schema in a database file.
+///
+/// Tables and views are logged on create. Functions are only kept in memory.
+#[derive(Debug)]
+pub struct PersistentSchema {
+    db: Arc<DatabaseFile>,
+    inner: Arc<dyn Schema>,
+}
+
+impl PersistentSchema {
+    fn schema_name(&self) -> String {
+        self.inner.as_entry().name.clone()
+    }
+}
+
+impl Schema for PersistentSchema {
+    fn as_entry(&self) -> Arc<CatalogEntry> {
+        self.inner.as_entry()
+    }
+
+    fn create_table(
+        &self,
+        tx: &Transaction,
+        create: &CreateTableInfo,
+        storage_id: StorageTableId,
+    ) -> Result<Arc<CatalogEntry>> {
+        self.db.register_transaction(tx);
+        let mut state = self.db.state.lock();
+        let ent = self.inner.create_table(tx, create, storage_id)?;
+        self.db.log_catalog(
+            &mut state,
+            tx,
+            CatalogRecord::CreateTable {
+                schema: self.schema_name(),
+                info: create.clone(),
+                table_id: storage_id,
+            },
+        )?;
+        Ok(ent)
+    }
+
+    fn create_external_table(
+        &self,
+        _tx: &Transaction,
+        _create: &CreateExternalTableInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        Err(DbError::new(
+            "External tables cannot be created in database files",
+        ))
+    }
+
+    fn create_view(&self, tx: &Transaction, create: &CreateViewInfo) -> Result<Arc<CatalogEntry>> {
+        self.db.register_transaction(tx);
+        let mut state = self.db.state.lock();
+        let ent = self.inner.create_view(tx, create)?;
+        self.db.log_catalog(
+            &mut state,
+            tx,
+            CatalogRecord::CreateView {
+                schema: self.schema_name(),
+                info: create.clone(),
+            },
+        )?;
+        Ok(ent)
+    }
+
+    fn create_scalar_function(
+        &self,
+        create: &CreateScalarFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        self.inner.create_scalar_function(create)
+    }
+
+    fn create_aggregate_function(
+        &self,
+        create: &CreateAggregateFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        self.inner.create_aggregate_function(create)
+    }
+
+    fn create_window_function(
+        &self,
+        create: &CreateWindowFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        self.inner.create_window_function(create)
+    }
+
+    fn create_table_function(&self, create: &CreateTableFunctionInfo) -> Result<Arc<CatalogEntry>> {
+        self.inner.create_table_function(create)
+    }
+
+    fn create_copy_to_function(
+        &self,
+        create: &CreateCopyToFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        self.inner.create_copy_to_function(create)
+    }
+
+    fn get_table_or_view(&self, tx: &Transaction, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_table_or_view(tx, name)
+    }
+
+    fn get_table_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_table_function(name)
+    }
+
+    fn get_inferred_table_function(&self, path: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_inferred_table_function(path)
+    }
+
+    fn get_copy_to_function(&self, format: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_copy_to_function(format)
+    }
+
+    fn get_inferred_copy_to_function(&self, path: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_inferred_copy_to_function(path)
+    }
+
+    fn get_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_function(name)
+    }
+
+    fn get_scalar_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_scalar_function(name)
+    }
+
+    fn get_aggregate_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_aggregate_function(name)
+    }
+
+    fn get_window_function(&self, name: &str) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.get_window_function(name)
+    }
+
+    fn find_similar_entry(
+        &self,
+        tx: &Transaction,
+        entry_types: &[CatalogEntryType],
+        name: &str,
+    ) -> Result<Option<Arc<CatalogEntry>>> {
+        self.inner.find_similar_entry(tx, entry_types, name)
+    }
+
+    fn list_entries(&self, tx: &Transaction) -> CatalogStream<Arc<CatalogEntry>> {
+        self.inner.list_entries(tx)
+    }
+
+    fn list_tables(&self, tx: &Transaction) -> CatalogStream<Arc<CatalogEntry>> {
+        self.inner.list_tables(tx)
+    }
+}
+
+/// Storage for tables in a database file.
+#[derive(Debug)]
+pub struct PersistentStorage {
+    db: Arc<DatabaseFile>,
+}
+
+impl Storage for PersistentStorage {
+    fn create_table(
+        &self,
+        tx: &Transaction,
+        datatypes: &[DataType],
+    ) -> Result<(StorageTableId, Arc<DataTable>)> {
+        if encode::check_spill_types(datatypes).is_err() {
+            return Err(DbError::new(
+                "Nested types are not yet supported in database files",
+            ));
+        }
+
+        let id = self.db.storage.next_table_id();
+        let table = self.db.create_datatable(id, datatypes.to_vec())?;
+        self.db.storage.remove_on_rollback(tx, id);
+
+        Ok((id, table))
+    }
+
+    fn drop_table(&self, tx: &Transaction, id: StorageTableId) -> Result<()> {
+        // Logged as part of dropping the catalog entry.
+        self.db.storage.remove_on_commit(tx, id)
+    }
+
+    fn get_table(&self, id: StorageTableId) -> Result<Arc<DataTable>> {
+        self.db.storage.get_table(id)
+    }
+}
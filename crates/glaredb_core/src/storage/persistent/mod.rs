@@ -0,0 +1,101 @@
+//! Persistent database files.
+//!
+//! A database file is made up of three files on disk:
+//!
+//! - `<path>`: The last checkpoint, containing the catalog and all table data.
+//! - `<path>.wal`: Changes made since the last checkpoint.
+//! - `<path>.checkpoint`: A new checkpoint being written, only exists while
+//!   checkpointing.
+//!
+//! On open, the checkpoint is loaded into memory and the WAL is replayed on
+//! top of it. Catalog changes, appends, and deletes are written to the WAL as
+//! they happen, followed by a commit record once the transaction making them
+//! commits. Changes from transactions without a commit record are discarded
+//! on replay. Once the WAL grows large enough (or the database is detached),
+//! a new checkpoint is written and the WAL is reset. Checkpoints only contain
+//! committed live rows, deleted rows are dropped.
+//!
+//! The WAL is synced to disk (`fdatasync`) before a commit returns, so
+//! committed changes survive both process and machine crashes. Checkpoints are
+//! synced before the WAL is reset.
+//!
+//! All file operations are blocking, so the database file must be on a
+//! synchronous filesystem (e.g. the local filesystem).
+//!
+//! A database file should only be opened by a single database at a time.
+//! There's no locking to prevent multiple processes from opening the same
+//! file.
+pub mod checkpoint;
+pub mod database;
+pub mod wal;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use database::DatabaseFile;
+use glaredb_error::{DbError, Result};
+
+use crate::arrays::scalar::ScalarValue;
+use crate::catalog::database::AccessMode;
+use crate::catalog::datasource::{AttachedDatabase, ConnectContext, DataSource, DataSourceFuture};
+use crate::runtime::filesystem::FileOpenContext;
+
+/// Name of the built-in datasource for database files.
+pub const GLARE_DATASOURCE_NAME: &str = "glaredb";
+
+/// Extension used for database files.
+pub const GLARE_FILE_EXTENSION: &str = ".glare";
+
+/// Datasource for attaching a database file, creating it if it doesn't exist.
+///
+/// ```sql
+/// ATTACH 'mydb.glare';
+/// CREATE TABLE mydb.main.t1 AS SELECT * FROM generate_series(1, 10);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlareDataSource;
+
+impl GlareDataSource {
+    async fn connect_inner(
+        &self,
+        context: ConnectContext<'_>,
+        options: &HashMap<String, ScalarValue>,
+    ) -> Result<AttachedDatabase> {
+        let path = options
+            .get("path")
+            .ok_or_else(|| DbError::new("Missing path for database file"))?
+            .try_as_str()?;
+        if let Some(key) = options.keys().find(|key| key.as_str() != "path") {
+            return Err(DbError::new(format!(
+                "Unknown option for database file: '{key}'"
+            )));
+        }
+
+        let filesystem = context
+            .dispatch
+            .filesystem_for_path(path)?
+            .load_state(FileOpenContext::new(
+                context.database_context,
+                &HashMap::new(),
+            ))
+            .await?;
+
+        let db = DatabaseFile::open(filesystem, path)?;
+
+        Ok(AttachedDatabase {
+            mode: AccessMode::ReadWrite,
+            catalog: Arc::new(db.catalog()),
+            storage: Arc::new(db.storage()),
+        })
+    }
+}
+
+impl DataSource for GlareDataSource {
+    fn connect<'a>(
+        &'a self,
+        context: ConnectContext<'a>,
+        options: &'a HashMap<String, ScalarValue>,
+    ) -> DataSourceFuture<'a, Result<AttachedDatabase>> {
+        Box::pin(self.connect_inner(context, options))
+    }
+}
@@ -0,0 +1,500 @@
+//! Write-ahead log for database files.
+//!
+//! The WAL starts with a header containing the generation of the checkpoint
+//! it applies to, followed by a sequence of records:
+//!
+//! ```text
+//! [magic: 8 bytes][generation: u64]
+//! [len: u32][crc32: u32][payload: len bytes]
+//! ...
+//! ```
+//!
+//! Each payload starts with the record kind followed by the id of the
+//! transaction that made the change. Catalog records are serialized as json,
+//! appends contain the table id, row count, and the rows encoded with the
+//! spill encoding. Deletes contain the table id, row count, and the row ids of
+//! the deleted rows. Commit records have no body.
+//!
+//! Changes from a transaction only apply once a commit record for that
+//! transaction has been written. Changes made with the system transaction are
+//! always applied.
+
+use glaredb_error::{DbError, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::arrays::batch::Batch;
+use crate::catalog::create::{CreateSchemaInfo, CreateTableInfo, CreateViewInfo};
+use crate::catalog::drop::DropInfo;
+use crate::execution::spill::encode;
+use crate::runtime::filesystem::blocking::{complete, sync, write_all};
+use crate::runtime::filesystem::{AnyFile, FileSystemWithState, OpenFlags};
+use crate::storage::storage_manager::StorageTableId;
+
+pub const WAL_MAGIC: &[u8; 8] = b"GLAREWAL";
+
+/// Size of the WAL header (magic + generation).
+pub const WAL_HEADER_SIZE: usize = 8 + 8;
+
+/// Size of the header for each record (length + checksum).
+const RECORD_HEADER_SIZE: usize = 4 + 4;
+
+const RECORD_KIND_CATALOG: u8 = 0;
+const RECORD_KIND_APPEND: u8 = 1;
+const RECORD_KIND_DELETE: u8 = 2;
+const RECORD_KIND_COMMIT: u8 = 3;
+
+/// Size of the record kind and transaction id at the start of each payload.
+const PAYLOAD_PREFIX_SIZE: usize = 1 + 8;
+
+/// A change to the catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CatalogRecord {
+    CreateSchema(CreateSchemaInfo),
+    CreateTable {
+        schema: String,
+        info: CreateTableInfo,
+        table_id: StorageTableId,
+    },
+    CreateView {
+        schema: String,
+        info: CreateViewInfo,
+    },
+    Drop(DropInfo),
+}
+
+/// A record read from the WAL.
+#[derive(Debug, PartialEq, Eq)]
+pub enum WalRecord<'a> {
+    Catalog {
+        transaction_id: u64,
+        record: CatalogRecord,
+    },
+    /// Rows appended to a table.
+    ///
+    /// Data is encoded with the spill encoding, and needs the table's types to
+    /// decode.
+    Append {
+        transaction_id: u64,
+        table_id: StorageTableId,
+        num_rows: usize,
+        data: &'a [u8],
+    },
+    /// Rows deleted from a table.
+    ///
+    /// Row ids are relative to the table as loaded from the checkpoint and
+    /// replayed up to this record.
+    Delete {
+        transaction_id: u64,
+        table_id: StorageTableId,
+        row_ids: Vec<usize>,
+    },
+    /// A transaction committed.
+    Commit { transaction_id: u64 },
+}
+
+impl WalRecord<'_> {
+    /// Id of the transaction that wrote this record.
+    pub fn transaction_id(&self) -> u64 {
+        match self {
+            Self::Catalog { transaction_id, .. }
+            | Self::Append { transaction_id, .. }
+            | Self::Delete { transaction_id, .. }
+            | Self::Commit { transaction_id } => *transaction_id,
+        }
+    }
+}
+
+/// Appends records to a WAL file.
+#[derive(Debug)]
+pub struct WalWriter {
+    file: AnyFile,
+    /// Total size of the WAL including the header.
+    size: usize,
+    /// Reusable buffer for encoding records.
+    buf: Vec<u8>,
+}
+
+impl WalWriter {
+    /// Create a new WAL for the given checkpoint generation, truncating the
+    /// file if it exists.
+    pub fn create(filesystem: &FileSystemWithState, path: &str, generation: u64) -> Result<Self> {
+        let flags = OpenFlags::new([OpenFlags::WRITE, OpenFlags::CREATE, OpenFlags::TRUNCATE]);
+        let mut file = complete(filesystem.open(flags, path))?;
+
+        let mut header = Vec::with_capacity(WAL_HEADER_SIZE);
+        header.extend_from_slice(WAL_MAGIC);
+        header.extend_from_slice(&generation.to_le_bytes());
+        write_all(&mut file, &header)?;
+        sync(&mut file)?;
+
+        Ok(WalWriter {
+            file,
+            size: WAL_HEADER_SIZE,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Open an existing WAL for appending records.
+    ///
+    /// `size` should be the end of the last valid record in the file.
+    pub fn open_append(filesystem: &FileSystemWithState, path: &str, size: usize) -> Result<Self> {
+        let flags = OpenFlags::new([OpenFlags::WRITE]);
+        let mut file = complete(filesystem.open(flags, path))?;
+        complete(file.call_seek(std::io::SeekFrom::Start(size as u64)))?;
+
+        Ok(WalWriter {
+            file,
+            size,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Total size of the WAL in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns if any records have been written to the WAL.
+    pub fn has_records(&self) -> bool {
+        self.size > WAL_HEADER_SIZE
+    }
+
+    pub fn write_catalog(&mut self, transaction_id: u64, record: &CatalogRecord) -> Result<()> {
+        self.begin_record(RECORD_KIND_CATALOG, transaction_id);
+        serde_json::to_writer(&mut self.buf, record).context("Failed to serialize WAL record")?;
+        self.finish_record()
+    }
+
+    pub fn write_append(
+        &mut self,
+        transaction_id: u64,
+        table_id: StorageTableId,
+        batch: &Batch,
+    ) -> Result<()> {
+        self.begin_record(RECORD_KIND_APPEND, transaction_id);
+        self.buf
+            .extend_from_slice(&(table_id.as_usize() as u64).to_le_bytes());
+        self.buf
+            .extend_from_slice(&(batch.num_rows() as u32).to_le_bytes());
+        encode::encode_batch(batch, &mut self.buf)?;
+        self.finish_record()
+    }
+
+    pub fn write_delete(
+        &mut self,
+        transaction_id: u64,
+        table_id: StorageTableId,
+        row_ids: &[usize],
+    ) -> Result<()> {
+        self.begin_record(RECORD_KIND_DELETE, transaction_id);
+        encode_delete(&mut self.buf, table_id, row_ids);
+        self.finish_record()
+    }
+
+    pub fn write_commit(&mut self, transaction_id: u64) -> Result<()> {
+        self.begin_record(RECORD_KIND_COMMIT, transaction_id);
+        self.finish_record()
+    }
+
+    /// Flush written records and sync them to disk.
+    pub fn sync(&mut self) -> Result<()> {
+        sync(&mut self.file)
+    }
+
+    fn begin_record(&mut self, kind: u8, transaction_id: u64) {
+        self.buf.clear();
+        self.buf.resize(RECORD_HEADER_SIZE, 0);
+        self.buf.push(kind);
+        self.buf.extend_from_slice(&transaction_id.to_le_bytes());
+    }
+
+    fn finish_record(&mut self) -> Result<()> {
+        let payload = &self.buf[RECORD_HEADER_SIZE..];
+        let len = u32::try_from(payload.len())
+            .map_err(|_| DbError::new("WAL record too large").with_field("len", payload.len()))?;
+        let checksum = crc32fast::hash(payload);
+
+        self.buf[0..4].copy_from_slice(&len.to_le_bytes());
+        self.buf[4..8].copy_from_slice(&checksum.to_le_bytes());
+
+        write_all(&mut self.file, &self.buf)?;
+        self.size += self.buf.len();
+
+        Ok(())
+    }
+}
+
+/// Reads records from a WAL that's been read into memory.
+#[derive(Debug, Clone)]
+pub struct WalReader<'a> {
+    buf: &'a [u8],
+    offset: usize,
+    generation: u64,
+}
+
+impl<'a> WalReader<'a> {
+    /// Create a new reader for the WAL, returning None if the WAL header isn't
+    /// valid.
+    pub fn try_new(buf: &'a [u8]) -> Option<Self> {
+        if buf.len() < WAL_HEADER_SIZE || &buf[0..8] != WAL_MAGIC {
+            return None;
+        }
+        let generation = u64::from_le_bytes(buf[8..16].try_into().unwrap());
+
+        Some(WalReader {
+            buf,
+            offset: WAL_HEADER_SIZE,
+            generation,
+        })
+    }
+
+    /// Generation of the checkpoint this WAL applies to.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Offset of the end of the last record read.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns if the entire WAL has been read.
+    ///
+    /// If reading stops before the end, the WAL contained a partially written
+    /// or corrupt record.
+    pub fn is_exhausted(&self) -> bool {
+        self.offset == self.buf.len()
+    }
+
+    /// Read the next record.
+    ///
+    /// Returns Ok(None) if there's no more records, or if the next record is
+    /// incomplete or fails its checksum. Everything after a bad record is
+    /// ignored since a record is only written once all previous records have
+    /// been written.
+    pub fn next_record(&mut self) -> Result<Option<WalRecord<'a>>> {
+        let rem = &self.buf[self.offset..];
+        if rem.len() < RECORD_HEADER_SIZE {
+            return Ok(None);
+        }
+
+        let len = u32::from_le_bytes(rem[0..4].try_into().unwrap()) as usize;
+        let checksum = u32::from_le_bytes(rem[4..8].try_into().unwrap());
+        let payload = match rem.get(RECORD_HEADER_SIZE..RECORD_HEADER_SIZE + len) {
+            Some(payload) if !payload.is_empty() => payload,
+            _ => return Ok(None),
+        };
+        if crc32fast::hash(payload) != checksum {
+            return Ok(None);
+        }
+        if payload.len() < PAYLOAD_PREFIX_SIZE {
+            return Err(DbError::new("WAL record too short"));
+        }
+
+        let transaction_id = u64::from_le_bytes(payload[1..9].try_into().unwrap());
+        let body = &payload[PAYLOAD_PREFIX_SIZE..];
+
+        let record = match payload[0] {
+            RECORD_KIND_CATALOG => {
+                let record =
+                    serde_json::from_slice(body).context("Failed to deserialize WAL record")?;
+                WalRecord::Catalog {
+                    transaction_id,
+                    record,
+                }
+            }
+            RECORD_KIND_APPEND => {
+                if body.len() < 8 + 4 {
+                    return Err(DbError::new("WAL append record too short"));
+                }
+                let table_id = u64::from_le_bytes(body[0..8].try_into().unwrap());
+                let num_rows = u32::from_le_bytes(body[8..12].try_into().unwrap());
+                WalRecord::Append {
+                    transaction_id,
+                    table_id: StorageTableId::from_usize(table_id as usize),
+                    num_rows: num_rows as usize,
+                    data: &body[12..],
+                }
+            }
+            RECORD_KIND_DELETE => {
+                if body.len() < 8 + 4 {
+                    return Err(DbError::new("WAL delete record too short"));
+                }
+                let table_id = u64::from_le_bytes(body[0..8].try_into().unwrap());
+                let num_rows = u32::from_le_bytes(body[8..12].try_into().unwrap()) as usize;
+                let data = &body[12..];
+                if data.len() != num_rows * 8 {
+                    return Err(DbError::new("Invalid WAL delete record length")
+                        .with_field("num_rows", num_rows)
+                        .with_field("len", data.len()));
+                }
+                let row_ids = data
+                    .chunks_exact(8)
+                    .map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize)
+                    .collect();
+                WalRecord::Delete {
+                    transaction_id,
+                    table_id: StorageTableId::from_usize(table_id as usize),
+                    row_ids,
+                }
+            }
+            RECORD_KIND_COMMIT => WalRecord::Commit { transaction_id },
+            other => {
+                return Err(DbError::new("Unknown WAL record kind").with_field("kind", other));
+            }
+        };
+
+        self.offset += RECORD_HEADER_SIZE + len;
+
+        Ok(Some(record))
+    }
+}
+
+/// Encode the body of a delete record.
+fn encode_delete(buf: &mut Vec<u8>, table_id: StorageTableId, row_ids: &[usize]) {
+    buf.extend_from_slice(&(table_id.as_usize() as u64).to_le_bytes());
+    buf.extend_from_slice(&(row_ids.len() as u32).to_le_bytes());
+    for &row_id in row_ids {
+        buf.extend_from_slice(&(row_id as u64).to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::create::OnConflict;
+    use crate::catalog::drop::DropObject;
+
+    fn encode_header(generation: u64) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(WAL_MAGIC);
+        buf.extend_from_slice(&generation.to_le_bytes());
+        buf
+    }
+
+    fn encode_payload(buf: &mut Vec<u8>, payload: &[u8]) {
+        buf.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&crc32fast::hash(payload).to_le_bytes());
+        buf.extend_from_slice(payload);
+    }
+
+    fn payload_prefix(kind: u8, transaction_id: u64) -> Vec<u8> {
+        let mut payload = vec![kind];
+        payload.extend_from_slice(&transaction_id.to_le_bytes());
+        payload
+    }
+
+    fn encode_records(generation: u64, records: &[CatalogRecord]) -> Vec<u8> {
+        let mut buf = encode_header(generation);
+
+        for record in records {
+            let mut payload = payload_prefix(RECORD_KIND_CATALOG, 0);
+            serde_json::to_writer(&mut payload, record).unwrap();
+            encode_payload(&mut buf, &payload);
+        }
+
+        buf
+    }
+
+    fn test_records() -> Vec<CatalogRecord> {
+        vec![
+            CatalogRecord::CreateSchema(CreateSchemaInfo {
+                name: "s1".to_string(),
+                on_conflict: OnConflict::Error,
+            }),
+            CatalogRecord::Drop(DropInfo {
+                schema: "s1".to_string(),
+                object: DropObject::Table("t1".to_string()),
+                cascade: false,
+                if_exists: true,
+            }),
+        ]
+    }
+
+    fn read_all(reader: &mut WalReader) -> Vec<CatalogRecord> {
+        let mut out = Vec::new();
+        while let Some(record) = reader.next_record().unwrap() {
+            match record {
+                WalRecord::Catalog { record, .. } => out.push(record),
+                other => panic!("unexpected record: {other:?}"),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn read_records() {
+        let records = test_records();
+        let buf = encode_records(4, &records);
+
+        let mut reader = WalReader::try_new(&buf).unwrap();
+        assert_eq!(4, reader.generation());
+        assert_eq!(records, read_all(&mut reader));
+        assert!(reader.is_exhausted());
+    }
+
+    #[test]
+    fn read_torn_record() {
+        let records = test_records();
+        let buf = encode_records(1, &records);
+        let first_len = encode_records(1, &records[0..1]).len();
+
+        // Last record partially written.
+        let torn = &buf[..buf.len() - 3];
+        let mut reader = WalReader::try_new(torn).unwrap();
+        assert_eq!(&records[0..1], read_all(&mut reader));
+        assert_eq!(first_len, reader.offset());
+        assert!(!reader.is_exhausted());
+    }
+
+    #[test]
+    fn read_corrupt_record() {
+        let records = test_records();
+        let mut buf = encode_records(1, &records);
+        let last = buf.len() - 1;
+        buf[last] ^= 0xFF;
+
+        let mut reader = WalReader::try_new(&buf).unwrap();
+        assert_eq!(&records[0..1], read_all(&mut reader));
+        assert!(!reader.is_exhausted());
+    }
+
+    #[test]
+    fn invalid_header() {
+        assert!(WalReader::try_new(b"GLAREWAL").is_none());
+        assert!(WalReader::try_new(b"NOTAWAL\0\0\0\0\0\0\0\0\0").is_none());
+    }
+
+    #[test]
+    fn read_delete_record() {
+        let mut buf = encode_header(1);
+
+        let mut payload = payload_prefix(RECORD_KIND_DELETE, 7);
+        encode_delete(&mut payload, StorageTableId::from_usize(3), &[1, 5, 8]);
+        encode_payload(&mut buf, &payload);
+
+        let mut reader = WalReader::try_new(&buf).unwrap();
+        let record = reader.next_record().unwrap().unwrap();
+        assert_eq!(
+            WalRecord::Delete {
+                transaction_id: 7,
+                table_id: StorageTableId::from_usize(3),
+                row_ids: vec![1, 5, 8],
+            },
+            record
+        );
+        assert!(reader.is_exhausted());
+    }
+
+    #[test]
+    fn read_commit_record() {
+        let mut buf = encode_header(1);
+        encode_payload(&mut buf, &payload_prefix(RECORD_KIND_COMMIT, 12));
+
+        let mut reader = WalReader::try_new(&buf).unwrap();
+        let record = reader.next_record().unwrap().unwrap();
+        assert_eq!(WalRecord::Commit { transaction_id: 12 }, record);
+        assert_eq!(12, record.transaction_id());
+        assert!(reader.is_exhausted());
+    }
+}
@@ -3,46 +3,143 @@ use std::sync::Arc;
 use std::sync::atomic::{self, AtomicUsize};
 
 use glaredb_error::{DbError, Result};
+use serde::{Deserialize, Serialize};
 
-use super::datatable::DataTable;
+use super::Storage;
+use super::datatable::{DataTable, WriteLog};
+use crate::arrays::datatype::DataType;
+use crate::config::session::DEFAULT_BATCH_SIZE;
+use crate::transaction::{Transaction, TransactionChange};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct StorageTableId(usize);
 
+impl StorageTableId {
+    pub const fn from_usize(id: usize) -> Self {
+        StorageTableId(id)
+    }
+
+    pub const fn as_usize(&self) -> usize {
+        self.0
+    }
+}
+
+/// Number of chunks per segment for new tables.
+// TODO: How do we want to configure datatables?
+const TABLE_SEGMENT_SIZE: usize = 16;
+
+type TableMap = scc::HashMap<StorageTableId, Arc<DataTable>>;
+
 #[derive(Debug)]
 pub struct StorageManager {
     next_id: AtomicUsize,
-    tables: scc::HashMap<StorageTableId, Arc<DataTable>>,
+    /// All tables, shared with pending transaction changes that remove tables.
+    tables: Arc<TableMap>,
 }
 
 impl StorageManager {
     pub fn empty() -> Self {
         StorageManager {
             next_id: AtomicUsize::new(0),
-            tables: scc::HashMap::new(),
+            tables: Arc::new(scc::HashMap::new()),
         }
     }
 
-    pub fn insert_table(&self, table: Arc<DataTable>) -> Result<StorageTableId> {
-        let id = self.next_id.fetch_add(1, atomic::Ordering::Relaxed);
-        let id = StorageTableId(id);
+    /// Create a new data table for the given column types.
+    ///
+    /// The table isn't added to storage.
+    pub fn new_datatable(datatypes: impl IntoIterator<Item = DataType>) -> DataTable {
+        DataTable::new(datatypes, TABLE_SEGMENT_SIZE, DEFAULT_BATCH_SIZE)
+    }
 
-        // Failing here shouldn't happen.
+    /// Like `new_datatable`, but with changes written to the given log.
+    pub fn new_datatable_with_log(
+        datatypes: impl IntoIterator<Item = DataType>,
+        log: Arc<dyn WriteLog>,
+    ) -> DataTable {
+        DataTable::new_with_log(datatypes, TABLE_SEGMENT_SIZE, DEFAULT_BATCH_SIZE, log)
+    }
+
+    /// Get the id to use for the next table.
+    pub fn next_table_id(&self) -> StorageTableId {
+        StorageTableId(self.next_id.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+
+    /// Insert a table with a specific id.
+    ///
+    /// Ids for tables inserted later will be greater than this id.
+    pub fn insert_table_with_id(&self, id: StorageTableId, table: Arc<DataTable>) -> Result<()> {
+        self.next_id.fetch_max(id.0 + 1, atomic::Ordering::Relaxed);
         self.tables
             .insert(id, table)
-            .map_err(|_| DbError::new(format!("Table with id already exists: {id:?}")))?;
+            .map_err(|_| DbError::new(format!("Table with id already exists: {id:?}")))
+    }
 
-        Ok(id)
+    /// Remove the table with the given id if the transaction rolls back.
+    pub fn remove_on_rollback(&self, tx: &Transaction, id: StorageTableId) {
+        tx.add_change(Box::new(RemoveTable {
+            tables: self.tables.clone(),
+            id,
+            on_commit: false,
+        }));
     }
 
-    pub fn drop_table(&self, id: StorageTableId) -> Result<()> {
-        if self.tables.remove(&id).is_none() {
+    /// Remove the table with the given id once the transaction commits.
+    pub fn remove_on_commit(&self, tx: &Transaction, id: StorageTableId) -> Result<()> {
+        if !self.tables.contains(&id) {
             return Err(DbError::new(format!("Missing table for id: {id:?}")));
         }
+        tx.add_change(Box::new(RemoveTable {
+            tables: self.tables.clone(),
+            id,
+            on_commit: true,
+        }));
         Ok(())
     }
+}
+
+/// Removes a table when a transaction completes.
+#[derive(Debug)]
+struct RemoveTable {
+    tables: Arc<TableMap>,
+    id: StorageTableId,
+    /// Remove on commit if true, otherwise on rollback.
+    on_commit: bool,
+}
+
+impl TransactionChange for RemoveTable {
+    fn commit(&self, _commit_ts: u64) {
+        if self.on_commit {
+            self.tables.remove(&self.id);
+        }
+    }
+
+    fn rollback(&self) {
+        if !self.on_commit {
+            self.tables.remove(&self.id);
+        }
+    }
+}
+
+impl Storage for StorageManager {
+    fn create_table(
+        &self,
+        tx: &Transaction,
+        datatypes: &[DataType],
+    ) -> Result<(StorageTableId, Arc<DataTable>)> {
+        let id = self.next_table_id();
+        let table = Arc::new(Self::new_datatable(datatypes.iter().cloned()));
+        self.insert_table_with_id(id, table.clone())?;
+        self.remove_on_rollback(tx, id);
+
+        Ok((id, table))
+    }
+
+    fn drop_table(&self, tx: &Transaction, id: StorageTableId) -> Result<()> {
+        self.remove_on_commit(tx, id)
+    }
 
-    pub fn get_table(&self, id: StorageTableId) -> Result<Arc<DataTable>> {
+    fn get_table(&self, id: StorageTableId) -> Result<Arc<DataTable>> {
         let table = self
             .tables
             .get(&id)
@@ -38,3 +38,6 @@ pub mod exprs {
 mod plan_exprs;
 
 pub mod database_context;
+
+/// Spilling helpers.
+pub mod spill;
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::buffer::buffer_manager::{DefaultBufferManager, MemoryLimit};
+use crate::execution::spill::SpillContext;
+use crate::runtime::filesystem::memory::MemoryFileSystem;
+use crate::runtime::filesystem::{AnyFileSystem, AnyState, FileSystemWithState};
+
+/// Create an in-memory filesystem for writing spill files to.
+///
+/// The returned `MemoryFileSystem` shares files with the filesystem, and can
+/// be used to check which files exist.
+pub fn test_spill_filesystem() -> (FileSystemWithState, MemoryFileSystem) {
+    let fs = MemoryFileSystem::new(&DefaultBufferManager);
+    let filesystem = FileSystemWithState {
+        fs: AnyFileSystem::from_filesystem(fs.clone()),
+        state: AnyState(Arc::new(())),
+    };
+
+    (filesystem, fs)
+}
+
+/// Create a spill context with a memory limit of `limit` bytes.
+///
+/// Spill files are written to the root of the returned in-memory filesystem.
+pub fn test_spill_context(limit: usize) -> (Arc<SpillContext>, MemoryFileSystem) {
+    let (filesystem, fs) = test_spill_filesystem();
+    let context = SpillContext::new(MemoryLimit::new(limit), filesystem, "/", "test");
+
+    (Arc::new(context), fs)
+}
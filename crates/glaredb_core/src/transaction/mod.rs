@@ -0,0 +1,525 @@
+//! Transactions and snapshot isolation.
+//!
+//! Every change to a table or the catalog is stamped with a version. While a
+//! transaction is running, its changes are stamped with the transaction id.
+//! On commit, the stamps are replaced with the commit timestamp, on rollback
+//! inserted rows and created entries are stamped as aborted and deletes are
+//! undone.
+//!
+//! Transaction ids start at `TRANSACTION_ID_START` and are always greater than
+//! any commit timestamp, so a snapshot can see a version if it's a commit
+//! timestamp from before the snapshot was taken, or if it's the snapshot's own
+//! transaction id.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicU64};
+
+use glaredb_error::{DbError, Result};
+use parking_lot::Mutex;
+
+/// Version for changes made outside of a transaction, visible to everyone.
+pub const SYSTEM_VERSION: u64 = 0;
+
+/// First id to use for transactions.
+pub const TRANSACTION_ID_START: u64 = 1 << 62;
+
+/// Version for changes from a transaction that was rolled back, visible to no
+/// one.
+pub const ABORTED_VERSION: u64 = u64::MAX - 1;
+
+/// Delete version for rows that haven't been deleted.
+pub const NOT_DELETED: u64 = u64::MAX;
+
+/// Determines which versions of rows and catalog entries a transaction can
+/// see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Versions below this are visible.
+    pub start: u64,
+    /// Id of the transaction reading, its own changes are visible.
+    pub transaction_id: u64,
+}
+
+impl Snapshot {
+    /// Snapshot seeing everything that's been committed.
+    pub const LATEST: Snapshot = Snapshot {
+        start: TRANSACTION_ID_START,
+        transaction_id: SYSTEM_VERSION,
+    };
+
+    /// Snapshot seeing everything except for rolled back changes, including
+    /// changes from transactions that are still in progress.
+    ///
+    /// Used when checking constraints, a row inserted by another transaction
+    /// should still conflict even if we can't see it.
+    pub const fn dirty(transaction_id: u64) -> Self {
+        Snapshot {
+            start: ABORTED_VERSION,
+            transaction_id,
+        }
+    }
+
+    /// Returns if a change stamped with `version` is visible to this snapshot.
+    pub const fn is_visible(&self, version: u64) -> bool {
+        version < self.start || version == self.transaction_id
+    }
+}
+
+/// A change made by a transaction that needs to be finalized when the
+/// transaction completes.
+pub trait TransactionChange: Debug + Sync + Send {
+    /// Stamp the change with the commit timestamp, making it visible to
+    /// transactions started after this one.
+    fn commit(&self, commit_ts: u64);
+
+    /// Undo the change.
+    fn rollback(&self);
+}
+
+/// Log that needs to know when a transaction it's written changes for
+/// completes.
+pub trait TransactionLog: Debug + Sync + Send {
+    /// Record that the transaction committed.
+    ///
+    /// Called before any changes are made visible. Errors cause the
+    /// transaction to be rolled back.
+    fn log_commit(&self, transaction_id: u64) -> Result<()>;
+
+    /// Called once the transaction has committed or rolled back.
+    fn end_transaction(&self, transaction_id: u64);
+}
+
+/// Hands out transaction ids and commit timestamps.
+#[derive(Debug)]
+pub struct TransactionManager {
+    next_transaction_id: AtomicU64,
+    /// Timestamp of the most recent commit.
+    last_commit: AtomicU64,
+    /// Held while committing so that commits are applied in timestamp order.
+    commit_lock: Mutex<()>,
+}
+
+impl Default for TransactionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionManager {
+    pub fn new() -> Self {
+        TransactionManager {
+            next_transaction_id: AtomicU64::new(TRANSACTION_ID_START),
+            last_commit: AtomicU64::new(SYSTEM_VERSION),
+            commit_lock: Mutex::new(()),
+        }
+    }
+
+    /// Begin a new transaction seeing everything committed so far.
+    pub fn begin(self: &Arc<Self>) -> Arc<Transaction> {
+        let id = self
+            .next_transaction_id
+            .fetch_add(1, atomic::Ordering::Relaxed);
+        let start = self.last_commit.load(atomic::Ordering::Acquire) + 1;
+
+        Arc::new(Transaction {
+            id,
+            snapshot: Snapshot {
+                start,
+                transaction_id: id,
+            },
+            manager: Some(self.clone()),
+            state: Mutex::new(TransactionState {
+                status: TransactionStatus::Active,
+                changes: Vec::new(),
+                logs: Vec::new(),
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStatus {
+    Active,
+    /// A statement in the transaction failed, the transaction can only be
+    /// rolled back.
+    Aborted,
+    Committed,
+    RolledBack,
+}
+
+#[derive(Debug)]
+pub struct Transaction {
+    id: u64,
+    snapshot: Snapshot,
+    /// Manager for getting commit timestamps, None for the system transaction.
+    manager: Option<Arc<TransactionManager>>,
+    state: Mutex<TransactionState>,
+}
+
+#[derive(Debug)]
+struct TransactionState {
+    status: TransactionStatus,
+    /// Changes in the order they were made.
+    changes: Vec<Box<dyn TransactionChange>>,
+    /// Logs that changes were written to.
+    logs: Vec<Arc<dyn TransactionLog>>,
+}
+
+impl Transaction {
+    /// Create a transaction for changes that should be visible immediately.
+    ///
+    /// Used when loading and bootstrapping catalogs. Changes are committed as
+    /// they're added, and can't be rolled back.
+    pub fn system() -> Self {
+        Transaction {
+            id: SYSTEM_VERSION,
+            snapshot: Snapshot::LATEST,
+            manager: None,
+            state: Mutex::new(TransactionState {
+                status: TransactionStatus::Active,
+                changes: Vec::new(),
+                logs: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        self.snapshot
+    }
+
+    pub fn status(&self) -> TransactionStatus {
+        self.state.lock().status
+    }
+
+    pub fn is_system(&self) -> bool {
+        self.manager.is_none()
+    }
+
+    /// Add a change to finalize when the transaction completes.
+    ///
+    /// The change is rolled back immediately if the transaction has already
+    /// completed.
+    pub fn add_change(&self, change: Box<dyn TransactionChange>) {
+        if self.is_system() {
+            change.commit(SYSTEM_VERSION);
+            return;
+        }
+
+        let mut state = self.state.lock();
+        match state.status {
+            TransactionStatus::Active | TransactionStatus::Aborted => state.changes.push(change),
+            TransactionStatus::Committed | TransactionStatus::RolledBack => {
+                std::mem::drop(state);
+                change.rollback();
+            }
+        }
+    }
+
+    /// Add a log that needs to be notified when the transaction completes.
+    ///
+    /// Returns true if the log wasn't already added. Logs aren't tracked for
+    /// the system transaction, and false is always returned.
+    pub fn add_log(&self, log: Arc<dyn TransactionLog>) -> bool {
+        if self.is_system() {
+            return false;
+        }
+
+        let mut state = self.state.lock();
+        if state
+            .logs
+            .iter()
+            .any(|existing| std::ptr::addr_eq(Arc::as_ptr(existing), Arc::as_ptr(&log)))
+        {
+            return false;
+        }
+        state.logs.push(log);
+
+        true
+    }
+
+    /// Mark the transaction as aborted after a failed statement.
+    pub fn abort(&self) {
+        let mut state = self.state.lock();
+        if state.status == TransactionStatus::Active {
+            state.status = TransactionStatus::Aborted;
+        }
+    }
+
+    /// Commit the transaction, making its changes visible to transactions
+    /// that start after this.
+    ///
+    /// The transaction is rolled back if it can't be committed.
+    pub fn commit(&self) -> Result<()> {
+        let manager = match &self.manager {
+            Some(manager) => manager,
+            None => return Ok(()),
+        };
+
+        let mut state = self.state.lock();
+        match state.status {
+            TransactionStatus::Active => (),
+            TransactionStatus::Aborted => {
+                std::mem::drop(state);
+                self.rollback()?;
+                return Err(DbError::new(
+                    "Transaction was aborted and has been rolled back",
+                ));
+            }
+            TransactionStatus::Committed | TransactionStatus::RolledBack => {
+                return Err(DbError::new("Transaction has already completed"));
+            }
+        }
+
+        let commit_lock = manager.commit_lock.lock();
+        let commit_ts = manager.last_commit.load(atomic::Ordering::Acquire) + 1;
+
+        for log in &state.logs {
+            if let Err(e) = log.log_commit(self.id) {
+                std::mem::drop(commit_lock);
+                Self::rollback_inner(&mut state, self.id);
+                return Err(e);
+            }
+        }
+
+        for change in state.changes.drain(..) {
+            change.commit(commit_ts);
+        }
+        manager
+            .last_commit
+            .store(commit_ts, atomic::Ordering::Release);
+        std::mem::drop(commit_lock);
+
+        state.status = TransactionStatus::Committed;
+        let logs = std::mem::take(&mut state.logs);
+        std::mem::drop(state);
+
+        for log in logs {
+            log.end_transaction(self.id);
+        }
+
+        Ok(())
+    }
+
+    /// Roll back the transaction, undoing all of its changes.
+    pub fn rollback(&self) -> Result<()> {
+        let mut state = self.state.lock();
+        match state.status {
+            TransactionStatus::Active | TransactionStatus::Aborted => {
+                Self::rollback_inner(&mut state, self.id);
+                Ok(())
+            }
+            TransactionStatus::RolledBack => Ok(()),
+            TransactionStatus::Committed => {
+                Err(DbError::new("Cannot roll back a committed transaction"))
+            }
+        }
+    }
+
+    fn rollback_inner(state: &mut TransactionState, id: u64) {
+        // Undo in reverse so that later changes depending on earlier ones are
+        // undone first.
+        while let Some(change) = state.changes.pop() {
+            change.rollback();
+        }
+        state.status = TransactionStatus::RolledBack;
+
+        for log in std::mem::take(&mut state.logs) {
+            log.end_transaction(id);
+        }
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        let state = self.state.get_mut();
+        if matches!(
+            state.status,
+            TransactionStatus::Active | TransactionStatus::Aborted
+        ) {
+            Self::rollback_inner(state, self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    /// Change recording the version it was finalized with.
+    #[derive(Debug)]
+    struct TestChange {
+        version: Arc<AtomicU64>,
+    }
+
+    impl TransactionChange for TestChange {
+        fn commit(&self, commit_ts: u64) {
+            self.version.store(commit_ts, atomic::Ordering::Relaxed);
+        }
+
+        fn rollback(&self) {
+            self.version
+                .store(ABORTED_VERSION, atomic::Ordering::Relaxed);
+        }
+    }
+
+    fn add_test_change(tx: &Transaction) -> Arc<AtomicU64> {
+        let version = Arc::new(AtomicU64::new(tx.id()));
+        tx.add_change(Box::new(TestChange {
+            version: version.clone(),
+        }));
+        version
+    }
+
+    #[derive(Debug, Default)]
+    struct TestLog {
+        fail_commit: bool,
+        commits: AtomicUsize,
+        ended: AtomicUsize,
+    }
+
+    impl TransactionLog for TestLog {
+        fn log_commit(&self, _transaction_id: u64) -> Result<()> {
+            if self.fail_commit {
+                return Err(DbError::new("failed"));
+            }
+            self.commits.fetch_add(1, atomic::Ordering::Relaxed);
+            Ok(())
+        }
+
+        fn end_transaction(&self, _transaction_id: u64) {
+            self.ended.fetch_add(1, atomic::Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn snapshot_visibility() {
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx1 = manager.begin();
+        let version = add_test_change(&tx1);
+        assert!(
+            tx1.snapshot()
+                .is_visible(version.load(atomic::Ordering::Relaxed))
+        );
+
+        // Not visible to a concurrent transaction.
+        let tx2 = manager.begin();
+        assert!(
+            !tx2.snapshot()
+                .is_visible(version.load(atomic::Ordering::Relaxed))
+        );
+
+        tx1.commit().unwrap();
+        let committed = version.load(atomic::Ordering::Relaxed);
+        assert!(committed < TRANSACTION_ID_START);
+
+        // Still not visible to the transaction started before the commit.
+        assert!(!tx2.snapshot().is_visible(committed));
+
+        // Visible to new transactions.
+        let tx3 = manager.begin();
+        assert!(tx3.snapshot().is_visible(committed));
+        assert!(Snapshot::LATEST.is_visible(committed));
+    }
+
+    #[test]
+    fn rollback_in_reverse() {
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx = manager.begin();
+        let v1 = add_test_change(&tx);
+        let v2 = add_test_change(&tx);
+        tx.rollback().unwrap();
+
+        assert_eq!(ABORTED_VERSION, v1.load(atomic::Ordering::Relaxed));
+        assert_eq!(ABORTED_VERSION, v2.load(atomic::Ordering::Relaxed));
+        assert_eq!(TransactionStatus::RolledBack, tx.status());
+
+        tx.commit().unwrap_err();
+    }
+
+    #[test]
+    fn drop_rolls_back() {
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx = manager.begin();
+        let version = add_test_change(&tx);
+        std::mem::drop(tx);
+
+        assert_eq!(ABORTED_VERSION, version.load(atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    fn commit_aborted_rolls_back() {
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx = manager.begin();
+        let version = add_test_change(&tx);
+        tx.abort();
+        tx.commit().unwrap_err();
+
+        assert_eq!(ABORTED_VERSION, version.load(atomic::Ordering::Relaxed));
+        assert_eq!(TransactionStatus::RolledBack, tx.status());
+    }
+
+    #[test]
+    fn change_after_rollback() {
+        let manager = Arc::new(TransactionManager::new());
+
+        let tx = manager.begin();
+        tx.rollback().unwrap();
+
+        let version = add_test_change(&tx);
+        assert_eq!(ABORTED_VERSION, version.load(atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    fn system_commits_immediately() {
+        let tx = Transaction::system();
+        let version = add_test_change(&tx);
+        assert_eq!(SYSTEM_VERSION, version.load(atomic::Ordering::Relaxed));
+        assert!(Snapshot::LATEST.is_visible(SYSTEM_VERSION));
+    }
+
+    #[test]
+    fn logs_notified() {
+        let manager = Arc::new(TransactionManager::new());
+        let log = Arc::new(TestLog::default());
+
+        let tx = manager.begin();
+        assert!(tx.add_log(log.clone()));
+        assert!(!tx.add_log(log.clone()));
+        tx.commit().unwrap();
+
+        assert_eq!(1, log.commits.load(atomic::Ordering::Relaxed));
+        assert_eq!(1, log.ended.load(atomic::Ordering::Relaxed));
+    }
+
+    #[test]
+    fn failed_log_commit_rolls_back() {
+        let manager = Arc::new(TransactionManager::new());
+        let log = Arc::new(TestLog {
+            fail_commit: true,
+            ..Default::default()
+        });
+
+        let tx = manager.begin();
+        let version = add_test_change(&tx);
+        tx.add_log(log.clone());
+        tx.commit().unwrap_err();
+
+        assert_eq!(ABORTED_VERSION, version.load(atomic::Ordering::Relaxed));
+        assert_eq!(1, log.ended.load(atomic::Ordering::Relaxed));
+
+        // Commit timestamp not used.
+        let tx = manager.begin();
+        assert_eq!(SYSTEM_VERSION + 1, tx.snapshot().start);
+    }
+}
@@ -122,6 +122,7 @@ mod tests {
                     where_expr: None,
                     group_by: None,
                     having: None,
+                    windows: Vec::new(),
                 })),
                 order_by: None,
                 limit: LimitModifier {
@@ -15,6 +15,8 @@ pub struct CreateTable<T: AstMeta> {
     pub external: bool,
     pub name: T::ItemReference,
     pub columns: Vec<ColumnDef<T>>,
+    /// Table level constraints.
+    pub constraints: Vec<TableConstraint>,
     pub source: Option<QueryNode<T>>,
 }
 
@@ -35,13 +37,19 @@ impl AstParseable for CreateTable<Raw> {
 
         let name = ObjectReference::parse(parser)?;
 
-        let columns = if parser.consume_token(&Token::LeftParen) {
-            let columns = parser.parse_comma_separated(ColumnDef::parse)?;
+        let mut columns = Vec::new();
+        let mut constraints = Vec::new();
+        if parser.consume_token(&Token::LeftParen) {
+            // Column definitions and table constraints may be interleaved.
+            parser.parse_comma_separated(|parser| {
+                match TableConstraint::parse_if_constraint(parser)? {
+                    Some(constraint) => constraints.push(constraint),
+                    None => columns.push(ColumnDef::parse(parser)?),
+                }
+                Ok(())
+            })?;
             parser.expect_token(&Token::RightParen)?;
-            columns
-        } else {
-            Vec::new()
-        };
+        }
 
         let source = if parser.parse_keyword(Keyword::AS) {
             Some(QueryNode::parse(parser)?)
@@ -56,6 +64,7 @@ impl AstParseable for CreateTable<Raw> {
             external,
             name,
             columns,
+            constraints,
             source,
         })
     }
@@ -72,6 +81,40 @@ pub struct ColumnDef<T: AstMeta> {
 pub enum ColumnOption {
     Null,
     NotNull,
+    PrimaryKey,
+    Unique,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableConstraint {
+    /// PRIMARY KEY (<columns>)
+    PrimaryKey(Vec<Ident>),
+    /// UNIQUE (<columns>)
+    Unique(Vec<Ident>),
+}
+
+impl TableConstraint {
+    /// Try to parse a table constraint, returning None if the next tokens
+    /// aren't for a constraint.
+    fn parse_if_constraint(parser: &mut Parser) -> Result<Option<Self>> {
+        let primary_key = if parser.parse_keyword_sequence(&[Keyword::PRIMARY, Keyword::KEY]) {
+            true
+        } else if parser.parse_keyword(Keyword::UNIQUE) {
+            false
+        } else {
+            return Ok(None);
+        };
+
+        parser.expect_token(&Token::LeftParen)?;
+        let columns = parser.parse_comma_separated(Ident::parse)?;
+        parser.expect_token(&Token::RightParen)?;
+
+        if primary_key {
+            Ok(Some(TableConstraint::PrimaryKey(columns)))
+        } else {
+            Ok(Some(TableConstraint::Unique(columns)))
+        }
+    }
 }
 
 impl AstParseable for ColumnDef<Raw> {
@@ -81,11 +124,18 @@ impl AstParseable for ColumnDef<Raw> {
 
         let mut opts = Vec::new();
 
-        if parser.parse_keyword_sequence(&[Keyword::NOT, Keyword::NULL]) {
-            opts.push(ColumnOption::NotNull)
-        }
-        if parser.parse_keyword(Keyword::NULL) {
-            opts.push(ColumnOption::Null)
+        loop {
+            if parser.parse_keyword_sequence(&[Keyword::NOT, Keyword::NULL]) {
+                opts.push(ColumnOption::NotNull)
+            } else if parser.parse_keyword(Keyword::NULL) {
+                opts.push(ColumnOption::Null)
+            } else if parser.parse_keyword_sequence(&[Keyword::PRIMARY, Keyword::KEY]) {
+                opts.push(ColumnOption::PrimaryKey)
+            } else if parser.parse_keyword(Keyword::UNIQUE) {
+                opts.push(ColumnOption::Unique)
+            } else {
+                break;
+            }
         }
 
         Ok(ColumnDef {
@@ -131,6 +181,7 @@ mod tests {
                 datatype: DataType::Integer,
                 opts: Vec::new(),
             }],
+            constraints: Vec::new(),
             source: None,
         };
         assert_eq!(expected, got);
@@ -157,6 +208,7 @@ mod tests {
                     opts: Vec::new(),
                 },
             ],
+            constraints: Vec::new(),
             source: None,
         };
         assert_eq!(expected, got);
@@ -183,6 +235,7 @@ mod tests {
                     opts: Vec::new(),
                 },
             ],
+            constraints: Vec::new(),
             source: None,
         };
         assert_eq!(expected, got);
@@ -202,6 +255,7 @@ mod tests {
                 datatype: DataType::Integer,
                 opts: Vec::new(),
             }],
+            constraints: Vec::new(),
             source: None,
         };
         assert_eq!(expected, got);
@@ -222,6 +276,7 @@ mod tests {
                 datatype: DataType::Integer,
                 opts: Vec::new(),
             }],
+            constraints: Vec::new(),
             source: None,
         };
         assert_eq!(expected, got);
@@ -237,8 +292,75 @@ mod tests {
             external: false,
             name: ObjectReference::from_strings(["hello"]),
             columns: Vec::new(),
+            constraints: Vec::new(),
             source: Some(query_node_values_1()),
         };
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn column_constraints() {
+        let got = parse_ast::<CreateTable<_>>(
+            "create table hello (a int primary key, b text not null unique)",
+        )
+        .unwrap();
+        let expected = CreateTable {
+            or_replace: false,
+            if_not_exists: false,
+            temp: false,
+            external: false,
+            name: ObjectReference::from_strings(["hello"]),
+            columns: vec![
+                ColumnDef {
+                    name: Ident::new_unquoted("a"),
+                    datatype: DataType::Integer,
+                    opts: vec![ColumnOption::PrimaryKey],
+                },
+                ColumnDef {
+                    name: Ident::new_unquoted("b"),
+                    datatype: DataType::Varchar(None),
+                    opts: vec![ColumnOption::NotNull, ColumnOption::Unique],
+                },
+            ],
+            constraints: Vec::new(),
+            source: None,
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn table_constraints() {
+        let got = parse_ast::<CreateTable<_>>(
+            "create table hello (a int, primary key (a, b), b int, unique (b))",
+        )
+        .unwrap();
+        let expected = CreateTable {
+            or_replace: false,
+            if_not_exists: false,
+            temp: false,
+            external: false,
+            name: ObjectReference::from_strings(["hello"]),
+            columns: vec![
+                ColumnDef {
+                    name: Ident::new_unquoted("a"),
+                    datatype: DataType::Integer,
+                    opts: Vec::new(),
+                },
+                ColumnDef {
+                    name: Ident::new_unquoted("b"),
+                    datatype: DataType::Integer,
+                    opts: Vec::new(),
+                },
+            ],
+            constraints: vec![
+                TableConstraint::PrimaryKey(vec![
+                    Ident::new_unquoted("a"),
+                    Ident::new_unquoted("b"),
+                ]),
+                TableConstraint::Unique(vec![Ident::new_unquoted("b")]),
+            ],
+            source: None,
+        };
+        assert_eq!(expected, got);
+    }
 }
@@ -40,10 +40,16 @@ pub enum DataType {
     Bool,
     /// DATE
     Date,
-    /// TIMESTAMP
+    /// TIME, TIME WITHOUT TIME ZONE
+    Time,
+    /// TIMESTAMP, TIMESTAMP WITHOUT TIME ZONE
     Timestamp,
+    /// TIMESTAMPTZ, TIMESTAMP WITH TIME ZONE
+    TimestampTz,
     /// INTERVAL
     Interval,
+    /// UUID
+    Uuid,
 }
 
 impl AstParseable for DataType {
@@ -60,6 +66,7 @@ impl AstParseable for DataType {
             Keyword::VARCHAR => DataType::Varchar(None), // TODO: With length.
             Keyword::TEXT | Keyword::STRING => DataType::Varchar(None),
             Keyword::BINARY | Keyword::BLOB => DataType::Binary(None), // TODO: With length.
+            Keyword::UUID => DataType::Uuid,
             Keyword::TINYINT | Keyword::INT1 => DataType::TinyInt,
             Keyword::SMALLINT | Keyword::INT2 => DataType::SmallInt,
             Keyword::INT | Keyword::INTEGER | Keyword::INT4 => DataType::Integer,
@@ -77,7 +84,23 @@ impl AstParseable for DataType {
             }
             Keyword::BOOL | Keyword::BOOLEAN => DataType::Bool,
             Keyword::DATE => DataType::Date,
-            Keyword::TIMESTAMP => DataType::Timestamp,
+            Keyword::TIME => {
+                parser.parse_keyword_sequence(&[Keyword::WITHOUT, Keyword::TIME, Keyword::ZONE]);
+                DataType::Time
+            }
+            Keyword::TIMESTAMP => {
+                if parser.parse_keyword_sequence(&[Keyword::WITH, Keyword::TIME, Keyword::ZONE]) {
+                    DataType::TimestampTz
+                } else {
+                    parser.parse_keyword_sequence(&[
+                        Keyword::WITHOUT,
+                        Keyword::TIME,
+                        Keyword::ZONE,
+                    ]);
+                    DataType::Timestamp
+                }
+            }
+            Keyword::TIMESTAMPTZ => DataType::TimestampTz,
             Keyword::INTERVAL => DataType::Interval,
             other => {
                 return Err(DbError::new(format!(
@@ -122,6 +145,9 @@ mod tests {
         assert_ast_eq(DataType::Binary(None), "binary");
         assert_ast_eq(DataType::Binary(None), "blob");
 
+        assert_ast_eq(DataType::Uuid, "UUID");
+        assert_ast_eq(DataType::Uuid, "uuid");
+
         assert_ast_eq(DataType::TinyInt, "tinyint");
         assert_ast_eq(DataType::TinyInt, "int1");
 
@@ -162,7 +188,13 @@ mod tests {
 
         assert_ast_eq(DataType::Date, "date");
 
+        assert_ast_eq(DataType::Time, "TIME");
+        assert_ast_eq(DataType::Time, "time without time zone");
         assert_ast_eq(DataType::Timestamp, "TIMESTAMP");
+        assert_ast_eq(DataType::Timestamp, "timestamp without time zone");
+
+        assert_ast_eq(DataType::TimestampTz, "TIMESTAMPTZ");
+        assert_ast_eq(DataType::TimestampTz, "timestamp with time zone");
 
         assert_ast_eq(DataType::Interval, "INTERVAL");
     }
@@ -0,0 +1,83 @@
+use glaredb_error::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{AstParseable, Expr, Ident, ObjectReference};
+use crate::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Delete<T: AstMeta> {
+    pub table: T::TableReference,
+    pub alias: Option<Ident>,
+    pub where_expr: Option<Expr<T>>,
+}
+
+impl AstParseable for Delete<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::DELETE)?;
+        parser.expect_keyword(Keyword::FROM)?;
+
+        let table = ObjectReference::parse(parser)?;
+        let alias = parser.parse_alias(RESERVED_FOR_TABLE_ALIAS)?;
+
+        let where_expr = if parser.parse_keyword(Keyword::WHERE) {
+            Some(Expr::parse(parser)?)
+        } else {
+            None
+        };
+
+        Ok(Delete {
+            table,
+            alias,
+            where_expr,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, Literal};
+
+    #[test]
+    fn delete_all() {
+        let got = parse_ast("delete from t1").unwrap();
+        let expected = Delete {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            where_expr: None,
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn delete_with_where() {
+        let got = parse_ast("delete from s1.t1 where a = 1").unwrap();
+        let expected = Delete {
+            table: ObjectReference::from_strings(["s1", "t1"]),
+            alias: None,
+            where_expr: Some(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Literal(Literal::Number("1".to_string()))),
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn delete_with_alias() {
+        let got = parse_ast("delete from t1 as x where x.a").unwrap();
+        let expected = Delete {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: Some(Ident::new_unquoted("x")),
+            where_expr: Some(Expr::CompoundIdent(vec![
+                Ident::new_unquoted("x"),
+                Ident::new_unquoted("a"),
+            ])),
+        };
+        assert_eq!(expected, got);
+    }
+}
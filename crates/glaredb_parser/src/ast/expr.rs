@@ -50,6 +50,10 @@ pub enum BinaryOperator {
     StringConcat,
     /// String starts with operator, e.g. `a ^@ b`
     StringStartsWith,
+    /// JSON extract operator, e.g. `a -> 'b'`
+    JsonExtract,
+    /// JSON extract as text operator, e.g. `a ->> 'b'`
+    JsonExtractString,
     /// Greater than, e.g. `a > b`
     Gt,
     /// Less than, e.g. `a < b`
@@ -114,12 +118,22 @@ pub struct Function<T: AstMeta> {
     pub star: bool,
     /// Arguments to the function.
     pub args: Vec<FunctionArg<T>>,
+    /// `IGNORE NULLS` or `RESPECT NULLS` for window functions.
+    pub null_treatment: Option<NullTreatment>,
     /// Filter part of `COUNT(col) FILTER (WHERE col > 5)`
     pub filter: Option<Box<Expr<T>>>,
     /// Option OVER clause indicating this is a window function.
     pub over: Option<WindowSpec<T>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NullTreatment {
+    /// `IGNORE NULLS`
+    IgnoreNulls,
+    /// `RESPECT NULLS`
+    RespectNulls,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FunctionArg<T: AstMeta> {
     /// A named argument. Allows use of either `=>` or `=` for assignment.
@@ -306,6 +320,13 @@ pub enum Expr<T: AstMeta> {
         date_part: DatePart,
         expr: Box<Expr<T>>,
     },
+    /// Time zone conversion.
+    ///
+    /// `<expr> AT TIME ZONE <timezone>`
+    AtTimeZone {
+        expr: Box<Expr<T>>,
+        timezone: Box<Expr<T>>,
+    },
 }
 
 impl AstParseable for Expr<Raw> {
@@ -327,7 +348,7 @@ impl Expr<Raw> {
     const PREC_MUL_DIV_MOD: u8 = 90;
     const PREC_EXPONENTIATION: u8 = 100;
     const PREC_UNARY_MINUS: u8 = 105;
-    const _PREC_AT: u8 = 110; // AT TIME ZONE
+    const PREC_AT: u8 = 110; // AT TIME ZONE
     const _PREC_COLLATE: u8 = 120;
     const PREC_ARRAY_ELEM: u8 = 130; // []
     const PREC_CAST: u8 = 140; // ::
@@ -352,19 +373,23 @@ impl Expr<Raw> {
         //
         // DATE '1992-10-11'
         // BOOL 'true'
+        let start = parser.idx;
         match parser.maybe_parse(DataType::parse) {
             // INTERVAL is a special case.
             Some(DataType::Interval) => {
                 let interval = Interval::parse(parser)?;
                 return Ok(Expr::Interval(interval));
             }
-            Some(dt) => {
-                let s = Self::parse_string_literal(parser)?;
-                return Ok(Expr::TypedString {
-                    datatype: dt,
-                    value: s,
-                });
-            }
+            Some(dt) => match parser.maybe_parse(Self::parse_string_literal) {
+                Some(s) => {
+                    return Ok(Expr::TypedString {
+                        datatype: dt,
+                        value: s,
+                    });
+                }
+                // Not a typed string, e.g. a column named 'date'.
+                None => parser.idx = start,
+            },
             None => (), // Continue trying to parse a normal expression.
         }
 
@@ -495,6 +520,23 @@ impl Expr<Raw> {
                             expr: Box::new(expr),
                         }
                     }
+                    Keyword::CURRENT_DATE | Keyword::CURRENT_TIME | Keyword::CURRENT_TIMESTAMP => {
+                        let w = w.clone();
+                        if matches!(parser.peek().map(|t| &t.token), Some(Token::LeftParen)) {
+                            Self::parse_ident_expr(w, parser)?
+                        } else {
+                            // SQL allows calling these without parentheses.
+                            Expr::Function(Box::new(Function {
+                                reference: ObjectReference(vec![Ident::from(w)]),
+                                distinct: false,
+                                star: false,
+                                args: Vec::new(),
+                                null_treatment: None,
+                                filter: None,
+                                over: None,
+                            }))
+                        }
+                    }
                     Keyword::COLUMNS => {
                         // TODO: Should we just special case on left paren? And
                         // assume ident otherwise?
@@ -593,6 +635,8 @@ impl Expr<Raw> {
             Token::Ampersand => Some(BinaryOperator::BitwiseAnd),
             Token::Concat => Some(BinaryOperator::StringConcat),
             Token::CaretAt => Some(BinaryOperator::StringStartsWith),
+            Token::Arrow => Some(BinaryOperator::JsonExtract),
+            Token::LongArrow => Some(BinaryOperator::JsonExtractString),
             Token::Word(w) => match w.keyword {
                 Some(Keyword::AND) => Some(BinaryOperator::And),
                 Some(Keyword::OR) => Some(BinaryOperator::Or),
@@ -788,6 +832,15 @@ impl Expr<Raw> {
                         high: Box::new(high),
                     })
                 }
+                Keyword::AT => {
+                    parser.expect_keyword(Keyword::TIME)?;
+                    parser.expect_keyword(Keyword::ZONE)?;
+                    let timezone = Expr::parse_subexpr(parser, Self::PREC_AT)?;
+                    Ok(Expr::AtTimeZone {
+                        expr: Box::new(prefix),
+                        timezone: Box::new(timezone),
+                    })
+                }
                 other => {
                     return Err(DbError::new(format!(
                         "Unexpected keyword in infix expression: {other}"
@@ -868,6 +921,23 @@ impl Expr<Raw> {
             Token::Word(w) if w.keyword == Some(Keyword::IN) => Ok(Self::PREC_CONTAINMENT),
             Token::Word(w) if w.keyword == Some(Keyword::BETWEEN) => Ok(Self::PREC_CONTAINMENT),
 
+            // "AT TIME ZONE", only treated as an operator if the full sequence
+            // is present so that `at` can still be used as an alias.
+            Token::Word(w) if w.keyword == Some(Keyword::AT) => {
+                let is_time_zone = matches!(
+                    (
+                        parser.peek_nth(1).and_then(|t| t.keyword()),
+                        parser.peek_nth(2).and_then(|t| t.keyword())
+                    ),
+                    (Some(Keyword::TIME), Some(Keyword::ZONE))
+                );
+                if is_time_zone {
+                    Ok(Self::PREC_AT)
+                } else {
+                    Ok(0)
+                }
+            }
+
             // "LIKE"
             Token::Word(w) if w.keyword == Some(Keyword::LIKE) => Ok(Self::PREC_CONTAINMENT),
             Token::Word(w) if w.keyword == Some(Keyword::ILIKE) => Ok(Self::PREC_CONTAINMENT),
@@ -906,6 +976,9 @@ impl Expr<Raw> {
             // Starts with
             Token::CaretAt => Ok(Self::PREC_EVERYTHING_ELSE),
 
+            // JSON extract
+            Token::Arrow | Token::LongArrow => Ok(Self::PREC_EVERYTHING_ELSE),
+
             // Array, struct literals
             Token::LeftBrace | Token::LeftBracket => Ok(Self::PREC_ARRAY_ELEM),
 
@@ -965,6 +1038,16 @@ impl Expr<Raw> {
                 }
             };
 
+            // IGNORE NULLS | RESPECT NULLS
+            let null_treatment =
+                if parser.parse_keyword_sequence(&[Keyword::IGNORE, Keyword::NULLS]) {
+                    Some(NullTreatment::IgnoreNulls)
+                } else if parser.parse_keyword_sequence(&[Keyword::RESPECT, Keyword::NULLS]) {
+                    Some(NullTreatment::RespectNulls)
+                } else {
+                    None
+                };
+
             // FILTER (WHERE <expr>)
             let filter = if parser.parse_keyword(Keyword::FILTER) {
                 parser.expect_token(&Token::LeftParen)?;
@@ -999,6 +1082,7 @@ impl Expr<Raw> {
                 distinct,
                 star,
                 args,
+                null_treatment,
                 filter,
                 over,
             })))
@@ -1390,6 +1474,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: Expr::Ident(Ident::new_unquoted("my_col")),
             }],
+            null_treatment: None,
             filter: None,
             over: None,
         }));
@@ -1404,6 +1489,7 @@ mod tests {
             distinct: false,
             star: false,
             args: Vec::new(),
+            null_treatment: None,
             filter: None,
             over: None,
         }));
@@ -1420,6 +1506,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: Expr::Ident(Ident::new_unquoted("x")),
             }],
+            null_treatment: None,
             filter: Some(Box::new(Expr::BinaryExpr {
                 left: Box::new(Expr::Ident(Ident::new_unquoted("x"))),
                 op: BinaryOperator::Gt,
@@ -1440,6 +1527,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: Expr::Ident(Ident::new_unquoted("x")),
             }],
+            null_treatment: None,
             filter: None,
             over: None,
         }));
@@ -1455,6 +1543,7 @@ mod tests {
             distinct: false,
             star: false,
             args: Vec::new(),
+            null_treatment: None,
             filter: None,
             over: Some(WindowSpec::Definition(WindowDefinition {
                 existing: None,
@@ -1485,6 +1574,7 @@ mod tests {
             distinct: false,
             star: false,
             args: Vec::new(),
+            null_treatment: None,
             filter: None,
             // Note that this should be Some but everything empty. We need to
             // differentiate between and empty OVER and missing OVER.
@@ -1498,6 +1588,40 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn function_call_ignore_nulls() {
+        let expr: Expr<_> = parse_ast("lag(x) ignore nulls over ()").unwrap();
+        let expected = Expr::Function(Box::new(Function {
+            reference: ObjectReference(vec![Ident::new_unquoted("lag")]),
+            distinct: false,
+            star: false,
+            args: vec![FunctionArg::Unnamed {
+                arg: Expr::Ident(Ident::new_unquoted("x")),
+            }],
+            null_treatment: Some(NullTreatment::IgnoreNulls),
+            filter: None,
+            over: Some(WindowSpec::Definition(WindowDefinition::default())),
+        }));
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn function_call_respect_nulls() {
+        let expr: Expr<_> = parse_ast("first_value(x) respect nulls over w").unwrap();
+        let expected = Expr::Function(Box::new(Function {
+            reference: ObjectReference(vec![Ident::new_unquoted("first_value")]),
+            distinct: false,
+            star: false,
+            args: vec![FunctionArg::Unnamed {
+                arg: Expr::Ident(Ident::new_unquoted("x")),
+            }],
+            null_treatment: Some(NullTreatment::RespectNulls),
+            filter: None,
+            over: Some(WindowSpec::Named(Ident::new_unquoted("w"))),
+        }));
+        assert_eq!(expected, expr);
+    }
+
     #[test]
     fn nested_expr() {
         let expr: Expr<_> = parse_ast("(1 + 2)").unwrap();
@@ -1517,6 +1641,7 @@ mod tests {
             distinct: false,
             star: true,
             args: Vec::new(),
+            null_treatment: None,
             filter: None,
             over: None,
         }));
@@ -1534,6 +1659,7 @@ mod tests {
                 distinct: false,
                 star: true,
                 args: Vec::new(),
+                null_treatment: None,
                 filter: None,
                 over: None,
             }))),
@@ -1550,6 +1676,7 @@ mod tests {
                 distinct: false,
                 star: true,
                 args: Vec::new(),
+                null_treatment: None,
                 filter: None,
                 over: None,
             }))),
@@ -1569,6 +1696,48 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn time_typed_string() {
+        let expr: Expr<_> = parse_ast("TIME '13:45:00'").unwrap();
+        let expected = Expr::TypedString {
+            datatype: DataType::Time,
+            value: "13:45:00".to_string(),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn type_name_as_ident() {
+        let expr: Expr<_> = parse_ast("date").unwrap();
+        assert_eq!(Expr::Ident(Ident::new_unquoted("date")), expr);
+
+        let expr: Expr<_> = parse_ast("time + 1").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::Ident(Ident::new_unquoted("time"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Literal(Literal::Number("1".to_string()))),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn current_date_without_parens() {
+        let expected = Expr::Function(Box::new(Function {
+            reference: ObjectReference(vec![Ident::new_unquoted("current_date")]),
+            distinct: false,
+            star: false,
+            args: Vec::new(),
+            null_treatment: None,
+            filter: None,
+            over: None,
+        }));
+
+        let expr: Expr<_> = parse_ast("current_date").unwrap();
+        assert_eq!(expected, expr);
+        let expr: Expr<_> = parse_ast("current_date()").unwrap();
+        assert_eq!(expected, expr);
+    }
+
     #[test]
     fn double_colon_cast() {
         let expr: Expr<_> = parse_ast("4::TEXT").unwrap();
@@ -1837,4 +2006,62 @@ mod tests {
         };
         assert_eq!(expected, expr);
     }
+
+    #[test]
+    fn at_time_zone() {
+        let expr: Expr<_> = parse_ast("a AT TIME ZONE 'UTC'").unwrap();
+        let expected = Expr::AtTimeZone {
+            expr: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+            timezone: Box::new(Expr::Literal(Literal::SingleQuotedString(
+                "UTC".to_string(),
+            ))),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn at_time_zone_precedence() {
+        // Binds tighter than addition.
+        let expr: Expr<_> = parse_ast("a + b AT TIME ZONE c").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::AtTimeZone {
+                expr: Box::new(Expr::Ident(Ident::new_unquoted("b"))),
+                timezone: Box::new(Expr::Ident(Ident::new_unquoted("c"))),
+            }),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn json_extract_operators() {
+        let expr: Expr<_> = parse_ast("a -> 'b' ->> 0").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+                op: BinaryOperator::JsonExtract,
+                right: Box::new(Expr::Literal(Literal::SingleQuotedString("b".to_string()))),
+            }),
+            op: BinaryOperator::JsonExtractString,
+            right: Box::new(Expr::Literal(Literal::Number("0".to_string()))),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn json_extract_precedence() {
+        // Binds tighter than comparison.
+        let expr: Expr<_> = parse_ast("a->>'b' = 'c'").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+                op: BinaryOperator::JsonExtractString,
+                right: Box::new(Expr::Literal(Literal::SingleQuotedString("b".to_string()))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Literal(Literal::SingleQuotedString("c".to_string()))),
+        };
+        assert_eq!(expected, expr);
+    }
 }
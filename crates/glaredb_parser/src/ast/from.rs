@@ -100,7 +100,9 @@ impl AstParseable for FromNode<Raw> {
                                 JoinType::LeftSemi
                             }
                             _ => {
-                                return Err(DbError::new("Expected one of OUTER, SEMI, or JOIN"));
+                                return Err(DbError::new(
+                                    "Expected one of OUTER, ANTI, SEMI, or JOIN",
+                                ));
                             }
                         }
                     }
@@ -127,7 +129,9 @@ impl AstParseable for FromNode<Raw> {
                                 JoinType::RightSemi
                             }
                             _ => {
-                                return Err(DbError::new("Expected one of OUTER, SEMI, or JOIN"));
+                                return Err(DbError::new(
+                                    "Expected one of OUTER, ANTI, SEMI, or JOIN",
+                                ));
                             }
                         }
                     }
@@ -142,6 +146,11 @@ impl AstParseable for FromNode<Raw> {
                         parser.expect_keyword(Keyword::JOIN)?;
                         JoinType::LeftSemi
                     }
+                    Keyword::ANTI => {
+                        parser.expect_keyword(Keyword::ANTI)?;
+                        parser.expect_keyword(Keyword::JOIN)?;
+                        JoinType::LeftAnti
+                    }
                     _ => return Ok(node), // Unknown join keyword, probably time to start working on a different part of the query.
                 };
 
@@ -622,6 +631,43 @@ mod tests {
         assert_eq!(expected, node);
     }
 
+    #[test]
+    fn join_types() {
+        let cases = [
+            ("t1 FULL JOIN t2 USING (c1)", JoinType::Outer),
+            ("t1 FULL OUTER JOIN t2 USING (c1)", JoinType::Outer),
+            ("t1 ANTI JOIN t2 USING (c1)", JoinType::LeftAnti),
+            ("t1 LEFT ANTI JOIN t2 USING (c1)", JoinType::LeftAnti),
+            ("t1 SEMI JOIN t2 USING (c1)", JoinType::LeftSemi),
+            ("t1 RIGHT SEMI JOIN t2 USING (c1)", JoinType::RightSemi),
+            ("t1 RIGHT ANTI JOIN t2 USING (c1)", JoinType::RightAnti),
+        ];
+
+        for (sql, join_type) in cases {
+            let node: FromNode<_> = parse_ast(sql).unwrap();
+            let expected = FromNode {
+                alias: None,
+                body: FromNodeBody::Join(FromJoin {
+                    left: Box::new(FromNode {
+                        alias: None,
+                        body: FromNodeBody::BaseTable(FromBaseTable {
+                            reference: ObjectReference::from_strings(["t1"]),
+                        }),
+                    }),
+                    right: Box::new(FromNode {
+                        alias: None,
+                        body: FromNodeBody::BaseTable(FromBaseTable {
+                            reference: ObjectReference::from_strings(["t2"]),
+                        }),
+                    }),
+                    join_type,
+                    join_condition: JoinCondition::Using(vec![Ident::new_unquoted("c1")]),
+                }),
+            };
+            assert_eq!(expected, node, "sql: {sql}");
+        }
+    }
+
     #[test]
     fn nested_join() {
         let node: FromNode<_> = parse_ast("t1 LEFT JOIN t2 RIGHT JOIN t3").unwrap();
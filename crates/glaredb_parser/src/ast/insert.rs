@@ -1,7 +1,7 @@
 use glaredb_error::Result;
 use serde::{Deserialize, Serialize};
 
-use super::{AstParseable, Ident, ObjectReference, QueryNode};
+use super::{Assignment, AstParseable, Expr, Ident, ObjectReference, QueryNode};
 use crate::keywords::Keyword;
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
@@ -12,6 +12,27 @@ pub struct Insert<T: AstMeta> {
     pub table: T::TableReference,
     pub columns: Vec<Ident>,
     pub source: QueryNode<T>,
+    pub on_conflict: Option<InsertOnConflict<T>>,
+}
+
+/// `ON CONFLICT [(<columns>)] DO ...` for an INSERT.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsertOnConflict<T: AstMeta> {
+    /// Columns making up the conflicting unique constraint. Empty if not
+    /// provided.
+    pub columns: Vec<Ident>,
+    pub action: OnConflictAction<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OnConflictAction<T: AstMeta> {
+    /// DO NOTHING
+    DoNothing,
+    /// DO UPDATE SET <assignments> [WHERE <expr>]
+    DoUpdate {
+        assignments: Vec<Assignment<T>>,
+        where_expr: Option<Expr<T>>,
+    },
 }
 
 impl AstParseable for Insert<Raw> {
@@ -31,19 +52,63 @@ impl AstParseable for Insert<Raw> {
 
         let source = QueryNode::parse(parser)?;
 
+        let on_conflict = if parser.parse_keyword_sequence(&[Keyword::ON, Keyword::CONFLICT]) {
+            Some(InsertOnConflict::parse(parser)?)
+        } else {
+            None
+        };
+
         Ok(Insert {
             table,
             columns,
             source,
+            on_conflict,
         })
     }
 }
 
+impl InsertOnConflict<Raw> {
+    /// Parse the conflict target and action, assumes ON CONFLICT has already
+    /// been parsed.
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let columns = if parser.consume_token(&Token::LeftParen) {
+            let columns = parser.parse_comma_separated(Ident::parse)?;
+            parser.expect_token(&Token::RightParen)?;
+            columns
+        } else {
+            Vec::new()
+        };
+
+        parser.expect_keyword(Keyword::DO)?;
+
+        let action = if parser.parse_keyword(Keyword::NOTHING) {
+            OnConflictAction::DoNothing
+        } else {
+            parser.expect_keyword(Keyword::UPDATE)?;
+            parser.expect_keyword(Keyword::SET)?;
+            let assignments = parser.parse_comma_separated(Assignment::parse)?;
+
+            let where_expr = if parser.parse_keyword(Keyword::WHERE) {
+                Some(Expr::parse(parser)?)
+            } else {
+                None
+            };
+
+            OnConflictAction::DoUpdate {
+                assignments,
+                where_expr,
+            }
+        };
+
+        Ok(InsertOnConflict { columns, action })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ast::testutil::parse_ast;
-    use crate::ast::{Expr, LimitModifier, Literal, QueryNodeBody, Values};
+    use crate::ast::{BinaryOperator, LimitModifier, Literal, QueryNodeBody, Values};
 
     /// Query node for 'values (1)'
     fn query_node_values_1() -> QueryNode<Raw> {
@@ -67,6 +132,7 @@ mod tests {
             table: ObjectReference::from_strings(["t1"]),
             columns: Vec::new(),
             source: query_node_values_1(),
+            on_conflict: None,
         };
         assert_eq!(expected, got);
     }
@@ -78,7 +144,59 @@ mod tests {
             table: ObjectReference::from_strings(["t1"]),
             columns: vec![Ident::new_unquoted("c1"), Ident::new_unquoted("c2")],
             source: query_node_values_1(),
+            on_conflict: None,
         };
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn on_conflict_do_nothing() {
+        let got = parse_ast("insert into t1 values (1) on conflict do nothing").unwrap();
+        let expected = Insert {
+            table: ObjectReference::from_strings(["t1"]),
+            columns: Vec::new(),
+            source: query_node_values_1(),
+            on_conflict: Some(InsertOnConflict {
+                columns: Vec::new(),
+                action: OnConflictAction::DoNothing,
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn on_conflict_do_update() {
+        let got = parse_ast(
+            "insert into t1 values (1) on conflict (c1) do update set c2 = excluded.c2 where c2 > 1",
+        )
+        .unwrap();
+        let expected = Insert {
+            table: ObjectReference::from_strings(["t1"]),
+            columns: Vec::new(),
+            source: query_node_values_1(),
+            on_conflict: Some(InsertOnConflict {
+                columns: vec![Ident::new_unquoted("c1")],
+                action: OnConflictAction::DoUpdate {
+                    assignments: vec![Assignment {
+                        column: Ident::new_unquoted("c2"),
+                        expr: Expr::CompoundIdent(vec![
+                            Ident::new_unquoted("excluded"),
+                            Ident::new_unquoted("c2"),
+                        ]),
+                    }],
+                    where_expr: Some(Expr::BinaryExpr {
+                        left: Box::new(Expr::Ident(Ident::new_unquoted("c2"))),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::Literal(Literal::Number("1".to_string()))),
+                    }),
+                },
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn on_conflict_missing_action() {
+        parse_ast::<Insert<_>>("insert into t1 values (1) on conflict (c1)").unwrap_err();
+    }
 }
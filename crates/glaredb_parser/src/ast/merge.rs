@@ -0,0 +1,251 @@
+use glaredb_error::{DbError, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{Assignment, AstParseable, Expr, FromNode, Ident, ObjectReference};
+use crate::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+use crate::tokens::Token;
+
+/// MERGE INTO <table> USING <source> ON <expr> WHEN ...
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Merge<T: AstMeta> {
+    pub table: T::TableReference,
+    pub alias: Option<Ident>,
+    pub source: FromNode<T>,
+    pub on: Expr<T>,
+    pub clauses: Vec<MergeClause<T>>,
+}
+
+/// WHEN [NOT] MATCHED [AND <expr>] THEN <action>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeClause<T: AstMeta> {
+    /// If this clause applies to source rows matching a row in the table.
+    pub matched: bool,
+    pub condition: Option<Expr<T>>,
+    pub action: MergeAction<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MergeAction<T: AstMeta> {
+    /// UPDATE SET <assignments>
+    Update { assignments: Vec<Assignment<T>> },
+    /// DELETE
+    Delete,
+    /// INSERT [(<columns>)] VALUES (<exprs>)
+    Insert {
+        columns: Vec<Ident>,
+        values: Vec<Expr<T>>,
+    },
+    /// DO NOTHING
+    DoNothing,
+}
+
+impl AstParseable for Merge<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::MERGE)?;
+        parser.expect_keyword(Keyword::INTO)?;
+
+        let table = ObjectReference::parse(parser)?;
+        let alias = parser.parse_alias(RESERVED_FOR_TABLE_ALIAS)?;
+
+        parser.expect_keyword(Keyword::USING)?;
+        let source = FromNode::parse_base_from(parser)?;
+
+        parser.expect_keyword(Keyword::ON)?;
+        let on = Expr::parse(parser)?;
+
+        let mut clauses = Vec::new();
+        while parser.parse_keyword(Keyword::WHEN) {
+            clauses.push(MergeClause::parse(parser)?);
+        }
+
+        if clauses.is_empty() {
+            return Err(DbError::new("MERGE requires at least one WHEN clause"));
+        }
+
+        Ok(Merge {
+            table,
+            alias,
+            source,
+            on,
+            clauses,
+        })
+    }
+}
+
+impl MergeClause<Raw> {
+    /// Parse a single clause, assumes WHEN has already been parsed.
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let matched = !parser.parse_keyword(Keyword::NOT);
+        parser.expect_keyword(Keyword::MATCHED)?;
+
+        let condition = if parser.parse_keyword(Keyword::AND) {
+            Some(Expr::parse(parser)?)
+        } else {
+            None
+        };
+
+        parser.expect_keyword(Keyword::THEN)?;
+
+        let action = if parser.parse_keyword_sequence(&[Keyword::DO, Keyword::NOTHING]) {
+            MergeAction::DoNothing
+        } else if matched && parser.parse_keyword(Keyword::UPDATE) {
+            parser.expect_keyword(Keyword::SET)?;
+            let assignments = parser.parse_comma_separated(Assignment::parse)?;
+            MergeAction::Update { assignments }
+        } else if matched && parser.parse_keyword(Keyword::DELETE) {
+            MergeAction::Delete
+        } else if !matched && parser.parse_keyword(Keyword::INSERT) {
+            let columns = if parser.consume_token(&Token::LeftParen) {
+                let columns = parser.parse_comma_separated(Ident::parse)?;
+                parser.expect_token(&Token::RightParen)?;
+                columns
+            } else {
+                Vec::new()
+            };
+
+            parser.expect_keyword(Keyword::VALUES)?;
+            let values = parser.parse_parenthesized_comma_separated(Expr::parse)?;
+
+            MergeAction::Insert { columns, values }
+        } else if matched {
+            return Err(DbError::new(
+                "Expected UPDATE, DELETE, or DO NOTHING for WHEN MATCHED",
+            ));
+        } else {
+            return Err(DbError::new(
+                "Expected INSERT or DO NOTHING for WHEN NOT MATCHED",
+            ));
+        };
+
+        Ok(MergeClause {
+            matched,
+            condition,
+            action,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, FromBaseTable, FromNodeBody, Literal};
+
+    fn compound(parts: [&str; 2]) -> Expr<Raw> {
+        Expr::CompoundIdent(parts.into_iter().map(Ident::new_unquoted).collect())
+    }
+
+    #[test]
+    fn merge_all_actions() {
+        let got = parse_ast(
+            "merge into t1 as t using t2 s on t.a = s.a
+               when matched and s.b is null then delete
+               when matched then update set b = s.b
+               when not matched then insert values (s.a, s.b)",
+        )
+        .unwrap();
+
+        let expected = Merge {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: Some(Ident::new_unquoted("t")),
+            source: FromNode {
+                alias: Some(crate::ast::FromAlias {
+                    alias: Ident::new_unquoted("s"),
+                    columns: None,
+                }),
+                body: FromNodeBody::BaseTable(FromBaseTable {
+                    reference: ObjectReference::from_strings(["t2"]),
+                }),
+            },
+            on: Expr::BinaryExpr {
+                left: Box::new(compound(["t", "a"])),
+                op: BinaryOperator::Eq,
+                right: Box::new(compound(["s", "a"])),
+            },
+            clauses: vec![
+                MergeClause {
+                    matched: true,
+                    condition: Some(Expr::IsNull {
+                        expr: Box::new(compound(["s", "b"])),
+                        negated: false,
+                    }),
+                    action: MergeAction::Delete,
+                },
+                MergeClause {
+                    matched: true,
+                    condition: None,
+                    action: MergeAction::Update {
+                        assignments: vec![Assignment {
+                            column: Ident::new_unquoted("b"),
+                            expr: compound(["s", "b"]),
+                        }],
+                    },
+                },
+                MergeClause {
+                    matched: false,
+                    condition: None,
+                    action: MergeAction::Insert {
+                        columns: Vec::new(),
+                        values: vec![compound(["s", "a"]), compound(["s", "b"])],
+                    },
+                },
+            ],
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn merge_insert_columns_do_nothing() {
+        let got = parse_ast(
+            "merge into t1 using t2 on t1.a = t2.a
+               when matched then do nothing
+               when not matched then insert (a) values (1)",
+        )
+        .unwrap();
+
+        let expected = Merge {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            source: FromNode {
+                alias: None,
+                body: FromNodeBody::BaseTable(FromBaseTable {
+                    reference: ObjectReference::from_strings(["t2"]),
+                }),
+            },
+            on: Expr::BinaryExpr {
+                left: Box::new(compound(["t1", "a"])),
+                op: BinaryOperator::Eq,
+                right: Box::new(compound(["t2", "a"])),
+            },
+            clauses: vec![
+                MergeClause {
+                    matched: true,
+                    condition: None,
+                    action: MergeAction::DoNothing,
+                },
+                MergeClause {
+                    matched: false,
+                    condition: None,
+                    action: MergeAction::Insert {
+                        columns: vec![Ident::new_unquoted("a")],
+                        values: vec![Expr::Literal(Literal::Number("1".to_string()))],
+                    },
+                },
+            ],
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn merge_invalid_actions() {
+        parse_ast::<Merge<_>>("merge into t1 using t2 on t1.a = t2.a").unwrap_err();
+        parse_ast::<Merge<_>>("merge into t1 using t2 on t1.a = t2.a when not matched then delete")
+            .unwrap_err();
+        parse_ast::<Merge<_>>(
+            "merge into t1 using t2 on t1.a = t2.a when matched then insert values (1)",
+        )
+        .unwrap_err();
+    }
+}
@@ -27,6 +27,12 @@ pub mod explain;
 pub use explain::*;
 pub mod insert;
 pub use insert::*;
+pub mod delete;
+pub use delete::*;
+pub mod update;
+pub use update::*;
+pub mod merge;
+pub use merge::*;
 pub mod variable;
 pub use variable::*;
 pub mod cte;
@@ -34,6 +40,7 @@ pub use cte::*;
 pub mod drop;
 pub use drop::*;
 pub mod attach;
+pub mod transaction;
 pub mod window;
 use std::fmt;
 use std::hash::Hash;
@@ -41,6 +48,7 @@ use std::hash::Hash;
 pub use attach::*;
 use glaredb_error::{DbError, Result};
 use serde::{Deserialize, Serialize};
+pub use transaction::*;
 pub use window::*;
 
 use crate::parser::Parser;
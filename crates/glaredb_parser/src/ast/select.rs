@@ -1,7 +1,15 @@
 use glaredb_error::{DbError, Result, not_implemented};
 use serde::{Deserialize, Serialize};
 
-use super::{AstParseable, DistinctModifier, Expr, FromNode, Ident, ObjectReference};
+use super::{
+    AstParseable,
+    DistinctModifier,
+    Expr,
+    FromNode,
+    Ident,
+    NamedWindowDefinition,
+    ObjectReference,
+};
 use crate::keywords::{Keyword, RESERVED_FOR_COLUMN_ALIAS};
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
@@ -21,6 +29,8 @@ pub struct SelectNode<T: AstMeta> {
     pub group_by: Option<GroupByNode<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
+    /// WINDOW
+    pub windows: Vec<NamedWindowDefinition<T>>,
 }
 
 impl AstParseable for SelectNode<Raw> {
@@ -75,6 +85,13 @@ impl AstParseable for SelectNode<Raw> {
             None
         };
 
+        // WINDOW
+        let windows = if parser.parse_keyword(Keyword::WINDOW) {
+            parser.parse_comma_separated(NamedWindowDefinition::parse)?
+        } else {
+            Vec::new()
+        };
+
         Ok(SelectNode {
             distinct,
             projections,
@@ -82,6 +99,7 @@ impl AstParseable for SelectNode<Raw> {
             where_expr,
             group_by,
             having,
+            windows,
         })
     }
 }
@@ -293,8 +311,11 @@ pub enum GroupByExpr<T: AstMeta> {
     Cube(Vec<Expr<T>>),
     /// `GROUP BY ROLLUP (<expr>)`
     Rollup(Vec<Expr<T>>),
-    /// `GROUP BY GROUPING SETS (<expr>)`
-    GroupingSets(Vec<Expr<T>>), // TODO: vec vec
+    /// `GROUP BY GROUPING SETS ((<expr>[, ...])[, ...])`
+    ///
+    /// Each inner vec is a single grouping set. A set containing a single
+    /// expression may be written without parentheses.
+    GroupingSets(Vec<Vec<Expr<T>>>),
 }
 
 impl AstParseable for GroupByExpr<Raw> {
@@ -323,14 +344,77 @@ impl AstParseable for GroupByExpr<Raw> {
                 Keyword::GROUPING => {
                     parser.next();
                     parser.expect_keyword(Keyword::SETS)?;
-                    let exprs = parser.parse_parenthesized_comma_separated(Expr::parse)?;
-                    return Ok(GroupByExpr::GroupingSets(exprs));
+                    let sets = parser.parse_parenthesized_comma_separated(|parser| {
+                        if parser
+                            .peek()
+                            .is_some_and(|tok| tok.token == Token::LeftParen)
+                        {
+                            // A parenthesized list is only a grouping set if
+                            // it's the whole item, otherwise it's the start
+                            // of an expression like `(a + b) * 2`.
+                            let start = parser.idx;
+                            let set = parser.maybe_parse(|parser| {
+                                parser.parse_parenthesized_comma_separated(Expr::parse)
+                            });
+                            let is_set = parser.peek().is_some_and(|tok| {
+                                tok.token == Token::Comma || tok.token == Token::RightParen
+                            });
+                            match set {
+                                Some(set) if is_set => return Ok(set),
+                                _ => parser.idx = start,
+                            }
+                        }
+                        Ok(vec![Expr::parse(parser)?])
+                    })?;
+                    return Ok(GroupByExpr::GroupingSets(sets));
                 }
                 _ => (), // Fallthrough, need to parse as an expression.
             }
         }
 
-        let exprs = parser.parse_comma_separated(Expr::parse)?;
-        Ok(GroupByExpr::Expr(exprs))
+        // Parse a single expression, allowing for plain expressions to be mixed
+        // with CUBE, ROLLUP, and GROUPING SETS.
+        let expr = Expr::parse(parser)?;
+        Ok(GroupByExpr::Expr(vec![expr]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, Literal};
+
+    fn ident(s: &str) -> Expr<Raw> {
+        Expr::Ident(Ident::new_unquoted(s))
+    }
+
+    #[test]
+    fn group_by_grouping_sets() {
+        let got: GroupByExpr<_> = parse_ast("grouping sets ((a, b), (a), b)").unwrap();
+        let expected = GroupByExpr::GroupingSets(vec![
+            vec![ident("a"), ident("b")],
+            vec![ident("a")],
+            vec![ident("b")],
+        ]);
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn group_by_grouping_sets_parenthesized_expr() {
+        let got: GroupByExpr<_> = parse_ast("grouping sets ((a + b) * 2, (a))").unwrap();
+        let expected = GroupByExpr::GroupingSets(vec![
+            vec![Expr::BinaryExpr {
+                left: Box::new(Expr::Nested(Box::new(Expr::BinaryExpr {
+                    left: Box::new(ident("a")),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(ident("b")),
+                }))),
+                op: BinaryOperator::Multiply,
+                right: Box::new(Expr::Literal(Literal::Number("2".to_string()))),
+            }],
+            vec![ident("a")],
+        ]);
+        assert_eq!(expected, got);
     }
 }
@@ -0,0 +1,83 @@
+use glaredb_error::{DbError, Result};
+use serde::{Deserialize, Serialize};
+
+use super::AstParseable;
+use crate::keywords::Keyword;
+use crate::parser::Parser;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionStatement {
+    /// BEGIN [TRANSACTION | WORK]
+    /// START TRANSACTION
+    Begin,
+    /// COMMIT [TRANSACTION | WORK]
+    /// END [TRANSACTION | WORK]
+    Commit,
+    /// ROLLBACK [TRANSACTION | WORK]
+    /// ABORT [TRANSACTION | WORK]
+    Rollback,
+}
+
+impl AstParseable for TransactionStatement {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let stmt = match parser.next_keyword()? {
+            Keyword::BEGIN => TransactionStatement::Begin,
+            Keyword::START => {
+                parser.expect_keyword(Keyword::TRANSACTION)?;
+                return Ok(TransactionStatement::Begin);
+            }
+            Keyword::COMMIT | Keyword::END => TransactionStatement::Commit,
+            Keyword::ROLLBACK | Keyword::ABORT => TransactionStatement::Rollback,
+            other => {
+                return Err(DbError::new(format!(
+                    "Expected a transaction statement, got '{other}'"
+                )));
+            }
+        };
+
+        // Optional noise word.
+        let _ = parser.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
+
+        Ok(stmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+
+    #[test]
+    fn begin() {
+        for sql in [
+            "begin",
+            "BEGIN TRANSACTION",
+            "begin work",
+            "start transaction",
+        ] {
+            let got = parse_ast::<TransactionStatement>(sql).unwrap();
+            assert_eq!(TransactionStatement::Begin, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn commit() {
+        for sql in ["commit", "COMMIT TRANSACTION", "end", "end work"] {
+            let got = parse_ast::<TransactionStatement>(sql).unwrap();
+            assert_eq!(TransactionStatement::Commit, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn rollback() {
+        for sql in ["rollback", "ROLLBACK WORK", "abort", "abort transaction"] {
+            let got = parse_ast::<TransactionStatement>(sql).unwrap();
+            assert_eq!(TransactionStatement::Rollback, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn start_requires_transaction() {
+        parse_ast::<TransactionStatement>("start").unwrap_err();
+    }
+}
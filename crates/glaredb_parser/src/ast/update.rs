@@ -0,0 +1,117 @@
+use glaredb_error::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{AstParseable, Expr, Ident, ObjectReference};
+use crate::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+use crate::tokens::Token;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Update<T: AstMeta> {
+    pub table: T::TableReference,
+    pub alias: Option<Ident>,
+    pub assignments: Vec<Assignment<T>>,
+    pub where_expr: Option<Expr<T>>,
+}
+
+/// `<column> = <expr>` in the SET clause of an UPDATE.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Assignment<T: AstMeta> {
+    pub column: Ident,
+    pub expr: Expr<T>,
+}
+
+impl AstParseable for Update<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::UPDATE)?;
+
+        let table = ObjectReference::parse(parser)?;
+        let alias = parser.parse_alias(RESERVED_FOR_TABLE_ALIAS)?;
+
+        parser.expect_keyword(Keyword::SET)?;
+        let assignments = parser.parse_comma_separated(Assignment::parse)?;
+
+        let where_expr = if parser.parse_keyword(Keyword::WHERE) {
+            Some(Expr::parse(parser)?)
+        } else {
+            None
+        };
+
+        Ok(Update {
+            table,
+            alias,
+            assignments,
+            where_expr,
+        })
+    }
+}
+
+impl AstParseable for Assignment<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let column = Ident::parse(parser)?;
+        parser.expect_token(&Token::Eq)?;
+        let expr = Expr::parse(parser)?;
+
+        Ok(Assignment { column, expr })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, Literal};
+
+    #[test]
+    fn update_single_column() {
+        let got = parse_ast("update t1 set a = 1").unwrap();
+        let expected = Update {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            assignments: vec![Assignment {
+                column: Ident::new_unquoted("a"),
+                expr: Expr::Literal(Literal::Number("1".to_string())),
+            }],
+            where_expr: None,
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn update_multiple_columns_with_where() {
+        let got = parse_ast("update t1 x set a = a + 1, b = 'b' where x.a > 2").unwrap();
+        let expected = Update {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: Some(Ident::new_unquoted("x")),
+            assignments: vec![
+                Assignment {
+                    column: Ident::new_unquoted("a"),
+                    expr: Expr::BinaryExpr {
+                        left: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::Literal(Literal::Number("1".to_string()))),
+                    },
+                },
+                Assignment {
+                    column: Ident::new_unquoted("b"),
+                    expr: Expr::Literal(Literal::SingleQuotedString("b".to_string())),
+                },
+            ],
+            where_expr: Some(Expr::BinaryExpr {
+                left: Box::new(Expr::CompoundIdent(vec![
+                    Ident::new_unquoted("x"),
+                    Ident::new_unquoted("a"),
+                ])),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Literal(Literal::Number("2".to_string()))),
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn update_missing_set() {
+        parse_ast::<Update<_>>("update t1 a = 1").unwrap_err();
+    }
+}
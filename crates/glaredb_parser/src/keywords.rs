@@ -45,6 +45,7 @@ macro_rules! define_keywords {
 // Keep keywords sorted to allow for binary search.
 #[rustfmt::skip]
 define_keywords!(
+    ABORT,
     ALL,
     ANALYZE,
     AND,
@@ -52,6 +53,7 @@ define_keywords!(
     ANY,
     AS,
     ASC,
+    AT,
     ATTACH,
     BEGIN,
     BETWEEN,
@@ -71,11 +73,16 @@ define_keywords!(
     CENTURY,
     CLUSTER,
     COLUMNS,
+    COMMIT,
+    CONFLICT,
     COPY,
     CREATE,
     CROSS,
     CUBE,
     CURRENT,
+    CURRENT_DATE,
+    CURRENT_TIME,
+    CURRENT_TIMESTAMP,
     DATABASE,
     DATABASES,
     DATE,
@@ -84,11 +91,13 @@ define_keywords!(
     DECADE,
     DECADES,
     DECIMAL,
+    DELETE,
     DESC,
     DESCRIBE,
     DETACH,
     DISTINCT,
     DISTRIBUTE,
+    DO,
     DOUBLE,
     DOW,
     DOY,
@@ -124,6 +133,7 @@ define_keywords!(
     HOUR,
     HOURS,
     IF,
+    IGNORE,
     ILIKE,
     IN,
     INDEX,
@@ -144,12 +154,15 @@ define_keywords!(
     JOIN,
     JSON,
     JULIAN,
+    KEY,
     LAST,
     LATERAL,
     LEFT,
     LIKE,
     LIMIT,
+    MATCHED,
     MATERIALIZED,
+    MERGE,
     MICROSECOND,
     MICROSECONDS,
     MILLENIUM,
@@ -165,6 +178,7 @@ define_keywords!(
     NATURAL,
     NO,
     NOT,
+    NOTHING,
     NULL,
     NULLS,
     NUMERIC,
@@ -187,6 +201,7 @@ define_keywords!(
     REGEXP,
     REPLACE,
     RESET,
+    RESPECT,
     RESTRICT,
     RIGHT,
     RLIKE,
@@ -207,6 +222,7 @@ define_keywords!(
     SMALLINT,
     SOME,
     SORT,
+    START,
     STRING,
     SUBSTRING,
     TABLE,
@@ -216,6 +232,7 @@ define_keywords!(
     TEXT,
     THEN,
     TIES,
+    TIME,
     TIMESTAMP,
     TIMESTAMPTZ,
     TIMEZONE,
@@ -224,7 +241,9 @@ define_keywords!(
     TINYINT,
     TO,
     TOP,
+    TRANSACTION,
     TRUE,
+    TYPE,
     UBIGINT,
     UINT,
     UINT1,
@@ -233,10 +252,13 @@ define_keywords!(
     UINT8,
     UNBOUNDED,
     UNION,
+    UNIQUE,
     UNPIVOT,
+    UPDATE,
     USING,
     USMALLINT,
     UTINYINT,
+    UUID,
     VALUES,
     VARCHAR,
     VERBOSE,
@@ -247,9 +269,12 @@ define_keywords!(
     WHERE,
     WINDOW,
     WITH,
+    WITHOUT,
+    WORK,
     XOR,
     YEAR,
     YEARS,
+    ZONE,
 );
 
 /// These keywords can't be used as a table alias, so that `FROM table_name alias`
@@ -286,6 +311,7 @@ pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
     Keyword::RIGHT,
     Keyword::NATURAL,
     Keyword::SEMI,
+    Keyword::ANTI,
     Keyword::USING,
     Keyword::CLUSTER,
     Keyword::DISTRIBUTE,
@@ -311,6 +337,7 @@ pub const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
     Keyword::GROUP,
     Keyword::SORT,
     Keyword::HAVING,
+    Keyword::WINDOW,
     Keyword::ORDER,
     Keyword::TOP,
     Keyword::LATERAL,
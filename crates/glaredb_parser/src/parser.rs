@@ -8,16 +8,20 @@ use crate::ast::{
     CreateSchema,
     CreateTable,
     CreateView,
+    Delete,
     Describe,
     Detach,
     DropStatement,
     ExplainNode,
     Ident,
     Insert,
+    Merge,
     QueryNode,
     ResetVariable,
     SetVariable,
     Show,
+    TransactionStatement,
+    Update,
 };
 use crate::keywords::{Keyword, RESERVED_FOR_COLUMN_ALIAS};
 use crate::meta::Raw;
@@ -112,11 +116,22 @@ impl<'a> Parser<'a> {
                     Keyword::SET => Ok(RawStatement::SetVariable(SetVariable::parse(self)?)),
                     Keyword::RESET => Ok(RawStatement::ResetVariable(ResetVariable::parse(self)?)),
                     Keyword::SHOW => Ok(RawStatement::Show(Show::parse(self)?)),
+                    Keyword::BEGIN
+                    | Keyword::START
+                    | Keyword::COMMIT
+                    | Keyword::END
+                    | Keyword::ROLLBACK
+                    | Keyword::ABORT => Ok(RawStatement::Transaction(TransactionStatement::parse(
+                        self,
+                    )?)),
                     Keyword::DESCRIBE => Ok(RawStatement::Describe(Describe::parse(self)?)),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => {
                         Ok(RawStatement::Query(QueryNode::parse(self)?))
                     }
                     Keyword::INSERT => Ok(RawStatement::Insert(Insert::parse(self)?)),
+                    Keyword::DELETE => Ok(RawStatement::Delete(Delete::parse(self)?)),
+                    Keyword::UPDATE => Ok(RawStatement::Update(Update::parse(self)?)),
+                    Keyword::MERGE => Ok(RawStatement::Merge(Merge::parse(self)?)),
                     Keyword::EXPLAIN => Ok(RawStatement::Explain(ExplainNode::parse(self)?)),
                     other => Err(DbError::new(format!("Unexpected keyword: {other:?}",))),
                 }
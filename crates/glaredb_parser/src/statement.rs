@@ -6,15 +6,19 @@ use crate::ast::{
     CreateSchema,
     CreateTable,
     CreateView,
+    Delete,
     Describe,
     Detach,
     DropStatement,
     ExplainNode,
     Insert,
+    Merge,
     QueryNode,
     ResetVariable,
     SetVariable,
     Show,
+    TransactionStatement,
+    Update,
 };
 use crate::meta::{AstMeta, Raw};
 
@@ -52,6 +56,15 @@ pub enum Statement<T: AstMeta> {
     /// INSERT INTO ...
     Insert(Insert<T>),
 
+    /// DELETE FROM ...
+    Delete(Delete<T>),
+
+    /// UPDATE <table> SET ...
+    Update(Update<T>),
+
+    /// MERGE INTO <table> USING ...
+    Merge(Merge<T>),
+
     /// SET <variable> TO <value>
     SetVariable(SetVariable<T>),
 
@@ -62,4 +75,7 @@ pub enum Statement<T: AstMeta> {
 
     /// RESET <variable>
     ResetVariable(ResetVariable<T>),
+
+    /// BEGIN, COMMIT, ROLLBACK
+    Transaction(TransactionStatement),
 }
@@ -82,6 +82,10 @@ pub enum Token {
     RightBracket,
     /// '=>'
     RightArrow,
+    /// '->'
+    Arrow,
+    /// '->>'
+    LongArrow,
     /// '!'
     Exclamation,
     /// '^'
@@ -321,6 +325,16 @@ impl<'a> Tokenizer<'a> {
                             Comment::SingleLine(self.take_single_line_comment().to_string());
                         Token::Comment(comment)
                     }
+                    Some('>') => {
+                        self.state.next();
+                        match self.state.peek() {
+                            Some('>') => {
+                                self.state.next();
+                                Token::LongArrow
+                            }
+                            _ => Token::Arrow,
+                        }
+                    }
                     _ => Token::Minus,
                 }
             }
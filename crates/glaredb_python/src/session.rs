@@ -1,4 +1,5 @@
 use ext_csv::extension::CsvExtension;
+use ext_json::extension::JsonExtension;
 use ext_parquet::extension::ParquetExtension;
 use ext_spark::SparkExtension;
 use ext_tpch_gen::TpchGenExtension;
@@ -33,6 +34,7 @@ pub fn connect() -> Result<PythonSession> {
     engine.register_extension(SparkExtension)?;
     engine.register_extension(TpchGenExtension)?;
     engine.register_extension(CsvExtension)?;
+    engine.register_extension(JsonExtension)?;
     engine.register_extension(ParquetExtension)?;
 
     Ok(PythonSession {
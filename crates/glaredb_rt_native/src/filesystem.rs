@@ -1,5 +1,5 @@
 use std::fs::{self, File as StdFile, OpenOptions};
-use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
 use std::path::PathBuf;
 use std::task::{Context, Poll};
 
@@ -36,10 +36,21 @@ impl FileHandle for LocalFileHandle {
         Poll::Ready(result)
     }
 
-    fn poll_write(&mut self, _cx: &mut Context, _buf: &[u8]) -> Poll<Result<usize>> {
-        Poll::Ready(Err(DbError::new(
-            "not implemented: poll write for local file",
-        )))
+    fn poll_write(&mut self, _cx: &mut Context, buf: &[u8]) -> Poll<Result<usize>> {
+        let result = self
+            .file
+            .write(buf)
+            .context("Failed to write to file")
+            .and_then(|n| {
+                // Writes past the end extend the file.
+                let pos = self
+                    .file
+                    .stream_position()
+                    .context("Failed to get position")?;
+                self.len = usize::max(self.len, pos as usize);
+                Ok(n)
+            });
+        Poll::Ready(result)
     }
 
     fn poll_seek(&mut self, _cx: &mut Context, seek: SeekFrom) -> Poll<Result<()>> {
@@ -47,9 +58,16 @@ impl FileHandle for LocalFileHandle {
     }
 
     fn poll_flush(&mut self, _cx: &mut Context) -> Poll<Result<()>> {
-        Poll::Ready(Err(DbError::new(
-            "not implemented: poll flush for local file",
-        )))
+        Poll::Ready(self.file.flush().context("Failed to flush file"))
+    }
+
+    fn poll_sync(&mut self, _cx: &mut Context) -> Poll<Result<()>> {
+        let result = self
+            .file
+            .flush()
+            .and_then(|_| self.file.sync_data())
+            .context("Failed to sync file");
+        Poll::Ready(result)
     }
 }
 
@@ -58,6 +76,7 @@ pub struct LocalFileSystem {}
 
 impl FileSystem for LocalFileSystem {
     const NAME: &str = "Local";
+    const SYNCHRONOUS: bool = true;
 
     type FileHandle = LocalFileHandle;
     type ReadDirHandle = LocalDirHandle;
@@ -72,6 +91,7 @@ impl FileSystem for LocalFileSystem {
             .read(flags.is_read())
             .write(flags.is_write())
             .create(flags.is_create())
+            .truncate(flags.is_truncate())
             .open(path)?;
 
         let metadata = file.metadata()?;
@@ -101,6 +121,10 @@ impl FileSystem for LocalFileSystem {
         Ok(Some(FileStat { file_type }))
     }
 
+    async fn delete(&self, path: &str, _state: &()) -> Result<()> {
+        fs::remove_file(path).context_fn(|| format!("Failed to delete file: {path}"))
+    }
+
     fn read_dir(&self, dir: &str, _state: &Self::State) -> Result<Self::ReadDirHandle> {
         Ok(LocalDirHandle {
             path: dir.into(),
@@ -87,6 +87,8 @@ pub struct NativeSystemRuntime {
     // so the engine could have 'default' filesystems, then the session could
     // either be a super or subset of that.
     dispatch: Arc<FileSystemDispatch>,
+    /// Http client shared with the http-based filesystems.
+    http_client: TokioWrappedHttpClient,
 }
 
 impl NativeSystemRuntime {
@@ -106,7 +108,7 @@ impl NativeSystemRuntime {
         dispatch.register_filesystem(s3_fs);
 
         // TODO: Client?
-        let gcs_fs = GcsFileSystem::new(client);
+        let gcs_fs = GcsFileSystem::new(client.clone());
         dispatch.register_filesystem(gcs_fs);
 
         // Register normal local filesystem.
@@ -114,8 +116,14 @@ impl NativeSystemRuntime {
 
         NativeSystemRuntime {
             dispatch: Arc::new(dispatch),
+            http_client: client,
         }
     }
+
+    /// Get the http client used by this runtime.
+    pub fn http_client(&self) -> &TokioWrappedHttpClient {
+        &self.http_client
+    }
 }
 
 impl SystemRuntime for NativeSystemRuntime {
@@ -20,6 +20,8 @@ use crate::time::PerformanceInstant;
 #[derive(Debug, Clone)]
 pub struct WasmSystemRuntime {
     dispatch: Arc<FileSystemDispatch>,
+    /// Http client shared with the http-based filesystems.
+    http_client: WasmHttpClient,
 }
 
 impl WasmSystemRuntime {
@@ -36,7 +38,7 @@ impl WasmSystemRuntime {
         let s3_fs = S3FileSystem::new(client.clone(), "us-east-1");
         dispatch.register_filesystem(s3_fs);
 
-        let gcs_fs = GcsFileSystem::new(client);
+        let gcs_fs = GcsFileSystem::new(client.clone());
         dispatch.register_filesystem(gcs_fs);
 
         // TODO: When it works, aka we need web workers.
@@ -47,8 +49,14 @@ impl WasmSystemRuntime {
 
         Ok(WasmSystemRuntime {
             dispatch: Arc::new(dispatch),
+            http_client: client,
         })
     }
+
+    /// Get the http client used by this runtime.
+    pub fn http_client(&self) -> &WasmHttpClient {
+        &self.http_client
+    }
 }
 
 impl SystemRuntime for WasmSystemRuntime {
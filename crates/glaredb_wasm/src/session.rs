@@ -1,7 +1,10 @@
 use std::rc::Rc;
 
 use ext_csv::extension::CsvExtension;
+use ext_delta::extension::DeltaExtension;
+use ext_iceberg::catalog::datasource::{ICEBERG_REST_DATASOURCE_NAME, IcebergRestDataSource};
 use ext_iceberg::extension::IcebergExtension;
+use ext_json::extension::JsonExtension;
 use ext_parquet::extension::ParquetExtension;
 use ext_spark::SparkExtension;
 use ext_tpch_gen::TpchGenExtension;
@@ -32,8 +35,14 @@ impl WasmSession {
         engine.register_extension(SparkExtension)?;
         engine.register_extension(TpchGenExtension)?;
         engine.register_extension(CsvExtension)?;
+        engine.register_extension(JsonExtension)?;
         engine.register_extension(ParquetExtension)?;
+        engine.register_extension(DeltaExtension)?;
         engine.register_extension(IcebergExtension)?;
+        engine.register_datasource(
+            ICEBERG_REST_DATASOURCE_NAME,
+            IcebergRestDataSource::new(runtime.http_client().clone()),
+        )?;
 
         Ok(WasmSession { runtime, engine })
     }
@@ -0,0 +1,143 @@
+//! Minimal Iceberg REST catalog server for testing ATTACH.
+//!
+//! Serves a fixed set of namespaces and tables, with table metadata loaded
+//! from the iceberg testdata directory.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+use glaredb_error::{Result, ResultExt};
+
+/// Tables served by the catalog as (namespace, table, metadata location).
+const TABLES: &[(&str, &str, &str)] = &[
+    (
+        "sales",
+        "orders",
+        "../testdata/iceberg/tables-v2/deletes/metadata/v1.metadata.json",
+    ),
+    (
+        "tpch",
+        "lineitem",
+        "../testdata/iceberg/tables-v2/lineitem_simple/metadata/v1.metadata.json",
+    ),
+    (
+        "tpch",
+        "lineitem_versioned",
+        "../testdata/iceberg/tables-v2/lineitem_versioned/metadata/v2.metadata.json",
+    ),
+];
+
+/// Namespaces served by the catalog. 'empty' has no tables.
+const NAMESPACES: &[&str] = &["sales", "tpch", "empty"];
+
+/// Start the server on a random local port in a background thread, returning
+/// the url for the catalog.
+pub fn start_mock_server() -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").context("Failed to bind mock server")?;
+    let addr = listener
+        .local_addr()
+        .context("Failed to get mock server address")?;
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            std::thread::spawn(move || {
+                if let Err(e) = handle_connection(stream) {
+                    eprintln!("Mock iceberg catalog error: {e}");
+                }
+            });
+        }
+    });
+
+    Ok(format!("http://{addr}"))
+}
+
+fn handle_connection(mut stream: TcpStream) -> Result<()> {
+    let mut reader = BufReader::new(&stream);
+
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .context("Failed to read request line")?;
+
+    // Skip headers, requests from the client don't have bodies.
+    loop {
+        let mut line = String::new();
+        let n = reader
+            .read_line(&mut line)
+            .context("Failed to read header")?;
+        if n == 0 || line == "\r\n" {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+
+    let (status, body) = match method {
+        "GET" => route(path)?,
+        _ => not_found(path),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream
+        .write_all(response.as_bytes())
+        .context("Failed to write response")?;
+
+    Ok(())
+}
+
+fn route(path: &str) -> Result<(&'static str, String)> {
+    let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+
+    let resp = match segments.as_slice() {
+        ["v1", "config"] => ("200 OK", r#"{"defaults": {}, "overrides": {}}"#.to_string()),
+        ["v1", "namespaces"] => {
+            let namespaces: Vec<_> = NAMESPACES.iter().map(|ns| format!(r#"["{ns}"]"#)).collect();
+            (
+                "200 OK",
+                format!(r#"{{"namespaces": [{}]}}"#, namespaces.join(",")),
+            )
+        }
+        ["v1", "namespaces", namespace, "tables"] if NAMESPACES.contains(namespace) => {
+            let identifiers: Vec<_> = TABLES
+                .iter()
+                .filter(|(ns, _, _)| ns == namespace)
+                .map(|(ns, table, _)| format!(r#"{{"namespace": ["{ns}"], "name": "{table}"}}"#))
+                .collect();
+            (
+                "200 OK",
+                format!(r#"{{"identifiers": [{}]}}"#, identifiers.join(",")),
+            )
+        }
+        ["v1", "namespaces", namespace, "tables", table] => {
+            match TABLES
+                .iter()
+                .find(|(ns, name, _)| ns == namespace && name == table)
+            {
+                Some((_, _, location)) => {
+                    let metadata = std::fs::read_to_string(location)
+                        .context_fn(|| format!("Failed to read metadata at {location}"))?;
+                    (
+                        "200 OK",
+                        format!(r#"{{"metadata-location": "{location}", "metadata": {metadata}}}"#),
+                    )
+                }
+                None => not_found(path),
+            }
+        }
+        _ => not_found(path),
+    };
+
+    Ok(resp)
+}
+
+fn not_found(path: &str) -> (&'static str, String) {
+    (
+        "404 Not Found",
+        format!(r#"{{"message": "Not found: {path}", "type": "NotFoundException", "code": 404}}"#),
+    )
+}
@@ -1,7 +1,13 @@
+mod iceberg_rest;
+
 use std::path::Path;
 use std::time::Duration;
 
 use ext_csv::extension::CsvExtension;
+use ext_delta::extension::DeltaExtension;
+use ext_iceberg::catalog::datasource::{ICEBERG_REST_DATASOURCE_NAME, IcebergRestDataSource};
+use ext_iceberg::extension::IcebergExtension;
+use ext_json::extension::JsonExtension;
 use ext_parquet::extension::ParquetExtension;
 use ext_tpch_gen::TpchGenExtension;
 use glaredb_core::engine::single_user::SingleUserEngine;
@@ -22,22 +28,34 @@ pub fn main() -> Result<()> {
     // Standard tests.
     run_with_all_thread_configurations::<StandardSetup>("../slt/standard", "slt_standard")?;
 
+    // Database files.
+    run_with_all_thread_configurations::<PersistentSetup>("../slt/persistent", "slt_persistent")?;
+
     // TPC-H gen extension.
     run_with_all_thread_configurations::<TpchGenSetup>("../slt/tpch_gen", "slt_tpch_gen")?;
 
     // CSV extension.
     run_with_all_thread_configurations::<CsvSetup>("../slt/csv", "slt_csv")?;
 
+    // JSON extension.
+    run_with_all_thread_configurations::<JsonSetup>("../slt/json", "slt_json")?;
+
     // Parquet extension.
     run_with_all_thread_configurations::<ParquetSetup>("../slt/parquet", "slt_parquet")?;
 
+    // Delta extension.
+    run_with_all_thread_configurations::<DeltaSetup>("../slt/delta", "slt_delta")?;
+
+    // Iceberg extension.
+    run_with_all_thread_configurations::<IcebergSetup>("../slt/iceberg", "slt_iceberg")?;
+
     // Read files over http
     run_with_all_thread_configurations::<HttpSetup>("../slt/http", "slt_http")?;
 
     // Public S3 with CSV, parquet
     run_with_all_thread_configurations::<S3PublicSetup>("../slt/s3/public", "slt_s3_public")?;
 
-    // Private S3 with CSV, parquet
+    // Private S3 with CSV, parquet, delta, iceberg
     run_with_all_thread_configurations::<S3PrivateSetup>("../slt/s3/private", "slt_s3_private")?;
 
     // Public GCS with CSV, parquet
@@ -98,6 +116,24 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+struct PersistentSetup;
+
+impl<E, R> EngineSetup<E, R> for PersistentSetup
+where
+    E: PipelineRuntime,
+    R: SystemRuntime,
+{
+    fn setup(engine: SingleUserEngine<E, R>) -> Result<RunConfig<E, R>> {
+        Ok(RunConfig {
+            engine,
+            vars: ReplacementVars::default(),
+            create_slt_tmp: true,
+            query_timeout: Duration::from_secs(30),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct TpchGenSetup;
 
@@ -127,6 +163,25 @@ where
 {
     fn setup(engine: SingleUserEngine<E, R>) -> Result<RunConfig<E, R>> {
         engine.register_extension(CsvExtension)?;
+        Ok(RunConfig {
+            engine,
+            vars: ReplacementVars::default(),
+            create_slt_tmp: true,
+            query_timeout: Duration::from_secs(5),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct JsonSetup;
+
+impl<E, R> EngineSetup<E, R> for JsonSetup
+where
+    E: PipelineRuntime,
+    R: SystemRuntime,
+{
+    fn setup(engine: SingleUserEngine<E, R>) -> Result<RunConfig<E, R>> {
+        engine.register_extension(JsonExtension)?;
         Ok(RunConfig {
             engine,
             vars: ReplacementVars::default(),
@@ -149,6 +204,56 @@ where
         Ok(RunConfig {
             engine,
             vars: ReplacementVars::default(),
+            create_slt_tmp: true,
+            query_timeout: Duration::from_secs(5),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DeltaSetup;
+
+impl<E, R> EngineSetup<E, R> for DeltaSetup
+where
+    E: PipelineRuntime,
+    R: SystemRuntime,
+{
+    fn setup(engine: SingleUserEngine<E, R>) -> Result<RunConfig<E, R>> {
+        engine.register_extension(DeltaExtension)?;
+        Ok(RunConfig {
+            engine,
+            vars: ReplacementVars::default(),
+            create_slt_tmp: false,
+            query_timeout: Duration::from_secs(5),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IcebergSetup;
+
+impl<E> EngineSetup<E, NativeSystemRuntime> for IcebergSetup
+where
+    E: PipelineRuntime,
+{
+    fn setup(
+        engine: SingleUserEngine<E, NativeSystemRuntime>,
+    ) -> Result<RunConfig<E, NativeSystemRuntime>> {
+        engine.register_extension(IcebergExtension)?;
+        engine.register_datasource(
+            ICEBERG_REST_DATASOURCE_NAME,
+            IcebergRestDataSource::new(engine.engine.runtime().http_client().clone()),
+        )?;
+
+        let mut vars = ReplacementVars::default();
+        vars.add_var(
+            "ICEBERG_REST_URL",
+            VarValue::Plain(iceberg_rest::start_mock_server()?),
+        );
+
+        Ok(RunConfig {
+            engine,
+            vars,
             create_slt_tmp: false,
             query_timeout: Duration::from_secs(5),
         })
@@ -206,6 +311,8 @@ where
     fn setup(engine: SingleUserEngine<E, R>) -> Result<RunConfig<E, R>> {
         engine.register_extension(CsvExtension)?;
         engine.register_extension(ParquetExtension)?;
+        engine.register_extension(DeltaExtension)?;
+        engine.register_extension(IcebergExtension)?;
 
         let mut vars = ReplacementVars::default();
 
@@ -0,0 +1,365 @@
+"""
+Generate a small v2 iceberg table containing position and equality delete
+files.
+
+Spark doesn't easily produce equality deletes, so this writes the table
+metadata, manifest lists, and manifests directly. Parquet files are written
+using the glaredb CLI.
+
+Usage (from this directory):
+
+    $ python generate_deletes.py --glaredb ../../target/debug/glaredb
+
+Table contents by snapshot:
+
+1. Append 'data-1.parquet' with ids 1 through 6.
+2. Position deletes for rows 1 and 3 in 'data-1.parquet' (ids 2 and 4).
+3. Equality delete on id = 5, and append 'data-2.parquet' with ids 5 and 7.
+   The equality delete has the same sequence number as 'data-2.parquet' so
+   only applies to 'data-1.parquet'.
+
+Reading the current snapshot should return ids 1, 3, 5 ('five again'), 6, and
+7.
+"""
+
+import argparse
+import io
+import json
+import os
+import shutil
+import subprocess
+
+TABLE_DIR = "./tables-v2/deletes"
+LOCATION = "./iceberg/tables-v2/deletes"
+
+SCHEMA = {
+    "type": "struct",
+    "schema-id": 0,
+    "fields": [
+        {"id": 1, "name": "id", "required": False, "type": "long"},
+        {"id": 2, "name": "name", "required": False, "type": "string"},
+    ],
+}
+
+PARTITION_SPEC = {"spec-id": 0, "fields": []}
+
+MANIFEST_LIST_SCHEMA = {
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+        {
+            "name": "partitions",
+            "type": [
+                "null",
+                {
+                    "type": "array",
+                    "items": {
+                        "type": "record",
+                        "name": "r508",
+                        "fields": [
+                            {"name": "contains_null", "type": "boolean", "field-id": 509},
+                            {"name": "contains_nan", "type": ["null", "boolean"], "field-id": 518},
+                            {"name": "lower_bound", "type": ["null", "bytes"], "field-id": 510},
+                            {"name": "upper_bound", "type": ["null", "bytes"], "field-id": 511},
+                        ],
+                    },
+                    "element-id": 508,
+                },
+            ],
+            "default": None,
+            "field-id": 507,
+        },
+    ],
+}
+
+MANIFEST_SCHEMA = {
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": ["null", "long"], "default": None, "field-id": 1},
+        {"name": "sequence_number", "type": ["null", "long"], "default": None, "field-id": 3},
+        {"name": "file_sequence_number", "type": ["null", "long"], "default": None, "field-id": 4},
+        {
+            "name": "data_file",
+            "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    {"name": "content", "type": "int", "field-id": 134},
+                    {"name": "file_path", "type": "string", "field-id": 100},
+                    {"name": "file_format", "type": "string", "field-id": 101},
+                    {
+                        "name": "partition",
+                        "type": {"type": "record", "name": "r102", "fields": []},
+                        "field-id": 102,
+                    },
+                    {"name": "record_count", "type": "long", "field-id": 103},
+                    {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                    {
+                        "name": "equality_ids",
+                        "type": ["null", {"type": "array", "items": "int", "element-id": 136}],
+                        "default": None,
+                        "field-id": 135,
+                    },
+                ],
+            },
+            "field-id": 2,
+        },
+    ],
+}
+
+
+# Minimal avro encoding, only what's needed for the schemas above.
+
+
+def encode_long(n):
+    n = (n << 1) ^ (n >> 63)
+    out = bytearray()
+    while n & ~0x7F:
+        out.append((n & 0x7F) | 0x80)
+        n >>= 7
+    out.append(n)
+    return bytes(out)
+
+
+def encode_bytes(b):
+    return encode_long(len(b)) + b
+
+
+def encode_string(s):
+    return encode_bytes(s.encode("utf-8"))
+
+
+def encode_union(index, value=b""):
+    return encode_long(index) + value
+
+
+def encode_array(items):
+    if not items:
+        return encode_long(0)
+    return encode_long(len(items)) + b"".join(items) + encode_long(0)
+
+
+def write_avro(path, schema, metadata, records):
+    sync = bytes(range(16))
+    meta = {"avro.schema": json.dumps(schema), "avro.codec": "null", **metadata}
+
+    buf = io.BytesIO()
+    buf.write(b"Obj\x01")
+    buf.write(encode_long(len(meta)))
+    for key, value in meta.items():
+        buf.write(encode_string(key))
+        buf.write(encode_bytes(value.encode("utf-8")))
+    buf.write(encode_long(0))
+    buf.write(sync)
+
+    block = b"".join(records)
+    buf.write(encode_long(len(records)))
+    buf.write(encode_long(len(block)))
+    buf.write(block)
+    buf.write(sync)
+
+    with open(path, "wb") as f:
+        f.write(buf.getvalue())
+    return os.path.getsize(path)
+
+
+def manifest_entry(snapshot_id, sequence_number, content, path, record_count, equality_ids=None):
+    seq = encode_union(0) if sequence_number is None else encode_union(1, encode_long(sequence_number))
+    eq_ids = (
+        encode_union(0)
+        if equality_ids is None
+        else encode_union(1, encode_array([encode_long(i) for i in equality_ids]))
+    )
+    data_file = (
+        encode_long(content)
+        + encode_string(path)
+        + encode_string("PARQUET")
+        + encode_long(record_count)
+        + encode_long(os.path.getsize(table_path(path)))
+        + eq_ids
+    )
+    return (
+        encode_long(1)  # Added
+        + encode_union(1, encode_long(snapshot_id))
+        + seq
+        + seq
+        + data_file
+    )
+
+
+def manifest_list_entry(path, length, content, sequence_number, snapshot_id, rows):
+    return (
+        encode_string(path)
+        + encode_long(length)
+        + encode_long(0)
+        + encode_long(content)
+        + encode_long(sequence_number)
+        + encode_long(sequence_number)
+        + encode_long(snapshot_id)
+        + encode_long(1)
+        + encode_long(0)
+        + encode_long(0)
+        + encode_long(rows)
+        + encode_long(0)
+        + encode_long(0)
+        + encode_union(1, encode_array([]))
+    )
+
+
+def table_path(path):
+    """Convert a path relative to the table location to a local path."""
+    return os.path.join(TABLE_DIR, os.path.relpath(path, LOCATION))
+
+
+def write_manifest(name, content, entries):
+    path = f"{LOCATION}/metadata/{name}"
+    metadata = {
+        "schema": json.dumps(SCHEMA),
+        "schema-id": "0",
+        "partition-spec": json.dumps(PARTITION_SPEC["fields"]),
+        "partition-spec-id": "0",
+        "format-version": "2",
+        "content": content,
+    }
+    length = write_avro(table_path(path), MANIFEST_SCHEMA, metadata, entries)
+    return path, length
+
+
+def write_manifest_list(snapshot_id, sequence_number, entries):
+    path = f"{LOCATION}/metadata/snap-{snapshot_id}.avro"
+    metadata = {
+        "snapshot-id": str(snapshot_id),
+        "sequence-number": str(sequence_number),
+        "format-version": "2",
+    }
+    write_avro(table_path(path), MANIFEST_LIST_SCHEMA, metadata, entries)
+    return path
+
+
+def write_parquet(glaredb, path, query):
+    sql = f"COPY ({query}) TO '{table_path(path)}';"
+    subprocess.run([glaredb, "-c", sql], check=True, stdout=subprocess.DEVNULL)
+
+
+def main():
+    parser = argparse.ArgumentParser()
+    parser.add_argument("--glaredb", default="glaredb", help="Path to the glaredb binary")
+    args = parser.parse_args()
+
+    shutil.rmtree(TABLE_DIR, ignore_errors=True)
+    os.makedirs(f"{TABLE_DIR}/data")
+    os.makedirs(f"{TABLE_DIR}/metadata")
+
+    data_1 = f"{LOCATION}/data/data-1.parquet"
+    pos_deletes = f"{LOCATION}/data/pos-deletes-1.parquet"
+    eq_deletes = f"{LOCATION}/data/eq-deletes-1.parquet"
+    data_2 = f"{LOCATION}/data/data-2.parquet"
+
+    write_parquet(
+        args.glaredb,
+        data_1,
+        "SELECT * FROM (VALUES (1::BIGINT, 'one'), (2, 'two'), (3, 'three'), "
+        "(4, 'four'), (5, 'five'), (6, 'six')) v(id, name)",
+    )
+    write_parquet(
+        args.glaredb,
+        pos_deletes,
+        f"SELECT * FROM (VALUES ('{data_1}', 1::BIGINT), ('{data_1}', 3)) v(file_path, pos)",
+    )
+    write_parquet(args.glaredb, eq_deletes, "SELECT 5::BIGINT AS id")
+    write_parquet(
+        args.glaredb,
+        data_2,
+        "SELECT * FROM (VALUES (5::BIGINT, 'five again'), (7, 'seven')) v(id, name)",
+    )
+
+    # Snapshot 1, append data.
+    m1 = write_manifest("m1.avro", "data", [manifest_entry(1, 1, 0, data_1, 6)])
+    # Snapshot 2, position deletes.
+    m2 = write_manifest("m2.avro", "deletes", [manifest_entry(2, 2, 1, pos_deletes, 2)])
+    # Snapshot 3, equality deletes and new data. The data entry inherits its
+    # sequence number from the manifest list.
+    m3 = write_manifest(
+        "m3.avro", "deletes", [manifest_entry(3, 3, 2, eq_deletes, 1, equality_ids=[1])]
+    )
+    m4 = write_manifest("m4.avro", "data", [manifest_entry(3, None, 0, data_2, 2)])
+
+    lists = {
+        1: write_manifest_list(1, 1, [manifest_list_entry(*m1, 0, 1, 1, 6)]),
+        2: write_manifest_list(
+            2,
+            2,
+            [manifest_list_entry(*m1, 0, 1, 1, 6), manifest_list_entry(*m2, 1, 2, 2, 2)],
+        ),
+        3: write_manifest_list(
+            3,
+            3,
+            [
+                manifest_list_entry(*m1, 0, 1, 1, 6),
+                manifest_list_entry(*m2, 1, 2, 2, 2),
+                manifest_list_entry(*m3, 1, 3, 3, 1),
+                manifest_list_entry(*m4, 0, 3, 3, 2),
+            ],
+        ),
+    }
+
+    timestamp_ms = 1700000000000
+    snapshots = [
+        {
+            "sequence-number": seq,
+            "snapshot-id": seq,
+            **({"parent-snapshot-id": seq - 1} if seq > 1 else {}),
+            "timestamp-ms": timestamp_ms + seq,
+            "summary": {"operation": "overwrite" if seq > 1 else "append"},
+            "manifest-list": lists[seq],
+            "schema-id": 0,
+        }
+        for seq in [1, 2, 3]
+    ]
+
+    metadata = {
+        "format-version": 2,
+        "table-uuid": "5b3f7a64-7d0c-4f8e-9a55-2d1c6e0b9f41",
+        "location": LOCATION,
+        "last-sequence-number": 3,
+        "last-updated-ms": timestamp_ms + 3,
+        "last-column-id": 2,
+        "current-schema-id": 0,
+        "schemas": [SCHEMA],
+        "default-spec-id": 0,
+        "partition-specs": [PARTITION_SPEC],
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{"order-id": 0, "fields": []}],
+        "properties": {"write.delete.mode": "merge-on-read"},
+        "current-snapshot-id": 3,
+        "snapshots": snapshots,
+        "snapshot-log": [
+            {"timestamp-ms": s["timestamp-ms"], "snapshot-id": s["snapshot-id"]} for s in snapshots
+        ],
+        "metadata-log": [],
+    }
+
+    with open(f"{TABLE_DIR}/metadata/v1.metadata.json", "w") as f:
+        json.dump(metadata, f, indent=2)
+    with open(f"{TABLE_DIR}/metadata/version-hint.text", "w") as f:
+        f.write("1")
+
+
+if __name__ == "__main__":
+    main()